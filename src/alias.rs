@@ -0,0 +1,442 @@
+//! User-defined command aliases and default arguments, from the `[alias]`
+//! and `[defaults]` config sections.
+//!
+//! Aliases are expanded in argv before clap parses it, so `wt ls -v` with
+//! `ls = "list --branches"` parses exactly like `wt list --branches -v`.
+//! Built-in subcommands always take precedence, and expansions aren't
+//! re-expanded, so an alias can't shadow or recurse into another command.
+//!
+//! Defaults are inserted right after the subcommand's name, so with
+//! `list = ["--branches"]` `wt list --full` parses like
+//! `wt list --branches --full`. Arguments override themselves (see
+//! [`cli::build_command`]), so anything given on the command line wins.
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use color_print::cformat;
+use worktrunk::config::{DefaultArgs, WorktrunkConfig, set_config_path, set_profile};
+use worktrunk::git::set_base_path;
+
+use crate::cli;
+
+/// An option of [`cli::Cli`] itself, as it can appear before the subcommand:
+/// the global ones, and root-only ones like `--yes`
+struct GlobalArg {
+    id: String,
+    longs: Vec<String>,
+    shorts: Vec<char>,
+    takes_value: bool,
+}
+
+/// The options before the subcommand, read from clap so new ones are
+/// recognized as they're added
+fn global_args() -> Vec<GlobalArg> {
+    cli::build_command()
+        .get_arguments()
+        .filter(|arg| !arg.is_positional())
+        .map(|arg| GlobalArg {
+            id: arg.get_id().to_string(),
+            longs: arg
+                .get_long()
+                .into_iter()
+                .chain(arg.get_all_aliases().unwrap_or_default())
+                .map(String::from)
+                .collect(),
+            shorts: arg
+                .get_short()
+                .into_iter()
+                .chain(arg.get_all_short_aliases().unwrap_or_default())
+                .collect(),
+            takes_value: arg.get_action().takes_values(),
+        })
+        .collect()
+}
+
+/// Where the subcommand word sits in argv, skipping leading global options.
+#[derive(Default)]
+pub struct Scan {
+    /// Index of the first positional argument (the subcommand name)
+    pub position: Option<usize>,
+    /// Value of `--config`, needed to load aliases before clap runs
+    pub config: Option<PathBuf>,
+    /// Value of `-C`, needed to give plugins repository context
+    pub directory: Option<PathBuf>,
+    /// Value of `--repo`, the registered-repository alternative to `-C`
+    pub repo: Option<String>,
+    /// Value of `--profile`, since profiles can define aliases
+    pub profile: Option<String>,
+}
+
+pub fn scan(args: &[OsString]) -> Scan {
+    let globals = global_args();
+    let mut result = Scan::default();
+    let mut record = |id: &str, value: Option<OsString>| match id {
+        "config" => result.config = value.map(PathBuf::from),
+        "repo" => result.repo = value.map(|v| v.to_string_lossy().into_owned()),
+        "profile" => result.profile = value.map(|v| v.to_string_lossy().into_owned()),
+        "directory" => result.directory = value.map(PathBuf::from),
+        _ => {}
+    };
+    let mut i = 1;
+    'args: while let Some(arg) = args.get(i) {
+        let Some(arg) = arg.to_str() else { break };
+        if let Some(long) = arg.strip_prefix("--") {
+            // `--config=x.toml` carries its value; `--config x.toml` takes the next argument
+            let (name, inline) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value)),
+                None => (long, None),
+            };
+            // Any other option (e.g. `--help`) is clap's business
+            let Some(global) = globals.iter().find(|g| g.longs.iter().any(|l| l == name)) else {
+                break;
+            };
+            match (global.takes_value, inline) {
+                (true, Some(value)) => {
+                    record(&global.id, Some(OsString::from(value)));
+                    i += 1;
+                }
+                (true, None) => {
+                    record(&global.id, args.get(i + 1).cloned());
+                    i += 2;
+                }
+                (false, None) => i += 1,
+                (false, Some(_)) => break,
+            }
+        } else if let Some(shorts) = arg.strip_prefix('-').filter(|s| !s.is_empty()) {
+            // Short flags combine (`-qy`, `-vvv`); one taking a value ends the
+            // group, with the rest (`-C/tmp`) or the next argument as its value
+            for (offset, short) in shorts.char_indices() {
+                let Some(global) = globals.iter().find(|g| g.shorts.contains(&short)) else {
+                    break 'args;
+                };
+                if global.takes_value {
+                    let rest = &shorts[offset + short.len_utf8()..];
+                    let rest = rest.strip_prefix('=').unwrap_or(rest);
+                    if rest.is_empty() {
+                        record(&global.id, args.get(i + 1).cloned());
+                        i += 2;
+                    } else {
+                        record(&global.id, Some(OsString::from(rest)));
+                        i += 1;
+                    }
+                    continue 'args;
+                }
+            }
+            i += 1;
+        } else {
+            result.position = Some(i);
+            break;
+        }
+    }
+    result
+}
+
+/// Whether `name` is a built-in subcommand (or one of its clap aliases).
+pub fn is_builtin(name: &str) -> bool {
+    cli::build_command().find_subcommand(name).is_some()
+}
+
+/// Load the configured aliases, ignoring config errors.
+///
+/// A broken config file is reported when the command itself loads the config;
+/// here it just means no aliases are available.
+pub fn load_aliases() -> BTreeMap<String, String> {
+    WorktrunkConfig::load()
+        .map(|config| config.alias)
+        .unwrap_or_default()
+}
+
+/// Replace the word at `position` with the alias expansion.
+///
+/// Returns the number of words the alias expanded to, or `None` if the word
+/// isn't an alias.
+fn expand_at(
+    args: &mut Vec<OsString>,
+    position: usize,
+    aliases: &BTreeMap<String, String>,
+) -> anyhow::Result<Option<usize>> {
+    let Some(expansion) = args[position].to_str().and_then(|name| aliases.get(name)) else {
+        return Ok(None);
+    };
+    let words = shlex::split(expansion)
+        .filter(|words| !words.is_empty())
+        .ok_or_else(|| {
+            let name = args[position].to_string_lossy();
+            anyhow::anyhow!(cformat!("Invalid alias <bold>{name}</>: {expansion:?}"))
+        })?;
+    let len = words.len();
+    args.splice(position..=position, words.into_iter().map(OsString::from));
+    Ok(Some(len))
+}
+
+/// Insert `[defaults]` arguments after the subcommand name at `position`.
+///
+/// Nested subcommands are looked up by their full path (`"step commit"`),
+/// using canonical names so a subcommand's clap aliases share its defaults.
+fn insert_defaults(
+    args: &mut Vec<OsString>,
+    position: usize,
+    defaults: &BTreeMap<String, DefaultArgs>,
+) -> anyhow::Result<()> {
+    let mut command = cli::build_command();
+    let mut path = Vec::new();
+    let mut end = position;
+    while let Some(sub) = args
+        .get(end)
+        .and_then(|arg| arg.to_str())
+        .and_then(|name| command.find_subcommand(name))
+    {
+        path.push(sub.get_name().to_string());
+        command = sub.clone();
+        end += 1;
+    }
+    let key = path.join(" ");
+    let Some(default_args) = defaults.get(&key) else {
+        return Ok(());
+    };
+    let words = default_args.args().ok_or_else(|| {
+        anyhow::anyhow!(cformat!(
+            "Invalid defaults for <bold>{key}</>: {default_args:?}"
+        ))
+    })?;
+    args.splice(end..end, words.into_iter().map(OsString::from));
+    Ok(())
+}
+
+/// Expand a user-defined alias in `args` (including `argv[0]`), then insert
+/// the subcommand's configured default arguments.
+pub fn expand_args(mut args: Vec<OsString>) -> anyhow::Result<Vec<OsString>> {
+    let scan = scan(&args);
+    let Some(position) = scan.position else {
+        return Ok(args);
+    };
+    if args[position].to_str().is_none() {
+        return Ok(args);
+    }
+    if let Some(path) = scan.config {
+        set_config_path(path);
+    }
+    if let Some(name) = scan.profile {
+        set_profile(name);
+    }
+    // Repository config can define aliases and defaults too, so look in the `-C` directory
+    if let Some(dir) = scan.directory {
+        set_base_path(dir);
+    }
+    // A broken config is reported when the command itself loads it
+    let Ok(config) = WorktrunkConfig::load() else {
+        return Ok(args);
+    };
+    if args[position]
+        .to_str()
+        .is_some_and(|name| !is_builtin(name))
+    {
+        expand_at(&mut args, position, &config.alias)?;
+    }
+    if !config.defaults.is_empty() {
+        insert_defaults(&mut args, position, &config.defaults)?;
+    }
+    Ok(args)
+}
+
+/// Expand an alias in completion words, shifting the cursor `index` to match.
+pub fn expand_completion_args(args: &mut Vec<OsString>, index: &mut usize) {
+    let scan = scan(args);
+    // Only expand once the alias name is complete (cursor is past it)
+    let Some(position) = scan.position.filter(|&p| p < *index) else {
+        return;
+    };
+    if args[position].to_str().is_none_or(is_builtin) {
+        return;
+    }
+    if let Some(path) = scan.config {
+        set_config_path(path);
+    }
+    if let Some(name) = scan.profile {
+        set_profile(name);
+    }
+    if let Ok(Some(len)) = expand_at(args, position, &load_aliases()) {
+        *index += len - 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(words: &[&str]) -> Vec<OsString> {
+        words.iter().map(OsString::from).collect()
+    }
+
+    fn aliases() -> BTreeMap<String, String> {
+        BTreeMap::from([
+            ("ls".to_string(), "list --branches --remotes".to_string()),
+            ("bad".to_string(), "list 'unterminated".to_string()),
+        ])
+    }
+
+    #[test]
+    fn test_scan_skips_global_options() {
+        let result = scan(&args(&["wt", "-C", "/tmp", "-v", "ls"]));
+        assert_eq!(result.position, Some(4));
+        assert_eq!(result.config, None);
+        assert_eq!(result.directory, Some(PathBuf::from("/tmp")));
+        assert_eq!(scan(&args(&["wt", "--no-emoji", "ls"])).position, Some(2));
+        assert_eq!(scan(&args(&["wt", "--no-hooks", "ls"])).position, Some(2));
+
+        let result = scan(&args(&["wt", "-C/tmp", "--config=/x.toml", "ls"]));
+        assert_eq!(result.position, Some(3));
+        assert_eq!(result.config, Some(PathBuf::from("/x.toml")));
+        assert_eq!(result.directory, Some(PathBuf::from("/tmp")));
+
+        let result = scan(&args(&[
+            "wt", "--config", "/x.toml", "--repo", "app", "ls", "-v",
+        ]));
+        assert_eq!(result.position, Some(5));
+        assert_eq!(result.config, Some(PathBuf::from("/x.toml")));
+        assert_eq!(result.repo.as_deref(), Some("app"));
+
+        let result = scan(&args(&["wt", "--profile", "work", "--profile=oss", "ls"]));
+        assert_eq!(result.position, Some(4));
+        assert_eq!(result.profile.as_deref(), Some("oss"));
+
+        let result = scan(&args(&[
+            "wt",
+            "--git-dir",
+            "/r/.git",
+            "--work-tree=/r",
+            "ls",
+        ]));
+        assert_eq!(result.position, Some(4));
+        let result = scan(&args(&[
+            "wt",
+            "--remote",
+            "upstream",
+            "--remote=origin",
+            "ls",
+        ]));
+        assert_eq!(result.position, Some(4));
+    }
+
+    #[test]
+    fn test_scan_combined_short_flags() {
+        assert_eq!(scan(&args(&["wt", "-vvv", "ls"])).position, Some(2));
+        assert_eq!(scan(&args(&["wt", "-qy", "ls"])).position, Some(2));
+        let result = scan(&args(&["wt", "-vC/tmp", "ls"]));
+        assert_eq!(result.position, Some(2));
+        assert_eq!(result.directory, Some(PathBuf::from("/tmp")));
+        let result = scan(&args(&["wt", "-yC", "/tmp", "ls"]));
+        assert_eq!(result.position, Some(3));
+        assert_eq!(result.directory, Some(PathBuf::from("/tmp")));
+    }
+
+    #[test]
+    fn test_scan_recognizes_every_global_arg() {
+        let globals = global_args();
+        for arg in cli::build_command().get_arguments() {
+            if arg.is_global_set() {
+                assert!(globals.iter().any(|g| g.id == arg.get_id().as_str()));
+            }
+        }
+        assert!(globals.iter().any(|g| g.id == "assume_yes"));
+        for global in globals {
+            let mut forms: Vec<Vec<String>> = Vec::new();
+            for long in &global.longs {
+                if global.takes_value {
+                    forms.push(vec![format!("--{long}"), "x".into()]);
+                    forms.push(vec![format!("--{long}=x")]);
+                } else {
+                    forms.push(vec![format!("--{long}")]);
+                }
+            }
+            for short in &global.shorts {
+                if global.takes_value {
+                    forms.push(vec![format!("-{short}"), "x".into()]);
+                    forms.push(vec![format!("-{short}x")]);
+                } else {
+                    forms.push(vec![format!("-{short}")]);
+                }
+            }
+            assert!(!forms.is_empty(), "{} has no flag", global.id);
+            for form in forms {
+                let mut words = vec!["wt".to_string()];
+                words.extend(form.iter().cloned());
+                words.push("ls".into());
+                let words: Vec<&str> = words.iter().map(String::as_str).collect();
+                assert_eq!(
+                    scan(&args(&words)).position,
+                    Some(form.len() + 1),
+                    "{form:?} wasn't skipped"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_scan_stops_at_other_options() {
+        assert_eq!(scan(&args(&["wt", "--help", "ls"])).position, None);
+        assert_eq!(scan(&args(&["wt"])).position, None);
+    }
+
+    #[test]
+    fn test_expand_at() {
+        let mut words = args(&["wt", "-v", "ls", "--full"]);
+        assert_eq!(expand_at(&mut words, 2, &aliases()).unwrap(), Some(3));
+        assert_eq!(
+            words,
+            args(&["wt", "-v", "list", "--branches", "--remotes", "--full"])
+        );
+
+        let mut words = args(&["wt", "unknown"]);
+        assert_eq!(expand_at(&mut words, 1, &aliases()).unwrap(), None);
+        assert_eq!(words, args(&["wt", "unknown"]));
+    }
+
+    #[test]
+    fn test_insert_defaults() {
+        let defaults = BTreeMap::from([
+            (
+                "list".to_string(),
+                DefaultArgs::Args(vec!["--branches".to_string()]),
+            ),
+            (
+                "step commit".to_string(),
+                DefaultArgs::Line("--stage 'tracked'".to_string()),
+            ),
+            ("merge".to_string(), DefaultArgs::Line("'".to_string())),
+        ]);
+
+        let mut words = args(&["wt", "-v", "list", "--full"]);
+        insert_defaults(&mut words, 2, &defaults).unwrap();
+        assert_eq!(words, args(&["wt", "-v", "list", "--branches", "--full"]));
+
+        let mut words = args(&["wt", "step", "commit", "-y"]);
+        insert_defaults(&mut words, 1, &defaults).unwrap();
+        assert_eq!(
+            words,
+            args(&["wt", "step", "commit", "--stage", "tracked", "-y"])
+        );
+
+        // Other commands, including parents of configured ones, are untouched
+        let mut words = args(&["wt", "step", "push"]);
+        insert_defaults(&mut words, 1, &defaults).unwrap();
+        assert_eq!(words, args(&["wt", "step", "push"]));
+
+        let mut words = args(&["wt", "merge"]);
+        let err = insert_defaults(&mut words, 1, &defaults).unwrap_err();
+        assert!(err.to_string().contains("merge"), "{err}");
+    }
+
+    #[test]
+    fn test_expand_at_invalid() {
+        let mut words = args(&["wt", "bad"]);
+        let err = expand_at(&mut words, 1, &aliases()).unwrap_err();
+        assert!(
+            err.to_string().ends_with(r#": "list 'unterminated""#),
+            "{err}"
+        );
+        assert_eq!(words, args(&["wt", "bad"]));
+    }
+}
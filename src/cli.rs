@@ -90,7 +90,12 @@ pub fn build_command() -> Command {
 }
 
 fn apply_help_template_recursive(mut cmd: Command, path: &str) -> Command {
-    cmd = cmd.help_template(HELP_TEMPLATE).display_name(path);
+    // Repeating an argument replaces its earlier value rather than erroring,
+    // so command-line arguments override `[defaults]` inserted before them
+    cmd = cmd
+        .help_template(HELP_TEMPLATE)
+        .display_name(path)
+        .args_override_self(true);
 
     for sub in cmd.get_subcommands_mut() {
         let sub_cmd = std::mem::take(sub);
@@ -139,6 +144,18 @@ Getting started
   wt merge                      Squash, rebase, and merge to default branch
 
 Run `wt config shell` to set up directory switching.
+Other commands run a `wt-<name>` executable from PATH, like git.
+
+Exit codes
+
+  0    Success
+  1    Other failure
+  2    Usage error
+  3    Refused: uncommitted changes
+  4    Conflicting or diverged history
+  5    Remote, network, or CI provider failure
+
+Failing hook commands exit with the command's own code.
 
 Docs: https://worktrunk.dev
 GitHub: https://github.com/max-sixty/worktrunk")]
@@ -153,25 +170,170 @@ pub struct Cli {
     )]
     pub directory: Option<std::path::PathBuf>,
 
+    /// Registered repository to operate on
+    #[arg(
+        long,
+        global = true,
+        value_name = "name",
+        conflicts_with = "directory",
+        display_order = 101,
+        help_heading = "Global Options",
+        add = crate::completion::repo_name_completer()
+    )]
+    pub repo: Option<String>,
+
+    /// Git directory to operate on
+    ///
+    /// Like `git --git-dir`, for repositories kept apart from their files,
+    /// such as a bare dotfiles repository. Defaults to `$GIT_DIR`.
+    #[arg(
+        long,
+        global = true,
+        value_name = "path",
+        display_order = 102,
+        help_heading = "Global Options"
+    )]
+    pub git_dir: Option<std::path::PathBuf>,
+
+    /// Working tree of the `--git-dir` repository
+    ///
+    /// Like `git --work-tree`. Defaults to `$GIT_WORK_TREE`.
+    #[arg(
+        long,
+        global = true,
+        value_name = "path",
+        display_order = 103,
+        help_heading = "Global Options"
+    )]
+    pub work_tree: Option<std::path::PathBuf>,
+
+    /// Remote to compare against
+    ///
+    /// Detects the default branch from this remote, and counts ahead/behind
+    /// and checks integration against its copy (`upstream/main`), instead of
+    /// the first remote git lists. Also settable with `remote` in the user
+    /// config.
+    #[arg(
+        long,
+        global = true,
+        value_name = "name",
+        display_order = 104,
+        help_heading = "Global Options"
+    )]
+    pub remote: Option<String>,
+
     /// User config file path
+    ///
+    /// Load the user config from this file instead of
+    /// `~/.config/worktrunk/config.toml`; the file must exist.
+    /// `WORKTRUNK_CONFIG_PATH` also sets the location, but the file there is
+    /// optional.
     #[arg(
         long,
         global = true,
         value_name = "path",
-        display_order = 101,
+        display_order = 105,
         help_heading = "Global Options"
     )]
     pub config: Option<std::path::PathBuf>,
 
-    /// Show commands and debug info
+    /// Config profile to apply
+    #[arg(
+        long,
+        global = true,
+        value_name = "name",
+        display_order = 106,
+        help_heading = "Global Options"
+    )]
+    pub profile: Option<String>,
+
+    /// Append a JSON-lines log of what worktrunk does to this file
+    ///
+    /// Records every command run (with duration and result), every hook
+    /// command, and every worktree, branch, and directory change, one JSON
+    /// object per line. Useful for auditing and for attaching to bug reports.
+    /// Also settable with `log-file` in the user config.
+    #[arg(
+        long,
+        global = true,
+        value_name = "path",
+        display_order = 106,
+        help_heading = "Global Options"
+    )]
+    pub log_file: Option<std::path::PathBuf>,
+
+    /// Show commands run; -vv for timing and debug info
+    ///
+    /// `-v` logs each external command (`git`, `gh`, hooks' shells) as it
+    /// starts. `-vv` adds how long each took, its output, and internal debug
+    /// messages. Logs go to stderr; `RUST_LOG` overrides the level.
     #[arg(
         long,
         short = 'v',
         global = true,
-        display_order = 102,
+        action = clap::ArgAction::Count,
+        display_order = 107,
+        help_heading = "Global Options"
+    )]
+    pub verbose: u8,
+
+    /// Only show warnings and errors
+    ///
+    /// Progress, success, and hint messages are left out. Prompts, hook
+    /// output, and command output on stdout (such as `wt list`) are not
+    /// affected.
+    #[arg(
+        long,
+        short = 'q',
+        global = true,
+        conflicts_with = "verbose",
+        display_order = 107,
+        help_heading = "Global Options"
+    )]
+    pub quiet: bool,
+
+    /// Use plain-text tags instead of emoji in messages
+    ///
+    /// Prefixes messages with tags like `[ok]` and `[error]` instead of
+    /// symbols like `✓` and `✗`, for terminals and logs that render them
+    /// poorly. Also settable with `styling.emoji = false`.
+    #[arg(
+        long,
+        global = true,
+        display_order = 108,
+        help_heading = "Global Options"
+    )]
+    pub no_emoji: bool,
+
+    /// Skip all configured hooks
+    ///
+    /// User and project hooks of every type are skipped for this invocation,
+    /// as `--no-verify` does for a single command. Useful when a hook is
+    /// broken, or when scripting bulk operations that shouldn't trigger
+    /// expensive setup.
+    #[arg(
+        long,
+        global = true,
+        display_order = 109,
         help_heading = "Global Options"
     )]
-    pub verbose: bool,
+    pub no_hooks: bool,
+
+    /// Answer yes to every prompt
+    ///
+    /// Approves project commands (without remembering them, like a command's
+    /// own `--yes`) and confirms shell setup changes, so nothing waits for
+    /// input; commands that need input, like `wt select`, fail instead. Goes
+    /// before the command (`wt --yes merge`). Also settable with
+    /// `WORKTRUNK_ASSUME_YES=1`.
+    #[arg(
+        long = "yes",
+        short = 'y',
+        id = "assume_yes",
+        display_order = 110,
+        help_heading = "Global Options"
+    )]
+    pub assume_yes: bool,
 
     #[command(subcommand)]
     pub command: Option<Commands>,
@@ -199,9 +361,39 @@ Zsh (~/.zshrc):
 eval "$(wt config shell init zsh)"
 ```
 
+Nushell (saved to the autoload directory; rerun after upgrading):
+```console
+wt config shell init nushell | save -f ($nu.default-config-dir | path join autoload wt.nu)
+```
+
+PowerShell ($PROFILE):
+```console
+if (Get-Command wt -ErrorAction SilentlyContinue) { Invoke-Expression (& wt config shell init powershell) }
+```
+
 ## Auto setup
 
-Use `wt config shell install` to add to the shell config automatically."#)]
+Use `wt config shell install` to add to the shell config automatically.
+
+When the wrapper changes directory, `wt` also reports the new directory to the terminal with an OSC 7 sequence, so terminals and multiplexers that open new tabs and panes in the current directory follow the switch.
+
+## Running shell code after switching
+
+Hooks run as separate processes, so they can't change the interactive shell — activate a virtualenv, select a Node version, export variables. `--on-switch` adds a snippet to the wrapper that runs in the current shell whenever a `wt` command succeeds and changes directory (`wt switch`, or `wt remove`/`wt merge` returning to the primary worktree):
+
+```bash
+eval "$(wt config shell init bash --on-switch '[ -f .venv/bin/activate ] && source .venv/bin/activate')"
+```
+
+The snippet is written in the shell's own language and runs from the new directory. `wt config shell install` writes the line without it; add `--on-switch` by hand afterwards.
+
+## Fish abbreviations
+
+`--with-abbr` also defines abbreviations — `wts` for `wt switch`, `wtl` for `wt list` — and a `wtcd` function that changes to an existing worktree without running hooks:
+
+```fish
+wt config shell init fish --with-abbr | source
+```"#)]
     Init {
         /// Shell to generate code for
         #[arg(value_enum)]
@@ -214,6 +406,46 @@ Use `wt config shell install` to add to the shell config automatically."#)]
         /// instead of `wt`, useful on Windows where `wt` conflicts with Windows Terminal.
         #[arg(long)]
         cmd: Option<String>,
+
+        /// Shell code to run in the current shell after changing directory
+        ///
+        /// For setup that has to happen in the interactive shell itself, which
+        /// hooks (separate processes) can't do, e.g. `source .venv/bin/activate`.
+        #[arg(long, value_name = "SNIPPET")]
+        on_switch: Option<String>,
+
+        /// Also define abbreviations and helpers (fish only)
+        ///
+        /// `wts` expands to `wt switch`, `wtl` to `wt list`, and `wtcd`
+        /// changes to an existing worktree without running hooks.
+        #[arg(long)]
+        with_abbr: bool,
+    },
+
+    /// Generate completions for shells without shell integration
+    #[command(
+        after_long_help = r#"Elvish and xonsh don't get the directory-changing shell integration, but can still complete commands, flags, branches, and hook names. The completions call back into `wt` as you type, so they stay current as branches come and go.
+
+Elvish (~/.config/elvish/rc.elv):
+```console
+eval (wt config shell completions elvish | slurp)
+```
+
+Xonsh (~/.xonshrc):
+```console
+execx($(wt config shell completions xonsh))
+```
+
+Set `WORKTRUNK_BIN` to point the completions at a different binary."#
+    )]
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: crate::completion::CompletionShell,
+
+        /// Command name to complete (defaults to binary name)
+        #[arg(long)]
+        cmd: Option<String>,
     },
 
     /// Write shell integration to config files
@@ -323,6 +555,55 @@ all approvals across all projects."#
     },
 }
 
+#[derive(Subcommand)]
+pub enum RepoCommand {
+    /// Register a repository
+    #[command(
+        after_long_help = r#"Records the repository's location under its project identifier (e.g., `github.com/owner/repo`), derived from the primary remote. Registering again updates the location."#
+    )]
+    Add {
+        /// Repository path [default: current]
+        path: Option<std::path::PathBuf>,
+    },
+
+    /// List registered repositories
+    List,
+
+    /// Unregister a repository
+    #[command(
+        after_long_help = r#"Only removes the registry entry; the repository itself and its approved commands are untouched."#
+    )]
+    Remove {
+        /// Repository identifier or its trailing part
+        #[arg(add = crate::completion::repo_name_completer())]
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TrustCommand {
+    /// List approved commands
+    #[command(
+        after_long_help = r#"Shows approved commands for the current project, each with its approval ID. Outside a repository, or with `--all`, shows approvals for every project."#
+    )]
+    List {
+        /// Show every project
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Revoke an approved command
+    #[command(
+        after_long_help = r#"Accepts an approval ID from `wt trust list` (or a unique prefix of one), or the exact command. The command will prompt for approval again on its next run.
+
+Looks in the current project first, then in every project."#
+    )]
+    Revoke {
+        /// Approval ID or command
+        approval: String,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum ConfigCommand {
     /// Shell integration setup
@@ -375,6 +656,72 @@ This tests:
         full: bool,
     },
 
+    /// Check configuration files for problems
+    #[command(
+        after_long_help = r#"Checks the user config, per-repository config (`.worktrunk.toml`, `.git/worktrunk.toml`), and project config (`.config/wt.toml`) without running anything.
+
+Reports, with the file, key, and line:
+- **Syntax errors** — Invalid TOML
+- **Wrong types** — e.g. `squash = "yes"` where a boolean is expected
+- **Unknown keys** — Ignored when loading; suggests the closest known key
+- **Invalid templates** — Syntax errors or unknown filters in `worktree-path`, hooks, and `list.url`
+
+Exits with status 1 if any problems are found.
+
+```console
+$ wt config validate
+▲ ~/.config/worktrunk/config.toml:4: Unknown key merge.sqaush will be ignored; did you mean squash?
+✓ .config/wt.toml is valid
+```"#
+    )]
+    Validate,
+
+    /// Update the user config to the current schema version
+    #[command(
+        after_long_help = r#"The user config records the schema version it was written for in a top-level `version` key; files without one are treated as version 0. When a release renames config keys, older files are migrated the next time worktrunk runs, so renamed settings are never silently ignored. The original is kept alongside as `config.toml.v<version>.bak`.
+
+`wt config migrate` runs the migration explicitly, and also records the current version in a file that needs no renames.
+
+```console
+$ wt config migrate --dry-run
+○ Would migrate ~/.config/worktrunk/config.toml from version 0 to 1
+   post-create-command → post-create
+```
+
+Renames apply at the top level and inside `[profiles.<name>]` and `[if."<pattern>"]` sections. When both the old and new key are set, the old one is left in place for you to resolve.
+
+| Version | Changes |
+|---------|---------|
+| 1 | Hooks drop their `-command` suffix (`post-create-command` → `post-create`, and so on) |"#
+    )]
+    Migrate {
+        /// Show what would change without writing
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Change a setting in the user config
+    #[command(
+        after_long_help = r#"Writes one setting to the user config file, creating the file or section when needed and keeping existing comments and formatting. The value is converted to the type the key takes, and the result is validated before anything is written.
+
+```console
+$ wt config set list.branches true
+$ wt config set sync.strategy merge
+$ wt config set alias.ls "list --branches"
+```
+
+Shell completion offers the known keys, and the values of keys with a fixed set of them. Lists, and settings inside `[profiles.<name>]` or `[if."<pattern>"]`, are edited in the file."#
+    )]
+    Set {
+        /// Dotted key path, like `merge.squash`
+        #[arg(add = crate::completion::config_key_completer())]
+        key: String,
+
+        /// New value
+        #[arg(add = crate::completion::config_value_completer())]
+        value: String,
+    },
+
     /// Get, set, or clear stored state
     #[command(
         after_long_help = r#"State is stored in `.git/` (config entries and log files), separate from configuration files.
@@ -564,6 +911,8 @@ Stored in git config as `worktrunk.state.<branch>.marker`. Set directly with:
 git config worktrunk.state.feature.marker '{"marker":"🚧","set_at":0}'
 ```
 
+With `extensions.worktreeConfig` enabled, a marker for a branch that's checked out is stored in that worktree's own config (`git config --worktree`), so it's removed with the worktree and doesn't show up in other worktrees' shared config. The base recorded by `wt switch --create --base` for a tag or commit is stored the same way. If that worktree later checks out another branch, the old branch's values there are ignored; `wt config state marker clear --all` removes them.
+
 Without a subcommand, runs `get` for the current branch. For `--branch`, use `get --branch=NAME`."#
     )]
     Marker {
@@ -922,6 +1271,21 @@ pub enum StepCommand {
         /// Defaults to default branch.
         #[arg(add = crate::completion::branch_value_completer())]
         target: Option<String>,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Skip hooks
+        #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true)]
+        verify: bool,
+
+        /// Output format (table, json)
+        ///
+        /// With `json`, prints an object describing what changed (worktrees,
+        /// refs, hooks) to stdout.
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
     },
 
     /// Rebase onto target
@@ -954,6 +1318,10 @@ All variables are shell-escaped:
 | `{{ commit }}` | Current HEAD commit SHA (full) |
 | `{{ short_commit }}` | Current HEAD commit SHA (7 chars) |
 | `{{ default_branch }}` | Default branch name (e.g., "main") |
+| `{{ branch_slug }}` | Lowercase branch name with other characters replaced by `-` |
+| `{{ ticket }}` | Ticket ID from the branch name, if any |
+| `{{ date }}` | Today's date (`YYYY-MM-DD`) |
+| `{{ user }}` | Git `user.name` |
 | `{{ remote }}` | Primary remote name (e.g., "origin") |
 | `{{ remote_url }}` | Primary remote URL |
 | `{{ upstream }}` | Upstream tracking branch, if configured |
@@ -1002,7 +1370,7 @@ pub enum HookCommand {
     /// Lists user and project hooks. Project hooks show approval status (❓ = needs approval).
     Show {
         /// Hook type to show (default: all)
-        #[arg(value_parser = ["post-create", "post-start", "post-switch", "pre-commit", "pre-merge", "post-merge", "pre-remove"])]
+        #[arg(value_parser = ["post-create", "post-start", "pre-switch", "post-switch", "pre-commit", "pre-merge", "post-merge", "pre-push", "pre-remove", "post-remove"])]
         hook_type: Option<String>,
 
         /// Show expanded commands with current variables
@@ -1010,6 +1378,17 @@ pub enum HookCommand {
         expanded: bool,
     },
 
+    /// Show background hook commands
+    ///
+    /// Lists commands started in the background (post-start, post-switch,
+    /// post-remove, and entries with `background = true`) and whether they're
+    /// still running. Output is in `.git/wt-logs/`.
+    Status {
+        /// Output format (table, json)
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
+    },
+
     /// Run post-create hooks
     ///
     /// Blocking — waits for completion before continuing.
@@ -1042,6 +1421,20 @@ pub enum HookCommand {
         yes: bool,
     },
 
+    /// Run pre-switch hooks
+    PreSwitch {
+        /// Filter by command name
+        ///
+        /// Supports `user:name` or `project:name` to filter by source.
+        /// `user:` alone runs all user hooks; `project:` alone runs all project hooks.
+        #[arg(add = crate::completion::hook_command_name_completer())]
+        name: Option<String>,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        yes: bool,
+    },
+
     /// Run post-switch hooks
     ///
     /// Background — runs without blocking.
@@ -1100,6 +1493,20 @@ pub enum HookCommand {
         yes: bool,
     },
 
+    /// Run pre-push hooks
+    PrePush {
+        /// Filter by command name
+        ///
+        /// Supports `user:name` or `project:name` to filter by source.
+        /// `user:` alone runs all user hooks; `project:` alone runs all project hooks.
+        #[arg(add = crate::completion::hook_command_name_completer())]
+        name: Option<String>,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        yes: bool,
+    },
+
     /// Run pre-remove hooks
     PreRemove {
         /// Filter by command name
@@ -1114,6 +1521,22 @@ pub enum HookCommand {
         yes: bool,
     },
 
+    /// Run post-remove hooks
+    ///
+    /// Background — runs without blocking.
+    PostRemove {
+        /// Filter by command name
+        ///
+        /// Supports `user:name` or `project:name` to filter by source.
+        /// `user:` alone runs all user hooks; `project:` alone runs all project hooks.
+        #[arg(add = crate::completion::hook_command_name_completer())]
+        name: Option<String>,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        yes: bool,
+    },
+
     /// Manage command approvals
     #[command(after_long_help = r#"## How Approvals Work
 
@@ -1212,6 +1635,12 @@ Show current configuration and file locations:
 wt config show
 ```
 
+Change one user setting, keeping the file's comments (shell completion offers keys and values):
+
+```console
+wt config set list.full true
+```
+
 ## User config
 
 The user config stores personal preferences that apply across all repositories. Create it with `wt config create` and view with `wt config show`.
@@ -1224,6 +1653,17 @@ Controls where new worktrees are created. The template is relative to the reposi
 - `{{ main_worktree }}` — main worktree directory name
 - `{{ branch }}` — raw branch name (e.g., `feature/foo`)
 - `{{ branch | sanitize }}` — branch name with `/` and `\` replaced by `-`
+- `{{ branch_slug }}` — lowercase branch name with other characters collapsed to `-` (e.g., `feature-foo`)
+- `{{ ticket }}` — ticket ID found in the branch name (e.g., `PROJ-123`), if any
+- `{{ date }}` — today's date (`YYYY-MM-DD`)
+- `{{ user }}` — git `user.name`
+- `{{ default_branch }}` — default branch name (e.g., `main`)
+
+Tickets are found with the `ticket-pattern` regex, which defaults to Jira-style keys. With a capture group, only the group is used:
+
+```toml
+ticket-pattern = "^gh-([0-9]+)"   # gh-42-fix-login → 42
+```
 
 **Examples** for a repo at `~/code/myproject` creating branch `feature/login`:
 
@@ -1245,6 +1685,8 @@ worktree-path = "../worktrees/{{ main_worktree }}/{{ branch | sanitize }}"
 worktree-path = "../{{ branch | sanitize }}"
 ```
 
+Branches whose names sanitize alike (`feature/login` and `feature-login`) map to the same path. `wt switch` checks for this before creating anything: when the other branch's worktree is already there, it stops and suggests a free name.
+
 ### Command settings
 
 Set persistent flag values for commands. These apply unless explicitly overridden on the command line.
@@ -1257,8 +1699,23 @@ Set persistent flag values for commands. These apply unless explicitly overridde
 full = true      # --full
 branches = true  # --branches
 remotes = true   # --remotes
+# Columns to show, in order (--columns); default is every column
+columns = ["branch", "status", "head", "main", "age", "message"]
+```
+
+**CI status** (`wt list --ci`, `wt pr`, and the statusline):
+
+```toml
+[ci]
+# Detected from the origin remote's URL by default
+provider = "gitlab"                         # "github", "gitlab", "gerrit", or "none"
+api-url = "https://gitlab.example.com"      # Self-hosted instance (required for Gerrit)
+token-env = "WORK_GITLAB_TOKEN"             # Read the API token from this variable
+enabled = true                              # Fetch CI status without --ci
 ```
 
+GitHub and GitLab go through `gh` and `glab`, which receive the host and token as `GH_HOST`/`GH_TOKEN` or `GITLAB_HOST`/`GITLAB_TOKEN`; without `token-env` they use their own login. Gerrit reports the change's `Verified` label, queried with `curl`; its token is `user:http-password`. `provider = "none"` skips CI lookups entirely, even with `--full`. Since a token is sent to `api-url`, `[ci]` is only read from the user config and `.git/worktrunk.toml`, never from a tracked `.worktrunk.toml`.
+
 **`wt step commit` and `wt merge` staging:**
 
 ```toml
@@ -1276,26 +1733,118 @@ commit = false  # Skip committing uncommitted changes (also disables squash)
 rebase = false  # Skip rebase (fails if not already rebased)
 remove = false  # Keep worktree after merge (--no-remove)
 verify = false  # Skip hooks (--no-verify)
+require-ci = true  # Refuse to merge until CI passes (--require-ci; --override-ci skips)
+sign = true        # GPG-sign the commits the merge creates (--gpg-sign; --no-gpg-sign skips)
+autostash = true   # Stash changes the merge won't commit, restore them after (--autostash)
 ```
 
-### LLM commit messages
-
-Configure automatic commit message generation. Requires an external tool like [llm](https://llm.datasette.io/):
+**`wt switch`:**
 
 ```toml
-[commit-generation]
-command = "llm"
-args = ["-m", "claude-haiku-4.5"]
+[switch]
+init-submodules = true  # Check out submodules in new worktrees
+lfs-pull = true         # Pull Git LFS content in new worktrees (needs git-lfs)
+tmux = true             # Create a tmux session (or window) for new worktrees
+issue-branch = "{{ ticket }}-{{ slug }}"  # Branch name for --from-issue
 ```
 
-See [LLM Commit Messages](@/llm-commits.md) for setup details and template customization.
+**Desktop notifications** (`notify-send`, or `osascript` on macOS):
 
-### Approved commands
+```toml
+[notify]
+enabled = true        # Notify when switch, remove, merge, list, step, hook, or clone finish
+min-duration = "30s"  # ...but only after running this long (default: "10s")
+```
 
-When project hooks run for the first time, Worktrunk prompts for approval. Approved commands are saved here automatically:
+Background hooks (such as `post-start`) notify when they finish, too.
+
+**Worktree budget**, for workflows (such as agents) that create worktrees faster than anyone removes them:
 
 ```toml
-[projects."my-project"]
+[limits]
+max-worktrees = 15        # Warn when `wt switch --create` makes more than this
+max-total-disk = "20GB"   # ...or when the worktrees use more disk than this
+```
+
+Over budget, `wt switch --create` warns (with `--strict`, it refuses) and `wt list` names worktrees to clean up: merged ones first, then the least recently committed.
+
+**`wt step push` and `wt merge`:**
+
+```toml
+[push]
+require-signed = true                # Refuse to push commits without a good signature
+allowed-keys = ["SHA256:abc…", "…"]  # Only accept these keys (full fingerprints or long IDs)
+```
+
+**`wt sync`:**
+
+```toml
+[sync]
+strategy = "rebase"    # Rebase onto the target, or "merge" it in (default: rebase)
+
+[sync.branches]
+"release/*" = "merge"  # Per-branch overrides; the most specific pattern wins
+```
+
+**`wt select`:**
+
+```toml
+[select]
+height = "full"               # Share of the terminal, like "60%", or "full" (default: "90%")
+preview-position = "down"     # "right", "down", or "auto" (default: by terminal shape)
+preview-size = "40%"          # Preview's share of the picker (default: automatic)
+preview-hidden = true         # Start with the preview hidden; alt-p shows it
+```
+
+**Message symbols** (all commands):
+
+```toml
+[styling]
+emoji = false   # Tags like [ok] and [error] instead of ✓ and ✗ (--no-emoji)
+```
+
+### Aliases
+
+Define shortcuts for commands and their flags. Aliases expand before arguments are parsed, so further flags can follow them:
+
+```toml
+[alias]
+ls = "list --branches --remotes"
+up = "merge --no-remove"
+```
+
+With these, `wt ls --full` runs `wt list --branches --remotes --full`. Built-in commands take precedence over aliases of the same name, and an alias can't refer to another alias.
+
+### Default arguments
+
+Change a command's defaults by listing arguments to insert after its name. Nested commands are keyed by their full name; a string is split like a shell would:
+
+```toml
+[defaults]
+list = ["--branches", "--format", "json"]
+"step commit" = "--stage tracked"
+```
+
+With these, `wt list` runs `wt list --branches --format json`. Arguments given on the command line come after the defaults and win, so `wt list --format table` shows a table. Flags without a negated form (like `--branches`) can't be turned off again on the command line; prefer the settings above where a command has one.
+
+### LLM commit messages
+
+Configure automatic commit message generation. Requires an external tool like [llm](https://llm.datasette.io/):
+
+```toml
+[commit-generation]
+command = "llm"
+args = ["-m", "claude-haiku-4.5"]
+```
+
+See [LLM Commit Messages](@/llm-commits.md) for setup details and template customization.
+
+### Approved commands
+
+When project hooks run for the first time, Worktrunk prompts for approval. Approved commands are saved here automatically:
+
+```toml
+[projects."my-project"]
 approved-commands = [
     "post-create.install = npm ci",
     "pre-merge.test = npm test",
@@ -1320,6 +1869,65 @@ User hooks run before project hooks and don't require approval. Skip with `--no-
 
 See [wt hook](@/hook.md#user-hooks) for complete documentation.
 
+### Comparison remote
+
+In a fork with `origin` and `upstream`, the default branch and the ahead/behind counts come from whichever remote git lists first. Name the remote to compare against:
+
+```toml
+# .git/worktrunk.toml
+remote = "upstream"
+```
+
+The default branch is then read from `upstream`, and `wt list`, `wt merge`, and `wt remove` compare against `upstream/main` once it's fetched. `--remote` does the same for one command.
+
+### Per-repository settings
+
+User config settings can be overridden for a single repository. Two files are read, each taking precedence over the last:
+
+1. `.worktrunk.toml` at the worktree root — tracked in git and shared with the team
+2. `.git/worktrunk.toml` — local to this clone
+
+```toml
+# .worktrunk.toml
+worktree-path = ".worktrees/{{ branch | sanitize }}"
+
+[merge]
+squash = false
+```
+
+Neither file can hold approvals. Because `.worktrunk.toml` is shared, it can only set settings that don't run commands, reach outside the repository, or relax checks — not hooks, `[commit-generation]`, `[alias]`, `[defaults]`, `[ci]`, `[push]`, or `log-file`. Of `[merge]` it can set `squash`, `commit`, `rebase`, `remove`, and `autostash`, and of `[switch]` only `issue-branch`. Shared hooks go in the project config, where they require approval; the rest belong in `.git/worktrunk.toml`. Environment variables still override both files.
+
+### Conditional settings
+
+Settings can apply only to repositories whose remote matches a pattern, like git's `includeIf`. The pattern is matched against the primary remote's `host/owner/repo`; `*` matches within one segment and `**` matches any number of segments:
+
+```toml
+[if."github.com/acme/*"]
+worktree-path = ".worktrees/{{ branch | sanitize }}"
+
+[if."github.com/acme/*".post-create]
+vpn = "acme-vpn check"
+```
+
+Matching sections override the config files; when several match, the more specific pattern wins. Hooks in a matching section replace that hook's commands entirely, as with profiles.
+
+### Profiles
+
+Profiles are named sets of settings for switching between contexts, such as work and open-source checkouts. Define them under `[profiles.<name>]` and select one with `--profile <name>` or `WORKTRUNK_PROFILE`:
+
+```toml
+[profiles.oss]
+worktree-path = ".worktrees/{{ branch | sanitize }}"
+
+[profiles.oss.merge]
+squash = false
+
+[profiles.oss.post-create]
+setup = "just setup"
+```
+
+A profile's settings override the config files (including per-repository ones) and conditional sections; environment variables still override the profile. Sections like `[merge]` are merged key by key, while a profile's hooks replace that hook's commands entirely. Profiles can't hold approvals.
+
 ## Project config
 
 The project config defines lifecycle hooks and project-specific settings. This file is checked into version control and shared across the team.
@@ -1369,25 +1977,35 @@ eval "$(wt config shell init zsh)"
 wt config shell init fish | source
 ```
 
+Nushell can't evaluate generated code at startup, so save the wrapper to its autoload directory instead (and again after upgrading):
+
+```console
+wt config shell init nushell | save -f ($nu.default-config-dir | path join autoload wt.nu)
+```
+
 Without shell integration, `wt switch` prints the target directory but cannot `cd` into it.
 
 ## Environment variables
 
-All user config options can be overridden with environment variables using the `WORKTRUNK_` prefix.
+All user config options can be overridden with environment variables using the `WORKTRUNK_` prefix. Environment variables take precedence over the user config and any per-repository config files, so CI jobs and one-off runs can change behavior without editing files.
 
 ### Naming convention
 
 Config keys use kebab-case (`worktree-path`), while env vars use SCREAMING_SNAKE_CASE (`WORKTRUNK_WORKTREE_PATH`). The conversion happens automatically.
 
-For nested config sections, use double underscores to separate levels:
+Nested keys can be written with single underscores throughout, or with double underscores separating levels:
 
 | Config | Environment Variable |
 |--------|---------------------|
 | `worktree-path` | `WORKTRUNK_WORKTREE_PATH` |
+| `merge.squash` | `WORKTRUNK_MERGE_SQUASH` or `WORKTRUNK_MERGE__SQUASH` |
+| `list.branches` | `WORKTRUNK_LIST_BRANCHES` or `WORKTRUNK_LIST__BRANCHES` |
 | `commit-generation.command` | `WORKTRUNK_COMMIT_GENERATION__COMMAND` |
 | `commit-generation.args` | `WORKTRUNK_COMMIT_GENERATION__ARGS` |
 
-Note the single underscore after `WORKTRUNK` and double underscores between nested keys.
+Keys under named tables (aliases, hook commands) need the double-underscore form, e.g. `WORKTRUNK_ALIAS__LS="list --branches"`. Approved commands can't be set from the environment.
+
+Boolean settings accept `true`/`false`, `1`/`0`, or `yes`/`no`; anything else is an error. List settings take comma-separated values (`WORKTRUNK_LIST_COLUMNS=branch,age`). `wt config show` lists the overrides that are active.
 
 ### Array values
 
@@ -1412,8 +2030,9 @@ WORKTRUNK_COMMIT_GENERATION__ARGS="test: automated commit" \
 | Variable | Purpose |
 |----------|---------|
 | `WORKTRUNK_BIN` | Override binary path for shell wrappers (useful for testing dev builds) |
-| `WORKTRUNK_CONFIG_PATH` | Override user config file location |
+| `WORKTRUNK_CONFIG_PATH` | Override user config file location (created on demand) |
 | `WORKTRUNK_DIRECTIVE_FILE` | Internal: set by shell wrappers to enable directory changes |
+| `WORKTRUNK_DIRECTIVE_PROTOCOL` | Internal: set by shell wrappers to the directive format they read |
 | `WORKTRUNK_SHELL` | Internal: set by shell wrappers to indicate shell type (e.g., `powershell`) |
 | `WORKTRUNK_MAX_CONCURRENT_COMMANDS` | Max parallel git commands (default: 32). Lower if hitting resource limits. |
 | `NO_COLOR` | Disable colored output ([standard](https://no-color.org/)) |
@@ -1480,7 +2099,7 @@ wt step push
         name = "hook",
         after_long_help = r#"Shell commands that run at key points in the worktree lifecycle.
 
-Hooks run automatically during `wt switch`, `wt merge`, & `wt remove`. `wt hook <type>` runs them on demand. Both user hooks (from `~/.config/worktrunk/config.toml`) and project hooks (from `.config/wt.toml`) are supported.
+Hooks run automatically during `wt switch`, `wt merge`, `wt step push`, & `wt remove`. `wt hook <type>` runs them on demand. Both user hooks (from `~/.config/worktrunk/config.toml`) and project hooks (from `.config/wt.toml`) are supported.
 
 ## Hook types
 
@@ -1488,11 +2107,14 @@ Hooks run automatically during `wt switch`, `wt merge`, & `wt remove`. `wt hook
 |------|------|----------|-----------|
 | `post-create` | After worktree created | Yes | No |
 | `post-start` | After worktree created | No (background) | No |
+| `pre-switch` | Before every switch | Yes | Yes |
 | `post-switch` | After every switch | No (background) | No |
 | `pre-commit` | Before commit during merge | Yes | Yes |
 | `pre-merge` | Before merging to target | Yes | Yes |
 | `post-merge` | After successful merge | Yes | No |
+| `pre-push` | Before pushing to target | Yes | Yes |
 | `pre-remove` | Before worktree removed | Yes | Yes |
+| `post-remove` | After worktree removed | No (background) | No |
 
 **Blocking**: Command waits for hook to complete before continuing.
 **Fail-fast**: First failure aborts the operation.
@@ -1524,6 +2146,16 @@ server = "npm run dev"
 
 Output logged to `.git/wt-logs/{branch}-{source}-post-start-{name}.log` (source is `user` or `project`).
 
+### pre-switch
+
+Runs before every `wt switch`, in the **worktree being left**, **fail-fast**. `{{ branch }}` is the current branch and `{{ target }}` the branch being switched to; a failure aborts the switch.
+
+**Use cases**: Stopping dev servers, saving editor state, pausing containers.
+
+```toml
+pre-switch = "docker compose -p {{ branch | sanitize }} stop"
+```
+
 ### post-switch
 
 Runs after **every** switch operation, **in background**. Triggers on all switch results: creating new worktrees, switching to existing ones, or switching to the current worktree.
@@ -1570,6 +2202,16 @@ Runs after successful merge in the **worktree for the target branch** if it exis
 post-merge = "cargo install --path ."
 ```
 
+### pre-push
+
+Runs before `wt step push` or `wt merge` fast-forwards the target branch, **fail-fast**. All commands must exit 0 for the push to proceed.
+
+**Use cases**: Final checks that gate the target branch, changelog validation.
+
+```toml
+pre-push = "cargo test --release"
+```
+
 ### pre-remove
 
 Runs before worktree removal during `wt remove`, **fail-fast**. All commands must exit 0 for removal to proceed.
@@ -1581,11 +2223,25 @@ Runs before worktree removal during `wt remove`, **fail-fast**. All commands mus
 cleanup = "rm -rf /tmp/cache/{{ branch }}"
 ```
 
+### post-remove
+
+Runs after a worktree is removed, **in background**, from the worktree you land in (usually the main worktree). `{{ branch }}` is the removed worktree's branch.
+
+**Use cases**: Tearing down containers, databases, or other per-branch resources.
+
+```toml
+post-remove = "docker compose -p {{ branch | sanitize }} down --volumes"
+```
+
+Output logged to `.git/wt-logs/{branch}-{source}-post-remove-{name}.log` (source is `user` or `project`).
+
 ### Timing during merge
 
 - **pre-commit** — After staging, before squash commit
 - **pre-merge** — After rebase, before merge to target
+- **pre-push** — After pre-merge, before fast-forwarding the target
 - **pre-remove** — Before removing worktree during cleanup
+- **post-remove** — After removing worktree during cleanup
 - **post-merge** — After cleanup completes
 
 See [wt merge](@/merge.md#pipeline) for the complete pipeline.
@@ -1604,6 +2260,67 @@ test = "cargo test"
 build = "cargo build --release"
 ```
 
+### Timeouts and failure handling
+
+A named command can be a table, adding a `timeout` and an `on-failure` policy:
+
+```toml
+[post-create.install]
+command = "npm ci"
+timeout = "5m"
+on-failure = "abort"
+```
+
+A command still running when its `timeout` (`ms`, `s`, `m`, or `h`) expires is stopped and fails with exit code 124, so a hung install can't wedge `wt switch`.
+
+`on-failure` decides what a failed command does:
+
+| Value | Effect | Default for |
+|-------|--------|-------------|
+| `abort` | Stop the operation | pre-switch, pre-commit, pre-merge, pre-push, pre-remove |
+| `warn` | Print a warning and continue | post-create, post-merge |
+| `ignore` | Continue silently | — |
+
+Both options apply to blocking hooks; background hooks (post-start, post-switch, post-remove) never hold up a command and log their failures instead.
+
+### Background commands
+
+`background = true` moves a slow command out of a blocking hook, so `wt switch --create` returns while the dependency install or container build carries on:
+
+```toml
+[post-create.containers]
+command = "docker compose build"
+background = true
+```
+
+Background entries start once the hook's other commands have succeeded, with output in `.git/wt-logs/` like post-start hooks. `wt hook status` lists background commands and whether they're still running, and `wt list` marks worktrees with commands still running with `◷`.
+
+### Branch scoping
+
+`branches` limits a command to branches matching any of its globs, and `exclude` skips branches matching any of its own:
+
+```toml
+[post-create.frontend]
+command = "npm ci --prefix web"
+branches = ["ui/*"]
+exclude = ["ui/legacy-*"]
+```
+
+Commands scoped with `branches` don't run on a detached HEAD. Naming a command, as in `wt hook post-create frontend`, runs it whatever its scope.
+
+### Shared build directories
+
+`symlink` and `hardlink` in `[post-create]` take a list of paths to share from the primary worktree instead of rebuilding them:
+
+```toml
+[post-create]
+symlink = ["node_modules"]
+hardlink = ["target"]
+install = "npm ci"
+```
+
+`symlink` points each path at the primary worktree's copy; on Windows, directories fall back to junctions when symlinks aren't permitted. `hardlink` recreates the directory with hard-linked files (copying across filesystems), so later builds in either worktree don't overwrite each other's new files. Links are made before any post-create commands run. Paths missing from the primary worktree are skipped, and paths that already exist in the new worktree are left alone. For bare repositories, the primary worktree is the default branch's.
+
 ### Template variables
 
 Hooks can use template variables that expand at runtime:
@@ -1615,13 +2332,19 @@ Hooks can use template variables that expand at runtime:
 | `{{ worktree }}` | /path/to/worktree | Absolute worktree path |
 | `{{ worktree_name }}` | my-project.feature-foo | Worktree directory name |
 | `{{ repo_root }}` | /path/to/main | Repository root path |
+| `{{ branch_slug }}` | feature-foo | Lowercase branch name with other characters replaced by `-` |
+| `{{ ticket }}` | PROJ-123 | Ticket ID from the branch name, if any (see `ticket-pattern`) |
+| `{{ date }}` | 2025-01-02 | Today's date |
+| `{{ user }}` | Jane Doe | Git `user.name` |
 | `{{ default_branch }}` | main | Default branch name |
 | `{{ commit }}` | a1b2c3d4e5f6... | Full HEAD commit SHA |
 | `{{ short_commit }}` | a1b2c3d | Short HEAD commit SHA |
 | `{{ remote }}` | origin | Primary remote name |
 | `{{ remote_url }}` | git@github.com:user/repo.git | Remote URL |
 | `{{ upstream }}` | origin/feature | Upstream tracking branch |
-| `{{ target }}` | main | Target branch (merge hooks only) |
+| `{{ target }}` | main | Target branch (merge and push hooks) or destination branch (pre-switch) |
+| `{{ base }}` | main | Branch the worktree was created from (post-create and post-start, with `--create`) |
+| `{{ issue_url }}` | https://github.com/owner/repo/issues/1234 | Issue the branch was created for with `wt switch --from-issue` |
 
 ### Filters
 
@@ -1658,6 +2381,44 @@ print(f"Setting up {ctx['repo']} on branch {ctx['branch']}")
 
 The JSON includes all template variables plus `hook_type` and `hook_name`.
 
+### Environment variables
+
+Hook commands also run with these set, so scripts work without templating:
+
+| Variable | Value |
+|----------|-------|
+| `WT_BRANCH` | `{{ branch }}` |
+| `WT_WORKTREE` | `{{ worktree }}` |
+| `WT_REPO_ROOT` | `{{ repo_root }}` |
+| `WT_BASE_BRANCH` | `{{ base }}`, else the merge or push `{{ target }}`, else `{{ default_branch }}` |
+| `WT_HOOK_TYPE` | Hook type, e.g. `post-create` |
+
+```bash
+#!/bin/sh
+# scripts/setup.sh, run with: post-create = "scripts/setup.sh"
+git diff --stat "$WT_BASE_BRANCH"...HEAD
+echo "Set up $WT_BRANCH in $WT_WORKTREE"
+```
+
+### Parallel execution
+
+By default a hook's commands run one after another. To run them at the same time, set `parallel` in the user config:
+
+```toml
+# ~/.config/worktrunk/config.toml
+[hooks]
+parallel = true
+```
+
+This applies to blocking hooks (everything except post-start, post-switch, and post-remove, which already run in the background). Each line of output is prefixed with the command it came from:
+
+```
+user:lint    │ All checks passed
+project:test │ running 42 tests
+```
+
+Every command runs to completion, then failures are summarized together. A fail-fast hook still stops the operation if any of its commands failed.
+
 ## Designing effective hooks
 
 ### post-create vs post-start
@@ -1786,7 +2547,7 @@ Project commands require approval on first run:
 - Approvals are saved to user config (`~/.config/worktrunk/config.toml`)
 - If a command changes, new approval is required
 - Use `--yes` to bypass prompts (useful for CI/automation)
-- Use `--no-verify` to skip hooks
+- Use `--no-verify` (or `--no-hooks` on any command) to skip hooks
 
 Manage approvals with `wt hook approvals add` and `wt hook approvals clear`.
 
@@ -1814,7 +2575,7 @@ User hooks support the same hook types and template variables as project hooks.
 | Approval | Required | Not required |
 | Execution order | After user hooks | Before project hooks |
 
-Skip hooks with `--no-verify`. To run a specific hook when user and project both define the same name, use `user:name` or `project:name` syntax.
+Skip hooks with `--no-verify`, or with the global `--no-hooks`, which every command accepts. To run a specific hook when user and project both define the same name, use `user:name` or `project:name` syntax.
 
 **Use cases:**
 - Personal notifications or logging
@@ -1949,7 +2710,7 @@ fi
 
     /// Interactive worktree selector
     ///
-    /// Toggle preview tabs with 1/2/3 keys. Toggle preview visibility with alt-p.
+    /// Toggle preview tabs with 1/2/3 keys. Toggle preview visibility with alt-p. Press ? for help.
     #[cfg_attr(not(unix), command(hide = true))]
     #[command(
         after_long_help = r#"Interactive worktree picker with live preview. Navigate worktrees with keyboard shortcuts and press Enter to switch.
@@ -1982,8 +2743,20 @@ Toggle between views with number keys:
 | `1`/`2`/`3` | Switch preview tab |
 | `Alt-p` | Toggle preview panel |
 | `Ctrl-u`/`Ctrl-d` | Scroll preview up/down |
+| `Ctrl-o` | Open the previewed diff in the pager at full size; quitting the pager returns to the picker |
+| `?`/`Ctrl-h` | Show all keybindings and preview modes in the preview |
 
-Branches without worktrees are included — selecting one creates a worktree. (`wt list` requires `--branches` to show them.)
+The preview sits beside the list on wide terminals and below it on tall ones. To fix its position or size, change the picker's height, or start with the preview hidden, set `[select]` in the [user config](@/config.md#command-settings).
+
+The selector reopens the way it was last left in each repository: on the same preview tab, and with the preview shown or hidden as `Alt-p` left it, which takes precedence over `preview-hidden`. `wt config state clear` forgets this.
+
+Branches without worktrees are included — selecting one creates a worktree. (`wt list` requires `--branches` to show them.) To only include branches matching a glob pattern:
+
+```console
+wt select --branches 'feature/*'
+```
+
+Typing also matches [branch descriptions](@/list.md#branch-descriptions), so a branch can be found by what it's for. The selector shows the same columns as `wt list`, including `description` when selected.
 
 ## See also
 
@@ -1991,14 +2764,28 @@ Branches without worktrees are included — selecting one creates a worktree. (`
 - [wt switch](@/switch.md) — Direct switching to a known target branch
 "#
     )]
-    Select,
+    Select {
+        /// Only include branches without worktrees that match this glob
+        ///
+        /// Repeat for more patterns, e.g. `--branches 'feature/*' --branches 'fix/*'`.
+        #[arg(long, value_name = "PATTERN")]
+        branches: Vec<String>,
+
+        /// Open this item's full diff in the pager (the picker's `ctrl-o`)
+        #[arg(long, hide = true, value_name = "TARGET", requires = "preview_state")]
+        open_diff: Option<String>,
+
+        /// The picker's preview state file, naming the tab `--open-diff` follows
+        #[arg(long, hide = true, value_name = "PATH")]
+        preview_state: Option<std::path::PathBuf>,
+    },
 
     /// List worktrees and optionally branches
     #[command(
         after_long_help = r#"Show all worktrees with their status. The table includes uncommitted changes, divergence from the default branch and remote, and optional CI status.
 <!-- demo: wt-list.gif 1600x900 -->
 
-The table renders progressively: branch names, paths, and commit hashes appear immediately, then status, divergence, and other columns fill in as background git operations complete. With `--full`, CI status fetches from the network — the table displays instantly and CI fills in as results arrive.
+The table renders progressively: branch names, paths, and commit hashes appear immediately, then status, divergence, and other columns fill in as background git operations complete. With `--full`, CI status fetches from the network — the table displays instantly and CI fills in as results arrive. Terminals that can't redraw rows in place — `TERM=dumb`, Emacs' `M-x shell` — get the finished table instead, as do pipes.
 
 ## Examples
 
@@ -2023,34 +2810,106 @@ Include branches that don't have worktrees:
 $ wt list --branches --full
 ```
 
+Only branches matching a glob pattern, in repos with many stale branches (worktrees are always listed):
+
+```console
+$ wt list --branches 'feature/*'
+```
+
 Output as JSON for scripting:
 
 ```console
 $ wt list --format=json
 ```
 
+Worktrees across every repository registered with `wt repo add`, with a leading Repo column:
+
+```console
+$ wt list --all-repos
+```
+
+With `--format=json`, each item gains a `repo` field holding the project identifier.
+
 ## Columns
 
 | Column | Shows |
 |--------|-------|
-| Branch | Branch name |
+| # | Row number, usable as `wt switch 3` or `wt remove 3` (only when selected with `--columns`) |
+| Branch | Branch name; `∅` with the short SHA and nearest tag for a detached HEAD |
 | Status | Compact symbols (see below) |
 | HEAD± | Uncommitted changes: +added -deleted lines |
 | main↕ | Commits ahead/behind default branch |
 | main…± | Line diffs since the merge-base with the default branch (`--full`) |
+| Files | Files changed since the merge-base with the default branch (only when selected with `--columns`) |
 | Path | Worktree directory |
 | Remote⇅ | Commits ahead/behind tracking branch |
 | URL | Dev server URL from project config (dimmed if port not listening) |
-| CI | Pipeline status (`--full`) |
+| CI | Pipeline status (`--ci` or `--full`) |
+| Sig | Tip commit signature (only when selected with `--columns`) |
+| Description | Branch description (only when selected with `--columns`) |
 | Commit | Short hash (8 chars) |
 | Age | Time since last commit |
 | Message | Last commit message (truncated) |
 
-Note: `main↕` and `main…±` refer to the default branch (header label stays `main` for compactness). `main…±` uses a merge-base (three-dot) diff.
+Note: `main↕` and `main…±` refer to the default branch (header label stays `main` for compactness). For a branch created with `wt switch --create --base <tag>`, `main↕` counts against that tag instead. `main…±` uses a merge-base (three-dot) diff.
+
+### Choosing columns
+
+`--columns` (or `list.columns` in the user config) picks which columns appear and in what order, by name: `index` (#), `branch`, `status`, `head` (HEAD±), `main` (main↕), `main-diff` (main…±), `files`, `path`, `remote` (Remote⇅), `url`, `ci`, `signature` (Sig), `description`, `commit`, `age`, `message`. All but `index`, `files`, `signature`, and `description` appear by default.
+
+A `wt list` table showing the `index` column remembers its rows, so `wt switch 3` and `wt remove 3` refer to the third row of the last listing; a branch literally named `3` takes precedence. A listing without the column forgets them, so a number only ever refers to a row that was shown.
+
+```console
+$ wt list --columns branch,age,main,message
+```
+
+Columns without data still follow the usual rules — `ci` needs `--ci` or `--full`, `main-diff` needs `--full`, `url` needs a project URL template — and narrow terminals drop the least essential columns first. JSON output always includes every field.
+
+### Styling by age
+
+To make recency visible without reading the Age column, the user config can dim rows whose last commit is old and bold the branch names of very recent ones:
+
+```toml
+[list]
+dim-after = "30d"         # units: h, d, w
+highlight-within = "12h"
+```
+
+### Loading placeholders
+
+While `wt list` fills in the table, cells still loading show a dim `⋯`. Where dim text looks like real values, pick another placeholder or style:
+
+```toml
+[list]
+placeholder = "…"            # a single character, or "" to leave cells blank
+placeholder-style = "reverse" # "dim" (default), "normal", "bold", "italic", or "reverse"
+```
+
+### Branch descriptions
+
+A branch description, set with `git branch --edit-description`, gives a human label to a cryptic branch name. Add `description` to the selected columns to show each branch's (first line) next to it:
+
+```console
+$ git config branch.fix-4821.description "Login redirect loop on Safari"
+$ wt list --columns branch,description,status,age
+```
+
+JSON output includes the full text as `description`.
+
+### Commit signatures
+
+For teams that require signed commits, the Sig column shows whether each branch's tip commit is signed (GPG or SSH) and whether the signature verifies. Verifying runs `gpg` or `ssh-keygen` once per branch, so the column only appears when selected, e.g. `list.columns = ["branch", "status", "signature", "age", "message"]`. JSON output includes a `signature` object with `--full`.
+
+| Indicator | Meaning |
+|-----------|---------|
+| `✓` green | Good signature from a trusted key |
+| `?` yellow | Signed, but the key is untrusted or not available to check it |
+| `✗` red | Bad signature, or an expired or revoked key |
+| (blank) | Not signed |
 
 ### CI status
 
-The CI column shows GitHub/GitLab pipeline status:
+The CI column shows GitHub/GitLab pipeline status, or a Gerrit change's `Verified` vote. It's fetched with `--ci` or `--full`, or always with `ci.enabled = true`; the `[ci]` section of the [user config](@/config.md#command-settings) also sets the provider for self-hosted instances.
 
 | Indicator | Meaning |
 |-----------|---------|
@@ -2074,12 +2933,17 @@ The Status column has multiple subcolumns. Within each, only the first matching
 | Working tree (2) | `!` | Modified files (unstaged) |
 | Working tree (3) | `?` | Untracked files |
 | Worktree | `✘` | Merge conflicts |
+| | `↻` | Merge conflicts, all resolved by [rerere](https://git-scm.com/docs/git-rerere) (pending `git add`) |
 | | `⤴` | Rebase in progress |
 | | `⤵` | Merge in progress |
 | | `/` | Branch without worktree |
+| | `⊘` | Broken `.git` link, e.g. after moving the repository (fix with `wt repair`) |
 | | `⚑` | Worktree path doesn't match branch name |
 | | `⊟` | Prunable (directory missing) |
 | | `⊞` | Locked worktree |
+| | `⊡` | Submodules uninitialized, out of sync, or dirty |
+| | `◌` | Git LFS objects not pulled |
+| | `◷` | Background hooks running (see `wt hook status`) |
 | Default branch | `^` | Is the default branch |
 | | `✗` | Would conflict if merged to the default branch (with `--full`, includes uncommitted changes) |
 | | `_` | Same commit as the default branch, clean |
@@ -2095,6 +2959,10 @@ The Status column has multiple subcolumns. Within each, only the first matching
 
 Rows are dimmed when [safe to delete](@/remove.md#branch-cleanup) (`_` same commit with clean working tree or `⊂` content integrated).
 
+`wt list --legend` prints this table in the terminal, colored as the Status column shows it, followed by `∅`, which marks a detached HEAD in the Branch column.
+
+During a rebase, the Message column starts with how far it got — `rebase 3/7 ·` while applying the third of seven commits — so an interrupted rebase shows how much is left.
+
 ## JSON output
 
 Query structured data with `--format=json`:
@@ -2116,6 +2984,19 @@ wt list --format=json | jq '.[] | select(.main.ahead > 0)'
 wt list --format=json | jq '.[] | select(.main_state == "integrated" or .main_state == "empty")'
 ```
 
+For a smaller payload, `--json` takes the fields to keep. Each item then has exactly those fields, `null` where it has no value, so scripts don't break when an optional field is absent. `ahead` and `behind` are the counts against the default branch, from `main`:
+
+```console
+wt list --json branch,ahead,behind,path
+```
+
+`--template` formats each selected item on its own line, with the same Jinja syntax as hooks — no `jq` needed:
+
+```console
+wt list --json branch,ahead --template '{{ branch }}: {{ ahead }} ahead'
+wt list --json branch,is_merged --template '{% if is_merged %}{{ branch }}{% endif %}'
+```
+
 **Fields:**
 
 | Field | Type | Description |
@@ -2127,7 +3008,10 @@ wt list --format=json | jq '.[] | select(.main_state == "integrated" or .main_st
 | `working_tree` | object | Working tree state (see below) |
 | `main_state` | string | Relation to the default branch (see below) |
 | `integration_reason` | string | Why branch is integrated (see below) |
-| `operation_state` | string | `"conflicts"`, `"rebase"`, or `"merge"` (absent when clean) |
+| `is_merged` | boolean | Branch is in the default branch's history, so `wt remove --merged` removes it (absent when is_main) |
+| `operation_state` | string | `"conflicts"`, `"resolved"`, `"rebase"`, or `"merge"` (absent when clean) |
+| `resolution` | object | During a rebase/merge: `remaining` conflicted paths and paths `rerere_resolved` |
+| `operation_progress` | object | During a rebase: the commit being applied, `step` of `total` |
 | `main` | object | Relationship to the default branch (see below, absent when is_main) |
 | `remote` | object | Tracking branch info (see below, absent when no tracking) |
 | `worktree` | object | Worktree metadata (see below) |
@@ -2135,6 +3019,8 @@ wt list --format=json | jq '.[] | select(.main_state == "integrated" or .main_st
 | `is_current` | boolean | Is the current worktree |
 | `is_previous` | boolean | Previous worktree from wt switch |
 | `ci` | object | CI status (see below, absent when no CI) |
+| `description` | string | Branch description from `git branch --edit-description` (absent when unset) |
+| `signature` | object | Tip commit signature: `status` and `signer` (with `--full` or the `signature` column) |
 | `url` | string | Dev server URL from project config (absent when not configured) |
 | `url_active` | boolean | Whether the URL's port is listening (absent when not configured) |
 | `statusline` | string | Pre-formatted status with ANSI colors |
@@ -2158,8 +3044,8 @@ wt list --format=json | jq '.[] | select(.main_state == "integrated" or .main_st
 | `untracked` | boolean | Has untracked files |
 | `renamed` | boolean | Has renamed files |
 | `deleted` | boolean | Has deleted files |
-| `diff` | object | Lines changed vs HEAD: `{added, deleted}` |
-| `diff_vs_main` | object | Lines changed vs the default branch: `{added, deleted}` |
+| `diff` | object | Changes vs HEAD: `{added, deleted, files}` |
+| `diff_vs_main` | object | Changes vs the default branch: `{added, deleted, files}` |
 
 ### main object
 
@@ -2167,7 +3053,8 @@ wt list --format=json | jq '.[] | select(.main_state == "integrated" or .main_st
 |-------|------|-------------|
 | `ahead` | number | Commits ahead of the default branch |
 | `behind` | number | Commits behind the default branch |
-| `diff` | object | Lines changed vs the default branch: `{added, deleted}` |
+| `diff` | object | Changes vs the default branch: `{added, deleted, files}` |
+| `conflicts` | array | Paths that would conflict when merging into the default branch, including uncommitted changes with `--full` (absent when none) |
 
 ### remote object
 
@@ -2182,9 +3069,13 @@ wt list --format=json | jq '.[] | select(.main_state == "integrated" or .main_st
 
 | Field | Type | Description |
 |-------|------|-------------|
-| `state` | string | `"path_mismatch"`, `"prunable"`, `"locked"` (absent when normal) |
-| `reason` | string | Reason for locked/prunable state |
+| `state` | string | `"broken"`, `"path_mismatch"`, `"prunable"`, `"locked"`, `"submodules"`, `"lfs_missing"`, `"hooks_running"` (or absent) |
+| `reason` | string | Reason for broken/locked/prunable state |
 | `detached` | boolean | HEAD is detached |
+| `describe` | string | Nearest tag for a detached HEAD, e.g. `"v1.2+3"` (absent when on a branch or untagged) |
+| `submodules` | object | Counts of `uninitialized`, `out_of_sync`, and `dirty` submodules (absent without submodules) |
+| `lfs_missing` | number | Git LFS files checked out as pointers (absent without LFS) |
+| `hooks_running` | number | Background hook commands still running (absent when none are) |
 
 ### ci object
 
@@ -2209,6 +3100,10 @@ When `main_state == "integrated"`: `"ancestor"` `"trees_match"` `"no_added_chang
 
 `"passed"` `"running"` `"failed"` `"conflicts"` `"no-ci"` `"error"`
 
+### signature.status values
+
+`"good"` `"untrusted"` `"expired"` `"revoked"` `"bad"` `"unverifiable"` `"unsigned"`
+
 Missing a field that would be generally useful? Open an issue at https://github.com/max-sixty/worktrunk.
 
 ## See also
@@ -2229,9 +3124,31 @@ Missing a field that would be generally useful? Open an issue at https://github.
         #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
         format: OutputFormat,
 
+        /// JSON with only these fields (comma-separated)
+        ///
+        /// Each item has exactly the fields asked for, `null` where it has no
+        /// value. See "JSON output" below for names.
+        #[arg(
+            long,
+            value_delimiter = ',',
+            value_name = "FIELDS",
+            conflicts_with = "format"
+        )]
+        json: Option<Vec<String>>,
+
+        /// Format each `--json` item with a template
+        ///
+        /// Jinja syntax over the selected fields, one line per item, e.g.
+        /// `--template '{{ branch }} {{ ahead }}'`.
+        #[arg(long, requires = "json")]
+        template: Option<String>,
+
         /// Include branches without worktrees
-        #[arg(long)]
-        branches: bool,
+        ///
+        /// With a glob pattern, only branches matching it, e.g. `--branches
+        /// 'feature/*'`. Repeat for more patterns. Also filters `--remotes`.
+        #[arg(long, num_args = 0..=1, value_name = "PATTERN")]
+        branches: Option<Vec<String>>,
 
         /// Include remote branches
         #[arg(long)]
@@ -2241,10 +3158,40 @@ Missing a field that would be generally useful? Open an issue at https://github.
         #[arg(long)]
         full: bool,
 
+        /// Show CI status
+        ///
+        /// Fetches CI status without the rest of `--full`. On by default with
+        /// `ci.enabled = true`.
+        #[arg(long)]
+        ci: bool,
+
+        /// Columns to show, in order (comma-separated)
+        ///
+        /// Overrides `list.columns`. See "Choosing columns" below for names.
+        #[arg(
+            long,
+            value_enum,
+            value_delimiter = ',',
+            value_name = "COLUMNS",
+            hide_possible_values = true
+        )]
+        columns: Vec<worktrunk::config::ListColumn>,
+
+        /// Include all registered repositories
+        ///
+        /// Lists worktrees from every repository added with `wt repo add`.
+        #[arg(long)]
+        all_repos: bool,
+
+        /// Print what each status symbol means, then exit
+        #[arg(long)]
+        legend: bool,
+
         /// Show fast info immediately, update with slow info
         ///
         /// Displays local data (branches, paths, status) first, then updates
-        /// with remote data (CI, upstream) as it arrives. Auto-enabled for TTY.
+        /// with remote data (CI, upstream) as it arrives. Auto-enabled for TTY,
+        /// except terminals that can't redraw rows (`TERM=dumb`, Emacs shell).
         #[arg(long, overrides_with = "no_progressive")]
         progressive: bool,
 
@@ -2253,45 +3200,547 @@ Missing a field that would be generally useful? Open an issue at https://github.
         no_progressive: bool,
     },
 
-    /// Switch to a worktree
+    /// Show commits unique to a branch
     #[command(
-        after_long_help = r#"Change directory to a worktree, creating one if needed.
-<!-- demo: wt-switch.gif 1600x900 -->
-
-Worktrees are addressed by branch name; paths are computed from a template. Unlike `git switch`, this navigates between worktrees rather than changing branches in place.
+        after_long_help = r#"Shows only the commits a branch has over the default branch — the work that `wt merge` would bring in. Each commit shows its diffstat and age, matching the log tab in `wt select`.
 
 ## Examples
 
+Commits on the current branch:
+
 ```console
-wt switch feature-auth           # Switch to worktree
-wt switch -                      # Previous worktree (like cd -)
-wt switch --create new-feature   # Create new branch and worktree
-wt switch --create hotfix --base production
+wt log
 ```
 
-## Creating a branch
-
-The `--create` flag creates a new branch from the `--base` branch (defaults to default branch). Without `--create`, the branch must already exist.
-
-## Creating worktrees
-
-If the branch already has a worktree, `wt switch` changes directories to it. Otherwise, it creates one, running [hooks](@/hook.md).
+Commits on another branch:
 
-When creating a worktree, worktrunk:
+```console
+wt log feature-auth
+```
 
-1. Creates worktree at configured path
-2. Switches to new directory
-3. Runs [post-create hooks](@/hook.md#post-create) (blocking)
-4. Spawns [post-start hooks](@/hook.md#post-start) (background)
+Unique commits from every worktree, newest first:
 
 ```console
-wt switch feature                        # Existing branch → creates worktree
-wt switch --create feature               # New branch and worktree
-wt switch --create fix --base release    # New branch from release
-wt switch --create temp --no-verify      # Skip hooks
+wt log --all
 ```
 
-## Shortcuts
+With `--all`, each commit is labeled with its branch. Commits shared by stacked branches appear once, labeled with every branch that contains them. The default branch and detached worktrees are skipped.
+
+## See also
+
+- [wt list](@/list.md) — Commit counts ahead of and behind the default branch
+- [wt select](@/select.md) — Interactive log preview
+"#
+    )]
+    Log {
+        /// Branch name [default: current]
+        ///
+        /// Shortcuts: '^' (default branch), '-' (previous), '@' (current)
+        #[arg(add = crate::completion::worktree_branch_completer(), conflicts_with = "all")]
+        branch: Option<String>,
+
+        /// Include all worktrees
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Annotate a branch
+    #[command(
+        after_long_help = r#"Sets a short note on a branch, shown in the Status column of `wt list` — "waiting on review", "blocked", "🚧". Notes are stored per branch in git config, so every worktree and the statusline see them.
+
+## Examples
+
+Set a note:
+
+```console
+wt note feature-auth "waiting on review"
+```
+
+Note the current branch:
+
+```console
+wt note @ 🚧
+```
+
+Show a branch's note:
+
+```console
+wt note feature-auth
+```
+
+Clear it:
+
+```console
+wt note feature-auth --clear
+```
+
+Notes are the same as markers in `wt config state marker`, which can also clear every note at once with `--all`.
+"#
+    )]
+    Note {
+        /// Branch name
+        ///
+        /// Shortcuts: '^' (default branch), '-' (previous), '@' (current)
+        #[arg(add = crate::completion::branch_value_completer())]
+        branch: String,
+
+        /// Note text [default: show current note]
+        text: Option<String>,
+
+        /// Remove the note
+        #[arg(long, conflicts_with = "text")]
+        clear: bool,
+    },
+
+    /// Compact status for shell prompts
+    #[command(
+        after_long_help = r#"Prints a short segment for the current worktree — its name, commits ahead of (`↑`) and behind (`↓`) the default branch, and `*` for uncommitted changes — for starship, powerlevel10k, or a hand-written `PS1`:
+
+```
+feature ↑2↓1 *
+```
+
+It is built to run on every prompt: a handful of git commands, no worktree listing, and no colors, so the prompt tool can style it. Outside a worktree it prints nothing and succeeds. Ahead/behind counts need the default branch to be known; run any other `wt` command once to detect it.
+
+For the full set of `wt list` status symbols, use `wt list statusline` instead.
+
+## Examples
+
+A [starship](https://starship.rs/config/#custom-commands) custom module:
+
+```toml
+[custom.worktrunk]
+command = "wt prompt"
+when = "git rev-parse --is-inside-work-tree"
+```
+
+As JSON, for prompts that format the fields themselves:
+
+```console
+$ wt prompt --format=json
+{"name":"feature","branch":"feature","path":"/home/user/code/myproject.feature","ahead":2,"behind":1,"dirty":true}
+```
+
+`branch` is null on a detached HEAD, where `name` is the worktree's directory name instead. `ahead` and `behind` are absent when the default branch isn't known.
+"#
+    )]
+    Prompt {
+        /// Output format (table, json)
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
+    },
+
+    /// Open a worktree in tmux
+    #[command(
+        after_long_help = r#"Creates a tmux session named after the branch, rooted at the worktree, and attaches to it. If the session already exists, attaches to it instead, so `wt tmux` is also how to get back.
+
+Inside tmux, a window in the current session is used rather than a separate session, and selected instead of attached.
+
+Session and window names are the branch name with `/`, `.`, and `:` replaced by `-`, which tmux reserves for targets.
+
+## Examples
+
+Open the current worktree:
+
+```console
+wt tmux
+```
+
+Open another worktree:
+
+```console
+wt tmux feature-auth
+```
+
+## New worktrees
+
+To create a session for every worktree `wt switch --create` makes, in the background, set `tmux` in the user config:
+
+```toml
+[switch]
+tmux = true
+```
+
+Attach to it later with `wt tmux`.
+"#
+    )]
+    Tmux {
+        /// Branch name [default: current]
+        ///
+        /// Shortcuts: '^' (default branch), '-' (previous), '@' (current)
+        #[arg(add = crate::completion::worktree_branch_completer())]
+        branch: Option<String>,
+    },
+
+    /// Write a VS Code workspace of all worktrees
+    #[command(
+        after_long_help = r#"Writes a [multi-root workspace](https://code.visualstudio.com/docs/editor/multi-root-workspaces) file with every worktree as a folder, named by branch, so parallel work can be opened in one VS Code (or Cursor) window:
+
+```console
+wt workspace
+code ../myproject.code-workspace
+```
+
+By default the file goes beside the repository, alongside its worktrees: `myproject.code-workspace` for a repository in `myproject`. `--output` writes it elsewhere; folder paths are relative to the file.
+
+Run it again after creating or removing worktrees to refresh the folders; VS Code picks up the change in an open window. Other settings in the file, such as `settings` and `extensions`, are kept. The file must be plain JSON — comments and trailing commas, which VS Code otherwise allows, stop it from being updated.
+"#
+    )]
+    Workspace {
+        /// Workspace file to write [default: beside the repository]
+        #[arg(long, short, value_name = "PATH")]
+        output: Option<std::path::PathBuf>,
+    },
+
+    /// Show a branch's pull request
+    #[command(
+        after_long_help = r#"Shows the pull request for a branch: title, state, reviews, and CI checks. Requires the [GitHub CLI](https://cli.github.com/) (`gh`), authenticated for the repository.
+
+## Examples
+
+Pull request for the current branch:
+
+```console
+wt pr
+```
+
+Open it in the browser:
+
+```console
+wt pr --open
+```
+
+Review someone else's pull request in a new worktree:
+
+```console
+wt pr --checkout 123
+```
+
+## Checks
+
+Check status is the same as the CI column in `wt list --full`, including its short-lived cache. When local commits haven't been pushed yet, checks are marked as belonging to an older commit.
+
+## Checking out
+
+`--checkout` fetches the pull request's head into a local branch and switches to it, creating a worktree and running hooks like `wt switch`. Pull requests from the same repository use their branch name; pull requests from forks use `owner/branch`. An existing local branch with that name is reused as-is.
+
+## See also
+
+- [wt list](@/list.md#ci-status) — CI status for every branch
+- [wt switch](@/switch.md) — Create worktrees for branches
+"#
+    )]
+    Pr {
+        /// Branch name [default: current]
+        ///
+        /// Shortcuts: '^' (default branch), '-' (previous), '@' (current)
+        #[arg(add = crate::completion::worktree_branch_completer())]
+        branch: Option<String>,
+
+        /// Open in browser
+        #[arg(long, conflicts_with = "checkout")]
+        open: bool,
+
+        /// Check out pull request in a worktree
+        #[arg(long, value_name = "number", conflicts_with = "branch")]
+        checkout: Option<u32>,
+
+        /// Skip approval prompts
+        #[arg(short, long, requires = "checkout")]
+        yes: bool,
+
+        /// Skip hooks
+        #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true, requires = "checkout")]
+        verify: bool,
+    },
+
+    /// Clone a repository
+    #[command(
+        after_long_help = r#"Clones a repository with `git clone`, then changes into it (with shell integration).
+
+## Examples
+
+Clone into `./worktrunk`:
+
+```console
+wt clone https://github.com/max-sixty/worktrunk.git
+```
+
+Clone in the bare layout:
+
+```console
+wt clone --bare https://github.com/max-sixty/worktrunk.git
+```
+
+## Bare layout
+
+`--bare` keeps the repository in `<directory>/.git` and checks out every branch as a worktree beside it:
+
+```
+worktrunk/
+├── .git/       # bare repository
+├── main/       # default branch
+└── feature/    # wt switch --create feature
+```
+
+It configures the clone to fetch remote branches (which a plain `git clone --bare` doesn't), checks out the default branch, and writes `worktree-path = "../{{ branch | sanitize }}"` to `.git/worktrunk.toml` so new worktrees land beside `.git` without changing the user config. In this layout the default branch's worktree stands in for the main worktree: `wt list` compares against it, and `wt remove` returns to it.
+"#
+    )]
+    Clone {
+        /// Repository URL or path
+        url: String,
+
+        /// Directory to clone into [default: derived from the URL]
+        #[arg(value_name = "DIRECTORY")]
+        dest: Option<std::path::PathBuf>,
+
+        /// Clone bare, with every branch checked out as a worktree
+        #[arg(long)]
+        bare: bool,
+    },
+
+    /// Relink worktrees with a broken `.git` file
+    #[command(
+        after_long_help = r#"A linked worktree's `.git` file points at its admin directory inside the main repository's `.git/worktrees/`. If that link breaks — the worktree was copied or restored from a backup, or its `.git` file was edited — git fails on every command run inside it, and `wt list` marks it `⊘`.
+
+`wt repair` finds these worktrees and rewrites their links with `git worktree repair`. Run it from any worktree that still works, such as the main one.
+
+## Moved repositories
+
+Moving or renaming the main repository breaks the link of every linked worktree at once, and commands run inside them fail with an explanation. Point `--from` at the repository's new location to relink them all in one step, from anywhere:
+
+```console
+wt repair --from ~/code/myproject
+```
+
+## Examples
+
+```console
+wt repair
+```
+"#
+    )]
+    Repair {
+        /// Repository to relink worktrees to [default: current]
+        ///
+        /// Use after moving or renaming the main repository, from one of its
+        /// worktrees.
+        #[arg(long, value_name = "PATH")]
+        from: Option<std::path::PathBuf>,
+    },
+
+    /// Report worktrees and branches to clean up
+    #[command(
+        after_long_help = r#"Looks over every worktree and local branch and prints what needs attention, each with the command that deals with it. It changes nothing; run the commands that fit.
+
+## Examples
+
+```console
+wt tidy
+wt tidy --stale-after 2w
+```
+
+## Categories
+
+Each branch is listed once, under the first category that applies:
+
+| Category | Meaning | Suggested command |
+|----------|---------|-------------------|
+| Merged | An ancestor of the default branch (or its upstream, when ahead) | `wt remove <branch>` |
+| Upstream branch deleted | Its remote branch is gone, usually after its pull request merged | `wt remove <branch> -D` |
+| Stale | No commits within `--stale-after` | `wt remove <branch>` (keeps the branch), or `-D` for a branch without a worktree |
+| Uncommitted changes | A dirty worktree with no commits within `--stale-after` | `wt switch <branch> -x 'wt step commit'` |
+| Would conflict | Merging into the default branch would conflict | `wt switch <branch> -x 'wt step rebase'` |
+
+Worktrees with uncommitted changes are never suggested for removal. The main worktree, the default branch, and locked worktrees are skipped, as with `wt remove --merged`.
+
+Upstream branches count as deleted once a fetch prunes them, for example with `git fetch --prune`.
+"#
+    )]
+    Tidy {
+        /// Age without commits after which a branch is stale
+        ///
+        /// Days (`30d`), weeks (`2w`), or hours (`12h`).
+        #[arg(long, value_name = "AGE", default_value = "30d", value_parser = worktrunk::config::parse_age)]
+        stale_after: std::time::Duration,
+    },
+
+    /// Show what worktrunk has changed in this repository
+    #[command(
+        after_long_help = r#"Lists the worktrees worktrunk created and removed, the branches it deleted, and the merges it performed, newest first. Entries are numbered for [`wt undo`](#wt-undo).
+
+## Examples
+
+```console
+wt history
+```
+
+## Journal
+
+The history is kept in `.git/worktrunk/journal`, one JSON object per line, shared by every worktree of the repository. It's only appended to. Removing a worktree also writes a bundle of the commits its branch has over the merge target (or the default branch) to `.git/worktrunk/bundles/`, so `wt undo` can restore it after `git gc`; bundles are pruned after 90 days. Only committed work is kept: untracked files that `wt remove --force` deletes can't be restored. A background removal is recorded once it has finished.
+"#
+    )]
+    History,
+
+    /// Reverse a worktree or branch removal
+    #[command(
+        after_long_help = r#"Undoes the most recent removal in [`wt history`](#wt-history) that hasn't been undone, or the numbered entry given.
+
+## Examples
+
+```console
+wt undo
+wt undo 12
+```
+
+## What can be undone
+
+| Action | Undo |
+|--------|------|
+| Worktree removed | Added back at the same path, on its branch, recreating the branch if it was deleted too; detached at the removed commit if the branch has since moved off it |
+| Branch removed | Recreated at the commit it pointed to |
+
+Creating worktrees and merging aren't undone: remove the worktree with `wt remove`, and revert a merge with git.
+"#
+    )]
+    Undo {
+        /// Entry number from `wt history` [default: most recent]
+        #[arg(value_name = "ENTRY")]
+        entry: Option<usize>,
+    },
+
+    /// Manage registered repositories
+    #[command(
+        after_long_help = r#"Keeps a registry of repositories in user config so commands can target them from anywhere with `--repo <name>`, without changing directory.
+
+## Examples
+
+Register the current repository:
+
+```console
+wt repo add
+```
+
+List worktrees of a registered repository from anywhere:
+
+```console
+wt --repo worktrunk list
+```
+
+## Names
+
+Repositories are keyed by project identifier — `github.com/owner/repo` for a repository cloned from GitHub. `--repo` accepts the full identifier or any unique trailing part of it, such as `owner/repo` or `repo`.
+
+The registry lives alongside approved commands in the user config:
+
+```toml
+[projects."github.com/owner/repo"]
+path = "/home/user/code/repo"
+```
+"#
+    )]
+    Repo {
+        #[command(subcommand)]
+        action: RepoCommand,
+    },
+
+    /// Manage approved project commands
+    #[command(
+        after_long_help = r#"Commands from project hooks (`.config/wt.toml`) prompt for approval before their first run. Approvals are remembered per project identifier (e.g., `github.com/owner/repo`) and per command — changing a command's template requires approving it again.
+
+Each approval has a short ID derived from a hash of the command, shown by `wt trust list`.
+
+## Examples
+
+List approvals for the current project:
+
+```console
+wt trust list
+```
+
+Revoke one approval:
+
+```console
+wt trust revoke 3f2a9c1e
+```
+
+To approve all of a project's commands up front, run `wt hook approvals add`; to clear them all, `wt hook approvals clear`.
+"#
+    )]
+    Trust {
+        #[command(subcommand)]
+        action: TrustCommand,
+    },
+
+    /// Switch to a worktree
+    #[command(
+        after_long_help = r#"Change directory to a worktree, creating one if needed.
+<!-- demo: wt-switch.gif 1600x900 -->
+
+Worktrees are addressed by branch name; paths are computed from a template. Unlike `git switch`, this navigates between worktrees rather than changing branches in place.
+
+## Examples
+
+```console
+wt switch feature-auth           # Switch to worktree
+wt switch -                      # Previous worktree (like cd -)
+wt switch --create new-feature   # Create new branch and worktree
+wt switch --create hotfix --base production
+```
+
+## Creating a branch
+
+The `--create` flag creates a new branch from the `--base` branch (defaults to default branch). Without `--create`, the branch must already exist.
+
+With `--orphan`, the new branch has no history: it starts at an empty root commit, for branches like `gh-pages` that share nothing with the rest of the repository. Having no merge base with the default branch, all of its commits count as ahead in `wt list`; it never shows as conflicting, and there's no line diff against the default branch.
+
+```console
+wt switch --create gh-pages --orphan
+```
+
+With `--cherry-pick`, the new branch starts from the base and then receives the given commit, or range of commits — for pulling a couple of commits out of a tangled branch into a clean PR:
+
+```console
+wt switch --create fix-typo --cherry-pick 1a2b3c4
+wt switch --create extract-api --cherry-pick tangled~3..tangled~1
+```
+
+If a commit doesn't apply cleanly, the cherry-pick stops with the worktree created; resolve it there and run `git cherry-pick --continue`.
+
+## Creating worktrees
+
+If the branch already has a worktree — wherever it is, and however the branch is named (`feature`, `heads/feature`, `refs/heads/feature`) — `wt switch` changes directories to it rather than checking the branch out a second time. Otherwise, it creates one, running [hooks](@/hook.md).
+
+When creating a worktree, worktrunk:
+
+1. Creates worktree at configured path
+2. Switches to new directory
+3. Runs [post-create hooks](@/hook.md#post-create) (blocking)
+4. Spawns [post-start hooks](@/hook.md#post-start) (background)
+
+```console
+wt switch feature                        # Existing branch → creates worktree
+wt switch --create feature               # New branch and worktree
+wt switch --create fix --base release    # New branch from release
+wt switch --create temp --no-verify      # Skip hooks
+```
+
+## Branches from issues
+
+`--from-issue` names the new branch after a GitHub or GitLab issue, looked up with `gh` or `glab`:
+
+```console
+wt switch --create --from-issue 1234     # e.g. 1234-fix-login-redirect
+```
+
+The name comes from the `switch.issue-branch` template in user config, with `{{ ticket }}` (the issue number), `{{ title }}`, and `{{ slug }}` (the title, lowercased and shortened). A branch name given alongside `--from-issue` is used instead. The issue's URL is recorded, so hooks can link it when opening a pull request:
+
+```toml
+[post-create]
+draft-pr = "gh pr create --draft --fill --body 'Closes {{ issue_url }}'"
+```
+
+## Shortcuts
 
 | Shortcut | Meaning |
 |----------|---------|
@@ -2324,20 +3773,53 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
     Switch {
         /// Branch name
         ///
-        /// Shortcuts: '^' (default branch), '-' (previous), '@' (current)
-        #[arg(add = crate::completion::worktree_branch_completer())]
-        branch: String,
+        /// Shortcuts: '^' (default branch), '-' (previous), '@' (current),
+        /// a number (that row of the last `wt list` with the `#` column)
+        #[arg(
+            required_unless_present = "from_issue",
+            add = crate::completion::worktree_branch_completer()
+        )]
+        branch: Option<String>,
 
         /// Create a new branch
         #[arg(short = 'c', long)]
         create: bool,
 
-        /// Base branch
+        /// Name the new branch after an issue on GitHub or GitLab
         ///
-        /// Defaults to default branch.
+        /// Fetches the issue's title with `gh` or `glab` and names the branch
+        /// with the `switch.issue-branch` template (default
+        /// `{{ ticket }}-{{ slug }}`, e.g. `1234-fix-login-redirect`). The
+        /// issue's URL is recorded for hooks as `{{ issue_url }}`.
+        #[arg(long, value_name = "NUMBER", requires = "create")]
+        from_issue: Option<u32>,
+
+        /// Base branch, tag, or commit
+        ///
+        /// Defaults to default branch. A tag or commit base is remembered, and
+        /// `wt list` counts the new branch's ahead/behind against it.
         #[arg(short = 'b', long, add = crate::completion::branch_value_completer())]
         base: Option<String>,
 
+        /// Start the new branch with no history
+        ///
+        /// The branch begins at an empty root commit, sharing nothing with
+        /// the default branch — for `gh-pages` and similar.
+        #[arg(long, requires = "create", conflicts_with = "base")]
+        orphan: bool,
+
+        /// Cherry-pick commits onto the new branch
+        ///
+        /// A commit, or a range `<from>..<to>` (the commits after `<from>` up
+        /// to `<to>`), applied on top of the base before hooks run.
+        #[arg(
+            long,
+            value_name = "REV[..REV]",
+            requires = "create",
+            conflicts_with = "orphan"
+        )]
+        cherry_pick: Option<String>,
+
         /// Command to run after switch
         ///
         /// Replaces the wt process with the command after switching, giving
@@ -2373,9 +3855,23 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
         #[arg(long)]
         clobber: bool,
 
+        /// Refuse to create a worktree over the `[limits]` budget
+        ///
+        /// Without it, going over `limits.max-worktrees` or
+        /// `limits.max-total-disk` only warns.
+        #[arg(long, requires = "create")]
+        strict: bool,
+
         /// Skip hooks
         #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true)]
         verify: bool,
+
+        /// Output format (table, json)
+        ///
+        /// With `json`, prints an object describing what changed (worktrees,
+        /// refs, hooks) to stdout.
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
     },
 
     /// Remove worktree and branch
@@ -2409,6 +3905,21 @@ Force-delete an unmerged branch:
 wt remove -D experimental
 ```
 
+Clean up every worktree whose branch has landed in the default branch:
+
+```console
+wt remove --merged
+```
+
+Remove a family of branches, such as ones an agent created:
+
+```console
+wt remove --pattern 'experiment/*' --dry-run
+wt remove --pattern 'experiment/*'
+```
+
+`--pattern` lists the matching worktrees and asks once before removing them all (`--yes` skips the question). In the glob, `*` and `?` also match `/`. The main worktree, the default branch, and locked worktrees are never selected.
+
 ## Branch cleanup
 
 Branches delete automatically when merging them would add nothing. This works with squash-merge and rebase workflows where commit history differs but file changes match.
@@ -2425,6 +3936,8 @@ Check 1 compares against the default branch. Checks 2-5 compare against **target
 
 Branches showing `_` or `⊂` are dimmed as safe to delete.
 
+`--merged` selects worktrees with the cheapest check alone: their branch is an ancestor of the target (conditions 1 and 2, `is_merged` in `wt list --format=json`). The main worktree and locked worktrees are skipped.
+
 Use `-D` to force-delete branches with unmerged changes. Use `--no-delete-branch` to keep the branch regardless of status.
 
 ## Background removal
@@ -2442,8 +3955,8 @@ Removal runs in the background by default (returns immediately). Logs are writte
 "#
     )]
     Remove {
-        /// Branch name [default: current]
-        #[arg(add = crate::completion::local_branches_completer())]
+        /// Branch name, worktree path, or row number from the last `wt list` [default: current]
+        #[arg(add = crate::completion::worktree_completer())]
         branches: Vec<String>,
 
         /// Keep branch after removal
@@ -2454,6 +3967,18 @@ Removal runs in the background by default (returns immediately). Logs are writte
         #[arg(short = 'D', long = "force-delete")]
         force_delete: bool,
 
+        /// Remove every worktree whose branch is merged into the default branch
+        #[arg(long, conflicts_with = "branches")]
+        merged: bool,
+
+        /// Remove every worktree whose branch matches a glob, after confirming
+        #[arg(long, value_name = "GLOB", conflicts_with_all = ["branches", "merged"])]
+        pattern: Option<String>,
+
+        /// List the worktrees `--merged` or `--pattern` selects without removing them
+        #[arg(long)]
+        dry_run: bool,
+
         /// Run removal in foreground
         #[arg(long = "no-background", action = clap::ArgAction::SetFalse, default_value_t = true)]
         background: bool,
@@ -2472,6 +3997,13 @@ Removal runs in the background by default (returns immediately). Logs are writte
         /// artifacts). Without this flag, removal fails if untracked files exist.
         #[arg(short, long)]
         force: bool,
+
+        /// Output format (table, json)
+        ///
+        /// With `json`, prints an object describing what changed (worktrees,
+        /// refs, hooks) to stdout.
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
     },
 
     /// Merge worktree into target branch
@@ -2518,12 +4050,21 @@ wt merge --no-commit
 `wt merge` runs these steps:
 
 1. **Squash** — Stages uncommitted changes, then combines all commits since target into one (like GitHub's "Squash and merge"). Use `--stage` to control what gets staged: `all` (default), `tracked`, or `none`. A backup ref is saved to `refs/wt-backup/<branch>`. With `--no-squash`, uncommitted changes become a separate commit and individual commits are preserved.
-2. **Rebase** — Rebases onto target if behind. Skipped if already up-to-date. Conflicts abort immediately.
+2. **Rebase** — Rebases onto target if behind. Skipped if already up-to-date. Conflicts stop the merge: resolve them and run `wt merge --continue` to finish the remaining steps, or `wt merge --abort` to put the branch back as it was before the merge (changes it committed or squashed are left staged).
 3. **Pre-merge hooks** — Project commands run after rebase, before merge. Failures abort. See [wt hook](@/hook.md).
-4. **Merge** — Fast-forward merge to the target branch. Non-fast-forward merges are rejected.
-5. **Pre-remove hooks** — Project commands run before removing worktree. Failures abort.
-6. **Cleanup** — Removes the worktree and branch. Use `--no-remove` to keep the worktree. When already on the target branch or in the main worktree, the worktree is preserved.
-7. **Post-merge hooks** — Project commands run after cleanup. Failures are logged but don't abort.
+4. **Pre-push hooks** — Project commands run just before the target branch moves. Failures abort.
+5. **Merge** — Fast-forward merge to the target branch. Non-fast-forward merges are rejected.
+6. **Pre-remove hooks** — Project commands run before removing worktree. Failures abort.
+7. **Cleanup** — Removes the worktree and branch, then starts post-remove hooks in the background. Use `--no-remove` to keep the worktree. When already on the target branch or in the main worktree, the worktree is preserved.
+8. **Post-merge hooks** — Project commands run after cleanup. Failures are logged but don't abort.
+
+Use `--require-ci` (or `require-ci = true` under `[merge]` in user config) to refuse the merge unless the branch's latest CI run — the same status `wt list --full` shows — passed on the commit being merged. `--override-ci` merges anyway.
+
+Use `--gpg-sign` (or `sign = true` under `[merge]`) to sign every commit the merge creates — the commit of uncommitted changes, the squash commit, and commits rewritten by the rebase — for branches protected by signed-commit rules. It passes `-S` to `git commit` and `git rebase`, so signing uses git's own `user.signingkey` and `gpg.format` settings.
+
+Use `--autostash` (or `autostash = true` under `[merge]`) to merge from a worktree with changes the merge won't commit — with `--no-commit`, or files left out by `--stage`. They're stashed before the rebase and restored once the target branch has moved, like `git rebase --autostash`. The worktree is kept so the restored changes aren't lost. If the rebase stops on conflicts, the stash waits for `wt merge --continue` or `wt merge --abort`.
+
+Use `--dry-run` to check a merge first: it lists the commits that would land on the target and any files that would conflict (including uncommitted changes), without running hooks or touching either branch.
 
 Use `--no-commit` to skip committing uncommitted changes and squashing; rebase still runs by default and can rewrite commits unless `--no-rebase` is passed. Useful after preparing commits manually with `wt step`. Requires a clean working tree.
 
@@ -2552,7 +4093,7 @@ lint = "cargo clippy"
         /// Target branch
         ///
         /// Defaults to default branch.
-        #[arg(add = crate::completion::branch_value_completer())]
+        #[arg(add = crate::completion::checked_out_branch_completer())]
         target: Option<String>,
 
         /// Force commit squashing
@@ -2602,5 +4143,116 @@ lint = "cargo clippy"
         /// What to stage before committing [default: all]
         #[arg(long)]
         stage: Option<crate::commands::commit::StageMode>,
+
+        /// Show what would be merged, and any conflicts, without changing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Refuse to merge unless the branch's CI has passed
+        #[arg(long)]
+        require_ci: bool,
+
+        /// Merge even if CI hasn't passed, overriding `--require-ci` and `merge.require-ci`
+        #[arg(long)]
+        override_ci: bool,
+
+        /// GPG-sign the commits the merge creates (`git commit -S`, `git rebase -S`)
+        #[arg(short = 'S', long, overrides_with = "no_gpg_sign")]
+        gpg_sign: bool,
+
+        /// Don't sign commits, overriding `merge.sign`
+        #[arg(long = "no-gpg-sign", overrides_with = "gpg_sign")]
+        no_gpg_sign: bool,
+
+        /// Stash uncommitted changes before rebasing and restore them afterward
+        #[arg(long, overrides_with = "no_autostash")]
+        autostash: bool,
+
+        /// Don't stash uncommitted changes, overriding `merge.autostash`
+        #[arg(long = "no-autostash", overrides_with = "autostash")]
+        no_autostash: bool,
+
+        /// Finish a merge stopped by rebase conflicts, once they're resolved
+        #[arg(long = "continue", conflicts_with_all = ["target", "abort", "dry_run"])]
+        continue_merge: bool,
+
+        /// Undo a merge stopped by rebase conflicts
+        #[arg(long, conflicts_with_all = ["target", "dry_run"])]
+        abort: bool,
+
+        /// Output format (table, json)
+        ///
+        /// With `json`, prints an object describing what changed (worktrees,
+        /// refs, hooks) to stdout.
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
+    },
+
+    /// Bring the current branch up to date with its target
+    #[command(
+        after_long_help = r#"Rebases the current branch onto the target branch, or merges the target into it, depending on the strategy.
+
+The strategy comes from `--strategy`, else from the `[sync]` section of user config, where a default can be overridden per branch pattern:
+
+```toml
+[sync]
+strategy = "rebase"       # default for branches no pattern matches
+
+[sync.branches]
+"release/*" = "merge"     # keep release history unrewritten
+```
+
+An exact branch name beats a pattern, and a longer pattern beats a shorter one. Without any config, branches are rebased.
+
+Conflicts stop the rebase or merge with git's usual state in place: resolve them and continue with git, or abort.
+
+## Examples
+
+Update the current branch from the default branch:
+
+```console
+wt sync
+```
+
+Merge `develop` into the current branch, whatever the config says:
+
+```console
+wt sync develop --strategy merge
+```
+
+Rebase `feature` onto `origin/feature` after its pull request was updated from the web:
+
+```console
+wt sync --branch feature --rebase-onto-upstream
+```
+
+## Diverged upstream
+
+A branch shows `⇅` in the Remote column of `wt list` when it's both ahead of and behind its upstream — for instance, when commits were added to its pull request from the web while work went on locally. `--rebase-onto-upstream` replays the local commits on top of the upstream, in the branch's own worktree, so it can be pushed again without forcing. `--branch` picks a branch other than the current one. The worktree must have no uncommitted changes.
+
+## See also
+
+- [wt step](@/step.md) — `wt step rebase` always rebases
+- [wt merge](@/merge.md) — Merge the branch into its target
+"#
+    )]
+    Sync {
+        /// Target branch
+        ///
+        /// Defaults to default branch.
+        #[arg(add = crate::completion::branch_value_completer())]
+        target: Option<String>,
+
+        /// Rebase onto the target or merge it in [default: from `[sync]` config, else rebase]
+        #[arg(long)]
+        strategy: Option<worktrunk::config::SyncStrategy>,
+
+        /// Rebase onto the branch's upstream instead of a target
+        #[arg(long, conflicts_with_all = ["target", "strategy"])]
+        rebase_onto_upstream: bool,
+
+        /// Branch to rebase onto its upstream, in its worktree [default: current]
+        #[arg(long, requires = "rebase_onto_upstream", add = crate::completion::branch_value_completer())]
+        branch: Option<String>,
     },
 }
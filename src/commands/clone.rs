@@ -0,0 +1,148 @@
+//! `wt clone`: clone a repository, optionally in the bare layout.
+//!
+//! The bare layout keeps the repository in `<dir>/.git` with every checkout a
+//! worktree beside it (`<dir>/main`, `<dir>/feature`, ...). Setting it up by
+//! hand takes several steps that are easy to miss — a bare clone has no fetch
+//! refspec, so remote branches never update — so `--bare` does them all.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::{GitError, Repository, working_dir};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{hint_message, progress_message, success_message};
+
+use super::worktree::compute_worktree_path;
+
+/// Worktree path template written to a bare clone's local config, placing
+/// worktrees beside `.git`
+const BARE_WORKTREE_PATH: &str = "../{{ branch | sanitize }}";
+
+/// The directory `git clone` would pick for `url`: its last path segment,
+/// without `.git`
+fn directory_for_url(url: &str) -> Option<&str> {
+    let name = url
+        .trim_end_matches(['/', '\\'])
+        .rsplit(['/', '\\', ':'])
+        .next()?;
+    let name = name.strip_suffix(".git").unwrap_or(name);
+    (!name.is_empty()).then_some(name)
+}
+
+/// Handle `wt clone <url> [directory] [--bare]`
+pub fn handle_clone(url: &str, directory: Option<&Path>, bare: bool) -> anyhow::Result<()> {
+    let directory = match directory {
+        Some(directory) => directory.to_path_buf(),
+        None => PathBuf::from(directory_for_url(url).ok_or_else(|| GitError::Other {
+            message: cformat!("Can't derive a directory name from <bold>{url}</>"),
+        })?),
+    };
+    let cwd = working_dir().context("Failed to get current directory")?;
+    let directory = cwd.join(directory);
+    if directory.exists() {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Directory already exists: <bold>{}</>",
+                format_path_for_display(&directory)
+            ),
+        }
+        .into());
+    }
+
+    let launcher = Repository::at(&cwd);
+    let git_dir = directory.join(".git");
+    // Everything that waits on the network runs under the spinner
+    crate::output::with_progress(
+        progress_message(cformat!("Cloning <bold>{url}</>...")),
+        || -> anyhow::Result<()> {
+            if !bare {
+                launcher.run_command(&["clone", "--quiet", url, &directory.to_string_lossy()])?;
+                return Ok(());
+            }
+            launcher.run_command(&[
+                "clone",
+                "--quiet",
+                "--bare",
+                url,
+                &git_dir.to_string_lossy(),
+            ])?;
+            let repo = Repository::at(&git_dir);
+            // Bare clones copy branches but track nothing; restore the usual refspec
+            // so fetches update origin/* and `wt list` can compare against them
+            repo.run_command(&[
+                "config",
+                "remote.origin.fetch",
+                "+refs/heads/*:refs/remotes/origin/*",
+            ])?;
+            repo.run_command(&["fetch", "--quiet", "origin"])?;
+            repo.run_command(&["remote", "set-head", "origin", "--auto"])?;
+            Ok(())
+        },
+    )??;
+    if !bare {
+        crate::output::print(success_message(cformat!(
+            "Cloned <bold>{url}</> to <bold>{}</>",
+            format_path_for_display(&directory)
+        )))?;
+        crate::output::change_directory(&directory)?;
+        return Ok(());
+    }
+
+    let repo = Repository::at(&git_dir);
+
+    // Keep worktrees beside .git, for this clone only
+    std::fs::write(
+        git_dir.join("worktrunk.toml"),
+        format!("worktree-path = \"{BARE_WORKTREE_PATH}\"\n"),
+    )
+    .context("Failed to write worktrunk.toml")?;
+
+    // Check out the default branch as the first worktree
+    let default_branch = repo.run_command(&["symbolic-ref", "--short", "HEAD"])?;
+    let default_branch = default_branch.trim();
+    let mut config = WorktrunkConfig::load().context("Failed to load config")?;
+    config.worktree_path = BARE_WORKTREE_PATH.to_string();
+    let worktree_path = compute_worktree_path(&repo, default_branch, &config)?;
+    repo.run_command(&[
+        "worktree",
+        "add",
+        "--quiet",
+        &worktree_path.to_string_lossy(),
+        default_branch,
+    ])?;
+
+    crate::output::print(success_message(cformat!(
+        "Cloned <bold>{url}</> as a bare repository in <bold>{}</>, with <bold>{default_branch}</> @ <bold>{}</>",
+        format_path_for_display(&directory),
+        format_path_for_display(&worktree_path)
+    )))?;
+    crate::output::print(hint_message(cformat!(
+        "To add a worktree beside it, run <bright-black>wt switch --create <<branch>></>"
+    )))?;
+    crate::output::change_directory(&worktree_path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directory_for_url() {
+        for (url, expected) in [
+            (
+                "https://github.com/max-sixty/worktrunk.git",
+                Some("worktrunk"),
+            ),
+            ("https://github.com/max-sixty/worktrunk/", Some("worktrunk")),
+            ("git@github.com:max-sixty/worktrunk.git", Some("worktrunk")),
+            ("/srv/git/project.git", Some("project")),
+            ("https://example.com/", Some("example.com")),
+            (".git", None),
+        ] {
+            assert_eq!(directory_for_url(url), expected, "{url}");
+        }
+    }
+}
@@ -17,6 +17,8 @@
 //! This ensures approval happens exactly once at the command entry point,
 //! eliminating the need to thread `auto_trust` through execution layers.
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use super::hooks::{HookSource, ParsedFilter};
 use super::project_config::{HookCommand, collect_commands_for_hooks};
 use super::repository_ext::RepositoryCliExt;
@@ -30,6 +32,19 @@ use worktrunk::styling::{
     stderr, warning_message,
 };
 
+/// Whether every prompt is answered yes; set by `wt --yes`
+static ASSUME_YES: AtomicBool = AtomicBool::new(false);
+
+/// Answer yes to every prompt (`wt --yes` or `WORKTRUNK_ASSUME_YES`)
+pub fn set_assume_yes(enabled: bool) {
+    ASSUME_YES.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether prompts are answered yes without asking
+pub fn assume_yes() -> bool {
+    ASSUME_YES.load(Ordering::Relaxed)
+}
+
 /// Batch approval helper used when multiple commands are queued for execution.
 /// Returns `Ok(true)` when execution may continue, `Ok(false)` when the user
 /// declined, and `Err` if config reload/save fails.
@@ -130,6 +145,11 @@ fn prompt_for_batch_approval(commands: &[&HookCommand], project_id: &str) -> any
         output::print(format_bash_with_gutter(&cmd.command.template))?;
     }
 
+    // `wt --yes` answers for the user, e.g. for `wt --yes hook approvals add`
+    if assume_yes() {
+        return Ok(true);
+    }
+
     // Check if stdin is a TTY before attempting to prompt
     // This happens AFTER showing the commands so they appear in CI/CD logs
     // even when the prompt cannot be displayed (fail-fast principle)
@@ -194,6 +214,11 @@ pub fn approve_hooks_filtered(
     hook_types: &[HookType],
     name_filter: Option<&str>,
 ) -> anyhow::Result<bool> {
+    // Nothing will run under --no-hooks, so there's nothing to approve
+    if !super::hooks::hooks_enabled() {
+        return Ok(true);
+    }
+
     // Parse filter to understand source and name separately
     // Uses the same ParsedFilter as hooks.rs for consistent behavior
     let parsed = name_filter.map(ParsedFilter::parse);
@@ -226,5 +251,11 @@ pub fn approve_hooks_filtered(
     }
 
     let project_id = ctx.repo.project_identifier()?;
-    approve_command_batch(&commands, project_id, ctx.config, ctx.yes, false)
+    approve_command_batch(
+        &commands,
+        project_id,
+        ctx.config,
+        ctx.yes || assume_yes(),
+        false,
+    )
 }
@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 use std::path::Path;
+use std::time::Duration;
 use worktrunk::HookType;
-use worktrunk::config::{Command, CommandConfig, WorktrunkConfig, expand_template};
+use worktrunk::config::{Command, CommandConfig, OnFailure, WorktrunkConfig, expand_template};
 use worktrunk::git::Repository;
 use worktrunk::path::to_posix_path;
 
@@ -10,8 +11,19 @@ pub struct PreparedCommand {
     pub name: Option<String>,
     pub expanded: String,
     pub context_json: String,
+    /// `WT_*` variables set in the command's environment (see [`hook_env`])
+    pub env: HookEnv,
+    /// Stop the command once it has run this long
+    pub timeout: Option<Duration>,
+    /// Overrides the hook's default failure handling
+    pub on_failure: Option<OnFailure>,
+    /// Spawn detached instead of running to completion
+    pub background: bool,
 }
 
+/// Environment variables for a hook command, as `(name, value)` pairs
+pub type HookEnv = Vec<(&'static str, String)>;
+
 #[derive(Clone, Copy, Debug)]
 pub struct CommandContext<'a> {
     pub repo: &'a Repository,
@@ -82,6 +94,13 @@ pub fn build_hook_context(
         to_posix_path(&repo_root.to_string_lossy()),
     );
 
+    if let Some(user) = ctx.repo.user_name() {
+        map.insert("user".into(), user.into());
+    }
+    for (name, value) in ctx.config.branch_template_vars(ctx.branch_or_head()) {
+        map.insert(name.into(), value);
+    }
+
     if let Ok(default_branch) = ctx.repo.default_branch() {
         map.insert("default_branch".into(), default_branch);
     }
@@ -107,6 +126,10 @@ pub fn build_hook_context(
         }
     }
 
+    if let Some(issue_url) = ctx.branch.and_then(|branch| ctx.repo.branch_issue(branch)) {
+        map.insert("issue_url".into(), issue_url);
+    }
+
     // Add extra vars (e.g., target branch for merge)
     for (k, v) in extra_vars {
         map.insert((*k).into(), (*v).into());
@@ -115,6 +138,33 @@ pub fn build_hook_context(
     map
 }
 
+/// Environment variables for a hook command, derived from its context.
+///
+/// These mirror the most-used template variables so hook scripts can read
+/// them without templating. `WT_BASE_BRANCH` is the branch a new worktree was
+/// created from, else the merge or push target, else the default branch.
+pub fn hook_env(context: &HashMap<String, String>) -> HookEnv {
+    // pre-switch's `target` is the destination, not a base to compare against
+    let target = context
+        .get("target")
+        .filter(|_| context.get("hook_type").map(String::as_str) != Some("pre-switch"));
+    let base_branch = context
+        .get("base")
+        .or(target)
+        .or_else(|| context.get("default_branch"));
+
+    [
+        ("WT_BRANCH", context.get("branch")),
+        ("WT_WORKTREE", context.get("worktree")),
+        ("WT_REPO_ROOT", context.get("repo_root")),
+        ("WT_BASE_BRANCH", base_branch),
+        ("WT_HOOK_TYPE", context.get("hook_type")),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| value.map(|value| (name, value.clone())))
+    .collect()
+}
+
 /// Expand commands from a CommandConfig without approval
 ///
 /// This is the canonical command expansion implementation.
@@ -124,7 +174,7 @@ fn expand_commands(
     ctx: &CommandContext<'_>,
     extra_vars: &[(&str, &str)],
     hook_type: HookType,
-) -> anyhow::Result<Vec<(Command, String)>> {
+) -> anyhow::Result<Vec<(Command, String, HookEnv)>> {
     if commands.is_empty() {
         return Ok(Vec::new());
     }
@@ -158,8 +208,14 @@ fn expand_commands(
             .expect("HashMap<String, String> serialization should never fail");
 
         result.push((
-            Command::with_expansion(cmd.name.clone(), cmd.template.clone(), expanded_str),
+            Command {
+                timeout: cmd.timeout,
+                on_failure: cmd.on_failure,
+                background: cmd.background,
+                ..Command::with_expansion(cmd.name.clone(), cmd.template.clone(), expanded_str)
+            },
             context_json,
+            hook_env(&cmd_context),
         ));
     }
 
@@ -189,10 +245,14 @@ pub fn prepare_commands(
 
     Ok(expanded_with_json
         .into_iter()
-        .map(|(cmd, context_json)| PreparedCommand {
+        .map(|(cmd, context_json, env)| PreparedCommand {
             name: cmd.name,
             expanded: cmd.expanded,
             context_json,
+            env,
+            timeout: cmd.timeout,
+            on_failure: cmd.on_failure,
+            background: cmd.background,
         })
         .collect())
 }
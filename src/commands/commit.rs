@@ -22,6 +22,8 @@ pub struct CommitOptions<'a> {
     pub stage_mode: StageMode,
     pub warn_about_untracked: bool,
     pub show_no_squash_note: bool,
+    /// GPG-sign the commit (`git commit -S`)
+    pub sign: bool,
 }
 
 impl<'a> CommitOptions<'a> {
@@ -34,6 +36,7 @@ impl<'a> CommitOptions<'a> {
             stage_mode: StageMode::All,
             warn_about_untracked: true,
             show_no_squash_note: false,
+            sign: false,
         }
     }
 }
@@ -79,6 +82,7 @@ impl<'a> CommitGenerator<'a> {
         &self,
         show_no_squash_note: bool,
         stage_mode: StageMode,
+        sign: bool,
     ) -> anyhow::Result<()> {
         let repo = Repository::current();
 
@@ -125,7 +129,7 @@ impl<'a> CommitGenerator<'a> {
         let formatted_message = self.format_message_for_display(&commit_message);
         crate::output::print(format_with_gutter(&formatted_message, None))?;
 
-        repo.run_command(&["commit", "-m", &commit_message])
+        repo.run_command(&commit_args(&commit_message, sign))
             .context("Failed to commit")?;
 
         let commit_hash = repo
@@ -141,6 +145,15 @@ impl<'a> CommitGenerator<'a> {
     }
 }
 
+/// Arguments for `git commit` with `message`, GPG-signed when `sign` is set
+pub(crate) fn commit_args(message: &str, sign: bool) -> Vec<&str> {
+    let mut args = vec!["commit", "-m", message];
+    if sign {
+        args.push("-S");
+    }
+    args
+}
+
 /// Commit uncommitted changes with the shared commit pipeline.
 impl CommitOptions<'_> {
     pub fn commit(self) -> anyhow::Result<()> {
@@ -207,8 +220,11 @@ impl CommitOptions<'_> {
             }
         }
 
-        CommitGenerator::new(&self.ctx.config.commit_generation)
-            .commit_staged_changes(self.show_no_squash_note, self.stage_mode)
+        CommitGenerator::new(&self.ctx.config.commit_generation).commit_staged_changes(
+            self.show_no_squash_note,
+            self.stage_mode,
+            self.sign,
+        )
     }
 }
 
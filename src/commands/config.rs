@@ -4,8 +4,12 @@ use etcetera::base_strategy::{BaseStrategy, choose_base_strategy};
 use std::fmt::Write as _;
 use std::path::PathBuf;
 use worktrunk::config::WorktrunkConfig;
-use worktrunk::config::{find_unknown_project_keys, find_unknown_user_keys};
-use worktrunk::git::Repository;
+use worktrunk::config::{
+    CONFIG_VERSION, ConfigKind, Diagnostic, EnvOverrides, Rename, Severity, env_overrides,
+    explicit_config_path, get_config_path, migrate_str, repo_config_paths, selected_profile,
+    set_value, validate_file, validate_str, write_migration,
+};
+use worktrunk::git::{Repository, WorktrunkError};
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell::{Shell, scan_for_detection_details};
 use worktrunk::styling::{
@@ -126,6 +130,15 @@ pub fn handle_config_show(full: bool) -> anyhow::Result<()> {
     render_user_config(&mut show_output)?;
     show_output.push('\n');
 
+    // Render repository layers of the user config, when present
+    render_repo_config(&mut show_output)?;
+
+    // Render matching conditional sections, the selected profile, and
+    // WORKTRUNK_* overrides, when any apply
+    render_conditions(&mut show_output)?;
+    render_profile(&mut show_output)?;
+    render_env_overrides(&mut show_output)?;
+
     // Render project config if in a git repository
     render_project_config(&mut show_output)?;
     show_output.push('\n');
@@ -149,20 +162,215 @@ pub fn handle_config_show(full: bool) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Handle `wt config validate`
+pub fn handle_config_validate() -> anyhow::Result<()> {
+    let mut files = Vec::new();
+    // The file `WorktrunkConfig::load` reads, honoring `--config`
+    if let Some(user_config) = get_config_path().filter(|path| path.exists()) {
+        files.push((ConfigKind::User, user_config));
+    }
+    files.extend(
+        repo_config_paths()
+            .into_iter()
+            .map(|(path, _)| (ConfigKind::User, path)),
+    );
+    if let Ok(root) = Repository::current().worktree_root() {
+        let project_config = root.join(".config").join("wt.toml");
+        if project_config.exists() {
+            files.push((ConfigKind::Project, project_config));
+        }
+    }
+
+    if files.is_empty() {
+        output::print(info_message("No config files found"))?;
+        return Ok(());
+    }
+
+    let mut problems = 0;
+    for (kind, path) in files {
+        let diagnostics = validate_file(kind, &path);
+        if diagnostics.is_empty() {
+            output::print(success_message(cformat!(
+                "<bold>{}</> is valid",
+                format_path_for_display(&path)
+            )))?;
+        }
+        for diagnostic in &diagnostics {
+            output::print(format_diagnostic(diagnostic, diagnostic.to_string()))?;
+        }
+        problems += diagnostics.len();
+    }
+
+    if problems > 0 {
+        return Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into());
+    }
+    Ok(())
+}
+
+/// Handle `wt config migrate`
+pub fn handle_config_migrate(dry_run: bool) -> anyhow::Result<()> {
+    let Some(path) = get_config_path().filter(|path| path.exists()) else {
+        output::print(info_message("No user config file to migrate"))?;
+        return Ok(());
+    };
+    let contents = std::fs::read_to_string(&path).context("Failed to read config file")?;
+    let migration = migrate_str(&contents)?;
+    let display_path = format_path_for_display(&path);
+
+    if !migration.is_outdated() {
+        output::print(info_message(cformat!(
+            "<bold>{display_path}</> is up to date (version {})",
+            migration.from_version
+        )))?;
+        return Ok(());
+    }
+
+    if dry_run {
+        output::print(info_message(cformat!(
+            "Would migrate <bold>{display_path}</> from version {} to {CONFIG_VERSION}",
+            migration.from_version
+        )))?;
+        print_renames(&migration.renames)?;
+        return Ok(());
+    }
+
+    let backup = write_migration(&path, &migration)?;
+    output::print(success_message(cformat!(
+        "Migrated <bold>{display_path}</> from version {} to {CONFIG_VERSION}",
+        migration.from_version
+    )))?;
+    print_renames(&migration.renames)?;
+    output::print(hint_message(cformat!(
+        "Original saved to <bright-black>{}</>",
+        format_path_for_display(&backup)
+    )))?;
+    Ok(())
+}
+
+/// Handle `wt config set`
+pub fn handle_config_set(key: &str, value: &str) -> anyhow::Result<()> {
+    // The file the config is loaded from, including `WORKTRUNK_CONFIG_PATH`
+    let path = get_config_path().ok_or_else(|| {
+        anyhow::anyhow!(
+            "Cannot determine config directory. Set $HOME or $XDG_CONFIG_HOME environment variable"
+        )
+    })?;
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e).context("Failed to read config file"),
+    };
+    let updated = set_value(&contents, key, value)?;
+
+    let errors: Vec<String> = validate_str(ConfigKind::User, &path, &updated)
+        .into_iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .map(|diagnostic| diagnostic.to_string())
+        .collect();
+    if !errors.is_empty() {
+        return Err(anyhow::anyhow!(errors.join("\n")))
+            .context(format!("Not setting {key}; the config would be invalid"));
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).context("Failed to create config directory")?;
+    }
+    std::fs::write(&path, updated).context("Failed to write config file")?;
+    output::print(success_message(cformat!(
+        "Set <bold>{key}</> to <bold>{value}</> in <bold>{}</>",
+        format_path_for_display(&path)
+    )))?;
+    Ok(())
+}
+
+/// Migrate an outdated user config before a command reads it.
+///
+/// Only rewrites the file when keys need renaming; a file that's merely
+/// unversioned is left alone until `wt config migrate` is run.
+pub fn migrate_user_config_if_needed() {
+    let Some(path) = get_config_path().filter(|path| path.exists()) else {
+        return;
+    };
+    // Unreadable or invalid files are reported when the config is loaded
+    let Some(migration) = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| migrate_str(&contents).ok())
+        .filter(|migration| migration.is_outdated() && migration.renames_keys())
+    else {
+        return;
+    };
+
+    let display_path = format_path_for_display(&path);
+    let _ = match write_migration(&path, &migration) {
+        Ok(backup) => output::print(info_message(cformat!(
+            "Migrated <bold>{display_path}</> to config version {CONFIG_VERSION} (original saved to <bright-black>{}</>)",
+            format_path_for_display(&backup)
+        )))
+        .and_then(|()| print_renames(&migration.renames)),
+        Err(e) => output::print(warning_message(cformat!(
+            "Couldn't migrate <bold>{display_path}</>: {e}"
+        ))),
+    };
+}
+
+/// List a migration's renames, flagging keys left for the user to resolve
+fn print_renames(renames: &[Rename]) -> std::io::Result<()> {
+    if renames.is_empty() {
+        return Ok(());
+    }
+    let lines: Vec<String> = renames
+        .iter()
+        .map(|rename| {
+            if rename.conflict {
+                cformat!(
+                    "{} → {} <yellow>(both set; remove {} by hand)</>",
+                    rename.from,
+                    rename.to,
+                    rename.from
+                )
+            } else {
+                format!("{} → {}", rename.from, rename.to)
+            }
+        })
+        .collect();
+    output::print(format_with_gutter(&lines.join("\n"), None))
+}
+
 /// Run full diagnostic checks (CI tools, commit generation) and render to buffer
 fn render_diagnostics(out: &mut String) -> anyhow::Result<()> {
-    use super::list::ci_status::{CiPlatform, CiToolsStatus, get_platform_for_repo};
+    use super::list::ci_status::{CiPlatform, CiToolsStatus, get_configured_platform};
+    use worktrunk::config::CiProvider;
 
     writeln!(out, "{}", format_heading("DIAGNOSTICS", None))?;
 
-    // Check CI tool based on detected platform
+    let config = WorktrunkConfig::load()?;
+    let ci = config.ci();
+
+    // Check CI tool based on the configured or detected platform
     let platform = Repository::current()
         .worktree_root()
         .ok()
-        .and_then(|root| get_platform_for_repo(root.to_str()?));
+        .and_then(|root| get_configured_platform(root.to_str()?, &ci));
 
-    match platform {
-        Some(CiPlatform::GitHub) => {
+    match (ci.provider, platform) {
+        (Some(CiProvider::None), _) => {
+            writeln!(
+                out,
+                "{}",
+                info_message(cformat!(
+                    "CI status disabled (<bold>ci.provider = \"none\"</>)"
+                ))
+            )?;
+        }
+        (Some(CiProvider::Gerrit), _) => {
+            let api_url = ci.api_url.as_deref().unwrap_or_default();
+            writeln!(
+                out,
+                "{}",
+                info_message(cformat!("CI status from Gerrit at <bold>{api_url}</>"))
+            )?;
+        }
+        (_, Some(CiPlatform::GitHub)) => {
             let ci_tools = CiToolsStatus::detect(None);
             render_ci_tool_status(
                 out,
@@ -172,8 +380,8 @@ fn render_diagnostics(out: &mut String) -> anyhow::Result<()> {
                 ci_tools.gh_authenticated,
             )?;
         }
-        Some(CiPlatform::GitLab) => {
-            let ci_tools = CiToolsStatus::detect(None);
+        (_, Some(CiPlatform::GitLab)) => {
+            let ci_tools = CiToolsStatus::detect(ci.api_host());
             render_ci_tool_status(
                 out,
                 "glab",
@@ -182,7 +390,7 @@ fn render_diagnostics(out: &mut String) -> anyhow::Result<()> {
                 ci_tools.glab_authenticated,
             )?;
         }
-        None => {
+        (_, None) => {
             writeln!(
                 out,
                 "{}",
@@ -192,7 +400,6 @@ fn render_diagnostics(out: &mut String) -> anyhow::Result<()> {
     }
 
     // Test commit generation
-    let config = WorktrunkConfig::load()?;
     let commit_config = &config.commit_generation;
 
     if !commit_config.is_configured() {
@@ -268,8 +475,8 @@ fn render_user_config(out: &mut String) -> anyhow::Result<()> {
         return Ok(());
     }
 
-    // Check for unknown keys and warn
-    warn_unknown_keys(out, &find_unknown_user_keys(&contents))?;
+    // Report problems (unknown keys, wrong types, invalid templates)
+    write_diagnostics(out, ConfigKind::User, &config_path, &contents)?;
 
     // Display TOML with syntax highlighting (gutter at column 0)
     write!(out, "{}", format_toml(&contents))?;
@@ -277,14 +484,115 @@ fn render_user_config(out: &mut String) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Write warnings for any unknown config keys
-fn warn_unknown_keys(out: &mut String, unknown_keys: &[String]) -> anyhow::Result<()> {
-    for key in unknown_keys {
+fn render_repo_config(out: &mut String) -> anyhow::Result<()> {
+    for (config_path, _) in repo_config_paths() {
         writeln!(
             out,
             "{}",
-            warning_message(cformat!("Unknown key <bold>{key}</> will be ignored"))
+            format_heading("REPO CONFIG", Some(&format_path_for_display(&config_path)))
         )?;
+        let contents =
+            std::fs::read_to_string(&config_path).context("Failed to read config file")?;
+        if contents.trim().is_empty() {
+            writeln!(out, "{}", hint_message("Empty file"))?;
+        } else {
+            write_diagnostics(out, ConfigKind::User, &config_path, &contents)?;
+            write!(out, "{}", format_toml(&contents))?;
+        }
+        out.push('\n');
+    }
+    Ok(())
+}
+
+fn render_conditions(out: &mut String) -> anyhow::Result<()> {
+    // Load errors are already reported against the files above
+    let Ok(config) = WorktrunkConfig::load() else {
+        return Ok(());
+    };
+    if config.active_conditions.is_empty() {
+        return Ok(());
+    }
+    writeln!(out, "{}", format_heading("CONDITIONAL CONFIG", None))?;
+    for pattern in &config.active_conditions {
+        writeln!(
+            out,
+            "{}",
+            info_message(cformat!(
+                "Remote matches <bold>[if.{pattern:?}]</>; its settings override the config files above"
+            ))
+        )?;
+    }
+    out.push('\n');
+    Ok(())
+}
+
+fn render_profile(out: &mut String) -> anyhow::Result<()> {
+    let Some(name) = selected_profile() else {
+        return Ok(());
+    };
+    writeln!(out, "{}", format_heading("PROFILE", Some(&name)))?;
+    writeln!(
+        out,
+        "{}",
+        info_message(cformat!(
+            "Settings in <bold>[profiles.{name}]</> override the config files above"
+        ))
+    )?;
+    out.push('\n');
+    Ok(())
+}
+
+fn render_env_overrides(out: &mut String) -> anyhow::Result<()> {
+    let EnvOverrides {
+        overrides,
+        unrecognized,
+    } = env_overrides();
+    if overrides.is_empty() && unrecognized.is_empty() {
+        return Ok(());
+    }
+    writeln!(out, "{}", format_heading("ENVIRONMENT", None))?;
+    for var in &unrecognized {
+        writeln!(
+            out,
+            "{}",
+            warning_message(cformat!(
+                "<bold>{var}</> doesn't match a config key; ignored"
+            ))
+        )?;
+    }
+    if !overrides.is_empty() {
+        let assignments: Vec<String> = overrides
+            .iter()
+            .map(|found| format!("{}={}", found.var, found.value))
+            .collect();
+        writeln!(out, "{}", format_bash_with_gutter(&assignments.join("\n")))?;
+    }
+    out.push('\n');
+    Ok(())
+}
+
+/// Style a diagnostic as an error or warning line
+fn format_diagnostic(diagnostic: &Diagnostic, text: String) -> String {
+    match diagnostic.severity {
+        Severity::Error => error_message(text).to_string(),
+        Severity::Warning => warning_message(text).to_string(),
+    }
+}
+
+/// Write problems found in a config file shown under its own heading
+fn write_diagnostics(
+    out: &mut String,
+    kind: ConfigKind,
+    path: &std::path::Path,
+    contents: &str,
+) -> anyhow::Result<()> {
+    for diagnostic in validate_str(kind, path, contents) {
+        // The heading already names the file, so only the line is useful here
+        let text = match diagnostic.line {
+            Some(line) => cformat!("{} <dim>(line {line})</>", diagnostic.description()),
+            None => diagnostic.description(),
+        };
+        writeln!(out, "{}", format_diagnostic(&diagnostic, text))?;
     }
     Ok(())
 }
@@ -331,8 +639,8 @@ fn render_project_config(out: &mut String) -> anyhow::Result<()> {
         return Ok(());
     }
 
-    // Check for unknown keys and warn
-    warn_unknown_keys(out, &find_unknown_project_keys(&contents))?;
+    // Report problems (unknown keys, wrong types, invalid templates)
+    write_diagnostics(out, ConfigKind::Project, &config_path, &contents)?;
 
     // Display TOML with syntax highlighting (gutter at column 0)
     write!(out, "{}", format_toml(&contents))?;
@@ -655,6 +963,10 @@ fn get_user_config_path() -> Option<PathBuf> {
 }
 
 fn require_user_config_path() -> anyhow::Result<PathBuf> {
+    // `--config` names the file that's actually loaded
+    if let Some(path) = explicit_config_path() {
+        return Ok(path);
+    }
     get_user_config_path().ok_or_else(|| {
         anyhow::anyhow!(
             "Cannot determine config directory. Set $HOME or $XDG_CONFIG_HOME environment variable"
@@ -662,7 +974,7 @@ fn require_user_config_path() -> anyhow::Result<PathBuf> {
     })
 }
 
-/// Clear all log files from the wt-logs directory
+/// Clear all log files (and background hook status files) from the wt-logs directory
 fn clear_logs(repo: &Repository) -> anyhow::Result<usize> {
     let git_common_dir = repo.git_common_dir()?;
     let log_dir = git_common_dir.join("wt-logs");
@@ -678,6 +990,8 @@ fn clear_logs(repo: &Repository) -> anyhow::Result<usize> {
         if path.is_file() && path.extension().is_some_and(|ext| ext == "log") {
             std::fs::remove_file(&path)?;
             cleared += 1;
+        } else if path.is_file() && path.extension().is_some_and(|ext| ext == "status") {
+            std::fs::remove_file(&path)?;
         }
     }
 
@@ -812,7 +1126,10 @@ pub fn handle_state_get(key: &str, refresh: bool, branch: Option<String>) -> any
             }
 
             let has_upstream = repo.upstream_branch(&branch_name).ok().flatten().is_some();
-            let ci_status = PrStatus::detect(&branch_name, &head, repo_root, has_upstream)
+            let ci = WorktrunkConfig::load()
+                .context("Failed to load config")?
+                .ci();
+            let ci_status = PrStatus::detect(&branch_name, &head, repo_root, has_upstream, &ci)
                 .map_or(super::list::ci_status::CiStatus::NoCI, |s| s.ci_status);
             let status_str: &'static str = ci_status.into();
             crate::output::stdout(status_str)?;
@@ -860,15 +1177,7 @@ pub fn handle_state_set(key: &str, value: String, branch: Option<String>) -> any
                 None => repo.require_current_branch("set marker for current branch")?,
             };
 
-            // Store as JSON with timestamp
-            let now = get_now();
-            let json = serde_json::json!({
-                "marker": value,
-                "set_at": now
-            });
-
-            let config_key = format!("worktrunk.state.{branch_name}.marker");
-            repo.run_command(&["config", &config_key, &json.to_string()])?;
+            repo.set_branch_state(&branch_name, "marker", Some(&marker_json(&value)))?;
 
             crate::output::print(success_message(cformat!(
                 "Set marker for <bold>{branch_name}</> to <bold>{value}</>"
@@ -882,6 +1191,39 @@ pub fn handle_state_set(key: &str, value: String, branch: Option<String>) -> any
     Ok(())
 }
 
+/// A marker as stored in git config: JSON with the time it was set
+fn marker_json(marker: &str) -> String {
+    serde_json::json!({
+        "marker": marker,
+        "set_at": get_now()
+    })
+    .to_string()
+}
+
+/// Handle `wt note`: set, show, or clear a branch's note (its marker)
+pub fn handle_note(branch: &str, text: Option<String>, clear: bool) -> anyhow::Result<()> {
+    let repo = Repository::current();
+
+    if clear {
+        if repo.set_branch_state(branch, "marker", None)? {
+            crate::output::print(success_message(cformat!(
+                "Cleared note for <bold>{branch}</>"
+            )))?;
+        } else {
+            crate::output::print(info_message(cformat!("No note set for <bold>{branch}</>")))?;
+        }
+    } else if let Some(text) = text {
+        repo.set_branch_state(branch, "marker", Some(&marker_json(&text)))?;
+        crate::output::print(success_message(cformat!(
+            "Set note for <bold>{branch}</> to <bold>{text}</>"
+        )))?;
+    } else {
+        let note = repo.branch_keyed_marker(branch);
+        crate::output::stdout(note.unwrap_or_default())?;
+    }
+    Ok(())
+}
+
 /// Handle the state clear command
 pub fn handle_state_clear(key: &str, branch: Option<String>, all: bool) -> anyhow::Result<()> {
     let repo = Repository::current();
@@ -938,17 +1280,7 @@ pub fn handle_state_clear(key: &str, branch: Option<String>, all: bool) -> anyho
         }
         "marker" => {
             if all {
-                let output = repo
-                    .run_command(&["config", "--get-regexp", r"^worktrunk\.state\..+\.marker$"])
-                    .unwrap_or_default();
-
-                let mut cleared_count = 0;
-                for line in output.lines() {
-                    if let Some(config_key) = line.split_whitespace().next() {
-                        repo.run_command(&["config", "--unset", config_key])?;
-                        cleared_count += 1;
-                    }
-                }
+                let cleared_count = repo.clear_all_branch_state("marker")?;
 
                 if cleared_count == 0 {
                     crate::output::print(info_message("No markers to clear"))?;
@@ -964,11 +1296,7 @@ pub fn handle_state_clear(key: &str, branch: Option<String>, all: bool) -> anyho
                     None => repo.require_current_branch("clear marker for current branch")?,
                 };
 
-                let config_key = format!("worktrunk.state.{branch_name}.marker");
-                if repo
-                    .run_command(&["config", "--unset", &config_key])
-                    .is_ok()
-                {
+                if repo.set_branch_state(&branch_name, "marker", None)? {
                     crate::output::print(success_message(cformat!(
                         "Cleared marker for <bold>{branch_name}</>"
                     )))?;
@@ -1018,15 +1346,14 @@ pub fn handle_state_clear_all() -> anyhow::Result<()> {
         cleared_any = true;
     }
 
+    // Clear wt select's remembered preview
+    if repo.clear_select_preferences().unwrap_or(false) {
+        cleared_any = true;
+    }
+
     // Clear all markers
-    let markers_output = repo
-        .run_command(&["config", "--get-regexp", r"^worktrunk\.state\..+\.marker$"])
-        .unwrap_or_default();
-    for line in markers_output.lines() {
-        if let Some(config_key) = line.split_whitespace().next() {
-            let _ = repo.run_command(&["config", "--unset", config_key]);
-            cleared_any = true;
-        }
+    if repo.clear_all_branch_state("marker").unwrap_or(0) > 0 {
+        cleared_any = true;
     }
 
     // Clear all CI status cache
@@ -1256,23 +1583,9 @@ struct MarkerEntry {
 
 /// Get all branch markers from git config with timestamps
 fn get_all_markers(repo: &Repository) -> Vec<MarkerEntry> {
-    let output = repo
-        .run_command(&["config", "--get-regexp", r"^worktrunk\.state\..+\.marker$"])
-        .unwrap_or_default();
-
     let mut markers = Vec::new();
-    for line in output.lines() {
-        // Format: "worktrunk.state.<branch>.marker json_value"
-        let Some((key, value)) = line.split_once(' ') else {
-            continue;
-        };
-        let Some(branch) = key
-            .strip_prefix("worktrunk.state.")
-            .and_then(|s| s.strip_suffix(".marker"))
-        else {
-            continue;
-        };
-        let Ok(parsed) = serde_json::from_str::<serde_json::Value>(value) else {
+    for (branch, value) in repo.all_branch_state("marker") {
+        let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&value) else {
             continue; // Skip invalid JSON
         };
         let Some(marker) = parsed.get("marker").and_then(|v| v.as_str()) else {
@@ -1280,7 +1593,7 @@ fn get_all_markers(repo: &Repository) -> Vec<MarkerEntry> {
         };
         let set_at = parsed.get("set_at").and_then(|v| v.as_u64()).unwrap_or(0);
         markers.push(MarkerEntry {
-            branch: branch.to_string(),
+            branch,
             marker: marker.to_string(),
             set_at,
         });
@@ -1389,33 +1702,36 @@ mod tests {
         assert_eq!(comment_out_config(input), expected);
     }
 
-    // ==================== warn_unknown_keys tests ====================
+    // ==================== write_diagnostics tests ====================
 
-    #[test]
-    fn test_warn_unknown_keys_empty() {
+    fn diagnostics_for(kind: ConfigKind, contents: &str) -> String {
         let mut out = String::new();
-        warn_unknown_keys(&mut out, &[]).unwrap();
-        assert!(out.is_empty());
+        write_diagnostics(&mut out, kind, std::path::Path::new("c.toml"), contents).unwrap();
+        out
     }
 
     #[test]
-    fn test_warn_unknown_keys_single() {
-        let mut out = String::new();
-        warn_unknown_keys(&mut out, &["unknown-key".to_string()]).unwrap();
-        assert!(out.contains("unknown-key"));
-        assert!(out.contains("Unknown key"));
+    fn test_write_diagnostics_empty() {
+        assert!(diagnostics_for(ConfigKind::User, "[merge]\nsquash = false\n").is_empty());
     }
 
     #[test]
-    fn test_warn_unknown_keys_multiple() {
-        let mut out = String::new();
-        warn_unknown_keys(&mut out, &["key1".to_string(), "key2".to_string()]).unwrap();
+    fn test_write_diagnostics_unknown_keys() {
+        let out = diagnostics_for(ConfigKind::User, "key1 = 1\n\n[merge]\nkey2 = 2\n");
         assert!(out.contains("key1"));
-        assert!(out.contains("key2"));
+        assert!(out.contains("merge.key2"));
+        assert!(out.contains("line 4"));
         // Should have two separate warning lines
         assert_eq!(out.matches("Unknown key").count(), 2);
     }
 
+    #[test]
+    fn test_write_diagnostics_suggestion() {
+        let out = diagnostics_for(ConfigKind::Project, "[pre-marge]\ntest = \"make\"\n");
+        assert!(out.contains("did you mean"), "{out}");
+        assert!(out.contains("pre-merge"), "{out}");
+    }
+
     // ==================== render_ci_tool_status tests ====================
 
     #[test]
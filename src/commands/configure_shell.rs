@@ -1,3 +1,4 @@
+use color_print::cformat;
 use std::fs::{self, OpenOptions};
 use std::io::{self, BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
@@ -5,8 +6,8 @@ use worktrunk::path::format_path_for_display;
 use worktrunk::shell::{self, Shell};
 use worktrunk::shell_exec::ShellConfig;
 use worktrunk::styling::{
-    INFO_SYMBOL, PROMPT_SYMBOL, SUCCESS_SYMBOL, format_bash_with_gutter, format_with_gutter,
-    hint_message, warning_message,
+    INFO_SYMBOL, PROMPT_SYMBOL, SUCCESS_SYMBOL, Symbol, format_bash_with_gutter,
+    format_with_gutter, hint_message, warning_message,
 };
 
 use crate::output;
@@ -67,7 +68,7 @@ impl UninstallAction {
         }
     }
 
-    pub fn symbol(&self) -> &'static str {
+    pub fn symbol(&self) -> Symbol {
         match self {
             UninstallAction::Removed => SUCCESS_SYMBOL,
             UninstallAction::WouldRemove => INFO_SYMBOL,
@@ -96,7 +97,7 @@ impl ConfigAction {
     }
 
     /// Returns the appropriate symbol for this action
-    pub fn symbol(&self) -> &'static str {
+    pub fn symbol(&self) -> Symbol {
         match self {
             ConfigAction::Added | ConfigAction::Created => SUCCESS_SYMBOL,
             ConfigAction::AlreadyExists => INFO_SYMBOL,
@@ -110,6 +111,15 @@ pub fn handle_configure_shell(
     skip_confirmation: bool,
     cmd: String,
 ) -> Result<ScanResult, String> {
+    // Nushell can't eval generated code from a config line, and a saved copy of
+    // the wrapper would go stale on upgrade, so leave saving it to the user
+    if shell_filter == Some(Shell::Nushell) {
+        return Err(cformat!(
+            "Can't install nushell integration automatically; save it to autoload with <bold>{}</>",
+            Shell::Nushell.config_line(&cmd)
+        ));
+    }
+
     // First, do a dry-run to see what would be changed
     let preview = scan_shell_configs(shell_filter, true, &cmd)?;
 
@@ -560,7 +570,10 @@ fn prompt_for_confirmation(
 }
 
 /// Prompt user for yes/no confirmation, returns true if user confirms
-fn prompt_yes_no() -> Result<bool, String> {
+pub(super) fn prompt_yes_no() -> Result<bool, String> {
+    if crate::commands::command_approval::assume_yes() {
+        return Ok(true);
+    }
     use anstyle::Style;
     use std::io::Write;
     use worktrunk::styling::{PROMPT_SYMBOL, eprint};
@@ -704,8 +717,8 @@ fn scan_for_uninstall(
             .config_paths(cmd)
             .map_err(|e| format!("Failed to get config paths for {}: {}", shell, e))?;
 
-        // For Fish, delete entire {cmd}.fish file
-        if matches!(shell, Shell::Fish) {
+        // For Fish, delete entire {cmd}.fish file (and likewise nushell's {cmd}.nu)
+        if matches!(shell, Shell::Fish | Shell::Nushell) {
             if let Some(fish_path) = paths.first() {
                 if fish_path.exists() {
                     if dry_run {
@@ -31,7 +31,8 @@ impl CommandEnv {
     /// Used in error messages when the environment can't be loaded.
     pub fn for_action(action: &str) -> anyhow::Result<Self> {
         let repo = Repository::current();
-        let worktree_path = std::env::current_dir().context("Failed to get current directory")?;
+        let worktree_path =
+            worktrunk::git::working_dir().context("Failed to get current directory")?;
         let branch = repo.require_current_branch(action)?;
         let config = WorktrunkConfig::load().context("Failed to load config")?;
         let repo_root = repo
@@ -53,7 +54,8 @@ impl CommandEnv {
     /// such as running hooks (where `{{ branch }}` expands to "HEAD" if detached).
     pub fn for_action_branchless() -> anyhow::Result<Self> {
         let repo = Repository::current();
-        let worktree_path = std::env::current_dir().context("Failed to get current directory")?;
+        let worktree_path =
+            worktrunk::git::working_dir().context("Failed to get current directory")?;
         // Propagate git errors (broken repo, missing git) but allow None for detached HEAD
         let branch = repo
             .current_branch()
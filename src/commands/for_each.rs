@@ -58,9 +58,17 @@ pub fn step_for_each(args: Vec<String>) -> anyhow::Result<()> {
     // Get repo root for context
     let repo_root = repo.worktree_base()?;
 
-    for wt in &worktrees {
+    for (index, wt) in worktrees.iter().enumerate() {
         let display_name = worktree_display_name(wt, &repo, &config);
-        output::print(progress_message(format!("Running in {display_name}...")))?;
+        // Count worktrees so a long run shows how far along it is
+        let position = if total > 1 {
+            format!(" ({}/{total})", index + 1)
+        } else {
+            String::new()
+        };
+        output::print(progress_message(format!(
+            "Running in {display_name}{position}..."
+        )))?;
 
         // Open repository at worktree path to get worktree-specific context (commit, etc.)
         let wt_repo = Repository::at(&wt.path);
@@ -0,0 +1,284 @@
+//! `wt history` and `wt undo`: read and reverse the repository's journal.
+//!
+//! The journal itself (what's recorded, and the backups taken before a
+//! worktree is removed) lives in `worktrunk::journal`.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::journal::{self, Action, Entry};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    hint_message, info_message, success_message, suggest_command, warning_message,
+};
+
+use crate::display::format_relative_time_short;
+
+/// Handle `wt history`
+pub fn handle_history() -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let entries = journal::read(&repo)?;
+    if entries.is_empty() {
+        crate::output::print(info_message("No recorded actions"))?;
+        return Ok(());
+    }
+
+    let undone = undone_entries(&entries);
+    let width = entries
+        .last()
+        .map_or(1, |(number, _)| number.to_string().len());
+    let lines: Vec<String> = entries
+        .iter()
+        .rev()
+        .map(|(number, entry)| {
+            let age = format_relative_time_short(entry.ts as i64);
+            let mut line = cformat!(
+                "<dim>{number:>width$}</>  <bright-black>{age:>3}</>  {}",
+                describe(&entry.action)
+            );
+            if undone.contains(number) {
+                line.push_str(&cformat!(" <bright-black>(undone)</>"));
+            }
+            line
+        })
+        .collect();
+    crate::output::stdout(lines.join("\n"))?;
+    Ok(())
+}
+
+/// Handle `wt undo [<entry>]`
+pub fn handle_undo(entry: Option<usize>) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let entries = journal::read(&repo)?;
+    let undone = undone_entries(&entries);
+
+    let (number, action) = match entry {
+        Some(number) => {
+            let Some((_, entry)) = entries.iter().find(|(n, _)| *n == number) else {
+                return Err(GitError::Other {
+                    message: cformat!("No entry <bold>{number}</> in the history"),
+                }
+                .into());
+            };
+            if undone.contains(&number) {
+                return Err(GitError::Other {
+                    message: cformat!("Entry <bold>{number}</> is already undone"),
+                }
+                .into());
+            }
+            if !entry.action.is_reversible() {
+                return Err(GitError::Other {
+                    message: cformat!(
+                        "Entry <bold>{number}</> can't be undone: {}",
+                        describe(&entry.action)
+                    ),
+                }
+                .into());
+            }
+            (number, &entry.action)
+        }
+        None => {
+            let Some((number, entry)) = entries
+                .iter()
+                .rev()
+                .find(|(n, entry)| entry.action.is_reversible() && !undone.contains(n))
+            else {
+                crate::output::print(info_message("Nothing to undo"))?;
+                return Ok(());
+            };
+            (*number, &entry.action)
+        }
+    };
+
+    match action {
+        Action::WorktreeRemoved {
+            path,
+            branch,
+            sha: Some(sha),
+            bundle,
+            ..
+        } => restore_worktree(&repo, path, branch.as_deref(), sha, bundle.as_deref())?,
+        Action::BranchDeleted { branch, sha } => restore_branch(&repo, branch, sha)?,
+        _ => unreachable!("only reversible entries are undone"),
+    }
+    journal::record(&repo, Action::Undone { entry: number });
+    Ok(())
+}
+
+/// Entries that a later `undone` entry names
+fn undone_entries(entries: &[(usize, Entry)]) -> HashSet<usize> {
+    entries
+        .iter()
+        .filter_map(|(_, entry)| match entry.action {
+            Action::Undone { entry } => Some(entry),
+            _ => None,
+        })
+        .collect()
+}
+
+/// One line describing an action, in the words the command used when it ran
+fn describe(action: &Action) -> String {
+    match action {
+        Action::WorktreeCreated {
+            path,
+            branch,
+            created_branch: true,
+        } => cformat!(
+            "Created <bold>{branch}</> worktree & branch @ <bold>{}</>",
+            format_path_for_display(path)
+        ),
+        Action::WorktreeCreated { path, branch, .. } => cformat!(
+            "Created <bold>{branch}</> worktree @ <bold>{}</>",
+            format_path_for_display(path)
+        ),
+        Action::WorktreeRemoved {
+            path,
+            branch: Some(branch),
+            branch_deleted,
+            ..
+        } => {
+            let what = if *branch_deleted {
+                "worktree & branch"
+            } else {
+                "worktree"
+            };
+            cformat!(
+                "Removed <bold>{branch}</> {what} @ <bold>{}</>",
+                format_path_for_display(path)
+            )
+        }
+        Action::WorktreeRemoved { path, .. } => cformat!(
+            "Removed detached worktree @ <bold>{}</>",
+            format_path_for_display(path)
+        ),
+        Action::BranchDeleted { branch, sha } => {
+            cformat!("Removed branch <bold>{branch}</> @ <dim>{}</>", short(sha))
+        }
+        Action::Merged {
+            branch,
+            target,
+            sha,
+        } => cformat!(
+            "Merged <bold>{branch}</> to <bold>{target}</> @ <dim>{}</>",
+            short(sha)
+        ),
+        Action::Undone { entry } => cformat!("Undid <bold>{entry}</>"),
+    }
+}
+
+fn short(sha: &str) -> &str {
+    &sha[..sha.len().min(7)]
+}
+
+/// Add the removed worktree back at its old path, on its branch (recreated if
+/// it was deleted too). A branch that has since moved off the removed commit
+/// isn't checked out; the worktree is restored detached at that commit.
+fn restore_worktree(
+    repo: &Repository,
+    path: &Path,
+    branch: Option<&str>,
+    sha: &str,
+    bundle: Option<&Path>,
+) -> anyhow::Result<()> {
+    if path.exists() {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Can't restore worktree; <bold>{}</> already exists",
+                format_path_for_display(path)
+            ),
+        }
+        .into());
+    }
+    // The commits may have been garbage-collected since; the bundle has them
+    if let Some(bundle) = bundle.filter(|bundle| bundle.exists()) {
+        repo.run_command(&["bundle", "unbundle", &bundle.to_string_lossy()])?;
+    }
+    if !repo.run_command_check(&["cat-file", "-e", &format!("{sha}^{{commit}}")])? {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Can't restore worktree; commit <dim>{}</> no longer exists",
+                short(sha)
+            ),
+        }
+        .into());
+    }
+
+    let path_str = path.to_string_lossy();
+    let branch_moved = match branch {
+        Some(branch) if repo.local_branch_exists(branch)? => !repo.is_ancestor(sha, branch)?,
+        _ => false,
+    };
+    let branch = if branch_moved {
+        crate::output::print(warning_message(cformat!(
+            "<bold>{}</> has moved off <dim>{}</>; restoring the worktree detached at that commit",
+            branch.unwrap_or_default(),
+            short(sha)
+        )))?;
+        None
+    } else {
+        branch
+    };
+    let recreated_branch = match branch {
+        Some(branch) if repo.local_branch_exists(branch)? => {
+            repo.run_command(&["worktree", "add", &path_str, branch])?;
+            false
+        }
+        Some(branch) => {
+            repo.run_command(&["worktree", "add", "-b", branch, &path_str, sha])?;
+            true
+        }
+        None => {
+            repo.run_command(&["worktree", "add", "--detach", &path_str, sha])?;
+            false
+        }
+    };
+
+    let path_display = format_path_for_display(path);
+    let what = if recreated_branch {
+        "worktree & branch"
+    } else {
+        "worktree"
+    };
+    match branch {
+        Some(branch) => {
+            crate::output::print(success_message(cformat!(
+                "Restored <bold>{branch}</> {what} @ <bold>{path_display}</>"
+            )))?;
+            let cmd = suggest_command("switch", &[branch], &[]);
+            crate::output::print(hint_message(cformat!(
+                "To switch to it, run <bright-black>{cmd}</>"
+            )))?;
+        }
+        None => crate::output::print(success_message(cformat!(
+            "Restored detached worktree @ <bold>{path_display}</>"
+        )))?,
+    }
+    Ok(())
+}
+
+/// Recreate a deleted branch at the commit it pointed to
+fn restore_branch(repo: &Repository, branch: &str, sha: &str) -> anyhow::Result<()> {
+    if repo.local_branch_exists(branch)? {
+        return Err(GitError::Other {
+            message: cformat!("Can't restore branch; <bold>{branch}</> already exists"),
+        }
+        .into());
+    }
+    if !repo.run_command_check(&["cat-file", "-e", &format!("{sha}^{{commit}}")])? {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Can't restore branch <bold>{branch}</>; commit <dim>{}</> no longer exists",
+                short(sha)
+            ),
+        }
+        .into());
+    }
+    repo.run_command(&["branch", branch, sha])?;
+    crate::output::print(success_message(cformat!(
+        "Restored branch <bold>{branch}</> @ <dim>{}</>",
+        short(sha)
+    )))?;
+    Ok(())
+}
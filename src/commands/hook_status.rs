@@ -0,0 +1,291 @@
+//! Tracking for background hook commands.
+//!
+//! Each background hook command gets a `.status` file next to its log in
+//! `.git/wt-logs/`. Worktrunk writes what was started; the command's shell then
+//! appends its pid as it starts and its exit code as it finishes. `wt hook status`
+//! lists these, and `wt list` flags worktrees with commands still running.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{format_heading, format_with_gutter};
+use worktrunk::utils::get_now;
+
+use crate::cli::OutputFormat;
+use crate::display::format_relative_time_short;
+use crate::help_pager::show_help_in_pager;
+
+/// How long a command may go without recording its pid before it's presumed lost
+const STARTUP_GRACE_SECS: u64 = 30;
+
+/// Where a background hook command has got to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunState {
+    Running,
+    Succeeded,
+    Failed(i32),
+    /// Exited without recording a status (killed, or never started)
+    Lost,
+}
+
+impl RunState {
+    fn as_json_str(self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Succeeded => "succeeded",
+            Self::Failed(_) => "failed",
+            Self::Lost => "lost",
+        }
+    }
+}
+
+/// One background hook command, read from its `.status` file
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundHook {
+    pub hook: String,
+    /// Source-qualified name, e.g. `project:install`
+    pub name: String,
+    pub branch: String,
+    pub worktree: PathBuf,
+    pub started_at: u64,
+    pub state: RunState,
+    pub log: PathBuf,
+}
+
+/// Status file for the command logging to `log_path`
+pub fn status_path(log_path: &Path) -> PathBuf {
+    log_path.with_extension("status")
+}
+
+/// Record that a background hook command is about to start
+pub fn begin(
+    status_path: &Path,
+    hook: &str,
+    name: &str,
+    branch: &str,
+    worktree: &Path,
+) -> std::io::Result<()> {
+    let contents = format!(
+        "hook {hook}\nname {name}\nbranch {branch}\nworktree {}\nstarted {}\n",
+        worktree.display(),
+        get_now()
+    );
+    fs::write(status_path, contents)
+}
+
+/// Wrap a command so it appends its pid and exit code to `status_path`
+pub fn track(command: &str, status_path: &Path) -> String {
+    let path = shell_escape::escape(status_path.to_string_lossy());
+    format!(
+        "echo \"pid $$\" >> {path}; {{ {command}{} }}; wt_status=$?; \
+echo \"exit $wt_status\" >> {path}; exit $wt_status",
+        super::process::posix_command_separator(command)
+    )
+}
+
+/// Parse a status file's contents; `None` if it doesn't say what was started
+fn parse(contents: &str, log: PathBuf, is_alive: impl Fn(u32) -> bool) -> Option<BackgroundHook> {
+    let mut fields = std::collections::HashMap::new();
+    for line in contents.lines() {
+        if let Some((key, value)) = line.split_once(' ') {
+            fields.insert(key, value);
+        }
+    }
+    let started_at = fields.get("started")?.parse().ok()?;
+    let pid = fields.get("pid").and_then(|pid| pid.parse().ok());
+    let state = match fields.get("exit").and_then(|code| code.parse().ok()) {
+        Some(0) => RunState::Succeeded,
+        Some(code) => RunState::Failed(code),
+        None => match pid {
+            Some(pid) if is_alive(pid) => RunState::Running,
+            Some(_) => RunState::Lost,
+            None if get_now().saturating_sub(started_at) < STARTUP_GRACE_SECS => RunState::Running,
+            None => RunState::Lost,
+        },
+    };
+    Some(BackgroundHook {
+        hook: fields.get("hook")?.to_string(),
+        name: fields.get("name")?.to_string(),
+        branch: fields.get("branch")?.to_string(),
+        worktree: PathBuf::from(fields.get("worktree")?),
+        started_at,
+        state,
+        log,
+    })
+}
+
+#[cfg(unix)]
+fn is_alive(pid: u32) -> bool {
+    use nix::errno::Errno;
+    match nix::sys::signal::kill(nix::unistd::Pid::from_raw(pid as i32), None) {
+        Ok(()) | Err(Errno::EPERM) => true,
+        Err(_) => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn is_alive(_pid: u32) -> bool {
+    // No cheap liveness check; trust the missing exit code
+    true
+}
+
+/// Every tracked background hook command, most recently started first
+pub fn load_all(repo: &Repository) -> anyhow::Result<Vec<BackgroundHook>> {
+    let log_dir = repo.git_common_dir()?.join("wt-logs");
+    let Ok(entries) = fs::read_dir(&log_dir) else {
+        return Ok(Vec::new());
+    };
+    let mut hooks: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "status"))
+        .filter_map(|path| {
+            let contents = fs::read_to_string(&path).ok()?;
+            parse(&contents, path.with_extension("log"), is_alive)
+        })
+        .collect();
+    hooks.sort_by(|a, b| {
+        b.started_at
+            .cmp(&a.started_at)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    Ok(hooks)
+}
+
+/// Number of background hook commands still running in `worktree`
+pub fn running_in(repo: &Repository, worktree: &Path) -> usize {
+    let worktree = dunce::canonicalize(worktree).unwrap_or_else(|_| worktree.to_path_buf());
+    load_all(repo)
+        .unwrap_or_default()
+        .iter()
+        .filter(|hook| hook.state == RunState::Running)
+        .filter(|hook| {
+            dunce::canonicalize(&hook.worktree).unwrap_or_else(|_| hook.worktree.clone())
+                == worktree
+        })
+        .count()
+}
+
+/// Handle `wt hook status`
+pub fn handle_hook_status(format: OutputFormat) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let hooks = load_all(&repo)?;
+
+    if let OutputFormat::Json = format {
+        let json: Vec<_> = hooks
+            .iter()
+            .map(|hook| {
+                serde_json::json!({
+                    "hook": hook.hook,
+                    "name": hook.name,
+                    "branch": hook.branch,
+                    "worktree": hook.worktree,
+                    "state": hook.state.as_json_str(),
+                    "exit_code": match hook.state {
+                        RunState::Failed(code) => Some(code),
+                        RunState::Succeeded => Some(0),
+                        _ => None,
+                    },
+                    "started_at": hook.started_at,
+                    "log": hook.log,
+                })
+            })
+            .collect();
+        crate::output::stdout(serde_json::to_string_pretty(&json)?)?;
+        return Ok(());
+    }
+
+    let mut out = String::new();
+    let log_dir = repo.git_common_dir()?.join("wt-logs");
+    writeln!(
+        out,
+        "{}",
+        format_heading(
+            "BACKGROUND HOOKS",
+            Some(&format!("@ {}", format_path_for_display(&log_dir)))
+        )
+    )?;
+    if hooks.is_empty() {
+        write!(out, "{}", format_with_gutter("(none)", None))?;
+    } else {
+        let mut table = String::from("| Branch | Hook | Command | State | Started |\n");
+        table.push_str("|--------|------|---------|-------|---------|\n");
+        for hook in &hooks {
+            let state = match hook.state {
+                RunState::Running => cformat!("<cyan>running</>"),
+                RunState::Succeeded => cformat!("<green>succeeded</>"),
+                RunState::Failed(code) => cformat!("<red>failed (exit {code})</>"),
+                RunState::Lost => cformat!("<yellow>lost</>"),
+            };
+            table.push_str(&format!(
+                "| {} | {} | {} | {state} | {} |\n",
+                hook.branch,
+                hook.hook,
+                hook.name,
+                format_relative_time_short(hook.started_at as i64)
+            ));
+        }
+        let rendered = crate::md_help::render_markdown_table(&table);
+        write!(out, "{}", rendered.trim_end())?;
+    }
+
+    // Display through pager (fall back to stderr if pager unavailable)
+    if show_help_in_pager(&out).is_err() {
+        worktrunk::styling::eprintln!("{}", out);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STARTED: &str = "hook post-create\nname project:install\nbranch feature\nworktree /repo.feature\nstarted 100\n";
+
+    fn state(contents: &str, alive: bool) -> RunState {
+        parse(contents, PathBuf::from("x.log"), |_| alive)
+            .unwrap()
+            .state
+    }
+
+    #[test]
+    fn test_parse_status() {
+        let hook = parse(
+            &format!("{STARTED}pid 42\n"),
+            PathBuf::from("x.log"),
+            |_| true,
+        )
+        .unwrap();
+        assert_eq!(hook.hook, "post-create");
+        assert_eq!(hook.name, "project:install");
+        assert_eq!(hook.branch, "feature");
+        assert_eq!(hook.worktree, PathBuf::from("/repo.feature"));
+        assert_eq!(hook.started_at, 100);
+        assert_eq!(hook.state, RunState::Running);
+
+        assert_eq!(state(&format!("{STARTED}pid 42\n"), false), RunState::Lost);
+        assert_eq!(
+            state(&format!("{STARTED}pid 42\nexit 0\n"), false),
+            RunState::Succeeded
+        );
+        assert_eq!(
+            state(&format!("{STARTED}pid 42\nexit 3\n"), false),
+            RunState::Failed(3)
+        );
+        assert!(parse("pid 42\n", PathBuf::from("x.log"), |_| true).is_none());
+    }
+
+    #[test]
+    fn test_track_wraps_command() {
+        let wrapped = track("npm ci", Path::new("/tmp/wt logs/a.status"));
+        assert_eq!(
+            wrapped,
+            "echo \"pid $$\" >> '/tmp/wt logs/a.status'; { npm ci; }; wt_status=$?; \
+echo \"exit $wt_status\" >> '/tmp/wt logs/a.status'; exit $wt_status"
+        );
+    }
+}
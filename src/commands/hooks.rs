@@ -1,15 +1,33 @@
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use color_print::cformat;
 use worktrunk::HookType;
-use worktrunk::config::CommandConfig;
+use worktrunk::config::{CommandConfig, OnFailure};
 use worktrunk::git::WorktrunkError;
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{format_bash_with_gutter, progress_message, warning_message};
 
 use super::command_executor::{CommandContext, PreparedCommand, prepare_commands};
-use crate::commands::process::spawn_detached;
-use crate::output::execute_command_in_worktree;
+use super::hook_status;
+use crate::commands::process::{self, notify_on_completion, spawn_detached};
+use crate::output::report::{self, HookReport, HookStatus};
+use crate::output::{
+    LabeledCommand, execute_command_in_worktree, execute_commands_in_worktree_parallel,
+};
+
+/// Whether configured hooks run at all; cleared by `--no-hooks`
+static HOOKS_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Run configured hooks (`true`, the default) or skip every one (`false`)
+pub fn set_hooks_enabled(enabled: bool) {
+    HOOKS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether configured hooks run, i.e. `--no-hooks` wasn't passed
+pub fn hooks_enabled() -> bool {
+    HOOKS_ENABLED.load(Ordering::Relaxed)
+}
 
 /// A prepared command with its source information.
 pub struct SourcedCommand {
@@ -22,6 +40,36 @@ pub struct SourcedCommand {
 }
 
 impl SourcedCommand {
+    /// Source-qualified name, e.g. `user:lint`, or just the source when unnamed
+    fn label(&self) -> String {
+        match &self.prepared.name {
+            Some(name) => format!("{}:{}", self.source, name),
+            None => self.source.to_string(),
+        }
+    }
+
+    /// Add this command's outcome to the `--format json` report and the log file
+    fn report(&self, status: HookStatus, exit_code: Option<i32>) {
+        worktrunk::op_log::record(
+            "hook",
+            serde_json::json!({
+                "hook": self.hook_type.to_string(),
+                "name": self.label(),
+                "cmd": self.prepared.expanded,
+                "status": status,
+                "exit_code": exit_code,
+            }),
+        );
+        report::record(|report| {
+            report.hooks.push(HookReport {
+                hook: self.hook_type.to_string(),
+                name: self.label(),
+                status,
+                exit_code,
+            })
+        });
+    }
+
     /// Announce this command before execution.
     ///
     /// Format: "Running pre-merge user:foo:" for named, "Running post-create user hook:" for unnamed
@@ -62,6 +110,16 @@ pub enum HookFailureStrategy {
     Warn,
 }
 
+impl HookFailureStrategy {
+    /// How a failed command is handled, unless its entry sets `on-failure`
+    fn default_policy(self) -> OnFailure {
+        match self {
+            Self::FailFast => OnFailure::Abort,
+            Self::Warn => OnFailure::Warn,
+        }
+    }
+}
+
 /// Distinguishes between user hooks and project hooks for command preparation.
 ///
 /// Approval for project hooks is handled at the gate (command entry point),
@@ -116,6 +174,9 @@ impl<'a> ParsedFilter<'a> {
 /// Prepare hook commands from both user and project configs.
 ///
 /// Collects commands from user config first, then project config, applying the name filter.
+/// Returns nothing when hooks are disabled with `--no-hooks`.
+/// Commands scoped to other branches (`branches`/`exclude`) are left out, unless
+/// a name filter picks commands explicitly.
 /// The filter supports source prefixes: `user:foo` or `project:foo` to run only from one source.
 /// Returns a flat list of commands with source information for execution.
 ///
@@ -131,10 +192,19 @@ pub fn prepare_hook_commands(
     name_filter: Option<&str>,
     display_path: Option<&Path>,
 ) -> anyhow::Result<Vec<SourcedCommand>> {
+    if !hooks_enabled() {
+        return Ok(Vec::new());
+    }
+
     let parsed_filter = name_filter.map(ParsedFilter::parse);
     let mut commands = Vec::new();
 
     let display_path = display_path.map(|p| p.to_path_buf());
+    // Naming a command runs it whatever its branch scope
+    let scope = |config: &CommandConfig| match name_filter {
+        Some(_) => config.clone(),
+        None => config.scoped_to(ctx.branch),
+    };
 
     if let Some(config) = user_config {
         // Skip user commands if filter specifies project source
@@ -142,7 +212,7 @@ pub fn prepare_hook_commands(
             .as_ref()
             .is_none_or(|f| f.matches_source(HookSource::User))
         {
-            let prepared = prepare_commands(config, ctx, extra_vars, hook_type)?;
+            let prepared = prepare_commands(&scope(config), ctx, extra_vars, hook_type)?;
             let filtered = filter_by_name(prepared, parsed_filter.as_ref().map(|f| f.name));
             commands.extend(filtered.into_iter().map(|p| SourcedCommand {
                 prepared: p,
@@ -159,7 +229,7 @@ pub fn prepare_hook_commands(
             .as_ref()
             .is_none_or(|f| f.matches_source(HookSource::Project))
         {
-            let prepared = prepare_commands(config, ctx, extra_vars, hook_type)?;
+            let prepared = prepare_commands(&scope(config), ctx, extra_vars, hook_type)?;
             let filtered = filter_by_name(prepared, parsed_filter.as_ref().map(|f| f.name));
             commands.extend(filtered.into_iter().map(|p| SourcedCommand {
                 prepared: p,
@@ -221,13 +291,34 @@ pub fn spawn_hook_commands_background(
         // user and project hooks with the same name
         let operation = format!("{}-{}-{}", cmd.source, operation_prefix, name);
 
+        cmd.report(HookStatus::Background, None);
+        // Nobody is watching a background hook, so it's the one most worth a notification
+        let command = match ctx.config.notify_after() {
+            Some(after) => notify_on_completion(
+                &cmd.prepared.expanded,
+                after,
+                &format!("wt {hook_type} hook"),
+                &format!("{name} on {}", ctx.branch_or_head()),
+            ),
+            None => cmd.prepared.expanded.clone(),
+        };
+        // Tracking is best-effort: an untracked command still runs, it just
+        // doesn't show up in `wt hook status`
+        let command = match track_status(ctx, &cmd, &operation) {
+            Ok(status_path) => hook_status::track(&command, &status_path),
+            Err(err) => {
+                log::debug!("Not tracking {operation}: {err}");
+                command
+            }
+        };
         if let Err(err) = spawn_detached(
             ctx.repo,
             ctx.worktree_path,
-            &cmd.prepared.expanded,
+            &command,
             ctx.branch_or_head(),
             &operation,
             Some(&cmd.prepared.context_json),
+            &cmd.prepared.env,
         ) {
             let err_msg = err.to_string();
             let message = match &cmd.prepared.name {
@@ -242,6 +333,28 @@ pub fn spawn_hook_commands_background(
     Ok(())
 }
 
+/// Write the status file for a background hook command, returning its path
+fn track_status(
+    ctx: &CommandContext,
+    cmd: &SourcedCommand,
+    operation: &str,
+) -> anyhow::Result<PathBuf> {
+    // The status wrapper is POSIX shell
+    if cfg!(windows) && !worktrunk::shell_exec::ShellConfig::get().is_posix() {
+        anyhow::bail!("no POSIX shell");
+    }
+    let log_path = process::log_path(ctx.repo, ctx.branch_or_head(), operation)?;
+    let status_path = hook_status::status_path(&log_path);
+    hook_status::begin(
+        &status_path,
+        &cmd.hook_type.to_string(),
+        &cmd.label(),
+        ctx.branch_or_head(),
+        ctx.worktree_path,
+    )?;
+    Ok(status_path)
+}
+
 /// Check if a name filter was provided but no commands matched.
 /// Returns an error listing available command names if so.
 pub(crate) fn check_name_filter_matched(
@@ -289,7 +402,9 @@ pub(crate) fn check_name_filter_matched(
 /// Run user and project hooks for a given hook type.
 ///
 /// This is the canonical implementation for running hooks from both sources.
-/// Runs user hooks first, then project hooks sequentially. Handles name filtering
+/// Runs user hooks first, then project hooks sequentially (or all at once with
+/// `hooks.parallel = true` in the user config). Entries with `background = true`
+/// are spawned detached once the others succeed. Handles name filtering
 /// and returns an error if a name filter was provided but no matching command found.
 ///
 /// `display_path`: When `Some`, shows the path in hook announcements. Use when hooks
@@ -305,6 +420,10 @@ pub fn run_hook_with_filter(
     name_filter: Option<&str>,
     display_path: Option<&Path>,
 ) -> anyhow::Result<()> {
+    if !hooks_enabled() {
+        return Ok(());
+    }
+
     let commands = prepare_hook_commands(
         ctx,
         user_config,
@@ -317,6 +436,26 @@ pub fn run_hook_with_filter(
 
     check_name_filter_matched(name_filter, commands.len(), user_config, project_config)?;
 
+    let (background, commands): (Vec<_>, Vec<_>) = commands
+        .into_iter()
+        .partition(|cmd| cmd.prepared.background);
+
+    if commands.len() > 1 && ctx.config.parallel_hooks() {
+        run_commands_parallel(ctx, &commands, hook_type, failure_strategy)?;
+    } else {
+        run_commands_sequential(ctx, commands, hook_type, failure_strategy)?;
+    }
+
+    spawn_hook_commands_background(ctx, background, hook_type)
+}
+
+/// Run a hook's commands one at a time, in declaration order
+fn run_commands_sequential(
+    ctx: &CommandContext,
+    commands: Vec<SourcedCommand>,
+    hook_type: HookType,
+    failure_strategy: HookFailureStrategy,
+) -> anyhow::Result<()> {
     if commands.is_empty() {
         return Ok(());
     }
@@ -327,54 +466,56 @@ pub fn run_hook_with_filter(
     for cmd in commands {
         cmd.announce()?;
 
-        if let Err(err) = execute_command_in_worktree(
+        let Err(err) = execute_command_in_worktree(
             ctx.worktree_path,
             &cmd.prepared.expanded,
             Some(&cmd.prepared.context_json),
-        ) {
-            // Extract raw message and exit code from error
-            let (err_msg, exit_code) = if let Some(wt_err) = err.downcast_ref::<WorktrunkError>() {
-                match wt_err {
-                    WorktrunkError::ChildProcessExited { message, code } => {
-                        (message.clone(), Some(*code))
-                    }
-                    _ => (err.to_string(), None),
-                }
-            } else {
-                (err.to_string(), None)
-            };
-
-            match &failure_strategy {
-                HookFailureStrategy::FailFast => {
-                    crate::output::flush()?;
-                    return Err(WorktrunkError::HookCommandFailed {
-                        hook_type,
-                        command_name: cmd.prepared.name.clone(),
-                        error: err_msg,
-                        exit_code,
-                    }
-                    .into());
+            &cmd.prepared.env,
+            cmd.prepared.timeout,
+        ) else {
+            cmd.report(HookStatus::Succeeded, None);
+            continue;
+        };
+        let (err_msg, exit_code) = failure_details(&err);
+        cmd.report(HookStatus::Failed, exit_code);
+        let policy = cmd
+            .prepared
+            .on_failure
+            .unwrap_or(failure_strategy.default_policy());
+
+        match policy {
+            OnFailure::Abort => {
+                crate::output::flush()?;
+                return Err(WorktrunkError::HookCommandFailed {
+                    hook_type,
+                    command_name: cmd.prepared.name.clone(),
+                    error: err_msg,
+                    exit_code,
                 }
-                HookFailureStrategy::Warn => {
-                    let message = match &cmd.prepared.name {
-                        Some(name) => cformat!("Command <bold>{name}</> failed: {err_msg}"),
-                        None => format!("Command failed: {err_msg}"),
-                    };
-                    crate::output::print(warning_message(message))?;
-
-                    // Track first failure to propagate exit code later (only for PostMerge)
-                    if first_failure.is_none() && hook_type == HookType::PostMerge {
-                        first_failure =
-                            Some((err_msg, cmd.prepared.name.clone(), exit_code.unwrap_or(1)));
-                    }
+                .into());
+            }
+            OnFailure::Warn => {
+                let message = match &cmd.prepared.name {
+                    Some(name) => cformat!("Command <bold>{name}</> failed: {err_msg}"),
+                    None => format!("Command failed: {err_msg}"),
+                };
+                crate::output::print(warning_message(message))?;
+
+                // Track first failure to propagate exit code later (only for PostMerge)
+                if first_failure.is_none() && hook_type == HookType::PostMerge {
+                    first_failure =
+                        Some((err_msg, cmd.prepared.name.clone(), exit_code.unwrap_or(1)));
                 }
             }
+            OnFailure::Ignore => {
+                log::debug!("Ignoring failed {hook_type} command: {err_msg}");
+            }
         }
     }
 
     crate::output::flush()?;
 
-    // For Warn strategy with PostMerge: if any command failed, propagate the exit code
+    // For warned PostMerge failures, propagate the exit code
     // This matches git's behavior: post-hooks can't stop the operation but affect exit status
     if let Some((error, command_name, exit_code)) = first_failure {
         return Err(WorktrunkError::HookCommandFailed {
@@ -389,6 +530,109 @@ pub fn run_hook_with_filter(
     Ok(())
 }
 
+/// Raw message and exit code of a failed hook command
+fn failure_details(err: &anyhow::Error) -> (String, Option<i32>) {
+    match err.downcast_ref::<WorktrunkError>() {
+        Some(WorktrunkError::ChildProcessExited { message, code }) => {
+            (message.clone(), Some(*code))
+        }
+        _ => (err.to_string(), None),
+    }
+}
+
+/// Run a hook's commands concurrently (`hooks.parallel = true`).
+///
+/// Output is streamed with each line prefixed by the command's label. All
+/// commands run to completion, then failures that aren't ignored are
+/// summarized together; the first aborting failure (in declaration order) is
+/// surfaced as the error, and warned failures only propagate for PostMerge, as
+/// when sequential.
+fn run_commands_parallel(
+    ctx: &CommandContext,
+    commands: &[SourcedCommand],
+    hook_type: HookType,
+    failure_strategy: HookFailureStrategy,
+) -> anyhow::Result<()> {
+    for cmd in commands {
+        cmd.announce()?;
+    }
+
+    let labeled: Vec<_> = commands
+        .iter()
+        .map(|cmd| LabeledCommand {
+            label: cmd.label(),
+            command: &cmd.prepared.expanded,
+            stdin_content: Some(&cmd.prepared.context_json),
+            env: &cmd.prepared.env,
+            timeout: cmd.prepared.timeout,
+        })
+        .collect();
+    let results = execute_commands_in_worktree_parallel(ctx.worktree_path, &labeled)?;
+    for (cmd, result) in commands.iter().zip(&results) {
+        match result {
+            Ok(()) => cmd.report(HookStatus::Succeeded, None),
+            Err(err) => cmd.report(HookStatus::Failed, failure_details(err).1),
+        }
+    }
+
+    let failures: Vec<_> = commands
+        .iter()
+        .zip(results)
+        .filter_map(|(cmd, result)| {
+            let policy = cmd
+                .prepared
+                .on_failure
+                .unwrap_or(failure_strategy.default_policy());
+            let err = result.err()?;
+            if policy == OnFailure::Ignore {
+                log::debug!("Ignoring failed {hook_type} command: {err}");
+                return None;
+            }
+            Some((cmd, policy, failure_details(&err)))
+        })
+        .collect();
+    if failures.is_empty() {
+        return Ok(());
+    }
+
+    let summary: Vec<_> = failures
+        .iter()
+        .map(|(cmd, _, (_, code))| match code {
+            Some(code) => cformat!("<bold>{}</> (exit {code})", cmd.label()),
+            None => cformat!("<bold>{}</>", cmd.label()),
+        })
+        .collect();
+    crate::output::print(warning_message(format!(
+        "{} of {} {hook_type} commands failed: {}",
+        failures.len(),
+        commands.len(),
+        summary.join(", ")
+    )))?;
+    crate::output::flush()?;
+
+    let propagated = failures
+        .iter()
+        .find(|(_, policy, _)| *policy == OnFailure::Abort)
+        .map(|(cmd, _, (error, exit_code))| (cmd, error, *exit_code))
+        .or_else(|| {
+            // Warned PostMerge failures still set the exit code
+            failures
+                .first()
+                .filter(|_| hook_type == HookType::PostMerge)
+                .map(|(cmd, _, (error, exit_code))| (cmd, error, Some(exit_code.unwrap_or(1))))
+        });
+    let Some((cmd, error, exit_code)) = propagated else {
+        return Ok(());
+    };
+    Err(WorktrunkError::HookCommandFailed {
+        hook_type,
+        command_name: cmd.prepared.name.clone(),
+        error: error.clone(),
+        exit_code,
+    }
+    .into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
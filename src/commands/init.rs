@@ -1,8 +1,20 @@
 use worktrunk::shell;
 use worktrunk::styling::println;
 
-pub fn handle_init(shell: shell::Shell, cmd: String) -> Result<(), String> {
-    let init = shell::ShellInit::with_prefix(shell, cmd);
+pub fn handle_init(
+    shell: shell::Shell,
+    cmd: String,
+    on_switch: Option<String>,
+    with_abbr: bool,
+) -> Result<(), String> {
+    if with_abbr && shell != shell::Shell::Fish {
+        return Err(format!(
+            "--with-abbr is only supported for fish, not {shell}"
+        ));
+    }
+    let init = shell::ShellInit::with_prefix(shell, cmd)
+        .on_switch(on_switch)
+        .with_abbr(with_abbr);
 
     // Generate shell integration code (includes dynamic completion registration)
     let integration_output = init
@@ -0,0 +1,171 @@
+//! Branch names from issue tracker references.
+//!
+//! `wt switch --create --from-issue 1234` looks the issue up with `gh` or
+//! `glab` (whichever forge the remote is on, or `[ci] provider`), names the
+//! branch from `switch.issue-branch`, and records the issue's URL in
+//! `worktrunk.state.<branch>.issue` so hooks that open pull requests can link
+//! it as `{{ issue_url }}`.
+
+use std::collections::HashMap;
+
+use anyhow::Context;
+use serde::Deserialize;
+use worktrunk::config::{CiConfig, WorktrunkConfig, branch_slug, expand_template};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::shell_exec::run;
+
+use super::list::ci_status::{
+    CiPlatform, configure_non_interactive, get_configured_platform, provider_command,
+};
+
+/// Default `switch.issue-branch` template
+const DEFAULT_ISSUE_BRANCH: &str = "{{ ticket }}-{{ slug }}";
+
+/// Longest `{{ slug }}`, cut at a word boundary, so long titles still make
+/// usable branch names
+const MAX_SLUG_LEN: usize = 40;
+
+/// An issue from `gh issue view` or `glab issue view`
+#[derive(Debug, Deserialize)]
+struct Issue {
+    title: String,
+    /// `url` from `gh`, `web_url` from `glab`
+    #[serde(alias = "web_url")]
+    url: String,
+}
+
+/// Look up issue `number` on the repository's forge.
+fn fetch_issue(number: u32, repo_root: &str, ci: &CiConfig) -> anyhow::Result<Issue> {
+    let number = number.to_string();
+    let (program, args): (&str, Vec<&str>) = match get_configured_platform(repo_root, ci) {
+        Some(CiPlatform::GitLab) => ("glab", vec!["issue", "view", &number, "--output", "json"]),
+        Some(CiPlatform::GitHub) | None => {
+            ("gh", vec!["issue", "view", &number, "--json", "title,url"])
+        }
+    };
+
+    let mut cmd = provider_command(program, ci);
+    cmd.args(&args);
+    configure_non_interactive(&mut cmd);
+    cmd.current_dir(repo_root);
+
+    let command = format!("{program} issue view");
+    let output = run(&mut cmd, None).map_err(|e| GitError::ProviderCommandFailed {
+        command: command.clone(),
+        error: e.to_string(),
+    })?;
+    if !output.status.success() {
+        return Err(GitError::ProviderCommandFailed {
+            command,
+            error: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        }
+        .into());
+    }
+    serde_json::from_slice(&output.stdout)
+        .with_context(|| format!("Failed to parse `{command}` output"))
+}
+
+/// Slug of an issue title, shortened to whole words within [`MAX_SLUG_LEN`]
+/// characters (the first word is always kept)
+fn title_slug(title: &str) -> String {
+    let mut slug = String::new();
+    for word in branch_slug(title).split('-') {
+        if !slug.is_empty() {
+            if slug.chars().count() + 1 + word.chars().count() > MAX_SLUG_LEN {
+                break;
+            }
+            slug.push('-');
+        }
+        slug.push_str(word);
+    }
+    slug
+}
+
+/// Name a branch for `issue` with the `switch.issue-branch` template.
+///
+/// Variables: `{{ ticket }}` (the issue number), `{{ title }}`, and
+/// `{{ slug }}` (the title, slugified and shortened).
+fn issue_branch_name(template: &str, number: u32, title: &str) -> anyhow::Result<String> {
+    let ticket = number.to_string();
+    let slug = title_slug(title);
+    let vars = HashMap::from([
+        ("ticket", ticket.as_str()),
+        ("title", title),
+        ("slug", slug.as_str()),
+    ]);
+    let branch = expand_template(template, &vars, false).map_err(|e| GitError::Other {
+        message: format!("Invalid switch.issue-branch template: {e}"),
+    })?;
+    let branch = branch.trim().trim_matches('-').to_string();
+    if branch.is_empty() {
+        return Err(GitError::Other {
+            message: format!("switch.issue-branch expanded to an empty branch name for #{number}"),
+        }
+        .into());
+    }
+    Ok(branch)
+}
+
+/// Fetch issue `number` and return the branch name to create for it, with
+/// the issue's URL.
+pub fn issue_branch(number: u32) -> anyhow::Result<(String, String)> {
+    let repo = Repository::current();
+    let repo_root = repo.worktree_base()?.to_string_lossy().into_owned();
+    let config = WorktrunkConfig::load().context("Failed to load config")?;
+    let issue = fetch_issue(number, &repo_root, &config.ci())?;
+    let template = config
+        .switch
+        .as_ref()
+        .and_then(|switch| switch.issue_branch.as_deref())
+        .unwrap_or(DEFAULT_ISSUE_BRANCH);
+    let branch = issue_branch_name(template, number, &issue.title)?;
+    Ok((branch, issue.url))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_branch_name() {
+        assert_eq!(
+            issue_branch_name(
+                DEFAULT_ISSUE_BRANCH,
+                1234,
+                "Fix login: handle expired tokens"
+            )
+            .unwrap(),
+            "1234-fix-login-handle-expired-tokens"
+        );
+        assert_eq!(
+            issue_branch_name("issue/{{ ticket }}", 7, "Anything").unwrap(),
+            "issue/7"
+        );
+        // Long titles are cut at a word boundary
+        let branch = issue_branch_name(
+            DEFAULT_ISSUE_BRANCH,
+            1,
+            "Support configuring the retry policy for every outbound webhook delivery",
+        )
+        .unwrap();
+        assert_eq!(branch, "1-support-configuring-the-retry-policy-for");
+        // A title with nothing to slug still leaves the number
+        assert_eq!(
+            issue_branch_name(DEFAULT_ISSUE_BRANCH, 9, "🎉").unwrap(),
+            "9"
+        );
+    }
+
+    #[test]
+    fn test_issue_deserializes_from_gh_and_glab() {
+        let gh: Issue =
+            serde_json::from_str(r#"{"title":"Bug","url":"https://github.com/o/r/issues/1"}"#)
+                .unwrap();
+        assert_eq!(gh.url, "https://github.com/o/r/issues/1");
+        let glab: Issue = serde_json::from_str(
+            r#"{"iid":1,"title":"Bug","web_url":"https://gitlab.com/o/r/-/issues/1"}"#,
+        )
+        .unwrap();
+        assert_eq!(glab.url, "https://gitlab.com/o/r/-/issues/1");
+    }
+}
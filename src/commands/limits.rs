@@ -0,0 +1,209 @@
+//! Worktree budget from `[limits]`.
+//!
+//! Agents that create a worktree per task can pile them up faster than anyone
+//! removes them. `wt switch --create` warns (or, with `--strict`, refuses) when
+//! another worktree would go over the budget, and `wt list` suggests worktrees
+//! to clean up once the repository is over it.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use anyhow::bail;
+use color_print::cformat;
+use rayon::prelude::*;
+use worktrunk::config::LimitsConfig;
+use worktrunk::git::{Repository, Worktree};
+use worktrunk::styling::{hint_message, warning_message};
+
+/// How many cleanup candidates `wt list` names
+const MAX_CANDIDATES: usize = 3;
+
+/// Limits the repository's worktrees go over, with `extra` worktrees about to be created
+fn overruns(repo: &Repository, limits: &LimitsConfig, extra: usize) -> anyhow::Result<Vec<String>> {
+    let max_disk = limits.max_total_disk_bytes();
+    if limits.max_worktrees.is_none() && max_disk.is_none() {
+        return Ok(Vec::new());
+    }
+
+    let worktrees = live_worktrees(repo)?;
+    let mut overruns = Vec::new();
+    if let Some(max) = limits.max_worktrees {
+        let count = worktrees.len() + extra;
+        if count > max {
+            overruns.push(cformat!(
+                "{count} worktrees, over <bold>limits.max-worktrees</> ({max})"
+            ));
+        }
+    }
+    if let Some(max) = max_disk {
+        // Worktrees nested inside another (e.g. under `.worktrees/`) count once
+        let paths: HashSet<&Path> = worktrees.iter().map(|wt| wt.path.as_path()).collect();
+        let used: u64 = worktrees
+            .par_iter()
+            .map(|wt| dir_size(&wt.path, &paths))
+            .sum();
+        if used > max {
+            overruns.push(cformat!(
+                "{} on disk, over <bold>limits.max-total-disk</> ({})",
+                format_size(used),
+                format_size(max)
+            ));
+        }
+    }
+    Ok(overruns)
+}
+
+/// Before `wt switch` creates a worktree: warn when it goes over the budget,
+/// or with `strict`, refuse
+pub fn check_before_create(
+    repo: &Repository,
+    limits: &LimitsConfig,
+    strict: bool,
+) -> anyhow::Result<()> {
+    let overruns = overruns(repo, limits, 1)?;
+    if overruns.is_empty() {
+        return Ok(());
+    }
+    let overruns = overruns.join("; ");
+    if strict {
+        bail!("Not creating a worktree over budget: {overruns}");
+    }
+    crate::output::print(warning_message(cformat!(
+        "Over the worktree budget: {overruns}"
+    )))?;
+    crate::output::print(hint_message(cformat!(
+        "Clean up with <bright-black>wt remove --merged</>, or see candidates in <bright-black>wt list</>"
+    )))?;
+    Ok(())
+}
+
+/// After `wt list`'s table: when the repository is over the budget, say so
+/// and name worktrees to clean up
+pub fn report_for_list(repo: &Repository, limits: &LimitsConfig) -> anyhow::Result<()> {
+    let overruns = overruns(repo, limits, 0)?;
+    if overruns.is_empty() {
+        return Ok(());
+    }
+    crate::output::blank()?;
+    crate::output::print(warning_message(cformat!(
+        "Over the worktree budget: {}",
+        overruns.join("; ")
+    )))?;
+    let candidates = cleanup_candidates(repo)?;
+    if !candidates.is_empty() {
+        let names: Vec<String> = candidates
+            .iter()
+            .map(|branch| cformat!("<bold>{branch}</>"))
+            .collect();
+        crate::output::print(hint_message(cformat!(
+            "Candidates to remove: {} (merged first, then least recently committed)",
+            names.join(", ")
+        )))?;
+    }
+    Ok(())
+}
+
+/// Worktrees that exist on disk, leaving out a bare repository's entry
+fn live_worktrees(repo: &Repository) -> anyhow::Result<Vec<Worktree>> {
+    Ok(repo
+        .list_worktrees()?
+        .into_iter()
+        .filter(|wt| !wt.bare && wt.prunable.is_none())
+        .collect())
+}
+
+/// Branches whose worktrees are best removed first: merged ones, then the
+/// rest by oldest commit. Skips the same worktrees `wt remove --merged` does.
+fn cleanup_candidates(repo: &Repository) -> anyhow::Result<Vec<String>> {
+    let mut candidates = super::worktree::merged_worktree_branches(repo)?;
+    if candidates.len() < MAX_CANDIDATES {
+        let default_branch = repo.default_branch().unwrap_or_default();
+        let main_path = repo.primary_worktree()?.map(|wt| wt.path);
+        let current = repo.worktree_root().ok().map(Path::to_path_buf);
+        let rest: Vec<Worktree> = live_worktrees(repo)?
+            .into_iter()
+            .filter(|wt| wt.locked.is_none() && Some(&wt.path) != main_path.as_ref())
+            .filter(|wt| Some(&wt.path) != current.as_ref())
+            .filter(|wt| {
+                wt.branch
+                    .as_ref()
+                    .is_some_and(|branch| *branch != default_branch && !candidates.contains(branch))
+            })
+            .collect();
+        let heads: Vec<&str> = rest.iter().map(|wt| wt.head.as_str()).collect();
+        let timestamps = repo.commit_timestamps(&heads)?;
+        let mut rest: Vec<(i64, String)> = rest
+            .into_iter()
+            .filter_map(|wt| Some((*timestamps.get(&wt.head)?, wt.branch?)))
+            .collect();
+        rest.sort();
+        candidates.extend(rest.into_iter().map(|(_, branch)| branch));
+    }
+    candidates.truncate(MAX_CANDIDATES);
+    Ok(candidates)
+}
+
+/// Bytes used by the files under `path`, not following symlinks or descending
+/// into the directories in `skip`
+fn dir_size(path: &Path, skip: &HashSet<&Path>) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => {
+                let path = entry.path();
+                if skip.contains(path.as_path()) {
+                    0
+                } else {
+                    dir_size(&path, skip)
+                }
+            }
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+/// A byte count in the largest binary unit that keeps it at least 1, like `21.3 GiB`
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(20 * 1024 * 1024 * 1024), "20.0 GiB");
+    }
+
+    #[test]
+    fn test_dir_size() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a"), [0u8; 100]).unwrap();
+        fs::create_dir(dir.path().join("sub")).unwrap();
+        fs::write(dir.path().join("sub").join("b"), [0u8; 50]).unwrap();
+        assert_eq!(dir_size(dir.path(), &HashSet::new()), 150);
+        let sub = dir.path().join("sub");
+        assert_eq!(dir_size(dir.path(), &HashSet::from([sub.as_path()])), 100);
+        assert_eq!(dir_size(&dir.path().join("missing"), &HashSet::new()), 0);
+    }
+}
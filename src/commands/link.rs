@@ -0,0 +1,192 @@
+//! Built-in `symlink` / `hardlink` post-create actions.
+//!
+//! Large build directories (`node_modules`, `target`) are expensive to
+//! recreate in every worktree. Listing them under `[post-create]` as
+//! `symlink = [...]` or `hardlink = [...]` shares them from the primary
+//! worktree without writing shell commands, and works the same on every
+//! platform: on Windows, directory symlinks fall back to junctions (which
+//! need no special privileges) and file symlinks to hard links.
+
+use std::io;
+use std::path::Path;
+
+use color_print::cformat;
+use worktrunk::config::{CommandConfig, Link, LinkKind};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{success_message, warning_message};
+
+use super::command_executor::CommandContext;
+
+/// Link each configured path from the primary worktree into `ctx.worktree_path`.
+///
+/// Failures are warnings: a missing shared directory shouldn't undo the new
+/// worktree. Sources that don't exist are skipped quietly, since build output
+/// like `target` may simply not have been produced yet.
+pub fn create_links(
+    ctx: &CommandContext,
+    user_config: Option<&CommandConfig>,
+    project_config: Option<&CommandConfig>,
+) -> anyhow::Result<()> {
+    let links: Vec<&Link> = user_config
+        .into_iter()
+        .chain(project_config)
+        .flat_map(CommandConfig::links)
+        .collect();
+    if links.is_empty() || !super::hooks::hooks_enabled() {
+        return Ok(());
+    }
+
+    let Some(source_root) = ctx.repo.primary_worktree()?.map(|wt| wt.path) else {
+        crate::output::print(warning_message(
+            "Skipped post-create links; the default branch has no worktree to link from",
+        ))?;
+        return Ok(());
+    };
+    if source_root == ctx.worktree_path {
+        return Ok(());
+    }
+
+    let mut linked: Vec<(LinkKind, &str)> = Vec::new();
+    for link in links {
+        let source = source_root.join(&link.path);
+        let target = ctx.worktree_path.join(&link.path);
+        if !source.exists() {
+            log::debug!(
+                "Skipping {} {}: not in primary worktree",
+                link.kind,
+                link.path
+            );
+            continue;
+        }
+        if target.symlink_metadata().is_ok() {
+            crate::output::print(warning_message(cformat!(
+                "Skipped {} <bold>{}</>; it already exists in the new worktree",
+                link.kind,
+                link.path
+            )))?;
+            continue;
+        }
+        let result = match link.kind {
+            LinkKind::Symlink => symlink(&source, &target),
+            LinkKind::Hardlink => hardlink_tree(&source, &target),
+        };
+        match result {
+            Ok(()) => linked.push((link.kind, &link.path)),
+            Err(e) => crate::output::print(warning_message(cformat!(
+                "Failed to {} <bold>{}</>: {e}",
+                link.kind,
+                link.path
+            )))?,
+        }
+    }
+
+    let from = format_path_for_display(&source_root);
+    for (kind, verb) in [
+        (LinkKind::Symlink, "Symlinked"),
+        (LinkKind::Hardlink, "Hardlinked"),
+    ] {
+        let paths: Vec<_> = linked
+            .iter()
+            .filter(|(k, _)| *k == kind)
+            .map(|(_, path)| cformat!("<bold>{path}</>"))
+            .collect();
+        if !paths.is_empty() {
+            crate::output::print(success_message(cformat!(
+                "{verb} {} from <bold>{from}</>",
+                paths.join(", ")
+            )))?;
+        }
+    }
+    Ok(())
+}
+
+fn create_parent(target: &Path) -> io::Result<()> {
+    match target.parent() {
+        Some(parent) => std::fs::create_dir_all(parent),
+        None => Ok(()),
+    }
+}
+
+#[cfg(unix)]
+fn symlink(source: &Path, target: &Path) -> io::Result<()> {
+    create_parent(target)?;
+    std::os::unix::fs::symlink(source, target)
+}
+
+#[cfg(windows)]
+fn symlink(source: &Path, target: &Path) -> io::Result<()> {
+    create_parent(target)?;
+    if source.is_dir() {
+        // Symlinks need Developer Mode or admin rights; junctions don't
+        std::os::windows::fs::symlink_dir(source, target).or_else(|_| junction(source, target))
+    } else {
+        std::os::windows::fs::symlink_file(source, target)
+            .or_else(|_| std::fs::hard_link(source, target))
+    }
+}
+
+#[cfg(windows)]
+fn junction(source: &Path, target: &Path) -> io::Result<()> {
+    let status = std::process::Command::new("cmd")
+        .args(["/C", "mklink", "/J"])
+        .arg(target)
+        .arg(source)
+        .stdout(std::process::Stdio::null())
+        .status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other("mklink /J failed"))
+    }
+}
+
+/// Recreate `source` at `target` with hard-linked files, copying any that
+/// can't be linked (e.g. across filesystems)
+fn hardlink_tree(source: &Path, target: &Path) -> io::Result<()> {
+    create_parent(target)?;
+    let metadata = std::fs::symlink_metadata(source)?;
+    if metadata.is_dir() {
+        std::fs::create_dir(target)?;
+        for entry in std::fs::read_dir(source)? {
+            let entry = entry?;
+            hardlink_tree(&entry.path(), &target.join(entry.file_name()))?;
+        }
+        Ok(())
+    } else if metadata.is_symlink() {
+        // Keep symlinks as symlinks rather than linking what they point to
+        let destination = std::fs::read_link(source)?;
+        #[cfg(unix)]
+        return std::os::unix::fs::symlink(destination, target);
+        #[cfg(windows)]
+        return std::os::windows::fs::symlink_file(destination, target);
+    } else {
+        std::fs::hard_link(source, target).or_else(|_| std::fs::copy(source, target).map(|_| ()))
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hardlink_tree_shares_files() {
+        use std::os::unix::fs::MetadataExt;
+
+        let temp = tempfile::tempdir().unwrap();
+        let source = temp.path().join("src/node_modules");
+        std::fs::create_dir_all(source.join("pkg")).unwrap();
+        std::fs::write(source.join("pkg/index.js"), "x").unwrap();
+        std::os::unix::fs::symlink("pkg", source.join("alias")).unwrap();
+
+        let target = temp.path().join("dst/node_modules");
+        hardlink_tree(&source, &target).unwrap();
+
+        let original = std::fs::metadata(source.join("pkg/index.js")).unwrap();
+        let linked = std::fs::metadata(target.join("pkg/index.js")).unwrap();
+        assert_eq!(original.ino(), linked.ino());
+        assert_eq!(
+            std::fs::read_link(target.join("alias")).unwrap(),
+            Path::new("pkg")
+        );
+    }
+}
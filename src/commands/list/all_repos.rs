@@ -0,0 +1,253 @@
+//! `wt list --all-repos`: one table across every registered repository.
+//!
+//! Each repository from the `wt repo` registry is collected independently
+//! (buffered, no progressive rendering), then the rows share a single layout
+//! with a leading Repo column.
+
+use std::collections::HashSet;
+use std::ops::Range;
+use std::path::PathBuf;
+
+use anstyle::Style;
+use anyhow::Context;
+use color_print::cformat;
+use unicode_width::UnicodeWidthStr;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{hint_message, info_message, warning_message};
+
+use super::collect::{self, TaskKind};
+use super::json_output;
+use super::layout;
+use super::model::{ListData, ListItem};
+
+/// Header of the leading repository column
+const REPO_HEADER: &str = "Repo";
+
+/// Shortest trailing part of each identifier that's unique among `ids`.
+///
+/// `github.com/max/app` displays as `app` unless another repository is also
+/// named `app`, in which case both show `owner/app`, and so on.
+fn short_names(ids: &[&str]) -> Vec<String> {
+    let tail = |id: &str, parts: usize| -> String {
+        let segments: Vec<&str> = id.split('/').collect();
+        segments[segments.len().saturating_sub(parts)..].join("/")
+    };
+    ids.iter()
+        .map(|id| {
+            let segments = id.split('/').count();
+            (1..segments)
+                .map(|parts| tail(id, parts))
+                .find(|name| {
+                    ids.iter()
+                        .filter(|other| {
+                            *other == id || other.ends_with(&format!("/{name}")) || *other == name
+                        })
+                        .count()
+                        == 1
+                })
+                .unwrap_or_else(|| id.to_string())
+        })
+        .collect()
+}
+
+/// One repository's slice of the combined rows
+struct RepoRows {
+    id: String,
+    name: String,
+    main_worktree_path: PathBuf,
+    rows: Range<usize>,
+}
+
+/// Handle `wt list --all-repos`
+#[allow(clippy::too_many_arguments)]
+pub fn handle_list_all_repos(
+    format: crate::OutputFormat,
+    show_branches: bool,
+    show_remotes: bool,
+    branch_filter: &collect::BranchFilter,
+    show_full: bool,
+    show_ci: bool,
+    json_selection: Option<&json_output::JsonSelection>,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<()> {
+    let registered: Vec<_> = config.registered_repos().collect();
+    if registered.is_empty() {
+        crate::output::print(info_message("No repositories registered"))?;
+        crate::output::print(hint_message(cformat!(
+            "To register a repository, run <bright-black>wt repo add</> in it"
+        )))?;
+        return Ok(());
+    }
+
+    let mut skip_tasks = super::skip_tasks_for(show_full, show_ci);
+    // URL templates are per-project; a shared column would mostly be empty
+    skip_tasks.insert(TaskKind::UrlStatus);
+
+    // The repository we're in keeps its `@` marker; elsewhere nothing is current
+    let current_root = Repository::current().worktree_base().ok();
+
+    let ids: Vec<&str> = registered.iter().map(|(id, _)| *id).collect();
+    let names = short_names(&ids);
+    let mut repos = Vec::new();
+    let mut all_items: Vec<ListItem> = Vec::new();
+    for ((id, path), name) in registered.iter().zip(names) {
+        if !path.exists() {
+            crate::output::print(warning_message(cformat!(
+                "Skipping <bold>{name}</>; {} no longer exists",
+                format_path_for_display(path)
+            )))?;
+            continue;
+        }
+
+        let is_current = current_root.as_deref() == Some(*path);
+        let repo = if is_current {
+            Repository::current()
+        } else {
+            Repository::at(*path)
+        };
+        let data = collect::collect(
+            &repo,
+            show_branches,
+            show_remotes,
+            branch_filter,
+            &skip_tasks,
+            false,
+            false,
+            config,
+        )
+        .with_context(|| format!("Failed to list {id}"))?;
+        let Some(ListData {
+            mut items,
+            main_worktree_path,
+            ..
+        }) = data
+        else {
+            continue;
+        };
+
+        if !is_current {
+            for item in &mut items {
+                if let Some(wt) = item.worktree_data_mut() {
+                    wt.is_current = false;
+                }
+            }
+        }
+        let start = all_items.len();
+        all_items.extend(items);
+        repos.push(RepoRows {
+            id: id.to_string(),
+            name,
+            main_worktree_path,
+            rows: start..all_items.len(),
+        });
+    }
+
+    match format {
+        crate::OutputFormat::Json => {
+            let mut json_items = Vec::new();
+            for repo in &repos {
+                for item in json_output::to_json_items(&all_items[repo.rows.clone()]) {
+                    let mut value = serde_json::to_value(item)?;
+                    if let Some(object) = value.as_object_mut() {
+                        object.insert("repo".into(), repo.id.clone().into());
+                    }
+                    json_items.push(value);
+                }
+            }
+            json_output::print_json(&json_items, json_selection)?;
+        }
+        crate::OutputFormat::Table => render_table(
+            &repos,
+            &all_items,
+            &skip_tasks,
+            config,
+            show_branches || show_remotes,
+        )?,
+    }
+    Ok(())
+}
+
+fn render_table(
+    repos: &[RepoRows],
+    all_items: &[ListItem],
+    skip_tasks: &HashSet<TaskKind>,
+    config: &WorktrunkConfig,
+    show_branches: bool,
+) -> anyhow::Result<()> {
+    let selected = config.list_columns();
+    let Some(first) = repos.first() else {
+        return Ok(());
+    };
+    let repo_width = repos
+        .iter()
+        .map(|repo| repo.name.width())
+        .chain([REPO_HEADER.width()])
+        .max()
+        .unwrap_or(0);
+
+    // Widths come from every repository's rows; the column budget leaves room for Repo
+    let mut layout = layout::calculate_layout_with_width(
+        all_items,
+        skip_tasks,
+        selected,
+        layout::get_safe_list_width().saturating_sub(repo_width + 2),
+        &first.main_worktree_path,
+        None,
+    );
+    layout.age_thresholds = layout::AgeThresholds::from_config(config);
+    layout.placeholder = layout::Placeholder::from_config(config);
+
+    let bold = Style::new().bold();
+    let padding = " ".repeat(repo_width - REPO_HEADER.width());
+    crate::output::stdout(format!(
+        "{bold}{REPO_HEADER}{bold:#}{padding}  {}",
+        layout.format_header_line()
+    ))?;
+    for repo in repos {
+        // Paths are shown relative to each repository's own main worktree
+        layout.main_worktree_path = repo.main_worktree_path.clone();
+        for item in &all_items[repo.rows.clone()] {
+            crate::output::stdout(format!(
+                "{:repo_width$}  {}",
+                repo.name,
+                layout.format_list_item_line(item, None)
+            ))?;
+        }
+    }
+    crate::output::stdout("")?;
+    crate::output::stdout(super::format_summary_message(
+        all_items,
+        show_branches,
+        layout.hidden_column_count,
+    ))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_names_unique_tails() {
+        assert_eq!(
+            short_names(&["github.com/max/app", "github.com/max/cli"]),
+            ["app", "cli"]
+        );
+    }
+
+    #[test]
+    fn test_short_names_disambiguates_by_owner() {
+        assert_eq!(
+            short_names(&["github.com/a/app", "github.com/b/app", "gitlab.com/c/web"]),
+            ["a/app", "b/app", "web"]
+        );
+    }
+
+    #[test]
+    fn test_short_names_identifier_without_slashes() {
+        // Repositories without a remote are identified by directory name
+        assert_eq!(short_names(&["scratch"]), ["scratch"]);
+    }
+}
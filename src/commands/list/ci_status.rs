@@ -2,13 +2,14 @@ use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use worktrunk::config::{CiConfig, CiProvider};
 use worktrunk::git::{GitRemoteUrl, Repository};
 use worktrunk::shell_exec::run;
 use worktrunk::utils::get_now;
 
 /// CI platform detected from remote URL
-// TODO: Add a `[ci] platform = "github" | "gitlab"` override in project config
-// for cases where URL detection fails or users want to force a specific platform.
+///
+/// `[ci] provider` overrides detection (see [`PrStatus::detect`]).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CiPlatform {
     GitHub,
@@ -33,6 +34,18 @@ pub fn get_platform_for_repo(repo_root: &str) -> Option<CiPlatform> {
     detect_platform_from_url(&url)
 }
 
+/// Get the CI platform for a repository, preferring the `[ci] provider` setting.
+///
+/// Returns None for Gerrit and `provider = "none"`, which don't use `gh`/`glab`.
+pub fn get_configured_platform(repo_root: &str, ci: &CiConfig) -> Option<CiPlatform> {
+    match ci.provider {
+        Some(CiProvider::GitHub) => Some(CiPlatform::GitHub),
+        Some(CiProvider::GitLab) => Some(CiPlatform::GitLab),
+        Some(CiProvider::Gerrit | CiProvider::None) => None,
+        None => get_platform_for_repo(repo_root),
+    }
+}
+
 /// Get the origin remote URL for a repository.
 fn get_remote_url_for_repo(repo_root: &str) -> Option<String> {
     let mut cmd = Command::new("git");
@@ -586,6 +599,61 @@ mod tests {
         assert_eq!(aggregate_github_checks(&checks), CiStatus::Failed);
     }
 
+    #[test]
+    fn test_gerrit_change_ci_status() {
+        let changes = parse_gerrit_changes(
+            br#")]}'
+[
+  {"project": "a", "_number": 1, "labels": {"Verified": {"approved": {"_account_id": 1}}}},
+  {"project": "a", "_number": 2, "labels": {"Verified": {"rejected": {}, "approved": {}}}},
+  {"project": "a", "_number": 3, "labels": {"Verified": {}}},
+  {"project": "a", "_number": 4, "labels": {"Code-Review": {}}}
+]"#,
+            "abc123",
+        )
+        .unwrap();
+        let statuses: Vec<_> = changes.iter().map(GerritChange::ci_status).collect();
+        assert_eq!(
+            statuses,
+            [
+                CiStatus::Passed,
+                CiStatus::Failed,
+                CiStatus::Running,
+                CiStatus::NoCI
+            ]
+        );
+
+        // The XSSI prefix is optional
+        assert!(parse_gerrit_changes(b"[]", "abc123").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_curl_config_quote() {
+        assert_eq!(curl_config_quote("bot:pw"), r#""bot:pw""#);
+        assert_eq!(curl_config_quote(r#"a"b\c"#), r#""a\"b\\c""#);
+    }
+
+    #[test]
+    fn test_get_configured_platform_prefers_provider() {
+        let ci = |provider| CiConfig {
+            provider: Some(provider),
+            ..Default::default()
+        };
+        // No repository needed when the provider is set
+        assert_eq!(
+            get_configured_platform("/nonexistent", &ci(CiProvider::GitLab)),
+            Some(CiPlatform::GitLab)
+        );
+        assert_eq!(
+            get_configured_platform("/nonexistent", &ci(CiProvider::GitHub)),
+            Some(CiPlatform::GitHub)
+        );
+        assert_eq!(
+            get_configured_platform("/nonexistent", &ci(CiProvider::Gerrit)),
+            None
+        );
+    }
+
     #[test]
     fn test_parse_owner_repo() {
         // GitHub HTTPS
@@ -753,13 +821,13 @@ mod tests {
 /// could be on page 2+ and not be found. This is a trade-off: pagination would
 /// require multiple API calls and slow down status detection. In practice, 20
 /// is sufficient for most workflows.
-const MAX_PRS_TO_FETCH: u8 = 20;
+pub(crate) const MAX_PRS_TO_FETCH: u8 = 20;
 
 /// Get the owner of the origin remote (for GitHub fork detection).
 ///
 /// Used for client-side filtering of PRs by source repository.
 /// See [`parse_remote_owner`] for details on why this is necessary.
-fn get_origin_owner(repo_root: &str) -> Option<String> {
+pub(crate) fn get_origin_owner(repo_root: &str) -> Option<String> {
     let url = get_remote_url_for_repo(repo_root)?;
     parse_remote_owner(&url)
 }
@@ -785,9 +853,9 @@ fn get_owner_repo(repo_root: &str) -> Option<(String, String)> {
 /// the repo is actually GitLab-hosted. If glab is installed but the repo
 /// is GitHub, this adds an unnecessary CLI call. A future optimization
 /// could check the remote URL first and skip for non-GitLab remotes.
-fn get_gitlab_project_id(repo_root: &str) -> Option<u64> {
+fn get_gitlab_project_id(repo_root: &str, ci: &CiConfig) -> Option<u64> {
     // Use glab repo view to get the project info as JSON
-    let mut cmd = Command::new("glab");
+    let mut cmd = provider_command("glab", ci);
     cmd.args(["repo", "view", "--output", "json"]);
     cmd.current_dir(repo_root);
     // Disable color/pager to avoid ANSI noise in JSON output
@@ -817,7 +885,7 @@ fn get_gitlab_project_id(repo_root: &str) -> Option<u64> {
 /// - Prompting for user input (stdin set to /dev/null)
 /// - Using TTY-specific output formatting
 /// - Opening browsers for authentication
-fn configure_non_interactive(cmd: &mut Command) {
+pub(crate) fn configure_non_interactive(cmd: &mut Command) {
     use std::process::Stdio;
     cmd.stdin(Stdio::null());
     cmd.env_remove("CLICOLOR_FORCE");
@@ -827,6 +895,26 @@ fn configure_non_interactive(cmd: &mut Command) {
     cmd.env("GH_PROMPT_DISABLED", "1");
 }
 
+/// Build a `gh` or `glab` command pointed at the `[ci]` API and token, if configured.
+///
+/// `gh` takes a host name (`GH_HOST`), `glab` a URL (`GITLAB_HOST`); a token
+/// from `token-env` is passed as `GH_TOKEN`/`GITLAB_TOKEN`.
+pub(crate) fn provider_command(tool: &str, ci: &CiConfig) -> Command {
+    let mut cmd = Command::new(tool);
+    let (host_var, host, token_var) = if tool == "gh" {
+        ("GH_HOST", ci.api_host().map(str::to_string), "GH_TOKEN")
+    } else {
+        ("GITLAB_HOST", ci.api_url.clone(), "GITLAB_TOKEN")
+    };
+    if let Some(host) = host {
+        cmd.env(host_var, host);
+    }
+    if let Some(token) = ci.token() {
+        cmd.env(token_var, token);
+    }
+    cmd
+}
+
 /// Check if a CLI tool is available
 ///
 /// On Windows, this uses `cmd.exe /c` to properly resolve batch files (.cmd/.bat)
@@ -1281,12 +1369,19 @@ impl PrStatus {
     /// * `repo_path` - Repository root path from `Repository::worktree_root()`
     /// * `has_upstream` - Whether the branch has upstream tracking configured.
     ///   PR/MR detection always runs. Workflow/pipeline fallback only runs if true.
+    /// * `ci` - The `[ci]` config: provider override, self-hosted API URL, and token.
+    ///   With `provider = "none"`, nothing is fetched.
     pub fn detect(
         branch: &str,
         local_head: &str,
         repo_path: &std::path::Path,
         has_upstream: bool,
+        ci: &CiConfig,
     ) -> Option<Self> {
+        if ci.is_disabled() {
+            return None;
+        }
+
         // We run gh/glab commands from the repo directory to let them auto-detect the correct repo
         // (including upstream repos for forks)
         let repo_root = repo_path.to_str()?;
@@ -1315,7 +1410,7 @@ impl PrStatus {
         }
 
         // Cache miss or expired - fetch fresh status
-        let status = Self::detect_uncached(branch, local_head, repo_root, has_upstream);
+        let status = Self::detect_uncached(branch, local_head, repo_root, has_upstream, ci);
 
         // Cache the result (including None - means no CI found for this branch)
         let cached = CachedCiStatus {
@@ -1330,25 +1425,27 @@ impl PrStatus {
 
     /// Detect CI status without caching (internal implementation)
     ///
-    /// Platform is determined by the remote URL (github.com vs gitlab.com).
-    /// For unknown platforms (e.g., GitHub Enterprise with custom domains), falls back
-    /// to trying both platforms.
+    /// Platform is `[ci] provider` when set, else determined by the remote URL
+    /// (github.com vs gitlab.com). For unknown platforms (e.g., GitHub Enterprise
+    /// with custom domains), falls back to trying both platforms.
     /// PR/MR detection always runs. Workflow/pipeline fallback only runs if `has_upstream`.
     fn detect_uncached(
         branch: &str,
         local_head: &str,
         repo_root: &str,
         has_upstream: bool,
+        ci: &CiConfig,
     ) -> Option<Self> {
-        // Determine platform from remote URL
-        let platform = get_platform_for_repo(repo_root);
+        if ci.provider == Some(CiProvider::Gerrit) {
+            return Self::detect_gerrit(local_head, ci);
+        }
 
-        match platform {
+        match get_configured_platform(repo_root, ci) {
             Some(CiPlatform::GitHub) => {
-                Self::detect_github_ci(branch, local_head, repo_root, has_upstream)
+                Self::detect_github_ci(branch, local_head, repo_root, has_upstream, ci)
             }
             Some(CiPlatform::GitLab) => {
-                Self::detect_gitlab_ci(branch, local_head, repo_root, has_upstream)
+                Self::detect_gitlab_ci(branch, local_head, repo_root, has_upstream, ci)
             }
             None => {
                 // Unknown platform (e.g., GitHub Enterprise, self-hosted GitLab with custom domain)
@@ -1357,8 +1454,9 @@ impl PrStatus {
                     "Could not determine CI platform for {}, trying both",
                     repo_root
                 );
-                Self::detect_github_ci(branch, local_head, repo_root, has_upstream)
-                    .or_else(|| Self::detect_gitlab_ci(branch, local_head, repo_root, has_upstream))
+                Self::detect_github_ci(branch, local_head, repo_root, has_upstream, ci).or_else(
+                    || Self::detect_gitlab_ci(branch, local_head, repo_root, has_upstream, ci),
+                )
             }
         }
     }
@@ -1369,12 +1467,13 @@ impl PrStatus {
         local_head: &str,
         repo_root: &str,
         has_upstream: bool,
+        ci: &CiConfig,
     ) -> Option<Self> {
-        if let Some(status) = Self::detect_github(branch, local_head, repo_root) {
+        if let Some(status) = Self::detect_github(branch, local_head, repo_root, ci) {
             return Some(status);
         }
         if has_upstream {
-            return Self::detect_github_commit_checks(local_head, repo_root);
+            return Self::detect_github_commit_checks(local_head, repo_root, ci);
         }
         None
     }
@@ -1385,12 +1484,13 @@ impl PrStatus {
         local_head: &str,
         repo_root: &str,
         has_upstream: bool,
+        ci: &CiConfig,
     ) -> Option<Self> {
-        if let Some(status) = Self::detect_gitlab(branch, local_head, repo_root) {
+        if let Some(status) = Self::detect_gitlab(branch, local_head, repo_root, ci) {
             return Some(status);
         }
         if has_upstream {
-            return Self::detect_gitlab_pipeline(branch, local_head);
+            return Self::detect_gitlab_pipeline(branch, local_head, ci);
         }
         None
     }
@@ -1412,10 +1512,15 @@ impl PrStatus {
     /// - Fork workflows (PRs from your fork to upstream)
     /// - Organization repos (PRs from org branches)
     /// - Multiple users with same branch name
-    fn detect_github(branch: &str, local_head: &str, repo_root: &str) -> Option<Self> {
+    fn detect_github(
+        branch: &str,
+        local_head: &str,
+        repo_root: &str,
+        ci: &CiConfig,
+    ) -> Option<Self> {
         use std::process::Stdio;
         // Check if gh is available and authenticated
-        let mut auth_cmd = Command::new("gh");
+        let mut auth_cmd = provider_command("gh", ci);
         auth_cmd.args(["auth", "status"]);
         auth_cmd.stdin(Stdio::null());
         match run(&mut auth_cmd, None) {
@@ -1442,7 +1547,7 @@ impl PrStatus {
         //
         // We fetch up to MAX_PRS_TO_FETCH PRs to handle branch name collisions, then filter
         // client-side by headRepositoryOwner to find PRs from our fork.
-        let mut cmd = Command::new("gh");
+        let mut cmd = provider_command("gh", ci);
         cmd.args([
             "pr",
             "list",
@@ -1540,20 +1645,25 @@ impl PrStatus {
     /// 1. Get the current project ID via `glab repo view`
     /// 2. Fetch all open MRs with matching branch name (up to 20)
     /// 3. Filter client-side by comparing `source_project_id` to our project ID
-    fn detect_gitlab(branch: &str, local_head: &str, repo_root: &str) -> Option<Self> {
+    fn detect_gitlab(
+        branch: &str,
+        local_head: &str,
+        repo_root: &str,
+        ci: &CiConfig,
+    ) -> Option<Self> {
         if !tool_available("glab", &["--version"]) {
             return None;
         }
 
         // Get current project ID for filtering
-        let project_id = get_gitlab_project_id(repo_root);
+        let project_id = get_gitlab_project_id(repo_root, ci);
         if project_id.is_none() {
             log::debug!("Could not determine GitLab project ID for {}", repo_root);
         }
 
         // Fetch MRs with matching source branch.
         // We filter client-side by source_project_id (numeric project ID comparison).
-        let mut cmd = Command::new("glab");
+        let mut cmd = provider_command("glab", ci);
         cmd.args([
             "mr",
             "list",
@@ -1643,16 +1753,24 @@ impl PrStatus {
     /// This queries all check runs for the commit SHA, giving us the same data
     /// that `statusCheckRollup` provides for PRs. This correctly aggregates
     /// status across multiple workflows (e.g., `ci` and `publish-docs`).
-    fn detect_github_commit_checks(local_head: &str, repo_root: &str) -> Option<Self> {
+    fn detect_github_commit_checks(
+        local_head: &str,
+        repo_root: &str,
+        ci: &CiConfig,
+    ) -> Option<Self> {
         // Note: We don't log auth failures here since detect_github already logged them
-        if !tool_available("gh", &["auth", "status"]) {
+        let mut auth_cmd = provider_command("gh", ci);
+        auth_cmd
+            .args(["auth", "status"])
+            .stdin(std::process::Stdio::null());
+        if !run(&mut auth_cmd, None).is_ok_and(|o| o.status.success()) {
             return None;
         }
 
         let (owner, repo) = get_owner_repo(repo_root)?;
 
         // Use GitHub's check-runs API to get all checks for this commit
-        let mut cmd = Command::new("gh");
+        let mut cmd = provider_command("gh", ci);
         cmd.args([
             "api",
             &format!("repos/{owner}/{repo}/commits/{local_head}/check-runs"),
@@ -1700,14 +1818,14 @@ impl PrStatus {
         })
     }
 
-    fn detect_gitlab_pipeline(branch: &str, local_head: &str) -> Option<Self> {
+    fn detect_gitlab_pipeline(branch: &str, local_head: &str, ci: &CiConfig) -> Option<Self> {
         if !tool_available("glab", &["--version"]) {
             return None;
         }
 
         // Get most recent pipeline for the branch using JSON output
         use std::process::Stdio;
-        let mut cmd = Command::new("glab");
+        let mut cmd = provider_command("glab", ci);
         cmd.args(["ci", "list", "--per-page", "1", "--output", "json"])
             .env("BRANCH", branch) // glab ci list uses BRANCH env var
             .stdin(Stdio::null());
@@ -1747,6 +1865,115 @@ impl PrStatus {
             url: pipeline.web_url.clone(),
         })
     }
+
+    /// Detect CI status for a commit from its Gerrit change's `Verified` label.
+    ///
+    /// Queries `<api-url>/changes/?q=commit:<sha>` with `curl`. With a token
+    /// (`user:http-password`), the authenticated `/a/` endpoint is used; the
+    /// request goes to curl on stdin so the token stays out of process listings
+    /// and logs.
+    fn detect_gerrit(local_head: &str, ci: &CiConfig) -> Option<Self> {
+        use std::io::Write;
+        use std::process::Stdio;
+
+        let base = ci.api_url.as_deref()?.trim_end_matches('/');
+        let token = ci.token();
+        let auth_prefix = if token.is_some() { "/a" } else { "" };
+        let url = format!("{base}{auth_prefix}/changes/?q=commit:{local_head}&o=LABELS&n=1");
+
+        let mut request = format!("url = {}\n", curl_config_quote(&url));
+        if let Some(token) = &token {
+            request.push_str(&format!("user = {}\n", curl_config_quote(token)));
+        }
+
+        let mut cmd = Command::new("curl");
+        cmd.args([
+            "--silent",
+            "--show-error",
+            "--fail",
+            "--location",
+            "--config",
+            "-",
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR);
+        log::debug!("$ curl {base}{auth_prefix}/changes/?q=commit:{local_head}");
+
+        let output = cmd.spawn().and_then(|mut child| {
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin.write_all(request.as_bytes())?;
+            }
+            child.wait_with_output()
+        });
+        let output = match output {
+            Ok(output) => output,
+            Err(e) => {
+                log::warn!("curl failed to execute for {}: {}", local_head, e);
+                return None;
+            }
+        };
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if is_retriable_error(&stderr) {
+                return Some(Self::error());
+            }
+            log::debug!("Gerrit query failed for {}: {}", local_head, stderr.trim());
+            return None;
+        }
+
+        let changes = parse_gerrit_changes(&output.stdout, local_head)?;
+        let change = changes.first()?;
+        Some(PrStatus {
+            ci_status: change.ci_status(),
+            source: CiSource::PullRequest,
+            is_stale: false, // We're querying by SHA, so always current
+            url: Some(format!("{base}/c/{}/+/{}", change.project, change.number)),
+        })
+    }
+}
+
+/// Gerrit change from `GET /changes/?o=LABELS`
+#[derive(Debug, Deserialize)]
+struct GerritChange {
+    project: String,
+    #[serde(rename = "_number")]
+    number: u64,
+    #[serde(default)]
+    labels: std::collections::HashMap<String, GerritLabel>,
+}
+
+/// A label's summary: which of its extreme votes have been cast
+#[derive(Debug, Default, Deserialize)]
+struct GerritLabel {
+    approved: Option<serde_json::Value>,
+    rejected: Option<serde_json::Value>,
+}
+
+impl GerritChange {
+    /// Status from the `Verified` label, which CI systems vote on
+    fn ci_status(&self) -> CiStatus {
+        match self.labels.get("Verified") {
+            None => CiStatus::NoCI,
+            Some(label) if label.rejected.is_some() => CiStatus::Failed,
+            Some(label) if label.approved.is_some() => CiStatus::Passed,
+            Some(_) => CiStatus::Running,
+        }
+    }
+}
+
+/// Parse a Gerrit REST response, which starts with `)]}'` to guard against XSSI
+fn parse_gerrit_changes(stdout: &[u8], local_head: &str) -> Option<Vec<GerritChange>> {
+    let body = String::from_utf8_lossy(stdout);
+    let json = body.trim_start().strip_prefix(")]}'").unwrap_or(&body);
+    parse_json(json.as_bytes(), "gerrit changes", local_head)
+}
+
+/// Quote a value for a curl config file
+fn curl_config_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
 }
 
 /// GitHub PR info from `gh pr list --json ...`
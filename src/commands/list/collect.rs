@@ -48,29 +48,42 @@ use crossbeam_channel as chan;
 use dunce::canonicalize;
 use rayon::prelude::*;
 use rayon_join_macro::join;
+use worktrunk::config::ListColumn;
 use worktrunk::git::{LineDiff, Repository, Worktree};
-use worktrunk::styling::{INFO_SYMBOL, format_with_gutter, warning_message};
+use worktrunk::styling::{
+    INFO_SYMBOL, format_with_gutter, hint_message, suggest_command, warning_message,
+};
 
 use crate::commands::is_worktree_at_expected_path_with;
 
 use super::ci_status::PrStatus;
 use super::model::{
-    AheadBehind, BranchDiffTotals, CommitDetails, DisplayFields, GitOperationState, ItemKind,
-    ListItem, UpstreamStatus, WorktreeData,
+    AheadBehind, BranchDiffTotals, CommitDetails, CommitSignature, DisplayFields,
+    GitOperationState, ItemKind, ListItem, OperationProgress, ResolutionState, SubmoduleStatus,
+    UpstreamStatus, WorktreeData,
 };
 
 use super::model::WorkingTreeStatus;
 
+/// Result of a `git merge-tree` conflict simulation against main
+#[derive(Debug, Clone, Default)]
+pub(super) struct ConflictCheck {
+    pub would_conflict: bool,
+    /// Conflicting paths; empty when git refused the merge without listing
+    /// any (e.g. unrelated histories)
+    pub paths: Vec<String>,
+}
+
 /// Context for status symbol computation during result processing
 #[derive(Clone, Default)]
 struct StatusContext {
-    has_merge_tree_conflicts: bool,
+    merge_tree_conflicts: ConflictCheck,
     /// Working tree conflict check result (--full only, worktrees only).
     /// None = use commit check (task didn't run or working tree clean)
     /// Some(b) = dirty working tree, b is conflict result
     // TODO: If we need to distinguish "task didn't run" from "clean working tree",
     // expand to an enum. Currently both cases fall back to commit-based check.
-    has_working_tree_conflicts: Option<bool>,
+    working_tree_conflicts: Option<ConflictCheck>,
     user_marker: Option<String>,
     working_tree_status: Option<WorkingTreeStatus>,
     has_conflicts: bool,
@@ -82,13 +95,15 @@ impl StatusContext {
         //
         // Prefer working tree conflicts (--full) when available.
         // None means task didn't run or working tree was clean - use commit check.
-        let has_conflicts = self
-            .has_working_tree_conflicts
-            .unwrap_or(self.has_merge_tree_conflicts);
+        let conflicts = self
+            .working_tree_conflicts
+            .as_ref()
+            .unwrap_or(&self.merge_tree_conflicts);
+        item.conflict_paths = conflicts.paths.clone();
 
         item.compute_status_symbols(
             Some(target),
-            has_conflicts,
+            conflicts.would_conflict,
             self.user_marker.clone(),
             self.working_tree_status,
             self.has_conflicts,
@@ -154,11 +169,17 @@ pub(super) enum TaskResult {
         /// Working tree change flags
         working_tree_status: WorkingTreeStatus,
         has_conflicts: bool,
+        /// Submodule state (None when the worktree has no submodules)
+        submodules: Option<SubmoduleStatus>,
+        /// LFS files not pulled (None when LFS isn't in use)
+        lfs_missing: Option<usize>,
+        /// Background hook commands still running (None when there are none)
+        hooks_running: Option<usize>,
     },
     /// Potential merge conflicts with main (merge-tree simulation on committed HEAD)
     MergeTreeConflicts {
         item_idx: usize,
-        has_merge_tree_conflicts: bool,
+        merge_tree_conflicts: ConflictCheck,
     },
     /// Potential merge conflicts including working tree changes (--full only)
     ///
@@ -168,20 +189,28 @@ pub(super) enum TaskResult {
     WorkingTreeConflicts {
         item_idx: usize,
         /// None = working tree clean (use MergeTreeConflicts result)
-        /// Some(true) = dirty working tree would conflict
-        /// Some(false) = dirty working tree would not conflict
-        has_working_tree_conflicts: Option<bool>,
+        /// Some(check) = result for the dirty working tree
+        working_tree_conflicts: Option<ConflictCheck>,
     },
     /// Git operation in progress (rebase/merge)
     GitOperation {
         item_idx: usize,
         git_operation: GitOperationState,
+        /// Conflict counts (None when no operation is in progress)
+        resolution: Option<ResolutionState>,
+        /// Rebase step counters (None unless a rebase is in progress)
+        operation_progress: Option<OperationProgress>,
     },
     /// User-defined status from git config
     UserMarker {
         item_idx: usize,
         user_marker: Option<String>,
     },
+    /// Nearest tag for a detached HEAD
+    Describe {
+        item_idx: usize,
+        describe: Option<String>,
+    },
     /// Upstream tracking status
     Upstream {
         item_idx: usize,
@@ -192,6 +221,16 @@ pub(super) enum TaskResult {
         item_idx: usize,
         pr_status: Option<PrStatus>,
     },
+    /// Tip commit signature (`--full` or the `signature` column only)
+    Signature {
+        item_idx: usize,
+        signature: CommitSignature,
+    },
+    /// Branch description from `branch.<name>.description`
+    Description {
+        item_idx: usize,
+        description: Option<String>,
+    },
     /// URL status (expanded URL and health check result)
     UrlStatus {
         item_idx: usize,
@@ -218,8 +257,11 @@ impl TaskResult {
             | TaskResult::WorkingTreeConflicts { item_idx, .. }
             | TaskResult::GitOperation { item_idx, .. }
             | TaskResult::UserMarker { item_idx, .. }
+            | TaskResult::Describe { item_idx, .. }
             | TaskResult::Upstream { item_idx, .. }
             | TaskResult::CiStatus { item_idx, .. }
+            | TaskResult::Signature { item_idx, .. }
+            | TaskResult::Description { item_idx, .. }
             | TaskResult::UrlStatus { item_idx, .. } => *item_idx,
         }
     }
@@ -360,11 +402,11 @@ fn apply_default(items: &mut [ListItem], status_contexts: &mut [StatusContext],
         }
         TaskKind::MergeTreeConflicts => {
             // Don't show conflict symbol if we couldn't check
-            status_contexts[idx].has_merge_tree_conflicts = false;
+            status_contexts[idx].merge_tree_conflicts = ConflictCheck::default();
         }
         TaskKind::WorkingTreeConflicts => {
             // Fall back to commit-based check on failure
-            status_contexts[idx].has_working_tree_conflicts = None;
+            status_contexts[idx].working_tree_conflicts = None;
         }
         TaskKind::GitOperation => {
             // Already defaults to GitOperationState::None in WorktreeData
@@ -373,6 +415,9 @@ fn apply_default(items: &mut [ListItem], status_contexts: &mut [StatusContext],
             // Already defaults to None
             status_contexts[idx].user_marker = None;
         }
+        TaskKind::Describe => {
+            // Already defaults to None in WorktreeData
+        }
         TaskKind::Upstream => {
             items[idx].upstream = Some(UpstreamStatus::default());
         }
@@ -380,6 +425,13 @@ fn apply_default(items: &mut [ListItem], status_contexts: &mut [StatusContext],
             // Some(None) means "loaded but no CI"
             items[idx].pr_status = Some(None);
         }
+        TaskKind::Signature => {
+            // Couldn't check, so don't claim either way
+            items[idx].signature = Some(CommitSignature::default());
+        }
+        TaskKind::Description => {
+            items[idx].description = Some(None);
+        }
         TaskKind::UrlStatus => {
             // URL is set at item creation, only default url_active
             items[idx].url_active = None;
@@ -532,11 +584,17 @@ fn drain_results(
                 working_tree_diff_with_main,
                 working_tree_status,
                 has_conflicts,
+                submodules,
+                lfs_missing,
+                hooks_running,
                 ..
             } => {
                 if let ItemKind::Worktree(data) = &mut item.kind {
                     data.working_tree_diff = Some(working_tree_diff);
                     data.working_tree_diff_with_main = Some(working_tree_diff_with_main);
+                    data.submodules = submodules;
+                    data.lfs_missing = lfs_missing;
+                    data.hooks_running = hooks_running;
                 } else {
                     debug_assert!(false, "WorkingTreeDiff result for non-worktree item");
                 }
@@ -545,22 +603,29 @@ fn drain_results(
                 status_ctx.has_conflicts = has_conflicts;
             }
             TaskResult::MergeTreeConflicts {
-                has_merge_tree_conflicts,
+                merge_tree_conflicts,
                 ..
             } => {
                 // Store for status_symbols computation
-                status_ctx.has_merge_tree_conflicts = has_merge_tree_conflicts;
+                status_ctx.merge_tree_conflicts = merge_tree_conflicts;
             }
             TaskResult::WorkingTreeConflicts {
-                has_working_tree_conflicts,
+                working_tree_conflicts,
                 ..
             } => {
                 // Store for status_symbols computation (takes precedence over commit check)
-                status_ctx.has_working_tree_conflicts = has_working_tree_conflicts;
+                status_ctx.working_tree_conflicts = working_tree_conflicts;
             }
-            TaskResult::GitOperation { git_operation, .. } => {
+            TaskResult::GitOperation {
+                git_operation,
+                resolution,
+                operation_progress,
+                ..
+            } => {
                 if let ItemKind::Worktree(data) = &mut item.kind {
                     data.git_operation = git_operation;
+                    data.resolution = resolution;
+                    data.operation_progress = operation_progress;
                 } else {
                     debug_assert!(false, "GitOperation result for non-worktree item");
                 }
@@ -569,6 +634,13 @@ fn drain_results(
                 // Store for status_symbols computation
                 status_ctx.user_marker = user_marker;
             }
+            TaskResult::Describe { describe, .. } => {
+                if let ItemKind::Worktree(data) = &mut item.kind {
+                    data.describe = describe;
+                } else {
+                    debug_assert!(false, "Describe result for non-worktree item");
+                }
+            }
             TaskResult::Upstream { upstream, .. } => {
                 item.upstream = Some(upstream);
             }
@@ -576,6 +648,12 @@ fn drain_results(
                 // Wrap in Some() to indicate "loaded" (Some(None) = no CI, Some(Some(status)) = has CI)
                 item.pr_status = Some(pr_status);
             }
+            TaskResult::Signature { signature, .. } => {
+                item.signature = Some(signature);
+            }
+            TaskResult::Description { description, .. } => {
+                item.description = Some(description);
+            }
             TaskResult::UrlStatus { url, active, .. } => {
                 // Two-phase URL rendering:
                 // 1. First result (from spawning code): url=Some, active=None → URL appears in normal styling
@@ -597,6 +675,40 @@ fn drain_results(
     DrainOutcome::Complete
 }
 
+/// Glob patterns (`--branches 'feature/*'`) restricting which branches
+/// without worktrees are listed. Worktrees are always listed.
+#[derive(Debug, Default, Clone)]
+pub struct BranchFilter {
+    patterns: Vec<glob::Pattern>,
+}
+
+impl BranchFilter {
+    pub fn new(patterns: &[String]) -> anyhow::Result<Self> {
+        let patterns = patterns
+            .iter()
+            .map(|pattern| {
+                glob::Pattern::new(pattern)
+                    .with_context(|| format!("Invalid branch pattern '{pattern}'"))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self { patterns })
+    }
+
+    /// Whether `branch` matches any pattern (or there are none)
+    pub fn matches(&self, branch: &str) -> bool {
+        self.patterns.is_empty() || self.patterns.iter().any(|p| p.matches(branch))
+    }
+
+    /// Like [`matches`](Self::matches), for a remote branch such as
+    /// `origin/feature/x`, which matches `feature/*` as well as `origin/*`
+    fn matches_remote(&self, remote_branch: &str) -> bool {
+        self.matches(remote_branch)
+            || remote_branch
+                .split_once('/')
+                .is_some_and(|(_, branch)| self.matches(branch))
+    }
+}
+
 /// Get branches that don't have worktrees.
 ///
 /// Returns (branch_name, commit_sha) pairs for all branches without associated worktrees.
@@ -626,6 +738,18 @@ fn worktree_branch_set(worktrees: &[Worktree]) -> std::collections::HashSet<&str
         .collect()
 }
 
+/// Progress of [`collect_with_observer`], for callers that render rows
+/// themselves as data arrives (`wt select`)
+pub enum CollectEvent<'a> {
+    /// Every row is known, with only its basic data (branch, path, commit)
+    Started {
+        items: &'a [ListItem],
+        main_worktree_path: &'a std::path::Path,
+    },
+    /// More of row `item_idx`'s data has arrived
+    Updated { item_idx: usize, item: &'a ListItem },
+}
+
 /// Collect worktree data with optional progressive rendering.
 ///
 /// When `show_progress` is true, renders a skeleton immediately and updates as data arrives.
@@ -637,10 +761,40 @@ pub fn collect(
     repo: &Repository,
     show_branches: bool,
     show_remotes: bool,
+    branch_filter: &BranchFilter,
+    skip_tasks: &std::collections::HashSet<TaskKind>,
+    show_progress: bool,
+    render_table: bool,
+    config: &worktrunk::config::WorktrunkConfig,
+) -> anyhow::Result<Option<super::model::ListData>> {
+    collect_with_observer(
+        repo,
+        show_branches,
+        show_remotes,
+        branch_filter,
+        skip_tasks,
+        show_progress,
+        render_table,
+        config,
+        None,
+    )
+}
+
+/// [`collect`], reporting each row to `observer` as it starts and fills in.
+///
+/// An observer owns the terminal while collection runs, so collection
+/// problems are logged rather than printed as warnings.
+#[allow(clippy::too_many_arguments)]
+pub fn collect_with_observer(
+    repo: &Repository,
+    show_branches: bool,
+    show_remotes: bool,
+    branch_filter: &BranchFilter,
     skip_tasks: &std::collections::HashSet<TaskKind>,
     show_progress: bool,
     render_table: bool,
     config: &worktrunk::config::WorktrunkConfig,
+    mut observer: Option<&mut dyn FnMut(CollectEvent<'_>)>,
 ) -> anyhow::Result<Option<super::model::ListData>> {
     use super::progressive_table::ProgressiveTable;
 
@@ -686,8 +840,15 @@ pub fn collect(
         }
     );
     let default_branch = default_branch?;
-    let branches_without_worktrees = branches_without_worktrees?;
-    let remote_branches = remote_branches?;
+    // Filter before anything is computed for them
+    let branches_without_worktrees: Vec<_> = branches_without_worktrees?
+        .into_iter()
+        .filter(|(name, _)| branch_filter.matches(name))
+        .collect();
+    let remote_branches: Vec<_> = remote_branches?
+        .into_iter()
+        .filter(|(name, _)| branch_filter.matches_remote(name))
+        .collect();
 
     // Main worktree is the worktree on the default branch (if exists), else first worktree
     let main_worktree = worktrees
@@ -778,8 +939,13 @@ pub fn collect(
                 has_file_changes: None,
                 would_merge_add: None,
                 is_ancestor: None,
+                conflict_paths: Vec::new(),
+                is_remote: false,
+                ordinal: None,
                 upstream: None,
                 pr_status: None,
+                signature: None,
+                description: None,
                 url: None,
                 url_active: None,
                 status_symbols: None,
@@ -798,33 +964,64 @@ pub fn collect(
     );
 
     let remote_start_idx = all_items.len();
-    all_items.extend(
-        remote_branches
-            .iter()
-            .map(|(name, sha)| ListItem::new_branch(sha.clone(), name.clone())),
-    );
+    all_items.extend(remote_branches.iter().map(|(name, sha)| ListItem {
+        is_remote: true,
+        ..ListItem::new_branch(sha.clone(), name.clone())
+    }));
+
+    // Row numbers for the `#` column and `wt switch 3`
+    for (idx, item) in all_items.iter_mut().enumerate() {
+        item.ordinal = Some(idx + 1);
+    }
 
     // If no URL template configured, add UrlStatus to skip_tasks
     let mut effective_skip_tasks = skip_tasks.clone();
     if url_template.is_none() {
         effective_skip_tasks.insert(TaskKind::UrlStatus);
     }
+    // Opt-in columns: selecting one computes it regardless of `--full`, and
+    // tables that don't show it skip the work
+    for (column, task) in [
+        (ListColumn::Signature, TaskKind::Signature),
+        (ListColumn::Description, TaskKind::Description),
+    ] {
+        if config.list_columns().contains(&column) {
+            effective_skip_tasks.remove(&task);
+        } else if render_table {
+            effective_skip_tasks.insert(task);
+        }
+    }
+    // The Files column counts the files in the branch diff
+    if config.list_columns().contains(&ListColumn::Files) {
+        effective_skip_tasks.remove(&TaskKind::BranchDiff);
+    }
 
     // Calculate layout from items (worktrees, local branches, and remote branches)
-    let layout = super::layout::calculate_layout_from_basics(
+    let mut layout = super::layout::calculate_layout_from_basics(
         &all_items,
         &effective_skip_tasks,
+        config.list_columns(),
         &main_worktree.path,
         url_template.as_deref(),
     );
+    layout.age_thresholds = super::layout::AgeThresholds::from_config(config);
+    layout.placeholder = super::layout::Placeholder::from_config(config);
 
     // Single-line invariant: use safe width to prevent line wrapping
     let max_width = super::layout::get_safe_list_width();
 
+    if let Some(observer) = observer.as_mut() {
+        observer(CollectEvent::Started {
+            items: &all_items,
+            main_worktree_path: &main_worktree.path,
+        });
+    }
+
     // Create collection options from skip set
     let options = super::collect_progressive_impl::CollectOptions {
         skip_tasks: effective_skip_tasks,
         url_template: url_template.clone(),
+        ci: config.ci(),
     };
 
     // Track expected results per item - populated as spawns are queued
@@ -940,14 +1137,31 @@ pub fn collect(
     let target_clone = integration_target.clone();
     let expected_results_wt = expected_results.clone();
     let options_wt = options.clone();
+    let main_path_wt = main_worktree.path.clone();
     std::thread::spawn(move || {
         sorted_worktrees_clone
             .par_iter()
             .enumerate()
-            .for_each(|(idx, wt)| {
+            .for_each(|(idx, wt)| match wt.branch.as_deref() {
+                // Git fails inside a worktree whose `.git` link is broken, so
+                // collect its branch from the main worktree, like a branch
+                // without a worktree
+                Some(branch) if wt.broken_gitdir().is_some() => {
+                    super::collect_progressive_impl::collect_branch_progressive(
+                        branch,
+                        &wt.head,
+                        &main_path_wt,
+                        idx,
+                        &default_branch_clone,
+                        &target_clone,
+                        &options_wt,
+                        tx_worktrees.clone(),
+                        &expected_results_wt,
+                    );
+                }
                 // Pass default_branch (local default) for stable informational stats,
                 // and target (effective target) for integration checks.
-                super::collect_progressive_impl::collect_worktree_progressive(
+                _ => super::collect_progressive_impl::collect_worktree_progressive(
                     wt,
                     idx,
                     &default_branch_clone,
@@ -955,7 +1169,7 @@ pub fn collect(
                     &options_wt,
                     tx_worktrees.clone(),
                     &expected_results_wt,
-                );
+                ),
             });
     });
 
@@ -1022,6 +1236,10 @@ pub fn collect(
             // This is idempotent and updates status as new data (like upstream) arrives.
             ctx.apply_to(item, integration_target.as_str());
 
+            if let Some(observer) = observer.as_mut() {
+                observer(CollectEvent::Updated { item_idx, item });
+            }
+
             // Progressive mode only: update UI
             if let Some(ref mut table) = progressive_table {
                 use anstyle::Style;
@@ -1082,7 +1300,11 @@ pub fn collect(
             "\n\nThis likely indicates a git command hung. Run with RUST_LOG=debug for details.",
         );
 
-        crate::output::print(warning_message(diag))?;
+        if observer.is_some() {
+            log::warn!("{diag}");
+        } else {
+            crate::output::print(warning_message(diag))?;
+        }
     }
 
     // Finalize progressive table or render buffered output
@@ -1149,7 +1371,50 @@ pub fn collect(
             "Some git operations failed:\n{}",
             format_with_gutter(&error_lines.join("\n"), None)
         );
-        crate::output::print(warning_message(warning))?;
+        if observer.is_some() {
+            log::warn!("{warning}");
+        } else {
+            crate::output::print(warning_message(warning))?;
+        }
+    }
+
+    if render_table
+        && all_items
+            .iter()
+            .filter_map(ListItem::worktree_data)
+            .any(|data| data.broken_gitdir.is_some())
+    {
+        crate::output::print(hint_message(cformat!(
+            "Worktrees marked <red>⊘</> have a broken <bold>.git</> link; to relink them, run <bright-black>wt repair</>"
+        )))?;
+    }
+
+    // Both ahead of and behind the upstream, as when a pull request branch is
+    // updated from the web while work goes on locally
+    let diverged: Vec<&str> = all_items
+        .iter()
+        .filter(|item| item.worktree_data().is_some())
+        .filter(|item| {
+            item.upstream
+                .as_ref()
+                .and_then(|upstream| upstream.active())
+                .is_some_and(|upstream| upstream.ahead > 0 && upstream.behind > 0)
+        })
+        .map(ListItem::branch_name)
+        .collect();
+    if render_table && let Some(first) = diverged.first() {
+        let cmd = suggest_command("sync", &[], &["--branch", first, "--rebase-onto-upstream"]);
+        let message = if diverged.len() == 1 {
+            cformat!(
+                "<bold>{first}</> has diverged from its upstream (⇅); to rebase onto it, run <bright-black>{cmd}</>"
+            )
+        } else {
+            cformat!(
+                "{} branches have diverged from their upstreams (⇅); to rebase one, run <bright-black>{cmd}</>",
+                diverged.len()
+            )
+        };
+        crate::output::print(hint_message(message))?;
     }
 
     // Populate display fields for all items (used by JSON output and statusline)
@@ -1166,9 +1431,15 @@ pub fn collect(
     // - Buffered: rendered final table (no progress bars)
     // JSON mode (render_table=false): no rendering, data returned for serialization
 
+    let index_shown = layout
+        .columns
+        .iter()
+        .any(|column| column.kind == super::columns::ColumnKind::Index);
+
     Ok(Some(super::model::ListData {
         items,
         main_worktree_path: main_worktree.path.clone(),
+        index_shown,
     }))
 }
 
@@ -1244,8 +1515,13 @@ pub fn build_worktree_item(
         has_file_changes: None,
         would_merge_add: None,
         is_ancestor: None,
+        conflict_paths: Vec::new(),
+        is_remote: false,
+        ordinal: None,
         upstream: None,
         pr_status: None,
+        signature: None,
+        description: None,
         url: None,
         url_active: None,
         status_symbols: None,
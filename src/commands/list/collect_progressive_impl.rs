@@ -20,9 +20,13 @@ use std::sync::Arc;
 use worktrunk::git::{LineDiff, Repository, Worktree};
 
 use super::ci_status::PrStatus;
-use super::collect::{ExpectedResults, TaskError, TaskKind, TaskResult, detect_git_operation};
+use super::collect::{
+    ConflictCheck, ExpectedResults, TaskError, TaskKind, TaskResult, detect_git_operation,
+};
 use super::model::{
-    AheadBehind, BranchDiffTotals, CommitDetails, UpstreamStatus, WorkingTreeStatus,
+    AheadBehind, BranchDiffTotals, CommitDetails, CommitSignature, GitOperationState,
+    OperationProgress, ResolutionState, SignatureStatus, SubmoduleStatus, UpstreamStatus,
+    WorkingTreeStatus,
 };
 
 // ============================================================================
@@ -45,6 +49,9 @@ pub struct CollectOptions {
     /// URL template from project config (e.g., "http://localhost:{{ branch | hash_port }}").
     /// Expanded per-item in task spawning (post-skeleton) to minimize time-to-skeleton.
     pub url_template: Option<String>,
+
+    /// The `[ci]` config, passed to CI status detection.
+    pub ci: worktrunk::config::CiConfig,
 }
 
 /// Context for task computation. Cloned and moved into spawned threads.
@@ -65,6 +72,8 @@ pub struct TaskContext {
     /// Expanded URL for this item (from project config template).
     /// UrlStatusTask uses this to check if the port is listening.
     pub item_url: Option<String>,
+    /// The `[ci]` config (provider, API URL, token) for CiStatusTask.
+    pub ci: worktrunk::config::CiConfig,
 }
 
 impl TaskContext {
@@ -173,6 +182,10 @@ impl TaskSpawner {
         self.spawn::<WorkingTreeDiffTask>(scope, ctx);
         self.spawn::<GitOperationTask>(scope, ctx);
         self.spawn::<UserMarkerTask>(scope, ctx);
+        // Detached worktrees have no branch name to show, so find the nearest tag
+        if ctx.branch.is_none() {
+            self.spawn::<DescribeTask>(scope, ctx);
+        }
         // Working tree conflict check only with --full
         if !skip.contains(&TaskKind::WorkingTreeConflicts) {
             self.spawn::<WorkingTreeConflictsTask>(scope, ctx);
@@ -197,6 +210,12 @@ impl TaskSpawner {
         if !skip.contains(&TaskKind::WouldMergeAdd) {
             self.spawn::<WouldMergeAddTask>(scope, ctx);
         }
+        if !skip.contains(&TaskKind::Signature) {
+            self.spawn::<SignatureTask>(scope, ctx);
+        }
+        if !skip.contains(&TaskKind::Description) {
+            self.spawn::<DescriptionTask>(scope, ctx);
+        }
         // URL status only runs if this item has a URL
         if !skip.contains(&TaskKind::UrlStatus) && ctx.item_url.is_some() {
             self.spawn::<UrlStatusTask>(scope, ctx);
@@ -233,16 +252,35 @@ impl Task for CommitDetailsTask {
 }
 
 /// Task 2: Ahead/behind counts vs local default branch (informational stats)
+///
+/// Branches created from a tag or commit (`--base v1.2.3`) count against that
+/// base instead, falling back to the default branch if it no longer resolves.
 pub struct AheadBehindTask;
 
 impl Task for AheadBehindTask {
     const KIND: TaskKind = TaskKind::AheadBehind;
 
     fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
-        let base = ctx.require_default_branch(Self::KIND)?;
         let repo = ctx.repo();
+        if let Some((ahead, behind)) = ctx
+            .branch
+            .as_deref()
+            .and_then(|branch| repo.branch_base(branch))
+            .and_then(|base| repo.ahead_behind(&base, &ctx.commit_sha).ok())
+        {
+            return Ok(TaskResult::AheadBehind {
+                item_idx: ctx.item_idx,
+                counts: AheadBehind { ahead, behind },
+            });
+        }
+
+        let base = ctx.require_default_branch(Self::KIND)?;
+        // With `--remote upstream`, count against `upstream/main`
+        let base = repo
+            .chosen_remote_branch(base)
+            .unwrap_or_else(|| base.to_string());
         let (ahead, behind) = repo
-            .ahead_behind(base, &ctx.commit_sha)
+            .ahead_behind(&base, &ctx.commit_sha)
             .map_err(|e| ctx.error(Self::KIND, e))?;
         Ok(TaskResult::AheadBehind {
             item_idx: ctx.item_idx,
@@ -406,6 +444,31 @@ impl Task for WorkingTreeDiffTask {
         let (working_tree_status, is_dirty, has_conflicts) =
             parse_working_tree_status(&status_output);
 
+        // Checking for .gitmodules keeps repos without submodules at one git call
+        let submodules = if ctx.repo_path.join(".gitmodules").exists() {
+            let submodule_output = repo
+                .run_command(&["submodule", "status"])
+                .map_err(|e| ctx.error(Self::KIND, e))?;
+            Some(parse_submodule_status(&submodule_output, &status_output))
+        } else {
+            None
+        };
+
+        // Without git-lfs installed there's nothing to report
+        let lfs_missing = if crate::commands::uses_lfs(&ctx.repo_path) {
+            repo.run_command(&["lfs", "ls-files"])
+                .ok()
+                .map(|output| count_lfs_pointers(&output))
+        } else {
+            None
+        };
+
+        let hooks_running = Some(crate::commands::hook_status::running_in(
+            &repo,
+            &ctx.repo_path,
+        ))
+        .filter(|&n| n > 0);
+
         let working_tree_diff = if is_dirty {
             repo.working_tree_diff_stats()
                 .map_err(|e| ctx.error(Self::KIND, e))?
@@ -424,6 +487,9 @@ impl Task for WorkingTreeDiffTask {
             working_tree_diff_with_main,
             working_tree_status,
             has_conflicts,
+            submodules,
+            lfs_missing,
+            hooks_running,
         })
     }
 }
@@ -440,12 +506,11 @@ impl Task for MergeTreeConflictsTask {
     fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
         let base = ctx.require_default_branch(Self::KIND)?;
         let repo = ctx.repo();
-        let has_merge_tree_conflicts = repo
-            .has_merge_conflicts(base, &ctx.commit_sha)
-            .map_err(|e| ctx.error(Self::KIND, e))?;
+        let merge_tree_conflicts =
+            check_conflicts(&repo, base, &ctx.commit_sha).map_err(|e| ctx.error(Self::KIND, e))?;
         Ok(TaskResult::MergeTreeConflicts {
             item_idx: ctx.item_idx,
-            has_merge_tree_conflicts,
+            merge_tree_conflicts,
         })
     }
 }
@@ -475,7 +540,7 @@ impl Task for WorkingTreeConflictsTask {
             // Clean working tree - return None to signal "use commit-based check"
             return Ok(TaskResult::WorkingTreeConflicts {
                 item_idx: ctx.item_idx,
-                has_working_tree_conflicts: None,
+                working_tree_conflicts: None,
             });
         }
 
@@ -493,7 +558,7 @@ impl Task for WorkingTreeConflictsTask {
                 // Fall back to commit-based check
                 return Ok(TaskResult::WorkingTreeConflicts {
                     item_idx: ctx.item_idx,
-                    has_working_tree_conflicts: None,
+                    working_tree_conflicts: None,
                 });
             }
         };
@@ -504,18 +569,17 @@ impl Task for WorkingTreeConflictsTask {
         if stash_sha.is_empty() {
             return Ok(TaskResult::WorkingTreeConflicts {
                 item_idx: ctx.item_idx,
-                has_working_tree_conflicts: None,
+                working_tree_conflicts: None,
             });
         }
 
         // Run merge-tree with the stash commit
-        let has_conflicts = repo
-            .has_merge_conflicts(base, stash_sha)
-            .map_err(|e| ctx.error(Self::KIND, e))?;
+        let conflicts =
+            check_conflicts(&repo, base, stash_sha).map_err(|e| ctx.error(Self::KIND, e))?;
 
         Ok(TaskResult::WorkingTreeConflicts {
             item_idx: ctx.item_idx,
-            has_working_tree_conflicts: Some(has_conflicts),
+            working_tree_conflicts: Some(conflicts),
         })
     }
 }
@@ -529,9 +593,33 @@ impl Task for GitOperationTask {
     fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
         let repo = ctx.repo();
         let git_operation = detect_git_operation(&repo);
+        let resolution = if git_operation == GitOperationState::None {
+            None
+        } else {
+            let unmerged = repo
+                .run_command(&["diff", "--name-only", "--diff-filter=U"])
+                .map_err(|e| ctx.error(Self::KIND, e))?;
+            // With rerere off, `rerere remaining` prints nothing, which would
+            // read as every path resolved
+            let remaining = if rerere_enabled(&repo) {
+                repo.run_command(&["rerere", "remaining"])
+                    .map_err(|e| ctx.error(Self::KIND, e))?
+            } else {
+                unmerged.clone()
+            };
+            Some(parse_resolution(&unmerged, &remaining))
+        };
+        let operation_progress = if git_operation == GitOperationState::Rebase {
+            repo.rebase_progress()
+                .map(|(step, total)| OperationProgress { step, total })
+        } else {
+            None
+        };
         Ok(TaskResult::GitOperation {
             item_idx: ctx.item_idx,
             git_operation,
+            resolution,
+            operation_progress,
         })
     }
 }
@@ -552,6 +640,21 @@ impl Task for UserMarkerTask {
     }
 }
 
+/// Task 8b (worktree only, detached HEAD only): Nearest tag (`git describe`)
+pub struct DescribeTask;
+
+impl Task for DescribeTask {
+    const KIND: TaskKind = TaskKind::Describe;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        let describe = ctx.repo().describe(&ctx.commit_sha);
+        Ok(TaskResult::Describe {
+            item_idx: ctx.item_idx,
+            describe,
+        })
+    }
+}
+
 /// Task 9: Upstream tracking status
 pub struct UpstreamTask;
 
@@ -616,7 +719,7 @@ impl Task for CiStatusTask {
 
         let pr_status = ctx.branch.as_deref().and_then(|branch| {
             let has_upstream = repo.upstream_branch(branch).ok().flatten().is_some();
-            PrStatus::detect(branch, &ctx.commit_sha, &repo_path, has_upstream)
+            PrStatus::detect(branch, &ctx.commit_sha, &repo_path, has_upstream, &ctx.ci)
         });
 
         Ok(TaskResult::CiStatus {
@@ -626,6 +729,45 @@ impl Task for CiStatusTask {
     }
 }
 
+/// Task 11: Tip commit signature
+///
+/// Verification runs gpg or ssh-keygen per commit, so this only runs when the
+/// `signature` column is selected, or for JSON output with `--full`.
+pub struct SignatureTask;
+
+impl Task for SignatureTask {
+    const KIND: TaskKind = TaskKind::Signature;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        let output = ctx
+            .repo()
+            .run_command(&["log", "-1", "--format=%G?%x00%GS", &ctx.commit_sha])
+            .map_err(|e| ctx.error(Self::KIND, e))?;
+        Ok(TaskResult::Signature {
+            item_idx: ctx.item_idx,
+            signature: parse_signature(&output),
+        })
+    }
+}
+
+/// Task 12: Branch description (`git branch --edit-description`)
+pub struct DescriptionTask;
+
+impl Task for DescriptionTask {
+    const KIND: TaskKind = TaskKind::Description;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        let description = ctx
+            .branch
+            .as_deref()
+            .and_then(|branch| ctx.repo().branch_description(branch));
+        Ok(TaskResult::Description {
+            item_idx: ctx.item_idx,
+            description,
+        })
+    }
+}
+
 /// Task 13: URL health check (port availability).
 ///
 /// The URL itself is sent immediately after template expansion (in spawning code)
@@ -750,6 +892,7 @@ pub fn collect_worktree_progressive(
         target: Some(target.to_string()),
         item_idx,
         item_url,
+        ci: options.ci.clone(),
     };
 
     collect_progressive(ctx, true, options, tx, expected_results);
@@ -800,6 +943,7 @@ pub fn collect_branch_progressive(
         target: Some(target.to_string()),
         item_idx,
         item_url,
+        ci: options.ci.clone(),
     };
 
     collect_progressive(ctx, false, options, tx, expected_results);
@@ -875,10 +1019,171 @@ fn parse_working_tree_status(status_output: &str) -> (WorkingTreeStatus, bool, b
     (working_tree_status, is_dirty, has_conflicts)
 }
 
+/// Parse `git submodule status` output, using `git status --porcelain` output
+/// to spot submodules with uncommitted changes (which submodule status doesn't show).
+fn parse_submodule_status(submodule_output: &str, status_output: &str) -> SubmoduleStatus {
+    let mut status = SubmoduleStatus::default();
+    for line in submodule_output.lines() {
+        let Some(prefix) = line.chars().next() else {
+            continue;
+        };
+        match prefix {
+            '-' => status.uninitialized += 1,
+            // `+` = different commit checked out, `U` = merge conflicts
+            '+' | 'U' => status.out_of_sync += 1,
+            _ => {
+                let Some(path) = line[1..].split_whitespace().nth(1) else {
+                    continue;
+                };
+                let modified = status_output
+                    .lines()
+                    .any(|l| l.len() > 3 && &l[3..] == path && l.as_bytes()[1] == b'M');
+                if modified {
+                    status.dirty += 1;
+                }
+            }
+        }
+    }
+    status
+}
+
+/// Simulate merging `head` into `base`, listing conflicting paths.
+///
+/// merge-tree refuses some merges outright (missing objects); like
+/// [`Repository::has_merge_conflicts`], count those as conflicting, with no
+/// paths to list. An orphan branch shares no history to merge, so it never
+/// conflicts.
+fn check_conflicts(repo: &Repository, base: &str, head: &str) -> anyhow::Result<ConflictCheck> {
+    match repo.merge_conflicts(base, head) {
+        Ok(paths) => Ok(ConflictCheck {
+            would_conflict: !paths.is_empty(),
+            paths,
+        }),
+        Err(e) if e.downcast_ref::<worktrunk::git::GitError>().is_some() => Ok(ConflictCheck {
+            would_conflict: true,
+            paths: Vec::new(),
+        }),
+        Err(e) => Err(e),
+    }
+}
+
+/// Whether git records and replays conflict resolutions: `rerere.enabled`,
+/// or when unset, an existing `rr-cache` directory (git's own default)
+fn rerere_enabled(repo: &Repository) -> bool {
+    match repo.run_command(&["config", "--bool", "rerere.enabled"]) {
+        Ok(value) => value.trim() == "true",
+        Err(_) => repo
+            .git_common_dir()
+            .is_ok_and(|dir| dir.join("rr-cache").is_dir()),
+    }
+}
+
+/// Split unmerged paths (`git diff --name-only --diff-filter=U`) into those
+/// still conflicted (`git rerere remaining`) and those rerere resolved
+fn parse_resolution(unmerged_output: &str, remaining_output: &str) -> ResolutionState {
+    let unmerged: std::collections::BTreeSet<&str> = unmerged_output.lines().collect();
+    let remaining = remaining_output
+        .lines()
+        .filter(|path| unmerged.contains(path))
+        .collect::<std::collections::BTreeSet<_>>()
+        .len();
+    ResolutionState {
+        remaining,
+        rerere_resolved: unmerged.len() - remaining,
+    }
+}
+
+/// Parse `git log --format=%G?%x00%GS` output into a signature status and
+/// signer
+fn parse_signature(output: &str) -> CommitSignature {
+    let (code, signer) = output
+        .trim_end()
+        .split_once('\0')
+        .unwrap_or((output.trim(), ""));
+    CommitSignature {
+        status: SignatureStatus::from_code(code),
+        signer: (!signer.is_empty()).then(|| signer.to_string()),
+    }
+}
+
+/// Count files `git lfs ls-files` lists as pointers (`-`) rather than
+/// checked-out content (`*`)
+fn count_lfs_pointers(ls_files_output: &str) -> usize {
+    ls_files_output
+        .lines()
+        .filter(|line| line.split_whitespace().nth(1) == Some("-"))
+        .count()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_signature() {
+        assert_eq!(
+            parse_signature("G\0Alice <alice@example.com>\n"),
+            CommitSignature {
+                status: SignatureStatus::Good,
+                signer: Some("Alice <alice@example.com>".into()),
+            }
+        );
+        assert_eq!(
+            parse_signature("N\0\n"),
+            CommitSignature {
+                status: SignatureStatus::Unsigned,
+                signer: None,
+            }
+        );
+        assert_eq!(parse_signature("Y\0bob\n").status, SignatureStatus::Expired);
+    }
+
+    #[test]
+    fn test_parse_submodule_status() {
+        let submodules = "\
+-1111111111111111111111111111111111111111 vendor/empty
++2222222222222222222222222222222222222222 vendor/moved (v1.0-3-g2222222)
+ 3333333333333333333333333333333333333333 vendor/dirty (v2.0)
+ 4444444444444444444444444444444444444444 vendor/clean (v3.0)
+";
+        let status = " M vendor/moved\n M vendor/dirty\n?? notes.txt\n";
+        assert_eq!(
+            parse_submodule_status(submodules, status),
+            SubmoduleStatus {
+                uninitialized: 1,
+                out_of_sync: 1,
+                dirty: 1,
+            }
+        );
+        assert!(!parse_submodule_status("", "").needs_attention());
+    }
+
+    #[test]
+    fn test_parse_resolution() {
+        let unmerged = "src/lib.rs\nsrc/main.rs\nREADME.md\n";
+        assert_eq!(
+            parse_resolution(unmerged, "src/main.rs\n"),
+            ResolutionState {
+                remaining: 1,
+                rerere_resolved: 2,
+            }
+        );
+        assert!(parse_resolution(unmerged, "").resolved_by_rerere());
+        assert!(!parse_resolution(unmerged, unmerged).resolved_by_rerere());
+        assert!(!parse_resolution("", "").resolved_by_rerere());
+    }
+
+    #[test]
+    fn test_count_lfs_pointers() {
+        let output = "\
+4d7a214614 * assets/logo.png
+a1b2c3d4e5 - assets/video.mp4
+f6e5d4c3b2 - data/model bin.onnx
+";
+        assert_eq!(count_lfs_pointers(output), 2);
+        assert_eq!(count_lfs_pointers(""), 0);
+    }
+
     #[test]
     fn test_parse_status_ad_not_conflict() {
         // AD = added to index, deleted from worktree (not a conflict)
@@ -1,18 +1,24 @@
+use worktrunk::config::ListColumn;
+
 use super::collect::TaskKind;
 
 /// Logical identifier for each column rendered by `wt list`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ColumnKind {
     Gutter, // Type indicator: `@` (current), `^` (main), `+` (worktree), space (branch-only)
+    Index,  // Row number, for `wt switch 3`
     Branch,
-    Status, // Includes both git status symbols and user-defined status
+    Description, // From `branch.<name>.description`
+    Status,      // Includes both git status symbols and user-defined status
     WorkingDiff,
     AheadBehind,
     BranchDiff,
+    Files, // Files changed in the branch diff
     Path,
     Upstream,
     Url, // Dev server URL from project config template
     CiStatus,
+    Signature, // Tip commit signature verification
     Commit,
     Time,
     Message,
@@ -22,22 +28,49 @@ impl ColumnKind {
     pub const fn header(self) -> &'static str {
         match self {
             ColumnKind::Gutter => "",
+            ColumnKind::Index => "#",
             ColumnKind::Branch => "Branch",
+            ColumnKind::Description => "Description",
             ColumnKind::Status => "Status",
             ColumnKind::WorkingDiff => "HEAD±",
             ColumnKind::AheadBehind => "main↕",
             ColumnKind::BranchDiff => "main…±",
+            ColumnKind::Files => "Files",
             ColumnKind::Path => "Path",
             ColumnKind::Upstream => "Remote⇅",
             ColumnKind::Url => "URL",
             ColumnKind::Time => "Age",
             ColumnKind::CiStatus => "CI",
+            ColumnKind::Signature => "Sig",
             ColumnKind::Commit => "Commit",
             ColumnKind::Message => "Message",
         }
     }
 }
 
+impl From<ListColumn> for ColumnKind {
+    fn from(column: ListColumn) -> Self {
+        match column {
+            ListColumn::Index => ColumnKind::Index,
+            ListColumn::Branch => ColumnKind::Branch,
+            ListColumn::Status => ColumnKind::Status,
+            ListColumn::Head => ColumnKind::WorkingDiff,
+            ListColumn::Main => ColumnKind::AheadBehind,
+            ListColumn::MainDiff => ColumnKind::BranchDiff,
+            ListColumn::Files => ColumnKind::Files,
+            ListColumn::Path => ColumnKind::Path,
+            ListColumn::Remote => ColumnKind::Upstream,
+            ListColumn::Url => ColumnKind::Url,
+            ListColumn::Ci => ColumnKind::CiStatus,
+            ListColumn::Signature => ColumnKind::Signature,
+            ListColumn::Description => ColumnKind::Description,
+            ListColumn::Commit => ColumnKind::Commit,
+            ListColumn::Age => ColumnKind::Time,
+            ListColumn::Message => ColumnKind::Message,
+        }
+    }
+}
+
 /// Differentiates between diff-style columns with plus/minus symbols and those with arrows.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum DiffVariant {
@@ -70,25 +103,54 @@ impl ColumnSpec {
 /// Static registry of all possible columns in display order.
 pub const COLUMN_SPECS: &[ColumnSpec] = &[
     ColumnSpec::new(ColumnKind::Gutter, 0, None),
+    ColumnSpec::new(ColumnKind::Index, 16, None),
     ColumnSpec::new(ColumnKind::Branch, 1, None),
-    ColumnSpec::new(ColumnKind::Status, 2, None),
-    ColumnSpec::new(ColumnKind::WorkingDiff, 3, None),
-    ColumnSpec::new(ColumnKind::AheadBehind, 4, None),
-    ColumnSpec::new(ColumnKind::BranchDiff, 5, Some(TaskKind::BranchDiff)),
-    ColumnSpec::new(ColumnKind::Path, 6, None),
-    ColumnSpec::new(ColumnKind::Upstream, 7, None),
-    ColumnSpec::new(ColumnKind::Url, 8, Some(TaskKind::UrlStatus)),
-    ColumnSpec::new(ColumnKind::CiStatus, 9, Some(TaskKind::CiStatus)),
-    ColumnSpec::new(ColumnKind::Commit, 10, None),
-    ColumnSpec::new(ColumnKind::Time, 11, None),
-    ColumnSpec::new(ColumnKind::Message, 12, None),
+    ColumnSpec::new(ColumnKind::Description, 2, Some(TaskKind::Description)),
+    ColumnSpec::new(ColumnKind::Status, 3, None),
+    ColumnSpec::new(ColumnKind::WorkingDiff, 4, None),
+    ColumnSpec::new(ColumnKind::AheadBehind, 5, None),
+    ColumnSpec::new(ColumnKind::BranchDiff, 6, Some(TaskKind::BranchDiff)),
+    ColumnSpec::new(ColumnKind::Files, 15, Some(TaskKind::BranchDiff)),
+    ColumnSpec::new(ColumnKind::Path, 7, None),
+    ColumnSpec::new(ColumnKind::Upstream, 8, None),
+    ColumnSpec::new(ColumnKind::Url, 9, Some(TaskKind::UrlStatus)),
+    ColumnSpec::new(ColumnKind::CiStatus, 10, Some(TaskKind::CiStatus)),
+    ColumnSpec::new(ColumnKind::Signature, 11, Some(TaskKind::Signature)),
+    ColumnSpec::new(ColumnKind::Commit, 12, None),
+    ColumnSpec::new(ColumnKind::Time, 13, None),
+    ColumnSpec::new(ColumnKind::Message, 14, None),
 ];
 
-pub fn column_display_index(kind: ColumnKind) -> usize {
-    COLUMN_SPECS
+/// Position of `kind` in the table: its place in `selected` when columns are
+/// configured (after the gutter), else its place in [`COLUMN_SPECS`].
+pub fn column_display_index(kind: ColumnKind, selected: &[ListColumn]) -> usize {
+    if kind == ColumnKind::Gutter || selected.is_empty() {
+        return COLUMN_SPECS
+            .iter()
+            .position(|spec| spec.kind == kind)
+            .unwrap_or(usize::MAX);
+    }
+    selected
         .iter()
-        .position(|spec| spec.kind == kind)
-        .unwrap_or(usize::MAX)
+        .position(|&column| ColumnKind::from(column) == kind)
+        .map_or(usize::MAX, |index| index + 1)
+}
+
+/// Whether `kind` is shown with the configured `selected` columns (all but
+/// the opt-in Index, Files, Signature, and Description columns when empty)
+pub fn column_selected(kind: ColumnKind, selected: &[ListColumn]) -> bool {
+    kind == ColumnKind::Gutter
+        || (selected.is_empty()
+            && !matches!(
+                kind,
+                ColumnKind::Index
+                    | ColumnKind::Files
+                    | ColumnKind::Signature
+                    | ColumnKind::Description
+            ))
+        || selected
+            .iter()
+            .any(|&column| ColumnKind::from(column) == kind)
 }
 
 #[cfg(test)]
@@ -101,15 +163,19 @@ mod tests {
         let kinds: Vec<ColumnKind> = COLUMN_SPECS.iter().map(|c| c.kind).collect();
         let expected = vec![
             ColumnKind::Gutter,
+            ColumnKind::Index,
             ColumnKind::Branch,
+            ColumnKind::Description,
             ColumnKind::Status,
             ColumnKind::WorkingDiff,
             ColumnKind::AheadBehind,
             ColumnKind::BranchDiff,
+            ColumnKind::Files,
             ColumnKind::Path,
             ColumnKind::Upstream,
             ColumnKind::Url,
             ColumnKind::CiStatus,
+            ColumnKind::Signature,
             ColumnKind::Commit,
             ColumnKind::Time,
             ColumnKind::Message,
@@ -125,6 +191,12 @@ mod tests {
             .unwrap();
         assert_eq!(branch_diff.requires_task, Some(TaskKind::BranchDiff));
 
+        let files = COLUMN_SPECS
+            .iter()
+            .find(|c| c.kind == ColumnKind::Files)
+            .unwrap();
+        assert_eq!(files.requires_task, Some(TaskKind::BranchDiff));
+
         let url = COLUMN_SPECS
             .iter()
             .find(|c| c.kind == ColumnKind::Url)
@@ -137,11 +209,26 @@ mod tests {
             .unwrap();
         assert_eq!(ci_status.requires_task, Some(TaskKind::CiStatus));
 
+        let signature = COLUMN_SPECS
+            .iter()
+            .find(|c| c.kind == ColumnKind::Signature)
+            .unwrap();
+        assert_eq!(signature.requires_task, Some(TaskKind::Signature));
+
+        let description = COLUMN_SPECS
+            .iter()
+            .find(|c| c.kind == ColumnKind::Description)
+            .unwrap();
+        assert_eq!(description.requires_task, Some(TaskKind::Description));
+
         // All other columns should not require a background task to render
         for spec in COLUMN_SPECS {
             if spec.kind != ColumnKind::BranchDiff
+                && spec.kind != ColumnKind::Files
                 && spec.kind != ColumnKind::Url
                 && spec.kind != ColumnKind::CiStatus
+                && spec.kind != ColumnKind::Signature
+                && spec.kind != ColumnKind::Description
             {
                 assert!(
                     spec.requires_task.is_none(),
@@ -152,6 +239,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_selected_columns_order() {
+        let selected = [ListColumn::Message, ListColumn::Branch];
+        assert!(column_selected(ColumnKind::Gutter, &selected));
+        assert!(column_selected(ColumnKind::Message, &selected));
+        assert!(!column_selected(ColumnKind::Path, &selected));
+        assert!(column_selected(ColumnKind::Path, &[]));
+        assert!(!column_selected(ColumnKind::Signature, &[]));
+        assert!(!column_selected(ColumnKind::Description, &[]));
+        assert!(!column_selected(ColumnKind::Files, &[]));
+        assert!(!column_selected(ColumnKind::Index, &[]));
+        assert!(column_selected(
+            ColumnKind::Signature,
+            &[ListColumn::Signature]
+        ));
+
+        assert_eq!(column_display_index(ColumnKind::Gutter, &selected), 0);
+        assert_eq!(column_display_index(ColumnKind::Message, &selected), 1);
+        assert_eq!(column_display_index(ColumnKind::Branch, &selected), 2);
+        assert_eq!(column_display_index(ColumnKind::Branch, &[]), 2);
+    }
+
     #[test]
     fn test_column_spec_new() {
         let spec = ColumnSpec::new(ColumnKind::Branch, 1, None);
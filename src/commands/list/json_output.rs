@@ -10,18 +10,23 @@
 //! Fields are organized by concept, matching the status display subcolumns:
 //! - `working_tree`: staged/modified/untracked changes
 //! - `main_state`: relationship to main (would_conflict, same_commit, integrated, diverged, ahead, behind)
-//! - `operation_state`: git operations in progress (conflicts, rebase, merge)
+//! - `operation_state`: git operations in progress (conflicts, resolved, rebase, merge)
 //! - `main`: relationship to main branch (ahead/behind/diff counts)
 //! - `remote`: relationship to tracking branch
 //! - `worktree`: worktree-specific state (locked, prunable, etc.)
 
 use std::path::PathBuf;
 
+use anyhow::Context;
+use color_print::cformat;
 use serde::Serialize;
-use worktrunk::git::LineDiff;
+use worktrunk::config::{check_template, expand_template_with};
+use worktrunk::git::{GitError, LineDiff};
 
 use super::ci_status::{CiSource, PrStatus};
-use super::model::{ItemKind, ListItem, UpstreamStatus};
+use super::model::{
+    ItemKind, ListItem, OperationProgress, ResolutionState, SubmoduleStatus, UpstreamStatus,
+};
 
 /// JSON output for a single list item
 #[derive(Debug, Clone, Serialize)]
@@ -53,10 +58,23 @@ pub struct JsonItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub integration_reason: Option<&'static str>,
 
-    /// Git operation in progress: conflicts, rebase, merge (null when none)
+    /// Branch HEAD is in the default branch's history (`git merge-base
+    /// --is-ancestor`), so removing it loses nothing (absent when is_main)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_merged: Option<bool>,
+
+    /// Git operation in progress: conflicts, resolved, rebase, merge (null when none)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub operation_state: Option<&'static str>,
 
+    /// Conflicted paths during a rebase/merge (absent when no operation is in progress)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<ResolutionState>,
+
+    /// Rebase step counters, `{step, total}` (absent unless a rebase is in progress)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_progress: Option<OperationProgress>,
+
     /// Relationship to main branch (absent when is_main == true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub main: Option<JsonMain>,
@@ -82,6 +100,14 @@ pub struct JsonItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ci: Option<JsonCi>,
 
+    /// Branch description from `git branch --edit-description`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Tip commit signature (`--full` or the `signature` column only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<JsonSignature>,
+
     /// Dev server URL from project config template
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
@@ -147,6 +173,8 @@ pub struct JsonWorkingTree {
 pub struct JsonDiff {
     pub added: usize,
     pub deleted: usize,
+    /// Files changed, including binary files
+    pub files: usize,
 }
 
 impl From<LineDiff> for JsonDiff {
@@ -154,6 +182,7 @@ impl From<LineDiff> for JsonDiff {
         Self {
             added: d.added,
             deleted: d.deleted,
+            files: d.files,
         }
     }
 }
@@ -170,6 +199,10 @@ pub struct JsonMain {
     /// Lines added/deleted vs main branch
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diff: Option<JsonDiff>,
+
+    /// Paths that would conflict when merging into main
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub conflicts: Vec<String>,
 }
 
 /// Relationship to remote tracking branch
@@ -191,16 +224,33 @@ pub struct JsonRemote {
 /// Worktree-specific state
 #[derive(Debug, Clone, Serialize)]
 pub struct JsonWorktree {
-    /// Worktree state: "no_worktree", "path_mismatch", "prunable", "locked" (absent when normal)
+    /// Worktree state: "no_worktree", "broken", "path_mismatch", "prunable", "locked",
+    /// "submodules", "lfs_missing", "hooks_running" (absent when normal)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<&'static str>,
 
-    /// Reason for locked/prunable state
+    /// Reason for broken/locked/prunable state
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
 
     /// HEAD is detached (not on a branch)
     pub detached: bool,
+
+    /// Nearest tag for a detached HEAD, e.g. "v1.2+3" (absent when on a branch or untagged)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub describe: Option<String>,
+
+    /// Submodule counts (absent when the worktree has no submodules)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submodules: Option<SubmoduleStatus>,
+
+    /// Git LFS files not pulled (absent when LFS isn't in use)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lfs_missing: Option<usize>,
+
+    /// Background hook commands still running (absent when none are)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hooks_running: Option<usize>,
 }
 
 /// CI status from PR or branch workflow
@@ -220,6 +270,17 @@ pub struct JsonCi {
     pub url: Option<String>,
 }
 
+/// Tip commit signature
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonSignature {
+    /// "good", "untrusted", "expired", "revoked", "bad", "unverifiable", "unsigned"
+    pub status: &'static str,
+
+    /// Signer identity (absent when unsigned)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signer: Option<String>,
+}
+
 impl JsonItem {
     /// Convert a ListItem to the new JSON structure
     pub fn from_list_item(item: &ListItem) -> Self {
@@ -297,6 +358,7 @@ impl JsonItem {
                 ahead: counts.ahead,
                 behind: counts.behind,
                 diff: item.branch_diff.map(|bd| JsonDiff::from(bd.diff)),
+                conflicts: item.conflict_paths.clone(),
             })
         };
 
@@ -313,6 +375,10 @@ impl JsonItem {
                 state,
                 reason,
                 detached: data.detached,
+                describe: data.describe.clone(),
+                submodules: data.submodules,
+                lfs_missing: data.lfs_missing,
+                hooks_running: data.hooks_running,
             }
         });
 
@@ -326,6 +392,11 @@ impl JsonItem {
             .and_then(|opt| opt.as_ref())
             .map(JsonCi::from);
 
+        let signature = item.signature.as_ref().map(|signature| JsonSignature {
+            status: signature.status.into(),
+            signer: signature.signer.clone(),
+        });
+
         // Statusline and symbols (raw, without ANSI codes)
         let statusline = item.display.statusline.clone();
         let symbols = item
@@ -342,7 +413,10 @@ impl JsonItem {
             working_tree,
             main_state,
             integration_reason,
+            is_merged: if is_main { None } else { item.is_ancestor },
             operation_state,
+            resolution: worktree_data.and_then(|data| data.resolution),
+            operation_progress: worktree_data.and_then(|data| data.operation_progress),
             main,
             remote,
             worktree,
@@ -350,6 +424,8 @@ impl JsonItem {
             is_current,
             is_previous,
             ci,
+            description: item.description.clone().flatten(),
+            signature,
             url: item.url.clone(),
             url_active: item.url_active,
             statusline,
@@ -372,6 +448,12 @@ fn upstream_to_json(upstream: &UpstreamStatus, branch: &Option<String>) -> Optio
     })
 }
 
+fn broken_reason(data: &super::model::WorktreeData) -> Option<String> {
+    data.broken_gitdir
+        .as_ref()
+        .map(|gitdir| format!(".git points to missing {}", gitdir.display()))
+}
+
 /// Extract worktree state and reason from WorktreeData
 fn worktree_state_to_json(
     data: &super::model::WorktreeData,
@@ -384,14 +466,21 @@ fn worktree_state_to_json(
         match symbols.worktree_state {
             WorktreeState::None => {}
             WorktreeState::Branch => return (Some("no_worktree"), None),
+            WorktreeState::Broken => return (Some("broken"), broken_reason(data)),
             WorktreeState::PathMismatch => return (Some("path_mismatch"), None),
             WorktreeState::Prunable => return (Some("prunable"), data.prunable.clone()),
             WorktreeState::Locked => return (Some("locked"), data.locked.clone()),
+            WorktreeState::Submodules => return (Some("submodules"), None),
+            WorktreeState::LfsMissing => return (Some("lfs_missing"), None),
+            WorktreeState::HooksRunning => return (Some("hooks_running"), None),
         }
     }
 
     // Fallback: check direct fields when status_symbols is None
     // This can happen early in progressive rendering before status is computed
+    if data.broken_gitdir.is_some() {
+        return (Some("broken"), broken_reason(data));
+    }
     if data.prunable.is_some() {
         return (Some("prunable"), data.prunable.clone());
     }
@@ -435,7 +524,7 @@ fn format_raw_symbols(symbols: &super::model::StatusSymbols) -> String {
         result.push_str(upstream_div);
     }
 
-    // Worktree state (operations ✘⤴⤵ take priority over location /⚑⊟⊞)
+    // Worktree state (operations ✘⤴⤵ take priority over location /⚑⊟⊞⊡◌◷)
     let op_state = symbols.operation_state.to_string();
     if !op_state.is_empty() {
         result.push_str(&op_state);
@@ -459,6 +548,125 @@ pub fn to_json_items(items: &[ListItem]) -> Vec<JsonItem> {
     items.iter().map(JsonItem::from_list_item).collect()
 }
 
+/// Fields `--json` can select: each top-level field (`repo` only with
+/// `--all-repos`), plus `ahead` and `behind` from `main`
+pub const JSON_FIELDS: &[&str] = &[
+    "branch",
+    "path",
+    "kind",
+    "commit",
+    "working_tree",
+    "main_state",
+    "integration_reason",
+    "is_merged",
+    "operation_state",
+    "resolution",
+    "operation_progress",
+    "main",
+    "remote",
+    "worktree",
+    "is_main",
+    "is_current",
+    "is_previous",
+    "ci",
+    "description",
+    "signature",
+    "url",
+    "url_active",
+    "statusline",
+    "symbols",
+    "repo",
+    "ahead",
+    "behind",
+];
+
+/// `wt list --json <fields>`, with `--template` to format each item
+#[derive(Debug, Clone)]
+pub struct JsonSelection {
+    pub fields: Vec<String>,
+    pub template: Option<String>,
+}
+
+impl JsonSelection {
+    /// Check the fields and template up front, before collecting anything
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if let Some(field) = self
+            .fields
+            .iter()
+            .find(|field| !JSON_FIELDS.contains(&field.as_str()))
+        {
+            return Err(GitError::Other {
+                message: cformat!(
+                    "Unknown JSON field <bold>{field}</>; available fields: {}",
+                    JSON_FIELDS.join(", ")
+                ),
+            }
+            .into());
+        }
+        if let Some(template) = &self.template {
+            check_template(template).map_err(|message| GitError::Other { message })?;
+        }
+        Ok(())
+    }
+
+    /// The selected fields of one item. A field the item doesn't have is
+    /// `null`, so every item has the same keys.
+    fn select(&self, item: &serde_json::Value) -> serde_json::Value {
+        let selected = self
+            .fields
+            .iter()
+            .map(|field| {
+                let value = match field.as_str() {
+                    "ahead" | "behind" => item.get("main").and_then(|main| main.get(field)),
+                    _ => item.get(field),
+                };
+                (field.clone(), value.cloned().unwrap_or_default())
+            })
+            .collect();
+        serde_json::Value::Object(selected)
+    }
+}
+
+/// Print items for `--format=json`, or with a selection, only its fields:
+/// as JSON, or one line per item through its template
+pub fn print_json(
+    items: &[impl Serialize],
+    selection: Option<&JsonSelection>,
+) -> anyhow::Result<()> {
+    let Some(selection) = selection else {
+        let json = serde_json::to_string_pretty(&items).context("Failed to serialize to JSON")?;
+        crate::output::stdout(json)?;
+        return Ok(());
+    };
+    let selected = items
+        .iter()
+        .map(|item| serde_json::to_value(item).map(|item| selection.select(&item)))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to serialize to JSON")?;
+    match &selection.template {
+        Some(template) => {
+            // A missing value renders as nothing rather than `none`
+            let lines = selected
+                .into_iter()
+                .map(|mut item| {
+                    if let Some(object) = item.as_object_mut() {
+                        object.retain(|_, value| !value.is_null());
+                    }
+                    expand_template_with(template, &item)
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|message| GitError::Other { message })?;
+            crate::output::stdout(lines.join("\n"))?;
+        }
+        None => {
+            let json =
+                serde_json::to_string_pretty(&selected).context("Failed to serialize to JSON")?;
+            crate::output::stdout(json)?;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -478,18 +686,17 @@ mod tests {
         let line_diff = LineDiff {
             added: 10,
             deleted: 5,
+            files: 2,
         };
         let json_diff = JsonDiff::from(line_diff);
         assert_eq!(json_diff.added, 10);
         assert_eq!(json_diff.deleted, 5);
+        assert_eq!(json_diff.files, 2);
     }
 
     #[test]
     fn test_json_diff_from_line_diff_zeros() {
-        let line_diff = LineDiff {
-            added: 0,
-            deleted: 0,
-        };
+        let line_diff = LineDiff::default();
         let json_diff = JsonDiff::from(line_diff);
         assert_eq!(json_diff.added, 0);
         assert_eq!(json_diff.deleted, 0);
@@ -626,11 +833,18 @@ mod tests {
             is_current: false,
             is_previous: false,
             detached: false,
+            describe: None,
             locked: None,
             prunable: None,
+            broken_gitdir: None,
             working_tree_diff: None,
             working_tree_diff_with_main: None,
             git_operation: GitOperationState::None,
+            resolution: None,
+            operation_progress: None,
+            submodules: None,
+            lfs_missing: None,
+            hooks_running: None,
             path_mismatch: false,
             working_diff_display: None,
         }
@@ -829,6 +1043,7 @@ mod tests {
             diff: Some(JsonDiff {
                 added: 10,
                 deleted: 5,
+                files: 1,
             }),
             diff_vs_main: None,
         };
@@ -846,11 +1061,15 @@ mod tests {
             diff: Some(JsonDiff {
                 added: 50,
                 deleted: 20,
+                files: 4,
             }),
+            conflicts: vec!["src/lib.rs".to_string()],
         };
         let json = serde_json::to_string(&main).unwrap();
         assert!(json.contains("\"ahead\":3"));
         assert!(json.contains("\"behind\":1"));
+        assert!(json.contains("\"files\":4"));
+        assert!(json.contains("\"conflicts\":[\"src/lib.rs\"]"));
     }
 
     #[test]
@@ -872,10 +1091,33 @@ mod tests {
             state: Some("locked"),
             reason: Some("manual".to_string()),
             detached: false,
+            describe: None,
+            submodules: None,
+            lfs_missing: None,
+            hooks_running: None,
         };
         let json = serde_json::to_string(&wt).unwrap();
         assert!(json.contains("\"state\":\"locked\""));
         assert!(json.contains("\"reason\":\"manual\""));
+        assert!(!json.contains("submodules"));
+
+        let wt = JsonWorktree {
+            state: Some("submodules"),
+            reason: None,
+            detached: false,
+            describe: None,
+            submodules: Some(SubmoduleStatus {
+                uninitialized: 1,
+                out_of_sync: 0,
+                dirty: 2,
+            }),
+            lfs_missing: Some(3),
+            hooks_running: None,
+        };
+        let json = serde_json::to_string(&wt).unwrap();
+        assert!(
+            json.contains("\"submodules\":{\"uninitialized\":1,\"out_of_sync\":0,\"dirty\":2}")
+        );
     }
 
     #[test]
@@ -175,16 +175,19 @@ use anstyle::Style;
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use unicode_width::UnicodeWidthStr;
+use worktrunk::config::ListColumn;
 use worktrunk::styling::{ADDITION, DELETION};
 
 use super::collect::TaskKind;
-use super::columns::{COLUMN_SPECS, ColumnKind, ColumnSpec, column_display_index};
+use super::columns::{COLUMN_SPECS, ColumnKind, ColumnSpec, column_display_index, column_selected};
 
 // Re-export DiffVariant for external use (e.g., select command)
 pub use super::columns::DiffVariant;
 
 /// Width of short commit hash display (first 8 hex characters)
 const COMMIT_HASH_WIDTH: usize = 8;
+/// Room reserved after a detached worktree's SHA for its nearest tag (` v1.2+3`)
+const DESCRIBE_WIDTH_ESTIMATE: usize = 8;
 /// Get terminal width for list rendering.
 pub fn get_safe_list_width() -> usize {
     get_terminal_width()
@@ -240,11 +243,15 @@ pub struct DiffWidths {
 
 #[derive(Clone, Debug)]
 pub struct ColumnWidths {
+    pub index: usize,
     pub branch: usize,
     pub status: usize, // Includes both git status symbols and user-defined status
     pub time: usize,
     pub url: usize,
     pub ci_status: usize,
+    pub signature: usize,
+    pub description: usize,
+    pub files: usize,
     pub message: usize,
     pub ahead_behind: DiffWidths,
     pub working_diff: DiffWidths,
@@ -262,6 +269,8 @@ pub struct ColumnDataFlags {
     pub upstream: bool,
     pub url: bool,
     pub ci_status: bool,
+    pub signature: bool,
+    pub description: bool,
     pub path: bool, // True if any worktree has path_mismatch (path doesn't match template)
 }
 
@@ -311,16 +320,19 @@ impl ColumnKind {
     pub fn has_data(self, flags: &ColumnDataFlags) -> bool {
         match self {
             ColumnKind::Gutter => true, // Always present (shows @ ^ + or space)
+            ColumnKind::Index => true,
             ColumnKind::Branch => true,
             ColumnKind::Status => flags.status,
             ColumnKind::WorkingDiff => flags.working_diff,
             ColumnKind::AheadBehind => flags.ahead_behind,
-            ColumnKind::BranchDiff => flags.branch_diff,
+            ColumnKind::BranchDiff | ColumnKind::Files => flags.branch_diff,
             ColumnKind::Path => flags.path,
             ColumnKind::Upstream => flags.upstream,
             ColumnKind::Url => flags.url,
             ColumnKind::Time => true,
             ColumnKind::CiStatus => flags.ci_status,
+            ColumnKind::Signature => flags.signature,
+            ColumnKind::Description => flags.description,
             ColumnKind::Commit => true,
             ColumnKind::Message => true,
         }
@@ -334,12 +346,16 @@ impl ColumnKind {
     ) -> Option<ColumnIdeal> {
         match self {
             ColumnKind::Gutter => ColumnIdeal::text(2), // Fixed width: symbol (1 char) + space (1 char)
+            ColumnKind::Index => ColumnIdeal::text(widths.index),
             ColumnKind::Branch => ColumnIdeal::text(widths.branch),
             ColumnKind::Status => ColumnIdeal::text(widths.status),
             ColumnKind::Path => ColumnIdeal::text(max_path_width),
             ColumnKind::Time => ColumnIdeal::text(widths.time),
             ColumnKind::Url => ColumnIdeal::text(widths.url),
             ColumnKind::CiStatus => ColumnIdeal::text(widths.ci_status),
+            ColumnKind::Signature => ColumnIdeal::text(widths.signature),
+            ColumnKind::Description => ColumnIdeal::text(widths.description),
+            ColumnKind::Files => ColumnIdeal::text(widths.files),
             ColumnKind::Commit => ColumnIdeal::text(commit_width),
             ColumnKind::Message => None,
             ColumnKind::WorkingDiff => {
@@ -383,6 +399,72 @@ pub struct LayoutConfig {
     pub max_message_len: usize,
     pub hidden_column_count: usize,
     pub status_position_mask: super::model::PositionMask,
+    pub age_thresholds: AgeThresholds,
+    pub placeholder: Placeholder,
+}
+
+/// What cells show while progressive collection is still computing them
+/// (`list.placeholder`, `list.placeholder-style`)
+#[derive(Clone, Debug)]
+pub struct Placeholder {
+    pub text: String,
+    pub style: Style,
+}
+
+impl Default for Placeholder {
+    fn default() -> Self {
+        Self {
+            text: "⋯".to_string(),
+            style: Style::new().dimmed(),
+        }
+    }
+}
+
+impl Placeholder {
+    pub fn from_config(config: &worktrunk::config::WorktrunkConfig) -> Self {
+        use worktrunk::config::PlaceholderStyle;
+
+        let list = config.list.as_ref();
+        let default = Self::default();
+        let style = match list.and_then(|list| list.placeholder_style) {
+            None | Some(PlaceholderStyle::Dim) => default.style,
+            Some(PlaceholderStyle::Normal) => Style::new(),
+            Some(PlaceholderStyle::Bold) => Style::new().bold(),
+            Some(PlaceholderStyle::Italic) => Style::new().italic(),
+            Some(PlaceholderStyle::Reverse) => Style::new().invert(),
+        };
+        Self {
+            text: list
+                .and_then(|list| list.placeholder.clone())
+                .unwrap_or(default.text),
+            style,
+        }
+    }
+
+    /// Append the placeholder to a cell
+    pub fn push_to(&self, cell: &mut worktrunk::styling::StyledLine) {
+        if !self.text.is_empty() {
+            cell.push_styled(self.text.clone(), self.style);
+        }
+    }
+}
+
+/// Commit ages at which rows are dimmed or highlighted (`list.dim-after`,
+/// `list.highlight-within`), so the table reads by recency at a glance
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AgeThresholds {
+    pub dim_after: Option<std::time::Duration>,
+    pub highlight_within: Option<std::time::Duration>,
+}
+
+impl AgeThresholds {
+    pub fn from_config(config: &worktrunk::config::WorktrunkConfig) -> Self {
+        let list = config.list.as_ref();
+        Self {
+            dim_after: list.and_then(|list| list.dim_after()),
+            highlight_within: list.and_then(|list| list.highlight_within()),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -485,6 +567,9 @@ fn build_estimated_widths(
     let upstream_fixed = fit_header(ColumnKind::Upstream.header(), 7); // "↑99 ↓99"
     let age_estimate = 4; // "11mo" (short format)
     let ci_estimate = fit_header(ColumnKind::CiStatus.header(), 1); // Single indicator symbol
+    let signature_estimate = fit_header(ColumnKind::Signature.header(), 1); // ✓ ? ✗
+    let description_estimate = 30; // Unknown until loaded; longer text is truncated
+    let files_estimate = fit_header(ColumnKind::Files.header(), 3); // "999"
 
     // Assume columns will have data (better to show and hide than to not show).
     // This is a limitation of progressive mode - we can't know which columns have data
@@ -492,7 +577,7 @@ fn build_estimated_widths(
     //
     // Exceptions that we can compute instantly from items:
     // - path: true only if any worktree has path_mismatch (path doesn't match template)
-    // - branch_diff/ci_status: false if their required task is skipped
+    // - branch_diff/ci_status/signature/description: false if their required task is skipped
     let data_flags = ColumnDataFlags {
         status: true,
         working_diff: true,
@@ -501,6 +586,8 @@ fn build_estimated_widths(
         upstream: true,
         url: !skip_tasks.contains(&TaskKind::UrlStatus),
         ci_status: !skip_tasks.contains(&TaskKind::CiStatus),
+        signature: !skip_tasks.contains(&TaskKind::Signature),
+        description: !skip_tasks.contains(&TaskKind::Description),
         path: has_path_mismatch,
     };
 
@@ -513,11 +600,15 @@ fn build_estimated_widths(
     };
 
     let widths = ColumnWidths {
+        index: fit_header(ColumnKind::Index.header(), 1), // Set from the row count by callers
         branch: max_branch,
         status: status_fixed,
         time: age_estimate,
         url: url_estimate,
         ci_status: ci_estimate,
+        signature: signature_estimate,
+        description: description_estimate,
+        files: files_estimate,
         message: 50, // Will be flexible during allocation
         // Commit counts (Arrows): compact notation, 2 digits covers up to 99
         ahead_behind: DiffWidths {
@@ -555,9 +646,15 @@ fn build_estimated_widths(
 ///
 /// This is the core allocation algorithm used by `calculate_layout_from_basics()`
 /// with pre-allocated width estimates for expensive-to-compute columns.
+///
+/// With configured `selected` columns, only those are candidates and they're
+/// displayed in the configured order; priorities still decide which are
+/// dropped on narrow terminals.
+#[allow(clippy::too_many_arguments)]
 fn allocate_columns_with_priority(
     metadata: &LayoutMetadata,
     skip_tasks: &HashSet<TaskKind>,
+    selected: &[ListColumn],
     max_path_width: usize,
     commit_width: usize,
     terminal_width: usize,
@@ -567,9 +664,10 @@ fn allocate_columns_with_priority(
     let mut remaining = terminal_width;
 
     // Build candidates with priorities
-    // Filter out columns whose required task is being skipped
+    // Filter out unselected columns and those whose required task is being skipped
     let mut candidates: Vec<ColumnCandidate> = COLUMN_SPECS
         .iter()
+        .filter(|spec| column_selected(spec.kind, selected))
         .filter(|spec| {
             spec.requires_task
                 .is_none_or(|task| !skip_tasks.contains(&task))
@@ -676,7 +774,7 @@ fn allocate_columns_with_priority(
     }
 
     // Sort by display order to maintain correct visual order
-    pending.sort_by_key(|col| column_display_index(col.spec.kind));
+    pending.sort_by_key(|col| column_display_index(col.spec.kind, selected));
 
     // Build final column layouts with positions
     let gap = 2;
@@ -724,6 +822,8 @@ fn allocate_columns_with_priority(
         max_message_len,
         hidden_column_count,
         status_position_mask: metadata.status_position_mask,
+        age_thresholds: AgeThresholds::default(),
+        placeholder: Placeholder::default(),
     }
 }
 
@@ -750,12 +850,14 @@ fn allocate_columns_with_priority(
 pub fn calculate_layout_from_basics(
     items: &[super::model::ListItem],
     skip_tasks: &HashSet<TaskKind>,
+    selected: &[ListColumn],
     main_worktree_path: &Path,
     url_template: Option<&str>,
 ) -> LayoutConfig {
     calculate_layout_with_width(
         items,
         skip_tasks,
+        selected,
         get_safe_list_width(),
         main_worktree_path,
         url_template,
@@ -763,9 +865,13 @@ pub fn calculate_layout_from_basics(
 }
 
 /// Calculate layout with explicit width (for contexts like skim where available width differs)
+///
+/// `selected` is the configured column list (`list.columns` or `--columns`);
+/// empty means every column in the standard order.
 pub fn calculate_layout_with_width(
     items: &[super::model::ListItem],
     skip_tasks: &HashSet<TaskKind>,
+    selected: &[ListColumn],
     terminal_width: usize,
     main_worktree_path: &Path,
     url_template: Option<&str>,
@@ -777,7 +883,18 @@ pub fn calculate_layout_with_width(
         .filter_map(|item| item.branch.as_deref())
         .max_by_key(|b| b.width());
 
-    let max_branch = longest_branch.map(|b| b.width()).unwrap_or(0);
+    // Detached worktrees show `∅ <sha>` plus a tag that arrives later; reserve room for it
+    let max_detached = items
+        .iter()
+        .filter(|item| item.branch.is_none() && item.worktree_data().is_some())
+        .map(|item| item.branch_label().width() + DESCRIBE_WIDTH_ESTIMATE)
+        .max()
+        .unwrap_or(0);
+
+    let max_branch = longest_branch
+        .map(|b| b.width())
+        .unwrap_or(0)
+        .max(max_detached);
     let max_branch = fit_header(ColumnKind::Branch.header(), max_branch);
 
     let path_data_width = items
@@ -802,13 +919,16 @@ pub fn calculate_layout_with_width(
     let url_width = estimate_url_width(url_template, longest_branch);
 
     // Build pre-allocated width estimates (same as buffered mode)
-    let metadata = build_estimated_widths(max_branch, skip_tasks, has_path_mismatch, url_width);
+    let mut metadata = build_estimated_widths(max_branch, skip_tasks, has_path_mismatch, url_width);
+    // Row numbers are known up front
+    metadata.widths.index = fit_header(ColumnKind::Index.header(), items.len().to_string().len());
 
     let commit_width = fit_header(ColumnKind::Commit.header(), COMMIT_HASH_WIDTH);
 
     allocate_columns_with_priority(
         &metadata,
         skip_tasks,
+        selected,
         max_path_width,
         commit_width,
         terminal_width,
@@ -872,6 +992,8 @@ mod tests {
             upstream: true,
             url: true,
             ci_status: true,
+            signature: true,
+            description: true,
             path: true,
         };
         let all_false = ColumnDataFlags {
@@ -882,6 +1004,8 @@ mod tests {
             upstream: false,
             url: false,
             ci_status: false,
+            signature: false,
+            description: false,
             path: false,
         };
 
@@ -907,6 +1031,10 @@ mod tests {
         assert!(!ColumnKind::Url.has_data(&all_false));
         assert!(ColumnKind::CiStatus.has_data(&all_true));
         assert!(!ColumnKind::CiStatus.has_data(&all_false));
+        assert!(ColumnKind::Signature.has_data(&all_true));
+        assert!(!ColumnKind::Signature.has_data(&all_false));
+        assert!(ColumnKind::Description.has_data(&all_true));
+        assert!(!ColumnKind::Description.has_data(&all_false));
         assert!(ColumnKind::Path.has_data(&all_true));
         assert!(!ColumnKind::Path.has_data(&all_false));
     }
@@ -974,11 +1102,15 @@ mod tests {
     #[test]
     fn test_column_kind_ideal() {
         let widths = ColumnWidths {
+            index: 1,
             branch: 15,
             status: 8,
             time: 4,
             url: 0,
             ci_status: 2,
+            signature: 3,
+            description: 30,
+            files: 5,
             message: 50,
             ahead_behind: DiffWidths {
                 total: 7,
@@ -1132,8 +1264,13 @@ mod tests {
             has_file_changes: Some(true),
             would_merge_add: None,
             is_ancestor: None,
+            conflict_paths: Vec::new(),
+            is_remote: false,
+            ordinal: None,
             upstream: Some(UpstreamStatus::from_parts(Some("origin".to_string()), 4, 2)),
             pr_status: None,
+            signature: None,
+            description: None,
             url: None,
             url_active: None,
             status_symbols: Some(StatusSymbols::default()),
@@ -1141,11 +1278,18 @@ mod tests {
             kind: ItemKind::Worktree(Box::new(WorktreeData {
                 path: PathBuf::from("/test/path"),
                 detached: false,
+                describe: None,
                 locked: None,
                 prunable: None,
+                broken_gitdir: None,
                 working_tree_diff: Some(LineDiff::from((100, 50))),
                 working_tree_diff_with_main: Some(Some(LineDiff::default())),
                 git_operation: GitOperationState::None,
+                resolution: None,
+                operation_progress: None,
+                submodules: None,
+                lfs_missing: None,
+                hooks_running: None,
                 is_main: false,
                 is_current: false,
                 is_previous: false,
@@ -1159,7 +1303,8 @@ mod tests {
             .into_iter()
             .collect();
         let main_worktree_path = PathBuf::from("/test");
-        let layout = calculate_layout_from_basics(&items, &skip_tasks, &main_worktree_path, None);
+        let layout =
+            calculate_layout_from_basics(&items, &skip_tasks, &[], &main_worktree_path, None);
 
         assert!(
             !layout.columns.is_empty(),
@@ -1230,8 +1375,13 @@ mod tests {
             has_file_changes: Some(true),
             would_merge_add: None,
             is_ancestor: None,
+            conflict_paths: Vec::new(),
+            is_remote: false,
+            ordinal: None,
             upstream: Some(UpstreamStatus::default()),
             pr_status: None,
+            signature: None,
+            description: None,
             url: None,
             url_active: None,
             status_symbols: Some(StatusSymbols::default()),
@@ -1239,11 +1389,18 @@ mod tests {
             kind: ItemKind::Worktree(Box::new(WorktreeData {
                 path: PathBuf::from("/test"),
                 detached: false,
+                describe: None,
                 locked: None,
                 prunable: None,
+                broken_gitdir: None,
                 working_tree_diff: Some(LineDiff::default()),
                 working_tree_diff_with_main: Some(Some(LineDiff::default())),
                 git_operation: GitOperationState::None,
+                resolution: None,
+                operation_progress: None,
+                submodules: None,
+                lfs_missing: None,
+                hooks_running: None,
                 is_main: true, // Primary worktree: no ahead/behind shown
                 is_current: false,
                 is_previous: false,
@@ -1257,7 +1414,8 @@ mod tests {
             .into_iter()
             .collect();
         let main_worktree_path = PathBuf::from("/home/user/project");
-        let layout = calculate_layout_from_basics(&items, &skip_tasks, &main_worktree_path, None);
+        let layout =
+            calculate_layout_from_basics(&items, &skip_tasks, &[], &main_worktree_path, None);
 
         assert!(
             layout
@@ -45,7 +45,7 @@
 //! First run in a repo without cached default branch adds ~100-300ms for network lookup.
 //!
 //! After the skeleton appears, cells fill in progressively as git operations complete.
-//! The slowest operation (CI status) only runs with `--full`.
+//! The slowest operation (CI status) only runs with `--full` or `--ci`.
 //!
 //! ## Git Commands Per Worktree
 //!
@@ -116,6 +116,7 @@
 //! - Run `git gc` periodically to consolidate objects into pack files
 //! - Minimize uncommitted changes across worktrees (each dirty worktree adds diff overhead)
 
+mod all_repos;
 pub mod ci_status;
 pub(crate) mod collect;
 mod collect_progressive_impl;
@@ -131,42 +132,93 @@ pub(crate) mod render;
 mod spacing_test;
 
 // Layout is calculated in collect.rs
-use anyhow::Context;
 use model::{ListData, ListItem};
 use progressive::RenderMode;
 use worktrunk::git::Repository;
 
+pub use all_repos::handle_list_all_repos;
+pub use json_output::JsonSelection;
+
 // Re-export for statusline and other consumers
 pub use collect::{CollectOptions, build_worktree_item, populate_item};
 
-pub fn handle_list(
-    format: crate::OutputFormat,
-    show_branches: bool,
-    show_remotes: bool,
+/// Tasks to skip for the requested detail.
+///
+/// Without `--full`, expensive operations (BranchDiff, CiStatus, WorkingTreeConflicts,
+/// Signature) are skipped; CI status runs whenever `show_ci` is set, independently of `--full`.
+// TODO: WouldMergeAdd (~500ms-2s per worktree) is currently enabled for ⊂ detection.
+// If this causes performance issues, consider adding it back to skip_tasks or
+// implementing a timeout for the merge simulation.
+pub(crate) fn skip_tasks_for(
     show_full: bool,
-    render_mode: RenderMode,
-    config: &worktrunk::config::WorktrunkConfig,
-) -> anyhow::Result<()> {
+    show_ci: bool,
+) -> std::collections::HashSet<collect::TaskKind> {
     use collect::TaskKind;
 
-    let repo = Repository::current();
-
-    // Build skip set based on flags
-    // Without --full: skip expensive operations (BranchDiff, CiStatus, WorkingTreeConflicts)
-    // TODO: WouldMergeAdd (~500ms-2s per worktree) is currently enabled for ⊂ detection.
-    // If this causes performance issues, consider adding it back to skip_tasks or
-    // implementing a timeout for the merge simulation.
-    let skip_tasks: std::collections::HashSet<TaskKind> = if show_full {
+    let mut skip_tasks: std::collections::HashSet<TaskKind> = if show_full {
         std::collections::HashSet::new() // Compute everything
     } else {
         [
             TaskKind::BranchDiff,
-            TaskKind::CiStatus,
             TaskKind::WorkingTreeConflicts,
+            TaskKind::Signature,
         ]
         .into_iter()
         .collect()
     };
+    if !show_ci {
+        skip_tasks.insert(TaskKind::CiStatus);
+    }
+    skip_tasks
+}
+
+/// Handle `wt list --legend`: print what each status symbol means
+pub fn handle_list_legend() -> anyhow::Result<()> {
+    use color_print::cformat;
+    use worktrunk::styling::StyledLine;
+
+    let legend = model::status_legend();
+    let subcolumn_width = legend
+        .iter()
+        .map(|entry| entry.subcolumn.len())
+        .max()
+        .unwrap_or_default();
+
+    let mut out = String::new();
+    let mut previous = "";
+    for entry in &legend {
+        let mut line = StyledLine::new();
+        // Name each subcolumn once, on its first row
+        if entry.subcolumn != previous {
+            line.push_raw(cformat!("<dim>{}</>", entry.subcolumn));
+        }
+        line.pad_to(subcolumn_width + 2);
+        line.push_raw(entry.styled.clone());
+        line.pad_to(subcolumn_width + 5);
+        line.push_raw(entry.meaning);
+        out.push_str(&line.render());
+        out.push('\n');
+        previous = entry.subcolumn;
+    }
+    crate::output::stdout(out.trim_end())?;
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle_list(
+    format: crate::OutputFormat,
+    show_branches: bool,
+    show_remotes: bool,
+    branch_filter: &collect::BranchFilter,
+    show_full: bool,
+    show_ci: bool,
+    render_mode: RenderMode,
+    json_selection: Option<&json_output::JsonSelection>,
+    config: &worktrunk::config::WorktrunkConfig,
+) -> anyhow::Result<()> {
+    let repo = Repository::current();
+
+    let skip_tasks = skip_tasks_for(show_full, show_ci);
 
     // Progressive rendering only for table format with Progressive mode
     let show_progress = match format {
@@ -181,33 +233,43 @@ pub fn handle_list(
         &repo,
         show_branches,
         show_remotes,
+        branch_filter,
         &skip_tasks,
         show_progress,
         render_table,
         config,
     )?;
 
-    let Some(ListData { items, .. }) = list_data else {
+    let Some(ListData {
+        items, index_shown, ..
+    }) = list_data
+    else {
         return Ok(());
     };
 
     match format {
         crate::OutputFormat::Json => {
-            // Convert to new JSON structure
             let json_items = json_output::to_json_items(&items);
-            let json =
-                serde_json::to_string_pretty(&json_items).context("Failed to serialize to JSON")?;
-            crate::output::stdout(json)?;
+            json_output::print_json(&json_items, json_selection)?;
         }
         crate::OutputFormat::Table => {
-            // Table and summary already rendered in collect() for all modes
-            // Nothing to do here - collect() handles the complete table rendering
+            // Table and summary already rendered in collect() for all modes.
+            // Remember the rows so `wt switch 3` can refer to them, but only
+            // numbers the user saw; a failed write only loses the shortcut.
+            if index_shown {
+                let entries: Vec<String> = items.iter().map(ListItem::switch_target).collect();
+                let _ = repo.record_listing(&entries);
+            } else {
+                let _ = repo.clear_listing();
+            }
+            crate::commands::limits::report_for_list(&repo, &config.limits())?;
         }
     }
 
     // Show hint if CI status was requested but no tools can fetch it.
     // Skip the check if any items already have CI status (tools must be available).
-    if show_full {
+    let ci = config.ci();
+    if show_ci && ci.provider != Some(worktrunk::config::CiProvider::Gerrit) {
         // pr_status semantics: None = not fetched, Some(None) = fetched but no PR,
         // Some(Some(status)) = fetched with status. If any item is Some(_), we
         // successfully communicated with CI tools, so they're available.
@@ -217,24 +279,25 @@ pub fn handle_list(
             log::debug!("Skipping CI tools detection - CI status already fetched");
         } else {
             // No CI was fetched - run full detection to show helpful hint
-            let gitlab_host = repo
-                .worktree_root()
-                .ok()
-                .and_then(|p| p.to_str().map(|s| s.to_string()))
-                .and_then(|p| ci_status::get_gitlab_host_for_repo(&p));
+            let gitlab_host = ci.api_host().map(str::to_string).or_else(|| {
+                repo.worktree_root()
+                    .ok()
+                    .and_then(|p| p.to_str().map(|s| s.to_string()))
+                    .and_then(|p| ci_status::get_gitlab_host_for_repo(&p))
+            });
             let ci_tools = ci_status::CiToolsStatus::detect(gitlab_host.as_deref());
 
             if !ci_tools.any_available() {
-                use ci_status::{CiPlatform, get_platform_for_repo};
+                use ci_status::{CiPlatform, get_configured_platform};
                 use color_print::cformat;
                 use worktrunk::git::Repository;
                 use worktrunk::styling::hint_message;
 
-                // Detect platform from repo's remote URL
+                // Platform from `[ci] provider`, else the repo's remote URL
                 let platform = Repository::current()
                     .worktree_root()
                     .ok()
-                    .and_then(|root| get_platform_for_repo(root.to_str()?));
+                    .and_then(|root| get_configured_platform(root.to_str()?, &ci));
 
                 // Only show hint for the relevant platform's tool
                 let hint = match platform {
@@ -308,14 +371,10 @@ impl SummaryMetrics {
             {
                 self.dirty_worktrees += 1;
             }
+        } else if item.is_remote {
+            self.remote_branches += 1;
         } else {
-            // Distinguish local vs remote branches by presence of '/' in name
-            // Remote branches are like "origin/feature", local are like "feature"
-            if item.branch.as_ref().is_some_and(|b| b.contains('/')) {
-                self.remote_branches += 1;
-            } else {
-                self.local_branches += 1;
-            }
+            self.local_branches += 1;
         }
 
         let counts = item.counts();
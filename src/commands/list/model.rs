@@ -67,10 +67,17 @@ impl DisplayFields {
 pub struct WorktreeData {
     pub path: PathBuf,
     pub detached: bool,
+    /// Nearest tag for a detached HEAD (`v1.2+3`); None when on a branch or no tag is reachable
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub describe: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locked: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prunable: Option<String>,
+    /// Missing admin directory the worktree's `.git` file points to; git
+    /// can't run inside the worktree until it's repaired
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub broken_gitdir: Option<PathBuf>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub working_tree_diff: Option<LineDiff>,
     /// Diff between working tree and main branch.
@@ -83,6 +90,22 @@ pub struct WorktreeData {
     /// Git operation in progress (rebase/merge)
     #[serde(skip_serializing_if = "git_operation_is_none")]
     pub git_operation: GitOperationState,
+    /// Conflicted paths during a rebase/merge; `None` when no operation is in progress
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<ResolutionState>,
+    /// Rebase step counters; `None` when no rebase is in progress
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_progress: Option<OperationProgress>,
+    /// Submodule state; `None` when there are no submodules or it isn't computed yet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submodules: Option<SubmoduleStatus>,
+    /// Git LFS files checked out as pointers; `None` when LFS isn't in use or
+    /// git-lfs isn't installed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lfs_missing: Option<usize>,
+    /// Background hook commands still running here; `None` when there are none
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hooks_running: Option<usize>,
     pub is_main: bool,
     /// Whether this is the current worktree (matches $PWD)
     #[serde(skip_serializing_if = "std::ops::Not::not")]
@@ -112,6 +135,7 @@ impl WorktreeData {
             detached: wt.detached,
             locked: wt.locked.clone(),
             prunable: wt.prunable.clone(),
+            broken_gitdir: wt.broken_gitdir(),
             is_main,
             is_current,
             is_previous,
@@ -127,7 +151,7 @@ impl WorktreeData {
 /// WorktreeData is boxed to reduce the size of ItemKind enum (304 bytes → 24 bytes).
 /// This reduces stack pressure when passing ListItem by value and improves cache locality
 /// in `Vec<ListItem>` by keeping the discriminant and common fields together.
-#[derive(serde::Serialize)]
+#[derive(Clone, serde::Serialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum ItemKind {
     Worktree(Box<WorktreeData>),
@@ -152,6 +176,110 @@ pub struct BranchDiffTotals {
     pub diff: LineDiff,
 }
 
+/// Submodules needing attention in a worktree, from `git submodule status`
+#[derive(serde::Serialize, Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SubmoduleStatus {
+    /// Registered but never checked out (empty directory)
+    pub uninitialized: usize,
+    /// Checked out at a different commit than the superproject records
+    pub out_of_sync: usize,
+    /// Uncommitted changes inside the submodule
+    pub dirty: usize,
+}
+
+impl SubmoduleStatus {
+    pub fn needs_attention(&self) -> bool {
+        self.uninitialized + self.out_of_sync + self.dirty > 0
+    }
+}
+
+/// How far an in-progress rebase has got: applying commit `step` of `total`
+#[derive(serde::Serialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OperationProgress {
+    pub step: usize,
+    pub total: usize,
+}
+
+impl std::fmt::Display for OperationProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}", self.step, self.total)
+    }
+}
+
+/// Conflict resolution progress during a merge or rebase
+#[derive(serde::Serialize, Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ResolutionState {
+    /// Unmerged paths still needing a manual resolution
+    pub remaining: usize,
+    /// Unmerged paths rerere resolved from a recorded resolution, waiting
+    /// for `git add`
+    pub rerere_resolved: usize,
+}
+
+impl ResolutionState {
+    /// Every conflict has a resolution from rerere
+    pub fn resolved_by_rerere(&self) -> bool {
+        self.remaining == 0 && self.rerere_resolved > 0
+    }
+}
+
+/// Whether a commit is signed, and whether the signature verifies
+/// (git's `%G?` placeholder)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum SignatureStatus {
+    /// Valid signature from a trusted key (`G`)
+    Good,
+    /// Valid signature from a key of unknown validity (`U`)
+    Untrusted,
+    /// Valid signature, but it or its key has expired (`X`, `Y`)
+    Expired,
+    /// Signed with a revoked key (`R`)
+    Revoked,
+    /// Signature doesn't verify (`B`)
+    Bad,
+    /// Signed, but the key isn't available to check it (`E`)
+    #[default]
+    Unverifiable,
+    /// Not signed (`N`)
+    Unsigned,
+}
+
+impl SignatureStatus {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "G" => Self::Good,
+            "U" => Self::Untrusted,
+            "X" | "Y" => Self::Expired,
+            "R" => Self::Revoked,
+            "B" => Self::Bad,
+            "N" => Self::Unsigned,
+            _ => Self::Unverifiable,
+        }
+    }
+
+    /// Indicator for the Signature column: green ✓ verified, yellow ? can't
+    /// be trusted or checked, red ✗ invalid, blank when unsigned
+    pub fn styled(self) -> String {
+        use color_print::cformat;
+
+        match self {
+            Self::Good => cformat!("<green>✓</>"),
+            Self::Untrusted | Self::Unverifiable => cformat!("<yellow>?</>"),
+            Self::Expired | Self::Revoked | Self::Bad => cformat!("<red>✗</>"),
+            Self::Unsigned => String::new(),
+        }
+    }
+}
+
+/// Signature on a branch's tip commit
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommitSignature {
+    pub status: SignatureStatus,
+    /// Signer identity as reported by gpg/ssh (empty when unsigned)
+    pub signer: Option<String>,
+}
+
 #[derive(serde::Serialize, Default, Clone, Debug)]
 pub struct UpstreamStatus {
     #[serde(rename = "upstream_remote")]
@@ -188,8 +316,11 @@ impl UpstreamStatus {
     }
 }
 
+/// Marks a detached-HEAD worktree in the Branch column
+pub const DETACHED_SYMBOL: &str = "∅";
+
 /// Unified item for displaying worktrees and branches in the same table
-#[derive(serde::Serialize)]
+#[derive(Clone, serde::Serialize)]
 pub struct ListItem {
     // Common fields (present for both worktrees and branches)
     #[serde(rename = "head_sha")]
@@ -227,6 +358,19 @@ pub struct ListItem {
     /// This is the cheapest integration check (~1ms).
     #[serde(skip)]
     pub is_ancestor: Option<bool>,
+    /// Paths that would conflict when merging into the default branch
+    /// (with `--full`, including uncommitted changes). Empty when clean or
+    /// not computed.
+    #[serde(skip)]
+    pub conflict_paths: Vec<String>,
+
+    /// Remote branch without a local branch (`--remotes`), like `origin/feature`
+    #[serde(skip)]
+    pub is_remote: bool,
+
+    /// 1-based row number in the table, for `wt switch 3` (the `#` column)
+    #[serde(skip)]
+    pub ordinal: Option<usize>,
 
     // TODO: Same concern as counts/branch_diff above - should upstream fields always be present?
     #[serde(flatten, skip_serializing_if = "Option::is_none")]
@@ -235,6 +379,13 @@ pub struct ListItem {
     /// CI/PR status: None = not loaded, Some(None) = no CI, Some(Some(status)) = has CI
     pub pr_status: Option<Option<PrStatus>>,
 
+    /// Tip commit signature: None = not loaded (or not requested)
+    #[serde(skip)]
+    pub signature: Option<CommitSignature>,
+    /// Branch description: None = not loaded, Some(None) = no description
+    #[serde(skip)]
+    pub description: Option<Option<String>>,
+
     /// Dev server URL computed from project config template
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
@@ -262,6 +413,9 @@ pub struct ListData {
     /// Currently only read by `select` command (unix-only).
     #[cfg_attr(windows, allow(dead_code))]
     pub main_worktree_path: std::path::PathBuf,
+    /// Whether the table showed the `#` column, numbering rows for
+    /// `wt switch 3` and `wt remove 3`
+    pub index_shown: bool,
 }
 
 impl ListItem {
@@ -277,8 +431,13 @@ impl ListItem {
             has_file_changes: None,
             would_merge_add: None,
             is_ancestor: None,
+            conflict_paths: Vec::new(),
+            is_remote: false,
+            ordinal: None,
             upstream: None,
             pr_status: None,
+            signature: None,
+            description: None,
             url: None,
             url_active: None,
             status_symbols: None,
@@ -291,6 +450,30 @@ impl ListItem {
         self.branch.as_deref().unwrap_or("(detached)")
     }
 
+    /// What `wt switch` takes to reach this row: the branch name, or the
+    /// worktree path for a detached HEAD.
+    pub fn switch_target(&self) -> String {
+        match (&self.branch, self.worktree_path()) {
+            (None, Some(path)) => path.to_string_lossy().into_owned(),
+            _ => self.branch_name().to_string(),
+        }
+    }
+
+    /// Branch column text: the branch name, or `∅ <short sha> <tag>` for a detached HEAD.
+    pub fn branch_label(&self) -> String {
+        if let Some(branch) = &self.branch {
+            return branch.clone();
+        }
+        let short_head = &self.head[..8.min(self.head.len())];
+        match self
+            .worktree_data()
+            .and_then(|data| data.describe.as_deref())
+        {
+            Some(describe) => format!("{DETACHED_SYMBOL} {short_head} {describe}"),
+            None => format!("{DETACHED_SYMBOL} {short_head}"),
+        }
+    }
+
     pub fn is_main(&self) -> bool {
         matches!(&self.kind, ItemKind::Worktree(data) if data.is_main)
     }
@@ -387,6 +570,18 @@ impl ListItem {
             }
         }
 
+        // Conflict counts while a rebase/merge is stopped on them
+        if let Some(data) = self.worktree_data()
+            && let Some(resolution) = data.resolution
+            && resolution.remaining + resolution.rerere_resolved > 0
+        {
+            let mut counts = format!("{} unresolved", resolution.remaining);
+            if resolution.rerere_resolved > 0 {
+                counts.push_str(&format!(", {} by rerere", resolution.rerere_resolved));
+            }
+            parts.push(counts);
+        }
+
         // 3. Working diff (worktrees only)
         // Prefix with @ ("at" current state) to distinguish from branch diff (^)
         if let Some(data) = self.worktree_data()
@@ -482,27 +677,38 @@ impl ListItem {
             ItemKind::Worktree(data) => {
                 // Full status computation for worktrees
 
-                // Worktree location state - priority: path_mismatch > prunable > locked
-                let worktree_state = if data.path_mismatch {
+                // Worktree location state - priority: broken > path_mismatch > prunable > locked > submodules > lfs > hooks
+                let worktree_state = if data.broken_gitdir.is_some() {
+                    WorktreeState::Broken
+                } else if data.path_mismatch {
                     WorktreeState::PathMismatch
                 } else if data.prunable.is_some() {
                     WorktreeState::Prunable
                 } else if data.locked.is_some() {
                     WorktreeState::Locked
+                } else if data.submodules.is_some_and(|s| s.needs_attention()) {
+                    WorktreeState::Submodules
+                } else if data.lfs_missing.is_some_and(|n| n > 0) {
+                    WorktreeState::LfsMissing
+                } else if data.hooks_running.is_some() {
+                    WorktreeState::HooksRunning
                 } else {
                     WorktreeState::None
                 };
 
-                // Operation state - priority: conflicts > rebase > merge
-                let operation_state = if has_conflicts {
-                    OperationState::Conflicts
-                } else if data.git_operation == GitOperationState::Rebase {
-                    OperationState::Rebase
-                } else if data.git_operation == GitOperationState::Merge {
-                    OperationState::Merge
-                } else {
-                    OperationState::None
-                };
+                // Operation state - priority: conflicts (↻ once rerere resolved them all) > rebase > merge
+                let operation_state =
+                    if has_conflicts && data.resolution.is_some_and(|r| r.resolved_by_rerere()) {
+                        OperationState::Resolved
+                    } else if has_conflicts {
+                        OperationState::Conflicts
+                    } else if data.git_operation == GitOperationState::Rebase {
+                        OperationState::Rebase
+                    } else if data.git_operation == GitOperationState::Merge {
+                        OperationState::Merge
+                    } else {
+                        OperationState::None
+                    };
 
                 // Check if content is integrated into main (safe to delete)
                 let has_untracked = working_tree_status.is_some_and(|s| s.untracked);
@@ -707,6 +913,17 @@ impl Divergence {
             Some(cformat!("<dim>{}</>", self.symbol()))
         }
     }
+
+    /// Meaning shown in the status legend
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::None => "",
+            Self::InSync => "In sync with remote",
+            Self::Ahead => "Ahead of remote",
+            Self::Behind => "Behind remote",
+            Self::Diverged => "Diverged from remote",
+        }
+    }
 }
 
 /// Worktree state indicator
@@ -715,19 +932,27 @@ impl Divergence {
 /// - For worktrees: whether the path matches the template, or has issues
 /// - For branches (without worktree): shows / to distinguish from worktrees
 ///
-/// Priority order for worktrees: PathMismatch > Prunable > Locked
+/// Priority order for worktrees: Broken > PathMismatch > Prunable > Locked > Submodules > LfsMissing > HooksRunning
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum::IntoStaticStr)]
 pub enum WorktreeState {
     #[strum(serialize = "")]
     /// Normal worktree (path matches template, not locked or prunable)
     #[default]
     None,
+    /// `.git` file points to a missing admin directory (git fails inside it)
+    Broken,
     /// Path doesn't match what the template would generate (red flag = "not at home")
     PathMismatch,
     /// Prunable (worktree directory missing)
     Prunable,
     /// Locked (protected from removal)
     Locked,
+    /// Submodules uninitialized, out of sync, or dirty
+    Submodules,
+    /// Git LFS objects not pulled (pointer files in the working tree)
+    LfsMissing,
+    /// Background hook commands still running
+    HooksRunning,
     /// Branch indicator (for branches without worktrees)
     Branch,
 }
@@ -736,14 +961,53 @@ impl std::fmt::Display for WorktreeState {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::None => Ok(()),
+            Self::Broken => write!(f, "⊘"),
             Self::PathMismatch => write!(f, "⚑"),
             Self::Prunable => write!(f, "⊟"),
             Self::Locked => write!(f, "⊞"),
+            Self::Submodules => write!(f, "⊡"),
+            Self::LfsMissing => write!(f, "◌"),
+            Self::HooksRunning => write!(f, "◷"),
             Self::Branch => write!(f, "/"),
         }
     }
 }
 
+impl WorktreeState {
+    /// Returns styled symbol with appropriate color, or None for None variant.
+    ///
+    /// Color semantics:
+    /// - ERROR (red): Broken, PathMismatch - the worktree isn't where git expects
+    /// - WARNING (yellow): Prunable, Locked, Submodules, LfsMissing, HooksRunning
+    /// - HINT (dimmed): Branch - informational
+    pub fn styled(self) -> Option<String> {
+        use color_print::cformat;
+        match self {
+            Self::None => None,
+            Self::Branch => Some(cformat!("<dim>{self}</>")),
+            Self::Broken | Self::PathMismatch => Some(cformat!("<red>{self}</>")),
+            _ => Some(cformat!("<yellow>{self}</>")),
+        }
+    }
+
+    /// Meaning shown in the status legend
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::None => "",
+            Self::Broken => {
+                "Broken .git link, e.g. after moving the repository (fix with wt repair)"
+            }
+            Self::PathMismatch => "Worktree path doesn't match branch name",
+            Self::Prunable => "Prunable (directory missing)",
+            Self::Locked => "Locked worktree",
+            Self::Submodules => "Submodules uninitialized, out of sync, or dirty",
+            Self::LfsMissing => "Git LFS objects not pulled",
+            Self::HooksRunning => "Background hooks running (see wt hook status)",
+            Self::Branch => "Branch without worktree",
+        }
+    }
+}
+
 impl serde::Serialize for WorktreeState {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -825,6 +1089,23 @@ impl MainState {
         }
     }
 
+    /// Meaning shown in the status legend
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::None => "",
+            Self::IsMain => "Is the default branch",
+            Self::WouldConflict => {
+                "Would conflict if merged to the default branch (with --full, includes uncommitted changes)"
+            }
+            Self::Empty => "Same commit as the default branch, clean",
+            Self::SameCommit => "Same commit as the default branch, uncommitted changes",
+            Self::Integrated(_) => "Content integrated into the default branch or target",
+            Self::Diverged => "Diverged from the default branch",
+            Self::Ahead => "Ahead of the default branch",
+            Self::Behind => "Behind the default branch",
+        }
+    }
+
     /// Returns the integration reason if this is an integrated state, None otherwise.
     pub fn integration_reason(&self) -> Option<IntegrationReason> {
         match self {
@@ -900,6 +1181,8 @@ pub enum OperationState {
     None,
     /// Actual merge conflicts (unmerged paths in working tree)
     Conflicts,
+    /// Unmerged paths that rerere has resolved, waiting for `git add`
+    Resolved,
     /// Rebase in progress
     Rebase,
     /// Merge in progress
@@ -911,6 +1194,7 @@ impl std::fmt::Display for OperationState {
         match self {
             Self::None => Ok(()),
             Self::Conflicts => write!(f, "✘"),
+            Self::Resolved => write!(f, "↻"),
             Self::Rebase => write!(f, "⤴"),
             Self::Merge => write!(f, "⤵"),
         }
@@ -922,13 +1206,24 @@ impl OperationState {
     ///
     /// Color semantics:
     /// - ERROR (red): Conflicts - blocking problems
-    /// - WARNING (yellow): Rebase, Merge - active/stuck states
+    /// - WARNING (yellow): Resolved, Rebase, Merge - active/stuck states
     pub fn styled(&self) -> Option<String> {
         use color_print::cformat;
         match self {
             Self::None => None,
             Self::Conflicts => Some(cformat!("<red>{self}</>")),
-            Self::Rebase | Self::Merge => Some(cformat!("<yellow>{self}</>")),
+            Self::Resolved | Self::Rebase | Self::Merge => Some(cformat!("<yellow>{self}</>")),
+        }
+    }
+
+    /// Meaning shown in the status legend
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::None => "",
+            Self::Conflicts => "Merge conflicts",
+            Self::Resolved => "Merge conflicts, all resolved by rerere (pending git add)",
+            Self::Rebase => "Rebase in progress",
+            Self::Merge => "Merge in progress",
         }
     }
 
@@ -937,6 +1232,7 @@ impl OperationState {
         match self {
             Self::None => None,
             Self::Conflicts => Some("conflicts"),
+            Self::Resolved => Some("resolved"),
             Self::Rebase => Some("rebase"),
             Self::Merge => Some("merge"),
         }
@@ -991,7 +1287,7 @@ impl PositionMask {
     const STAGED: usize = 0; // + (staged changes)
     const MODIFIED: usize = 1; // ! (modified files)
     const UNTRACKED: usize = 2; // ? (untracked files)
-    const WORKTREE_STATE: usize = 3; // Worktree: ✘⤴⤵/⚑⊟⊞
+    const WORKTREE_STATE: usize = 3; // Worktree: ✘⤴⤵/⊘⚑⊟⊞⊡◌◷
     const MAIN_STATE: usize = 4; // Main relationship: ^✗_⊂↕↑↓
     const UPSTREAM_DIVERGENCE: usize = 5; // Remote: |⇅⇡⇣
     const USER_MARKER: usize = 6;
@@ -1003,7 +1299,7 @@ impl PositionMask {
             1, // STAGED: + (1 char)
             1, // MODIFIED: ! (1 char)
             1, // UNTRACKED: ? (1 char)
-            1, // WORKTREE_STATE: ✘⤴⤵/⚑⊟⊞ (1 char, priority: conflicts > rebase > merge > path_mismatch > prunable > locked > branch)
+            1, // WORKTREE_STATE: ✘↻⤴⤵/⊘⚑⊟⊞⊡◌◷ (1 char, priority: conflicts > resolved > rebase > merge > broken > path_mismatch > prunable > locked > submodules > lfs_missing > hooks_running > branch)
             1, // MAIN_STATE: ^✗_–⊂↕↑↓ (1 char, priority: is_main > would_conflict > empty > same_commit > integrated > diverged > ahead > behind)
             1, // UPSTREAM_DIVERGENCE: |⇡⇣⇅ (1 char)
             2, // USER_MARKER: single emoji or two chars (allocate 2)
@@ -1021,7 +1317,7 @@ impl PositionMask {
 /// Symbols are categorized to enable vertical alignment in table output.
 /// Display order (left to right):
 /// - Working tree: +, !, ? (staged, modified, untracked - NOT mutually exclusive)
-/// - Worktree state: ✘, ⤴, ⤵, /, ⚑, ⊟, ⊞ (operations + location)
+/// - Worktree state: ✘, ⤴, ⤵, /, ⊘, ⚑, ⊟, ⊞, ⊡, ◌, ◷ (operations + location)
 /// - Main state: ^, ✗, _, ⊂, ↕, ↑, ↓ (relationship to main branch - single-stroke vertical arrows)
 /// - Upstream divergence: |, ⇅, ⇡, ⇣ (relationship to remote - vertical arrows)
 /// - User marker: custom labels, emoji
@@ -1029,13 +1325,17 @@ impl PositionMask {
 /// ## Mutual Exclusivity
 ///
 /// **Worktree state (operations take priority over location):**
-/// Priority: ✘ > ⤴ > ⤵ > ⚑ > ⊟ > ⊞ > /
+/// Priority: ✘ > ⤴ > ⤵ > ⊘ > ⚑ > ⊟ > ⊞ > ⊡ > ◌ > ◷ > /
 /// - ✘: Actual conflicts (must resolve)
 /// - ⤴: Rebase in progress
 /// - ⤵: Merge in progress
+/// - ⊘: Broken `.git` link (admin directory missing)
 /// - ⚑: Worktree path doesn't match branch name
 /// - ⊟: Prunable (directory missing)
 /// - ⊞: Locked worktree
+/// - ⊡: Submodules uninitialized, out of sync, or dirty
+/// - ◌: Git LFS objects not pulled
+/// - ◷: Background hooks running
 /// - /: Branch without worktree
 ///
 /// **Main state (single position with priority):**
@@ -1064,10 +1364,10 @@ pub struct StatusSymbols {
     pub(crate) main_state: MainState,
 
     /// Worktree operation and location state (single position)
-    /// Operations (✘⤴⤵) take priority over location states (/⚑⊟⊞)
+    /// Operations (✘⤴⤵) take priority over location states (/⚑⊟⊞⊡◌◷)
     pub(crate) operation_state: OperationState,
 
-    /// Worktree location state: / for branches, ⚑⊟⊞ for worktrees
+    /// Worktree location state: / for branches, ⚑⊟⊞⊡◌◷ for worktrees
     pub(crate) worktree_state: WorktreeState,
 
     /// Remote/upstream divergence state (mutually exclusive)
@@ -1153,12 +1453,10 @@ impl StatusSymbols {
     /// - Yellow: Git operations, would_conflict, locked/prunable (states needing attention)
     /// - Dimmed: Main state symbols, divergence arrows, branch indicator (informational)
     fn styled_symbols(&self) -> [(usize, String, bool); 7] {
-        use color_print::cformat;
-
         // Working tree symbols split into 3 fixed columns for vertical alignment
         let style_working = |has: bool, sym: char| -> (String, bool) {
             if has {
-                (cformat!("<cyan>{sym}</>"), true)
+                (styled_working_tree(sym), true)
             } else {
                 (String::new(), false)
             }
@@ -1179,21 +1477,15 @@ impl StatusSymbols {
             .styled()
             .map_or((String::new(), false), |s| (s, true));
 
-        // Worktree state: operations (✘⤴⤵) take priority over location (/⚑⊟⊞)
+        // Worktree state: operations (✘⤴⤵) take priority over location (/⚑⊟⊞⊡◌◷)
         let (worktree_str, has_worktree) = if self.operation_state != OperationState::None {
             // Operation state takes priority
             (self.operation_state.styled().unwrap_or_default(), true)
         } else {
             // Fall back to location state
-            match self.worktree_state {
-                WorktreeState::None => (String::new(), false),
-                // Branch indicator (/) is informational (dimmed)
-                WorktreeState::Branch => (cformat!("<dim>{}</>", self.worktree_state), true),
-                // Path mismatch (⚑) is a stronger warning (red)
-                WorktreeState::PathMismatch => (cformat!("<red>{}</>", self.worktree_state), true),
-                // Other worktree attrs (⊟⊞) are warnings (yellow)
-                _ => (cformat!("<yellow>{}</>", self.worktree_state), true),
-            }
+            self.worktree_state
+                .styled()
+                .map_or((String::new(), false), |s| (s, true))
         };
 
         let user_marker_str = self.user_marker.as_deref().unwrap_or("").to_string();
@@ -1220,6 +1512,114 @@ impl StatusSymbols {
     }
 }
 
+/// Working tree symbols (+!?) are cyan (activity indicator)
+fn styled_working_tree(sym: char) -> String {
+    color_print::cformat!("<cyan>{sym}</>")
+}
+
+/// One row of the status symbol legend
+#[derive(Debug, Clone)]
+pub struct LegendEntry {
+    /// Status subcolumn the symbol appears in
+    pub subcolumn: &'static str,
+    pub symbol: String,
+    /// Symbol as `wt list` renders it, with color
+    pub styled: String,
+    pub meaning: &'static str,
+}
+
+/// Every status symbol with its meaning, grouped by subcolumn in priority order,
+/// followed by the Branch column's detached-HEAD marker.
+///
+/// Built from the same definitions `wt list` renders with, so `wt list --legend`
+/// and the colorized help can't drift from the table.
+pub fn status_legend() -> Vec<LegendEntry> {
+    let working_tree = [
+        ("Working tree (1)", '+', "Staged files"),
+        ("Working tree (2)", '!', "Modified files (unstaged)"),
+        ("Working tree (3)", '?', "Untracked files"),
+    ]
+    .into_iter()
+    .map(|(subcolumn, sym, meaning)| LegendEntry {
+        subcolumn,
+        symbol: sym.to_string(),
+        styled: styled_working_tree(sym),
+        meaning,
+    });
+
+    let operations = [
+        OperationState::Conflicts,
+        OperationState::Resolved,
+        OperationState::Rebase,
+        OperationState::Merge,
+    ]
+    .into_iter()
+    .map(|state| (state.to_string(), state.styled(), state.description()));
+    let locations = [
+        WorktreeState::Branch,
+        WorktreeState::Broken,
+        WorktreeState::PathMismatch,
+        WorktreeState::Prunable,
+        WorktreeState::Locked,
+        WorktreeState::Submodules,
+        WorktreeState::LfsMissing,
+        WorktreeState::HooksRunning,
+    ]
+    .into_iter()
+    .map(|state| (state.to_string(), state.styled(), state.description()));
+    let main = [
+        MainState::IsMain,
+        MainState::WouldConflict,
+        MainState::Empty,
+        MainState::SameCommit,
+        MainState::Integrated(IntegrationReason::Ancestor),
+        MainState::Diverged,
+        MainState::Ahead,
+        MainState::Behind,
+    ]
+    .into_iter()
+    .map(|state| (state.to_string(), state.styled(), state.description()));
+    let remote = [
+        Divergence::InSync,
+        Divergence::Diverged,
+        Divergence::Ahead,
+        Divergence::Behind,
+    ]
+    .into_iter()
+    .map(|state| {
+        (
+            state.symbol().to_string(),
+            state.styled(),
+            state.description(),
+        )
+    });
+
+    let group = |subcolumn: &'static str, rows: Vec<(String, Option<String>, &'static str)>| {
+        rows.into_iter()
+            .map(move |(symbol, styled, meaning)| LegendEntry {
+                subcolumn,
+                symbol,
+                styled: styled.unwrap_or_default(),
+                meaning,
+            })
+    };
+
+    // Not a status symbol, but the one marker outside the Status column
+    let detached = LegendEntry {
+        subcolumn: "Branch",
+        symbol: DETACHED_SYMBOL.to_string(),
+        styled: DETACHED_SYMBOL.to_string(),
+        meaning: "Detached HEAD, shown with the short SHA and nearest tag",
+    };
+
+    working_tree
+        .chain(group("Worktree", operations.chain(locations).collect()))
+        .chain(group("Default branch", main.collect()))
+        .chain(group("Remote", remote.collect()))
+        .chain(std::iter::once(detached))
+        .collect()
+}
+
 /// Working tree changes as structured booleans
 ///
 /// This is the canonical internal representation. Display strings are derived from this.
@@ -1621,9 +2021,13 @@ mod tests {
     #[test]
     fn test_worktree_state_display() {
         assert_eq!(format!("{}", WorktreeState::None), "");
+        assert_eq!(format!("{}", WorktreeState::Broken), "⊘");
         assert_eq!(format!("{}", WorktreeState::PathMismatch), "⚑");
         assert_eq!(format!("{}", WorktreeState::Prunable), "⊟");
         assert_eq!(format!("{}", WorktreeState::Locked), "⊞");
+        assert_eq!(format!("{}", WorktreeState::Submodules), "⊡");
+        assert_eq!(format!("{}", WorktreeState::LfsMissing), "◌");
+        assert_eq!(format!("{}", WorktreeState::HooksRunning), "◷");
         assert_eq!(format!("{}", WorktreeState::Branch), "/");
     }
 
@@ -1974,4 +2378,30 @@ mod tests {
         assert_eq!(details.timestamp, 0);
         assert_eq!(details.commit_message, "");
     }
+
+    // ============================================================================
+    // Status Legend Tests
+    // ============================================================================
+
+    #[test]
+    fn test_status_legend() {
+        let legend = status_legend();
+        let symbols: String = legend.iter().map(|entry| entry.symbol.as_str()).collect();
+        assert_eq!(symbols, "+!?✘↻⤴⤵/⊘⚑⊟⊞⊡◌◷^✗_–⊂↕↑↓|⇅⇡⇣∅");
+
+        // Styled exactly as the Status column renders them
+        let conflicts = legend.iter().find(|entry| entry.symbol == "✘").unwrap();
+        assert_eq!(conflicts.subcolumn, "Worktree");
+        assert_eq!(
+            Some(conflicts.styled.clone()),
+            OperationState::Conflicts.styled()
+        );
+        let hooks = legend.iter().find(|entry| entry.symbol == "◷").unwrap();
+        assert_eq!(
+            Some(hooks.styled.clone()),
+            WorktreeState::HooksRunning.styled()
+        );
+
+        assert!(legend.iter().all(|entry| !entry.meaning.is_empty()));
+    }
 }
@@ -15,7 +15,8 @@ impl RenderMode {
     /// * `progressive` - Rendering mode (Some(true) = --progressive, Some(false) = --no-progressive, None = auto)
     ///
     /// Table output goes to stdout, so we check stdout's TTY status. When piped
-    /// (`wt list | grep`), we buffer; when interactive, we render progressively.
+    /// (`wt list | grep`), we buffer; when interactive, we render progressively
+    /// unless the terminal can't redraw rows in place.
     pub fn detect(progressive: Option<bool>) -> Self {
         // Priority 1: Explicit CLI flag
         match progressive {
@@ -24,10 +25,9 @@ impl RenderMode {
             None => {} // Fall through to auto-detection
         }
 
-        // Priority 2: Auto-detect based on stdout TTY
+        // Priority 2: Auto-detect based on stdout TTY and terminal capabilities
         use std::io::IsTerminal;
-        if std::io::stdout().is_terminal() {
-            // TODO: Check for pager in environment
+        if std::io::stdout().is_terminal() && !cannot_redraw(|name| std::env::var(name).ok()) {
             RenderMode::Progressive
         } else {
             RenderMode::Buffered
@@ -35,6 +35,21 @@ impl RenderMode {
     }
 }
 
+/// Whether the environment shows a terminal that can't move the cursor back
+/// up to redraw rows, even though stdout is a TTY:
+///
+/// - `TERM=dumb`, which Emacs' `M-x shell` and many CI consoles set
+/// - Emacs' comint and eshell buffers (`INSIDE_EMACS`), which don't interpret
+///   cursor movement whatever `TERM` says; `term` and `vterm` buffers do
+/// - a pager git started for us (`GIT_PAGER_IN_USE`), which shows the escape
+///   sequences instead of acting on them
+fn cannot_redraw(env: impl Fn(&str) -> Option<String>) -> bool {
+    env("TERM").is_some_and(|term| term == "dumb")
+        || env("INSIDE_EMACS")
+            .is_some_and(|emacs| emacs.contains("comint") || emacs.contains("eshell"))
+        || env("GIT_PAGER_IN_USE").is_some_and(|value| !value.is_empty())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,4 +64,26 @@ mod tests {
 
         // None should auto-detect (tested via TTY checks in runtime)
     }
+
+    #[test]
+    fn test_cannot_redraw() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert!(!cannot_redraw(env(&[])));
+        assert!(!cannot_redraw(env(&[("TERM", "xterm-256color")])));
+        assert!(cannot_redraw(env(&[("TERM", "dumb")])));
+        assert!(cannot_redraw(env(&[
+            ("TERM", "xterm-256color"),
+            ("INSIDE_EMACS", "29.1,comint")
+        ])));
+        assert!(cannot_redraw(env(&[("INSIDE_EMACS", "29.1,eshell")])));
+        assert!(!cannot_redraw(env(&[("INSIDE_EMACS", "vterm")])));
+        assert!(!cannot_redraw(env(&[("INSIDE_EMACS", "29.1,term:0.96")])));
+        assert!(cannot_redraw(env(&[("GIT_PAGER_IN_USE", "true")])));
+    }
 }
@@ -3,11 +3,13 @@ use anstyle::Style;
 use std::path::Path;
 use unicode_width::UnicodeWidthStr;
 use worktrunk::styling::StyledLine;
+use worktrunk::utils::get_now;
 
 use super::ci_status::PrStatus;
 use super::columns::{ColumnKind, DiffVariant};
 use super::layout::{
-    ColumnFormat, ColumnLayout, DiffColumnConfig, DiffDisplayConfig, LayoutConfig,
+    AgeThresholds, ColumnFormat, ColumnLayout, DiffColumnConfig, DiffDisplayConfig, LayoutConfig,
+    Placeholder,
 };
 use super::model::{
     AheadBehind, CommitDetails, ListItem, PositionMask, UpstreamStatus, WorktreeData,
@@ -376,7 +378,12 @@ impl LayoutConfig {
         item: &ListItem,
         previous_branch: Option<&str>,
     ) -> StyledLine {
-        let ctx = ListRowContext::new(item, previous_branch);
+        let ctx = ListRowContext::new(
+            item,
+            previous_branch,
+            &self.age_thresholds,
+            &self.placeholder,
+        );
         self.render_line(|column| {
             column.render_cell(
                 &ctx,
@@ -393,7 +400,7 @@ impl LayoutConfig {
     pub fn format_skeleton_row(&self, item: &super::model::ListItem) -> String {
         use crate::display::shorten_path;
 
-        let branch = item.branch_name();
+        let branch = item.branch_label();
         let wt_data = item.worktree_data();
         let shortened_path = item
             .worktree_path()
@@ -401,7 +408,6 @@ impl LayoutConfig {
             .unwrap_or_default();
 
         let dim = Style::new().dimmed();
-        let spinner = "⋯"; // Placeholder character
 
         let line = self.render_line(|col| {
             let mut cell = StyledLine::new();
@@ -417,9 +423,14 @@ impl LayoutConfig {
                     };
                     cell.push_styled(symbol, dim);
                 }
+                ColumnKind::Index => {
+                    if let Some(ordinal) = item.ordinal {
+                        cell.push_styled(format!("{ordinal:>width$}", width = col.width), dim);
+                    }
+                }
                 ColumnKind::Branch => {
                     // Show actual branch name (no dim - start normal, gray out later if removable)
-                    cell.push_raw(branch.to_string());
+                    cell.push_raw(branch.clone());
                     cell.pad_to(col.width);
                 }
                 ColumnKind::Path => {
@@ -434,8 +445,8 @@ impl LayoutConfig {
                     cell.push_styled(short_head, dim);
                 }
                 _ => {
-                    // Show spinner for data columns
-                    cell.push_styled(spinner, dim);
+                    // Show placeholder for data columns
+                    self.placeholder.push_to(&mut cell);
                     cell.pad_to(col.width);
                 }
             }
@@ -458,10 +469,16 @@ struct ListRowContext<'a> {
     text_style: Option<Style>,
     is_current: bool,
     is_previous: bool,
+    placeholder: &'a Placeholder,
 }
 
 impl<'a> ListRowContext<'a> {
-    fn new(item: &'a ListItem, previous_branch: Option<&str>) -> Self {
+    fn new(
+        item: &'a ListItem,
+        previous_branch: Option<&str>,
+        age_thresholds: &AgeThresholds,
+        placeholder: &'a Placeholder,
+    ) -> Self {
         let worktree_data = item.worktree_data();
         let counts = item.counts();
         let commit = item.commit_details();
@@ -487,9 +504,10 @@ impl<'a> ListRowContext<'a> {
             text_style: None,
             is_current,
             is_previous,
+            placeholder,
         };
 
-        ctx.text_style = ctx.compute_text_style();
+        ctx.text_style = ctx.compute_text_style(age_thresholds);
         ctx
     }
 
@@ -497,11 +515,25 @@ impl<'a> ListRowContext<'a> {
         &self.head[..8.min(self.head.len())]
     }
 
-    fn compute_text_style(&self) -> Option<Style> {
+    fn compute_text_style(&self, age_thresholds: &AgeThresholds) -> Option<Style> {
         // No special styling for current worktree - gutter symbol (@) and top position
-        // already communicate it. Only dim removable worktrees.
+        // already communicate it. Dim removable worktrees, then style by commit age.
         if self.item.should_dim() {
+            return Some(Style::new().dimmed());
+        }
+        // Age is unknown until commit details load
+        self.item.commit.as_ref()?;
+        let age = (get_now() as i64 - self.commit.timestamp).max(0) as u64;
+        if age_thresholds
+            .dim_after
+            .is_some_and(|threshold| age > threshold.as_secs())
+        {
             Some(Style::new().dimmed())
+        } else if age_thresholds
+            .highlight_within
+            .is_some_and(|threshold| age < threshold.as_secs())
+        {
+            Some(Style::new().bold())
         } else {
             None
         }
@@ -546,13 +578,23 @@ impl ColumnLayout {
                 cell.push_raw(symbol.to_string());
                 cell
             }
+            ColumnKind::Index => {
+                let mut cell = StyledLine::new();
+                if let Some(ordinal) = ctx.item.ordinal {
+                    cell.push_styled(
+                        format!("{ordinal:>width$}", width = self.width),
+                        Style::new().dimmed(),
+                    );
+                }
+                cell
+            }
             ColumnKind::Branch => {
                 let mut cell = StyledLine::new();
-                let text = ctx.item.branch.as_deref().unwrap_or("-");
+                let text = ctx.item.branch_label();
                 if let Some(style) = ctx.text_style {
-                    cell.push_styled(text.to_string(), style);
+                    cell.push_styled(text, style);
                 } else {
-                    cell.push_raw(text.to_string());
+                    cell.push_raw(text);
                 }
                 cell.truncate_to_width(self.width)
             }
@@ -564,7 +606,7 @@ impl ColumnLayout {
                     cell.push_raw(status_symbols.render_with_mask(status_mask));
                 } else {
                     // Show spinner while status is being computed (both worktrees and branches)
-                    cell.push_styled("⋯", Style::new().dimmed());
+                    ctx.placeholder.push_to(&mut cell);
                 }
 
                 // Truncate if exceeds column width, then pad
@@ -598,6 +640,16 @@ impl ColumnLayout {
                 }
                 self.render_diff_cell(ctx.branch_diff.added, ctx.branch_diff.deleted)
             }
+            ColumnKind::Files => {
+                let files = ctx.branch_diff.files;
+                if ctx.item.is_main() || files == 0 {
+                    return StyledLine::new();
+                }
+                // Right-align counts, like the diff columns
+                let mut cell = StyledLine::new();
+                cell.push_raw(format!("{files:>width$}", width = self.width));
+                cell
+            }
             ColumnKind::Path => {
                 let Some(data) = ctx.worktree_data else {
                     return StyledLine::new();
@@ -633,7 +685,7 @@ impl ColumnLayout {
 
                 // Show spinner if commit details haven't loaded yet (for both worktrees and branches)
                 if ctx.item.commit.is_none() {
-                    cell.push_styled("⋯", Style::new().dimmed());
+                    ctx.placeholder.push_to(&mut cell);
                 } else {
                     let time_str = format_relative_time_short(ctx.commit.timestamp);
                     cell.push_styled(time_str, Style::new().dimmed());
@@ -679,7 +731,7 @@ impl ColumnLayout {
                     None => {
                         // Not loaded yet - show spinner
                         let mut cell = StyledLine::new();
-                        cell.push_styled("⋯", Style::new().dimmed());
+                        ctx.placeholder.push_to(&mut cell);
                         cell
                     }
                     Some(None) => {
@@ -692,6 +744,26 @@ impl ColumnLayout {
                     }
                 }
             }
+            ColumnKind::Description => {
+                let mut cell = StyledLine::new();
+                match &ctx.item.description {
+                    None => ctx.placeholder.push_to(&mut cell),
+                    Some(None) => {}
+                    // Descriptions can span lines; the first is the summary
+                    Some(Some(description)) => {
+                        cell.push_raw(description.lines().next().unwrap_or_default().to_string())
+                    }
+                }
+                cell.truncate_to_width(self.width)
+            }
+            ColumnKind::Signature => {
+                let mut cell = StyledLine::new();
+                match &ctx.item.signature {
+                    None => ctx.placeholder.push_to(&mut cell),
+                    Some(signature) => cell.push_raw(signature.status.styled()),
+                }
+                cell
+            }
             ColumnKind::Commit => {
                 let mut cell = StyledLine::new();
                 cell.push_styled(ctx.short_head().to_string(), Style::new().dimmed());
@@ -702,8 +774,22 @@ impl ColumnLayout {
 
                 // Show spinner if commit details haven't loaded yet (for both worktrees and branches)
                 if ctx.item.commit.is_none() {
-                    cell.push_styled("⋯", Style::new().dimmed());
+                    ctx.placeholder.push_to(&mut cell);
                 } else {
+                    let mut max_message_len = max_message_len;
+                    // An interrupted rebase leads with how far it got: `rebase 3/7 · …`
+                    if let Some(progress) = ctx
+                        .item
+                        .worktree_data()
+                        .and_then(|data| data.operation_progress)
+                    {
+                        let prefix = format!("rebase {progress} · ");
+                        max_message_len = max_message_len.saturating_sub(prefix.width());
+                        cell.push_styled(
+                            prefix,
+                            Style::new().fg_color(Some(anstyle::AnsiColor::Yellow.into())),
+                        );
+                    }
                     let msg = truncate_to_width(&ctx.commit.commit_message, max_message_len);
                     cell.push_styled(msg, Style::new().dimmed());
                 }
@@ -1,9 +1,13 @@
 use std::path::Path;
 
+use color_print::cformat;
+use serde::{Deserialize, Serialize};
 use worktrunk::HookType;
 use worktrunk::config::ProjectConfig;
 use worktrunk::git::Repository;
-use worktrunk::styling::info_message;
+use worktrunk::styling::{
+    format_with_gutter, info_message, progress_message, success_message, warning_message,
+};
 
 use super::command_approval::approve_command_batch;
 use super::command_executor::CommandContext;
@@ -24,6 +28,46 @@ pub struct MergeOptions<'a> {
     pub verify: bool,
     pub yes: bool,
     pub stage_mode: super::commit::StageMode,
+    pub dry_run: bool,
+    /// Refuse unless the branch's CI has passed (`--require-ci`, `merge.require-ci`)
+    pub require_ci: bool,
+    /// GPG-sign the commits the merge creates (`--gpg-sign`, `merge.sign`)
+    pub sign: bool,
+    /// Stash changes the merge won't commit, restoring them afterward
+    /// (`--autostash`, `merge.autostash`)
+    pub autostash: bool,
+}
+
+/// A `wt merge` stopped by rebase conflicts, saved so `wt merge --continue`
+/// can finish it and `wt merge --abort` can undo it
+#[derive(Serialize, Deserialize)]
+struct PendingMerge {
+    target: String,
+    /// Branch tip before the merge committed or squashed anything
+    orig_head: String,
+    remove: bool,
+    verify: bool,
+    committed: bool,
+    squashed: bool,
+    #[serde(default)]
+    sign: bool,
+    /// Commit of the changes `--autostash` set aside, restored once the merge finishes
+    #[serde(default)]
+    autostash: Option<String>,
+}
+
+impl PendingMerge {
+    fn load(repo: &Repository, branch: &str) -> Option<Self> {
+        serde_json::from_str(&repo.pending_merge(branch)?).ok()
+    }
+
+    fn save(&self, repo: &Repository, branch: &str) -> anyhow::Result<()> {
+        repo.set_pending_merge(branch, Some(&serde_json::to_string(self)?))
+    }
+
+    fn clear(repo: &Repository, branch: &str) -> anyhow::Result<()> {
+        repo.set_pending_merge(branch, None)
+    }
 }
 
 /// Reason why a worktree was preserved (not removed) after merge
@@ -34,6 +78,8 @@ enum PreserveReason {
     IsMainWorktree,
     /// Current branch is the same as the target branch
     AlreadyOnTarget,
+    /// The worktree holds changes restored by `--autostash`
+    Autostashed,
 }
 
 /// Context for collecting merge commands
@@ -73,9 +119,11 @@ impl<'a> MergeCommandCollector<'a> {
 
         if !self.no_verify {
             hooks.push(HookType::PreMerge);
+            hooks.push(HookType::PrePush);
             hooks.push(HookType::PostMerge);
             if self.will_remove {
                 hooks.push(HookType::PreRemove);
+                hooks.push(HookType::PostRemove);
                 hooks.push(HookType::PostSwitch);
             }
         }
@@ -97,7 +145,13 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         verify,
         yes,
         stage_mode,
+        dry_run,
+        require_ci,
+        sign,
+        autostash,
     } = opts;
+    // --no-hooks skips hooks everywhere, as --no-verify does for merge
+    let verify = verify && super::hooks::hooks_enabled();
 
     let env = CommandEnv::for_action("merge")?;
     let repo = &env.repo;
@@ -105,8 +159,8 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     // Merge requires being on a branch (can't merge from detached HEAD)
     let current_branch = env.require_branch("merge")?.to_string();
 
-    // Validate --no-commit: requires clean working tree
-    if !commit && repo.is_dirty()? {
+    // Validate --no-commit: requires clean working tree, unless it gets stashed
+    if !commit && !autostash && repo.is_dirty()? {
         return Err(worktrunk::git::GitError::UncommittedChanges {
             action: Some("merge with --no-commit".into()),
             branch: Some(current_branch.clone()),
@@ -119,15 +173,24 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
 
     // Get target branch (default to default branch if not provided)
     let target_branch = repo.resolve_target_branch(target)?;
-    let worktrees = repo.list_worktrees()?;
-    // Worktree for target is optional: if present we use it for safety checks and as destination.
-    let target_worktree_path = repo.worktree_for_branch(&target_branch)?;
+    crate::output::report::record(|report| {
+        report.branch = Some(current_branch.clone());
+        report.target = Some(target_branch.clone());
+    });
 
     // When current == target or we're in the main worktree, disable remove (can't remove it)
     let in_main = !repo.is_in_worktree().unwrap_or(false);
     let on_target = current_branch == target_branch;
     let remove_effective = remove && !on_target && !in_main;
 
+    if dry_run {
+        return handle_merge_dry_run(repo, &current_branch, &target_branch, commit);
+    }
+
+    if require_ci {
+        require_ci_passed(repo, &current_branch, config)?;
+    }
+
     // Collect and approve all commands upfront for batch permission request
     let (all_commands, project_id) = MergeCommandCollector {
         repo,
@@ -138,7 +201,13 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     .collect()?;
 
     // Approve all commands in a single batch (shows templates, not expanded values)
-    let approved = approve_command_batch(&all_commands, &project_id, config, yes, false)?;
+    let approved = approve_command_batch(
+        &all_commands,
+        &project_id,
+        config,
+        yes || super::command_approval::assume_yes(),
+        false,
+    )?;
 
     // If commands were declined, skip hooks but continue with merge
     // Shadow verify to gate all subsequent hook execution on approval
@@ -149,6 +218,9 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         verify
     };
 
+    // Remember where the branch started, so `wt merge --abort` can return to it
+    let orig_head = repo.run_command(&["rev-parse", "HEAD"])?.trim().to_string();
+
     // Handle uncommitted changes (skip if --no-commit) - track whether commit occurred
     let committed = if commit && repo.is_dirty()? {
         if squash_enabled {
@@ -161,6 +233,7 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
             options.stage_mode = stage_mode;
             options.warn_about_untracked = stage_mode == super::commit::StageMode::All;
             options.show_no_squash_note = true;
+            options.sign = sign;
 
             options.commit()?;
             true // Committed directly
@@ -176,7 +249,8 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
                 Some(&target_branch),
                 yes,
                 !verify, // skip_pre_commit when !verify
-                stage_mode
+                stage_mode,
+                sign,
             )?,
             super::standalone::SquashResult::Squashed
         )
@@ -184,69 +258,423 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         false
     };
 
+    // Set aside whatever is still uncommitted so the rebase sees a clean tree
+    let autostash = if autostash && repo.is_dirty()? {
+        autostash_changes(repo)?
+    } else {
+        None
+    };
+
     // Rebase onto target - track whether rebasing occurred
     let rebased = if rebase {
-        // Auto-rebase onto target
-        matches!(
-            super::standalone::handle_rebase(Some(&target_branch))?,
-            super::standalone::RebaseResult::Rebased
-        )
+        let pending = PendingMerge {
+            target: target_branch.clone(),
+            orig_head,
+            remove,
+            verify,
+            committed,
+            squashed,
+            sign,
+            autostash: autostash.clone(),
+        };
+        rebase_for_merge(repo, &current_branch, &pending)
+    } else if repo.is_rebased_onto(&target_branch)? {
+        Ok(false) // Already rebased, no rebase occurred
     } else {
         // --no-rebase: verify already rebased, fail if not
-        if !repo.is_rebased_onto(&target_branch)? {
-            return Err(worktrunk::git::GitError::NotRebased {
-                target_branch: target_branch.clone(),
+        Err(worktrunk::git::GitError::NotRebased {
+            target_branch: target_branch.clone(),
+        }
+        .into())
+    };
+    let rebased = match rebased {
+        Ok(rebased) => rebased,
+        Err(e) => {
+            // A rebase stopped by conflicts keeps the stash for `--continue` or `--abort`
+            let stopped = matches!(
+                e.downcast_ref::<worktrunk::git::GitError>(),
+                Some(worktrunk::git::GitError::RebaseConflict { .. })
+            );
+            if let Some(stash) = autostash.as_deref()
+                && !stopped
+            {
+                restore_autostash(repo, stash)?;
             }
-            .into());
+            return Err(e);
         }
-        false // Already rebased, no rebase occurred
     };
 
-    // Run pre-merge checks unless --no-verify was specified
-    // Do this after commit/squash/rebase to validate the final state that will be pushed
-    if verify {
-        let ctx = env.context(yes);
-        let project_config = repo.load_project_config()?.unwrap_or_default();
-        run_pre_merge_commands(&project_config, &ctx, &target_branch, None)?;
-    }
-
-    // Fast-forward push to target branch with commit/squash/rebase info for consolidated message
-    handle_push(
-        Some(&target_branch),
-        "Merged to",
-        Some(MergeOperations {
+    finish_merge(
+        &env,
+        &current_branch,
+        &target_branch,
+        remove,
+        verify,
+        yes,
+        MergeOperations {
             committed,
             squashed,
             rebased,
-        }),
+        },
+        autostash.as_deref(),
+    )
+}
+
+/// Fail unless the branch's latest CI run passed on the commit being merged
+fn require_ci_passed(
+    repo: &Repository,
+    branch: &str,
+    config: &worktrunk::config::WorktrunkConfig,
+) -> anyhow::Result<()> {
+    use super::list::ci_status::{CiStatus, PrStatus};
+
+    let head = repo.run_command(&["rev-parse", "HEAD"])?;
+    let has_upstream = repo.upstream_branch(branch).ok().flatten().is_some();
+    let status = PrStatus::detect(
+        branch,
+        head.trim(),
+        repo.worktree_root()?,
+        has_upstream,
+        &config.ci(),
+    );
+    let reason = match status {
+        Some(status) if status.is_stale => "CI hasn't run on the local commits; push them first",
+        Some(PrStatus {
+            ci_status: CiStatus::Passed,
+            ..
+        }) => {
+            crate::output::print(info_message(cformat!("CI passed on <bold>{branch}</>")))?;
+            return Ok(());
+        }
+        Some(PrStatus {
+            ci_status: CiStatus::Running,
+            ..
+        }) => "CI is still running",
+        Some(PrStatus {
+            ci_status: CiStatus::Failed,
+            ..
+        }) => "CI failed",
+        Some(PrStatus {
+            ci_status: CiStatus::Conflicts,
+            ..
+        }) => "its pull request has conflicts",
+        Some(PrStatus {
+            ci_status: CiStatus::Error,
+            ..
+        }) => "CI status couldn't be fetched",
+        Some(PrStatus {
+            ci_status: CiStatus::NoCI,
+            ..
+        })
+        | None => "no CI status found",
+    };
+    Err(worktrunk::git::GitError::CiNotPassing {
+        branch: branch.to_string(),
+        reason: reason.to_string(),
+    }
+    .into())
+}
+
+/// Rebase onto the merge target. When conflicts stop the rebase, save the
+/// merge so `wt merge --continue` can pick it up once they're resolved.
+fn rebase_for_merge(
+    repo: &Repository,
+    branch: &str,
+    pending: &PendingMerge,
+) -> anyhow::Result<bool> {
+    match super::standalone::handle_rebase(Some(&pending.target), pending.sign) {
+        Ok(result) => Ok(matches!(result, super::standalone::RebaseResult::Rebased)),
+        Err(e) => match e.downcast::<worktrunk::git::GitError>() {
+            Ok(worktrunk::git::GitError::RebaseConflict {
+                target_branch,
+                git_output,
+                ..
+            }) => {
+                pending.save(repo, branch)?;
+                Err(worktrunk::git::GitError::RebaseConflict {
+                    target_branch,
+                    git_output,
+                    in_merge_context: true,
+                }
+                .into())
+            }
+            Ok(other) => Err(other.into()),
+            Err(e) => Err(e),
+        },
+    }
+}
+
+/// Finish a `wt merge` stopped by rebase conflicts, once they're resolved
+pub fn handle_merge_continue(yes: bool) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let branch = stopped_merge_branch(&repo)?;
+    let Some(pending) = PendingMerge::load(&repo, &branch) else {
+        return Err(no_merge_in_progress(&branch));
+    };
+
+    if repo.rebase_head_branch().is_some() {
+        // `core.editor=true` keeps each commit message as is, without opening an editor
+        if let Err(e) = repo.run_command(&["-c", "core.editor=true", "rebase", "--continue"]) {
+            if repo.rebase_head_branch().is_some() {
+                return Err(worktrunk::git::GitError::RebaseConflict {
+                    target_branch: pending.target.clone(),
+                    git_output: e.to_string(),
+                    in_merge_context: true,
+                }
+                .into());
+            }
+            return Err(e);
+        }
+        crate::output::print(success_message(cformat!(
+            "Rebased onto <bold>{}</>",
+            pending.target
+        )))?;
+    }
+
+    // HEAD is back on the branch now, so load the environment afresh
+    let env = CommandEnv::for_action("merge")?;
+    let repo = &env.repo;
+    crate::output::report::record(|report| {
+        report.branch = Some(branch.clone());
+        report.target = Some(pending.target.clone());
+    });
+
+    // The target may have moved on while conflicts were being resolved
+    rebase_for_merge(repo, &branch, &pending)?;
+
+    let verify = pending.verify && super::hooks::hooks_enabled();
+    let in_main = !repo.is_in_worktree().unwrap_or(false);
+    let remove_effective = pending.remove && branch != pending.target && !in_main;
+    let (all_commands, project_id) = MergeCommandCollector {
+        repo,
+        no_commit: true,
+        no_verify: !verify,
+        will_remove: remove_effective,
+    }
+    .collect()?;
+    let approved = approve_command_batch(
+        &all_commands,
+        &project_id,
+        &env.config,
+        yes || super::command_approval::assume_yes(),
+        false,
     )?;
+    let verify = if !approved {
+        crate::output::print(info_message("Commands declined, continuing merge"))?;
+        false
+    } else {
+        verify
+    };
 
-    // Destination: prefer the target branch's worktree; fall back to main when absent
-    let destination_path = target_worktree_path
-        .clone()
-        .unwrap_or_else(|| worktrees[0].path.clone());
+    finish_merge(
+        &env,
+        &branch,
+        &pending.target,
+        pending.remove,
+        verify,
+        yes,
+        MergeOperations {
+            committed: pending.committed,
+            squashed: pending.squashed,
+            rebased: true,
+        },
+        pending.autostash.as_deref(),
+    )
+}
+
+/// Undo a `wt merge` stopped by rebase conflicts: abort the rebase and move
+/// the branch back to where it was, leaving any changes it committed staged
+pub fn handle_merge_abort() -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let branch = stopped_merge_branch(&repo)?;
+    let Some(pending) = PendingMerge::load(&repo, &branch) else {
+        return Err(no_merge_in_progress(&branch));
+    };
+
+    if repo.rebase_head_branch().is_some() {
+        repo.run_command(&["rebase", "--abort"])?;
+    }
+    let head = repo.run_command(&["rev-parse", "HEAD"])?.trim().to_string();
+    let restored = head != pending.orig_head;
+    if restored {
+        repo.run_command(&["reset", "--soft", &pending.orig_head])?;
+    }
+    PendingMerge::clear(&repo, &branch)?;
+    let staged = restored && repo.is_dirty()?;
+
+    crate::output::print(success_message(cformat!(
+        "Aborted merge of <bold>{branch}</> into <bold>{}</>",
+        pending.target
+    )))?;
+    if staged {
+        crate::output::print(info_message(
+            "Changes the merge committed or squashed are staged",
+        ))?;
+    }
+    if let Some(stash) = &pending.autostash {
+        restore_autostash(&repo, stash)?;
+    }
+    Ok(())
+}
+
+/// Stash uncommitted changes, including untracked files, for `--autostash`.
+/// Returns the stash commit, or `None` if git found nothing to stash.
+fn autostash_changes(repo: &Repository) -> anyhow::Result<Option<String>> {
+    crate::output::print(progress_message("Stashing uncommitted changes..."))?;
+    let output = repo.run_command(&[
+        "stash",
+        "push",
+        "--include-untracked",
+        "-m",
+        "worktrunk merge autostash",
+    ])?;
+    if output.contains("No local changes to save") {
+        return Ok(None);
+    }
+    let sha = repo.run_command(&["rev-parse", "stash@{0}"])?;
+    Ok(Some(sha.trim().to_string()))
+}
+
+/// Put back the changes `--autostash` set aside. Returns whether they came
+/// back; when they can't, they stay in the stash list and a warning says where.
+fn restore_autostash(repo: &Repository, stash: &str) -> anyhow::Result<bool> {
+    // Find the entry by commit: its `stash@{n}` position shifts as other stashes come and go
+    let list = repo.run_command(&["stash", "list", "--format=%gd %H"])?;
+    let Some(entry) = list
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .find_map(|(entry, sha)| (sha == stash).then_some(entry))
+    else {
+        crate::output::print(warning_message(cformat!(
+            "Autostashed changes are no longer in the stash list; recover them with <bold>git stash apply {stash}</>"
+        )))?;
+        return Ok(false);
+    };
+
+    crate::output::print(progress_message("Restoring autostashed changes..."))?;
+    if repo
+        .run_command(&["stash", "pop", "--quiet", entry])
+        .is_err()
+    {
+        crate::output::print(warning_message(cformat!(
+            "Couldn't restore autostashed changes cleanly; they're kept in <bold>{entry}</> (<bold>git stash pop</> to retry)"
+        )))?;
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+/// The branch of the current worktree, even while a rebase has HEAD detached
+fn stopped_merge_branch(repo: &Repository) -> anyhow::Result<String> {
+    if let Some(branch) = repo.rebase_head_branch() {
+        return Ok(branch);
+    }
+    repo.require_current_branch("merge")
+}
+
+fn no_merge_in_progress(branch: &str) -> anyhow::Error {
+    worktrunk::git::GitError::Other {
+        message: cformat!("No stopped merge on <bold>{branch}</> to continue or abort"),
+    }
+    .into()
+}
+
+/// The merge steps that follow the rebase: hooks, push, cleanup, and
+/// post-merge hooks. Shared by `wt merge` and `wt merge --continue`.
+#[allow(clippy::too_many_arguments)]
+fn finish_merge(
+    env: &CommandEnv,
+    current_branch: &str,
+    target_branch: &str,
+    remove: bool,
+    verify: bool,
+    yes: bool,
+    operations: MergeOperations,
+    autostash: Option<&str>,
+) -> anyhow::Result<()> {
+    let repo = &env.repo;
+    let config = &env.config;
+    let worktrees = repo.list_worktrees()?;
+    // Worktree for target is optional: if present we use it for safety checks and as destination.
+    let target_worktree_path = repo.worktree_for_branch(target_branch)?;
+    let in_main = !repo.is_in_worktree().unwrap_or(false);
+    let on_target = current_branch == target_branch;
+    let remove_effective = remove && !on_target && !in_main;
+
+    let pushed = (|| {
+        // Run pre-merge checks unless --no-verify was specified
+        // Do this after commit/squash/rebase to validate the final state that will be pushed
+        if verify {
+            let ctx = env.context(yes);
+            let project_config = repo.load_project_config()?.unwrap_or_default();
+            run_pre_merge_commands(&project_config, &ctx, target_branch, None)?;
+            run_pre_push_commands(&ctx, target_branch, None)?;
+        }
+
+        // Fast-forward push to target branch with commit/squash/rebase info for consolidated message
+        handle_push(Some(target_branch), "Merged to", Some(operations))
+    })();
+    if let Err(e) = pushed {
+        // Hand the stashed changes back; a later `--continue` has nothing to restore
+        if let Some(stash) = autostash {
+            restore_autostash(repo, stash)?;
+            if let Some(mut pending) = PendingMerge::load(repo, current_branch) {
+                pending.autostash = None;
+                pending.save(repo, current_branch)?;
+            }
+        }
+        return Err(e);
+    }
+    // Nothing left for `wt merge --continue` to finish
+    PendingMerge::clear(repo, current_branch)?;
+    if !on_target && let Ok(sha) = repo.run_command(&["rev-parse", "HEAD"]) {
+        worktrunk::journal::record(
+            repo,
+            worktrunk::journal::Action::Merged {
+                branch: current_branch.to_string(),
+                target: target_branch.to_string(),
+                sha: sha.trim().to_string(),
+            },
+        );
+    }
+
+    // The target has moved, so the stashed changes can come back. Removing the
+    // worktree would then discard them, so it stays.
+    let restored = match autostash {
+        Some(stash) => restore_autostash(repo, stash)?,
+        None => false,
+    };
+    let remove_effective = remove_effective && !restored;
+
+    // Destination: prefer the target branch's worktree; fall back to the primary when absent
+    let destination_path = match &target_worktree_path {
+        Some(path) => path.clone(),
+        None => repo
+            .primary_worktree()?
+            .map_or_else(|| worktrees[0].path.clone(), |wt| wt.path),
+    };
 
     // Finish worktree unless --no-remove was specified
     if remove_effective {
         // STEP 1: Check for uncommitted changes before attempting cleanup
         // This prevents showing "Cleaning up worktree..." before failing
-        repo.ensure_clean_working_tree("remove worktree after merge", Some(&current_branch))?;
+        repo.ensure_clean_working_tree("remove worktree after merge", Some(current_branch))?;
 
         // STEP 2: Remove worktree via shared remove output handler so final message matches wt remove
         let worktree_root = repo.worktree_root()?.to_path_buf();
         // After a successful merge, compute integration reason from main_path
         let main_repo = worktrunk::git::Repository::at(&destination_path);
-        let effective_target = main_repo.effective_integration_target(&target_branch);
+        let effective_target = main_repo.effective_integration_target(target_branch);
         let mut provider =
-            worktrunk::git::LazyGitIntegration::new(&main_repo, &current_branch, &effective_target);
+            worktrunk::git::LazyGitIntegration::new(&main_repo, current_branch, &effective_target);
         let integration_reason = worktrunk::git::check_integration(&mut provider);
         let remove_result = RemoveResult::RemovedWorktree {
             main_path: destination_path.clone(),
             worktree_path: worktree_root,
             changed_directory: true,
-            branch_name: Some(current_branch.clone()),
+            branch_name: Some(current_branch.to_string()),
             deletion_mode: BranchDeletionMode::SafeDelete,
-            target_branch: Some(target_branch.clone()),
+            target_branch: Some(target_branch.to_string()),
             integration_reason,
             // Don't force removal - if worktree has untracked files added after
             // commit, removal will fail and user can run `wt remove --force`
@@ -262,6 +690,8 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
             PreserveReason::IsMainWorktree
         } else if on_target {
             PreserveReason::AlreadyOnTarget
+        } else if restored && remove {
+            PreserveReason::Autostashed
         } else {
             PreserveReason::NoRemoveFlag
         };
@@ -277,7 +707,7 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         let ctx = CommandContext::new(
             &destination_repo,
             config,
-            Some(&current_branch),
+            Some(current_branch),
             &destination_path,
             &destination_repo_root,
             yes,
@@ -290,18 +720,66 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         } else {
             None
         };
-        execute_post_merge_commands(&ctx, &target_branch, None, display_path)?;
+        execute_post_merge_commands(&ctx, target_branch, None, display_path)?;
     }
 
     Ok(())
 }
 
+/// Report what `wt merge` would bring onto the target, and where it would
+/// conflict, without committing, running hooks, or moving any branch
+fn handle_merge_dry_run(
+    repo: &Repository,
+    current_branch: &str,
+    target_branch: &str,
+    include_uncommitted: bool,
+) -> anyhow::Result<()> {
+    let commits = repo.count_commits(target_branch, "HEAD")?;
+    // `stash create` snapshots uncommitted changes as a commit without
+    // touching the stash or the working tree
+    let snapshot = if include_uncommitted && repo.is_dirty()? {
+        Some(repo.run_command(&["stash", "create"])?.trim().to_string())
+    } else {
+        None
+    };
+    let head = snapshot.as_deref().filter(|sha| !sha.is_empty());
+
+    let uncommitted = if head.is_some() {
+        " and uncommitted changes"
+    } else {
+        ""
+    };
+    let commits = match commits {
+        1 => "1 commit".to_string(),
+        n => format!("{n} commits"),
+    };
+    crate::output::print(info_message(cformat!(
+        "Would merge {commits}{uncommitted} from <bold>{current_branch}</> into <bold>{target_branch}</>"
+    )))?;
+
+    let conflicts = repo.merge_conflicts(target_branch, head.unwrap_or("HEAD"))?;
+    if conflicts.is_empty() {
+        crate::output::print(success_message(cformat!(
+            "No conflicts with <bold>{target_branch}</>"
+        )))?;
+    } else {
+        crate::output::print(warning_message(cformat!(
+            "Would conflict with <bold>{target_branch}</> in {} file{}:",
+            conflicts.len(),
+            if conflicts.len() == 1 { "" } else { "s" }
+        )))?;
+        crate::output::print(format_with_gutter(&conflicts.join("\n"), None))?;
+    }
+    Ok(())
+}
+
 /// Handle output for merge summary using global output context
 fn handle_merge_summary_output(reason: PreserveReason) -> anyhow::Result<()> {
     let message = match reason {
         PreserveReason::IsMainWorktree => "Worktree preserved (main worktree)",
         PreserveReason::AlreadyOnTarget => "Worktree preserved (already on target branch)",
         PreserveReason::NoRemoveFlag => "Worktree preserved (--no-remove)",
+        PreserveReason::Autostashed => "Worktree preserved (holds the autostashed changes)",
     };
     crate::output::print(info_message(message))?;
     crate::output::flush()?;
@@ -331,6 +809,33 @@ pub fn run_pre_merge_commands(
     .map_err(worktrunk::git::add_hook_skip_hint)
 }
 
+/// Run pre-push commands sequentially (blocking, fail-fast)
+///
+/// Runs user hooks first, then project hooks, before the target branch is
+/// fast-forwarded by `wt step push` or `wt merge`.
+/// Approval is handled at the gate (command entry point).
+pub fn run_pre_push_commands(
+    ctx: &CommandContext,
+    target_branch: &str,
+    name_filter: Option<&str>,
+) -> anyhow::Result<()> {
+    let project_config = ctx.repo.load_project_config()?;
+
+    run_hook_with_filter(
+        ctx,
+        ctx.config.hooks.pre_push.as_ref(),
+        project_config
+            .as_ref()
+            .and_then(|c| c.hooks.pre_push.as_ref()),
+        HookType::PrePush,
+        &[("target", target_branch)],
+        HookFailureStrategy::FailFast,
+        name_filter,
+        None, // No path display - running in expected directory
+    )
+    .map_err(worktrunk::git::add_hook_skip_hint)
+}
+
 /// Execute post-merge commands sequentially in the target worktree (blocking)
 ///
 /// Runs user hooks first, then project hooks.
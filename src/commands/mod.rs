@@ -1,3 +1,4 @@
+mod clone;
 pub mod command_approval;
 pub mod command_executor;
 pub mod commit;
@@ -5,41 +6,75 @@ pub mod config;
 pub mod configure_shell;
 pub mod context;
 mod for_each;
+mod history;
+pub mod hook_status;
 mod hooks;
 pub mod init;
+mod issue;
+pub mod limits;
+mod link;
 pub mod list;
+pub mod log;
 pub mod merge;
+pub mod pr;
 pub mod process;
 pub mod project_config;
+mod prompt;
+mod repair;
+pub mod repo;
 pub mod repository_ext;
 #[cfg(unix)]
 pub mod select;
 pub mod standalone;
 pub mod statusline;
+mod sync;
+mod tidy;
+mod tmux;
+pub mod trust;
+mod workspace;
 pub mod worktree;
 
-pub use command_approval::approve_hooks;
+pub use clone::handle_clone;
+pub use command_approval::{approve_hooks, set_assume_yes};
 pub use config::{
-    handle_config_create, handle_config_show, handle_state_clear, handle_state_clear_all,
+    handle_config_create, handle_config_migrate, handle_config_set, handle_config_show,
+    handle_config_validate, handle_note, handle_state_clear, handle_state_clear_all,
     handle_state_get, handle_state_set, handle_state_show,
 };
 pub use configure_shell::{
     ConfigAction, handle_configure_shell, handle_show_theme, handle_unconfigure_shell,
 };
 pub use for_each::step_for_each;
+pub use history::{handle_history, handle_undo};
+pub use hooks::set_hooks_enabled;
 pub use init::handle_init;
-pub use list::handle_list;
-pub use merge::{MergeOptions, execute_pre_remove_commands, handle_merge};
+pub use issue::issue_branch;
+pub use list::{handle_list, handle_list_all_repos, handle_list_legend};
+pub use log::handle_log;
+pub use merge::{
+    MergeOptions, execute_pre_remove_commands, handle_merge, handle_merge_abort,
+    handle_merge_continue,
+};
+pub use pr::{fetch_pr_branch, handle_pr};
+pub use prompt::handle_prompt;
+pub use repair::handle_repair;
+pub use repo::{handle_repo_add, handle_repo_list, handle_repo_remove};
 #[cfg(unix)]
-pub use select::handle_select;
+pub use select::{handle_open_diff, handle_select};
 pub use standalone::{
     RebaseResult, SquashResult, add_approvals, clear_approvals, handle_hook_show, handle_rebase,
-    handle_squash, run_hook, step_commit, step_show_squash_prompt,
+    handle_squash, run_hook, step_commit, step_push, step_show_squash_prompt,
 };
+pub use sync::{handle_sync, handle_sync_upstream};
+pub use tidy::handle_tidy;
+pub use tmux::handle_tmux;
+pub use trust::{handle_trust_list, handle_trust_revoke};
+pub use workspace::handle_workspace;
 pub use worktree::{
-    ResolutionContext, compute_worktree_path, handle_remove, handle_remove_by_path,
-    handle_remove_current, handle_switch, is_worktree_at_expected_path_with, resolve_worktree_arg,
-    worktree_display_name,
+    ResolutionContext, compute_worktree_path, confirm_bulk_removal, handle_remove,
+    handle_remove_by_path, handle_remove_current, handle_switch, is_worktree_at_expected_path_with,
+    merged_worktree_branches, pattern_worktree_branches, print_bulk_removal, resolve_worktree_arg,
+    uses_lfs, worktree_display_name,
 };
 
 // Re-export Shell from the canonical location
@@ -0,0 +1,273 @@
+//! Pull request summary for a branch.
+//!
+//! `wt pr` looks up the branch's pull request with `gh` and reports its title,
+//! state, and reviews. Check status comes from the same detection (and cache)
+//! that powers the CI column in `wt list`, so the two never disagree.
+
+use anyhow::Context;
+use color_print::cformat;
+use serde::Deserialize;
+use worktrunk::config::{CiConfig, CiProvider, WorktrunkConfig};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::shell_exec::run;
+
+use super::list::ci_status::{
+    CiPlatform, MAX_PRS_TO_FETCH, PrStatus, configure_non_interactive, get_origin_owner,
+    get_platform_for_repo, provider_command,
+};
+
+/// PR details from `gh pr list --json ...`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PullRequest {
+    number: u64,
+    title: String,
+    /// "OPEN", "CLOSED", or "MERGED"
+    state: String,
+    #[serde(default)]
+    is_draft: bool,
+    /// "APPROVED", "CHANGES_REQUESTED", "REVIEW_REQUIRED", or empty
+    review_decision: Option<String>,
+    #[serde(default)]
+    latest_reviews: Vec<Review>,
+    url: String,
+    head_repository_owner: Option<Login>,
+}
+
+/// PR head details from `gh pr view <number> --json ...`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PullRequestHead {
+    head_ref_name: String,
+    is_cross_repository: bool,
+    head_repository_owner: Option<Login>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Review {
+    author: Option<Login>,
+    /// "APPROVED", "CHANGES_REQUESTED", "COMMENTED", "DISMISSED", "PENDING"
+    state: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Login {
+    login: String,
+}
+
+/// Lowercase a GitHub enum value for display (`CHANGES_REQUESTED` → `changes requested`)
+fn humanize(value: &str) -> String {
+    value.to_ascii_lowercase().replace('_', " ")
+}
+
+/// Run `gh` non-interactively from the repository root, returning stdout.
+fn run_gh(repo_root: &str, ci: &CiConfig, args: &[&str]) -> anyhow::Result<Vec<u8>> {
+    let mut cmd = provider_command("gh", ci);
+    cmd.args(args);
+    configure_non_interactive(&mut cmd);
+    cmd.current_dir(repo_root);
+
+    let command = format!("gh {}", args[..2].join(" "));
+    let output = run(&mut cmd, None).map_err(|e| GitError::ProviderCommandFailed {
+        command: command.clone(),
+        error: e.to_string(),
+    })?;
+    if !output.status.success() {
+        return Err(GitError::ProviderCommandFailed {
+            command,
+            error: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        }
+        .into());
+    }
+    Ok(output.stdout)
+}
+
+/// Find the PR whose head is `branch` on our origin, preferring the newest.
+fn find_pull_request(
+    branch: &str,
+    repo_root: &str,
+    ci: &CiConfig,
+) -> anyhow::Result<Option<PullRequest>> {
+    let stdout = run_gh(
+        repo_root,
+        ci,
+        &[
+            "pr",
+            "list",
+            "--head",
+            branch,
+            "--state",
+            "all",
+            "--limit",
+            &MAX_PRS_TO_FETCH.to_string(),
+            "--json",
+            "number,title,state,isDraft,reviewDecision,latestReviews,url,headRepositoryOwner",
+        ],
+    )?;
+    let prs: Vec<PullRequest> = serde_json::from_slice(&stdout)?;
+
+    // Same fork filtering as CI detection: a missing owner is a potential match
+    let origin_owner = get_origin_owner(repo_root);
+    Ok(prs
+        .into_iter()
+        .find(|pr| match (&origin_owner, &pr.head_repository_owner) {
+            (Some(owner), Some(head)) => head.login.eq_ignore_ascii_case(owner),
+            _ => true,
+        }))
+}
+
+/// Ensure the repository is hosted on GitHub (or unknown), since `wt pr` uses `gh`.
+///
+/// `[ci] provider` takes precedence over the remote URL.
+fn require_github(repo_root: &str, ci: &CiConfig) -> anyhow::Result<()> {
+    let github = match ci.provider {
+        Some(provider) => provider == CiProvider::GitHub,
+        None => get_platform_for_repo(repo_root) != Some(CiPlatform::GitLab),
+    };
+    if !github {
+        return Err(GitError::Other {
+            message: "wt pr supports GitHub repositories only".into(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Handle `wt pr [branch] [--open]`
+pub fn handle_pr(branch: Option<&str>, open: bool) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let branch = match branch {
+        Some(name) => repo.resolve_worktree_name(name)?,
+        None => repo.require_current_branch("show pull request")?,
+    };
+    let repo_path = repo.worktree_base()?;
+    let repo_root = repo_path.to_string_lossy().into_owned();
+    let ci = WorktrunkConfig::load()
+        .context("Failed to load config")?
+        .ci();
+    require_github(&repo_root, &ci)?;
+
+    let Some(pr) = find_pull_request(&branch, &repo_root, &ci)? else {
+        return Err(GitError::Other {
+            message: cformat!("No pull request found for <bold>{branch}</>"),
+        }
+        .into());
+    };
+
+    if open {
+        run_gh(
+            &repo_root,
+            &ci,
+            &["pr", "view", &pr.number.to_string(), "--web"],
+        )?;
+        crate::output::print(worktrunk::styling::success_message(cformat!(
+            "Opened <bold>#{}</> in browser",
+            pr.number
+        )))?;
+        return Ok(());
+    }
+
+    let state = match (pr.state.as_str(), pr.is_draft) {
+        ("OPEN", true) => "draft".to_string(),
+        (state, _) => humanize(state),
+    };
+
+    let reviewers: Vec<String> = pr
+        .latest_reviews
+        .iter()
+        .filter_map(|review| {
+            let author = review.author.as_ref()?;
+            Some(cformat!(
+                "<bold>{}</> {}",
+                author.login,
+                humanize(&review.state)
+            ))
+        })
+        .collect();
+    let decision = pr
+        .review_decision
+        .as_deref()
+        .filter(|d| !d.is_empty())
+        .map(humanize);
+    let reviews = match (decision, reviewers.is_empty()) {
+        (Some(decision), true) => decision,
+        (Some(decision), false) => format!("{decision} ({})", reviewers.join(", ")),
+        (None, false) => reviewers.join(", "),
+        (None, true) => cformat!("<dim>none</>"),
+    };
+
+    // Check status reuses the cached CI detection from `wt list --full`
+    let checks = repo
+        .run_command(&["rev-parse", &branch])
+        .ok()
+        .and_then(|head| {
+            let has_upstream = repo.upstream_branch(&branch).ok().flatten().is_some();
+            PrStatus::detect(&branch, head.trim(), &repo_path, has_upstream, &ci)
+        })
+        .map(|status| {
+            let label: &'static str = status.ci_status.into();
+            let stale = if status.is_stale {
+                cformat!(" <dim>(local commits not pushed)</>")
+            } else {
+                String::new()
+            };
+            format!("{} {label}{stale}", status.format_indicator())
+        })
+        .unwrap_or_else(|| cformat!("<dim>none</>"));
+
+    let lines = [
+        cformat!("<bold>#{}</> {}", pr.number, pr.title),
+        cformat!("  <dim>State</>    {state}"),
+        cformat!("  <dim>Reviews</>  {reviews}"),
+        cformat!("  <dim>Checks</>   {checks}"),
+        cformat!("  <dim>URL</>      {}", pr.url),
+    ];
+    crate::output::stdout(lines.join("\n"))?;
+    Ok(())
+}
+
+/// Fetch a pull request's head into a local branch, returning the branch name.
+///
+/// Same-repository PRs use the head branch name; PRs from forks are namespaced
+/// by owner (`owner/branch`) so they can't collide with local branches. An
+/// existing local branch is reused rather than overwritten.
+pub fn fetch_pr_branch(number: u32) -> anyhow::Result<String> {
+    let repo = Repository::current();
+    let repo_root = repo.worktree_base()?.to_string_lossy().into_owned();
+    let ci = WorktrunkConfig::load()
+        .context("Failed to load config")?
+        .ci();
+    require_github(&repo_root, &ci)?;
+
+    let stdout = run_gh(
+        &repo_root,
+        &ci,
+        &[
+            "pr",
+            "view",
+            &number.to_string(),
+            "--json",
+            "headRefName,isCrossRepository,headRepositoryOwner",
+        ],
+    )?;
+    let head: PullRequestHead = serde_json::from_slice(&stdout)?;
+
+    let branch = match (&head.head_repository_owner, head.is_cross_repository) {
+        (Some(owner), true) => format!("{}/{}", owner.login, head.head_ref_name),
+        _ => head.head_ref_name,
+    };
+
+    if repo.local_branch_exists(&branch)? {
+        return Ok(branch);
+    }
+
+    let remote = repo.primary_remote()?;
+    crate::output::with_progress(
+        worktrunk::styling::progress_message(cformat!(
+            "Fetching <bold>#{number}</> into <bold>{branch}</>..."
+        )),
+        || repo.run_command(&["fetch", remote, &format!("pull/{number}/head:{branch}")]),
+    )??;
+
+    Ok(branch)
+}
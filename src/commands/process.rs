@@ -1,6 +1,6 @@
 use anyhow::Context;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 #[cfg(unix)]
 use std::process::Command;
 use std::process::Stdio;
@@ -37,7 +37,7 @@ fn sanitize_for_filename(s: &str) -> String {
 
 /// Get the separator needed before closing brace in POSIX shell command grouping.
 /// Returns empty string if command already ends with newline or semicolon.
-fn posix_command_separator(command: &str) -> &'static str {
+pub(crate) fn posix_command_separator(command: &str) -> &'static str {
     if command.ends_with('\n') || command.ends_with(';') {
         ""
     } else {
@@ -45,6 +45,52 @@ fn posix_command_separator(command: &str) -> &'static str {
     }
 }
 
+/// Wrap a background command so a desktop notification reports how it went,
+/// if it ran for at least `after` (`[notify]`). Unchanged where notifications
+/// aren't available.
+pub(crate) fn notify_on_completion(
+    command: &str,
+    after: std::time::Duration,
+    title: &str,
+    label: &str,
+) -> String {
+    let (Some(succeeded), Some(failed)) = (
+        worktrunk::notify::command(title, &format!("{label} finished")),
+        worktrunk::notify::command(title, &format!("{label} failed")),
+    ) else {
+        return command.to_string();
+    };
+    format!(
+        "wt_start=$(date +%s); {{ {command}{} }}; wt_status=$?; \
+if [ $(( $(date +%s) - wt_start )) -ge {} ]; then \
+if [ $wt_status -eq 0 ]; then {succeeded}; else {failed}; fi; fi; exit $wt_status",
+        posix_command_separator(command),
+        after.as_secs()
+    )
+}
+
+/// Path of the log file for a background operation, creating its directory
+///
+/// Logs are named `{branch}-{name}.log` (e.g., "feature-post-start-npm.log",
+/// "bugfix-remove.log") with no timestamp, so each run overwrites the last.
+pub fn log_path(repo: &Repository, branch: &str, name: &str) -> anyhow::Result<PathBuf> {
+    // Get the git common directory (shared across all worktrees)
+    let git_common_dir = repo.git_common_dir()?;
+
+    // Create log directory in the common git directory
+    let log_dir = git_common_dir.join("wt-logs");
+    fs::create_dir_all(&log_dir).with_context(|| {
+        format!(
+            "Failed to create log directory {}",
+            format_path_for_display(&log_dir)
+        )
+    })?;
+
+    let safe_branch = sanitize_for_filename(branch);
+    let safe_name = sanitize_for_filename(name);
+    Ok(log_dir.join(format!("{}-{}.log", safe_branch, safe_name)))
+}
+
 /// Spawn a detached background process with output redirected to a log file
 ///
 /// The process will be fully detached from the parent:
@@ -70,24 +116,9 @@ pub fn spawn_detached(
     branch: &str,
     name: &str,
     context_json: Option<&str>,
-) -> anyhow::Result<std::path::PathBuf> {
-    // Get the git common directory (shared across all worktrees)
-    let git_common_dir = repo.git_common_dir()?;
-
-    // Create log directory in the common git directory
-    let log_dir = git_common_dir.join("wt-logs");
-    fs::create_dir_all(&log_dir).with_context(|| {
-        format!(
-            "Failed to create log directory {}",
-            format_path_for_display(&log_dir)
-        )
-    })?;
-
-    // Generate log filename (no timestamp - overwrites on each run)
-    // Format: {branch}-{name}.log (e.g., "feature-post-start-npm.log", "bugfix-remove.log")
-    let safe_branch = sanitize_for_filename(branch);
-    let safe_name = sanitize_for_filename(name);
-    let log_path = log_dir.join(format!("{}-{}.log", safe_branch, safe_name));
+    env: &[(&str, String)],
+) -> anyhow::Result<PathBuf> {
+    let log_path = log_path(repo, branch, name)?;
 
     // Create log file
     let log_file = fs::File::create(&log_path).with_context(|| {
@@ -99,12 +130,12 @@ pub fn spawn_detached(
 
     #[cfg(unix)]
     {
-        spawn_detached_unix(worktree_path, command, log_file, context_json, name)?;
+        spawn_detached_unix(worktree_path, command, log_file, context_json, env, name)?;
     }
 
     #[cfg(windows)]
     {
-        spawn_detached_windows(worktree_path, command, log_file, context_json, name)?;
+        spawn_detached_windows(worktree_path, command, log_file, context_json, env, name)?;
     }
 
     Ok(log_path)
@@ -116,6 +147,7 @@ fn spawn_detached_unix(
     command: &str,
     log_file: fs::File,
     context_json: Option<&str>,
+    env: &[(&str, String)],
     name: &str,
 ) -> anyhow::Result<()> {
     use std::os::unix::process::CommandExt;
@@ -158,6 +190,7 @@ fn spawn_detached_unix(
         .stderr(Stdio::from(log_file))
         // Prevent hooks from writing to the directive file
         .env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR)
+        .envs(env.iter().map(|(name, value)| (name, value)))
         .process_group(0) // New process group, not in PTY's foreground group
         .spawn()
         .context("Failed to spawn detached process")?;
@@ -176,6 +209,7 @@ fn spawn_detached_windows(
     command: &str,
     log_file: fs::File,
     context_json: Option<&str>,
+    env: &[(&str, String)],
     name: &str,
 ) -> anyhow::Result<()> {
     use std::os::windows::process::CommandExt;
@@ -200,7 +234,7 @@ fn spawn_detached_windows(
                 // Use printf to pipe JSON to the command's stdin (same as Unix)
                 format!(
                     "printf '%s' {} | {{ {}{} }}",
-                    shell_escape::escape(json.into()),
+                    shell.quote(json),
                     command,
                     posix_command_separator(command)
                 )
@@ -236,6 +270,7 @@ fn spawn_detached_windows(
         .stderr(Stdio::from(log_file))
         // Prevent hooks from writing to the directive file
         .env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR)
+        .envs(env.iter().map(|(name, value)| (name, value)))
         .creation_flags(CREATE_NEW_PROCESS_GROUP | DETACHED_PROCESS)
         .spawn()
         .context("Failed to spawn detached process")?;
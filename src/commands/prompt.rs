@@ -0,0 +1,112 @@
+//! `wt prompt`: a compact status segment for shell prompts.
+//!
+//! Prompts run on every command, so this does the least work that answers
+//! "where am I and is it clean": the worktree's name, commits ahead of and
+//! behind the default branch, and whether there are uncommitted changes. It
+//! never lists worktrees, never detects the default branch (only reads
+//! worktrunk's cache of it), and prints nothing — successfully — outside a
+//! worktree, so a prompt module can call it unconditionally.
+//!
+//! `wt list statusline` is the richer alternative, at the cost of a full
+//! `wt list` row.
+
+use serde::Serialize;
+use worktrunk::git::Repository;
+
+use crate::cli::OutputFormat;
+use crate::output;
+
+/// What `wt prompt` reports about the current worktree
+#[derive(Debug, Serialize)]
+struct PromptStatus {
+    /// Branch name, or the worktree's directory name on a detached HEAD
+    name: String,
+    /// Branch name (null on a detached HEAD)
+    branch: Option<String>,
+    path: String,
+    /// Commits ahead of the default branch (absent when it isn't known)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ahead: Option<usize>,
+    /// Commits behind the default branch (absent when it isn't known)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    behind: Option<usize>,
+    dirty: bool,
+}
+
+impl PromptStatus {
+    /// Gather the status, or `None` when not inside a worktree
+    fn collect(repo: &Repository) -> Option<Self> {
+        let root = repo.worktree_root().ok()?.to_path_buf();
+        let branch = repo.current_branch().ok()?.map(str::to_string);
+        let name = branch.clone().unwrap_or_else(|| {
+            root.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        });
+        let (ahead, behind) = repo
+            .cached_default_branch()
+            .and_then(|default_branch| repo.ahead_behind(&default_branch, "HEAD").ok())
+            .unzip();
+        Some(Self {
+            name,
+            branch,
+            path: root.display().to_string(),
+            ahead,
+            behind,
+            dirty: repo.is_dirty().unwrap_or(false),
+        })
+    }
+
+    /// `name ↑ahead↓behind *`, leaving out whatever is zero or clean
+    fn segment(&self) -> String {
+        let mut segment = self.name.clone();
+        let mut commits = String::new();
+        if let Some(ahead) = self.ahead.filter(|&n| n > 0) {
+            commits.push_str(&format!("↑{ahead}"));
+        }
+        if let Some(behind) = self.behind.filter(|&n| n > 0) {
+            commits.push_str(&format!("↓{behind}"));
+        }
+        if !commits.is_empty() {
+            segment.push(' ');
+            segment.push_str(&commits);
+        }
+        if self.dirty {
+            segment.push_str(" *");
+        }
+        segment
+    }
+}
+
+/// Handle `wt prompt [--format json]`
+pub fn handle_prompt(format: OutputFormat) -> anyhow::Result<()> {
+    let Some(status) = PromptStatus::collect(&Repository::current()) else {
+        return Ok(());
+    };
+    match format {
+        OutputFormat::Table => output::stdout(status.segment())?,
+        OutputFormat::Json => output::stdout(serde_json::to_string(&status)?)?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment() {
+        let status = |ahead, behind, dirty| PromptStatus {
+            name: "feature".into(),
+            branch: Some("feature".into()),
+            path: "/repo.feature".into(),
+            ahead,
+            behind,
+            dirty,
+        };
+        assert_eq!(status(Some(0), Some(0), false).segment(), "feature");
+        assert_eq!(status(Some(2), Some(0), false).segment(), "feature ↑2");
+        assert_eq!(status(Some(2), Some(1), true).segment(), "feature ↑2↓1 *");
+        assert_eq!(status(None, None, true).segment(), "feature *");
+    }
+}
@@ -0,0 +1,69 @@
+//! `wt repair`: relink worktrees whose `.git` file no longer points at their
+//! admin directory.
+//!
+//! Each linked worktree's `.git` file names a directory under the main
+//! repository's `.git/worktrees/`. Copying or restoring a worktree from
+//! elsewhere leaves that link dangling, and git then fails on every command
+//! inside it. `git worktree repair` rewrites the links; this finds the broken
+//! worktrees and reports what changed.
+//!
+//! Moving the main repository breaks every worktree's link at once, and from
+//! inside one of them git can't find the repository to repair it; `--from`
+//! names the new location.
+
+use std::path::Path;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository, working_dir};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{info_message, success_message};
+
+/// Handle `wt repair [--from <path>]`
+///
+/// `from` is the repository's new location, for when it has moved: the
+/// current worktree can't find it, so git has to run there instead.
+pub fn handle_repair(from: Option<&Path>) -> anyhow::Result<()> {
+    let repo = match from {
+        Some(path) => {
+            let dir = working_dir()
+                .context("Failed to get current directory")?
+                .join(path);
+            if !dir.is_dir() {
+                return Err(GitError::Other {
+                    message: cformat!(
+                        "Directory not found: <bold>{}</>",
+                        format_path_for_display(path)
+                    ),
+                }
+                .into());
+            }
+            Repository::at(dir)
+        }
+        None => Repository::current(),
+    };
+    let worktrees = repo.list_worktrees()?;
+    let broken: Vec<_> = worktrees
+        .iter()
+        .filter(|wt| wt.broken_gitdir().is_some())
+        .collect();
+    if broken.is_empty() {
+        crate::output::print(info_message("No broken worktree links"))?;
+        return Ok(());
+    }
+
+    // Without paths, git repairs every worktree it has an admin directory for;
+    // naming a broken worktree makes it try (and fail) to read its `.git` file
+    repo.run_command(&["worktree", "repair"])?;
+
+    let names: Vec<_> = broken
+        .iter()
+        .map(|wt| cformat!("<bold>{}</>", wt.branch.as_deref().unwrap_or(wt.dir_name())))
+        .collect();
+    let plural = if names.len() == 1 { "" } else { "s" };
+    crate::output::print(success_message(format!(
+        "Repaired worktree link{plural} for {}",
+        names.join(", ")
+    )))?;
+    Ok(())
+}
@@ -0,0 +1,193 @@
+//! Registry of known repositories.
+//!
+//! `wt repo add` records where a repository lives under its project identifier
+//! (e.g. `github.com/owner/repo`), in the same `[projects]` table of the user
+//! config that holds approved commands. The global `--repo <name>` flag then
+//! targets a registered repository from any directory.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::{GitError, Repository, working_dir};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{hint_message, info_message, success_message};
+
+/// Find a registered repository by identifier, or by a unique trailing part of
+/// one (`repo` or `owner/repo` for `github.com/owner/repo`).
+fn find_registered<'a>(
+    config: &'a WorktrunkConfig,
+    name: &str,
+) -> anyhow::Result<(&'a str, &'a Path)> {
+    let repos: Vec<_> = config.registered_repos().collect();
+    if let Some(&exact) = repos.iter().find(|(id, _)| *id == name) {
+        return Ok(exact);
+    }
+
+    let suffix = format!("/{name}");
+    let matches: Vec<_> = repos
+        .into_iter()
+        .filter(|(id, _)| id.ends_with(&suffix))
+        .collect();
+    match matches.as_slice() {
+        [single] => Ok(*single),
+        [] => Err(GitError::Other {
+            message: cformat!("No registered repository matches <bold>{name}</>"),
+        }
+        .into()),
+        _ => {
+            let ids: Vec<_> = matches.iter().map(|(id, _)| *id).collect();
+            Err(GitError::Other {
+                message: cformat!(
+                    "<bold>{name}</> matches several repositories: {}",
+                    ids.join(", ")
+                ),
+            }
+            .into())
+        }
+    }
+}
+
+/// Resolve `--repo <name>` to the registered repository's path.
+pub fn resolve_repo(name: &str) -> anyhow::Result<PathBuf> {
+    let config = WorktrunkConfig::load().context("Failed to load config")?;
+    let (_, path) = find_registered(&config, name)?;
+    Ok(path.to_path_buf())
+}
+
+/// Handle `wt repo add [path]`
+pub fn handle_repo_add(path: Option<&Path>) -> anyhow::Result<()> {
+    let path = match path {
+        Some(path) => path.to_path_buf(),
+        None => working_dir().context("Failed to get current directory")?,
+    };
+    let repo = Repository::at(&path);
+    let root = repo.worktree_base()?;
+    let project = repo.project_identifier()?.to_string();
+
+    let mut config = WorktrunkConfig::load().context("Failed to load config")?;
+    let existing = config
+        .projects
+        .get(&project)
+        .and_then(|p| p.path.as_deref());
+    if existing == Some(root.as_path()) {
+        crate::output::print(info_message(cformat!(
+            "<bold>{project}</> is already registered"
+        )))?;
+        return Ok(());
+    }
+
+    config
+        .register_repo(project.clone(), root.clone())
+        .context("Failed to save config")?;
+    crate::output::print(success_message(cformat!(
+        "Registered <bold>{project}</> at <bold>{}</>",
+        format_path_for_display(&root)
+    )))?;
+    Ok(())
+}
+
+/// Handle `wt repo list`
+pub fn handle_repo_list() -> anyhow::Result<()> {
+    let config = WorktrunkConfig::load().context("Failed to load config")?;
+    let repos: Vec<_> = config.registered_repos().collect();
+    if repos.is_empty() {
+        crate::output::print(info_message("No repositories registered"))?;
+        crate::output::print(hint_message(cformat!(
+            "To register the current repository, run <bright-black>wt repo add</>"
+        )))?;
+        return Ok(());
+    }
+
+    let width = repos.iter().map(|(id, _)| id.len()).max().unwrap_or(0);
+    let lines: Vec<String> = repos
+        .iter()
+        .map(|(id, path)| {
+            let missing = if path.exists() {
+                String::new()
+            } else {
+                cformat!(" <dim>(missing)</>")
+            };
+            cformat!(
+                "<bold>{id:width$}</>  {}{missing}",
+                format_path_for_display(path)
+            )
+        })
+        .collect();
+    crate::output::stdout(lines.join("\n"))?;
+    Ok(())
+}
+
+/// Handle `wt repo remove <name>`
+pub fn handle_repo_remove(name: &str) -> anyhow::Result<()> {
+    let mut config = WorktrunkConfig::load().context("Failed to load config")?;
+    let project = find_registered(&config, name)?.0.to_string();
+
+    config
+        .unregister_repo(&project)
+        .context("Failed to save config")?;
+    crate::output::print(success_message(cformat!(
+        "Removed <bold>{project}</> from registered repositories"
+    )))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use worktrunk::config::UserProjectConfig;
+
+    fn config_with(repos: &[&str]) -> WorktrunkConfig {
+        let mut config = WorktrunkConfig::default();
+        for id in repos {
+            config.projects.insert(
+                id.to_string(),
+                UserProjectConfig {
+                    approved_commands: vec![],
+                    path: Some(PathBuf::from(format!("/code/{id}"))),
+                },
+            );
+        }
+        config
+    }
+
+    #[test]
+    fn test_find_registered_by_suffix() {
+        let config = config_with(&["github.com/max/worktrunk", "github.com/max/other"]);
+        assert_eq!(
+            find_registered(&config, "worktrunk").unwrap().0,
+            "github.com/max/worktrunk"
+        );
+        assert_eq!(
+            find_registered(&config, "max/other").unwrap().0,
+            "github.com/max/other"
+        );
+        assert_eq!(
+            find_registered(&config, "github.com/max/other").unwrap().0,
+            "github.com/max/other"
+        );
+        // Suffix matches whole path components only
+        assert!(find_registered(&config, "trunk").is_err());
+    }
+
+    #[test]
+    fn test_find_registered_ambiguous() {
+        let config = config_with(&["github.com/a/app", "gitlab.com/b/app"]);
+        let err = find_registered(&config, "app").unwrap_err().to_string();
+        assert!(err.contains("github.com/a/app, gitlab.com/b/app"), "{err}");
+    }
+
+    #[test]
+    fn test_find_registered_ignores_unregistered_projects() {
+        let mut config = config_with(&[]);
+        config.projects.insert(
+            "github.com/max/approved-only".to_string(),
+            UserProjectConfig {
+                approved_commands: vec!["npm ci".to_string()],
+                path: None,
+            },
+        );
+        assert!(find_registered(&config, "approved-only").is_err());
+    }
+}
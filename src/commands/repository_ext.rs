@@ -81,7 +81,6 @@ impl RepositoryCliExt for Repository {
     ) -> anyhow::Result<RemoveResult> {
         let current_path = self.worktree_root()?.to_path_buf();
         let worktrees = self.list_worktrees()?;
-        let main_worktree_path = worktrees[0].path.clone();
 
         // Resolve target to worktree path and branch
         let (worktree_path, branch_name, is_current) = match target {
@@ -148,7 +147,17 @@ impl RepositoryCliExt for Repository {
 
         // Compute main_path and changed_directory based on whether we're removing current
         let (main_path, changed_directory) = if is_current {
-            (main_worktree_path, true)
+            // In a bare repo the primary worktree may be the one being removed;
+            // fall back to the bare repo directory itself
+            let primary = self
+                .primary_worktree()?
+                .map(|wt| wt.path)
+                .filter(|path| *path != worktree_path);
+            let main_path = match primary {
+                Some(path) => path,
+                None => self.worktree_base()?,
+            };
+            (main_path, true)
         } else {
             (current_path, false)
         };
@@ -270,7 +279,7 @@ impl RepositoryCliExt for Repository {
         let merge_base = self.merge_base("HEAD", target)?;
         let target_sha = self.run_command(&["rev-parse", target])?.trim().to_string();
 
-        if merge_base != target_sha {
+        if merge_base.as_deref() != Some(target_sha.as_str()) {
             return Ok(false); // Target has advanced past merge-base
         }
 
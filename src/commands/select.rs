@@ -2,18 +2,20 @@ use anyhow::Context;
 use color_print::cformat;
 use skim::prelude::*;
 use std::borrow::Cow;
+use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
-use std::sync::{Arc, OnceLock};
+use std::sync::{Arc, OnceLock, PoisonError, RwLock, RwLockReadGuard};
 use std::time::{Duration, Instant};
-use worktrunk::config::WorktrunkConfig;
+use worktrunk::config::{PreviewPosition, SelectConfig, WorktrunkConfig};
 use worktrunk::git::Repository;
 use worktrunk::shell_exec::run;
 
 use super::list::collect;
-use super::list::layout::{DiffDisplayConfig, DiffVariant};
+use super::list::layout::LayoutConfig;
 use super::list::model::ListItem;
+use super::log::{FIELD_DELIM, format_log_output};
 use super::worktree::handle_switch;
 use crate::output::handle_switch_output;
 
@@ -79,7 +81,7 @@ fn pager_needs_paging_disabled(pager_cmd: &str) -> bool {
 /// If the pager takes longer than this, kill it and fall back to raw diff.
 const PAGER_TIMEOUT: Duration = Duration::from_millis(2000);
 
-/// Skim uses this percentage of terminal height.
+/// Skim uses this percentage of terminal height, unless `select.height` says otherwise.
 const SKIM_HEIGHT_PERCENT: usize = 90;
 
 /// Maximum number of list items visible in down layout before scrolling.
@@ -189,11 +191,15 @@ fn run_git_diff_with_pager(git_args: &[&str], pager_cmd: &str) -> Option<String>
 ///
 /// TODO: Consider adding tab 4 "remote±" showing diff vs upstream tracking branch
 /// (unpushed commits). Would align with "Remote⇅" column in `wt list`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
 enum PreviewMode {
+    #[default]
     WorkingTree = 1,
     Log = 2,
     BranchDiff = 3,
+    /// Keybinding overlay (`?` / `ctrl-h`), not a tab
+    Help = 4,
 }
 
 /// Typical terminal character aspect ratio (width/height).
@@ -249,7 +255,8 @@ impl PreviewLayout {
     /// For Right layout: always 50%
     /// For Down layout: dynamically sized based on item count - list gets
     /// up to MAX_VISIBLE_ITEMS lines, preview gets the rest (min 5 lines)
-    fn to_preview_window_spec(self, num_items: usize) -> String {
+    /// of the `height_percent` of the terminal skim uses
+    fn to_preview_window_spec(self, num_items: usize, height_percent: usize) -> String {
         match self {
             Self::Right => "right:50%".to_string(),
             Self::Down => {
@@ -257,7 +264,7 @@ impl PreviewLayout {
                     .map(|(_, terminal_size::Height(h))| h as usize)
                     .unwrap_or(24);
 
-                let available = height * SKIM_HEIGHT_PERCENT / 100;
+                let available = height * height_percent / 100;
                 let list_lines = LIST_CHROME_LINES + num_items.min(MAX_VISIBLE_ITEMS);
                 // Ensure preview doesn't exceed available space while trying to maintain minimum
                 let remaining = available.saturating_sub(list_lines);
@@ -274,14 +281,139 @@ impl PreviewMode {
         match n {
             2 => Self::Log,
             3 => Self::BranchDiff,
+            4 => Self::Help,
             _ => Self::WorkingTree,
         }
     }
+
+    /// Preview tabs, in key order
+    const TABS: [Self; 3] = [Self::WorkingTree, Self::Log, Self::BranchDiff];
+
+    /// Tab label, prefixed with the key that selects it
+    fn tab_label(self) -> &'static str {
+        match self {
+            Self::WorkingTree => "1: HEAD±",
+            Self::Log => "2: log",
+            Self::BranchDiff => "3: main…±",
+            Self::Help => "?: help",
+        }
+    }
+
+    /// What the mode shows, for the help overlay
+    fn description(self) -> &'static str {
+        match self {
+            Self::WorkingTree => "Diff of uncommitted changes",
+            Self::Log => "Recent commits; commits already on the default branch are dimmed",
+            Self::BranchDiff => "Diff of changes since the merge-base with the default branch",
+            Self::Help => "Show this help",
+        }
+    }
+}
+
+/// A selector key binding and what it does
+///
+/// [`key_bindings`] is the single list that both configures skim and renders
+/// the help overlay, so the overlay always matches the keys in effect.
+struct KeyBinding {
+    keys: &'static [&'static str],
+    /// skim action, or `None` for skim's own keys (listed for the overlay only)
+    action: Option<String>,
+    description: &'static str,
+}
+
+/// Key bindings for `wt select`
+fn key_bindings() -> Vec<KeyBinding> {
+    let state_path = PreviewStateData::state_path();
+    let set_mode = |mode: PreviewMode| {
+        Some(format!(
+            "execute-silent(echo {} > {})+refresh-preview",
+            mode as u8,
+            state_path.display()
+        ))
+    };
+    // `{}` is the highlighted item's output: its branch, or path when detached
+    let open_diff = std::env::current_exe().ok().map(|exe| {
+        let quote = |path: &std::path::Path| {
+            let path = path.to_string_lossy();
+            shlex::try_quote(&path)
+                .map(Cow::into_owned)
+                .unwrap_or(path.into_owned())
+        };
+        format!(
+            "execute({} select --open-diff {{}} --preview-state {})",
+            quote(&exe),
+            quote(&state_path)
+        )
+    });
+    // Half-page scroll: skim uses 90% of terminal height, half of that = 45%
+    let half_page = terminal_size::terminal_size()
+        .map(|(_, terminal_size::Height(h))| (h as usize * 45 / 100).max(5))
+        .unwrap_or(10);
+
+    vec![
+        KeyBinding {
+            keys: &["enter"],
+            action: None,
+            description: "Switch to the selected worktree",
+        },
+        KeyBinding {
+            keys: &["esc"],
+            action: None,
+            description: "Cancel",
+        },
+        // Mode switching (1/2/3 keys change preview content)
+        KeyBinding {
+            keys: &["1"],
+            action: set_mode(PreviewMode::WorkingTree),
+            description: "Preview uncommitted changes",
+        },
+        KeyBinding {
+            keys: &["2"],
+            action: set_mode(PreviewMode::Log),
+            description: "Preview the log",
+        },
+        KeyBinding {
+            keys: &["3"],
+            action: set_mode(PreviewMode::BranchDiff),
+            description: "Preview the branch diff",
+        },
+        // Note: skim doesn't support change-preview-window like fzf, only toggle.
+        // Each toggle adds a line, so an odd count means the preview flipped.
+        KeyBinding {
+            keys: &["alt-p"],
+            action: Some(format!(
+                "toggle-preview+execute-silent(echo >> {})",
+                PreviewStateData::toggles_path().display()
+            )),
+            description: "Show or hide the preview",
+        },
+        KeyBinding {
+            keys: &["ctrl-u"],
+            action: Some(format!("preview-up({half_page})")),
+            description: "Scroll the preview up half a page",
+        },
+        KeyBinding {
+            keys: &["ctrl-d"],
+            action: Some(format!("preview-down({half_page})")),
+            description: "Scroll the preview down half a page",
+        },
+        KeyBinding {
+            keys: &["ctrl-o"],
+            action: open_diff,
+            description: "Open the previewed diff in the pager, then come back",
+        },
+        // `?` can't appear in a branch name, so binding it costs no queries
+        KeyBinding {
+            keys: &["?", "ctrl-h"],
+            action: set_mode(PreviewMode::Help),
+            description: PreviewMode::Help.description(),
+        },
+    ]
 }
 
 /// Preview state persistence (mode only, layout auto-detected)
 ///
-/// State file format: Single digit representing preview mode (1=WorkingTree, 2=Log, 3=BranchDiff)
+/// State file format: Single digit representing preview mode (1=WorkingTree, 2=Log, 3=BranchDiff, 4=Help)
 struct PreviewStateData;
 
 impl PreviewStateData {
@@ -292,8 +424,12 @@ impl PreviewStateData {
 
     /// Read current preview mode from state file
     fn read_mode() -> PreviewMode {
-        let state_path = Self::state_path();
-        fs::read_to_string(&state_path)
+        Self::read_mode_from(&Self::state_path())
+    }
+
+    /// Read the preview mode from another process's state file
+    fn read_mode_from(state_path: &Path) -> PreviewMode {
+        fs::read_to_string(state_path)
             .ok()
             .and_then(|s| s.trim().parse::<u8>().ok())
             .map(PreviewMode::from_u8)
@@ -304,21 +440,154 @@ impl PreviewStateData {
         let state_path = Self::state_path();
         let _ = fs::write(&state_path, format!("{}", mode as u8));
     }
+
+    /// File alt-p adds a line to each time it shows or hides the preview
+    fn toggles_path() -> PathBuf {
+        std::env::temp_dir().join(format!("wt-select-toggles-{}", std::process::id()))
+    }
+
+    /// How many times the preview was shown or hidden
+    fn read_toggles() -> usize {
+        fs::read_to_string(Self::toggles_path())
+            .map(|s| s.lines().count())
+            .unwrap_or(0)
+    }
+}
+
+/// How the picker was left, restored the next time it opens in the same
+/// repository. Stored as JSON in `.git/worktrunk/select`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+struct SelectPreferences {
+    preview: PreviewMode,
+    /// Whether alt-p left the preview hidden; `None` until it's used, which
+    /// defers to `[select] preview-hidden`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preview_hidden: Option<bool>,
+}
+
+impl SelectPreferences {
+    fn load(repo: &Repository) -> Self {
+        repo.select_preferences()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(self, repo: &Repository) {
+        if let Ok(value) = serde_json::to_string(&self) {
+            let _ = repo.set_select_preferences(&value);
+        }
+    }
+
+    /// The preferences after a session that ended in `mode`, with the preview
+    /// toggled `toggles` times from `hidden`
+    fn after(self, mode: PreviewMode, hidden: bool, toggles: usize) -> Self {
+        Self {
+            // The help overlay isn't a tab to come back to
+            preview: if mode == PreviewMode::Help {
+                self.preview
+            } else {
+                mode
+            },
+            preview_hidden: if toggles % 2 == 1 {
+                Some(!hidden)
+            } else {
+                self.preview_hidden
+            },
+        }
+    }
+}
+
+/// The picker's size and preview placement, from `[select]` config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SelectLayout {
+    /// Percentage of terminal height skim uses
+    height_percent: usize,
+    preview: PreviewLayout,
+    /// Preview's percentage of the picker; `None` sizes it automatically
+    preview_percent: Option<usize>,
+    preview_hidden: bool,
+}
+
+impl SelectLayout {
+    fn from_config(config: Option<&SelectConfig>) -> Self {
+        let preview = match config.and_then(|select| select.preview_position) {
+            None | Some(PreviewPosition::Auto) => PreviewLayout::auto_detect(),
+            Some(PreviewPosition::Right) => PreviewLayout::Right,
+            Some(PreviewPosition::Down) => PreviewLayout::Down,
+        };
+        Self {
+            height_percent: config
+                .and_then(SelectConfig::height_percent)
+                .map_or(SKIM_HEIGHT_PERCENT, usize::from),
+            preview,
+            preview_percent: config
+                .and_then(SelectConfig::preview_size_percent)
+                .map(usize::from),
+            preview_hidden: config
+                .and_then(|select| select.preview_hidden)
+                .unwrap_or(false),
+        }
+    }
+
+    /// skim's `--preview-window` spec
+    fn preview_window_spec(self, num_items: usize) -> String {
+        let mut spec = match (self.preview_percent, self.preview) {
+            (Some(percent), PreviewLayout::Right) => format!("right:{percent}%"),
+            (Some(percent), PreviewLayout::Down) => format!("down:{percent}%"),
+            (None, preview) => preview.to_preview_window_spec(num_items, self.height_percent),
+        };
+        if self.preview_hidden {
+            spec.push_str(":hidden");
+        }
+        spec
+    }
+
+    /// Columns left for the list: what the preview leaves beside it, or the
+    /// full width when the preview is below
+    fn list_width(self, terminal_width: usize) -> usize {
+        match self.preview {
+            PreviewLayout::Right => {
+                terminal_width * (100 - self.preview_percent.unwrap_or(50).min(100)) / 100
+            }
+            PreviewLayout::Down => terminal_width,
+        }
+    }
 }
 
 /// RAII wrapper for preview state file lifecycle management
 struct PreviewState {
     path: PathBuf,
-    initial_layout: PreviewLayout,
+    layout: SelectLayout,
+    /// What the picker opened with
+    preferences: SelectPreferences,
 }
 
 impl PreviewState {
-    fn new() -> Self {
+    /// Open with the preferences saved last time, over the `[select]` config
+    fn new(mut layout: SelectLayout, preferences: SelectPreferences) -> Self {
+        if let Some(hidden) = preferences.preview_hidden {
+            layout.preview_hidden = hidden;
+        }
         let path = PreviewStateData::state_path();
-        PreviewStateData::write_mode(PreviewMode::WorkingTree);
+        PreviewStateData::write_mode(preferences.preview);
+        let _ = fs::remove_file(PreviewStateData::toggles_path());
         Self {
             path,
-            initial_layout: PreviewLayout::auto_detect(),
+            layout,
+            preferences,
+        }
+    }
+
+    /// Save how the picker was left, if it changed
+    fn save_preferences(&self, repo: &Repository) {
+        let left = self.preferences.after(
+            PreviewStateData::read_mode(),
+            self.layout.preview_hidden,
+            PreviewStateData::read_toggles(),
+        );
+        if left != self.preferences {
+            left.save(repo);
         }
     }
 }
@@ -326,6 +595,7 @@ impl PreviewState {
 impl Drop for PreviewState {
     fn drop(&mut self) {
         let _ = fs::remove_file(&self.path);
+        let _ = fs::remove_file(PreviewStateData::toggles_path());
     }
 }
 
@@ -349,21 +619,51 @@ impl SkimItem for HeaderSkimItem {
     }
 }
 
-/// Wrapper to implement SkimItem for ListItem
-struct WorktreeSkimItem {
+/// A selector row's item and its rendering, replaced as the item's data arrives
+struct SelectRow {
     display_text: String,
     display_text_with_ansi: String,
+    item: ListItem,
+}
+
+impl SelectRow {
+    fn new(layout: &LayoutConfig, item: ListItem) -> Self {
+        // Use layout system to render the line - this handles all column alignment
+        let rendered_line = layout.render_list_item_line(&item, None);
+        let display_text_with_ansi = rendered_line.render();
+        let mut display_text = rendered_line.plain_text();
+        // Match on branch descriptions even when their column isn't shown
+        if let Some(Some(description)) = &item.description {
+            display_text.push(' ');
+            display_text.push_str(description);
+        }
+        Self {
+            display_text,
+            display_text_with_ansi,
+            item,
+        }
+    }
+}
+
+/// Wrapper to implement SkimItem for ListItem
+struct WorktreeSkimItem {
     branch_name: String,
-    item: Arc<ListItem>,
+    row: Arc<RwLock<SelectRow>>,
+}
+
+impl WorktreeSkimItem {
+    fn row(&self) -> RwLockReadGuard<'_, SelectRow> {
+        self.row.read().unwrap_or_else(PoisonError::into_inner)
+    }
 }
 
 impl SkimItem for WorktreeSkimItem {
     fn text(&self) -> Cow<'_, str> {
-        Cow::Borrowed(&self.display_text)
+        Cow::Owned(self.row().display_text.clone())
     }
 
     fn display<'a>(&'a self, _context: skim::DisplayContext<'a>) -> skim::AnsiString<'a> {
-        skim::AnsiString::parse(&self.display_text_with_ansi)
+        skim::AnsiString::parse(&self.row().display_text_with_ansi)
     }
 
     fn output(&self) -> Cow<'_, str> {
@@ -375,7 +675,8 @@ impl SkimItem for WorktreeSkimItem {
 
         // Build preview: tabs header + content
         let mut result = Self::render_preview_tabs(mode);
-        result.push_str(&self.preview_for_mode(mode, context.width, context.height));
+        let row = self.row();
+        result.push_str(&self.preview_for_mode(&row.item, mode, context.width, context.height));
 
         ItemPreview::AnsiText(result)
     }
@@ -400,9 +701,11 @@ impl WorktreeSkimItem {
             format!("{}{}{}", style.render(), label, style.render_reset())
         }
 
-        let tab1 = format_tab("1: HEAD±", mode == PreviewMode::WorkingTree);
-        let tab2 = format_tab("2: log", mode == PreviewMode::Log);
-        let tab3 = format_tab("3: main…±", mode == PreviewMode::BranchDiff);
+        let tabs = PreviewMode::TABS
+            .iter()
+            .map(|&tab| format_tab(tab.tab_label(), tab == mode))
+            .collect::<Vec<_>>()
+            .join(" | ");
 
         // Controls use dim yellow to distinguish from dimmed (white) tabs
         // while remaining subdued
@@ -410,20 +713,61 @@ impl WorktreeSkimItem {
             .dimmed()
             .fg_color(Some(anstyle::Color::Ansi(anstyle::AnsiColor::Yellow)));
         let controls = format!(
-            "{}Enter: switch | Esc: cancel | ctrl-u/d: scroll | alt-p: toggle{}",
+            "{}Enter: switch | Esc: cancel | ctrl-u/d: scroll | alt-p: toggle | ?: help{}",
             controls_style.render(),
             controls_style.render_reset()
         );
 
-        format!("{} | {} | {}\n{}\n\n", tab1, tab2, tab3, controls)
+        format!("{}\n{}\n\n", tabs, controls)
+    }
+
+    /// Render the help overlay: every key binding and preview mode
+    fn render_help(bindings: &[KeyBinding]) -> String {
+        let key_column = |keys: &[&str]| keys.join(" / ");
+        let width = bindings
+            .iter()
+            .map(|binding| key_column(binding.keys).chars().count())
+            .chain(
+                PreviewMode::TABS
+                    .iter()
+                    .map(|mode| mode.tab_label().chars().count()),
+            )
+            .max()
+            .unwrap_or(0);
+
+        let mut output = cformat!("<bold>Keys</>\n");
+        for binding in bindings {
+            let keys = key_column(binding.keys);
+            output.push_str(&cformat!(
+                "  <cyan>{keys:width$}</>  {}\n",
+                binding.description
+            ));
+        }
+        output.push_str(&cformat!("\n<bold>Preview modes</>\n"));
+        for mode in PreviewMode::TABS {
+            output.push_str(&cformat!(
+                "  <cyan>{:width$}</>  {}\n",
+                mode.tab_label(),
+                mode.description()
+            ));
+        }
+        output.push_str("\nPress 1, 2, or 3 to return to a preview\n");
+        output
     }
 
     /// Render preview for the given mode with specified dimensions
-    fn preview_for_mode(&self, mode: PreviewMode, width: usize, height: usize) -> String {
+    fn preview_for_mode(
+        &self,
+        item: &ListItem,
+        mode: PreviewMode,
+        width: usize,
+        height: usize,
+    ) -> String {
         match mode {
-            PreviewMode::WorkingTree => self.render_working_tree_preview(width),
-            PreviewMode::Log => self.render_log_preview(width, height),
-            PreviewMode::BranchDiff => self.render_branch_diff_preview(width),
+            PreviewMode::WorkingTree => self.render_working_tree_preview(item, width),
+            PreviewMode::Log => self.render_log_preview(item, width, height),
+            PreviewMode::BranchDiff => self.render_branch_diff_preview(item, width),
+            PreviewMode::Help => Self::render_help(&key_bindings()),
         }
     }
 
@@ -466,18 +810,18 @@ impl WorktreeSkimItem {
 
     /// Render Tab 1: Working tree preview (uncommitted changes vs HEAD)
     /// Matches `wt list` "HEAD±" column
-    fn render_working_tree_preview(&self, width: usize) -> String {
+    fn render_working_tree_preview(&self, item: &ListItem, width: usize) -> String {
         use worktrunk::styling::INFO_SYMBOL;
 
-        let Some(wt_info) = self.item.worktree_data() else {
+        let Some(wt_info) = item.worktree_data() else {
             // Branch without worktree - selecting will create one
-            let branch = self.item.branch_name();
+            let branch = item.branch_name();
             return format!(
                 "{INFO_SYMBOL} {branch} is branch only — press Enter to create worktree\n"
             );
         };
 
-        let branch = self.item.branch_name();
+        let branch = item.branch_name();
         let path = wt_info.path.display().to_string();
         self.render_diff_preview(
             &["-C", &path, "diff", "HEAD"],
@@ -488,32 +832,50 @@ impl WorktreeSkimItem {
 
     /// Render Tab 3: Branch diff preview (line diffs in commits ahead of default branch)
     /// Matches `wt list` "main…± (--full)" column
-    fn render_branch_diff_preview(&self, width: usize) -> String {
-        use worktrunk::styling::INFO_SYMBOL;
+    fn render_branch_diff_preview(&self, item: &ListItem, width: usize) -> String {
+        use worktrunk::styling::{INFO_SYMBOL, format_with_gutter, warning_message};
 
-        let branch = self.item.branch_name();
+        let branch = item.branch_name();
         let repo = Repository::current();
         let Ok(default_branch) = repo.default_branch() else {
             return cformat!("{INFO_SYMBOL} <bold>{branch}</> has no commits ahead of main\n");
         };
-        if self.item.counts().ahead == 0 {
+        if item.counts().ahead == 0 {
             return cformat!(
                 "{INFO_SYMBOL} <bold>{branch}</> has no commits ahead of <bold>{default_branch}</>\n"
             );
         }
 
-        let merge_base = format!("{}...{}", default_branch, self.item.head());
-        self.render_diff_preview(
+        // Select skips the conflict check while listing; it's one merge-tree
+        // call for the highlighted branch
+        let mut output = String::new();
+        if let Ok(conflicts) = repo.merge_conflicts(&default_branch, item.head())
+            && !conflicts.is_empty()
+        {
+            output.push_str(
+                &warning_message(cformat!(
+                    "<bold>{branch}</> would conflict with <bold>{default_branch}</>:"
+                ))
+                .to_string(),
+            );
+            output.push('\n');
+            output.push_str(&format_with_gutter(&conflicts.join("\n"), Some(width)));
+            output.push_str("\n\n");
+        }
+
+        let merge_base = format!("{}...{}", default_branch, item.head());
+        output.push_str(&self.render_diff_preview(
             &["diff", &merge_base],
             &cformat!(
                 "{INFO_SYMBOL} <bold>{branch}</> has no changes vs <bold>{default_branch}</>"
             ),
             width,
-        )
+        ));
+        output
     }
 
     /// Render Tab 2: Log preview
-    fn render_log_preview(&self, width: usize, height: usize) -> String {
+    fn render_log_preview(&self, item: &ListItem, width: usize, height: usize) -> String {
         use worktrunk::styling::INFO_SYMBOL;
         // Minimum preview width to show timestamps (adds ~7 chars: space + 4-char time + space)
         // Note: preview is typically 50% of terminal width, so 50 = 100-col terminal
@@ -526,8 +888,8 @@ impl WorktreeSkimItem {
         // Calculate how many log lines fit in preview (height minus header)
         let log_limit = height.saturating_sub(HEADER_LINES).max(1);
         let repo = Repository::current();
-        let head = self.item.head();
-        let branch = self.item.branch_name();
+        let head = item.head();
+        let branch = item.branch_name();
         let Ok(default_branch) = repo.default_branch() else {
             output.push_str(&cformat!(
                 "{INFO_SYMBOL} <bold>{branch}</> has no commits\n"
@@ -660,253 +1022,190 @@ impl WorktreeSkimItem {
     }
 }
 
-/// Parse a git numstat line and extract insertions/deletions
-///
-/// Numstat format: `added<TAB>deleted<TAB>filename`
-/// With --graph --color=always, lines have ANSI-colored graph prefix like `ESC[31m|ESC[m `.
-/// Binary files show "-" instead of numbers.
+/// Handle `ctrl-o` in `wt select`: show the full diff behind the active preview
+/// tab in the user's pager, at full terminal size.
 ///
-/// Returns Some((insertions, deletions)) for valid numstat lines.
-fn parse_numstat_line(line: &str) -> Option<(usize, usize)> {
-    use ansi_str::AnsiStr;
-
-    // First strip ANSI escape sequences (graph coloring contains digits that confuse parsing)
-    let stripped = line.ansi_strip();
-
-    // Strip graph prefix (e.g., "| ") and find tab-separated values
-    let trimmed = stripped.trim_start_matches(|c: char| !c.is_ascii_digit() && c != '-');
-
-    // Must have at least two tab-separated fields
-    let mut parts = trimmed.split('\t');
-    let added_str = parts.next()?;
-    let deleted_str = parts.next()?;
+/// skim runs this as a separate `wt` process while it's suspended, and returns
+/// to the picker when the pager exits. `target` is the item's switch target (a
+/// branch, or a path for a detached worktree); `preview_state` is the picker's
+/// state file, which says which tab is showing.
+pub fn handle_open_diff(target: &str, preview_state: &Path) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let mode = PreviewStateData::read_mode_from(preview_state);
 
-    // "-" means binary file, treat as 0
-    let added = if added_str == "-" {
-        0
-    } else {
-        added_str.parse().ok()?
-    };
-    let deleted = if deleted_str == "-" {
-        0
-    } else {
-        deleted_str.parse().ok()?
+    let worktree = match repo.worktree_for_branch(target)? {
+        Some(path) => Some(path),
+        None => Some(PathBuf::from(target)).filter(|path| path.is_dir()),
     };
+    // Inside the worktree, HEAD also covers detached worktrees
+    let head = if worktree.is_some() { "HEAD" } else { target };
+    let default_branch = repo.default_branch()?;
 
-    Some((added, deleted))
-}
-
-/// Field delimiter for git log format with timestamps
-const FIELD_DELIM: char = '\x1f';
-
-/// Timestamp column width ("12mo" is the longest)
-const TIMESTAMP_WIDTH: usize = 4;
-
-/// Format git log output with timestamps and diffstats.
-///
-/// Parses git log output in the format:
-/// `graph_hash\x1ftimestamp\x1f decoration message`
-/// followed by numstat lines (`added\tdeleted\tfilename`).
-///
-/// Returns formatted output with aligned timestamps and diff stats.
-fn format_log_output(log_output: &str) -> String {
-    use crate::display::format_relative_time_short;
-    format_log_output_with_formatter(log_output, format_relative_time_short)
-}
-
-/// Format git log output with a custom time formatter.
-///
-/// This variant allows dependency injection for testing with deterministic timestamps.
-fn format_log_output_with_formatter<F>(log_output: &str, format_time: F) -> String
-where
-    F: Fn(i64) -> String,
-{
-    // State machine: accumulate stats for each commit
-    let mut pending_commit: Option<&str> = None;
-    let mut pending_stats: (usize, usize) = (0, 0);
-    let mut result = Vec::new();
-
-    for line in log_output.lines() {
-        if line.contains(FIELD_DELIM) {
-            // This is a commit line - emit any pending commit first
-            if let Some(prev) = pending_commit.take() {
-                result.push(format_commit_line(prev, pending_stats, &format_time));
-            }
-            pending_commit = Some(line);
-            pending_stats = (0, 0);
-        } else if let Some((ins, del)) = parse_numstat_line(line) {
-            // Accumulate stats for pending commit
-            pending_stats.0 += ins;
-            pending_stats.1 += del;
+    let args = match (mode, &worktree) {
+        (PreviewMode::WorkingTree | PreviewMode::Help, Some(_)) => {
+            vec!["diff".to_string(), "HEAD".to_string()]
         }
-        // Skip empty/graph-only lines
-    }
+        (PreviewMode::Log, _) => vec![
+            "log".to_string(),
+            "-p".to_string(),
+            format!("{default_branch}..{head}"),
+        ],
+        // Branches without a worktree have no uncommitted changes; show what they'd merge
+        (PreviewMode::WorkingTree | PreviewMode::Help | PreviewMode::BranchDiff, _) => {
+            vec!["diff".to_string(), format!("{default_branch}...{head}")]
+        }
+    };
 
-    // Don't forget final commit
-    if let Some(last) = pending_commit {
-        result.push(format_commit_line(last, pending_stats, &format_time));
+    let mut cmd = Command::new("git");
+    if let Some(path) = &worktree {
+        cmd.arg("-C").arg(path);
     }
-
-    result.join("\n")
-}
-
-/// Format a single commit line with stats
-fn format_commit_line<F>(
-    commit_line: &str,
-    (insertions, deletions): (usize, usize),
-    format_time: &F,
-) -> String
-where
-    F: Fn(i64) -> String,
-{
-    use worktrunk::styling::{ADDITION, DELETION};
-
-    let dim_style = anstyle::Style::new().dimmed();
-    let reset = anstyle::Reset;
-
-    if let Some(first_delim) = commit_line.find(FIELD_DELIM)
-        && let Some(second_delim) = commit_line[first_delim + 1..].find(FIELD_DELIM)
-    {
-        let graph_hash = &commit_line[..first_delim];
-        let timestamp_str = &commit_line[first_delim + 1..first_delim + 1 + second_delim];
-        let rest = &commit_line[first_delim + 1 + second_delim + 1..];
-
-        let time = timestamp_str
-            .parse::<i64>()
-            .map(format_time)
-            .unwrap_or_default();
-
-        // Use the same diff formatting as wt list (aligned columns)
-        let diff_config = DiffDisplayConfig {
-            variant: DiffVariant::Signs,
-            positive_style: ADDITION,
-            negative_style: DELETION,
-            always_show_zeros: false,
-        };
-        let stat_str = format!(" {}", diff_config.format_aligned(insertions, deletions));
-
-        format!(
-            "{}{} {dim_style}{:>width$}{reset}{}",
-            graph_hash,
-            stat_str,
-            time,
-            rest,
-            width = TIMESTAMP_WIDTH
-        )
-    } else {
-        commit_line.to_string()
+    cmd.args(&args)
+        // Prevent git and the pager from writing to the directive file
+        .env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR);
+    // git's default `LESS=FRX` quits at once on diffs shorter than a screen,
+    // which would flash straight back to the picker
+    if std::env::var_os("LESS").is_none() {
+        cmd.env("LESS", "RX");
     }
+    log::debug!("Opening diff: git {}", args.join(" "));
+    cmd.status().context("Failed to run git")?;
+    Ok(())
 }
 
-pub fn handle_select() -> anyhow::Result<()> {
-    use std::io::IsTerminal;
-
-    // Select requires an interactive terminal for the TUI
-    if !std::io::stdin().is_terminal() {
-        anyhow::bail!("wt select requires an interactive terminal");
-    }
-
+/// Collect the selector's rows, sending each to skim as soon as it's known and
+/// re-rendering it as its data arrives.
+///
+/// `started` gets the number of rows once they've all been sent; `tx` drops
+/// when collection finishes, which tells skim the list is complete.
+fn collect_rows(
+    config: &WorktrunkConfig,
+    branch_filter: &collect::BranchFilter,
+    select_layout: SelectLayout,
+    tx: SkimItemSender,
+    started: std::sync::mpsc::Sender<usize>,
+) -> anyhow::Result<()> {
     let repo = Repository::current();
 
-    // Initialize preview mode state file (auto-cleanup on drop)
-    let state = PreviewState::new();
-
-    // Load config (or use default) for path mismatch detection
-    let config = WorktrunkConfig::load()
-        .inspect_err(|e| log::warn!("Config load failed, using defaults: {}", e))
-        .unwrap_or_default();
-
-    // Gather list data using simplified collection (buffered mode)
     // Skip expensive operations not needed for select UI
-    let skip_tasks = [
+    let skip_tasks: HashSet<_> = [
         collect::TaskKind::BranchDiff,
         collect::TaskKind::CiStatus,
         collect::TaskKind::MergeTreeConflicts,
+        collect::TaskKind::Signature,
     ]
     .into_iter()
     .collect();
 
-    let Some(list_data) = collect::collect(
+    let mut layout = None;
+    let mut rows: Vec<Arc<RwLock<SelectRow>>> = Vec::new();
+    let mut observer = |event: collect::CollectEvent<'_>| match event {
+        collect::CollectEvent::Started {
+            items,
+            main_worktree_path,
+        } => {
+            // Use the same layout system as `wt list` for proper column alignment
+            // List width depends on preview position and size
+            let skim_list_width =
+                select_layout.list_width(super::list::layout::get_safe_list_width());
+            let mut row_layout = super::list::layout::calculate_layout_with_width(
+                items,
+                &skip_tasks,
+                config.list_columns(),
+                skim_list_width,
+                main_worktree_path,
+                None, // URL column not shown in select
+            );
+            row_layout.age_thresholds = super::list::layout::AgeThresholds::from_config(config);
+            row_layout.placeholder = super::list::layout::Placeholder::from_config(config);
+
+            // Render header using layout system (need both plain and styled text for skim)
+            let header_line = row_layout.render_header_line();
+            let _ = tx.send(Arc::new(HeaderSkimItem {
+                display_text: header_line.plain_text(),
+                display_text_with_ansi: header_line.render(),
+            }));
+
+            for item in items {
+                let row = Arc::new(RwLock::new(SelectRow::new(&row_layout, item.clone())));
+                let _ = tx.send(Arc::new(WorktreeSkimItem {
+                    branch_name: item.switch_target(),
+                    row: Arc::clone(&row),
+                }));
+                rows.push(row);
+            }
+            layout = Some(row_layout);
+            let _ = started.send(items.len());
+        }
+        collect::CollectEvent::Updated { item_idx, item } => {
+            if let (Some(layout), Some(row)) = (&layout, rows.get(item_idx)) {
+                *row.write().unwrap_or_else(PoisonError::into_inner) =
+                    SelectRow::new(layout, item.clone());
+            }
+        }
+    };
+
+    collect::collect_with_observer(
         &repo,
         true,  // show_branches (include branches without worktrees)
         false, // show_remotes (local branches only, not remote branches)
+        branch_filter,
         &skip_tasks,
-        false, // show_progress (no progress bars)
+        false, // show_progress (skim renders the rows)
         false, // render_table (select renders its own UI)
-        &config,
-    )?
-    else {
-        return Ok(());
-    };
+        config,
+        Some(&mut observer),
+    )?;
+    Ok(())
+}
 
-    // Use the same layout system as `wt list` for proper column alignment
-    // List width depends on preview position:
-    // - Right layout: skim splits ~50% for list, ~50% for preview
-    // - Down layout: list gets full width, preview is below
-    let terminal_width = super::list::layout::get_safe_list_width();
-    let skim_list_width = match state.initial_layout {
-        PreviewLayout::Right => terminal_width / 2,
-        PreviewLayout::Down => terminal_width,
-    };
-    let layout = super::list::layout::calculate_layout_with_width(
-        &list_data.items,
-        &skip_tasks,
-        skim_list_width,
-        &list_data.main_worktree_path,
-        None, // URL column not shown in select
-    );
+pub fn handle_select(branch_filter: &collect::BranchFilter) -> anyhow::Result<()> {
+    use std::io::IsTerminal;
 
-    // Render header using layout system (need both plain and styled text for skim)
-    let header_line = layout.render_header_line();
-    let header_display_text = header_line.render();
-    let header_plain_text = header_line.plain_text();
-
-    // Convert to skim items using the layout system for rendering
-    let mut items: Vec<Arc<dyn SkimItem>> = list_data
-        .items
-        .into_iter()
-        .map(|item| {
-            let branch_name = item.branch_name().to_string();
-
-            // Use layout system to render the line - this handles all column alignment
-            let rendered_line = layout.render_list_item_line(&item, None);
-            let display_text_with_ansi = rendered_line.render();
-            let display_text = rendered_line.plain_text();
-
-            Arc::new(WorktreeSkimItem {
-                display_text,
-                display_text_with_ansi,
-                branch_name,
-                item: Arc::new(item),
-            }) as Arc<dyn SkimItem>
-        })
-        .collect();
+    // Select requires an interactive terminal for the TUI
+    if super::command_approval::assume_yes() {
+        anyhow::bail!("wt select needs input, so it can't run with --yes");
+    }
+    if !std::io::stdin().is_terminal() {
+        anyhow::bail!("wt select requires an interactive terminal");
+    }
 
-    // Insert header row at the beginning (will be non-selectable via header_lines option)
-    items.insert(
-        0,
-        Arc::new(HeaderSkimItem {
-            display_text: header_plain_text,
-            display_text_with_ansi: header_display_text,
-        }) as Arc<dyn SkimItem>,
+    // Load config (or use default) for path mismatch detection and layout
+    let config = WorktrunkConfig::load()
+        .inspect_err(|e| log::warn!("Config load failed, using defaults: {}", e))
+        .unwrap_or_default();
+
+    // Initialize preview mode state file (auto-cleanup on drop), reopening
+    // the way the picker was last left in this repository
+    let repo = Repository::current();
+    let state = PreviewState::new(
+        SelectLayout::from_config(config.select.as_ref()),
+        SelectPreferences::load(&repo),
     );
 
-    // Get state path for key bindings
-    let state_path_str = state.path.display().to_string();
+    // Rows stream into skim as soon as they're known and fill in as their data
+    // arrives, so the picker shows up before slow repos finish collecting
+    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+    let (started_tx, started_rx) = std::sync::mpsc::channel();
+    let branch_filter = branch_filter.clone();
+    let select_layout = state.layout;
+    let collector = std::thread::spawn(move || {
+        collect_rows(&config, &branch_filter, select_layout, tx, started_tx)
+    });
 
-    // Calculate half-page scroll: skim uses 90% of terminal height, half of that = 45%
-    let half_page = terminal_size::terminal_size()
-        .map(|(_, terminal_size::Height(h))| (h as usize * 45 / 100).max(5))
-        .unwrap_or(10);
+    let Ok(num_items) = started_rx.recv() else {
+        // Collection ended before listing any rows: no worktrees, or an error
+        return collector
+            .join()
+            .map_err(|_| anyhow::anyhow!("Worktree collection panicked"))?;
+    };
 
     // Calculate preview window spec based on auto-detected layout
-    // items.len() - 1 because we added a header row
-    let num_items = items.len().saturating_sub(1);
-    let preview_window_spec = state.initial_layout.to_preview_window_spec(num_items);
+    let preview_window_spec = state.layout.preview_window_spec(num_items);
 
     // Configure skim options with Rust-based preview and mode switching keybindings
     let options = SkimOptionsBuilder::default()
-        .height("90%".to_string())
+        .height(format!("{}%", state.layout.height_percent))
         .layout("reverse".to_string())
         .header_lines(1) // Make first line (header) non-selectable
         .multi(false)
@@ -930,42 +1229,29 @@ pub fn handle_select() -> anyhow::Result<()> {
             "fg:-1,bg:-1,header:-1,matched:108,current:237,current_bg:251,current_match:108"
                 .to_string(),
         ))
-        .bind(vec![
-            // Mode switching (1/2/3 keys change preview content)
-            format!(
-                "1:execute-silent(echo 1 > {0})+refresh-preview",
-                state_path_str
-            ),
-            format!(
-                "2:execute-silent(echo 2 > {0})+refresh-preview",
-                state_path_str
-            ),
-            format!(
-                "3:execute-silent(echo 3 > {0})+refresh-preview",
-                state_path_str
-            ),
-            // Preview toggle (alt-p shows/hides preview)
-            // Note: skim doesn't support change-preview-window like fzf, only toggle
-            "alt-p:toggle-preview".to_string(),
-            // Preview scrolling (half-page based on terminal height)
-            format!("ctrl-u:preview-up({half_page})"),
-            format!("ctrl-d:preview-down({half_page})"),
-        ])
+        .bind(
+            key_bindings()
+                .into_iter()
+                .filter_map(|binding| {
+                    let action = binding.action?;
+                    Some(
+                        binding
+                            .keys
+                            .iter()
+                            .map(move |key| format!("{key}:{action}")),
+                    )
+                })
+                .flatten()
+                .collect(),
+        )
         // Legend/controls moved to preview window tabs (render_preview_tabs)
         .no_clear(true) // Prevent skim from clearing screen, we'll do it manually
         .build()
         .map_err(|e| anyhow::anyhow!("Failed to build skim options: {}", e))?;
 
-    // Create item receiver
-    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
-    for item in items {
-        tx.send(item)
-            .map_err(|e| anyhow::anyhow!("Failed to send item to skim: {}", e))?;
-    }
-    drop(tx);
-
     // Run skim
     let output = Skim::run_with(&options, Some(rx));
+    state.save_preferences(&repo);
 
     // Handle selection
     if let Some(out) = output
@@ -973,7 +1259,7 @@ pub fn handle_select() -> anyhow::Result<()> {
         && let Some(selected) = out.selected_items.first()
     {
         // Get branch name or worktree path from selected item
-        // (output() returns the worktree path for existing worktrees, branch name otherwise)
+        // (output() returns the branch name, or the worktree path for detached worktrees)
         let identifier = selected.output().to_string();
 
         // Load config
@@ -981,8 +1267,17 @@ pub fn handle_select() -> anyhow::Result<()> {
 
         // Switch to the selected worktree
         // handle_switch can handle both branch names and worktree paths
-        let (result, branch_info) =
-            handle_switch(&identifier, false, None, false, false, false, &config)?;
+        let (result, branch_info) = handle_switch(
+            &identifier,
+            false,
+            None,
+            false,
+            None,
+            false,
+            false,
+            false,
+            &config,
+        )?;
 
         // Clear the terminal screen after skim exits to prevent artifacts
         // Use stderr for terminal control - stdout is reserved for data output
@@ -1007,21 +1302,101 @@ mod tests {
         assert_eq!(PreviewMode::from_u8(1), PreviewMode::WorkingTree);
         assert_eq!(PreviewMode::from_u8(2), PreviewMode::Log);
         assert_eq!(PreviewMode::from_u8(3), PreviewMode::BranchDiff);
+        assert_eq!(PreviewMode::from_u8(4), PreviewMode::Help);
         // Invalid values default to WorkingTree
         assert_eq!(PreviewMode::from_u8(0), PreviewMode::WorkingTree);
         assert_eq!(PreviewMode::from_u8(99), PreviewMode::WorkingTree);
     }
 
+    #[test]
+    fn test_select_preferences_after() {
+        let opened = SelectPreferences::default();
+
+        // The last tab is kept; the help overlay isn't
+        let left = opened.after(PreviewMode::Log, false, 0);
+        assert_eq!(left.preview, PreviewMode::Log);
+        assert_eq!(left.preview_hidden, None);
+        assert_eq!(
+            left.after(PreviewMode::Help, false, 0).preview,
+            PreviewMode::Log
+        );
+
+        // Only an odd number of toggles changes visibility
+        assert_eq!(
+            opened
+                .after(PreviewMode::WorkingTree, false, 1)
+                .preview_hidden,
+            Some(true)
+        );
+        assert_eq!(
+            opened
+                .after(PreviewMode::WorkingTree, true, 3)
+                .preview_hidden,
+            Some(false)
+        );
+        assert_eq!(
+            opened
+                .after(PreviewMode::WorkingTree, false, 2)
+                .preview_hidden,
+            None
+        );
+    }
+
+    #[test]
+    fn test_select_preferences_json() {
+        let preferences = SelectPreferences {
+            preview: PreviewMode::BranchDiff,
+            preview_hidden: Some(true),
+        };
+        let json = serde_json::to_string(&preferences).unwrap();
+        assert_eq!(json, r#"{"preview":"branch-diff","preview-hidden":true}"#);
+        assert_eq!(
+            serde_json::from_str::<SelectPreferences>(&json).unwrap(),
+            preferences
+        );
+        // Missing fields take their defaults
+        assert_eq!(
+            serde_json::from_str::<SelectPreferences>("{}").unwrap(),
+            SelectPreferences::default()
+        );
+    }
+
     #[test]
     fn test_preview_layout_to_preview_window_spec() {
         // Right is always 50%
-        assert_eq!(PreviewLayout::Right.to_preview_window_spec(10), "right:50%");
+        assert_eq!(
+            PreviewLayout::Right.to_preview_window_spec(10, SKIM_HEIGHT_PERCENT),
+            "right:50%"
+        );
 
         // Down calculates based on item count
-        let spec = PreviewLayout::Down.to_preview_window_spec(5);
+        let spec = PreviewLayout::Down.to_preview_window_spec(5, SKIM_HEIGHT_PERCENT);
         assert!(spec.starts_with("down:"));
     }
 
+    #[test]
+    fn test_select_layout_from_config() {
+        let config = SelectConfig {
+            height: Some("full".into()),
+            preview_position: Some(PreviewPosition::Right),
+            preview_size: Some("40%".into()),
+            preview_hidden: Some(true),
+        };
+        let layout = SelectLayout::from_config(Some(&config));
+        assert_eq!(layout.height_percent, 100);
+        assert_eq!(layout.preview_window_spec(3), "right:40%:hidden");
+        assert_eq!(layout.list_width(100), 60);
+
+        let config = SelectConfig {
+            preview_position: Some(PreviewPosition::Down),
+            ..SelectConfig::default()
+        };
+        let layout = SelectLayout::from_config(Some(&config));
+        assert_eq!(layout.height_percent, SKIM_HEIGHT_PERCENT);
+        assert!(layout.preview_window_spec(3).starts_with("down:"));
+        assert_eq!(layout.list_width(100), 100);
+    }
+
     #[test]
     fn test_preview_state_data_read_default() {
         // Use unique path to avoid interference from parallel tests
@@ -1127,226 +1502,40 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_numstat_line_basic() {
-        // Tab-separated: added<TAB>deleted<TAB>filename
-        let result = parse_numstat_line("10\t5\tfile.rs");
-        assert_eq!(result, Some((10, 5)));
-    }
-
-    #[test]
-    fn test_parse_numstat_line_insertions_only() {
-        let result = parse_numstat_line("15\t0\tfile.rs");
-        assert_eq!(result, Some((15, 0)));
-    }
-
-    #[test]
-    fn test_parse_numstat_line_deletions_only() {
-        let result = parse_numstat_line("0\t8\tfile.rs");
-        assert_eq!(result, Some((0, 8)));
-    }
-
-    #[test]
-    fn test_parse_numstat_line_binary_file() {
-        // Binary files show "-" instead of numbers
-        let result = parse_numstat_line("-\t-\timage.png");
-        assert_eq!(result, Some((0, 0)));
-    }
-
-    #[test]
-    fn test_parse_numstat_line_with_graph_prefix() {
-        // Git graph prefixes the numstat line with graph characters
-        let result = parse_numstat_line("| 10\t5\tfile.rs");
-        assert_eq!(result, Some((10, 5)));
-
-        // First numstat line after commit has "* | " prefix
-        let result = parse_numstat_line("* | 11\t0\tCargo.toml");
-        assert_eq!(result, Some((11, 0)));
-
-        // Subsequent numstat lines have "| " prefix
-        let result = parse_numstat_line("| 17\t3\tsrc/main.rs");
-        assert_eq!(result, Some((17, 3)));
-
-        // With ANSI colors (--color=always adds escape codes to graph)
-        // ESC[31m = red, ESC[m = reset
-        let esc = '\x1b';
-        let ansi_colored = format!("{esc}[31m|{esc}[m 11\t0\tCargo.toml");
-        let result = parse_numstat_line(&ansi_colored);
-        assert_eq!(result, Some((11, 0)));
-    }
-
-    #[test]
-    fn test_parse_numstat_line_not_numstat() {
-        // Not a numstat line
-        assert_eq!(parse_numstat_line("* abc1234 Fix bug"), None);
-        assert_eq!(parse_numstat_line(""), None);
-        assert_eq!(parse_numstat_line("regular text"), None);
-    }
-
-    // format_log_output tests use dependency injection for deterministic time formatting.
-    // The format_log_output_with_formatter function accepts a time formatter closure.
-
-    /// Fixed time formatter for deterministic tests
-    fn fixed_time_formatter(_timestamp: i64) -> String {
-        "1h".to_string() // Return a fixed time for all timestamps
-    }
-
-    #[test]
-    fn test_format_log_output_single_commit() {
-        // Simulate git log output: hash\x1ftimestamp\x1f message
-        let input = "abc1234\x1f1699999000\x1f Fix bug";
-        let output = format_log_output_with_formatter(input, fixed_time_formatter);
-
-        // Should contain the hash and message
-        assert!(output.contains("abc1234"), "output: {}", output);
-        assert!(output.contains("Fix bug"), "output: {}", output);
-        // Should contain formatted time
-        assert!(output.contains("1h"), "output: {}", output);
-    }
-
-    #[test]
-    fn test_format_log_output_with_numstat() {
-        // Commit line followed by numstat lines
-        let input = "abc1234\x1f1699999000\x1f Add feature\n\
-                     10\t5\tfile1.rs\n\
-                     3\t0\tfile2.rs";
-        let output = format_log_output_with_formatter(input, fixed_time_formatter);
-
-        // Should contain the hash and message
-        assert!(output.contains("abc1234"), "output: {}", output);
-        // Stats should be accumulated: 10+3=13 insertions, 5+0=5 deletions
-        // The output should contain the stats in the formatted line
-        assert!(output.contains("Add feature"), "output: {}", output);
-        // Verify stats are present (green +13, red -5)
-        assert!(output.contains("+13"), "expected +13 in output: {}", output);
-        assert!(output.contains("-5"), "expected -5 in output: {}", output);
-    }
-
-    #[test]
-    fn test_format_log_output_multiple_commits() {
-        // Two commits, each with numstat
-        let input = "abc1234\x1f1699999000\x1f First commit\n\
-                     5\t2\tfile.rs\n\
-                     def5678\x1f1699998000\x1f Second commit\n\
-                     10\t3\tother.rs";
-        let output = format_log_output_with_formatter(input, fixed_time_formatter);
-
-        // Both commits should be in output
-        assert!(output.contains("abc1234"), "output: {}", output);
-        assert!(output.contains("def5678"), "output: {}", output);
-        assert!(output.contains("First commit"), "output: {}", output);
-        assert!(output.contains("Second commit"), "output: {}", output);
-
-        // Output should be two lines (one per commit)
-        let lines: Vec<&str> = output.lines().collect();
-        assert_eq!(lines.len(), 2, "Expected 2 lines, got: {:?}", lines);
-    }
-
-    #[test]
-    fn test_format_log_output_empty_input() {
-        let output = format_log_output_with_formatter("", fixed_time_formatter);
-        assert!(output.is_empty());
-    }
-
-    #[test]
-    fn test_format_log_output_no_numstat() {
-        // Commit without numstat lines
-        let input = "abc1234\x1f1699999000\x1f Just a commit";
-        let output = format_log_output_with_formatter(input, fixed_time_formatter);
-
-        assert!(output.contains("abc1234"), "output: {}", output);
-        assert!(output.contains("Just a commit"), "output: {}", output);
-    }
-
-    #[test]
-    fn test_format_log_output_with_graph_prefix() {
-        // Git graph output includes graph characters
-        let input = "* abc1234\x1f1699999000\x1f Commit with graph\n\
-                     | 5\t2\tfile.rs";
-        let output = format_log_output_with_formatter(input, fixed_time_formatter);
-
-        assert!(output.contains("abc1234"), "output: {}", output);
-        assert!(output.contains("Commit with graph"), "output: {}", output);
-        // Verify stats are present
-        assert!(output.contains("+5"), "expected +5 in output: {}", output);
-        assert!(output.contains("-2"), "expected -2 in output: {}", output);
-    }
-
-    #[test]
-    fn test_format_log_output_binary_files() {
-        // Binary files show "-" in numstat
-        let input = "abc1234\x1f1699999000\x1f Add image\n\
-                     -\t-\timage.png\n\
-                     5\t0\tdocs.md";
-        let output = format_log_output_with_formatter(input, fixed_time_formatter);
-
-        // Binary files treated as 0 additions/deletions
-        // Should still format the commit line
-        assert!(output.contains("abc1234"), "output: {}", output);
-        assert!(output.contains("Add image"), "output: {}", output);
-        // Verify stats: 0 (binary) + 5 = 5 insertions, 0 deletions
-        assert!(output.contains("+5"), "expected +5 in output: {}", output);
-    }
-
-    #[test]
-    fn test_format_log_output_malformed_commit_line() {
-        // Line without proper field delimiters should be passed through
-        let input = "abc1234 regular commit line";
-        let output = format_log_output_with_formatter(input, fixed_time_formatter);
-
-        // Should be empty since no valid commit lines (no FIELD_DELIM)
-        assert!(output.is_empty(), "output: {}", output);
-    }
-
-    #[test]
-    fn test_format_log_output_commit_line_missing_second_delimiter() {
-        // Only one delimiter - malformed
-        let input = "abc1234\x1f1699999000 Fix bug";
-        let output = format_log_output_with_formatter(input, fixed_time_formatter);
-
-        // Should output the line as-is since it's malformed (only one \x1f)
-        assert!(output.contains("abc1234"), "output: {}", output);
-    }
-
-    #[test]
-    fn test_format_log_output_stats_only_deletions() {
-        // Commit with only deletions (no insertions)
-        let input = "abc1234\x1f1699999000\x1f Remove old code\n\
-                     0\t50\told_file.rs";
-        let output = format_log_output_with_formatter(input, fixed_time_formatter);
-
-        assert!(output.contains("abc1234"), "output: {}", output);
-        assert!(output.contains("Remove old code"), "output: {}", output);
-        // Should show deletions
-        assert!(output.contains("-50"), "expected -50 in output: {}", output);
+    fn test_render_help_lists_every_binding_and_mode() {
+        let bindings = key_bindings();
+        let output = WorktreeSkimItem::render_help(&bindings);
+        for binding in &bindings {
+            for key in binding.keys {
+                assert!(output.contains(key), "help is missing {key}");
+            }
+            assert!(output.contains(binding.description));
+        }
+        for mode in PreviewMode::TABS {
+            assert!(output.contains(mode.tab_label()));
+            assert!(output.contains(mode.description()));
+        }
     }
 
     #[test]
-    fn test_format_log_output_large_stats() {
-        // Commit with large stats (tests K notation)
-        let input = "abc1234\x1f1699999000\x1f Big refactor\n\
-                     1500\t800\tlarge_file.rs";
-        let output = format_log_output_with_formatter(input, fixed_time_formatter);
-
-        assert!(output.contains("abc1234"), "output: {}", output);
-        // Large numbers should use K notation
-        assert!(
-            output.contains("+1K") || output.contains("+1.5K"),
-            "expected K notation in output: {}",
-            output
-        );
+    fn test_help_bound_to_question_mark_and_ctrl_h() {
+        let help = key_bindings()
+            .into_iter()
+            .find(|binding| binding.keys.contains(&"?"))
+            .unwrap();
+        assert!(help.keys.contains(&"ctrl-h"));
+        assert!(help.action.unwrap().contains("echo 4 >"));
     }
 
     #[test]
-    fn test_format_commit_line_directly() {
-        // Test the format_commit_line function directly
-        let commit_line = "abc1234\x1f1699999000\x1f Test commit";
-        let stats = (10, 5);
-        let output = format_commit_line(commit_line, stats, &fixed_time_formatter);
-
-        assert!(output.contains("abc1234"), "output: {}", output);
-        assert!(output.contains("Test commit"), "output: {}", output);
-        assert!(output.contains("+10"), "expected +10 in output: {}", output);
-        assert!(output.contains("-5"), "expected -5 in output: {}", output);
-        assert!(output.contains("1h"), "expected time in output: {}", output);
+    fn test_ctrl_o_opens_diff_for_highlighted_item() {
+        let open_diff = key_bindings()
+            .into_iter()
+            .find(|binding| binding.keys.contains(&"ctrl-o"))
+            .unwrap()
+            .action
+            .unwrap();
+        assert!(open_diff.starts_with("execute("));
+        assert!(open_diff.contains(" select --open-diff {} --preview-state "));
     }
 }
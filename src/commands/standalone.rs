@@ -19,19 +19,28 @@ use super::hooks::{
 };
 use super::merge::{
     execute_post_merge_commands, execute_pre_remove_commands, run_pre_merge_commands,
+    run_pre_push_commands,
 };
 use super::project_config::collect_commands_for_hooks;
 use super::repository_ext::RepositoryCliExt;
+use super::worktree::handle_push;
 
 /// Handle `wt hook` command
 ///
 /// When explicitly invoking hooks, ALL hooks run (both user and project).
-/// There's no skip flag - if you explicitly run hooks, all configured hooks run.
+/// There's no per-source skip flag; only the global `--no-hooks` skips them.
 ///
 /// Works in detached HEAD state - `{{ branch }}` template variable will be "HEAD".
 pub fn run_hook(hook_type: HookType, yes: bool, name_filter: Option<&str>) -> anyhow::Result<()> {
     use super::command_approval::approve_hooks_filtered;
 
+    if !super::hooks::hooks_enabled() {
+        crate::output::print(worktrunk::styling::info_message(cformat!(
+            "Skipped {hook_type} hooks (<bright-black>--no-hooks</>)"
+        )))?;
+        return Ok(());
+    }
+
     // Derive context from current environment (branch-optional for CI compatibility)
     let env = CommandEnv::for_action_branchless()?;
     let repo = &env.repo;
@@ -82,6 +91,10 @@ pub fn run_hook(hook_type: HookType, yes: bool, name_filter: Option<&str>) -> an
                 .as_ref()
                 .and_then(|c| c.hooks.post_create.as_ref());
             require_hooks(user_config, project_config, hook_type)?;
+            // Links are unnamed, so targeting one command skips them
+            if name_filter.is_none() {
+                super::link::create_links(&ctx, user_config, project_config)?;
+            }
             run_hook_with_filter(
                 &ctx,
                 user_config,
@@ -112,6 +125,10 @@ pub fn run_hook(hook_type: HookType, yes: bool, name_filter: Option<&str>) -> an
             check_name_filter_matched(name_filter, commands.len(), user_config, project_config)?;
             spawn_hook_commands_background(&ctx, commands, hook_type)
         }
+        HookType::PreSwitch => {
+            // Use current branch as target (matches approval prompt for wt hook)
+            ctx.execute_pre_switch_commands(ctx.branch_or_head(), name_filter)
+        }
         HookType::PostSwitch => {
             // post-switch hooks spawn in background (matching their normal behavior during switch)
             let user_config = user_hook!(post_switch);
@@ -167,7 +184,31 @@ pub fn run_hook(hook_type: HookType, yes: bool, name_filter: Option<&str>) -> an
             // No display_path - user is already in the directory where hooks run
             execute_post_merge_commands(&ctx, ctx.branch_or_head(), name_filter, None)
         }
+        HookType::PrePush => {
+            // Use the default push target, as `wt step push` would
+            let target_branch = repo.resolve_target_branch(None)?;
+            run_pre_push_commands(&ctx, &target_branch, name_filter)
+        }
         HookType::PreRemove => execute_pre_remove_commands(&ctx, name_filter),
+        HookType::PostRemove => {
+            // post-remove hooks spawn in background (matching their normal behavior during remove)
+            let user_config = user_hook!(post_remove);
+            let project_config = project_config
+                .as_ref()
+                .and_then(|c| c.hooks.post_remove.as_ref());
+            require_hooks(user_config, project_config, hook_type)?;
+            let commands = prepare_hook_commands(
+                &ctx,
+                user_config,
+                project_config,
+                hook_type,
+                &[],
+                name_filter,
+                None,
+            )?;
+            check_name_filter_matched(name_filter, commands.len(), user_config, project_config)?;
+            spawn_hook_commands_background(&ctx, commands, hook_type)
+        }
     }
 }
 
@@ -217,6 +258,33 @@ pub fn step_commit(
     options.commit()
 }
 
+/// Handle `wt step push` command
+pub fn step_push(target: Option<&str>, yes: bool, verify: bool) -> anyhow::Result<()> {
+    use super::command_approval::approve_hooks;
+
+    // Pushing works from detached HEAD, so don't require a branch
+    let env = CommandEnv::for_action_branchless()?;
+    let ctx = env.context(yes);
+    crate::output::report::record(|report| report.branch = env.branch.clone());
+
+    // "Approve at the Gate": approve pre-push hooks upfront (unless --no-verify)
+    // Shadow verify: if user declines approval, skip hooks but continue push
+    let verify = verify && {
+        let approved = approve_hooks(&ctx, &[HookType::PrePush])?;
+        if !approved {
+            crate::output::print(info_message("Commands declined, pushing without hooks"))?;
+        }
+        approved
+    };
+
+    if verify {
+        let target_branch = env.repo.resolve_target_branch(target)?;
+        run_pre_push_commands(&ctx, &target_branch, None)?;
+    }
+
+    handle_push(target, "Pushed to", None)
+}
+
 /// Result of a squash operation
 #[derive(Debug, Clone)]
 pub enum SquashResult {
@@ -230,16 +298,30 @@ pub enum SquashResult {
     NoNetChanges,
 }
 
+/// Where squashing starts: the merge base of HEAD and `target_branch`
+fn squash_base(repo: &Repository, target_branch: &str) -> anyhow::Result<String> {
+    repo.merge_base("HEAD", target_branch)?.ok_or_else(|| {
+        GitError::Other {
+            message: cformat!(
+                "HEAD shares no history with <bold>{target_branch}</> (an orphan branch?), so there's no base to squash onto"
+            ),
+        }
+        .into()
+    })
+}
+
 /// Handle shared squash workflow (used by `wt step squash` and `wt merge`)
 ///
 /// # Arguments
 /// * `skip_pre_commit` - If true, skip all pre-commit hooks (both user and project)
 /// * `stage_mode` - What to stage before committing (All or Tracked; None not supported for squash)
+/// * `sign` - GPG-sign the squash commit
 pub fn handle_squash(
     target: Option<&str>,
     yes: bool,
     skip_pre_commit: bool,
     stage_mode: super::commit::StageMode,
+    sign: bool,
 ) -> anyhow::Result<SquashResult> {
     use super::commit::StageMode;
 
@@ -303,7 +385,7 @@ pub fn handle_squash(
     }
 
     // Get merge base with target branch
-    let merge_base = repo.merge_base("HEAD", &target_branch)?;
+    let merge_base = squash_base(repo, &target_branch)?;
 
     // Count commits since merge base
     let commit_count = repo.count_commits(&merge_base, "HEAD")?;
@@ -319,7 +401,7 @@ pub fn handle_squash(
 
     if commit_count == 0 && has_staged {
         // Just staged changes, no commits - commit them directly (no squashing needed)
-        generator.commit_staged_changes(true, stage_mode)?;
+        generator.commit_staged_changes(true, stage_mode, sign)?;
         return Ok(SquashResult::Squashed);
     }
 
@@ -417,7 +499,7 @@ pub fn handle_squash(
     }
 
     // Commit with the generated message
-    repo.run_command(&["commit", "-m", &commit_message])
+    repo.run_command(&super::commit::commit_args(&commit_message, sign))
         .context("Failed to create squash commit")?;
 
     // Get commit hash for display
@@ -450,7 +532,7 @@ pub fn step_show_squash_prompt(
     let current_branch = repo.current_branch()?.unwrap_or("HEAD");
 
     // Get merge base with target branch
-    let merge_base = repo.merge_base("HEAD", &target_branch)?;
+    let merge_base = squash_base(&repo, &target_branch)?;
 
     // Get commit subjects for the squash message
     let range = format!("{}..HEAD", merge_base);
@@ -484,7 +566,9 @@ pub enum RebaseResult {
 }
 
 /// Handle shared rebase workflow (used by `wt step rebase` and `wt merge`)
-pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
+///
+/// With `sign`, rebased commits are GPG-signed (`git rebase -S`).
+pub fn handle_rebase(target: Option<&str>, sign: bool) -> anyhow::Result<RebaseResult> {
     use super::repository_ext::RepositoryCliExt;
 
     let repo = Repository::current();
@@ -500,7 +584,7 @@ pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
     // Check if this is a fast-forward or true rebase
     let merge_base = repo.merge_base("HEAD", &target_branch)?;
     let head_sha = repo.run_command(&["rev-parse", "HEAD"])?.trim().to_string();
-    let is_fast_forward = merge_base == head_sha;
+    let is_fast_forward = merge_base.as_deref() == Some(head_sha.as_str());
 
     // Only show progress for true rebases (fast-forwards are instant)
     if !is_fast_forward {
@@ -509,7 +593,12 @@ pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
         )))?;
     }
 
-    let rebase_result = repo.run_command(&["rebase", &target_branch]);
+    let rebase_args: &[&str] = if sign {
+        &["rebase", "-S", &target_branch]
+    } else {
+        &["rebase", &target_branch]
+    };
+    let rebase_result = repo.run_command(rebase_args);
 
     // If rebase failed, check if it's due to conflicts
     if let Err(e) = rebase_result {
@@ -521,6 +610,7 @@ pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
             return Err(worktrunk::git::GitError::RebaseConflict {
                 target_branch: target_branch.clone(),
                 git_output,
+                in_merge_context: false,
             }
             .into());
         }
@@ -537,6 +627,7 @@ pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
         return Err(worktrunk::git::GitError::RebaseConflict {
             target_branch: target_branch.clone(),
             git_output: String::new(),
+            in_merge_context: false,
         }
         .into());
     }
@@ -626,14 +717,22 @@ pub fn clear_approvals(global: bool) -> anyhow::Result<()> {
 
     if global {
         // Clear all approvals for all projects
-        let project_count = config.projects.len();
+        let project_count = config
+            .projects
+            .values()
+            .filter(|p| !p.approved_commands.is_empty())
+            .count();
 
         if project_count == 0 {
             crate::output::print(info_message("No approvals to clear"))?;
             return Ok(());
         }
 
-        config.projects.clear();
+        // Registered repositories keep their entry (and path) without approvals
+        config.projects.retain(|_, p| p.path.is_some());
+        for project in config.projects.values_mut() {
+            project.approved_commands.clear();
+        }
         config.save().context("Failed to save config")?;
 
         crate::output::print(success_message(format!(
@@ -646,7 +745,10 @@ pub fn clear_approvals(global: bool) -> anyhow::Result<()> {
         let project_id = repo.project_identifier()?;
 
         // Check if project has any approvals
-        let had_approvals = config.projects.contains_key(project_id);
+        let had_approvals = config
+            .projects
+            .get(project_id)
+            .is_some_and(|p| !p.approved_commands.is_empty());
 
         if !had_approvals {
             crate::output::print(info_message("No approvals to clear for this project"))?;
@@ -683,15 +785,9 @@ pub fn handle_hook_show(hook_type_filter: Option<&str>, expanded: bool) -> anyho
     let project_id = repo.project_identifier().ok();
 
     // Parse hook type filter if provided
-    let filter: Option<HookType> = hook_type_filter.map(|s| match s {
-        "post-create" => HookType::PostCreate,
-        "post-start" => HookType::PostStart,
-        "post-switch" => HookType::PostSwitch,
-        "pre-commit" => HookType::PreCommit,
-        "pre-merge" => HookType::PreMerge,
-        "post-merge" => HookType::PostMerge,
-        "pre-remove" => HookType::PreRemove,
-        _ => unreachable!("clap validates hook type"),
+    let filter: Option<HookType> = hook_type_filter.map(|s| {
+        s.parse()
+            .unwrap_or_else(|_| unreachable!("clap validates hook type"))
     });
 
     // Build context for template expansion (only used if --expanded)
@@ -756,11 +852,14 @@ fn render_user_hooks(
     let hooks = [
         (HookType::PostCreate, &config.hooks.post_create),
         (HookType::PostStart, &config.hooks.post_start),
+        (HookType::PreSwitch, &config.hooks.pre_switch),
         (HookType::PostSwitch, &config.hooks.post_switch),
         (HookType::PreCommit, &config.hooks.pre_commit),
         (HookType::PreMerge, &config.hooks.pre_merge),
         (HookType::PostMerge, &config.hooks.post_merge),
+        (HookType::PrePush, &config.hooks.pre_push),
         (HookType::PreRemove, &config.hooks.pre_remove),
+        (HookType::PostRemove, &config.hooks.post_remove),
     ];
 
     let mut has_any = false;
@@ -816,11 +915,14 @@ fn render_project_hooks(
     let hooks = [
         (HookType::PostCreate, &config.hooks.post_create),
         (HookType::PostStart, &config.hooks.post_start),
+        (HookType::PreSwitch, &config.hooks.pre_switch),
         (HookType::PostSwitch, &config.hooks.post_switch),
         (HookType::PreCommit, &config.hooks.pre_commit),
         (HookType::PreMerge, &config.hooks.pre_merge),
         (HookType::PostMerge, &config.hooks.post_merge),
+        (HookType::PrePush, &config.hooks.pre_push),
         (HookType::PreRemove, &config.hooks.pre_remove),
+        (HookType::PostRemove, &config.hooks.post_remove),
     ];
 
     let mut has_any = false;
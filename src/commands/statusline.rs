@@ -137,7 +137,7 @@ pub fn run(claude_code: bool) -> Result<()> {
         (Path::new(&current_dir).to_path_buf(), model)
     } else {
         (
-            env::current_dir().context("Failed to get current directory")?,
+            worktrunk::git::working_dir().context("Failed to get current directory")?,
             None,
         )
     };
@@ -249,7 +249,19 @@ fn get_git_status(repo: &Repository, cwd: &Path) -> Result<Option<String>> {
         &mut item,
         &default_branch,
         &integration_target,
-        CollectOptions::default(),
+        CollectOptions {
+            ci: worktrunk::config::WorktrunkConfig::load()
+                .map(|config| config.ci())
+                .unwrap_or_default(),
+            // The statusline has nowhere to show it
+            skip_tasks: [
+                list::collect::TaskKind::Signature,
+                list::collect::TaskKind::Description,
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        },
     )?;
 
     // Return the pre-formatted statusline
@@ -0,0 +1,149 @@
+//! `wt sync`: bring the current branch up to date with its target.
+//!
+//! The strategy — rebase onto the target or merge it in — comes from
+//! `--strategy`, else `[sync]` config, where teams set a default and override
+//! it per branch pattern (`"release/*" = "merge"`). Conflicts stop with git's
+//! usual state in place, so they're resolved the way git would have them.
+//!
+//! `--rebase-onto-upstream` rebases onto the branch's upstream instead, for a
+//! branch that has diverged from it — typically a pull request branch that
+//! was updated from the web while local commits were made.
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{SyncStrategy, WorktrunkConfig};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{info_message, progress_message, success_message};
+
+use super::standalone::{RebaseResult, handle_rebase};
+
+/// Handle `wt sync [TARGET] [--strategy <rebase|merge>]`
+pub fn handle_sync(target: Option<&str>, strategy: Option<SyncStrategy>) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let branch = repo.require_current_branch("sync")?;
+    let strategy = match strategy {
+        Some(strategy) => strategy,
+        None => WorktrunkConfig::load()
+            .context("Failed to load config")?
+            .sync_strategy(&branch),
+    };
+
+    match strategy {
+        SyncStrategy::Rebase => {
+            if let RebaseResult::UpToDate(target_branch) = handle_rebase(target, false)? {
+                crate::output::print(info_message(cformat!(
+                    "Already up to date with <bold>{target_branch}</>"
+                )))?;
+            }
+            Ok(())
+        }
+        SyncStrategy::Merge => merge_target(&repo, target),
+    }
+}
+
+/// Merge the target into the current branch
+fn merge_target(repo: &Repository, target: Option<&str>) -> anyhow::Result<()> {
+    let target_branch = repo.resolve_target_branch(target)?;
+
+    if repo.is_ancestor(&target_branch, "HEAD")? {
+        crate::output::print(info_message(cformat!(
+            "Already up to date with <bold>{target_branch}</>"
+        )))?;
+        return Ok(());
+    }
+    let is_fast_forward = repo.is_ancestor("HEAD", &target_branch)?;
+
+    if !is_fast_forward {
+        crate::output::print(progress_message(cformat!(
+            "Merging <bold>{target_branch}</>..."
+        )))?;
+    }
+
+    if let Err(e) = repo.run_command(&["merge", "--no-edit", &target_branch]) {
+        if repo
+            .worktree_state()?
+            .is_some_and(|state| state == "MERGING")
+        {
+            return Err(GitError::MergeConflict {
+                target_branch,
+                git_output: e.to_string(),
+            }
+            .into());
+        }
+        return Err(GitError::Other {
+            message: format!("Failed to merge '{target_branch}': {e}"),
+        }
+        .into());
+    }
+
+    if is_fast_forward {
+        crate::output::print(success_message(cformat!(
+            "Fast-forwarded to <bold>{target_branch}</>"
+        )))?;
+    } else {
+        crate::output::print(success_message(cformat!("Merged <bold>{target_branch}</>")))?;
+    }
+    Ok(())
+}
+
+/// Handle `wt sync --rebase-onto-upstream [--branch <name>]`: rebase the
+/// branch onto its upstream, in the branch's worktree
+pub fn handle_sync_upstream(branch: Option<&str>) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let branch = match branch {
+        Some(name) => repo.resolve_worktree_name(name)?,
+        None => repo.require_current_branch("sync")?,
+    };
+    let Some(path) = repo.worktree_for_branch(&branch)? else {
+        return Err(GitError::NoWorktreeFound { branch }.into());
+    };
+    let worktree = Repository::at(&path);
+    let Some(upstream) = worktree.upstream_branch(&branch)? else {
+        return Err(GitError::Other {
+            message: cformat!("<bold>{branch}</> has no upstream branch"),
+        }
+        .into());
+    };
+
+    let (ahead, behind) = worktree.ahead_behind(&upstream, &branch)?;
+    if behind == 0 {
+        crate::output::print(info_message(cformat!(
+            "<bold>{branch}</> is already up to date with <bold>{upstream}</>"
+        )))?;
+        return Ok(());
+    }
+    worktree.ensure_clean_working_tree("rebase onto upstream", Some(&branch))?;
+
+    if ahead == 0 {
+        worktree.run_command(&["merge", "--ff-only", &upstream])?;
+        crate::output::print(success_message(cformat!(
+            "Fast-forwarded <bold>{branch}</> to <bold>{upstream}</>"
+        )))?;
+        return Ok(());
+    }
+
+    crate::output::print(progress_message(cformat!(
+        "Rebasing <bold>{branch}</> onto <bold>{upstream}</>..."
+    )))?;
+    if let Err(e) = worktree.run_command(&["rebase", &upstream]) {
+        if worktree
+            .worktree_state()?
+            .is_some_and(|state| state.starts_with("REBASING"))
+        {
+            return Err(GitError::RebaseConflict {
+                target_branch: upstream,
+                git_output: e.to_string(),
+                in_merge_context: false,
+            }
+            .into());
+        }
+        return Err(GitError::Other {
+            message: format!("Failed to rebase onto '{upstream}': {e}"),
+        }
+        .into());
+    }
+    crate::output::print(success_message(cformat!(
+        "Rebased <bold>{branch}</> onto <bold>{upstream}</>"
+    )))?;
+    Ok(())
+}
@@ -0,0 +1,210 @@
+//! `wt tidy`: report worktrees and branches that need attention, each with
+//! the command that deals with it.
+//!
+//! Nothing is changed; the report is for deciding what to run. Each branch
+//! lands in one category, the first that applies: merged, upstream gone, dirty
+//! but inactive, stale, then conflicting with the default branch.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use color_print::cformat;
+use rayon::prelude::*;
+use worktrunk::config::format_duration;
+use worktrunk::git::Repository;
+use worktrunk::styling::{format_with_gutter, info_message, success_message, suggest_command};
+
+const DAY: u64 = 24 * 60 * 60;
+
+/// Why a branch is in the report, in the order sections are printed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Category {
+    Merged,
+    UpstreamGone,
+    Stale,
+    DirtyInactive,
+    Conflicting,
+}
+
+/// A local branch other than the default, with its worktree if it has one
+struct Candidate {
+    branch: String,
+    head: String,
+    worktree: Option<PathBuf>,
+}
+
+struct Finding {
+    category: Category,
+    branch: String,
+    command: String,
+}
+
+/// Handle `wt tidy [--stale-after <age>]`
+pub fn handle_tidy(stale_after: Duration) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let default_branch = repo.default_branch()?;
+    let target = repo.effective_integration_target(&default_branch);
+    let candidates = candidates(&repo, &default_branch)?;
+    let gone = repo.branches_with_gone_upstream()?;
+    let heads: Vec<&str> = candidates.iter().map(|c| c.head.as_str()).collect();
+    let timestamps = repo.commit_timestamps(&heads)?;
+    let cutoff = worktrunk::utils::get_now() as i64 - stale_after.as_secs() as i64;
+
+    let findings: Vec<Option<Finding>> = candidates
+        .par_iter()
+        .map(|candidate| {
+            let dirty = match &candidate.worktree {
+                Some(path) => Repository::at(path).is_dirty()?,
+                None => false,
+            };
+            let inactive = timestamps
+                .get(&candidate.head)
+                .is_some_and(|&timestamp| timestamp < cutoff);
+            // A dirty worktree can't be removed as it is, so it's never
+            // suggested for removal
+            let category = if !dirty && repo.is_ancestor(&candidate.head, &target)? {
+                Category::Merged
+            } else if !dirty && gone.contains(&candidate.branch) {
+                Category::UpstreamGone
+            } else if dirty && inactive {
+                Category::DirtyInactive
+            } else if inactive {
+                Category::Stale
+            } else if repo.has_merge_conflicts(&default_branch, &candidate.head)? {
+                Category::Conflicting
+            } else {
+                return Ok(None);
+            };
+            Ok(Some(Finding {
+                category,
+                branch: candidate.branch.clone(),
+                command: command(category, candidate),
+            }))
+        })
+        .collect::<anyhow::Result<_>>()?;
+    let findings: Vec<Finding> = findings.into_iter().flatten().collect();
+
+    if findings.is_empty() {
+        crate::output::print(success_message("Nothing to tidy"))?;
+        return Ok(());
+    }
+
+    let age = format_age(stale_after);
+    let width = findings.iter().map(|f| f.branch.len()).max().unwrap_or(0);
+    let mut first = true;
+    for category in [
+        Category::Merged,
+        Category::UpstreamGone,
+        Category::Stale,
+        Category::DirtyInactive,
+        Category::Conflicting,
+    ] {
+        let lines: Vec<String> = findings
+            .iter()
+            .filter(|finding| finding.category == category)
+            .map(|finding| {
+                let pad = " ".repeat(width - finding.branch.len());
+                cformat!(
+                    "<bold>{}</>{pad}  <bright-black>{}</>",
+                    finding.branch,
+                    finding.command
+                )
+            })
+            .collect();
+        if lines.is_empty() {
+            continue;
+        }
+        if !first {
+            crate::output::blank()?;
+        }
+        first = false;
+        let title = match category {
+            Category::Merged => cformat!("Merged into <bold>{target}</>"),
+            Category::UpstreamGone => "Upstream branch deleted".to_string(),
+            Category::Stale => format!("No commits in {age}"),
+            Category::DirtyInactive => format!("Uncommitted changes, no commits in {age}"),
+            Category::Conflicting => cformat!("Would conflict with <bold>{default_branch}</>"),
+        };
+        crate::output::print(info_message(cformat!("{title} ({}):", lines.len())))?;
+        crate::output::print(format_with_gutter(&lines.join("\n"), None))?;
+    }
+    Ok(())
+}
+
+/// Branches to look at: those with worktrees, then those without. Skips the
+/// default branch, the main worktree, and the worktrees `wt remove --merged`
+/// leaves alone.
+fn candidates(repo: &Repository, default_branch: &str) -> anyhow::Result<Vec<Candidate>> {
+    let main_path = repo.primary_worktree()?.map(|wt| wt.path);
+    let mut checked_out = HashSet::new();
+    let mut candidates = Vec::new();
+    for wt in repo.list_worktrees()? {
+        let Some(branch) = wt.branch else { continue };
+        checked_out.insert(branch.clone());
+        if branch == default_branch
+            || wt.bare
+            || wt.locked.is_some()
+            || wt.prunable.is_some()
+            || Some(&wt.path) == main_path.as_ref()
+        {
+            continue;
+        }
+        candidates.push(Candidate {
+            branch,
+            head: wt.head,
+            worktree: Some(wt.path),
+        });
+    }
+    for (branch, head) in repo.list_local_branches()? {
+        if branch != default_branch && !checked_out.contains(&branch) {
+            candidates.push(Candidate {
+                branch,
+                head,
+                worktree: None,
+            });
+        }
+    }
+    Ok(candidates)
+}
+
+/// The command that resolves a finding
+fn command(category: Category, candidate: &Candidate) -> String {
+    let branch = candidate.branch.as_str();
+    match category {
+        // Removal deletes merged branches by itself
+        Category::Merged => suggest_command("remove", &[branch], &[]),
+        // Squash-merged branches aren't ancestors of the default branch
+        Category::UpstreamGone => suggest_command("remove", &[branch], &["-D"]),
+        // Removing only the worktree keeps the branch's commits
+        Category::Stale if candidate.worktree.is_some() => {
+            suggest_command("remove", &[branch], &[])
+        }
+        Category::Stale => suggest_command("remove", &[branch], &["-D"]),
+        Category::DirtyInactive => {
+            suggest_command("switch", &[branch], &["-x", "'wt step commit'"])
+        }
+        Category::Conflicting => suggest_command("switch", &[branch], &["-x", "'wt step rebase'"]),
+    }
+}
+
+/// An age like `30d`, or `12h` when it isn't whole days
+fn format_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    if secs > 0 && secs.is_multiple_of(DAY) {
+        format!("{}d", secs / DAY)
+    } else {
+        format_duration(age)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(Duration::from_secs(30 * DAY)), "30d");
+        assert_eq!(format_age(Duration::from_secs(12 * 60 * 60)), "12h");
+    }
+}
@@ -0,0 +1,155 @@
+//! `wt tmux`: a tmux session (or window) per worktree.
+//!
+//! Outside tmux, each worktree gets a session named after its branch, rooted
+//! at the worktree; running `wt tmux` again attaches to it. Inside tmux,
+//! switching sessions would hide the current one, so a window in the current
+//! session is used instead.
+//!
+//! With `switch.tmux = true`, `wt switch --create` sets one up in the
+//! background for every new worktree.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+use color_print::cformat;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::{GitError, Repository, ResolvedWorktree};
+use worktrunk::path::format_path_for_display;
+use worktrunk::shell_exec::run;
+use worktrunk::styling::{info_message, success_message, warning_message};
+
+use super::worktree::{ResolutionContext, resolve_worktree_arg};
+
+/// tmux target names can't contain `.` or `:` (they separate session, window,
+/// and pane), so replace those along with `/`
+fn tmux_name(branch: &str) -> String {
+    worktrunk::config::sanitize_branch_name(branch).replace(['.', ':'], "-")
+}
+
+fn run_tmux(args: &[&str]) -> anyhow::Result<Output> {
+    let mut cmd = Command::new("tmux");
+    cmd.args(args);
+    run(&mut cmd, None).map_err(|e| anyhow::anyhow!("failed to run tmux: {e}"))
+}
+
+/// Run tmux, failing with its stderr if it exits unsuccessfully
+fn run_tmux_checked(args: &[&str]) -> anyhow::Result<()> {
+    let output = run_tmux(args)?;
+    if output.status.success() {
+        return Ok(());
+    }
+    anyhow::bail!(
+        "tmux {} failed: {}",
+        args[0],
+        String::from_utf8_lossy(&output.stderr).trim()
+    )
+}
+
+/// Whether this process is running inside a tmux client
+fn inside_tmux() -> bool {
+    std::env::var_os("TMUX").is_some_and(|value| !value.is_empty())
+}
+
+fn kind() -> &'static str {
+    if inside_tmux() { "window" } else { "session" }
+}
+
+/// Create the worktree's session (or, inside tmux, window) in the background
+/// unless it exists, returning whether it was created
+fn ensure(name: &str, path: &Path) -> anyhow::Result<bool> {
+    let path = path.to_string_lossy();
+    if inside_tmux() {
+        let windows = run_tmux(&["list-windows", "-F", "#{window_name}"])?;
+        if String::from_utf8_lossy(&windows.stdout)
+            .lines()
+            .any(|window| window == name)
+        {
+            return Ok(false);
+        }
+        run_tmux_checked(&["new-window", "-d", "-n", name, "-c", &path])?;
+    } else {
+        if run_tmux(&["has-session", "-t", &format!("={name}")])?
+            .status
+            .success()
+        {
+            return Ok(false);
+        }
+        run_tmux_checked(&["new-session", "-d", "-s", name, "-c", &path])?;
+    }
+    Ok(true)
+}
+
+/// Bring the worktree's window forward, or attach to its session
+fn show(name: &str) -> anyhow::Result<()> {
+    if inside_tmux() {
+        return run_tmux_checked(&["select-window", "-t", &format!(":={name}")]);
+    }
+    // Attaching takes over the terminal, so it runs in place of wt
+    crate::output::execute(format!(
+        "tmux attach-session -t {}",
+        shell_escape::escape(format!("={name}").into())
+    ))
+}
+
+/// Handle `wt tmux [branch]`
+pub fn handle_tmux(branch: Option<&str>) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let config = WorktrunkConfig::load()?;
+    let (path, branch) = match resolve_worktree_arg(
+        &repo,
+        branch.unwrap_or("@"),
+        &config,
+        ResolutionContext::Remove,
+    )? {
+        ResolvedWorktree::Worktree { path, branch } => (path, branch),
+        ResolvedWorktree::BranchOnly { branch } => {
+            return Err(GitError::NoWorktreeFound { branch }.into());
+        }
+    };
+    // Detached worktrees go by their directory name
+    let name = tmux_name(&branch.unwrap_or_else(|| {
+        path.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }));
+    let kind = kind();
+    if ensure(&name, &path)? {
+        crate::output::print(success_message(cformat!(
+            "Created tmux {kind} <bold>{name}</> @ <bold>{}</>",
+            format_path_for_display(&path)
+        )))?;
+    } else {
+        crate::output::print(info_message(cformat!(
+            "Switching to tmux {kind} <bold>{name}</>"
+        )))?;
+    }
+    crate::output::flush()?;
+    show(&name)
+}
+
+/// Set up a background tmux session (or window) for a newly created worktree,
+/// for `switch.tmux`. Failures are warnings: the worktree is already there.
+pub fn create_for_new_worktree(branch: &str, path: &Path) -> anyhow::Result<()> {
+    let name = tmux_name(branch);
+    match ensure(&name, path) {
+        Ok(true) => crate::output::print(success_message(cformat!(
+            "Created tmux {} <bold>{name}</>",
+            kind()
+        )))?,
+        Ok(false) => {}
+        Err(e) => crate::output::print(warning_message(cformat!("Skipped tmux {}: {e}", kind())))?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tmux_name() {
+        assert_eq!(tmux_name("feature/auth"), "feature-auth");
+        assert_eq!(tmux_name("release-1.2"), "release-1-2");
+        assert_eq!(tmux_name("fix:colon"), "fix-colon");
+    }
+}
@@ -0,0 +1,221 @@
+//! Approved project commands.
+//!
+//! Project hooks prompt for approval before their first run (see
+//! `command_approval`); approvals are stored per project identifier in the
+//! user config. `wt trust` lists them with a short ID derived from a hash of
+//! each command and revokes them one at a time.
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{WorktrunkConfig, approval_id};
+use worktrunk::git::{GitError, Repository, WorktrunkError};
+use worktrunk::styling::{error_message, hint_message, info_message, success_message};
+
+/// One approved command
+#[derive(Debug, PartialEq)]
+struct Approval<'a> {
+    project: &'a str,
+    command: &'a str,
+    id: String,
+}
+
+/// Approvals for `project`, or for every project when it's `None`
+fn approvals<'a>(config: &'a WorktrunkConfig, project: Option<&str>) -> Vec<Approval<'a>> {
+    config
+        .projects
+        .iter()
+        .filter(|(id, _)| project.is_none_or(|project| project == id.as_str()))
+        .flat_map(|(id, project)| {
+            project.approved_commands.iter().map(|command| Approval {
+                project: id,
+                command,
+                id: approval_id(command),
+            })
+        })
+        .collect()
+}
+
+/// Find the approval `target` refers to: an exact command or ID, else a unique ID prefix
+fn find_approval<'a, 'b>(
+    approvals: &'b [Approval<'a>],
+    target: &str,
+) -> anyhow::Result<Option<&'b Approval<'a>>> {
+    let exact: Vec<_> = approvals
+        .iter()
+        .filter(|approval| approval.command == target || approval.id == target)
+        .collect();
+    let matches = if exact.is_empty() {
+        approvals
+            .iter()
+            .filter(|approval| approval.id.starts_with(target))
+            .collect()
+    } else {
+        exact
+    };
+    match matches.as_slice() {
+        [] => Ok(None),
+        [single] => Ok(Some(single)),
+        _ => {
+            let candidates: Vec<_> = matches
+                .iter()
+                .map(|approval| format!("{} ({})", approval.id, approval.project))
+                .collect();
+            Err(GitError::Other {
+                message: cformat!(
+                    "<bold>{target}</> matches several approvals: {}",
+                    candidates.join(", ")
+                ),
+            }
+            .into())
+        }
+    }
+}
+
+/// First line of a command, marking any that follow
+fn summarize(command: &str) -> String {
+    let mut lines = command.lines();
+    let first = lines.next().unwrap_or_default();
+    if lines.next().is_some() {
+        cformat!("{first} <dim>…</>")
+    } else {
+        first.to_string()
+    }
+}
+
+/// Handle `wt trust list`
+pub fn handle_trust_list(all: bool) -> anyhow::Result<()> {
+    let config = WorktrunkConfig::load().context("Failed to load config")?;
+    let repo = Repository::current();
+    let project = if all {
+        None
+    } else {
+        repo.project_identifier().ok()
+    };
+
+    let approvals = approvals(&config, project);
+    if approvals.is_empty() {
+        let message = if project.is_some() {
+            "No approved commands for this project"
+        } else {
+            "No approved commands"
+        };
+        crate::output::print(info_message(message))?;
+        return Ok(());
+    }
+
+    let mut lines = Vec::new();
+    let mut current = None;
+    for approval in &approvals {
+        // Group under a project heading when showing more than one project
+        if project.is_none() && current != Some(approval.project) {
+            lines.push(cformat!("<bold>{}</>", approval.project));
+            current = Some(approval.project);
+        }
+        let indent = if project.is_none() { "  " } else { "" };
+        lines.push(cformat!(
+            "{indent}<dim>{}</>  {}",
+            approval.id,
+            summarize(approval.command)
+        ));
+    }
+    crate::output::stdout(lines.join("\n"))?;
+    Ok(())
+}
+
+/// Handle `wt trust revoke <approval>`
+pub fn handle_trust_revoke(target: &str) -> anyhow::Result<()> {
+    let mut config = WorktrunkConfig::load().context("Failed to load config")?;
+    let repo = Repository::current();
+    let project = repo.project_identifier().ok();
+
+    // Prefer the current project, so an ID shared with another project is unambiguous here
+    let found = {
+        let local = approvals(&config, project);
+        match find_approval(&local, target)? {
+            Some(approval) => Some((approval.project.to_string(), approval.command.to_string())),
+            None => find_approval(&approvals(&config, None), target)?
+                .map(|approval| (approval.project.to_string(), approval.command.to_string())),
+        }
+    };
+    let Some((project, command)) = found else {
+        crate::output::print(error_message(cformat!(
+            "No approved command matches <bold>{target}</>"
+        )))?;
+        crate::output::print(hint_message(cformat!(
+            "To see approvals, run <bright-black>wt trust list --all</>"
+        )))?;
+        return Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into());
+    };
+
+    config
+        .revoke_command(&project, &command)
+        .context("Failed to save config")?;
+    crate::output::print(success_message(cformat!(
+        "Revoked approval for <bold>{}</> in <bold>{project}</>",
+        summarize(&command)
+    )))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use worktrunk::config::UserProjectConfig;
+
+    fn config_with(projects: &[(&str, &[&str])]) -> WorktrunkConfig {
+        let mut config = WorktrunkConfig::default();
+        for (id, commands) in projects {
+            config.projects.insert(
+                id.to_string(),
+                UserProjectConfig {
+                    approved_commands: commands.iter().map(|c| c.to_string()).collect(),
+                    path: None,
+                },
+            );
+        }
+        config
+    }
+
+    #[test]
+    fn test_find_approval_by_command_id_or_prefix() {
+        let config = config_with(&[("github.com/a/app", &["npm ci", "npm test"])]);
+        let approvals = approvals(&config, None);
+        let id = approval_id("npm test");
+
+        for target in ["npm test", id.as_str(), &id[..4]] {
+            let found = find_approval(&approvals, target).unwrap().unwrap();
+            assert_eq!(found.command, "npm test", "target: {target}");
+        }
+        assert_eq!(find_approval(&approvals, "cargo test").unwrap(), None);
+    }
+
+    #[test]
+    fn test_find_approval_ambiguous_across_projects() {
+        let config = config_with(&[
+            ("github.com/a/app", &["npm ci"]),
+            ("github.com/b/app", &["npm ci"]),
+        ]);
+        // The same command in two projects shares an ID
+        let err = find_approval(&approvals(&config, None), &approval_id("npm ci"))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("github.com/a/app") && err.contains("github.com/b/app"),
+            "{err}"
+        );
+        // Within one project it's unique
+        let local = approvals(&config, Some("github.com/b/app"));
+        let found = find_approval(&local, "npm ci").unwrap().unwrap();
+        assert_eq!(found.project, "github.com/b/app");
+    }
+
+    #[test]
+    fn test_approvals_filtered_by_project() {
+        let config = config_with(&[
+            ("github.com/a/app", &["npm ci"]),
+            ("github.com/b/web", &["make", "make test"]),
+        ]);
+        assert_eq!(approvals(&config, Some("github.com/b/web")).len(), 2);
+        assert_eq!(approvals(&config, None).len(), 3);
+    }
+}
@@ -0,0 +1,140 @@
+//! `wt workspace`: a VS Code multi-root workspace of every worktree.
+//!
+//! Writes a `.code-workspace` file whose folders are the repository's
+//! worktrees, named by branch, so the whole set can be opened in one window.
+//! Running it again refreshes the folders; other settings in the file (editor
+//! settings, recommended extensions) are kept.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use color_print::cformat;
+use serde_json::{Map, Value, json};
+use worktrunk::git::{GitError, Repository, Worktree, working_dir};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::success_message;
+
+/// Where the workspace file goes by default: beside the repository, where the
+/// default worktree-path template puts worktrees. A bare repository in a
+/// `.git` directory keeps it in the directory holding `.git`.
+fn default_path(repo: &Repository) -> anyhow::Result<PathBuf> {
+    let base = repo.worktree_base()?;
+    let name = base.file_name().map(|name| name.to_string_lossy());
+    let path = match name.as_deref() {
+        Some(".git") => {
+            let dir = base.parent().unwrap_or(&base);
+            dir.join(format!(
+                "{}.code-workspace",
+                worktrunk::git::path_dir_name(dir)
+            ))
+        }
+        Some(name) => {
+            let stem = name.strip_suffix(".git").unwrap_or(name);
+            base.with_file_name(format!("{stem}.code-workspace"))
+        }
+        None => base.join("workspace.code-workspace"),
+    };
+    Ok(path)
+}
+
+/// The workspace's `folders`: one per worktree, relative to the workspace
+/// file as VS Code writes them
+fn folders(worktrees: &[Worktree], workspace_dir: &Path) -> Vec<Value> {
+    worktrees
+        .iter()
+        .filter(|wt| !wt.bare && wt.prunable.is_none())
+        .map(|wt| {
+            let path = pathdiff::diff_paths(&wt.path, workspace_dir)
+                .filter(|path| !path.as_os_str().is_empty())
+                .unwrap_or_else(|| PathBuf::from("."));
+            json!({
+                "name": wt.branch.as_deref().unwrap_or(wt.dir_name()),
+                "path": path.to_string_lossy().replace('\\', "/"),
+            })
+        })
+        .collect()
+}
+
+/// Handle `wt workspace [--output <path>]`
+pub fn handle_workspace(output: Option<&Path>) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let path = match output {
+        Some(path) => working_dir()
+            .context("Failed to get current directory")?
+            .join(path),
+        None => default_path(&repo)?,
+    };
+    let workspace_dir = path.parent().unwrap_or(Path::new("."));
+    let workspace_dir = dunce::canonicalize(workspace_dir).unwrap_or(workspace_dir.to_path_buf());
+
+    let existing = match std::fs::read_to_string(&path) {
+        Ok(contents) => Some(serde_json::from_str::<Map<String, Value>>(&contents).map_err(
+            |e| GitError::Other {
+                message: cformat!(
+                    "Can't update <bold>{}</>; it isn't a JSON object ({e}). Comments and trailing commas aren't supported",
+                    format_path_for_display(&path)
+                ),
+            },
+        )?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => {
+            return Err(e).context(format!("Failed to read {}", path.display()));
+        }
+    };
+    let updating = existing.is_some();
+    let mut workspace = existing.unwrap_or_default();
+
+    let folders = folders(&repo.list_worktrees()?, &workspace_dir);
+    let count = folders.len();
+    workspace.insert("folders".into(), Value::Array(folders));
+
+    let contents = serde_json::to_string_pretty(&workspace)?;
+    std::fs::write(&path, contents + "\n")
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    let verb = if updating { "Updated" } else { "Wrote" };
+    let plural = if count == 1 { "" } else { "s" };
+    crate::output::print(success_message(cformat!(
+        "{verb} <bold>{}</> with {count} worktree{plural}",
+        format_path_for_display(&path)
+    )))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn worktree(path: &str, branch: Option<&str>) -> Worktree {
+        Worktree {
+            path: PathBuf::from(path),
+            head: String::new(),
+            branch: branch.map(String::from),
+            bare: false,
+            detached: branch.is_none(),
+            locked: None,
+            prunable: None,
+        }
+    }
+
+    #[test]
+    fn test_folders() {
+        let worktrees = [
+            worktree("/code/repo", Some("main")),
+            worktree("/code/repo.feature-auth", Some("feature/auth")),
+            worktree("/code/repo.scratch", None),
+        ];
+        assert_eq!(
+            folders(&worktrees, Path::new("/code")),
+            [
+                json!({"name": "main", "path": "repo"}),
+                json!({"name": "feature/auth", "path": "repo.feature-auth"}),
+                json!({"name": "repo.scratch", "path": "repo.scratch"}),
+            ]
+        );
+        assert_eq!(
+            folders(&worktrees[..1], Path::new("/code/repo")),
+            [json!({"name": "main", "path": "."})]
+        );
+    }
+}
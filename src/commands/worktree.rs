@@ -82,7 +82,7 @@ use anyhow::Context;
 use color_print::cformat;
 use dunce::canonicalize;
 use normalize_path::NormalizePath;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use worktrunk::HookType;
 use worktrunk::config::WorktrunkConfig;
 use worktrunk::git::{GitError, Repository, ResolvedWorktree};
@@ -134,7 +134,8 @@ pub enum ResolutionContext {
 /// 1. Special symbols ("@", "-", "^") are handled specially
 /// 2. Resolve argument as branch name
 /// 3. If branch has a worktree, return it
-/// 4. Otherwise, return branch-only (no worktree)
+/// 4. For `Remove`, an argument naming a worktree's directory resolves to it
+/// 5. Otherwise, return branch-only (no worktree)
 ///
 /// For `CreateOrSwitch` context: If the branch has no worktree but expected
 /// path is occupied by another branch's worktree, an error is raised.
@@ -177,6 +178,14 @@ pub fn resolve_worktree_arg(
         });
     }
 
+    // `wt remove ../repo.feature`: a path to a worktree, e.g. from completion
+    if context == ResolutionContext::Remove
+        && let Ok(path) = canonicalize(worktrunk::git::working_dir()?.join(name))
+        && let Some((path, branch)) = repo.worktree_at_path(&path)?
+    {
+        return Ok(ResolvedWorktree::Worktree { path, branch });
+    }
+
     // No worktree for branch - check if expected path is occupied (only for create/switch)
     if context == ResolutionContext::CreateOrSwitch {
         let expected_path = compute_worktree_path(repo, name, config)?;
@@ -209,6 +218,33 @@ pub fn compute_worktree_path(
     compute_worktree_path_with(repo, branch, config, &default_branch, is_bare)
 }
 
+/// Whether two worktree paths name the same directory, resolving symlinks where
+/// the paths exist
+fn worktree_paths_equal(a: &Path, b: &Path) -> bool {
+    let resolve = |path: &Path| canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    resolve(a) == resolve(b)
+}
+
+/// A branch name like `branch` (`feat/x-2`, `feat/x-3`, …) that doesn't exist
+/// yet and whose worktree path is free
+fn free_branch_name(
+    repo: &Repository,
+    branch: &str,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<Option<String>> {
+    for n in 2..10 {
+        let candidate = format!("{branch}-{n}");
+        if repo.branch_exists(&candidate)? {
+            continue;
+        }
+        let path = compute_worktree_path(repo, &candidate, config)?;
+        if !path.exists() && repo.worktree_at_path(&path)?.is_none() {
+            return Ok(Some(candidate));
+        }
+    }
+    Ok(None)
+}
+
 /// Check if a worktree is at its expected path based on config template.
 ///
 /// Returns true if the worktree's actual path matches what `compute_worktree_path`
@@ -285,8 +321,18 @@ fn compute_worktree_path_with(
             )
         })?;
 
+    // Skip the git call for templates that can't use it (most of them)
+    let user = if config.worktree_path.contains("user") {
+        repo.user_name().unwrap_or_default()
+    } else {
+        ""
+    };
     let relative_path = config
-        .format_path(repo_name, branch)
+        .format_path_with(
+            repo_name,
+            branch,
+            &[("default_branch", default_branch), ("user", user)],
+        )
         .map_err(|e| anyhow::anyhow!("Failed to format worktree path: {e}"))?;
 
     Ok(repo_root.join(relative_path).normalize())
@@ -365,6 +411,8 @@ pub struct SwitchBranchInfo {
     pub branch: String,
     /// Expected path when there's a path mismatch (None = path matches template)
     pub expected_path: Option<PathBuf>,
+    /// Short HEAD SHA when switching into a detached-HEAD worktree
+    pub detached_head: Option<String>,
 }
 
 impl SwitchBranchInfo {
@@ -439,10 +487,13 @@ pub enum RemoveResult {
     },
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_switch(
     branch: &str,
     create: bool,
     base: Option<&str>,
+    orphan: bool,
+    cherry_pick: Option<&str>,
     force: bool,
     clobber: bool,
     no_verify: bool,
@@ -462,6 +513,33 @@ pub fn handle_switch(
     // Record actual current branch as new "previous" for ping-pong behavior
     let new_previous = actual_current_branch;
 
+    // A path to a detached-HEAD worktree (e.g. picked in `wt select`) has no branch to look up
+    if !create
+        && let Ok(target) = canonicalize(worktrunk::git::working_dir()?.join(branch))
+        && let Some(wt) = repo
+            .list_worktrees()?
+            .into_iter()
+            .find(|wt| wt.detached && canonicalize(&wt.path).is_ok_and(|p| p == target))
+    {
+        let current_dir = std::env::current_dir()
+            .ok()
+            .and_then(|p| canonicalize(&p).ok());
+        let result = if current_dir.as_ref() == Some(&target) {
+            SwitchResult::AlreadyAt(target)
+        } else {
+            SwitchResult::Existing(target)
+        };
+        let _ = repo.record_switch_previous(new_previous);
+        return Ok((
+            result,
+            SwitchBranchInfo {
+                branch: "HEAD".to_string(),
+                expected_path: None,
+                detached_head: Some(wt.head[..8.min(wt.head.len())].to_string()),
+            },
+        ));
+    }
+
     // Resolve base if provided
     let resolved_base = if let Some(base_str) = base {
         Some(repo.resolve_worktree_name(base_str)?)
@@ -469,6 +547,19 @@ pub fn handle_switch(
         None
     };
 
+    // Check `--cherry-pick`'s revisions before creating anything
+    if let Some(range) = cherry_pick {
+        for rev in range.split("..").filter(|rev| !rev.is_empty()) {
+            let commit = format!("{rev}^{{commit}}");
+            if !repo.run_command_check(&["rev-parse", "--verify", "--quiet", &commit])? {
+                return Err(GitError::Other {
+                    message: cformat!("No commit <bold>{rev}</> to cherry-pick"),
+                }
+                .into());
+            }
+        }
+    }
+
     // Check for conflicting conditions
     if create && repo.local_branch_exists(&resolved_branch)? {
         return Err(GitError::BranchAlreadyExists {
@@ -529,6 +620,7 @@ pub fn handle_switch(
         let branch_info = SwitchBranchInfo {
             branch: resolved_branch.clone(),
             expected_path: path_mismatch,
+            detached_head: None,
         };
         (result, branch_info)
     };
@@ -556,6 +648,23 @@ pub fn handle_switch(
             return Err(GitError::WorktreeMissing { branch }.into());
         }
 
+        // Another branch that maps to the same path (`feat/x` vs `feat-x`)
+        if let Some(occupant) = path_branch.as_deref()
+            && worktree_paths_equal(
+                &compute_worktree_path(&repo, occupant, config)?,
+                &expected_path,
+            )
+        {
+            return Err(GitError::WorktreePathCollision {
+                alternative: free_branch_name(&repo, &resolved_branch, config)?,
+                branch: resolved_branch.clone(),
+                path: expected_path,
+                occupant: occupant.to_string(),
+                create,
+            }
+            .into());
+        }
+
         // Path is occupied by a different branch's worktree
         return Err(GitError::WorktreePathOccupied {
             branch: resolved_branch.clone(),
@@ -566,7 +675,7 @@ pub fn handle_switch(
     }
 
     // No existing worktree for branch or at expected path - will create one
-    let worktree_path = expected_path;
+    let worktree_path = expected_path.clone();
 
     // If the target path already exists but is NOT a worktree (e.g., stale directory),
     // either move it to .bak (with --clobber) or surface a helpful error.
@@ -613,7 +722,7 @@ pub fn handle_switch(
 
     // Use the resolved base, or default to default branch if creating without a base.
     // For bare repos with no branches yet (bootstrap case), allow None to create orphan branch.
-    let base_for_creation = if create {
+    let base_for_creation = if create && !orphan {
         match resolved_base {
             Some(b) => Some(b),
             None => repo.resolve_target_branch(None).ok(),
@@ -621,13 +730,20 @@ pub fn handle_switch(
     } else {
         None
     };
+    // `--orphan` starts from an empty root commit rather than an unborn
+    // branch, so the worktree has a HEAD like any other
+    let orphan_root = if orphan {
+        Some(empty_root_commit(&repo, &resolved_branch)?)
+    } else {
+        None
+    };
 
     // Build args based on whether we're creating or checking out
     if create {
         args.push("-b");
         args.push(&resolved_branch);
-        if let Some(ref base_branch) = base_for_creation {
-            args.push(base_branch);
+        if let Some(start) = base_for_creation.as_ref().or(orphan_root.as_ref()) {
+            args.push(start);
         }
     } else {
         args.push(&resolved_branch);
@@ -635,7 +751,25 @@ pub fn handle_switch(
 
     // Create worktree and parse specific error cases
     if let Err(e) = repo.run_command(&args) {
-        let msg = e.to_string();
+        let Some(msg) = GitError::git_stderr(&e).map(str::to_owned) else {
+            return Err(e);
+        };
+        // The branch is checked out in a worktree the lookup above missed:
+        // go there instead. Older git says "is already checked out at '<path>'",
+        // newer "is already used by worktree at '<path>'".
+        if let Some(path) = msg
+            .lines()
+            .find(|line| {
+                line.contains("is already checked out at")
+                    || line.contains("is already used by worktree at")
+            })
+            .and_then(|line| line.rsplit('\'').nth(1))
+            .map(PathBuf::from)
+            .filter(|path| path.exists())
+        {
+            let _ = repo.record_switch_previous(new_previous);
+            return Ok(switch_to_existing(path));
+        }
         // Check if error is about directory already existing
         if msg.contains("already exists") {
             // Parse the path from git's error message
@@ -660,6 +794,7 @@ pub fn handle_switch(
         // Format: "fatal: invalid reference: branch-name"
         if msg.contains("invalid reference:") {
             return Err(GitError::InvalidReference {
+                similar: similar_branches(&repo, &resolved_branch),
                 reference: resolved_branch.clone(),
             }
             .into());
@@ -683,6 +818,45 @@ pub fn handle_switch(
         None
     };
 
+    // A tag or bare commit as base has no branch to compare against later, so
+    // remember it for `wt list`'s ahead/behind. Clear any value left by an
+    // earlier branch of the same name.
+    if create {
+        let recorded_base = base_for_creation
+            .as_deref()
+            .and_then(|base| non_branch_base(&repo, base));
+        repo.set_branch_base(&resolved_branch, recorded_base.as_deref())?;
+    }
+
+    // Bring `--cherry-pick`'s commits onto the new branch before hooks run,
+    // so they see them
+    if let Some(range) = cherry_pick {
+        cherry_pick_onto(&worktree_path, &resolved_branch, range)?;
+    }
+
+    // New worktrees start with empty submodule directories (and LFS pointer
+    // files, without git-lfs's filters); fill them before post-create hooks,
+    // which may need them
+    if config
+        .switch
+        .as_ref()
+        .and_then(|s| s.init_submodules)
+        .unwrap_or(false)
+    {
+        init_submodules(&worktree_path)?;
+    }
+    if config
+        .switch
+        .as_ref()
+        .and_then(|s| s.lfs_pull)
+        .unwrap_or(false)
+    {
+        pull_lfs(&worktree_path)?;
+    }
+    if config.switch.as_ref().and_then(|s| s.tmux).unwrap_or(false) {
+        super::tmux::create_for_new_worktree(&resolved_branch, &worktree_path)?;
+    }
+
     // Execute post-create commands (sequential, blocking)
     // Note: If user declines, continue anyway - worktree already created
     if !no_verify {
@@ -696,7 +870,7 @@ pub fn handle_switch(
             force,
         );
         // Approval was handled at the gate
-        ctx.execute_post_create_commands()?;
+        ctx.execute_post_create_commands(base_for_creation.as_deref())?;
     }
 
     // Note: post-start commands are spawned AFTER success message is shown
@@ -715,10 +889,230 @@ pub fn handle_switch(
         SwitchBranchInfo {
             branch: resolved_branch,
             expected_path: None, // Created at expected path by definition
+            detached_head: None,
         },
     ))
 }
 
+/// Apply `--cherry-pick`'s commits in a new worktree. If the cherry-pick
+/// stops (conflicts, or a commit the base already has), it's left in
+/// progress for the user to finish there.
+fn cherry_pick_onto(worktree_path: &Path, branch: &str, range: &str) -> anyhow::Result<()> {
+    let worktree = Repository::at(worktree_path);
+    let start = worktree.run_command(&["rev-parse", "HEAD"])?;
+    let result = worktree.run_command(&["cherry-pick", range]);
+    let picked: usize = worktree
+        .run_command(&["rev-list", "--count", &format!("{}..HEAD", start.trim())])?
+        .trim()
+        .parse()
+        .unwrap_or(0);
+    let commits = if picked == 1 { "commit" } else { "commits" };
+
+    let Err(e) = result else {
+        crate::output::print(success_message(cformat!(
+            "Cherry-picked <bold>{picked}</> {commits} from <bold>{range}</> onto <bold>{branch}</>"
+        )))?;
+        return Ok(());
+    };
+    let in_progress =
+        worktree.run_command_check(&["rev-parse", "--verify", "--quiet", "CHERRY_PICK_HEAD"])?;
+    let Some(stderr) = GitError::git_stderr(&e).filter(|_| in_progress) else {
+        return Err(e);
+    };
+    crate::output::print(warning_message(cformat!(
+        "Cherry-pick of <bold>{range}</> stopped after <bold>{picked}</> {commits}"
+    )))?;
+    crate::output::print(format_with_gutter(stderr.trim(), None))?;
+    crate::output::print(hint_message(cformat!(
+        "To finish, resolve it in the new worktree, then run <bright-black>git cherry-pick --continue</>"
+    )))?;
+    Ok(())
+}
+
+/// Commit an empty tree with no parents, to start an orphan branch from
+fn empty_root_commit(repo: &Repository, branch: &str) -> anyhow::Result<String> {
+    // `git mktree` with no input writes the empty tree
+    let tree = repo.run_command(&["mktree"])?;
+    let message = format!("Start {branch}");
+    let commit = repo.run_command(&["commit-tree", tree.trim(), "-m", &message])?;
+    Ok(commit.trim().to_string())
+}
+
+/// The base to record for a new branch when it isn't itself a branch: the tag
+/// name for a tag, or the commit SHA for any other committish (`HEAD~3`).
+fn non_branch_base(repo: &Repository, base: &str) -> Option<String> {
+    let full_name = repo
+        .run_command(&["rev-parse", "--symbolic-full-name", base])
+        .ok()?;
+    let full_name = full_name.trim();
+    if full_name.starts_with("refs/heads/") || full_name.starts_with("refs/remotes/") {
+        None
+    } else if let Some(tag) = full_name.strip_prefix("refs/tags/") {
+        Some(tag.to_string())
+    } else {
+        repo.run_command(&["rev-parse", "--verify", &format!("{base}^{{commit}}")])
+            .ok()
+            .map(|sha| sha.trim().to_string())
+    }
+}
+
+/// Run `git submodule update --init --recursive` in a new worktree
+fn init_submodules(worktree_path: &Path) -> anyhow::Result<()> {
+    if !worktree_path.join(".gitmodules").exists() {
+        return Ok(());
+    }
+    run_setup_step(
+        worktree_path,
+        "Initializing submodules...",
+        "initialize submodules",
+        &[&["submodule", "update", "--init", "--recursive"]],
+    )
+}
+
+/// Fetch and check out Git LFS content in a new worktree, which otherwise
+/// holds pointer files when git-lfs's filters aren't set up for this clone
+fn pull_lfs(worktree_path: &Path) -> anyhow::Result<()> {
+    if !uses_lfs(worktree_path) {
+        return Ok(());
+    }
+    run_setup_step(
+        worktree_path,
+        "Pulling LFS objects...",
+        "pull LFS objects",
+        &[&["lfs", "install", "--local"], &["lfs", "pull"]],
+    )
+}
+
+/// Whether the worktree's `.gitattributes` routes any paths through Git LFS.
+///
+/// Only the top-level file is checked; that's where `git lfs track` writes.
+pub fn uses_lfs(worktree_path: &Path) -> bool {
+    std::fs::read_to_string(worktree_path.join(".gitattributes"))
+        .is_ok_and(|attributes| attributes.contains("filter=lfs"))
+}
+
+/// Run git commands that finish setting up a new worktree.
+///
+/// A failure is a warning: the worktree exists either way, and the commands
+/// can be rerun by hand.
+fn run_setup_step(
+    worktree_path: &Path,
+    progress: &str,
+    action: &str,
+    commands: &[&[&str]],
+) -> anyhow::Result<()> {
+    crate::output::print(progress_message(progress))?;
+    let worktree_repo = Repository::at(worktree_path);
+    for args in commands {
+        if let Err(e) = worktree_repo.run_command(args) {
+            crate::output::print(warning_message(format!("Failed to {action}")))?;
+            crate::output::print(format_with_gutter(&e.to_string(), None))?;
+            crate::output::print(hint_message(cformat!(
+                "To retry, run <bright-black>git {}</> in the new worktree",
+                args.join(" ")
+            )))?;
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Local branches whose names are close to `reference`, closest first
+fn similar_branches(repo: &Repository, reference: &str) -> Vec<String> {
+    let mut scored: Vec<_> = repo
+        .all_branches()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|branch| (strsim::jaro_winkler(reference, &branch), branch))
+        .filter(|(score, _)| *score > 0.8)
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    scored
+        .into_iter()
+        .take(3)
+        .map(|(_, branch)| branch)
+        .collect()
+}
+
+/// Branches of worktrees that `wt remove --merged` removes: those whose HEAD
+/// is in the default branch's history (or its upstream's, when that's ahead).
+///
+/// The main worktree, the default branch, and locked worktrees are never
+/// included.
+pub fn merged_worktree_branches(repo: &Repository) -> anyhow::Result<Vec<String>> {
+    let default_branch = repo.default_branch()?;
+    let target = repo.effective_integration_target(&default_branch);
+    let main_path = repo.primary_worktree()?.map(|wt| wt.path);
+    let mut branches = Vec::new();
+    for wt in repo.list_worktrees()? {
+        let Some(branch) = wt.branch else { continue };
+        if branch == default_branch || wt.locked.is_some() || Some(&wt.path) == main_path.as_ref() {
+            continue;
+        }
+        if repo.is_ancestor(&wt.head, &target)? {
+            branches.push(branch);
+        }
+    }
+    Ok(branches)
+}
+
+/// Branches of the worktrees whose branch matches `pattern` (a glob), for
+/// `wt remove --pattern`. Skips the same worktrees as `--merged`.
+pub fn pattern_worktree_branches(repo: &Repository, pattern: &str) -> anyhow::Result<Vec<String>> {
+    let glob = glob::Pattern::new(pattern).with_context(|| format!("Invalid pattern {pattern}"))?;
+    let default_branch = repo.default_branch().unwrap_or_default();
+    let main_path = repo.primary_worktree()?.map(|wt| wt.path);
+    Ok(repo
+        .list_worktrees()?
+        .into_iter()
+        .filter(|wt| wt.locked.is_none() && Some(&wt.path) != main_path.as_ref())
+        .filter_map(|wt| wt.branch)
+        .filter(|branch| *branch != default_branch && glob.matches(branch))
+        .collect())
+}
+
+/// List the worktrees a bulk `wt remove` selected, e.g. "merged" or "matching
+/// <glob>"
+pub fn print_bulk_removal(
+    repo: &Repository,
+    branches: &[String],
+    description: &str,
+) -> anyhow::Result<()> {
+    let count = branches.len();
+    let plural = if count == 1 { "" } else { "s" };
+    let lines: Vec<String> = branches
+        .iter()
+        .map(|branch| {
+            let path = repo
+                .worktree_for_branch(branch)
+                .ok()
+                .flatten()
+                .map(|path| worktrunk::path::format_path_for_display(&path))
+                .unwrap_or_default();
+            cformat!("<bold>{branch}</>  <bright-black>{path}</>")
+        })
+        .collect();
+    crate::output::print(info_message(cformat!(
+        "<bold>{count}</> worktree{plural} {description}:"
+    )))?;
+    crate::output::print(format_with_gutter(&lines.join("\n"), None))?;
+    Ok(())
+}
+
+/// Ask once before a bulk `wt remove`; `wt --yes` answers for the user
+pub fn confirm_bulk_removal() -> anyhow::Result<bool> {
+    use std::io::IsTerminal;
+
+    if !super::command_approval::assume_yes() && !std::io::stdin().is_terminal() {
+        anyhow::bail!("Cannot confirm removal in a non-interactive environment; add --yes");
+    }
+    let confirmed = super::configure_shell::prompt_yes_no().map_err(anyhow::Error::msg)?;
+    if !confirmed {
+        crate::output::print(info_message("Nothing removed"))?;
+    }
+    Ok(confirmed)
+}
+
 pub fn handle_remove(
     worktree_name: &str,
     no_delete_branch: bool,
@@ -814,9 +1208,18 @@ pub fn handle_remove_by_path(
 impl<'a> CommandContext<'a> {
     /// Execute post-create commands sequentially (blocking)
     ///
-    /// Runs user hooks first, then project hooks.
-    pub fn execute_post_create_commands(&self) -> anyhow::Result<()> {
+    /// Runs user hooks first, then project hooks. `base` is the branch the
+    /// worktree was created from, if it was created with a new branch.
+    pub fn execute_post_create_commands(&self, base: Option<&str>) -> anyhow::Result<()> {
         let project_config = self.repo.load_project_config()?;
+        let extra_vars: Vec<_> = base.into_iter().map(|base| ("base", base)).collect();
+        super::link::create_links(
+            self,
+            self.config.hooks.post_create.as_ref(),
+            project_config
+                .as_ref()
+                .and_then(|c| c.hooks.post_create.as_ref()),
+        )?;
         super::hooks::run_hook_with_filter(
             self,
             self.config.hooks.post_create.as_ref(),
@@ -824,7 +1227,7 @@ impl<'a> CommandContext<'a> {
                 .as_ref()
                 .and_then(|c| c.hooks.post_create.as_ref()),
             HookType::PostCreate,
-            &[],
+            &extra_vars,
             HookFailureStrategy::Warn,
             None,
             None, // No path display - running in expected directory
@@ -832,8 +1235,11 @@ impl<'a> CommandContext<'a> {
     }
 
     /// Spawn post-start commands in parallel as background processes (non-blocking)
-    pub fn spawn_post_start_commands(&self) -> anyhow::Result<()> {
+    ///
+    /// `base` is as for [`Self::execute_post_create_commands`].
+    pub fn spawn_post_start_commands(&self, base: Option<&str>) -> anyhow::Result<()> {
         let project_config = self.repo.load_project_config()?;
+        let extra_vars: Vec<_> = base.into_iter().map(|base| ("base", base)).collect();
 
         let commands = prepare_hook_commands(
             self,
@@ -842,7 +1248,7 @@ impl<'a> CommandContext<'a> {
                 .as_ref()
                 .and_then(|c| c.hooks.post_start.as_ref()),
             HookType::PostStart,
-            &[],
+            &extra_vars,
             None,
             None, // No path display - running in expected directory
         )?;
@@ -850,6 +1256,31 @@ impl<'a> CommandContext<'a> {
         spawn_hook_commands_background(self, commands, HookType::PostStart)
     }
 
+    /// Execute pre-switch commands sequentially (blocking, fail-fast)
+    ///
+    /// Runs user hooks first, then project hooks, in the worktree being left.
+    /// `target` is the branch being switched to; a failure aborts the switch.
+    pub fn execute_pre_switch_commands(
+        &self,
+        target: &str,
+        name_filter: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let project_config = self.repo.load_project_config()?;
+        super::hooks::run_hook_with_filter(
+            self,
+            self.config.hooks.pre_switch.as_ref(),
+            project_config
+                .as_ref()
+                .and_then(|c| c.hooks.pre_switch.as_ref()),
+            HookType::PreSwitch,
+            &[("target", target)],
+            HookFailureStrategy::FailFast,
+            name_filter,
+            None, // No path display - running in expected directory
+        )
+        .map_err(worktrunk::git::add_hook_skip_hint)
+    }
+
     /// Spawn post-switch commands in parallel as background processes (non-blocking)
     ///
     /// Runs on every switch, including to existing worktrees and newly created ones.
@@ -870,6 +1301,80 @@ impl<'a> CommandContext<'a> {
 
         spawn_hook_commands_background(self, commands, HookType::PostSwitch)
     }
+
+    /// Spawn post-remove commands in parallel as background processes (non-blocking)
+    ///
+    /// Runs in the worktree the user lands in after removal; `branch` is the
+    /// removed worktree's branch.
+    pub fn spawn_post_remove_commands(&self) -> anyhow::Result<()> {
+        let project_config = self.repo.load_project_config()?;
+
+        let commands = prepare_hook_commands(
+            self,
+            self.config.hooks.post_remove.as_ref(),
+            project_config
+                .as_ref()
+                .and_then(|c| c.hooks.post_remove.as_ref()),
+            HookType::PostRemove,
+            &[],
+            None,
+            None, // No path display - running in expected directory
+        )?;
+
+        spawn_hook_commands_background(self, commands, HookType::PostRemove)
+    }
+}
+
+/// Commits between `target_branch` and HEAD without a good signature, or
+/// (when `allowed_keys` isn't empty) signed by a key not in it, formatted as
+/// `<short sha> <subject> (<reason>)`
+fn unsigned_commits(
+    repo: &Repository,
+    target_branch: &str,
+    allowed_keys: &[String],
+) -> anyhow::Result<Vec<String>> {
+    // %G? is G (good) or U (good, unknown validity) for a valid signature;
+    // %GK and %GF are the signing key's ID and fingerprint
+    let log = repo.run_command(&[
+        "log",
+        "--format=%h%x00%G?%x00%GK%x00%GF%x00%s",
+        &format!("{target_branch}..HEAD"),
+    ])?;
+    // Whole IDs only: a suffix of a fingerprint is a short key ID, which can be forged.
+    // GPG IDs are hex (any case, maybe spaced); SSH `SHA256:` fingerprints are case-sensitive.
+    let same = |allowed: &str, actual: &str| {
+        let allowed: String = allowed.split_whitespace().collect();
+        !allowed.is_empty()
+            && if allowed.starts_with("SHA256:") {
+                allowed == actual
+            } else {
+                allowed.eq_ignore_ascii_case(actual)
+            }
+    };
+    let allowed = |key: &str, fingerprint: &str| {
+        allowed_keys
+            .iter()
+            .any(|allowed| same(allowed, key) || same(allowed, fingerprint))
+    };
+    Ok(log
+        .lines()
+        .filter_map(|line| {
+            let [sha, status, key, fingerprint, subject] =
+                line.splitn(5, '\0').collect::<Vec<_>>()[..]
+            else {
+                return None;
+            };
+            let reason = match status {
+                "G" | "U" if allowed_keys.is_empty() || allowed(key, fingerprint) => return None,
+                "G" | "U" => format!("signed by {key}, not an allowed key"),
+                "N" => "not signed".to_string(),
+                "B" => "bad signature".to_string(),
+                "E" => "signature can't be checked".to_string(),
+                _ => "expired or revoked signature".to_string(),
+            };
+            Some(format!("{sha} {subject} ({reason})"))
+        })
+        .collect())
 }
 
 /// Push changes to target branch
@@ -918,12 +1423,34 @@ pub fn handle_push(
         .into());
     }
 
+    // Refuse unsigned commits before touching the target worktree
+    if let Some(push) = WorktrunkConfig::load()?.push
+        && push.require_signed == Some(true)
+    {
+        let commits = unsigned_commits(
+            &repo,
+            &target_branch,
+            push.allowed_keys.as_deref().unwrap_or_default(),
+        )?;
+        if !commits.is_empty() {
+            return Err(GitError::UnsignedCommits {
+                target_branch,
+                commits,
+            }
+            .into());
+        }
+    }
+
     // Check for conflicting changes in target worktree (auto-stash safe changes)
     let mut target_worktree_stash =
         repo.prepare_target_worktree(target_worktree_path.as_ref(), &target_branch)?;
 
     // Count commits and show what will be pushed
     let commit_count = repo.count_commits(&target_branch, "HEAD")?;
+    crate::output::report::record(|report| {
+        report.target = Some(target_branch.clone());
+        report.commits = Some(commit_count);
+    });
 
     // Get diff statistics BEFORE push (will be needed for success message later)
     let stats_summary = if commit_count > 0 {
@@ -1016,6 +1543,12 @@ pub fn handle_push(
     if let Some(stash) = target_worktree_stash.take() {
         stash.restore()?;
     }
+    if commit_count > 0 {
+        worktrunk::op_log::record(
+            "branch_updated",
+            serde_json::json!({ "branch": target_branch, "commits": commit_count }),
+        );
+    }
 
     // Show success message after push completes
     if commit_count > 0 {
@@ -2,12 +2,12 @@ use std::cell::RefCell;
 use std::ffi::{OsStr, OsString};
 use std::io::Write;
 
-use clap::Command;
+use clap::{Command, ValueEnum};
 use clap_complete::engine::{ArgValueCompleter, CompletionCandidate, ValueCompleter};
 use clap_complete::env::CompleteEnv;
 
-use crate::cli;
 use crate::display::format_relative_time_short;
+use crate::{alias, cli};
 use worktrunk::config::{ProjectConfig, WorktrunkConfig};
 use worktrunk::git::{BranchCategory, HookType, Repository};
 
@@ -52,11 +52,14 @@ pub fn maybe_handle_env_completion() -> bool {
     // Determine the index of the word being completed.
     // - Bash/Zsh: Pass `_CLAP_COMPLETE_INDEX` env var with the cursor position
     // - Fish: Appends the current token as the last argument, so index = len - 1
-    let index: usize = std::env::var("_CLAP_COMPLETE_INDEX")
+    let mut index: usize = std::env::var("_CLAP_COMPLETE_INDEX")
         .ok()
         .and_then(|i| i.parse().ok())
         .unwrap_or_else(|| args.len() - 1);
 
+    // Complete `wt ls --<TAB>` as if the alias's expansion had been typed
+    alias::expand_completion_args(&mut args, &mut index);
+
     // Check if the current word is exactly "-" (single dash)
     // If so, we want to show both short flags (-h) AND long flags (--help)
     // clap only returns matches for the prefix, so we call complete twice
@@ -123,6 +126,13 @@ pub fn maybe_handle_env_completion() -> bool {
             let _ = write!(stdout, "{}", separator);
         }
         let value = candidate.get_value().to_string_lossy();
+        // zsh's `_describe` splits each entry at the first unescaped colon, so
+        // a value like `db:migrate` would lose everything after `db`
+        let value = if shell_name == "zsh" {
+            value.replace(':', "\\:").into()
+        } else {
+            value
+        };
         match (help_sep, candidate.get_help()) {
             (Some(sep), Some(help)) => {
                 let _ = write!(stdout, "{}{}{}", value, sep, help);
@@ -137,11 +147,68 @@ pub fn maybe_handle_env_completion() -> bool {
     true
 }
 
-/// Branch completion without additional context filtering (e.g., --base, merge target).
+/// Shells that get completions but not full shell integration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CompletionShell {
+    Elvish,
+    Xonsh,
+}
+
+/// Completion definitions for `shell`, which call back into `cmd` with
+/// `COMPLETE=<shell>` for dynamic candidates (branches, hook names, ...).
+///
+/// Like the fish completions, they find `cmd` in PATH, with `WORKTRUNK_BIN`
+/// as an optional override.
+pub fn completion_script(shell: CompletionShell, cmd: &str) -> String {
+    match shell {
+        CompletionShell::Elvish => format!(
+            r#"# worktrunk completions for elvish
+set edit:completion:arg-completer[{cmd}] = {{|@words|
+    var bin = {cmd}
+    if (has-env WORKTRUNK_BIN) {{ set bin = $E:WORKTRUNK_BIN }}
+    var index = (- (count $words) 1)
+    env _CLAP_IFS="\n" _CLAP_COMPLETE_INDEX=(to-string $index) COMPLETE=elvish $bin -- $@words | from-lines
+}}
+"#
+        ),
+        CompletionShell::Xonsh => {
+            let function = format!(
+                "_{}_completer",
+                cmd.replace(|c: char| !c.is_alphanumeric(), "_")
+            );
+            format!(
+                r#"# worktrunk completions for xonsh
+from xonsh.completers.completer import add_one_completer
+from xonsh.completers.tools import contextual_command_completer_for
+
+
+@contextual_command_completer_for("{cmd}")
+def {function}(command):
+    import subprocess
+
+    words = [arg.value for arg in command.args[: command.arg_index]] + [command.prefix]
+    env = dict(__xonsh__.env.detype())
+    env.update(COMPLETE="xonsh", _CLAP_IFS="\n", _CLAP_COMPLETE_INDEX=str(command.arg_index))
+    bin = env.get("WORKTRUNK_BIN", "{cmd}")
+    output = subprocess.run(
+        [bin, "--", *words], env=env, capture_output=True, text=True
+    ).stdout
+    return {{line for line in output.splitlines() if line}}, len(command.prefix)
+
+
+add_one_completer("{cmd}", {function}, "start")
+"#
+            )
+        }
+    }
+}
+
+/// Branch completion without additional context filtering (e.g., --base, push target).
 pub fn branch_value_completer() -> ArgValueCompleter {
     ArgValueCompleter::new(BranchCompleter {
         suppress_with_create: false,
-        exclude_remote_only: false,
+        worktrees_only: false,
+        include_paths: false,
     })
 }
 
@@ -150,18 +217,97 @@ pub fn branch_value_completer() -> ArgValueCompleter {
 pub fn worktree_branch_completer() -> ArgValueCompleter {
     ArgValueCompleter::new(BranchCompleter {
         suppress_with_create: true,
-        exclude_remote_only: false,
+        worktrees_only: false,
+        include_paths: false,
+    })
+}
+
+/// Branch completion for merge targets - only branches checked out in a worktree.
+pub fn checked_out_branch_completer() -> ArgValueCompleter {
+    ArgValueCompleter::new(BranchCompleter {
+        suppress_with_create: false,
+        worktrees_only: true,
+        include_paths: false,
     })
 }
 
-/// Branch completion for remove command - excludes remote-only branches.
-pub fn local_branches_completer() -> ArgValueCompleter {
+/// Worktree completion for remove - branches with worktrees, or worktree
+/// paths once the word looks like a path (starts with `.` or `/`).
+pub fn worktree_completer() -> ArgValueCompleter {
     ArgValueCompleter::new(BranchCompleter {
         suppress_with_create: false,
-        exclude_remote_only: true,
+        worktrees_only: true,
+        include_paths: true,
     })
 }
 
+/// Registered repository completion for `--repo` and `wt repo remove`.
+pub fn repo_name_completer() -> ArgValueCompleter {
+    ArgValueCompleter::new(RepoNameCompleter)
+}
+
+#[derive(Clone, Copy)]
+struct RepoNameCompleter;
+
+impl ValueCompleter for RepoNameCompleter {
+    fn complete(&self, current: &OsStr) -> Vec<CompletionCandidate> {
+        let Ok(config) = WorktrunkConfig::load() else {
+            return Vec::new();
+        };
+        let prefix = current.to_string_lossy();
+        config
+            .registered_repos()
+            .filter(|(id, _)| id.starts_with(&*prefix))
+            .map(|(id, path)| {
+                CompletionCandidate::new(id).help(Some(path.display().to_string().into()))
+            })
+            .collect()
+    }
+}
+
+/// Config key completion for `wt config set <key>`.
+pub fn config_key_completer() -> ArgValueCompleter {
+    ArgValueCompleter::new(ConfigKeyCompleter)
+}
+
+#[derive(Clone, Copy)]
+struct ConfigKeyCompleter;
+
+impl ValueCompleter for ConfigKeyCompleter {
+    fn complete(&self, current: &OsStr) -> Vec<CompletionCandidate> {
+        let prefix = current.to_string_lossy();
+        worktrunk::config::settable_keys()
+            .into_iter()
+            .filter(|key| key.starts_with(&*prefix))
+            .map(CompletionCandidate::new)
+            .collect()
+    }
+}
+
+/// Config value completion for `wt config set <key> <value>`, for keys with a
+/// fixed set of values.
+pub fn config_value_completer() -> ArgValueCompleter {
+    ArgValueCompleter::new(ConfigValueCompleter)
+}
+
+#[derive(Clone, Copy)]
+struct ConfigValueCompleter;
+
+impl ValueCompleter for ConfigValueCompleter {
+    fn complete(&self, current: &OsStr) -> Vec<CompletionCandidate> {
+        let Some(key) = CONTEXT.with(|ctx| ctx.borrow().as_ref().and_then(|ctx| ctx.config_key()))
+        else {
+            return Vec::new();
+        };
+        let prefix = current.to_string_lossy();
+        worktrunk::config::setting_values(&key)
+            .into_iter()
+            .filter(|value| value.starts_with(&*prefix))
+            .map(CompletionCandidate::new)
+            .collect()
+    }
+}
+
 /// Hook command name completion for `wt step <hook-type> <name>`.
 /// Completes with command names from the project config for the hook type being invoked.
 pub fn hook_command_name_completer() -> ArgValueCompleter {
@@ -196,26 +342,14 @@ fn complete_hook_commands() -> Vec<CompletionCandidate> {
     let hook_type = CONTEXT.with(|ctx| {
         ctx.borrow().as_ref().and_then(|ctx| {
             // Look for the hook subcommand in the args
-            for hook in &[
-                "post-create",
-                "post-start",
-                "pre-commit",
-                "pre-merge",
-                "post-merge",
-                "pre-remove",
-            ] {
-                if ctx.contains(hook) {
-                    return Some(*hook);
-                }
-            }
-            None
+            HookType::value_variants()
+                .iter()
+                .copied()
+                .find(|hook| ctx.contains(&hook.to_string()))
         })
     });
 
-    let Some(hook_type_str) = hook_type else {
-        return Vec::new();
-    };
-    let Ok(hook_type) = hook_type_str.parse::<HookType>() else {
+    let Some(hook_type) = hook_type else {
         return Vec::new();
     };
 
@@ -255,7 +389,8 @@ fn complete_hook_commands() -> Vec<CompletionCandidate> {
 #[derive(Clone, Copy)]
 struct BranchCompleter {
     suppress_with_create: bool,
-    exclude_remote_only: bool,
+    worktrees_only: bool,
+    include_paths: bool,
 }
 
 impl ValueCompleter for BranchCompleter {
@@ -267,7 +402,10 @@ impl ValueCompleter for BranchCompleter {
 
         // Filter branches by prefix - clap doesn't filter ArgValueCompleter results
         let prefix = current.to_string_lossy();
-        complete_branches(self.suppress_with_create, self.exclude_remote_only)
+        if self.include_paths && (prefix.starts_with('.') || prefix.starts_with('/')) {
+            return complete_worktree_paths(&prefix);
+        }
+        complete_branches(self.suppress_with_create, self.worktrees_only)
             .into_iter()
             .filter(|candidate| {
                 candidate
@@ -279,10 +417,7 @@ impl ValueCompleter for BranchCompleter {
     }
 }
 
-fn complete_branches(
-    suppress_with_create: bool,
-    exclude_remote_only: bool,
-) -> Vec<CompletionCandidate> {
+fn complete_branches(suppress_with_create: bool, worktrees_only: bool) -> Vec<CompletionCandidate> {
     if suppress_with_create && suppress_switch_branch_completion() {
         return Vec::new();
     }
@@ -298,9 +433,7 @@ fn complete_branches(
 
     branches
         .into_iter()
-        .filter(|branch| {
-            !exclude_remote_only || !matches!(branch.category, BranchCategory::Remote(_))
-        })
+        .filter(|branch| !worktrees_only || matches!(branch.category, BranchCategory::Worktree))
         .map(|branch| {
             let time_str = format_relative_time_short(branch.timestamp);
             let help = match branch.category {
@@ -313,6 +446,38 @@ fn complete_branches(
         .collect()
 }
 
+/// Worktree paths starting with `prefix`: absolute when it is, otherwise
+/// relative to the current directory
+fn complete_worktree_paths(prefix: &str) -> Vec<CompletionCandidate> {
+    let Ok(worktrees) = Repository::current().list_worktrees() else {
+        return Vec::new();
+    };
+    let cwd = std::env::current_dir().ok();
+    worktrees
+        .into_iter()
+        .filter(|wt| !wt.bare)
+        .filter_map(|wt| {
+            let path = if prefix.starts_with('/') {
+                wt.path.display().to_string()
+            } else {
+                let relative = pathdiff::diff_paths(&wt.path, cwd.as_deref()?)?;
+                let relative = relative.display().to_string();
+                if relative.starts_with("..") {
+                    relative
+                } else if relative.is_empty() {
+                    ".".to_string()
+                } else {
+                    format!("./{relative}")
+                }
+            };
+            path.starts_with(prefix).then(|| {
+                let help = wt.branch.unwrap_or_else(|| "(detached)".to_string());
+                CompletionCandidate::new(path).help(Some(help.into()))
+            })
+        })
+        .collect()
+}
+
 fn suppress_switch_branch_completion() -> bool {
     CONTEXT.with(|ctx| {
         ctx.borrow()
@@ -331,6 +496,14 @@ impl CompletionContext {
             .iter()
             .any(|arg| arg.to_string_lossy().as_ref() == needle)
     }
+
+    /// The key argument of `wt config set <key>`
+    fn config_key(&self) -> Option<String> {
+        let args: Vec<_> = self.args.iter().map(|arg| arg.to_string_lossy()).collect();
+        args.windows(3)
+            .find(|window| window[0] == "config" && window[1] == "set")
+            .map(|window| window[2].to_string())
+    }
 }
 
 // Thread-local context tracking is required because clap's ValueCompleter::complete()
@@ -342,10 +515,22 @@ thread_local! {
 }
 
 fn completion_command() -> Command {
-    let cmd = cli::build_command();
+    let cmd = add_alias_subcommands(cli::build_command());
     hide_non_positional_options_for_completion(cmd)
 }
 
+/// Offer configured aliases alongside built-in subcommands, described by their expansion.
+fn add_alias_subcommands(mut cmd: Command) -> Command {
+    for (name, expansion) in alias::load_aliases() {
+        if cmd.find_subcommand(&name).is_none() {
+            // clap wants `'static` names; completion is a one-shot process
+            let name: &'static str = name.leak();
+            cmd = cmd.subcommand(Command::new(name).about(format!("Alias for `{expansion}`")));
+        }
+    }
+    cmd
+}
+
 /// Hide non-positional options so they're filtered out when positional/subcommand
 /// completions exist, but still shown when completing `--<TAB>`.
 ///
@@ -3,9 +3,24 @@
 //! Handles parsing and representation of commands that run during various phases
 //! of worktree and merge operations.
 
-use indexmap::IndexMap;
+use std::fmt;
+use std::time::Duration;
+
+use serde::de::{self, MapAccess, Visitor};
 use serde::{Deserialize, Serialize};
 
+/// What happens when a hook command fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum OnFailure {
+    /// Stop the operation (the default for pre-* hooks)
+    Abort,
+    /// Print a warning and continue (the default for post-create and post-merge)
+    Warn,
+    /// Continue silently
+    Ignore,
+}
+
 /// Represents a command with its template and optionally expanded form
 #[derive(Debug, Clone, PartialEq)]
 pub struct Command {
@@ -15,6 +30,16 @@ pub struct Command {
     pub template: String,
     /// Expanded command with variables substituted (same as template if not expanded yet)
     pub expanded: String,
+    /// Stop the command once it has run this long (`timeout = "60s"`)
+    pub timeout: Option<Duration>,
+    /// Overrides how the hook handles this command failing (`on-failure = "warn"`)
+    pub on_failure: Option<OnFailure>,
+    /// Run detached once the hook's other commands succeed (`background = true`)
+    pub background: bool,
+    /// Only run for branches matching one of these globs (`branches = ["ui/*"]`)
+    pub branches: Vec<String>,
+    /// Never run for branches matching one of these globs (`exclude = ["main"]`)
+    pub exclude: Vec<String>,
 }
 
 impl Command {
@@ -24,6 +49,11 @@ impl Command {
             name,
             expanded: template.clone(),
             template,
+            timeout: None,
+            on_failure: None,
+            background: false,
+            branches: Vec::new(),
+            exclude: Vec::new(),
         }
     }
 
@@ -33,25 +63,118 @@ impl Command {
             name,
             template,
             expanded,
+            timeout: None,
+            on_failure: None,
+            background: false,
+            branches: Vec::new(),
+            exclude: Vec::new(),
+        }
+    }
+
+    /// Whether the entry sets options beyond its command
+    fn has_options(&self) -> bool {
+        self.timeout.is_some()
+            || self.on_failure.is_some()
+            || self.background
+            || !self.branches.is_empty()
+            || !self.exclude.is_empty()
+    }
+
+    /// Whether the entry's `branches` and `exclude` scope includes `branch`
+    ///
+    /// A detached HEAD (`None`) is only in scope when `branches` is unset.
+    pub fn applies_to(&self, branch: Option<&str>) -> bool {
+        let matches = |patterns: &[String], branch: &str| {
+            patterns.iter().any(|pattern| {
+                glob::Pattern::new(pattern).is_ok_and(|pattern| pattern.matches(branch))
+            })
+        };
+        match branch {
+            Some(branch) => {
+                (self.branches.is_empty() || matches(&self.branches, branch))
+                    && !matches(&self.exclude, branch)
+            }
+            None => self.branches.is_empty(),
+        }
+    }
+}
+
+/// How a [`Link`] shares a path with the primary worktree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum LinkKind {
+    /// A symbolic link (a junction for directories on Windows without symlink rights)
+    Symlink,
+    /// A copy whose files are hard links, falling back to plain copies across filesystems
+    Hardlink,
+}
+
+/// A path shared from the primary worktree into new worktrees
+/// (`symlink = ["node_modules"]` in `[post-create]`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Link {
+    pub kind: LinkKind,
+    /// Path relative to the worktree root
+    pub path: String,
+}
+
+/// Parse a duration like `"500ms"`, `"30s"`, `"5m"`, or `"1h"`
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration {value:?}; expected e.g. \"30s\" or \"5m\""))?;
+    let millis = match unit.trim() {
+        "ms" => 1,
+        "s" => 1000,
+        "m" => 60 * 1000,
+        "h" => 60 * 60 * 1000,
+        _ => {
+            return Err(format!(
+                "invalid duration {value:?}; expected a unit of ms, s, m, or h"
+            ));
+        }
+    };
+    Ok(Duration::from_millis(number.saturating_mul(millis)))
+}
+
+/// Format a duration in the largest unit [`parse_duration`] reads back exactly
+pub fn format_duration(duration: Duration) -> String {
+    let millis = duration.as_millis();
+    for (unit, size) in [("h", 60 * 60 * 1000), ("m", 60 * 1000), ("s", 1000)] {
+        if millis > 0 && millis.is_multiple_of(size) {
+            return format!("{}{unit}", millis / size);
         }
     }
+    format!("{millis}ms")
 }
 
 /// Configuration for commands - canonical representation
 ///
 /// Internally stores commands as `Vec<Command>` for uniform processing.
-/// Deserializes from two TOML formats:
+/// Deserializes from three TOML formats:
 /// - Single string: `post-create = "npm install"`
 /// - Named table: `[post-create]` followed by `install = "npm install"`
+/// - Named table with options: `[post-create.install]` followed by
+///   `command = "npm install"`, `timeout = "5m"`, `on-failure = "warn"`,
+///   `background = true`, `branches = ["ui/*"]`, `exclude = ["main"]`
+///
+/// In a named table, `symlink` and `hardlink` given a list of paths (rather
+/// than a command string) are [`Link`]s instead of commands.
 ///
 /// **Order preservation:** Named commands preserve TOML insertion order (requires
-/// `preserve_order` feature on toml crate and IndexMap for deserialization). This
-/// allows users to control execution order explicitly.
+/// `preserve_order` feature on toml crate). This allows users to control
+/// execution order explicitly.
 ///
 /// This canonical form eliminates branching at call sites - code just iterates over commands.
 #[derive(Debug, Clone, PartialEq)]
 pub struct CommandConfig {
     commands: Vec<Command>,
+    links: Vec<Link>,
 }
 
 impl CommandConfig {
@@ -59,35 +182,194 @@ impl CommandConfig {
     pub fn commands(&self) -> &[Command] {
         &self.commands
     }
+
+    /// The config with only the commands whose branch scope includes `branch`
+    pub fn scoped_to(&self, branch: Option<&str>) -> Self {
+        Self {
+            commands: self
+                .commands
+                .iter()
+                .filter(|command| command.applies_to(branch))
+                .cloned()
+                .collect(),
+            links: self.links.clone(),
+        }
+    }
+
+    /// Paths to link from the primary worktree, in declaration order
+    pub fn links(&self) -> &[Link] {
+        &self.links
+    }
 }
 
-// Custom deserialization to handle 2 TOML formats
-impl<'de> Deserialize<'de> for CommandConfig {
+/// One named entry: a command string, a table with the command and its
+/// options, or a list of paths (for `symlink` and `hardlink`)
+enum CommandEntry {
+    Command(Command),
+    Paths(Vec<String>),
+}
+
+/// Read a list of branch globs, rejecting any that don't parse
+fn branch_patterns<'de, A: MapAccess<'de>>(map: &mut A) -> Result<Vec<String>, A::Error> {
+    let patterns = map.next_value::<Vec<String>>()?;
+    for pattern in &patterns {
+        glob::Pattern::new(pattern).map_err(|err| {
+            de::Error::custom(format!("invalid branch pattern {pattern:?}: {err}"))
+        })?;
+    }
+    Ok(patterns)
+}
+
+/// Check a link path stays inside the worktree
+fn check_link_path(path: &str) -> Result<(), String> {
+    let relative = std::path::Path::new(path);
+    let escapes = relative.components().any(|component| {
+        !matches!(
+            component,
+            std::path::Component::Normal(_) | std::path::Component::CurDir
+        )
+    });
+    if path.is_empty() || escapes {
+        return Err(format!(
+            "invalid link path {path:?}; paths must be relative to the worktree, without `..`"
+        ));
+    }
+    Ok(())
+}
+
+impl<'de> Deserialize<'de> for CommandEntry {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum CommandConfigToml {
-            Single(String),
-            Named(IndexMap<String, String>),
+        struct EntryVisitor;
+
+        impl<'de> Visitor<'de> for EntryVisitor {
+            type Value = CommandEntry;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a command string, a table with `command`, or a list of paths")
+            }
+
+            fn visit_str<E: de::Error>(self, template: &str) -> Result<CommandEntry, E> {
+                Ok(CommandEntry::Command(Command::new(
+                    None,
+                    template.to_string(),
+                )))
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<CommandEntry, A::Error> {
+                let mut paths = Vec::new();
+                while let Some(path) = seq.next_element::<String>()? {
+                    check_link_path(&path).map_err(de::Error::custom)?;
+                    paths.push(path);
+                }
+                Ok(CommandEntry::Paths(paths))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<CommandEntry, A::Error> {
+                const FIELDS: &[&str] = &[
+                    "command",
+                    "timeout",
+                    "on-failure",
+                    "background",
+                    "branches",
+                    "exclude",
+                ];
+                let mut template = None;
+                let mut timeout = None;
+                let mut on_failure = None;
+                let mut background = false;
+                let mut branches = Vec::new();
+                let mut exclude = Vec::new();
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "command" => template = Some(map.next_value::<String>()?),
+                        "timeout" => {
+                            let value = map.next_value::<String>()?;
+                            timeout = Some(parse_duration(&value).map_err(de::Error::custom)?);
+                        }
+                        "on-failure" | "on_failure" => {
+                            let value = map.next_value::<String>()?;
+                            on_failure = Some(value.parse::<OnFailure>().map_err(|_| {
+                                de::Error::invalid_value(
+                                    de::Unexpected::Str(&value),
+                                    &"\"abort\", \"warn\", or \"ignore\"",
+                                )
+                            })?);
+                        }
+                        "background" => background = map.next_value::<bool>()?,
+                        "branches" => branches = branch_patterns(&mut map)?,
+                        "exclude" => exclude = branch_patterns(&mut map)?,
+                        other => return Err(de::Error::unknown_field(other, FIELDS)),
+                    }
+                }
+                let template = template.ok_or_else(|| de::Error::missing_field("command"))?;
+                let mut command = Command::new(None, template);
+                command.timeout = timeout;
+                command.on_failure = on_failure;
+                command.background = background;
+                command.branches = branches;
+                command.exclude = exclude;
+                Ok(CommandEntry::Command(command))
+            }
         }
 
-        let toml = CommandConfigToml::deserialize(deserializer)?;
-        let commands = match toml {
-            CommandConfigToml::Single(cmd) => {
+        deserializer.deserialize_any(EntryVisitor)
+    }
+}
+
+// Custom deserialization to handle the TOML formats
+impl<'de> Deserialize<'de> for CommandConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ConfigVisitor;
+
+        impl<'de> Visitor<'de> for ConfigVisitor {
+            type Value = CommandConfig;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a command string or a table of named commands")
+            }
+
+            fn visit_str<E: de::Error>(self, template: &str) -> Result<CommandConfig, E> {
                 // Phase will be set later when commands are collected
-                vec![Command::new(None, cmd)]
+                Ok(CommandConfig {
+                    commands: vec![Command::new(None, template.to_string())],
+                    links: Vec::new(),
+                })
             }
-            CommandConfigToml::Named(map) => {
-                // IndexMap preserves insertion order from TOML
-                map.into_iter()
-                    .map(|(name, template)| Command::new(Some(name), template))
-                    .collect()
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<CommandConfig, A::Error> {
+                // Entries arrive in TOML insertion order
+                let mut commands = Vec::new();
+                let mut links = Vec::new();
+                while let Some((name, entry)) = map.next_entry::<String, CommandEntry>()? {
+                    match entry {
+                        CommandEntry::Command(mut command) => {
+                            command.name = Some(name);
+                            commands.push(command);
+                        }
+                        CommandEntry::Paths(paths) => {
+                            let kind = name.parse::<LinkKind>().map_err(|_| {
+                                de::Error::custom(format!(
+                                    "`{name}` is a list; only `symlink` and `hardlink` take a list of paths"
+                                ))
+                            })?;
+                            links.extend(paths.into_iter().map(|path| Link { kind, path }));
+                        }
+                    }
+                }
+                Ok(CommandConfig { commands, links })
             }
-        };
-        Ok(CommandConfig { commands })
+        }
+
+        deserializer.deserialize_any(ConfigVisitor)
     }
 }
 
@@ -100,15 +382,62 @@ impl Serialize for CommandConfig {
         use serde::ser::SerializeMap;
 
         // If single unnamed command, serialize as string
-        if self.commands.len() == 1 && self.commands[0].name.is_none() {
+        if self.commands.len() == 1 && self.commands[0].name.is_none() && self.links.is_empty() {
             return self.commands[0].template.serialize(serializer);
         }
 
         // Serialize as named map (all commands from Named format have names)
-        let mut map = serializer.serialize_map(Some(self.commands.len()))?;
+        let mut map = serializer.serialize_map(None)?;
+        for kind in [LinkKind::Symlink, LinkKind::Hardlink] {
+            let paths: Vec<_> = self
+                .links
+                .iter()
+                .filter(|link| link.kind == kind)
+                .map(|link| link.path.as_str())
+                .collect();
+            if !paths.is_empty() {
+                map.serialize_entry(&kind.to_string(), &paths)?;
+            }
+        }
         for cmd in &self.commands {
             let key = cmd.name.as_ref().unwrap();
-            map.serialize_entry(key, &cmd.template)?;
+            if cmd.has_options() {
+                map.serialize_entry(key, &EntryOptions(cmd))?;
+            } else {
+                map.serialize_entry(key, &cmd.template)?;
+            }
+        }
+        map.end()
+    }
+}
+
+/// An entry with options, serialized as a table
+struct EntryOptions<'a>(&'a Command);
+
+impl Serialize for EntryOptions<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let cmd = self.0;
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("command", &cmd.template)?;
+        if let Some(timeout) = cmd.timeout {
+            map.serialize_entry("timeout", &format_duration(timeout))?;
+        }
+        if let Some(on_failure) = cmd.on_failure {
+            map.serialize_entry("on-failure", &on_failure.to_string())?;
+        }
+        if cmd.background {
+            map.serialize_entry("background", &true)?;
+        }
+        if !cmd.branches.is_empty() {
+            map.serialize_entry("branches", &cmd.branches)?;
+        }
+        if !cmd.exclude.is_empty() {
+            map.serialize_entry("exclude", &cmd.exclude)?;
         }
         map.end()
     }
@@ -228,6 +557,7 @@ third = "echo 3"
 
         let wrapper = Wrapper {
             cmd: CommandConfig {
+                links: Vec::new(),
                 commands: vec![Command::new(None, "npm install".to_string())],
             },
         };
@@ -246,6 +576,7 @@ third = "echo 3"
 
         let wrapper = Wrapper {
             cmd: CommandConfig {
+                links: Vec::new(),
                 commands: vec![
                     Command::new(Some("build".to_string()), "cargo build".to_string()),
                     Command::new(Some("test".to_string()), "cargo test".to_string()),
@@ -263,6 +594,7 @@ third = "echo 3"
     #[test]
     fn test_serialize_deserialize_roundtrip_single() {
         let config = CommandConfig {
+            links: Vec::new(),
             commands: vec![Command::new(None, "echo hello".to_string())],
         };
 
@@ -282,6 +614,7 @@ third = "echo 3"
     #[test]
     fn test_serialize_deserialize_roundtrip_named() {
         let config = CommandConfig {
+            links: Vec::new(),
             commands: vec![
                 Command::new(Some("a".to_string()), "echo a".to_string()),
                 Command::new(Some("b".to_string()), "echo b".to_string()),
@@ -307,6 +640,7 @@ third = "echo 3"
     #[test]
     fn test_commands_returns_slice() {
         let config = CommandConfig {
+            links: Vec::new(),
             commands: vec![
                 Command::new(None, "cmd1".to_string()),
                 Command::new(None, "cmd2".to_string()),
@@ -322,11 +656,215 @@ third = "echo 3"
     #[test]
     fn test_command_config_equality() {
         let config1 = CommandConfig {
+            links: Vec::new(),
             commands: vec![Command::new(None, "test".to_string())],
         };
         let config2 = CommandConfig {
+            links: Vec::new(),
             commands: vec![Command::new(None, "test".to_string())],
         };
         assert_eq!(config1, config2);
     }
+
+    // ============================================================================
+    // Entry Options Tests
+    // ============================================================================
+
+    #[test]
+    fn test_deserialize_entry_options() {
+        let toml_str = r#"
+[command]
+build = "cargo build"
+
+[command.install]
+command = "npm ci"
+timeout = "5m"
+on-failure = "warn"
+
+[command.containers]
+command = "docker compose build"
+background = true
+"#;
+
+        #[derive(Deserialize)]
+        struct Wrapper {
+            command: CommandConfig,
+        }
+
+        let wrapper: Wrapper = toml::from_str(toml_str).unwrap();
+        let commands = wrapper.command.commands();
+        assert_eq!(
+            commands[0],
+            Command::new(Some("build".into()), "cargo build".into())
+        );
+        assert_eq!(commands[1].name.as_deref(), Some("install"));
+        assert_eq!(commands[1].template, "npm ci");
+        assert_eq!(commands[1].timeout, Some(Duration::from_secs(300)));
+        assert_eq!(commands[1].on_failure, Some(OnFailure::Warn));
+        assert!(!commands[1].background);
+        assert!(commands[2].background);
+        assert_eq!(commands[2].timeout, None);
+    }
+
+    #[test]
+    fn test_deserialize_entry_errors() {
+        #[derive(Debug, Deserialize)]
+        struct Wrapper {
+            #[allow(dead_code)]
+            command: CommandConfig,
+        }
+
+        for (entry, expected) in [
+            ("timeout = \"1m\"", "missing field `command`"),
+            ("command = \"x\"\ntimeout = \"soon\"", "invalid duration"),
+            (
+                "command = \"x\"\non-failure = \"retry\"",
+                "\"abort\", \"warn\", or \"ignore\"",
+            ),
+            ("command = \"x\"\ntimout = \"1m\"", "unknown field `timout`"),
+            ("command = \"x\"\nbackground = \"yes\"", "invalid type"),
+            (
+                "command = \"x\"\nbranches = [\"ui/[\"]",
+                "invalid branch pattern",
+            ),
+        ] {
+            let toml_str = format!("[command.install]\n{entry}\n");
+            let err = toml::from_str::<Wrapper>(&toml_str)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected), "{entry}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_deserialize_links() {
+        #[derive(Serialize, Deserialize)]
+        struct Wrapper {
+            command: CommandConfig,
+        }
+
+        let toml_str = r#"
+[command]
+symlink = ["node_modules", "packages/web/.next"]
+install = "npm ci"
+hardlink = ["target"]
+"#;
+        let wrapper: Wrapper = toml::from_str(toml_str).unwrap();
+        assert_eq!(wrapper.command.commands().len(), 1);
+        assert_eq!(
+            wrapper.command.links(),
+            [
+                Link {
+                    kind: LinkKind::Symlink,
+                    path: "node_modules".into()
+                },
+                Link {
+                    kind: LinkKind::Symlink,
+                    path: "packages/web/.next".into()
+                },
+                Link {
+                    kind: LinkKind::Hardlink,
+                    path: "target".into()
+                },
+            ]
+        );
+        let roundtrip: Wrapper = toml::from_str(&toml::to_string(&wrapper).unwrap()).unwrap();
+        assert_eq!(roundtrip.command, wrapper.command);
+
+        // A string `symlink` is an ordinary command named "symlink"
+        let wrapper: Wrapper = toml::from_str("[command]\nsymlink = \"ln -s a b\"\n").unwrap();
+        assert!(wrapper.command.links().is_empty());
+        assert_eq!(
+            wrapper.command.commands()[0].name.as_deref(),
+            Some("symlink")
+        );
+
+        for (entry, expected) in [
+            ("symlink = [\"../shared\"]", "invalid link path"),
+            ("symlink = [\"/opt/cache\"]", "invalid link path"),
+            (
+                "install = [\"npm\", \"ci\"]",
+                "only `symlink` and `hardlink`",
+            ),
+        ] {
+            let err = toml::from_str::<Wrapper>(&format!("[command]\n{entry}\n"))
+                .err()
+                .unwrap()
+                .to_string();
+            assert!(err.contains(expected), "{entry}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_serialize_entry_options_roundtrip() {
+        #[derive(Serialize, Deserialize)]
+        struct Wrapper {
+            cmd: CommandConfig,
+        }
+
+        let mut install = Command::new(Some("install".into()), "npm ci".into());
+        install.timeout = Some(Duration::from_secs(90));
+        install.on_failure = Some(OnFailure::Ignore);
+        let mut build = Command::new(Some("build".into()), "make".into());
+        build.background = true;
+        build.branches = vec!["ui/*".into()];
+        build.exclude = vec!["ui/legacy".into()];
+        let wrapper = Wrapper {
+            cmd: CommandConfig {
+                links: Vec::new(),
+                commands: vec![install.clone(), build.clone()],
+            },
+        };
+
+        let serialized = toml::to_string(&wrapper).unwrap();
+        assert!(serialized.contains("timeout = \"90s\""), "{serialized}");
+        assert!(
+            serialized.contains("on-failure = \"ignore\""),
+            "{serialized}"
+        );
+        assert!(serialized.contains("background = true"), "{serialized}");
+        assert!(serialized.contains("branches = [\"ui/*\"]"), "{serialized}");
+        let deserialized: Wrapper = toml::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.cmd.commands(), [install, build]);
+    }
+
+    #[test]
+    fn test_command_applies_to() {
+        let mut command = Command::new(Some("deps".into()), "npm ci".into());
+        assert!(command.applies_to(Some("main")));
+        assert!(command.applies_to(None));
+
+        command.branches = vec!["ui/*".into(), "web".into()];
+        assert!(command.applies_to(Some("ui/button")));
+        assert!(command.applies_to(Some("web")));
+        assert!(!command.applies_to(Some("api/auth")));
+        assert!(!command.applies_to(None));
+
+        command.exclude = vec!["ui/legacy-*".into()];
+        assert!(command.applies_to(Some("ui/button")));
+        assert!(!command.applies_to(Some("ui/legacy-form")));
+
+        command.branches.clear();
+        assert!(command.applies_to(Some("main")));
+        assert!(!command.applies_to(Some("ui/legacy-form")));
+        assert!(command.applies_to(None));
+    }
+
+    #[test]
+    fn test_parse_and_format_duration() {
+        for (input, millis, formatted) in [
+            ("250ms", 250, "250ms"),
+            ("30s", 30_000, "30s"),
+            ("90s", 90_000, "90s"),
+            ("5m", 300_000, "5m"),
+            ("2h", 7_200_000, "2h"),
+        ] {
+            let duration = parse_duration(input).unwrap();
+            assert_eq!(duration, Duration::from_millis(millis), "{input}");
+            assert_eq!(format_duration(duration), formatted);
+        }
+        assert!(parse_duration("60").is_err());
+        assert!(parse_duration("s").is_err());
+        assert!(parse_duration("1d").is_err());
+    }
 }
@@ -0,0 +1,191 @@
+//! Changing one user config setting from the command line (`wt config set`).
+//!
+//! Settings are written with `toml_edit`, keeping the file's comments and
+//! formatting. Which keys exist, and what type each takes, comes from the
+//! validation schema, so the editor and `wt config validate` agree on both;
+//! the same lists drive `wt config set` completion.
+
+use config::ConfigError;
+use strum::VariantNames;
+
+use super::validate::{ConfigKind, schema, schema_child};
+use super::{CiProvider, PlaceholderStyle, PreviewPosition, StageMode, SyncStrategy};
+
+/// Sections that repeat the whole schema; their settings are edited in the file
+const OVERLAY_SECTIONS: [&str; 2] = ["profiles", "if"];
+
+/// Dotted paths of the user config settings `wt config set` can change: every
+/// key holding a single value, outside sections of arbitrary names (aliases,
+/// hooks) and the profile and condition overlays
+pub fn settable_keys() -> Vec<String> {
+    fn walk(prefix: &str, table: &toml::Table, keys: &mut Vec<String>) {
+        for (key, value) in table {
+            if key == "*" || (prefix.is_empty() && OVERLAY_SECTIONS.contains(&key.as_str())) {
+                continue;
+            }
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{prefix}.{key}")
+            };
+            match value {
+                toml::Value::Table(child) => walk(&path, child, keys),
+                toml::Value::Array(_) => {}
+                _ => keys.push(path),
+            }
+        }
+    }
+
+    let mut schema = schema(ConfigKind::User);
+    // Written by `wt config migrate`, not by hand
+    schema.remove("version");
+    let mut keys = Vec::new();
+    walk("", &schema, &mut keys);
+    keys
+}
+
+/// Values `key` accepts, when there's a fixed set of them
+pub fn setting_values(key: &str) -> Vec<&'static str> {
+    match key {
+        "commit.stage" => StageMode::VARIANTS.to_vec(),
+        "ci.provider" => CiProvider::VARIANTS.to_vec(),
+        "sync.strategy" => SyncStrategy::VARIANTS.to_vec(),
+        "list.placeholder-style" => PlaceholderStyle::VARIANTS.to_vec(),
+        "select.preview-position" => PreviewPosition::VARIANTS.to_vec(),
+        _ => match split_key(key).ok().and_then(|names| sample(&names)) {
+            Some(toml::Value::Boolean(_)) => vec!["true", "false"],
+            _ => Vec::new(),
+        },
+    }
+}
+
+/// Set `key` to `value` in user config `contents`, returning the new contents.
+///
+/// `value` is converted to the type the key takes (`true`, `42`, or a string).
+/// Lists and tables are left to the editor.
+pub fn set_value(contents: &str, key: &str, value: &str) -> Result<String, ConfigError> {
+    let names = split_key(key)?;
+    let item = match sample(&names) {
+        None => return Err(ConfigError::Message(format!("Unknown config key {key}"))),
+        Some(toml::Value::Boolean(_)) => value
+            .parse::<bool>()
+            .map(toml_edit::value)
+            .map_err(|_| ConfigError::Message(format!("{key} must be true or false")))?,
+        Some(toml::Value::Integer(_)) => value
+            .parse::<i64>()
+            .map(toml_edit::value)
+            .map_err(|_| ConfigError::Message(format!("{key} must be a whole number")))?,
+        Some(toml::Value::String(_)) => toml_edit::value(value),
+        Some(_) => {
+            return Err(ConfigError::Message(format!(
+                "{key} isn't a single value; edit the config file to change it"
+            )));
+        }
+    };
+
+    let mut doc: toml_edit::DocumentMut = contents
+        .parse()
+        .map_err(|e| ConfigError::Message(format!("Failed to parse config file: {e}")))?;
+    let (leaf, sections) = names.split_last().expect("keys have at least one part");
+    let mut table: &mut dyn toml_edit::TableLike = doc.as_table_mut();
+    for (depth, section) in sections.iter().enumerate() {
+        table = table
+            .entry(section)
+            .or_insert_with(|| {
+                let mut table = toml_edit::Table::new();
+                // Intermediate sections (`[projects."…"]`'s parent) get no header
+                table.set_implicit(depth + 1 < sections.len());
+                toml_edit::Item::Table(table)
+            })
+            .as_table_like_mut()
+            .ok_or_else(|| {
+                ConfigError::Message(format!(
+                    "{} isn't a table in the config file",
+                    names[..=depth].join(".")
+                ))
+            })?;
+    }
+    table.insert(leaf, item);
+    Ok(doc.to_string())
+}
+
+/// Split a dotted key, honoring quoted parts (`projects."github.com/o/r".path`)
+fn split_key(key: &str) -> Result<Vec<String>, ConfigError> {
+    toml_edit::Key::parse(key)
+        .map(|keys| keys.iter().map(|key| key.get().to_string()).collect())
+        .map_err(|e| ConfigError::Message(format!("Invalid config key {key}: {e}")))
+}
+
+/// The schema's sample value for a key, which gives the key's type
+fn sample(names: &[String]) -> Option<toml::Value> {
+    let schema = schema(ConfigKind::User);
+    let (leaf, sections) = names.split_last()?;
+    let mut table = &schema;
+    for section in sections {
+        table = schema_child(table, section)?;
+    }
+    table.get(leaf.as_str()).or_else(|| table.get("*")).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::validate_str;
+
+    #[test]
+    fn test_set_value_keeps_formatting() {
+        let contents = "# My settings\n[list]\nfull = true  # always\n";
+        let updated = set_value(contents, "list.branches", "true").unwrap();
+        assert_eq!(
+            updated,
+            "# My settings\n[list]\nfull = true  # always\nbranches = true\n"
+        );
+        let updated = set_value(&updated, "select.height", "full").unwrap();
+        assert!(updated.ends_with("\n[select]\nheight = \"full\"\n"));
+        let updated = set_value("", "projects.\"github.com/o/r\".path", "~/r").unwrap();
+        assert_eq!(updated, "[projects.\"github.com/o/r\"]\npath = \"~/r\"\n");
+    }
+
+    #[test]
+    fn test_set_value_rejects() {
+        for (key, value) in [
+            ("list.fulll", "true"),
+            ("list.full", "yes"),
+            ("list.columns", "branch"),
+            ("alias", "x"),
+        ] {
+            assert!(set_value("", key, value).is_err(), "{key} = {value}");
+        }
+    }
+
+    #[test]
+    fn test_settable_keys() {
+        let keys = settable_keys();
+        assert!(keys.contains(&"list.full".to_string()));
+        assert!(keys.contains(&"sync.strategy".to_string()));
+        assert!(!keys.iter().any(|key| key.contains('*')));
+        assert!(!keys.contains(&"version".to_string()));
+        assert!(!keys.contains(&"list.columns".to_string()));
+    }
+
+    /// Every value offered for completion is one the config accepts
+    #[test]
+    fn test_setting_values_validate() {
+        for key in settable_keys() {
+            for value in setting_values(&key) {
+                let contents = set_value("", &key, value).unwrap();
+                let errors: Vec<_> = validate_str(
+                    ConfigKind::User,
+                    std::path::Path::new("config.toml"),
+                    &contents,
+                )
+                .into_iter()
+                .filter(|diagnostic| diagnostic.key.as_deref() == Some(key.as_str()))
+                .collect();
+                assert!(errors.is_empty(), "{key} = {value}: {errors:?}");
+            }
+        }
+        assert_eq!(setting_values("list.full"), ["true", "false"]);
+        assert_eq!(setting_values("sync.strategy"), ["rebase", "merge"]);
+    }
+}
@@ -0,0 +1,271 @@
+//! `WORKTRUNK_*` environment overrides for user config keys.
+//!
+//! Every key in the user config schema can be set from the environment, in
+//! either of two spellings:
+//!
+//! - `WORKTRUNK_MERGE_SQUASH` — the whole key path flattened with underscores
+//! - `WORKTRUNK_MERGE__SQUASH` — double underscores between levels, which also
+//!   reaches keys under free-form tables (`WORKTRUNK_ALIAS__LS`)
+//!
+//! Names are matched against the schema, so variables like `WORKTRUNK_BIN`
+//! that aren't config keys are left alone. Values are typed by the key they
+//! set: booleans must be `true`/`false` (or `1`/`0`, `yes`/`no`), arrays are
+//! comma-separated (`WORKTRUNK_LIST_COLUMNS=branch,age`), and anything else is
+//! passed through as a string.
+
+use config::ConfigError;
+
+use super::validate::{ConfigKind, schema};
+use crate::git::HookType;
+
+const PREFIX: &str = "WORKTRUNK_";
+
+/// One environment variable that overrides a config key
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvOverride {
+    /// Variable name, e.g. `WORKTRUNK_MERGE_SQUASH`
+    pub var: String,
+    /// Dotted config key, e.g. `merge.squash`
+    pub key: String,
+    pub value: String,
+}
+
+/// Config overrides found in the environment
+#[derive(Debug, Default, PartialEq)]
+pub struct EnvOverrides {
+    pub overrides: Vec<EnvOverride>,
+    /// `WORKTRUNK_*__*` variables that don't name a config key
+    pub unrecognized: Vec<String>,
+}
+
+/// Read config overrides from the process environment
+pub fn env_overrides() -> EnvOverrides {
+    overrides_from(std::env::vars())
+}
+
+fn overrides_from(vars: impl IntoIterator<Item = (String, String)>) -> EnvOverrides {
+    let leaves = leaf_keys();
+    let mut found = EnvOverrides::default();
+    for (var, value) in vars {
+        let Some(name) = var.strip_prefix(PREFIX) else {
+            continue;
+        };
+        match resolve(name, &leaves) {
+            Some(key) => found.overrides.push(EnvOverride { var, key, value }),
+            // Single-segment names are often worktrunk's own variables (WORKTRUNK_BIN)
+            None if name.contains("__") => found.unrecognized.push(var),
+            None => {}
+        }
+    }
+    found.overrides.sort_by(|a, b| a.var.cmp(&b.var));
+    found.unrecognized.sort();
+    found
+}
+
+/// Apply environment overrides to `builder`, above every config file
+pub(super) fn add_env_overrides<St: config::builder::BuilderState>(
+    mut builder: config::ConfigBuilder<St>,
+) -> Result<config::ConfigBuilder<St>, ConfigError> {
+    let leaves = leaf_keys();
+    for EnvOverride { var, key, value } in env_overrides().overrides {
+        let leaf = leaves
+            .iter()
+            .find(|(path, _)| path.join(".") == key)
+            .map(|(_, leaf)| leaf);
+        builder = match leaf {
+            Some(leaf) if leaf.is_bool() => {
+                builder.set_override(&key, parse_bool(&var, &value)?)?
+            }
+            Some(leaf) if leaf.is_array() => builder.set_override(&key, parse_list(&value))?,
+            _ => builder.set_override(&key, value)?,
+        };
+    }
+    Ok(builder)
+}
+
+/// Split a comma-separated value for an array key (`branch,age`)
+fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn parse_bool(var: &str, value: &str) -> Result<bool, ConfigError> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "1" | "yes" => Ok(true),
+        "false" | "0" | "no" => Ok(false),
+        _ => Err(ConfigError::Message(format!(
+            "{var}: expected true or false, got {value:?}"
+        ))),
+    }
+}
+
+/// Every settable key in the user schema, as `(path, sample value)`.
+///
+/// Hook types are included as leaves since a hook can be a single command
+/// string. Free-form (`*`) entries are only reachable with `__` separators.
+fn leaf_keys() -> Vec<(Vec<String>, toml::Value)> {
+    fn walk(
+        table: &toml::Table,
+        prefix: &mut Vec<String>,
+        leaves: &mut Vec<(Vec<String>, toml::Value)>,
+    ) {
+        for (key, value) in table {
+            if key == "*" {
+                continue;
+            }
+            prefix.push(key.clone());
+            match value {
+                toml::Value::Table(child) => {
+                    if prefix.len() == 1 && key.parse::<HookType>().is_ok() {
+                        leaves.push((prefix.clone(), toml::Value::String(String::new())));
+                    }
+                    walk(child, prefix, leaves);
+                }
+                _ => leaves.push((prefix.clone(), value.clone())),
+            }
+            prefix.pop();
+        }
+    }
+    let mut leaves = Vec::new();
+    walk(&schema(ConfigKind::User), &mut Vec::new(), &mut leaves);
+    leaves
+}
+
+/// Config key for a variable name (without the prefix), if it names one
+fn resolve(name: &str, leaves: &[(Vec<String>, toml::Value)]) -> Option<String> {
+    let segments: Vec<String> = name
+        .split("__")
+        .map(|segment| segment.to_ascii_lowercase().replace('_', "-"))
+        .collect();
+    // Approvals are only recorded by worktrunk itself, never injected, and the
+    // version describes the file rather than setting anything
+    if segments.iter().any(|segment| segment.is_empty())
+        || segments[0].starts_with("projects")
+        || segments[0] == "version"
+    {
+        return None;
+    }
+
+    if let [flat] = segments.as_slice() {
+        // `MERGE_SQUASH`: compare against each key path flattened the same way
+        return leaves
+            .iter()
+            .find(|(path, _)| path.join("-") == *flat)
+            .map(|(path, _)| path.join("."));
+    }
+
+    // `MERGE__SQUASH`: walk the schema, letting `*` match any name
+    let schema = schema(ConfigKind::User);
+    let mut table = &schema;
+    for (i, segment) in segments.iter().enumerate() {
+        let value = table.get(segment).or_else(|| table.get("*"))?;
+        let last = i == segments.len() - 1;
+        match value {
+            toml::Value::Table(child) if !last => table = child,
+            toml::Value::Table(_) if i == 0 && segment.parse::<HookType>().is_ok() => {}
+            toml::Value::Table(_) => return None,
+            _ if !last => return None,
+            _ => {}
+        }
+    }
+    Some(segments.join("."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(vars: &[(&str, &str)]) -> Vec<String> {
+        overrides_from(
+            vars.iter()
+                .map(|(var, value)| (var.to_string(), value.to_string())),
+        )
+        .overrides
+        .into_iter()
+        .map(|found| found.key)
+        .collect()
+    }
+
+    #[test]
+    fn test_flat_and_nested_names() {
+        assert_eq!(
+            keys(&[
+                ("WORKTRUNK_MERGE_SQUASH", "false"),
+                ("WORKTRUNK_MERGE__VERIFY", "false"),
+                ("WORKTRUNK_WORKTREE_PATH", "../{{ branch }}"),
+                ("WORKTRUNK_COMMIT_GENERATION__COMMAND", "echo"),
+                ("WORKTRUNK_LIST_BRANCHES", "true"),
+            ]),
+            [
+                "commit-generation.command",
+                "list.branches",
+                "merge.squash",
+                "merge.verify",
+                "worktree-path",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_free_form_tables_need_separators() {
+        assert_eq!(
+            keys(&[
+                ("WORKTRUNK_ALIAS__LS", "list --branches"),
+                ("WORKTRUNK_POST_CREATE__INSTALL", "npm ci"),
+                ("WORKTRUNK_PRE_MERGE", "cargo test"),
+            ]),
+            ["alias.ls", "post-create.install", "pre-merge"]
+        );
+    }
+
+    #[test]
+    fn test_non_config_variables_ignored() {
+        let found = overrides_from(
+            [
+                "WORKTRUNK_BIN",
+                "WORKTRUNK_CONFIG_PATH",
+                "WORKTRUNK_DIRECTIVE_FILE",
+                "WORKTRUNK_DIRECTIVE_PROTOCOL",
+                "WORKTRUNK_SHELL",
+                "WORKTRUNK_MAX_CONCURRENT_COMMANDS",
+                "WORKTRUNK_MERGE__SQAUSH",
+                "WORKTRUNK_MERGE",
+                "WORKTRUNK_VERSION",
+                "WORKTRUNK_PROJECTS__X__APPROVED_COMMANDS",
+                "HOME",
+            ]
+            .map(|var| (var.to_string(), String::new())),
+        );
+        assert!(found.overrides.is_empty());
+        assert_eq!(
+            found.unrecognized,
+            [
+                "WORKTRUNK_MERGE__SQAUSH",
+                "WORKTRUNK_PROJECTS__X__APPROVED_COMMANDS"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(parse_list("branch,age"), ["branch", "age"]);
+        assert_eq!(parse_list(" branch , age ,"), ["branch", "age"]);
+        assert!(parse_list("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_bool() {
+        assert!(parse_bool("V", "TRUE").unwrap());
+        assert!(parse_bool("V", "1").unwrap());
+        assert!(!parse_bool("V", "no").unwrap());
+        let err = parse_bool("WORKTRUNK_MERGE_SQUASH", "maybe").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"WORKTRUNK_MERGE_SQUASH: expected true or false, got "maybe""#
+        );
+    }
+}
@@ -47,6 +47,48 @@ pub fn sanitize_branch_name(branch: &str) -> String {
     branch.replace(['/', '\\'], "-")
 }
 
+/// Default `ticket-pattern`: Jira-style keys such as `PROJ-123`
+pub const DEFAULT_TICKET_PATTERN: &str = "[A-Z][A-Z0-9]+-[0-9]+";
+
+/// Lowercase slug of a branch name: runs of anything but letters and digits
+/// become a single `-`, with none at either end.
+///
+/// # Examples
+/// ```
+/// use worktrunk::config::branch_slug;
+///
+/// assert_eq!(branch_slug("feature/PROJ-123_Add login"), "feature-proj-123-add-login");
+/// ```
+pub fn branch_slug(branch: &str) -> String {
+    branch
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Extract a ticket identifier from a branch name with `pattern`.
+///
+/// Uses the first capture group when the pattern has one, otherwise the whole
+/// match. Returns `None` if nothing matches or the pattern is invalid.
+pub fn extract_ticket(branch: &str, pattern: &str) -> Option<String> {
+    let captures = regex::Regex::new(pattern).ok()?.captures(branch)?;
+    captures
+        .get(1)
+        .or_else(|| captures.get(0))
+        .map(|ticket| ticket.as_str().to_string())
+}
+
+/// Today's local date as `YYYY-MM-DD`, respecting `SOURCE_DATE_EPOCH`
+pub fn current_date() -> String {
+    chrono::DateTime::from_timestamp(crate::utils::get_now() as i64, 0)
+        .unwrap_or_default()
+        .with_timezone(&chrono::Local)
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
 /// Expand a template with variable substitution.
 ///
 /// # Arguments
@@ -84,14 +126,13 @@ pub fn expand_template(
     vars: &HashMap<&str, &str>,
     shell_escape: bool,
 ) -> Result<String, String> {
-    use shell_escape::escape;
-    use std::borrow::Cow;
-
-    // Build context map, optionally shell-escaping values
+    // Build context map, optionally shell-escaping values for the shell
+    // hooks run in (quoting differs between sh and PowerShell)
+    let shell = crate::shell_exec::ShellConfig::get();
     let mut context = HashMap::new();
     for (key, value) in vars {
         let val = if shell_escape {
-            escape(Cow::Borrowed(*value)).to_string()
+            shell.quote(value).to_string()
         } else {
             (*value).to_string()
         };
@@ -99,23 +140,64 @@ pub fn expand_template(
     }
 
     // Render template with minijinja
-    let mut env = Environment::new();
+    let mut env = template_environment();
     if shell_escape {
         // Preserve trailing newlines in templates (important for multiline shell commands)
         env.set_keep_trailing_newline(true);
     }
 
-    // Register custom filters
+    let tmpl = env
+        .template_from_str(template)
+        .map_err(|e| format!("Template syntax error: {}", e))?;
+
+    tmpl.render(minijinja::Value::from_object(context))
+        .map_err(|e| format!("Template render error: {}", e))
+}
+
+/// Render a template against structured data, such as a `wt list --json` item.
+///
+/// Values keep their types, so `{% if main.ahead > 0 %}` works; nothing is
+/// shell-escaped.
+pub fn expand_template_with(template: &str, data: &serde_json::Value) -> Result<String, String> {
+    let env = template_environment();
+    let tmpl = env
+        .template_from_str(template)
+        .map_err(|e| format!("Template syntax error: {}", e))?;
+    tmpl.render(Value::from_serialize(data))
+        .map_err(|e| format!("Template render error: {}", e))
+}
+
+/// Environment with worktrunk's custom filters registered
+fn template_environment() -> Environment<'static> {
+    let mut env = Environment::new();
     env.add_filter("sanitize", |value: Value| -> String {
         sanitize_branch_name(value.as_str().unwrap_or_default())
     });
     env.add_filter("hash_port", |value: String| string_to_port(&value));
+    env
+}
+
+/// Context where every variable is defined, for checking templates without real values
+#[derive(Debug)]
+struct AnyVariable;
 
+impl minijinja::value::Object for AnyVariable {
+    fn get_value(self: &std::sync::Arc<Self>, _key: &Value) -> Option<Value> {
+        Some(Value::from("placeholder"))
+    }
+}
+
+/// Check a template for syntax errors and unknown filters without expanding it.
+///
+/// Variables aren't checked, since which ones exist depends on where the
+/// template is used.
+pub fn check_template(template: &str) -> Result<(), String> {
+    let env = template_environment();
     let tmpl = env
         .template_from_str(template)
         .map_err(|e| format!("Template syntax error: {}", e))?;
-
-    tmpl.render(minijinja::Value::from_object(context))
+    tmpl.render(Value::from_object(AnyVariable))
+        .map(|_| ())
         .map_err(|e| format!("Template render error: {}", e))
 }
 
@@ -123,6 +205,48 @@ pub fn expand_template(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_check_template() {
+        assert!(check_template("../{{ main_worktree }}.{{ branch | sanitize }}").is_ok());
+        assert!(check_template("{{ branch | hash_port }}").is_ok());
+
+        let err = check_template("{{ branch | sanitise }}").unwrap_err();
+        assert!(err.contains("unknown filter"), "{err}");
+        let err = check_template("{{ branch").unwrap_err();
+        assert!(err.starts_with("Template syntax error"), "{err}");
+    }
+
+    #[test]
+    fn test_branch_slug() {
+        let cases = [
+            ("feature/foo", "feature-foo"),
+            ("Fix__Bug--42", "fix-bug-42"),
+            ("/leading/and/trailing/", "leading-and-trailing"),
+            ("Émoji✨branch", "émoji-branch"),
+            ("", ""),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(branch_slug(input), expected, "input: {input}");
+        }
+    }
+
+    #[test]
+    fn test_extract_ticket() {
+        let default = DEFAULT_TICKET_PATTERN;
+        assert_eq!(
+            extract_ticket("feature/PROJ-123-login", default).as_deref(),
+            Some("PROJ-123")
+        );
+        assert_eq!(extract_ticket("feature/login", default), None);
+        // A capture group selects part of the match
+        assert_eq!(
+            extract_ticket("gh-42-fix", r"^gh-(\d+)").as_deref(),
+            Some("42")
+        );
+        // Invalid patterns never match
+        assert_eq!(extract_ticket("PROJ-1", "("), None);
+    }
+
     #[test]
     fn test_sanitize_branch_name() {
         let cases = [
@@ -23,6 +23,14 @@ pub struct HooksConfig {
     )]
     pub post_start: Option<CommandConfig>,
 
+    /// Commands to execute before switching away from a worktree (blocking, fail-fast)
+    #[serde(
+        default,
+        rename = "pre-switch",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pre_switch: Option<CommandConfig>,
+
     /// Commands to execute after switching to a worktree (background)
     #[serde(
         default,
@@ -51,6 +59,10 @@ pub struct HooksConfig {
     )]
     pub post_merge: Option<CommandConfig>,
 
+    /// Commands to execute before pushing to the target branch (blocking, fail-fast)
+    #[serde(default, rename = "pre-push", skip_serializing_if = "Option::is_none")]
+    pub pre_push: Option<CommandConfig>,
+
     /// Commands to execute before worktree removal (blocking, fail-fast)
     #[serde(
         default,
@@ -58,6 +70,14 @@ pub struct HooksConfig {
         skip_serializing_if = "Option::is_none"
     )]
     pub pre_remove: Option<CommandConfig>,
+
+    /// Commands to execute after worktree removal (background)
+    #[serde(
+        default,
+        rename = "post-remove",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub post_remove: Option<CommandConfig>,
 }
 
 impl HooksConfig {
@@ -65,11 +85,14 @@ impl HooksConfig {
         match hook {
             HookType::PostCreate => self.post_create.as_ref(),
             HookType::PostStart => self.post_start.as_ref(),
+            HookType::PreSwitch => self.pre_switch.as_ref(),
             HookType::PostSwitch => self.post_switch.as_ref(),
             HookType::PreCommit => self.pre_commit.as_ref(),
             HookType::PreMerge => self.pre_merge.as_ref(),
             HookType::PostMerge => self.post_merge.as_ref(),
+            HookType::PrePush => self.pre_push.as_ref(),
             HookType::PreRemove => self.pre_remove.as_ref(),
+            HookType::PostRemove => self.post_remove.as_ref(),
         }
     }
 }
@@ -0,0 +1,243 @@
+//! User config versioning and migration.
+//!
+//! The user config records the schema version it was written for in a
+//! top-level `version` key; files without one are version 0. When a release
+//! renames keys it bumps [`CONFIG_VERSION`] and adds the renames to
+//! [`MIGRATIONS`], so older files are rewritten instead of having the old keys
+//! silently ignored as unknown.
+//!
+//! Renames are applied with `toml_edit`, keeping comments and formatting, at
+//! the top level and inside every `[profiles.<name>]` and `[if."<pattern>"]`
+//! section (which hold ordinary user config keys).
+
+use std::path::{Path, PathBuf};
+
+use config::ConfigError;
+
+/// Current user config schema version
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Keys renamed by each version, as `(version, [(old, new)])`
+const MIGRATIONS: &[(u32, &[(&str, &str)])] = &[(
+    1,
+    // Hooks dropped their `-command` suffix
+    &[
+        ("post-create-command", "post-create"),
+        ("post-start-command", "post-start"),
+        ("post-switch-command", "post-switch"),
+        ("pre-commit-command", "pre-commit"),
+        ("pre-merge-command", "pre-merge"),
+        ("post-merge-command", "post-merge"),
+        ("pre-remove-command", "pre-remove"),
+    ],
+)];
+
+/// Sections whose entries hold user config keys of their own
+const NESTED_SECTIONS: &[&str] = &["profiles", "if"];
+
+/// One key renamed by a migration
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rename {
+    /// Dotted path of the old key, e.g. `profiles.work.post-create-command`
+    pub from: String,
+    /// Dotted path of the new key
+    pub to: String,
+    /// The new key was already set, so the old one was left in place
+    pub conflict: bool,
+}
+
+/// The result of migrating a config file's contents
+#[derive(Debug, Clone, PartialEq)]
+pub struct Migration {
+    /// Version the file declared (0 when it has no `version` key)
+    pub from_version: u32,
+    pub renames: Vec<Rename>,
+    /// Migrated file contents, stamped with [`CONFIG_VERSION`]
+    pub contents: String,
+}
+
+impl Migration {
+    /// Whether the file declares an older version than [`CONFIG_VERSION`]
+    pub fn is_outdated(&self) -> bool {
+        self.from_version < CONFIG_VERSION
+    }
+
+    /// Whether migrating changes any keys (as opposed to only the version)
+    pub fn renames_keys(&self) -> bool {
+        self.renames.iter().any(|rename| !rename.conflict)
+    }
+}
+
+/// Migrate config file `contents` to [`CONFIG_VERSION`].
+///
+/// Files that declare the current version or a newer one are returned
+/// unchanged.
+pub fn migrate_str(contents: &str) -> Result<Migration, ConfigError> {
+    let mut doc: toml_edit::DocumentMut = contents
+        .parse()
+        .map_err(|e| ConfigError::Message(format!("Failed to parse config file: {e}")))?;
+
+    let from_version = match doc.get("version") {
+        None => 0,
+        Some(item) => item
+            .as_integer()
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| {
+                ConfigError::Message("version must be a non-negative integer".to_string())
+            })?,
+    };
+
+    let mut renames = Vec::new();
+    if from_version < CONFIG_VERSION {
+        for (_, keys) in MIGRATIONS
+            .iter()
+            .filter(|(version, _)| *version > from_version)
+        {
+            rename_keys(doc.as_table_mut(), "", keys, &mut renames);
+            for section in NESTED_SECTIONS {
+                let Some(sections) = doc
+                    .get_mut(section)
+                    .and_then(toml_edit::Item::as_table_like_mut)
+                else {
+                    continue;
+                };
+                for (name, item) in sections.iter_mut() {
+                    if let Some(table) = item.as_table_like_mut() {
+                        let prefix = format!("{section}.{}.", name.get());
+                        rename_keys(table, &prefix, keys, &mut renames);
+                    }
+                }
+            }
+        }
+        doc["version"] = toml_edit::value(i64::from(CONFIG_VERSION));
+    }
+
+    Ok(Migration {
+        from_version,
+        renames,
+        contents: doc.to_string(),
+    })
+}
+
+/// Rename `keys` within one table, keeping each value's formatting
+fn rename_keys(
+    table: &mut dyn toml_edit::TableLike,
+    prefix: &str,
+    keys: &[(&str, &str)],
+    renames: &mut Vec<Rename>,
+) {
+    for (old, new) in keys {
+        let Some(old_key) = table.key(old).cloned() else {
+            continue;
+        };
+        let conflict = table.contains_key(new);
+        renames.push(Rename {
+            from: format!("{prefix}{old}"),
+            to: format!("{prefix}{new}"),
+            conflict,
+        });
+        if conflict {
+            continue;
+        }
+        let Some(item) = table.remove(old) else {
+            continue;
+        };
+        let new_key = toml_edit::Key::new(*new).with_leaf_decor(old_key.leaf_decor().clone());
+        table.entry_format(&new_key).or_insert(item);
+    }
+}
+
+/// Where the pre-migration copy of `path` is kept, e.g. `config.toml.v0.bak`
+pub fn backup_path(path: &Path, from_version: u32) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".v{from_version}.bak"));
+    path.with_file_name(name)
+}
+
+/// Write `migration` to `path`, copying the original to [`backup_path`] first.
+///
+/// Returns the backup's path.
+pub fn write_migration(path: &Path, migration: &Migration) -> Result<PathBuf, ConfigError> {
+    let backup = backup_path(path, migration.from_version);
+    std::fs::copy(path, &backup)
+        .map_err(|e| ConfigError::Message(format!("Failed to back up config file: {e}")))?;
+    std::fs::write(path, &migration.contents)
+        .map_err(|e| ConfigError::Message(format!("Failed to write config file: {e}")))?;
+    Ok(backup)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_renames_keys() {
+        let migration = migrate_str(
+            r#"# My config
+worktree-path = "../{{ branch }}"
+
+# Install dependencies
+[post-create-command]
+install = "npm ci"
+
+[profiles.work]
+pre-merge-command = "cargo test"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(migration.from_version, 0);
+        assert!(migration.renames_keys());
+        assert_eq!(
+            migration
+                .renames
+                .iter()
+                .map(|rename| (rename.from.as_str(), rename.to.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                ("post-create-command", "post-create"),
+                ("profiles.work.pre-merge-command", "profiles.work.pre-merge"),
+            ]
+        );
+        insta::assert_snapshot!(migration.contents, @r#"
+        # My config
+        worktree-path = "../{{ branch }}"
+        version = 1
+
+        # Install dependencies
+        [post-create]
+        install = "npm ci"
+
+        [profiles.work]
+        pre-merge = "cargo test"
+        "#);
+    }
+
+    #[test]
+    fn test_migrate_keeps_conflicting_keys() {
+        let migration =
+            migrate_str("pre-merge = \"cargo test\"\npre-merge-command = \"make test\"\n").unwrap();
+        assert!(!migration.renames_keys());
+        assert!(migration.renames[0].conflict);
+        assert!(migration.contents.contains("pre-merge-command"));
+    }
+
+    #[test]
+    fn test_migrate_current_or_newer_version_unchanged() {
+        for contents in ["version = 1\n", "version = 7\n[pre-merge-command]\n"] {
+            let migration = migrate_str(contents).unwrap();
+            assert!(!migration.is_outdated());
+            assert!(migration.renames.is_empty());
+            assert_eq!(migration.contents, contents);
+        }
+        assert!(migrate_str("version = \"1\"").is_err());
+    }
+
+    #[test]
+    fn test_backup_path() {
+        assert_eq!(
+            backup_path(Path::new("/home/u/.config/worktrunk/config.toml"), 0),
+            Path::new("/home/u/.config/worktrunk/config.toml.v0.bak")
+        );
+    }
+}
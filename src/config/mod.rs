@@ -13,27 +13,49 @@
 //! User config controls "how worktrunk behaves for me", project config controls
 //! "what commands run for this project".
 //!
+//! User config settings can also be layered per repository, from a tracked
+//! `.worktrunk.toml` and an untracked `.git/worktrunk.toml`. These share the
+//! user config's schema (minus approvals), so they're merged into
+//! [`WorktrunkConfig`] rather than being a third independent config.
+//!
 //! See `wt config --help` for complete documentation.
 
 mod commands;
+mod edit;
+mod env;
 mod expansion;
 mod hooks;
+mod migrate;
+mod overlay;
 mod project;
 #[cfg(test)]
 mod test;
 mod user;
+mod validate;
 
 // Re-export public types
-pub use commands::{Command, CommandConfig};
-pub use expansion::{expand_template, sanitize_branch_name};
+pub use commands::{
+    Command, CommandConfig, Link, LinkKind, OnFailure, format_duration, parse_duration,
+};
+pub use edit::{set_value, settable_keys, setting_values};
+pub use env::{EnvOverride, EnvOverrides, env_overrides};
+pub use expansion::{
+    DEFAULT_TICKET_PATTERN, branch_slug, check_template, current_date, expand_template,
+    expand_template_with, extract_ticket, sanitize_branch_name,
+};
 pub use hooks::HooksConfig;
+pub use migrate::{CONFIG_VERSION, Migration, Rename, backup_path, migrate_str, write_migration};
 pub use project::{
     ProjectConfig, ProjectListConfig, find_unknown_keys as find_unknown_project_keys,
 };
 pub use user::{
-    CommitGenerationConfig, StageMode, UserProjectConfig, WorktrunkConfig,
-    find_unknown_keys as find_unknown_user_keys, get_config_path, set_config_path,
+    CiConfig, CiProvider, CommitGenerationConfig, DefaultArgs, LimitsConfig, ListColumn,
+    NotifyConfig, PlaceholderStyle, PreviewPosition, PushConfig, REPO_CONFIG_FILE, SelectConfig,
+    StageMode, SwitchConfig, SyncConfig, SyncStrategy, UserProjectConfig, WorktrunkConfig,
+    approval_id, explicit_config_path, find_unknown_keys as find_unknown_user_keys,
+    get_config_path, parse_age, repo_config_paths, selected_profile, set_config_path, set_profile,
 };
+pub use validate::{ConfigKind, Diagnostic, Severity, validate_file, validate_str};
 
 #[cfg(test)]
 mod tests {
@@ -329,12 +351,15 @@ task2 = "echo 'Task 2 running' > task2.txt"
     fn test_user_project_config_equality() {
         let config1 = UserProjectConfig {
             approved_commands: vec!["npm install".to_string()],
+            path: None,
         };
         let config2 = UserProjectConfig {
             approved_commands: vec!["npm install".to_string()],
+            path: None,
         };
         let config3 = UserProjectConfig {
             approved_commands: vec!["npm test".to_string()],
+            path: None,
         };
         assert_eq!(config1, config2);
         assert_ne!(config1, config3);
@@ -347,6 +372,7 @@ task2 = "echo 'Task 2 running' > task2.txt"
             "github.com/user/repo".to_string(),
             UserProjectConfig {
                 approved_commands: vec!["npm install".to_string()],
+                path: None,
             },
         );
 
@@ -526,6 +552,54 @@ task2 = "echo 'Task 2 running' > task2.txt"
             .unwrap();
     }
 
+    #[test]
+    fn test_register_repo_survives_revoke() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("test-config.toml");
+        let project = "github.com/user/repo";
+
+        let mut config = WorktrunkConfig::default();
+        config
+            .approve_command_to(
+                project.to_string(),
+                "npm install".to_string(),
+                Some(&config_path),
+            )
+            .unwrap();
+        config
+            .register_repo_to(project.to_string(), "/code/repo".into(), Some(&config_path))
+            .unwrap();
+
+        let saved = std::fs::read_to_string(&config_path).unwrap();
+        assert!(saved.contains(r#"path = "/code/repo""#), "{saved}");
+
+        // Clearing approvals keeps the registration
+        config
+            .revoke_project_to(project, Some(&config_path))
+            .unwrap();
+        let saved = std::fs::read_to_string(&config_path).unwrap();
+        assert!(!saved.contains("approved-commands"), "{saved}");
+        assert_eq!(
+            config.registered_repos().collect::<Vec<_>>(),
+            [(project, std::path::Path::new("/code/repo"))]
+        );
+
+        // Unregistering drops the now-empty entry
+        assert!(
+            config
+                .unregister_repo_to(project, Some(&config_path))
+                .unwrap()
+        );
+        assert!(!config.projects.contains_key(project));
+        assert!(
+            !config
+                .unregister_repo_to(project, Some(&config_path))
+                .unwrap()
+        );
+    }
+
     #[test]
     fn test_expand_template_basic() {
         use std::collections::HashMap;
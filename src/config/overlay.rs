@@ -0,0 +1,235 @@
+//! Sections layered over the merged user config.
+//!
+//! - `[if."<remote pattern>"]` applies when the current repository's primary
+//!   remote matches, like git's `includeIf`
+//! - `[profiles.<name>]` applies when selected with `--profile` or
+//!   `WORKTRUNK_PROFILE`
+//!
+//! Both hold ordinary user config keys. Profiles are applied after matching
+//! conditions, so an explicit choice wins over an automatic one.
+
+use config::ConfigError;
+
+use crate::git::HookType;
+
+/// Keys an overlay section can't set
+const NOT_OVERLAYABLE: &[&str] = &["version", "projects", "profiles", "if"];
+
+/// Apply every `[if."<pattern>"]` section matching `remote` (`host/owner/repo`).
+///
+/// When several match, more specific patterns (more literal characters) are
+/// applied later and so take precedence. Returns the patterns that matched.
+pub(super) fn apply_conditions(config: &mut toml::Table, remote: Option<&str>) -> Vec<String> {
+    let Some(remote) = remote else {
+        return Vec::new();
+    };
+    let Some(conditions) = config.get("if").and_then(toml::Value::as_table) else {
+        return Vec::new();
+    };
+    let mut matched: Vec<(String, toml::Table)> = conditions
+        .iter()
+        .filter(|(pattern, _)| matches_remote(pattern, remote))
+        .filter_map(|(pattern, section)| Some((pattern.clone(), section.as_table()?.clone())))
+        .collect();
+    matched.sort_by(|(a, _), (b, _)| specificity(a).cmp(&specificity(b)).then(a.cmp(b)));
+
+    matched
+        .into_iter()
+        .map(|(pattern, section)| {
+            overlay(config, section);
+            pattern
+        })
+        .collect()
+}
+
+/// Apply `[profiles.<name>]` from `config` over the rest of `config`.
+pub(super) fn apply_profile(config: &mut toml::Table, name: &str) -> Result<(), ConfigError> {
+    let profiles = config.get("profiles").and_then(toml::Value::as_table);
+    let Some(profile) = profiles
+        .and_then(|profiles| profiles.get(name))
+        .and_then(toml::Value::as_table)
+        .cloned()
+    else {
+        let mut available: Vec<&str> = profiles
+            .map(|profiles| profiles.keys().map(String::as_str).collect())
+            .unwrap_or_default();
+        available.sort_unstable();
+        let hint = if available.is_empty() {
+            "no profiles are defined".to_string()
+        } else {
+            format!("available: {}", available.join(", "))
+        };
+        return Err(ConfigError::Message(format!(
+            "Profile {name:?} not found ({hint})"
+        )));
+    };
+    overlay(config, profile);
+    Ok(())
+}
+
+/// Layer `section` over `config`.
+///
+/// Hooks are replaced wholesale so an overlay's commands don't run alongside
+/// the base ones; other sections are merged key by key. Approvals and nested
+/// overlays are ignored.
+fn overlay(config: &mut toml::Table, section: toml::Table) {
+    for (key, value) in section {
+        if NOT_OVERLAYABLE.contains(&key.as_str()) {
+            continue;
+        }
+        let replace = key.parse::<HookType>().is_ok();
+        match (config.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(section)) if !replace => {
+                base.extend(section);
+            }
+            (_, value) => {
+                config.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Whether `pattern` matches a `host/owner/repo` identifier.
+///
+/// `*` matches within one path segment and `**` matches any number of
+/// segments, so `github.com/acme/*` matches every repository owned by `acme`
+/// and `github.example.com/**` everything on that host.
+fn matches_remote(pattern: &str, remote: &str) -> bool {
+    let pattern: Vec<&str> = pattern.trim_matches('/').split('/').collect();
+    let remote: Vec<&str> = remote.split('/').collect();
+    match_segments(&pattern, &remote)
+}
+
+fn match_segments(pattern: &[&str], remote: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => remote.is_empty(),
+        Some((&"**", rest)) => (0..=remote.len()).any(|skip| match_segments(rest, &remote[skip..])),
+        Some((first, rest)) => remote.split_first().is_some_and(|(segment, remote)| {
+            match_segment(first.as_bytes(), segment.as_bytes()) && match_segments(rest, remote)
+        }),
+    }
+}
+
+/// Match one segment against a pattern where `*` matches any run of characters.
+/// Hosts and owners are case-insensitive on every forge, so matching is too.
+fn match_segment(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len()).any(|skip| match_segment(rest, &text[skip..])),
+        Some((first, rest)) => text
+            .split_first()
+            .is_some_and(|(c, text)| first.eq_ignore_ascii_case(c) && match_segment(rest, text)),
+    }
+}
+
+/// How specific a pattern is: the number of literal characters
+fn specificity(pattern: &str) -> usize {
+    pattern.chars().filter(|c| *c != '*').count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_remote() {
+        let remote = "github.com/acme/api";
+        assert!(matches_remote("github.com/acme/api", remote));
+        assert!(matches_remote("github.com/acme/*", remote));
+        assert!(matches_remote("github.com/*/api", remote));
+        assert!(matches_remote("github.com/**", remote));
+        assert!(matches_remote("**/api", remote));
+        assert!(matches_remote("GitHub.com/Acme/*", remote));
+        assert!(matches_remote("github.com/acme/a*", remote));
+
+        assert!(!matches_remote("github.com/acme", remote));
+        assert!(!matches_remote("github.com/*", remote));
+        assert!(!matches_remote("gitlab.com/acme/*", remote));
+        assert!(!matches_remote("github.com/acme-corp/*", remote));
+    }
+
+    #[test]
+    fn test_apply_conditions_by_specificity() {
+        let mut config: toml::Table = toml::from_str(
+            r#"
+worktree-path = "../{{ branch }}"
+
+[if."github.com/acme/api".merge]
+squash = false
+
+[if."github.com/**"]
+worktree-path = "gh/{{ branch }}"
+merge = { squash = true, verify = false }
+
+[if."gitlab.com/**"]
+worktree-path = "gl/{{ branch }}"
+"#,
+        )
+        .unwrap();
+
+        let matched = apply_conditions(&mut config, Some("github.com/acme/api"));
+        assert_eq!(matched, ["github.com/**", "github.com/acme/api"]);
+        assert_eq!(config["worktree-path"].as_str(), Some("gh/{{ branch }}"));
+        assert_eq!(config["merge"]["squash"].as_bool(), Some(false));
+        assert_eq!(config["merge"]["verify"].as_bool(), Some(false));
+
+        // Without a parseable remote nothing applies
+        let mut config: toml::Table = toml::from_str("[if.\"**\"]\nworktree-path = \"x\"").unwrap();
+        assert!(apply_conditions(&mut config, None).is_empty());
+        assert!(!config.contains_key("worktree-path"));
+    }
+
+    #[test]
+    fn test_apply_profile() {
+        let mut config: toml::Table = toml::from_str(
+            r#"
+worktree-path = "../{{ branch }}"
+
+[merge]
+squash = true
+verify = true
+
+[post-create]
+install = "npm ci"
+deps = "make deps"
+
+[profiles.oss]
+worktree-path = ".worktrees/{{ branch }}"
+merge = { squash = false }
+post-create = { setup = "just setup" }
+projects = { "github.com/a/b" = { approved-commands = ["x"] } }
+"#,
+        )
+        .unwrap();
+        apply_profile(&mut config, "oss").unwrap();
+
+        assert_eq!(
+            config["worktree-path"].as_str(),
+            Some(".worktrees/{{ branch }}")
+        );
+        // Sections merge key by key...
+        assert_eq!(config["merge"]["squash"].as_bool(), Some(false));
+        assert_eq!(config["merge"]["verify"].as_bool(), Some(true));
+        // ...but hooks are replaced wholesale
+        let hooks = config["post-create"].as_table().unwrap();
+        assert_eq!(hooks.keys().collect::<Vec<_>>(), ["setup"]);
+        // Approvals never come from a profile
+        assert!(!config.contains_key("projects"));
+    }
+
+    #[test]
+    fn test_apply_profile_unknown_name() {
+        let mut config: toml::Table = toml::from_str("[profiles.work]\n[profiles.oss]\n").unwrap();
+        let err = apply_profile(&mut config, "home").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Profile "home" not found (available: oss, work)"#
+        );
+
+        let err = apply_profile(&mut toml::Table::new(), "home").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Profile "home" not found (no profiles are defined)"#
+        );
+    }
+}
@@ -198,20 +198,27 @@ test = "cargo test"
         let contents = r#"
 post-create = "npm install"
 post-start = "npm run watch"
+pre-switch = "docker compose stop"
 post-switch = "rename-tab"
 pre-commit = "cargo fmt --check"
 pre-merge = "cargo test"
 post-merge = "git push"
+pre-push = "cargo test --release"
 pre-remove = "echo bye"
+post-remove = "docker compose down"
 "#;
         let config: ProjectConfig = toml::from_str(contents).unwrap();
         assert!(config.hooks.post_create.is_some());
         assert!(config.hooks.post_start.is_some());
+        assert!(config.hooks.pre_switch.is_some());
         assert!(config.hooks.post_switch.is_some());
         assert!(config.hooks.pre_commit.is_some());
         assert!(config.hooks.pre_merge.is_some());
         assert!(config.hooks.post_merge.is_some());
+        assert!(config.hooks.pre_push.is_some());
         assert!(config.hooks.pre_remove.is_some());
+        assert!(config.hooks.post_remove.is_some());
+        assert!(find_unknown_keys(contents).is_empty());
     }
 
     // ============================================================================
@@ -2,12 +2,14 @@
 //!
 //! Personal preferences and per-project approved commands, not checked into git.
 
-use config::{Case, Config, ConfigError, File};
+use config::builder::DefaultState;
+use config::{Config, ConfigBuilder, ConfigError, File};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::path::PathBuf;
 use std::sync::OnceLock;
 
 use super::HooksConfig;
+use crate::git::{HookType, Repository};
 
 /// Deserialize a Vec<String> that can also accept a single String
 /// This enables setting array config fields via environment variables
@@ -59,7 +61,26 @@ pub fn set_config_path(path: PathBuf) {
     CONFIG_PATH.set(path).ok();
 }
 
-use super::expansion::expand_template;
+/// Profile selected via --profile CLI flag
+static PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Select a config profile (called from CLI --profile flag)
+pub fn set_profile(name: String) {
+    PROFILE.set(name).ok();
+}
+
+/// Profile to apply: `--profile`, else a non-empty `WORKTRUNK_PROFILE`
+pub fn selected_profile() -> Option<String> {
+    PROFILE.get().cloned().or_else(|| {
+        std::env::var("WORKTRUNK_PROFILE")
+            .ok()
+            .filter(|name| !name.is_empty())
+    })
+}
+
+use super::expansion::{
+    DEFAULT_TICKET_PATTERN, branch_slug, current_date, expand_template, extract_ticket,
+};
 
 /// What to stage before committing
 #[derive(
@@ -72,8 +93,10 @@ use super::expansion::expand_template;
     clap::ValueEnum,
     serde::Serialize,
     serde::Deserialize,
+    strum::VariantNames,
 )]
 #[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
 pub enum StageMode {
     /// Stage everything: untracked files + unstaged tracked changes
     #[default]
@@ -121,14 +144,45 @@ pub enum StageMode {
 /// - Linux: `$XDG_CONFIG_HOME/worktrunk/config.toml` or `~/.config/worktrunk/config.toml`
 /// - macOS: `$XDG_CONFIG_HOME/worktrunk/config.toml` or `~/.config/worktrunk/config.toml`
 /// - Windows: `%APPDATA%\worktrunk\config.toml`
+/// - Anywhere: the file given with `--config` or `WORKTRUNK_CONFIG_PATH`
+///
+/// Inside a repository, settings can be layered over the user config by a
+/// tracked `.worktrunk.toml` at the worktree root (shared with the team) and
+/// an untracked `.git/worktrunk.toml` (local to this clone). See
+/// [`repo_config_paths`].
 ///
-/// Environment variables can override config file settings using `WORKTRUNK_` prefix with
-/// `__` separator for nested fields (e.g., `WORKTRUNK_COMMIT_GENERATION__COMMAND`).
+/// Environment variables override any key, either flattened
+/// (`WORKTRUNK_MERGE_SQUASH`) or with `__` between levels
+/// (`WORKTRUNK_COMMIT_GENERATION__COMMAND`). See [`env_overrides`](super::env_overrides).
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WorktrunkConfig {
+    /// Schema version the file was written for (see [`CONFIG_VERSION`](super::CONFIG_VERSION));
+    /// older files are migrated when worktrunk starts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+
     #[serde(rename = "worktree-path", default = "default_worktree_path")]
     pub worktree_path: String,
 
+    /// Regex extracting `{{ ticket }}` from branch names (default: Jira-style
+    /// keys like `PROJ-123`); the first capture group is used if there is one
+    #[serde(
+        default,
+        rename = "ticket-pattern",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ticket_pattern: Option<String>,
+
+    /// File to append a JSON-lines log of commands, hooks, and changes to
+    /// (like `--log-file`); `~` is expanded
+    #[serde(default, rename = "log-file", skip_serializing_if = "Option::is_none")]
+    pub log_file: Option<String>,
+
+    /// Remote whose default branch to compare against (like `--remote`), for
+    /// forks with `origin` and `upstream`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+
     #[serde(default, rename = "commit-generation")]
     pub commit_generation: CommitGenerationConfig,
 
@@ -141,6 +195,11 @@ pub struct WorktrunkConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub list: Option<ListConfig>,
 
+    /// CI status lookups: provider, self-hosted API, and token (never from a
+    /// tracked `.worktrunk.toml`, which could send the token elsewhere)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ci: Option<CiConfig>,
+
     /// Configuration for the `wt step commit` command (also used by merge)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub commit: Option<CommitConfig>,
@@ -149,6 +208,69 @@ pub struct WorktrunkConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub merge: Option<MergeConfig>,
 
+    /// Configuration for the `wt switch` command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub switch: Option<SwitchConfig>,
+
+    /// Desktop notifications when long operations finish
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify: Option<NotifyConfig>,
+
+    /// Checks before `wt step push` and `wt merge` move the target branch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub push: Option<PushConfig>,
+
+    /// How `wt sync` brings branches up to date with their target
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sync: Option<SyncConfig>,
+
+    /// Layout of the `wt select` picker
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub select: Option<SelectConfig>,
+
+    /// How many worktrees, and how much disk, a repository's worktrees may use
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<LimitsConfig>,
+
+    /// Terminal output settings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub styling: Option<StylingConfig>,
+
+    /// How hook commands are run (the commands themselves are top-level keys)
+    #[serde(default, rename = "hooks", skip_serializing_if = "Option::is_none")]
+    pub hook_execution: Option<HookExecutionConfig>,
+
+    /// Command aliases, expanded before argument parsing (e.g., `ls = "list --branches"`)
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub alias: std::collections::BTreeMap<String, String>,
+
+    /// Arguments inserted after a subcommand's name, keyed by the subcommand's
+    /// path (e.g., `list = ["--branches"]`, `"step commit" = "--stage tracked"`)
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub defaults: std::collections::BTreeMap<String, DefaultArgs>,
+
+    /// Named sets of settings applied over the rest of the config when selected
+    /// with `--profile` or `WORKTRUNK_PROFILE` (see [`selected_profile`])
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub profiles: std::collections::BTreeMap<String, WorktrunkConfig>,
+
+    /// Name of the profile applied when loading, if any
+    #[serde(skip)]
+    pub active_profile: Option<String>,
+
+    /// Settings applied when the repository's remote matches a pattern
+    /// (`[if."github.com/acme/*"]`), keyed by pattern
+    #[serde(
+        default,
+        rename = "if",
+        skip_serializing_if = "std::collections::BTreeMap::is_empty"
+    )]
+    pub conditions: std::collections::BTreeMap<String, WorktrunkConfig>,
+
+    /// Patterns from `conditions` that matched when loading
+    #[serde(skip)]
+    pub active_conditions: Vec<String>,
+
     // =========================================================================
     // User-level hooks (same syntax as project hooks, run before project hooks)
     // =========================================================================
@@ -160,6 +282,27 @@ pub struct WorktrunkConfig {
     pub(crate) unknown: std::collections::HashMap<String, toml::Value>,
 }
 
+/// Default arguments for one subcommand, from `[defaults]`
+///
+/// Either an array of arguments or a single string split like a shell would
+/// (the form environment variables set).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum DefaultArgs {
+    Args(Vec<String>),
+    Line(String),
+}
+
+impl DefaultArgs {
+    /// The arguments, or `None` if a string isn't valid shell syntax
+    pub fn args(&self) -> Option<Vec<String>> {
+        match self {
+            Self::Args(args) => Some(args.clone()),
+            Self::Line(line) => shlex::split(line),
+        }
+    }
+}
+
 /// Configuration for commit message generation
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct CommitGenerationConfig {
@@ -212,6 +355,7 @@ impl CommitGenerationConfig {
 /// ```toml
 /// [projects."github.com/user/repo"]
 /// approved-commands = ["npm install", "npm test"]
+/// path = "/home/user/code/repo"  # set by `wt repo add`
 /// ```
 ///
 /// # Future Extensibility
@@ -228,10 +372,61 @@ pub struct UserProjectConfig {
         skip_serializing_if = "Vec::is_empty"
     )]
     pub approved_commands: Vec<String>,
+
+    /// Repository location, registered with `wt repo add` for `--repo <name>`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+impl UserProjectConfig {
+    /// True when the entry holds nothing worth keeping in the config file
+    fn is_empty(&self) -> bool {
+        self.approved_commands.is_empty() && self.path.is_none()
+    }
+}
+
+/// A column of the `wt list` table, named after its header
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum ListColumn {
+    /// Row number, usable as `wt switch 3` (`#`, shown only when selected)
+    Index,
+    /// Branch name
+    Branch,
+    /// Status symbols
+    Status,
+    /// Uncommitted changes (`HEAD±`)
+    Head,
+    /// Commits ahead/behind the default branch (`main↕`)
+    Main,
+    /// Line diff against the default branch (`main…±`, needs `--full`)
+    MainDiff,
+    /// Files changed against the default branch (shown only when selected)
+    Files,
+    /// Worktree path
+    Path,
+    /// Commits ahead/behind the upstream (`Remote⇅`)
+    Remote,
+    /// Dev server URL from the project's `list.url` template
+    Url,
+    /// CI status (needs `--ci` or `--full`)
+    Ci,
+    /// Tip commit signature (`Sig`, shown only when selected)
+    Signature,
+    /// Branch description from `git branch --edit-description` (shown only when selected)
+    Description,
+    /// Commit hash
+    Commit,
+    /// Commit age
+    Age,
+    /// Commit message
+    Message,
 }
 
 /// Configuration for the `wt list` command
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct ListConfig {
     /// Show CI and `main` diffstat by default
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -244,6 +439,165 @@ pub struct ListConfig {
     /// Include remote branches by default
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remotes: Option<bool>,
+
+    /// Columns to show, in display order (default: all, in the standard order)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<ListColumn>>,
+
+    /// Dim rows whose last commit is older than this, e.g. `"30d"`
+    #[serde(rename = "dim-after", skip_serializing_if = "Option::is_none")]
+    pub dim_after: Option<String>,
+
+    /// Highlight rows whose last commit is newer than this, e.g. `"12h"`
+    #[serde(rename = "highlight-within", skip_serializing_if = "Option::is_none")]
+    pub highlight_within: Option<String>,
+
+    /// Text shown in cells that are still loading, e.g. `"…"`, or `""` to
+    /// leave them blank (default: `"⋯"`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+
+    /// How the loading placeholder is styled (default: `dim`)
+    #[serde(rename = "placeholder-style", skip_serializing_if = "Option::is_none")]
+    pub placeholder_style: Option<PlaceholderStyle>,
+}
+
+/// Style of the `wt list` loading placeholder
+///
+/// Dim text is indistinguishable from normal text on terminals that ignore
+/// SGR 2, so the alternatives stand out some other way.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, strum::VariantNames,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum PlaceholderStyle {
+    #[default]
+    Dim,
+    Normal,
+    Bold,
+    Italic,
+    Reverse,
+}
+
+/// Widest `list.placeholder`, in columns; the narrowest data columns have
+/// room for one character
+const MAX_PLACEHOLDER_WIDTH: usize = 1;
+
+impl ListConfig {
+    /// Age past which rows are dimmed (`dim-after`)
+    pub fn dim_after(&self) -> Option<std::time::Duration> {
+        self.dim_after
+            .as_deref()
+            .and_then(|age| parse_age(age).ok())
+    }
+
+    /// Age below which rows are highlighted (`highlight-within`)
+    pub fn highlight_within(&self) -> Option<std::time::Duration> {
+        self.highlight_within
+            .as_deref()
+            .and_then(|age| parse_age(age).ok())
+    }
+}
+
+/// Parse a commit age like `"12h"`, `"30d"`, or `"2w"` (or any unit
+/// [`parse_duration`](super::parse_duration) reads)
+pub fn parse_age(value: &str) -> Result<std::time::Duration, String> {
+    const DAY: u64 = 24 * 60 * 60;
+    let value = value.trim();
+    let days = |number: &str, size: u64| {
+        number
+            .parse::<u64>()
+            .map(|n| std::time::Duration::from_secs(n.saturating_mul(size)))
+            .map_err(|_| format!("invalid age {value:?}; expected e.g. \"30d\""))
+    };
+    if let Some(number) = value.strip_suffix('d') {
+        days(number, DAY)
+    } else if let Some(number) = value.strip_suffix('w') {
+        days(number, 7 * DAY)
+    } else {
+        super::parse_duration(value)
+    }
+}
+
+/// CI provider queried for status
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, strum::VariantNames,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum CiProvider {
+    /// GitHub or GitHub Enterprise, via `gh`
+    #[serde(rename = "github")]
+    GitHub,
+    /// GitLab.com or self-hosted GitLab, via `glab`
+    #[serde(rename = "gitlab")]
+    GitLab,
+    /// Gerrit's `Verified` label, via its REST API (needs `api-url`)
+    Gerrit,
+    /// Don't fetch CI status
+    None,
+}
+
+/// Configuration for CI status lookups
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct CiConfig {
+    /// Provider to query (default: detected from the origin remote's URL)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<CiProvider>,
+
+    /// API base URL of a self-hosted instance (e.g., `https://gitlab.example.com`)
+    #[serde(rename = "api-url", skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+
+    /// Environment variable holding the API token (default: the provider CLI's login)
+    #[serde(rename = "token-env", skip_serializing_if = "Option::is_none")]
+    pub token_env: Option<String>,
+
+    /// Fetch CI status in `wt list` without `--ci` (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+impl CiConfig {
+    /// The API token from the `token-env` variable, if set and non-empty
+    pub fn token(&self) -> Option<String> {
+        let name = self.token_env.as_deref()?;
+        std::env::var(name).ok().filter(|token| !token.is_empty())
+    }
+
+    /// Host name from `api-url` (`https://git.example.com/api/v3` → `git.example.com`)
+    pub fn api_host(&self) -> Option<&str> {
+        let url = self.api_url.as_deref()?;
+        let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+        let host = rest.split(['/', '?', '#']).next()?;
+        // Drop any `user@` prefix
+        let host = host.rsplit('@').next()?;
+        (!host.is_empty()).then_some(host)
+    }
+
+    /// Whether CI status is never fetched
+    pub fn is_disabled(&self) -> bool {
+        self.provider == Some(CiProvider::None)
+    }
+}
+
+/// Terminal output settings
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct StylingConfig {
+    /// Prefix messages with symbols like `✓` and `▲` (default: true); when
+    /// false, plain-text tags like `[ok]` and `[warning]` are used instead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<bool>,
+}
+
+/// How hook commands are run, from `[hooks]`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct HookExecutionConfig {
+    /// Run a blocking hook's commands concurrently, prefixing each output
+    /// line with the command's name (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallel: Option<bool>,
 }
 
 /// Configuration for the `wt step commit` command
@@ -283,6 +637,228 @@ pub struct MergeConfig {
     /// Run project hooks (default: true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verify: Option<bool>,
+
+    /// Refuse to merge unless the branch's CI has passed (default: false)
+    #[serde(rename = "require-ci", skip_serializing_if = "Option::is_none")]
+    pub require_ci: Option<bool>,
+
+    /// GPG-sign the commits the merge creates (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sign: Option<bool>,
+
+    /// Stash changes the merge won't commit and restore them afterward (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autostash: Option<bool>,
+}
+
+/// Configuration for the `wt switch` command
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct SwitchConfig {
+    /// Run `git submodule update --init --recursive` in newly created
+    /// worktrees (default: false)
+    #[serde(rename = "init-submodules", skip_serializing_if = "Option::is_none")]
+    pub init_submodules: Option<bool>,
+
+    /// Run `git lfs pull` in newly created worktrees of repositories that
+    /// use Git LFS (default: false)
+    #[serde(rename = "lfs-pull", skip_serializing_if = "Option::is_none")]
+    pub lfs_pull: Option<bool>,
+
+    /// Create a tmux session (or, inside tmux, a window) for newly created
+    /// worktrees, as `wt tmux` does but without attaching (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tmux: Option<bool>,
+
+    /// Template naming branches created with `--from-issue`, from `{{ ticket }}`
+    /// (the issue number), `{{ title }}`, and `{{ slug }}` (default:
+    /// `"{{ ticket }}-{{ slug }}"`)
+    #[serde(rename = "issue-branch", skip_serializing_if = "Option::is_none")]
+    pub issue_branch: Option<String>,
+}
+
+/// Desktop notifications when long operations finish
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct NotifyConfig {
+    /// Send notifications (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Only for operations that ran at least this long, e.g. `"30s"` (default: `"10s"`)
+    #[serde(rename = "min-duration", skip_serializing_if = "Option::is_none")]
+    pub min_duration: Option<String>,
+}
+
+/// Checks before `wt step push` and `wt merge` fast-forward the target branch
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct PushConfig {
+    /// Refuse to push unless every outgoing commit has a good signature
+    /// (default: false)
+    #[serde(rename = "require-signed", skip_serializing_if = "Option::is_none")]
+    pub require_signed: Option<bool>,
+
+    /// Keys a signature must come from, as full fingerprints or long key IDs
+    /// (default: any key git can verify)
+    #[serde(rename = "allowed-keys", skip_serializing_if = "Option::is_none")]
+    pub allowed_keys: Option<Vec<String>>,
+}
+
+/// How `wt sync` brings a branch up to date with its target
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    clap::ValueEnum,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::VariantNames,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum SyncStrategy {
+    /// Rebase the branch onto the target
+    #[default]
+    Rebase,
+    /// Merge the target into the branch
+    Merge,
+}
+
+/// Configuration for the `wt sync` command
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct SyncConfig {
+    /// Strategy for branches no pattern in `branches` matches (default: rebase)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<SyncStrategy>,
+
+    /// Strategy per branch glob (e.g., `"release/*" = "merge"`); an exact
+    /// branch name beats a pattern, and a longer pattern beats a shorter one
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub branches: std::collections::BTreeMap<String, SyncStrategy>,
+}
+
+impl SyncConfig {
+    /// The strategy for `branch`
+    pub fn strategy_for(&self, branch: &str) -> SyncStrategy {
+        if let Some(strategy) = self.branches.get(branch) {
+            return *strategy;
+        }
+        self.branches
+            .iter()
+            .filter(|(pattern, _)| {
+                glob::Pattern::new(pattern).is_ok_and(|pattern| pattern.matches(branch))
+            })
+            .max_by_key(|(pattern, _)| pattern.len())
+            .map(|(_, strategy)| *strategy)
+            .or(self.strategy)
+            .unwrap_or_default()
+    }
+}
+
+/// Where `wt select` shows its preview
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, strum::VariantNames,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum PreviewPosition {
+    /// Below the list on portrait terminals, beside it otherwise
+    #[default]
+    Auto,
+    Right,
+    Down,
+}
+
+/// Configuration for the `wt select` picker's layout
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct SelectConfig {
+    /// Share of the terminal's height, like `"60%"`, or `"full"` (default: `"90%"`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<String>,
+
+    /// Where the preview goes (default: auto)
+    #[serde(rename = "preview-position", skip_serializing_if = "Option::is_none")]
+    pub preview_position: Option<PreviewPosition>,
+
+    /// Preview's share of the picker, like `"40%"` (default: half beside the
+    /// list, or whatever the list leaves below it)
+    #[serde(rename = "preview-size", skip_serializing_if = "Option::is_none")]
+    pub preview_size: Option<String>,
+
+    /// Start with the preview hidden; alt-p shows it
+    #[serde(rename = "preview-hidden", skip_serializing_if = "Option::is_none")]
+    pub preview_hidden: Option<bool>,
+}
+
+impl SelectConfig {
+    /// Picker height as a percentage of the terminal (`height`)
+    pub fn height_percent(&self) -> Option<u8> {
+        match self.height.as_deref()? {
+            "full" => Some(100),
+            height => parse_percent(height),
+        }
+    }
+
+    /// Preview size as a percentage of the picker (`preview-size`)
+    pub fn preview_size_percent(&self) -> Option<u8> {
+        self.preview_size.as_deref().and_then(parse_percent)
+    }
+}
+
+/// Budget for a repository's worktrees, checked by `wt switch --create` and `wt list`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct LimitsConfig {
+    /// Most worktrees to have at once, counting the main one (default: no limit)
+    #[serde(rename = "max-worktrees", skip_serializing_if = "Option::is_none")]
+    pub max_worktrees: Option<usize>,
+
+    /// Most disk all worktrees may use together, like `"20GB"` (default: no limit)
+    #[serde(rename = "max-total-disk", skip_serializing_if = "Option::is_none")]
+    pub max_total_disk: Option<String>,
+}
+
+impl LimitsConfig {
+    /// `max-total-disk` in bytes
+    pub fn max_total_disk_bytes(&self) -> Option<u64> {
+        self.max_total_disk.as_deref().and_then(parse_size)
+    }
+}
+
+/// Parse a size like `"20GB"`, `"512M"`, or `"1.5GiB"`; units are powers of 1024
+fn parse_size(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    let unit = unit.trim().to_ascii_uppercase();
+    let unit = unit
+        .strip_suffix("IB")
+        .or_else(|| unit.strip_suffix('B'))
+        .unwrap_or(&unit);
+    let exponent = match unit {
+        "" => 0,
+        "K" => 1,
+        "M" => 2,
+        "G" => 3,
+        "T" => 4,
+        _ => return None,
+    };
+    Some((number * 1024_f64.powi(exponent)) as u64)
+}
+
+/// Parse a percentage from 1% to 100%, like `"40%"`
+fn parse_percent(value: &str) -> Option<u8> {
+    value
+        .trim()
+        .strip_suffix('%')?
+        .trim()
+        .parse()
+        .ok()
+        .filter(|percent| (1..=100).contains(percent))
 }
 
 /// Default worktree path template (used by serde)
@@ -293,12 +869,31 @@ fn default_worktree_path() -> String {
 impl Default for WorktrunkConfig {
     fn default() -> Self {
         Self {
+            version: None,
             worktree_path: default_worktree_path(),
+            ticket_pattern: None,
+            log_file: None,
+            remote: None,
             commit_generation: CommitGenerationConfig::default(),
             projects: std::collections::BTreeMap::new(),
             list: None,
+            ci: None,
             commit: None,
             merge: None,
+            switch: None,
+            push: None,
+            sync: None,
+            select: None,
+            limits: None,
+            notify: None,
+            styling: None,
+            hook_execution: None,
+            alias: std::collections::BTreeMap::new(),
+            defaults: std::collections::BTreeMap::new(),
+            profiles: std::collections::BTreeMap::new(),
+            active_profile: None,
+            conditions: std::collections::BTreeMap::new(),
+            active_conditions: Vec::new(),
             hooks: HooksConfig::default(),
             unknown: std::collections::HashMap::new(),
         }
@@ -310,8 +905,12 @@ impl WorktrunkConfig {
     ///
     /// Configuration is loaded in the following order (later sources override earlier ones):
     /// 1. Default values
-    /// 2. Config file (see struct documentation for platform-specific paths)
-    /// 3. Environment variables (WORKTRUNK_*)
+    /// 2. Config file (see struct documentation for platform-specific paths), or
+    ///    the one given with `--config` (which must exist) or `WORKTRUNK_CONFIG_PATH`
+    /// 3. Repository config: `.worktrunk.toml`, then `.git/worktrunk.toml`
+    /// 4. `[if."<pattern>"]` sections matching the repository's remote
+    /// 5. The selected profile, if any (`[profiles.<name>]` from any of the above)
+    /// 6. Environment variables (WORKTRUNK_*)
     pub fn load() -> Result<Self, ConfigError> {
         let defaults = Self::default();
 
@@ -323,6 +922,15 @@ impl WorktrunkConfig {
             )?
             .set_default("commit-generation.args", defaults.commit_generation.args)?;
 
+        if let Some(path) = explicit_config_path()
+            && !path.exists()
+        {
+            return Err(ConfigError::Message(format!(
+                "Config file not found: {}",
+                crate::path::format_path_for_display(&path)
+            )));
+        }
+
         // Add config file if it exists
         if let Some(config_path) = get_config_path()
             && config_path.exists()
@@ -330,48 +938,198 @@ impl WorktrunkConfig {
             builder = builder.add_source(File::from(config_path));
         }
 
-        // Add environment variables with WORKTRUNK prefix
-        // - prefix_separator("_"): strip prefix with single underscore (WORKTRUNK_ → key)
-        // - separator("__"): double underscore for nested fields (COMMIT_GENERATION__COMMAND → commit-generation.command)
-        // - convert_case(Kebab): converts snake_case to kebab-case to match serde field names
-        // Example: WORKTRUNK_WORKTREE_PATH → worktree-path
-        builder = builder.add_source(
-            config::Environment::with_prefix("WORKTRUNK")
-                .prefix_separator("_")
-                .separator("__")
-                .convert_case(Case::Kebab),
-        );
+        builder = add_repo_config_sources(builder)?;
+
+        // Conditional sections, then the selected profile, replace settings from every file
+        let active_profile = selected_profile();
+        let mut merged: toml::Table = builder
+            .build_cloned()
+            .and_then(|built| built.try_deserialize())
+            .map_err(super::validate::explain_load_error)?;
+        let has_conditions = merged
+            .get("if")
+            .and_then(toml::Value::as_table)
+            .is_some_and(|conditions| !conditions.is_empty());
+        let mut active_conditions = Vec::new();
+        if has_conditions || active_profile.is_some() {
+            if has_conditions {
+                let remote = Repository::current()
+                    .primary_remote_url()
+                    .map(|url| url.project_identifier());
+                active_conditions =
+                    super::overlay::apply_conditions(&mut merged, remote.as_deref());
+            }
+            if let Some(name) = &active_profile {
+                super::overlay::apply_profile(&mut merged, name)?;
+            }
+            let merged = toml::to_string(&merged).map_err(|e| {
+                ConfigError::Message(format!("Failed to apply config overlays: {e}"))
+            })?;
+            builder =
+                Config::builder().add_source(File::from_str(&merged, config::FileFormat::Toml));
+        }
+
+        // Environment variables (WORKTRUNK_*) override every file
+        builder = super::env::add_env_overrides(builder)?;
+
+        let mut config: Self = builder
+            .build()
+            .and_then(|built| built.try_deserialize())
+            .map_err(super::validate::explain_load_error)?;
+        config.active_profile = active_profile;
+        config.active_conditions = active_conditions;
+
+        if let Some((_, message)) = config.invalid_values().into_iter().next() {
+            return Err(ConfigError::Message(message.into()));
+        }
+
+        Ok(config)
+    }
 
-        let config: Self = builder.build()?.try_deserialize()?;
+    /// Values that deserialize but aren't usable, as `(key, message)` pairs
+    pub(super) fn invalid_values(&self) -> Vec<(&'static str, &'static str)> {
+        let mut invalid = Vec::new();
 
         // Validate worktree path
-        if config.worktree_path.is_empty() {
-            return Err(ConfigError::Message("worktree-path cannot be empty".into()));
+        if self.worktree_path.is_empty() {
+            invalid.push(("worktree-path", "worktree-path cannot be empty"));
         }
-        if std::path::Path::new(&config.worktree_path).is_absolute() {
-            return Err(ConfigError::Message(
-                "worktree-path must be relative, not absolute".into(),
+        if std::path::Path::new(&self.worktree_path).is_absolute() {
+            invalid.push((
+                "worktree-path",
+                "worktree-path must be relative, not absolute",
             ));
         }
 
-        // Validate commit generation config
-        if config.commit_generation.template.is_some()
-            && config.commit_generation.template_file.is_some()
+        if let Some(pattern) = &self.ticket_pattern
+            && regex::Regex::new(pattern).is_err()
         {
-            return Err(ConfigError::Message(
-                "commit-generation.template and commit-generation.template-file are mutually exclusive".into(),
+            invalid.push((
+                "ticket-pattern",
+                "ticket-pattern is not a valid regular expression",
             ));
         }
 
-        if config.commit_generation.squash_template.is_some()
-            && config.commit_generation.squash_template_file.is_some()
+        if let Some(list) = &self.list {
+            if list
+                .dim_after
+                .as_deref()
+                .is_some_and(|a| parse_age(a).is_err())
+            {
+                invalid.push((
+                    "list.dim-after",
+                    "list.dim-after must be an age like \"30d\" or \"12h\"",
+                ));
+            }
+            if list
+                .highlight_within
+                .as_deref()
+                .is_some_and(|a| parse_age(a).is_err())
+            {
+                invalid.push((
+                    "list.highlight-within",
+                    "list.highlight-within must be an age like \"1d\" or \"12h\"",
+                ));
+            }
+            if list.placeholder.as_deref().is_some_and(|placeholder| {
+                unicode_width::UnicodeWidthStr::width(placeholder) > MAX_PLACEHOLDER_WIDTH
+            }) {
+                invalid.push((
+                    "list.placeholder",
+                    "list.placeholder must be a single character, or empty",
+                ));
+            }
+        }
+
+        if let Some(notify) = &self.notify
+            && notify
+                .min_duration
+                .as_deref()
+                .is_some_and(|d| super::parse_duration(d).is_err())
         {
-            return Err(ConfigError::Message(
-                "commit-generation.squash-template and commit-generation.squash-template-file are mutually exclusive".into(),
+            invalid.push((
+                "notify.min-duration",
+                "notify.min-duration must be a duration like \"10s\" or \"2m\"",
             ));
         }
 
-        Ok(config)
+        if let Some(ci) = &self.ci
+            && ci.provider == Some(CiProvider::Gerrit)
+            && ci.api_url.is_none()
+        {
+            invalid.push(("ci", "ci.provider = \"gerrit\" requires ci.api-url"));
+        }
+
+        if let Some(sync) = &self.sync
+            && sync
+                .branches
+                .keys()
+                .any(|pattern| glob::Pattern::new(pattern).is_err())
+        {
+            invalid.push((
+                "sync.branches",
+                "sync.branches keys must be branch names or glob patterns like \"release/*\"",
+            ));
+        }
+
+        if let Some(select) = &self.select {
+            if select.height.is_some() && select.height_percent().is_none() {
+                invalid.push((
+                    "select.height",
+                    "select.height must be a percentage like \"60%\", or \"full\"",
+                ));
+            }
+            if select.preview_size.is_some() && select.preview_size_percent().is_none() {
+                invalid.push((
+                    "select.preview-size",
+                    "select.preview-size must be a percentage like \"40%\"",
+                ));
+            }
+        }
+
+        if let Some(limits) = &self.limits
+            && limits.max_total_disk.is_some()
+            && limits.max_total_disk_bytes().is_none()
+        {
+            invalid.push((
+                "limits.max-total-disk",
+                "limits.max-total-disk must be a size like \"20GB\" or \"500MB\"",
+            ));
+        }
+
+        // Validate commit generation config
+        let generation = &self.commit_generation;
+        if generation.template.is_some() && generation.template_file.is_some() {
+            invalid.push((
+                "commit-generation",
+                "commit-generation.template and commit-generation.template-file are mutually exclusive",
+            ));
+        }
+        if generation.squash_template.is_some() && generation.squash_template_file.is_some() {
+            invalid.push((
+                "commit-generation",
+                "commit-generation.squash-template and commit-generation.squash-template-file are mutually exclusive",
+            ));
+        }
+
+        invalid
+    }
+
+    /// Template variables derived from `branch` and today's date:
+    /// `branch_slug`, `date`, and `ticket` (only when the branch contains one).
+    pub fn branch_template_vars(&self, branch: &str) -> Vec<(&'static str, String)> {
+        let pattern = self
+            .ticket_pattern
+            .as_deref()
+            .unwrap_or(DEFAULT_TICKET_PATTERN);
+        let mut vars = vec![
+            ("branch_slug", branch_slug(branch)),
+            ("date", current_date()),
+        ];
+        if let Some(ticket) = extract_ticket(branch, pattern) {
+            vars.push(("ticket", ticket));
+        }
+        vars
     }
 
     /// Format a worktree path using this configuration's template.
@@ -380,6 +1138,10 @@ impl WorktrunkConfig {
     /// * `main_worktree` - Main worktree directory name (replaces {{ main_worktree }} in template)
     /// * `branch` - Branch name (replaces {{ branch }} in template; use `{{ branch | sanitize }}` for paths)
     ///
+    /// Variables from [`branch_template_vars`](Self::branch_template_vars) are
+    /// also available; see [`format_path_with`](Self::format_path_with) for
+    /// repository-derived ones.
+    ///
     /// # Examples
     /// ```
     /// use worktrunk::config::WorktrunkConfig;
@@ -390,8 +1152,24 @@ impl WorktrunkConfig {
     /// assert_eq!(path, "../myproject.feature-foo");
     /// ```
     pub fn format_path(&self, main_worktree: &str, branch: &str) -> Result<String, String> {
+        self.format_path_with(main_worktree, branch, &[])
+    }
+
+    /// Format a worktree path with `extra` variables, such as `user` and
+    /// `default_branch`, which need a repository to compute.
+    pub fn format_path_with(
+        &self,
+        main_worktree: &str,
+        branch: &str,
+        extra: &[(&str, &str)],
+    ) -> Result<String, String> {
         use std::collections::HashMap;
-        let mut vars = HashMap::new();
+        let branch_vars = self.branch_template_vars(branch);
+        let mut vars: HashMap<&str, &str> = branch_vars
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        vars.extend(extra.iter().copied());
         vars.insert("main_worktree", main_worktree);
         vars.insert("repo", main_worktree);
         vars.insert("branch", branch);
@@ -503,7 +1281,7 @@ impl WorktrunkConfig {
             project_config.approved_commands.retain(|c| c != command);
             let changed = len_before != project_config.approved_commands.len();
 
-            if project_config.approved_commands.is_empty() {
+            if project_config.is_empty() {
                 self.projects.remove(project);
             }
 
@@ -531,10 +1309,132 @@ impl WorktrunkConfig {
         // Reload from disk first to get fresh state (fixes race condition)
         self.reload_projects_from(config_path)?;
 
-        if self.projects.remove(project).is_some() {
-            self.save_impl(config_path)?;
+        let Some(project_config) = self.projects.get_mut(project) else {
+            return Ok(());
+        };
+        // Keep the entry if the repository is registered
+        project_config.approved_commands.clear();
+        if project_config.is_empty() {
+            self.projects.remove(project);
+        }
+        self.save_impl(config_path)
+    }
+
+    /// Register a repository's location under its project identifier and save
+    pub fn register_repo(&mut self, project: String, path: PathBuf) -> Result<(), ConfigError> {
+        self.register_repo_to(project, path, None)
+    }
+
+    /// Register a repository's location and save to a specific config file (for testing)
+    ///
+    /// Reloads from disk before modifying to reduce race conditions from concurrent processes.
+    #[doc(hidden)]
+    pub fn register_repo_to(
+        &mut self,
+        project: String,
+        path: PathBuf,
+        config_path: Option<&std::path::Path>,
+    ) -> Result<(), ConfigError> {
+        self.reload_projects_from(config_path)?;
+        self.projects.entry(project).or_default().path = Some(path);
+        self.save_impl(config_path)
+    }
+
+    /// Remove a repository from the registry and save, returning whether it was registered
+    pub fn unregister_repo(&mut self, project: &str) -> Result<bool, ConfigError> {
+        self.unregister_repo_to(project, None)
+    }
+
+    /// Remove a repository from the registry and save to a specific config file (for testing)
+    ///
+    /// Approved commands for the project are kept.
+    #[doc(hidden)]
+    pub fn unregister_repo_to(
+        &mut self,
+        project: &str,
+        config_path: Option<&std::path::Path>,
+    ) -> Result<bool, ConfigError> {
+        self.reload_projects_from(config_path)?;
+
+        let Some(project_config) = self.projects.get_mut(project) else {
+            return Ok(false);
+        };
+        if project_config.path.take().is_none() {
+            return Ok(false);
         }
-        Ok(())
+        if project_config.is_empty() {
+            self.projects.remove(project);
+        }
+        self.save_impl(config_path)?;
+        Ok(true)
+    }
+
+    /// The `wt sync` strategy for `branch` (`[sync]`, default rebase)
+    pub fn sync_strategy(&self, branch: &str) -> SyncStrategy {
+        self.sync
+            .as_ref()
+            .map(|sync| sync.strategy_for(branch))
+            .unwrap_or_default()
+    }
+
+    /// The `[limits]` section, or no limits when it's absent
+    pub fn limits(&self) -> LimitsConfig {
+        self.limits.clone().unwrap_or_default()
+    }
+
+    /// The `[ci]` section, or defaults when it's absent
+    pub fn ci(&self) -> CiConfig {
+        self.ci.clone().unwrap_or_default()
+    }
+
+    /// How long an operation must run before its completion is notified, or
+    /// `None` when notifications are off (`[notify]`)
+    pub fn notify_after(&self) -> Option<std::time::Duration> {
+        let notify = self.notify.as_ref().filter(|n| n.enabled == Some(true))?;
+        Some(
+            notify
+                .min_duration
+                .as_deref()
+                .and_then(|d| super::parse_duration(d).ok())
+                .unwrap_or(std::time::Duration::from_secs(10)),
+        )
+    }
+
+    /// Whether messages use emoji symbols (`styling.emoji`, default true)
+    pub fn emoji(&self) -> bool {
+        self.styling
+            .as_ref()
+            .and_then(|styling| styling.emoji)
+            .unwrap_or(true)
+    }
+
+    /// The operation log file (`log-file`), with `~` expanded
+    pub fn log_file(&self) -> Option<std::path::PathBuf> {
+        let path = self.log_file.as_deref().filter(|path| !path.is_empty())?;
+        Some(std::path::PathBuf::from(shellexpand::tilde(path).as_ref()))
+    }
+
+    /// Whether blocking hooks run their commands concurrently (`hooks.parallel`, default false)
+    pub fn parallel_hooks(&self) -> bool {
+        self.hook_execution
+            .as_ref()
+            .and_then(|hooks| hooks.parallel)
+            .unwrap_or(false)
+    }
+
+    /// Columns configured for `wt list`, in display order (empty for the default set)
+    pub fn list_columns(&self) -> &[ListColumn] {
+        self.list
+            .as_ref()
+            .and_then(|list| list.columns.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Registered repositories as (project identifier, path) pairs, sorted by identifier
+    pub fn registered_repos(&self) -> impl Iterator<Item = (&str, &std::path::Path)> {
+        self.projects
+            .iter()
+            .filter_map(|(id, project)| Some((id.as_str(), project.path.as_deref()?)))
     }
 
     /// Save the current configuration to the default config file location
@@ -571,6 +1471,22 @@ impl WorktrunkConfig {
         array
     }
 
+    /// Write a project's settings into its `[projects."<id>"]` table, dropping unset keys
+    fn write_project_table(table: &mut toml_edit::Table, project: &UserProjectConfig) {
+        if project.approved_commands.is_empty() {
+            table.remove("approved-commands");
+        } else {
+            let commands = Self::format_multiline_array(project.approved_commands.iter());
+            table["approved-commands"] = toml_edit::value(commands);
+        }
+        match &project.path {
+            Some(path) => table["path"] = toml_edit::value(path.to_string_lossy().as_ref()),
+            None => {
+                table.remove("path");
+            }
+        }
+    }
+
     /// Save the current configuration to a specific file path
     ///
     /// Use this in tests to save to a temporary location instead of the user's config.
@@ -614,9 +1530,9 @@ impl WorktrunkConfig {
                     if !projects.contains_key(project_id) {
                         projects[project_id] = toml_edit::Item::Table(toml_edit::Table::new());
                     }
-                    let commands =
-                        Self::format_multiline_array(project_config.approved_commands.iter());
-                    projects[project_id]["approved-commands"] = toml_edit::value(commands);
+                    if let Some(table) = projects[project_id].as_table_mut() {
+                        Self::write_project_table(table, project_config);
+                    }
                 }
             }
 
@@ -640,9 +1556,7 @@ impl WorktrunkConfig {
                 projects_table.set_implicit(true); // Don't emit [projects] header
                 for (project_id, project_config) in &self.projects {
                     let mut table = toml_edit::Table::new();
-                    let commands =
-                        Self::format_multiline_array(project_config.approved_commands.iter());
-                    table["approved-commands"] = toml_edit::value(commands);
+                    Self::write_project_table(&mut table, project_config);
                     projects_table[project_id] = toml_edit::Item::Table(table);
                 }
                 doc["projects"] = toml_edit::Item::Table(projects_table);
@@ -658,10 +1572,171 @@ impl WorktrunkConfig {
     }
 }
 
+/// Short, stable ID for an approved command.
+///
+/// A 32-bit FNV-1a hash of the command template, so it's the same across runs
+/// and platforms and changes whenever the template does. Shown by
+/// `wt trust list` and accepted by `wt trust revoke`.
+pub fn approval_id(command: &str) -> String {
+    let hash = command.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    format!("{hash:08x}")
+}
+
+/// Tracked repository config, at the worktree root
+pub const REPO_CONFIG_FILE: &str = ".worktrunk.toml";
+
+/// Untracked repository config, in the git common directory
+const LOCAL_REPO_CONFIG_FILE: &str = "worktrunk.toml";
+
+/// Repository config files layered over the user config, lowest precedence first.
+///
+/// Only files that exist are returned; outside a repository there are none.
+/// Each path is paired with whether it's the tracked (shared) file.
+pub fn repo_config_paths() -> Vec<(PathBuf, bool)> {
+    let repo = Repository::current();
+    let shared = repo
+        .worktree_root()
+        .ok()
+        .map(|root| (root.join(REPO_CONFIG_FILE), true));
+    let local = repo
+        .git_common_dir()
+        .ok()
+        .map(|dir| (dir.join(LOCAL_REPO_CONFIG_FILE), false));
+    [shared, local]
+        .into_iter()
+        .flatten()
+        .filter(|(path, _)| path.exists())
+        .collect()
+}
+
+/// Top-level keys the tracked repository config may set.
+///
+/// The tracked file is shared through git, so anything that runs commands
+/// (hooks, `commit-generation`, `alias`, `defaults`), sends data elsewhere
+/// (`ci`), writes outside the repository (`log-file`), or relaxes a check the
+/// user asked for (`push`) stays out; keys added later are denied until
+/// they're listed here.
+const SHARED_REPO_KEYS: &[&str] = &[
+    "version",
+    "worktree-path",
+    "ticket-pattern",
+    "remote",
+    "list",
+    "commit",
+    "notify",
+    "sync",
+    "select",
+    "limits",
+    "styling",
+    "hooks",
+];
+
+/// Sections the tracked repository config may only set some keys of.
+///
+/// `merge.require-ci`, `merge.sign` and `merge.verify` would let it turn off
+/// checks, and `switch.init-submodules`, `switch.lfs-pull` and `switch.tmux`
+/// fetch or run things for each new worktree.
+const SHARED_REPO_SECTION_KEYS: &[(&str, &[&str])] = &[
+    (
+        "merge",
+        &["squash", "commit", "rebase", "remove", "autostash"],
+    ),
+    ("switch", &["issue-branch"]),
+];
+
+/// Find a top-level key that a repository config isn't allowed to set.
+///
+/// Approvals only live in the user config. The tracked file can only set keys
+/// in [`SHARED_REPO_KEYS`] — hooks for a project belong in `.config/wt.toml`,
+/// and anything else in `.git/worktrunk.toml` or the user config.
+fn disallowed_repo_key(contents: &str, shared: bool) -> Option<String> {
+    // Parse errors are reported with the file path when the source is built
+    let table: toml::Table = toml::from_str(contents).ok()?;
+    let is_overlay = |key: &str| key == "profiles" || key == "if";
+    // A key of `table` that's disallowed, as `key` or `section.key`
+    let disallowed = |table: &toml::Table, top_level: bool| {
+        table.iter().find_map(|(key, value)| {
+            if top_level && is_overlay(key) {
+                return None;
+            }
+            if key == "projects" {
+                return Some(key.clone());
+            }
+            if !shared || SHARED_REPO_KEYS.contains(&key.as_str()) {
+                return None;
+            }
+            let Some((_, allowed)) = SHARED_REPO_SECTION_KEYS
+                .iter()
+                .find(|(section, _)| section == key)
+            else {
+                return Some(key.clone());
+            };
+            let section = value.as_table()?;
+            let inner = section
+                .keys()
+                .find(|inner| !allowed.contains(&inner.as_str()))?;
+            Some(format!("{key}.{inner}"))
+        })
+    };
+    let top_level = disallowed(&table, true);
+    // Profiles and conditional sections from the tracked file can't sneak keys in either
+    let in_overlay = |overlays: &str| {
+        let sections = table.get(overlays)?.as_table()?;
+        sections.iter().find_map(|(name, section)| {
+            let key = disallowed(section.as_table()?, false)?;
+            Some(format!("{overlays}.{name:?}.{key}"))
+        })
+    };
+    top_level
+        .or_else(|| in_overlay("profiles"))
+        .or_else(|| in_overlay("if"))
+}
+
+/// Add the repository config files to `builder`, checking they only set allowed keys.
+fn add_repo_config_sources(
+    mut builder: ConfigBuilder<DefaultState>,
+) -> Result<ConfigBuilder<DefaultState>, ConfigError> {
+    for (path, shared) in repo_config_paths() {
+        let contents = std::fs::read_to_string(&path).map_err(|e| {
+            ConfigError::Message(format!(
+                "Failed to read config file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        if let Some(key) = disallowed_repo_key(&contents, shared) {
+            let name = key.rsplit('.').next().unwrap_or(&key);
+            let reason = if key == "projects" {
+                "approvals can only be stored in the user config"
+            } else if name.parse::<HookType>().is_ok() {
+                "commands from a tracked file need approval; use .config/wt.toml instead"
+            } else {
+                "a tracked file can't set it; use .git/worktrunk.toml or the user config instead"
+            };
+            return Err(ConfigError::Message(format!(
+                "{}: {key} can't be set here ({reason})",
+                path.display()
+            )));
+        }
+        builder = builder.add_source(File::from(path));
+    }
+    Ok(builder)
+}
+
+/// Config file named explicitly with `--config`.
+///
+/// Unlike the default location, an explicit file has to exist, so a typo in
+/// CI doesn't silently fall back to defaults.
+pub fn explicit_config_path() -> Option<PathBuf> {
+    CONFIG_PATH.get().cloned()
+}
+
 pub fn get_config_path() -> Option<PathBuf> {
     // Priority 1: CLI --config flag
-    if let Some(path) = CONFIG_PATH.get() {
-        return Some(path.clone());
+    if let Some(path) = explicit_config_path() {
+        return Some(path);
     }
 
     // Priority 2: Environment variable (also used by tests)
@@ -840,12 +1915,49 @@ rename-tab = "echo 'switched'"
             full: Some(true),
             branches: Some(false),
             remotes: None,
+            columns: Some(vec![ListColumn::Branch, ListColumn::Age]),
+            dim_after: Some("30d".into()),
+            highlight_within: None,
+            placeholder: Some(String::new()),
+            placeholder_style: Some(PlaceholderStyle::Reverse),
         };
         let json = serde_json::to_string(&config).unwrap();
         let parsed: ListConfig = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.full, Some(true));
         assert_eq!(parsed.branches, Some(false));
         assert_eq!(parsed.remotes, None);
+        assert_eq!(
+            parsed.columns,
+            Some(vec![ListColumn::Branch, ListColumn::Age])
+        );
+        assert_eq!(
+            parsed.dim_after(),
+            Some(std::time::Duration::from_secs(30 * 24 * 60 * 60))
+        );
+        assert_eq!(parsed.highlight_within(), None);
+        assert_eq!(parsed.placeholder.as_deref(), Some(""));
+        assert_eq!(parsed.placeholder_style, Some(PlaceholderStyle::Reverse));
+    }
+
+    #[test]
+    fn test_parse_age() {
+        use std::time::Duration;
+        assert_eq!(parse_age("2w"), Ok(Duration::from_secs(14 * 24 * 60 * 60)));
+        assert_eq!(parse_age("1d"), Ok(Duration::from_secs(24 * 60 * 60)));
+        assert_eq!(parse_age("12h"), Ok(Duration::from_secs(12 * 60 * 60)));
+        assert!(parse_age("d").is_err());
+        assert!(parse_age("30").is_err());
+        assert!(parse_age("1y").is_err());
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("20GB"), Some(20 * 1024 * 1024 * 1024));
+        assert_eq!(parse_size("512m"), Some(512 * 1024 * 1024));
+        assert_eq!(parse_size("1.5 GiB"), Some(3 * 512 * 1024 * 1024));
+        assert_eq!(parse_size("4096"), Some(4096));
+        assert_eq!(parse_size("GB"), None);
+        assert_eq!(parse_size("20PB"), None);
     }
 
     #[test]
@@ -881,6 +1993,7 @@ rename-tab = "echo 'switched'"
             "github.com/user/repo".to_string(),
             UserProjectConfig {
                 approved_commands: vec!["npm install".to_string(), "npm test".to_string()],
+                path: None,
             },
         );
         assert!(config.is_command_approved("github.com/user/repo", "npm install"));
@@ -889,6 +2002,43 @@ rename-tab = "echo 'switched'"
         assert!(!config.is_command_approved("other/project", "npm install"));
     }
 
+    #[test]
+    fn test_ci_config_api_host() {
+        let host = |url: &str| {
+            CiConfig {
+                api_url: Some(url.to_string()),
+                ..Default::default()
+            }
+            .api_host()
+            .map(str::to_string)
+        };
+        assert_eq!(
+            host("https://github.example.com/api/v3").as_deref(),
+            Some("github.example.com")
+        );
+        assert_eq!(
+            host("https://gitlab.example.com:8443").as_deref(),
+            Some("gitlab.example.com:8443")
+        );
+        assert_eq!(host("git.example.com").as_deref(), Some("git.example.com"));
+        assert_eq!(host("https://").as_deref(), None);
+        assert_eq!(CiConfig::default().api_host(), None);
+    }
+
+    #[test]
+    fn test_ci_config_gerrit_requires_api_url() {
+        let config: WorktrunkConfig = toml::from_str("[ci]\nprovider = \"gerrit\"\n").unwrap();
+        assert_eq!(
+            config.invalid_values(),
+            [("ci", "ci.provider = \"gerrit\" requires ci.api-url")]
+        );
+        let config: WorktrunkConfig = toml::from_str(
+            "[ci]\nprovider = \"gerrit\"\napi-url = \"https://review.example.com\"\n",
+        )
+        .unwrap();
+        assert!(config.invalid_values().is_empty());
+    }
+
     #[test]
     fn test_worktrunk_config_format_path() {
         let config = WorktrunkConfig::default();
@@ -935,6 +2085,53 @@ rename-tab = "echo 'switched'"
         );
     }
 
+    #[test]
+    fn test_approval_id() {
+        // Stable across runs and platforms
+        assert_eq!(approval_id(""), "811c9dc5");
+        assert_eq!(approval_id("npm ci"), approval_id("npm ci"));
+        assert_ne!(approval_id("npm ci"), approval_id("npm ci "));
+        assert_eq!(approval_id("npm ci").len(), 8);
+    }
+
+    #[test]
+    fn test_disallowed_repo_key() {
+        let settings = "worktree-path = \".worktrees/{{ branch }}\"\n[merge]\nsquash = false\n";
+        assert_eq!(disallowed_repo_key(settings, true), None);
+        assert_eq!(disallowed_repo_key(settings, false), None);
+
+        // Approvals stay in the user config
+        let projects = "[projects.\"github.com/a/b\"]\napproved-commands = [\"rm -rf /\"]\n";
+        assert_eq!(
+            disallowed_repo_key(projects, false).as_deref(),
+            Some("projects")
+        );
+
+        // Commands only from the untracked file
+        let hooks = "[post-create]\nsetup = \"make\"\n";
+        assert_eq!(
+            disallowed_repo_key(hooks, true).as_deref(),
+            Some("post-create")
+        );
+        assert_eq!(disallowed_repo_key(hooks, false), None);
+        let llm = "[commit-generation]\ncommand = \"llm\"\n";
+        assert_eq!(
+            disallowed_repo_key(llm, true).as_deref(),
+            Some("commit-generation")
+        );
+        let profile = "[profiles.ci.pre-merge]\ntest = \"make\"\n";
+        assert_eq!(
+            disallowed_repo_key(profile, true).as_deref(),
+            Some(r#"profiles."ci".pre-merge"#)
+        );
+        assert_eq!(disallowed_repo_key(profile, false), None);
+        let condition = "[if.\"github.com/acme/*\".post-start]\ndev = \"make\"\n";
+        assert_eq!(
+            disallowed_repo_key(condition, true).as_deref(),
+            Some(r#"if."github.com/acme/*".post-start"#)
+        );
+    }
+
     #[test]
     fn test_merge_config_serde() {
         let config = MergeConfig {
@@ -943,10 +2140,48 @@ rename-tab = "echo 'switched'"
             rebase: Some(false),
             remove: Some(true),
             verify: Some(true),
+            require_ci: Some(true),
+            sign: Some(true),
+            autostash: Some(true),
         };
         let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains("require-ci"));
         let parsed: MergeConfig = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.squash, Some(true));
         assert_eq!(parsed.rebase, Some(false));
+        assert_eq!(parsed.require_ci, Some(true));
+        assert_eq!(parsed.sign, Some(true));
+        assert_eq!(parsed.autostash, Some(true));
+    }
+
+    #[test]
+    fn test_sync_strategy_for_branch() {
+        let config: WorktrunkConfig = toml::from_str(
+            r#"
+[sync]
+strategy = "merge"
+
+[sync.branches]
+"*" = "rebase"
+"release/*" = "merge"
+"release/hotfix" = "rebase"
+"#,
+        )
+        .unwrap();
+        assert!(config.invalid_values().is_empty());
+        assert_eq!(config.sync_strategy("feature"), SyncStrategy::Rebase);
+        assert_eq!(config.sync_strategy("release/2.0"), SyncStrategy::Merge);
+        assert_eq!(config.sync_strategy("release/hotfix"), SyncStrategy::Rebase);
+
+        let config: WorktrunkConfig = toml::from_str("[sync]\nstrategy = \"merge\"\n").unwrap();
+        assert_eq!(config.sync_strategy("feature"), SyncStrategy::Merge);
+        assert_eq!(
+            WorktrunkConfig::default().sync_strategy("feature"),
+            SyncStrategy::Rebase
+        );
+
+        let config: WorktrunkConfig =
+            toml::from_str("[sync.branches]\n\"release/[\" = \"merge\"\n").unwrap();
+        assert_eq!(config.invalid_values().len(), 1);
     }
 }
@@ -0,0 +1,781 @@
+//! Config file validation with precise diagnostics.
+//!
+//! Checks a config file for TOML syntax errors, values of the wrong type,
+//! unknown keys, invalid templates, and values that `load()` rejects. Each
+//! problem is reported with the file, key path, and line, plus a suggestion
+//! when an unknown key looks like a typo of a known one.
+//!
+//! Unknown keys are warnings (they're ignored when loading); everything else is
+//! an error.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+use color_print::cformat;
+use config::ConfigError;
+use serde::de::DeserializeOwned;
+
+use super::expansion::check_template;
+use super::{HooksConfig, LinkKind, ProjectConfig, WorktrunkConfig};
+use crate::git::HookType;
+use crate::path::format_path_for_display;
+
+/// Which schema a config file follows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigKind {
+    /// User config, including the per-repository `.worktrunk.toml` layers
+    User,
+    /// Project config (`.config/wt.toml`)
+    Project,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// One problem found in a config file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub path: PathBuf,
+    /// Dotted key path, when the problem belongs to a key
+    pub key: Option<String>,
+    /// 1-based line number
+    pub line: Option<usize>,
+    /// Styled description
+    pub message: String,
+    /// Known key that an unknown key was probably meant to be
+    pub suggestion: Option<String>,
+}
+
+impl Diagnostic {
+    /// Description with the suggestion, without the file location
+    pub fn description(&self) -> String {
+        match &self.suggestion {
+            Some(suggestion) => cformat!("{}; did you mean <bold>{suggestion}</>?", self.message),
+            None => self.message.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format_path_for_display(&self.path))?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+        }
+        write!(f, ": {}", self.description())
+    }
+}
+
+/// Read and validate a config file.
+pub fn validate_file(kind: ConfigKind, path: &Path) -> Vec<Diagnostic> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => validate_str(kind, path, &contents),
+        Err(e) => vec![Diagnostic {
+            severity: Severity::Error,
+            path: path.to_path_buf(),
+            key: None,
+            line: None,
+            message: format!("Failed to read config file: {e}"),
+            suggestion: None,
+        }],
+    }
+}
+
+/// Validate config file `contents`; `path` is only used for reporting.
+pub fn validate_str(kind: ConfigKind, path: &Path, contents: &str) -> Vec<Diagnostic> {
+    let mut checker = Checker {
+        path,
+        contents,
+        keys: Vec::new(),
+        diagnostics: Vec::new(),
+    };
+
+    let document = match toml_edit::Document::parse(contents) {
+        Ok(document) => document,
+        Err(e) => {
+            let line = e.span().map(|span| checker.line_of(span.start));
+            checker.push(Severity::Error, None, line, e.message().trim().to_string());
+            return checker.diagnostics;
+        }
+    };
+    checker.index_keys(&mut Vec::new(), document.as_table());
+    // The document parsed, so this can't fail
+    let Ok(table) = toml::from_str::<toml::Table>(contents) else {
+        return checker.diagnostics;
+    };
+
+    match kind {
+        ConfigKind::User => {
+            let config = checker.check_types::<WorktrunkConfig>(&table);
+            checker.check_unknown_keys(&table, config.as_ref(), kind);
+            if let Some(config) = config {
+                checker.check_template("worktree-path", &config.worktree_path);
+                if let Some(template) = config
+                    .switch
+                    .as_ref()
+                    .and_then(|switch| switch.issue_branch.as_deref())
+                {
+                    checker.check_template("switch.issue-branch", template);
+                }
+                checker.check_hooks(&config.hooks);
+                for (key, message) in config.invalid_values() {
+                    let line = checker.line_of_key(key);
+                    checker.push(Severity::Error, Some(key.into()), line, message.into());
+                }
+            }
+        }
+        ConfigKind::Project => {
+            let config = checker.check_types::<ProjectConfig>(&table);
+            checker.check_unknown_keys(&table, config.as_ref(), kind);
+            if let Some(config) = config {
+                checker.check_hooks(&config.hooks);
+                if let Some(url) = config.list.as_ref().and_then(|list| list.url.as_deref()) {
+                    checker.check_template("list.url", url);
+                }
+            }
+        }
+    }
+    checker.diagnostics
+}
+
+/// Replace a generic error from loading the user config with diagnostics for
+/// the config files, when they explain it.
+///
+/// Errors that don't come from a file (e.g. environment variables) are
+/// returned unchanged.
+pub(super) fn explain_load_error(error: ConfigError) -> ConfigError {
+    let files = super::get_config_path()
+        .into_iter()
+        .filter(|path| path.exists())
+        .chain(super::repo_config_paths().into_iter().map(|(path, _)| path));
+    let errors: Vec<String> = files
+        .flat_map(|path| validate_file(ConfigKind::User, &path))
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .map(|diagnostic| diagnostic.to_string())
+        .collect();
+    if errors.is_empty() {
+        error
+    } else {
+        ConfigError::Message(errors.join("\n"))
+    }
+}
+
+/// Every key the schema knows, as a TOML table.
+///
+/// A table whose only key is `*` holds arbitrary names (aliases, project
+/// identifiers, named hook commands).
+pub(super) fn schema(kind: ConfigKind) -> toml::Table {
+    let sample = match kind {
+        ConfigKind::User => {
+            r#"
+version = 1
+worktree-path = ""
+ticket-pattern = ""
+log-file = ""
+remote = ""
+alias."*" = ""
+defaults."*" = [""]
+
+[commit-generation]
+command = ""
+args = [""]
+template = ""
+template-file = ""
+squash-template = ""
+squash-template-file = ""
+
+[projects."*"]
+approved-commands = [""]
+path = ""
+
+[list]
+full = true
+branches = true
+remotes = true
+columns = ["branch"]
+dim-after = ""
+highlight-within = ""
+placeholder = ""
+placeholder-style = "dim"
+
+[ci]
+provider = "github"
+api-url = ""
+token-env = ""
+enabled = true
+
+[commit]
+stage = "all"
+
+[styling]
+emoji = true
+
+[hooks]
+parallel = true
+
+[merge]
+squash = true
+commit = true
+rebase = true
+remove = true
+verify = true
+require-ci = true
+sign = true
+autostash = true
+
+[switch]
+init-submodules = true
+lfs-pull = true
+tmux = true
+issue-branch = ""
+
+[notify]
+enabled = true
+min-duration = ""
+
+[push]
+require-signed = true
+allowed-keys = [""]
+
+[sync]
+strategy = "rebase"
+branches."*" = "rebase"
+
+[select]
+height = ""
+preview-position = "auto"
+preview-size = ""
+preview-hidden = true
+
+[limits]
+max-worktrees = 1
+max-total-disk = ""
+"#
+        }
+        ConfigKind::Project => {
+            r#"
+[list]
+url = ""
+"#
+        }
+    };
+    let mut schema: toml::Table = toml::from_str(sample).expect("schema sample is valid TOML");
+    for hook in HookType::value_variants() {
+        let mut commands = toml::Table::new();
+        commands.insert("*".into(), toml::Value::String(String::new()));
+        schema.insert(hook.to_string(), toml::Value::Table(commands));
+    }
+    if kind == ConfigKind::User {
+        // Profiles and conditional sections can set anything except approvals
+        let mut profile = schema.clone();
+        profile.remove("projects");
+        for overlays in ["profiles", "if"] {
+            let mut sections = toml::Table::new();
+            sections.insert("*".into(), toml::Value::Table(profile.clone()));
+            schema.insert(overlays.into(), toml::Value::Table(sections));
+        }
+    }
+    schema
+}
+
+/// Schema for the children of a key, given the schema for its table
+pub(super) fn schema_child<'a>(schema: &'a toml::Table, key: &str) -> Option<&'a toml::Table> {
+    schema.get(key).or_else(|| schema.get("*"))?.as_table()
+}
+
+/// Closest known key to `key`, if any is close enough to be a likely typo
+fn suggest(key: &str, schema: &toml::Table) -> Option<String> {
+    let candidates = || schema.keys().filter(|candidate| *candidate != "*");
+    // A truncated key (`commit-gen`) means the key it's a prefix of
+    if let Some(completion) = candidates()
+        .filter(|candidate| candidate.starts_with(key))
+        .min_by_key(|candidate| candidate.len())
+    {
+        return Some(completion.clone());
+    }
+    candidates()
+        .map(|candidate| (strsim::jaro_winkler(key, candidate), candidate))
+        .filter(|(score, _)| *score > 0.8)
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, candidate)| candidate.clone())
+}
+
+/// Display a key path, quoting segments that aren't bare TOML keys
+fn display_key(segments: &[String]) -> String {
+    segments
+        .iter()
+        .map(|segment| {
+            let bare = !segment.is_empty()
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+            if bare {
+                segment.clone()
+            } else {
+                format!("{segment:?}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+struct Checker<'a> {
+    path: &'a Path,
+    contents: &'a str,
+    /// Every key path in the document with the line it's defined on
+    keys: Vec<(Vec<String>, Option<usize>)>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Checker<'_> {
+    fn push(
+        &mut self,
+        severity: Severity,
+        key: Option<String>,
+        line: Option<usize>,
+        message: String,
+    ) {
+        self.diagnostics.push(Diagnostic {
+            severity,
+            path: self.path.to_path_buf(),
+            key,
+            line,
+            message,
+            suggestion: None,
+        });
+    }
+
+    fn line_of(&self, offset: usize) -> usize {
+        let offset = offset.min(self.contents.len());
+        self.contents[..offset].matches('\n').count() + 1
+    }
+
+    fn line_of_segments(&self, segments: &[String]) -> Option<usize> {
+        self.keys
+            .iter()
+            .find(|(path, _)| path == segments)
+            .and_then(|(_, line)| *line)
+    }
+
+    fn line_of_key(&self, key: &str) -> Option<usize> {
+        self.line_of_segments(&key.split('.').map(String::from).collect::<Vec<_>>())
+    }
+
+    /// Record every key in `table` (recursively) with its spans
+    fn index_keys(&mut self, prefix: &mut Vec<String>, table: &dyn toml_edit::TableLike) {
+        for (name, item) in table.iter() {
+            prefix.push(name.to_string());
+            let span = table
+                .get_key_value(name)
+                .and_then(|(key, _)| key.span())
+                .or_else(|| item.span());
+            let line = span.map(|span| self.line_of(span.start));
+            self.keys.push((prefix.clone(), line));
+            if let Some(child) = item.as_table_like() {
+                self.index_keys(prefix, child);
+            }
+            prefix.pop();
+        }
+    }
+
+    /// Deserialize the whole file, reporting each value of the wrong type.
+    ///
+    /// serde's errors don't say which key failed (and `flatten` loses spans),
+    /// so each leaf value is deserialized on its own to find the culprits.
+    fn check_types<T: DeserializeOwned>(&mut self, table: &toml::Table) -> Option<T> {
+        let error = match T::deserialize(table.clone()) {
+            Ok(config) => return Some(config),
+            Err(e) => e,
+        };
+
+        let mut leaves = Vec::new();
+        collect_leaves(&mut Vec::new(), table, &mut leaves);
+        let before = self.diagnostics.len();
+        for (segments, value) in leaves {
+            let isolated = nest(&segments, value.clone());
+            if let Err(e) = T::deserialize(isolated) {
+                let key = display_key(&segments);
+                let line = self.line_of_segments(&segments);
+                let message = cformat!("<bold>{key}</>: {}", e.message().trim());
+                self.push(Severity::Error, Some(key), line, message);
+            }
+        }
+        if self.diagnostics.len() == before {
+            self.push(
+                Severity::Error,
+                None,
+                None,
+                error.message().trim().to_string(),
+            );
+        }
+        None
+    }
+
+    /// Warn about keys that are neither in the schema nor kept by deserialization.
+    ///
+    /// Without a deserialized `config` (it had type errors), only the schema is used.
+    fn check_unknown_keys<T: serde::Serialize>(
+        &mut self,
+        table: &toml::Table,
+        config: Option<&T>,
+        kind: ConfigKind,
+    ) {
+        let kept = config.and_then(|config| toml::Table::try_from(config).ok());
+        self.walk_unknown(&mut Vec::new(), table, kept.as_ref(), &schema(kind));
+    }
+
+    fn walk_unknown(
+        &mut self,
+        prefix: &mut Vec<String>,
+        table: &toml::Table,
+        kept: Option<&toml::Table>,
+        schema: &toml::Table,
+    ) {
+        for (key, value) in table {
+            prefix.push(key.clone());
+            let kept_value = kept.and_then(|kept| kept.get(key));
+            let known =
+                kept_value.is_some() || schema.contains_key(key) || schema.contains_key("*");
+            if !known {
+                let line = self.line_of_segments(prefix);
+                let display = display_key(prefix);
+                self.diagnostics.push(Diagnostic {
+                    severity: Severity::Warning,
+                    path: self.path.to_path_buf(),
+                    message: cformat!("Unknown key <bold>{display}</> will be ignored"),
+                    key: Some(display),
+                    line,
+                    suggestion: suggest(key, schema),
+                });
+            } else if let (Some(child), Some(child_schema)) =
+                (value.as_table(), schema_child(schema, key))
+            {
+                let kept_child = kept_value.and_then(toml::Value::as_table);
+                self.walk_unknown(prefix, child, kept_child, child_schema);
+            }
+            prefix.pop();
+        }
+    }
+
+    fn check_template(&mut self, key: &str, template: &str) {
+        if let Err(e) = check_template(template) {
+            let line = self.line_of_key(key);
+            let message = cformat!("<bold>{key}</>: {e}");
+            self.push(Severity::Error, Some(key.into()), line, message);
+        }
+    }
+
+    fn check_hooks(&mut self, hooks: &HooksConfig) {
+        for &hook in HookType::value_variants() {
+            let Some(config) = hooks.get(hook) else {
+                continue;
+            };
+            for command in config.commands() {
+                let key = match &command.name {
+                    Some(name) => display_key(&[hook.to_string(), name.clone()]),
+                    None => hook.to_string(),
+                };
+                self.check_template(&key, &command.template);
+            }
+            // Links only run when a worktree is created
+            if hook != HookType::PostCreate {
+                for kind in [LinkKind::Symlink, LinkKind::Hardlink] {
+                    if config.links().iter().any(|link| link.kind == kind) {
+                        let key = display_key(&[hook.to_string(), kind.to_string()]);
+                        let line = self.line_of_key(&key);
+                        let message = cformat!(
+                            "<bold>{key}</> will be ignored; {kind} only applies to post-create"
+                        );
+                        self.push(Severity::Warning, Some(key), line, message);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Every non-table value in `table`, with its key path
+///
+/// A hook command's table (`[post-create.install]` with `command`, `timeout`,
+/// ...) only deserializes as a whole, so it counts as one value.
+fn collect_leaves<'a>(
+    prefix: &mut Vec<String>,
+    table: &'a toml::Table,
+    leaves: &mut Vec<(Vec<String>, &'a toml::Value)>,
+) {
+    let in_hook = prefix
+        .last()
+        .is_some_and(|parent| parent.parse::<HookType>().is_ok());
+    for (key, value) in table {
+        prefix.push(key.clone());
+        match value {
+            toml::Value::Table(child) if !child.is_empty() && !in_hook => {
+                collect_leaves(prefix, child, leaves)
+            }
+            _ => leaves.push((prefix.clone(), value)),
+        }
+        prefix.pop();
+    }
+}
+
+/// A table holding only `value`, at the key path `segments`
+fn nest(segments: &[String], value: toml::Value) -> toml::Table {
+    let (last, parents) = segments.split_last().expect("key paths aren't empty");
+    let mut table = toml::Table::new();
+    table.insert(last.clone(), value);
+    for parent in parents.iter().rev() {
+        let mut outer = toml::Table::new();
+        outer.insert(parent.clone(), toml::Value::Table(table));
+        table = outer;
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Severity, key, line, and suggestion of a diagnostic
+    type Summary = (Severity, Option<String>, Option<usize>, Option<String>);
+
+    fn check(kind: ConfigKind, contents: &str) -> Vec<Summary> {
+        validate_str(kind, Path::new("config.toml"), contents)
+            .into_iter()
+            .map(|d| (d.severity, d.key, d.line, d.suggestion))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_config() {
+        let contents = r#"
+worktree-path = ".worktrees/{{ branch | sanitize }}"
+
+[merge]
+squash = false
+
+[projects."github.com/user/repo"]
+approved-commands = []
+
+[post-create]
+install = "npm ci"
+"#;
+        assert_eq!(check(ConfigKind::User, contents), []);
+    }
+
+    #[test]
+    fn test_syntax_error_line() {
+        let diagnostics = check(ConfigKind::User, "worktree-path = \"a\"\n[merge\n");
+        assert_eq!(diagnostics, [(Severity::Error, None, Some(2), None)]);
+    }
+
+    #[test]
+    fn test_wrong_type_points_at_key() {
+        let contents = "worktree-path = \"../{{ branch }}\"\n\n[merge]\nsquash = \"yes\"\n";
+        let diagnostics = validate_str(ConfigKind::User, Path::new("c.toml"), contents);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].key.as_deref(), Some("merge.squash"));
+        assert_eq!(diagnostics[0].line, Some(4));
+        assert!(
+            diagnostics[0].message.contains("expected a boolean"),
+            "{}",
+            diagnostics[0].message
+        );
+    }
+
+    #[test]
+    fn test_unknown_keys_with_suggestions() {
+        let contents =
+            "[merge]\nsqaush = true\n\n[marge]\nx = 1\n\n[projects.\"repo\"]\npth = \"/x\"\n";
+        assert_eq!(
+            check(ConfigKind::User, contents),
+            [
+                (
+                    Severity::Warning,
+                    Some("merge.sqaush".into()),
+                    Some(2),
+                    Some("squash".into())
+                ),
+                (
+                    Severity::Warning,
+                    Some("marge".into()),
+                    Some(4),
+                    Some("merge".into())
+                ),
+                (
+                    Severity::Warning,
+                    Some("projects.repo.pth".into()),
+                    Some(8),
+                    Some("path".into())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_suggest_completes_truncated_key() {
+        let schema = schema(ConfigKind::User);
+        assert_eq!(
+            suggest("commit-gen", &schema).as_deref(),
+            Some("commit-generation")
+        );
+        assert_eq!(suggest("unrelated", &schema), None);
+    }
+
+    #[test]
+    fn test_hook_name_typo() {
+        let diagnostics = check(ConfigKind::Project, "[post-craete]\nx = \"make\"\n");
+        assert_eq!(
+            diagnostics,
+            [(
+                Severity::Warning,
+                Some("post-craete".into()),
+                Some(1),
+                Some("post-create".into())
+            )]
+        );
+    }
+
+    #[test]
+    fn test_invalid_templates() {
+        let contents = "[pre-merge]\ntest = \"cargo test {{ branch | nope }}\"\n\n[list]\nurl = \"http://{{ branch\"\n";
+        let keys: Vec<_> = check(ConfigKind::Project, contents)
+            .into_iter()
+            .map(|(severity, key, line, _)| (severity, key, line))
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                (Severity::Error, Some("pre-merge.test".into()), Some(2)),
+                (Severity::Error, Some("list.url".into()), Some(5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let diagnostics = check(ConfigKind::User, "worktree-path = \"/abs/{{ branch }}\"\n");
+        assert_eq!(
+            diagnostics,
+            [(Severity::Error, Some("worktree-path".into()), Some(1), None)]
+        );
+    }
+
+    #[test]
+    fn test_invalid_list_ages() {
+        let diagnostics = check(
+            ConfigKind::User,
+            "[list]\ndim-after = \"30d\"\nhighlight-within = \"soon\"\n",
+        );
+        assert_eq!(
+            diagnostics,
+            [(
+                Severity::Error,
+                Some("list.highlight-within".into()),
+                Some(3),
+                None
+            )]
+        );
+    }
+
+    #[test]
+    fn test_invalid_list_placeholder() {
+        let diagnostics = check(
+            ConfigKind::User,
+            "[list]\nplaceholder-style = \"bold\"\nplaceholder = \"...\"\n",
+        );
+        assert_eq!(
+            diagnostics,
+            [(
+                Severity::Error,
+                Some("list.placeholder".into()),
+                Some(3),
+                None
+            )]
+        );
+        assert!(check(ConfigKind::User, "[list]\nplaceholder = \"\"\n").is_empty());
+    }
+
+    #[test]
+    fn test_invalid_select_layout() {
+        let diagnostics = check(
+            ConfigKind::User,
+            "[select]\nheight = \"tall\"\npreview-size = \"40%\"\n",
+        );
+        assert_eq!(
+            diagnostics,
+            [(Severity::Error, Some("select.height".into()), Some(2), None)]
+        );
+        assert!(check(ConfigKind::User, "[select]\nheight = \"full\"\n").is_empty());
+    }
+
+    /// Key paths in `sample` that are missing from `kept`
+    fn missing_keys(prefix: &str, sample: &toml::Table, kept: &toml::Table) -> Vec<String> {
+        sample
+            .iter()
+            .flat_map(|(key, value)| {
+                let path = format!("{prefix}{key}");
+                match (kept.get(key), value.as_table()) {
+                    (None, _) => vec![path],
+                    (Some(toml::Value::Table(kept)), Some(sample)) => {
+                        missing_keys(&format!("{path}."), sample, kept)
+                    }
+                    _ => vec![],
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_hook_entry_options() {
+        let contents = r#"
+[post-create.install]
+command = "npm ci"
+timeout = "5m"
+on-failure = "warn"
+"#;
+        assert_eq!(check(ConfigKind::Project, contents), []);
+
+        let contents = "[post-create.install]\ncommand = \"npm ci\"\non-failure = \"later\"\n";
+        let diagnostics = validate_str(ConfigKind::Project, Path::new("wt.toml"), contents);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].key.as_deref(), Some("post-create.install"));
+        assert_eq!(diagnostics[0].line, Some(1));
+    }
+
+    #[test]
+    fn test_hook_links() {
+        let contents = "[post-create]\nsymlink = [\"node_modules\"]\ninstall = \"npm ci\"\n";
+        assert_eq!(check(ConfigKind::Project, contents), []);
+
+        let contents = "[pre-merge]\nhardlink = [\"target\"]\n";
+        assert_eq!(
+            check(ConfigKind::Project, contents),
+            [(
+                Severity::Warning,
+                Some("pre-merge.hardlink".into()),
+                Some(2),
+                None
+            )]
+        );
+    }
+
+    #[test]
+    fn test_schema_matches_config_types() {
+        // Every key in the schema must survive deserializing into the config
+        // type, so the schema never suggests a key that would be ignored
+        let user = schema(ConfigKind::User);
+        let config: WorktrunkConfig = user.clone().try_into().unwrap();
+        let kept = toml::Table::try_from(&config).unwrap();
+        assert_eq!(missing_keys("", &user, &kept), Vec::<String>::new());
+
+        let project = schema(ConfigKind::Project);
+        let config: ProjectConfig = project.clone().try_into().unwrap();
+        let kept = toml::Table::try_from(&config).unwrap();
+        assert_eq!(missing_keys("", &project, &kept), Vec::<String>::new());
+    }
+}
@@ -61,6 +61,11 @@ fn format_relative_time_impl(timestamp: i64, now: i64) -> String {
 /// - Sibling: `../sibling`
 /// - Unrelated paths fall back to `~/...` or absolute
 pub fn shorten_path(path: &Path, main_worktree_path: &Path) -> String {
+    // Drop Windows verbatim prefixes (`\\?\C:\...`) so canonicalized and
+    // git-reported paths compare as the same location
+    let path = dunce::simplified(path);
+    let main_worktree_path = dunce::simplified(main_worktree_path);
+
     // Same path = main worktree
     if path == main_worktree_path {
         return ".".to_string();
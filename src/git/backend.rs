@@ -0,0 +1,57 @@
+//! How [`Repository`](super::Repository) runs git.
+//!
+//! Every git command a `Repository` issues goes through a [`GitBackend`]. The
+//! default, [`ProcessBackend`], spawns the `git` binary; tests of code that
+//! consumes worktrunk types can substitute a scripted backend (see
+//! `worktrunk::testing::MockGit`) to run without a real repository.
+
+use std::ffi::OsString;
+use std::path::Path;
+use std::process::{Command, Output};
+
+/// Runs git commands on behalf of a [`Repository`](super::Repository)
+pub trait GitBackend: std::fmt::Debug + Send + Sync {
+    /// Run `git <args>` in `dir`, returning its output whatever the exit status.
+    ///
+    /// `context` is a short label for logging (usually the worktree's name).
+    fn run(&self, dir: &Path, args: &[&str], context: &str) -> std::io::Result<Output>;
+}
+
+/// Variables that point git at a particular repository. Cleared for every
+/// command so each runs against the repository found from its own directory,
+/// even when worktrunk itself was started with them set (e.g. from a git hook).
+const REPOSITORY_ENV: &[&str] = &[
+    "GIT_DIR",
+    "GIT_WORK_TREE",
+    "GIT_COMMON_DIR",
+    "GIT_INDEX_FILE",
+];
+
+/// Runs git as a child process
+#[derive(Debug, Default, Clone)]
+pub struct ProcessBackend {
+    env: Vec<(OsString, OsString)>,
+}
+
+impl ProcessBackend {
+    /// Set an environment variable for every git command, e.g. to isolate
+    /// tests from the user's git config, or to select a repository with
+    /// `GIT_DIR`
+    pub fn env(mut self, key: impl Into<OsString>, value: impl Into<OsString>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+}
+
+impl GitBackend for ProcessBackend {
+    fn run(&self, dir: &Path, args: &[&str], context: &str) -> std::io::Result<Output> {
+        let mut cmd = Command::new("git");
+        cmd.args(args);
+        cmd.current_dir(dir);
+        for key in REPOSITORY_ENV {
+            cmd.env_remove(key);
+        }
+        cmd.envs(self.env.iter().map(|(key, value)| (key, value)));
+        crate::shell_exec::run(&mut cmd, Some(context))
+    }
+}
@@ -7,10 +7,12 @@ use color_print::cformat;
 pub struct LineDiff {
     pub added: usize,
     pub deleted: usize,
+    /// Files changed, including binary files (which have no line counts)
+    pub files: usize,
 }
 
 impl LineDiff {
-    /// Parse `git diff --numstat` output into aggregated line totals.
+    /// Parse `git diff --numstat` output into aggregated line and file totals.
     pub fn from_numstat(output: &str) -> anyhow::Result<Self> {
         let mut totals = LineDiff::default();
 
@@ -28,6 +30,10 @@ impl LineDiff {
             };
 
             // Binary files show "-" for added/deleted
+            if added_str == "-" && deleted_str == "-" {
+                totals.files += 1;
+                continue;
+            }
             if added_str == "-" || deleted_str == "-" {
                 continue;
             }
@@ -41,11 +47,13 @@ impl LineDiff {
 
             totals.added += added;
             totals.deleted += deleted;
+            totals.files += 1;
         }
 
         Ok(totals)
     }
 
+    /// No lines changed (binary-only changes count as empty)
     pub fn is_empty(&self) -> bool {
         self.added == 0 && self.deleted == 0
     }
@@ -62,6 +70,7 @@ impl From<(usize, usize)> for LineDiff {
         Self {
             added: value.0,
             deleted: value.1,
+            files: 0,
         }
     }
 }
@@ -149,6 +158,7 @@ mod tests {
         let diff = LineDiff {
             added: 0,
             deleted: 0,
+            files: 1,
         };
         assert!(diff.is_empty());
     }
@@ -158,6 +168,7 @@ mod tests {
         let diff = LineDiff {
             added: 5,
             deleted: 0,
+            files: 1,
         };
         assert!(!diff.is_empty());
     }
@@ -167,6 +178,7 @@ mod tests {
         let diff = LineDiff {
             added: 0,
             deleted: 5,
+            files: 1,
         };
         assert!(!diff.is_empty());
     }
@@ -183,6 +195,7 @@ mod tests {
         let diff = LineDiff {
             added: 10,
             deleted: 5,
+            files: 2,
         };
         let tuple: (usize, usize) = diff.into();
         assert_eq!(tuple, (10, 5));
@@ -55,12 +55,43 @@ pub enum GitError {
     },
     InvalidReference {
         reference: String,
+        /// Existing branches with similar names, closest first
+        similar: Vec<String>,
+    },
+
+    // Git command errors
+    /// A git command exited unsuccessfully.
+    ///
+    /// Unlike other variants, Display is git's output verbatim, so callers can
+    /// embed it in a more specific error (e.g. [`GitError::PushFailed`]); use
+    /// [`GitError::styled`] to show it to the user directly.
+    CommandFailed {
+        /// Arguments passed to git, without the leading `git`
+        args: Vec<String>,
+        /// Exit code, or `None` if git was killed by a signal
+        exit_code: Option<i32>,
+        /// Captured error output (stderr, followed by any stdout)
+        stderr: String,
     },
 
     // Worktree errors
     WorktreeMissing {
         branch: String,
     },
+    /// The current directory was a worktree that has since been removed
+    WorktreeDeleted {
+        worktree: PathBuf,
+        /// The main worktree it belonged to, if it can be found
+        primary: Option<PathBuf>,
+        /// Whether shell integration is taking the user there
+        changed_directory: bool,
+    },
+    /// A linked worktree's `.git` file points into a repository that isn't
+    /// there any more
+    RepositoryMoved {
+        worktree: PathBuf,
+        repository: PathBuf,
+    },
     NoWorktreeFound {
         branch: String,
     },
@@ -78,6 +109,15 @@ pub enum GitError {
         path: PathBuf,
         create: bool,
     },
+    /// Another branch's name maps to the same worktree path (`feat/x` and `feat-x`)
+    WorktreePathCollision {
+        branch: String,
+        path: PathBuf,
+        occupant: String,
+        create: bool,
+        /// A similar branch name whose path is free
+        alternative: Option<String>,
+    },
     WorktreeCreationFailed {
         branch: String,
         base_branch: Option<String>,
@@ -101,9 +141,27 @@ pub enum GitError {
         commits_formatted: String,
         in_merge_context: bool,
     },
+    /// `wt merge --require-ci` found the branch's CI not passing
+    CiNotPassing {
+        branch: String,
+        reason: String,
+    },
+    /// Outgoing commits without a good signature from an allowed key
+    /// (`push.require-signed`), one formatted line each
+    UnsignedCommits {
+        target_branch: String,
+        commits: Vec<String>,
+    },
     RebaseConflict {
         target_branch: String,
         git_output: String,
+        /// Stopped partway through `wt merge`, which `wt merge --continue` resumes
+        in_merge_context: bool,
+    },
+    /// `wt sync` stopped with conflicts merging the target into the branch
+    MergeConflict {
+        target_branch: String,
+        git_output: String,
     },
     NotRebased {
         target_branch: String,
@@ -131,6 +189,12 @@ pub enum GitError {
     ProjectConfigNotFound {
         config_path: PathBuf,
     },
+    /// A forge CLI (`gh`, `glab`) failed, e.g. offline or unauthenticated
+    ProviderCommandFailed {
+        /// The command as run, e.g. `gh pr list`
+        command: String,
+        error: String,
+    },
     Other {
         message: String,
     },
@@ -138,6 +202,139 @@ pub enum GitError {
 
 impl std::error::Error for GitError {}
 
+/// Class of failure, each with its own process exit code so wrappers and CI
+/// scripts can branch on why `wt` failed.
+///
+/// Child processes (hooks, `wt step for-each`) pass their own exit code through
+/// instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    /// Any other failure (1)
+    General,
+    /// Invalid arguments or an unknown name (2, matching clap's parse errors)
+    Usage,
+    /// Refused because a worktree has uncommitted changes (3)
+    DirtyWorktree,
+    /// Conflicting or diverged history (4)
+    Conflict,
+    /// A fetch, remote, or CI provider request failed (5)
+    Remote,
+}
+
+impl FailureKind {
+    /// The process exit code for this class
+    pub const fn exit_code(self) -> i32 {
+        match self {
+            FailureKind::General => 1,
+            FailureKind::Usage => 2,
+            FailureKind::DirtyWorktree => 3,
+            FailureKind::Conflict => 4,
+            FailureKind::Remote => 5,
+        }
+    }
+}
+
+/// Git subcommands that talk to a remote
+const REMOTE_GIT_COMMANDS: &[&str] = &["fetch", "pull", "ls-remote", "clone", "remote"];
+
+impl GitError {
+    /// Which class of failure this is, deciding the exit code
+    pub fn failure_kind(&self) -> FailureKind {
+        match self {
+            GitError::UncommittedChanges { .. } | GitError::ConflictingChanges { .. } => {
+                FailureKind::DirtyWorktree
+            }
+            GitError::NotFastForward { .. }
+            | GitError::RebaseConflict { .. }
+            | GitError::MergeConflict { .. }
+            | GitError::NotRebased { .. } => FailureKind::Conflict,
+            GitError::HookCommandNotFound { .. } => FailureKind::Usage,
+            GitError::ProviderCommandFailed { .. } => FailureKind::Remote,
+            GitError::CommandFailed { args, .. }
+                if args
+                    .first()
+                    .is_some_and(|command| REMOTE_GIT_COMMANDS.contains(&command.as_str())) =>
+            {
+                FailureKind::Remote
+            }
+            _ => FailureKind::General,
+        }
+    }
+
+    /// Build a [`GitError::CommandFailed`] from a finished git process
+    pub fn command_failed(args: &[&str], output: &std::process::Output) -> Self {
+        // Git uses \r for progress updates; in non-TTY contexts this causes snapshot instability
+        let stderr = String::from_utf8_lossy(&output.stderr).replace('\r', "\n");
+        // Some git commands print errors to stdout (e.g., `commit` with nothing to commit)
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = [stderr.trim(), stdout.trim()]
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+        GitError::CommandFailed {
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            exit_code: output.status.code(),
+            stderr,
+        }
+    }
+
+    /// Git's error output, if this is a failed git command
+    pub fn git_stderr(err: &anyhow::Error) -> Option<&str> {
+        match err.downcast_ref::<GitError>()? {
+            GitError::CommandFailed { stderr, .. } => Some(stderr),
+            _ => None,
+        }
+    }
+
+    /// Render for the user: the styled Display, and for failed git commands
+    /// the command line, exit code, and output, with a hint when the failure
+    /// is a familiar one.
+    pub fn styled(&self) -> String {
+        let GitError::CommandFailed {
+            args,
+            exit_code,
+            stderr,
+        } = self
+        else {
+            return self.to_string();
+        };
+        let command = std::iter::once("git")
+            .chain(args.iter().map(String::as_str))
+            .map(|arg| escape(Cow::Borrowed(arg)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let status = match exit_code {
+            Some(code) => format!("exit {code}"),
+            None => "killed by signal".to_string(),
+        };
+        let header = error_message(cformat!("<bold>{command}</> failed ({status})"));
+        let block = format_error_block(header, stderr);
+        match command_failure_hint(stderr) {
+            Some(hint) => format!("{block}\n{}", hint_message(hint)),
+            None => block,
+        }
+    }
+}
+
+/// A hint for git failures with a well-known fix
+fn command_failure_hint(stderr: &str) -> Option<String> {
+    if stderr.contains("index.lock': File exists") {
+        Some(
+            "Another git process is running; if none is, remove the stale .git/index.lock"
+                .to_string(),
+        )
+    } else if stderr.contains("Please tell me who you are") {
+        Some(cformat!(
+            "To set your identity, run <bright-black>git config --global user.email <<email>></>"
+        ))
+    } else if stderr.contains("not a git repository") {
+        Some("Run wt from inside a git repository".to_string())
+    } else {
+        None
+    }
+}
+
 impl std::fmt::Display for GitError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -189,19 +386,31 @@ impl std::fmt::Display for GitError {
                 )
             }
 
-            GitError::InvalidReference { reference } => {
+            GitError::InvalidReference { reference, similar } => {
                 let create_cmd = suggest_command("switch", &[reference], &["--create"]);
                 let list_cmd = suggest_command("list", &[], &["--branches", "--remotes"]);
+                let message = if similar.is_empty() {
+                    cformat!("Branch <bold>{reference}</> not found")
+                } else {
+                    let similar = similar
+                        .iter()
+                        .map(|branch| cformat!("<bold>{branch}</>"))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    cformat!("Branch <bold>{reference}</> not found — similar branches: {similar}")
+                };
                 write!(
                     f,
                     "{}\n{}",
-                    error_message(cformat!("Branch <bold>{reference}</> not found")),
+                    error_message(message),
                     hint_message(cformat!(
                         "To create a new branch, run <bright-black>{create_cmd}</>; to list branches, run <bright-black>{list_cmd}</>"
                     ))
                 )
             }
 
+            GitError::CommandFailed { stderr, .. } => write!(f, "{stderr}"),
+
             GitError::WorktreeMissing { branch } => {
                 write!(
                     f,
@@ -213,6 +422,58 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::WorktreeDeleted {
+                worktree,
+                primary,
+                changed_directory,
+            } => {
+                write!(
+                    f,
+                    "{}",
+                    error_message(cformat!(
+                        "Worktree <bold>{}</> has been removed",
+                        format_path_for_display(worktree)
+                    ))
+                )?;
+                match primary {
+                    Some(primary) if *changed_directory => write!(
+                        f,
+                        "\n{}",
+                        info_message(cformat!(
+                            "Changed directory to the main worktree <bold>{}</>",
+                            format_path_for_display(primary)
+                        ))
+                    ),
+                    Some(primary) => write!(
+                        f,
+                        "\n{}",
+                        hint_message(cformat!(
+                            "To return to the main worktree, run <bright-black>cd {}</>",
+                            format_path_for_display(primary)
+                        ))
+                    ),
+                    None => Ok(()),
+                }
+            }
+
+            GitError::RepositoryMoved {
+                worktree,
+                repository,
+            } => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Worktree <bold>{}</> can't find its repository at <bold>{}</>",
+                        format_path_for_display(worktree),
+                        format_path_for_display(repository)
+                    )),
+                    hint_message(cformat!(
+                        "If the repository moved, to relink its worktrees, run <bright-black>wt repair --from <<path>></>"
+                    ))
+                )
+            }
+
             GitError::NoWorktreeFound { branch } => {
                 write!(
                     f,
@@ -284,6 +545,42 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::WorktreePathCollision {
+                branch,
+                path,
+                occupant,
+                create,
+                alternative,
+            } => {
+                let path_display = format_path_for_display(path);
+                let switch_cmd = suggest_command("switch", &[occupant], &[]);
+                let hint = match alternative {
+                    Some(alternative) if *create => cformat!(
+                        "To go to that worktree, run <bright-black>{switch_cmd}</>; to use another name, run <bright-black>{}</>",
+                        suggest_command("switch", &[alternative], &["--create"])
+                    ),
+                    Some(alternative) => {
+                        let rename = format!(
+                            "git branch -m {} {}",
+                            escape(Cow::Borrowed(branch.as_str())),
+                            escape(Cow::Borrowed(alternative.as_str()))
+                        );
+                        cformat!(
+                            "To go to that worktree, run <bright-black>{switch_cmd}</>; to rename the branch, run <bright-black>{rename}</>"
+                        )
+                    }
+                    None => cformat!("To go to that worktree, run <bright-black>{switch_cmd}</>"),
+                };
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Cannot create worktree for <bold>{branch}</> — <bold>{occupant}</> maps to the same path, and its worktree is at <bold>{path_display}</>"
+                    )),
+                    hint_message(hint)
+                )
+            }
+
             GitError::WorktreeCreationFailed {
                 branch,
                 base_branch,
@@ -385,13 +682,28 @@ impl std::fmt::Display for GitError {
             GitError::RebaseConflict {
                 target_branch,
                 git_output,
+                in_merge_context,
             } => {
                 write!(
                     f,
                     "{}",
                     error_message(cformat!("Rebase onto <bold>{target_branch}</> incomplete"))
                 )?;
-                if !git_output.is_empty() {
+                if *in_merge_context {
+                    if !git_output.is_empty() {
+                        write!(f, "\n{}", format_with_gutter(git_output, None))?;
+                    }
+                    write!(
+                        f,
+                        "\n{}\n{}",
+                        hint_message(cformat!(
+                            "To finish the merge after resolving conflicts, run <bright-black>wt merge --continue</>"
+                        )),
+                        hint_message(cformat!(
+                            "To undo the merge, run <bright-black>wt merge --abort</>"
+                        ))
+                    )
+                } else if !git_output.is_empty() {
                     write!(f, "\n{}", format_with_gutter(git_output, None))
                 } else {
                     write!(
@@ -407,6 +719,63 @@ impl std::fmt::Display for GitError {
                 }
             }
 
+            GitError::MergeConflict {
+                target_branch,
+                git_output,
+            } => {
+                write!(
+                    f,
+                    "{}",
+                    error_message(cformat!("Merge of <bold>{target_branch}</> incomplete"))
+                )?;
+                if !git_output.is_empty() {
+                    write!(f, "\n{}", format_with_gutter(git_output, None))?;
+                }
+                write!(
+                    f,
+                    "\n{}\n{}",
+                    hint_message(cformat!(
+                        "To finish after resolving conflicts, run <bright-black>git commit</>"
+                    )),
+                    hint_message(cformat!("To abort, run <bright-black>git merge --abort</>"))
+                )
+            }
+
+            GitError::CiNotPassing { branch, reason } => {
+                let override_cmd = suggest_command("merge", &[], &["--override-ci"]);
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!("Can't merge <bold>{branch}</>: {reason}")),
+                    hint_message(cformat!(
+                        "To merge anyway, run <bright-black>{override_cmd}</>"
+                    ))
+                )
+            }
+
+            GitError::UnsignedCommits {
+                target_branch,
+                commits,
+            } => {
+                let count = commits.len();
+                let noun = if count == 1 {
+                    "commit isn't"
+                } else {
+                    "commits aren't"
+                };
+                write!(
+                    f,
+                    "{}\n{}\n{}",
+                    error_message(cformat!(
+                        "Can't push to local <bold>{target_branch}</> branch: {count} {noun} signed by an allowed key"
+                    )),
+                    format_with_gutter(&commits.join("\n"), None),
+                    hint_message(cformat!(
+                        "To re-sign them, run <bright-black>git rebase --exec 'git commit --amend --no-edit -S' {target_branch}</>"
+                    ))
+                )
+            }
+
             GitError::NotRebased { target_branch } => {
                 let rebase_cmd = suggest_command("step", &["rebase", target_branch], &[]);
                 write!(
@@ -495,6 +864,11 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::ProviderCommandFailed { command, error } => {
+                let header = error_message(cformat!("<bold>{command}</> failed"));
+                write!(f, "{}", format_error_block(header, error))
+            }
+
             GitError::ParseError { message } => {
                 write!(f, "{}", error_message(message))
             }
@@ -574,6 +948,12 @@ pub fn exit_code(err: &anyhow::Error) -> Option<i32> {
     if let Some(wrapper) = err.downcast_ref::<HookErrorWithHint>() {
         return exit_code(&wrapper.inner);
     }
+    if let Some(e) = err.downcast_ref::<GitError>() {
+        return match e.failure_kind() {
+            FailureKind::General => None,
+            kind => Some(kind.exit_code()),
+        };
+    }
     err.downcast_ref::<WorktrunkError>().and_then(|e| match e {
         WorktrunkError::ChildProcessExited { code, .. } => Some(*code),
         WorktrunkError::HookCommandFailed { exit_code, .. } => *exit_code,
@@ -777,6 +1157,34 @@ mod tests {
             None
         );
 
+        // GitError failure classes
+        let dirty = GitError::UncommittedChanges {
+            action: Some("merge".into()),
+            branch: None,
+        };
+        assert_eq!(exit_code(&dirty.into()), Some(3));
+        let conflict = GitError::NotRebased {
+            target_branch: "main".into(),
+        };
+        assert_eq!(exit_code(&conflict.into()), Some(4));
+        let fetch = GitError::CommandFailed {
+            args: vec!["fetch".into(), "origin".into()],
+            exit_code: Some(128),
+            stderr: "fatal: unable to access".into(),
+        };
+        assert_eq!(exit_code(&fetch.into()), Some(5));
+        let local = GitError::CommandFailed {
+            args: vec!["commit".into()],
+            exit_code: Some(1),
+            stderr: "nothing to commit".into(),
+        };
+        assert_eq!(exit_code(&local.into()), None);
+        let unknown = GitError::HookCommandNotFound {
+            name: "lint".into(),
+            available: vec![],
+        };
+        assert_eq!(exit_code(&unknown.into()), Some(2));
+
         // Wrapped hook error
         let inner: anyhow::Error = WorktrunkError::HookCommandFailed {
             hook_type: HookType::PreCommit,
@@ -864,6 +1272,7 @@ mod tests {
     fn test_git_error_invalid_reference() {
         let err = GitError::InvalidReference {
             reference: "nonexistent".into(),
+            similar: vec![],
         };
         let display = err.to_string();
         assert!(display.contains("nonexistent"));
@@ -1015,6 +1424,7 @@ mod tests {
         let err = GitError::RebaseConflict {
             target_branch: "main".into(),
             git_output: "CONFLICT in file.rs".into(),
+            in_merge_context: false,
         };
         let display = err.to_string();
         assert!(display.contains("main"));
@@ -1024,9 +1434,20 @@ mod tests {
         let err = GitError::RebaseConflict {
             target_branch: "main".into(),
             git_output: String::new(),
+            in_merge_context: false,
         };
         let display = err.to_string();
         assert!(display.contains("rebase --continue"));
+
+        // Stopped during `wt merge`: hints point at the merge command
+        let err = GitError::RebaseConflict {
+            target_branch: "main".into(),
+            git_output: "CONFLICT in file.rs".into(),
+            in_merge_context: true,
+        };
+        let display = err.to_string();
+        assert!(display.contains("wt merge --continue"));
+        assert!(display.contains("wt merge --abort"));
     }
 
     #[test]
@@ -1279,6 +1700,7 @@ mod tests {
         let err = GitError::RebaseConflict {
             target_branch: "main".into(),
             git_output: "".into(),
+            in_merge_context: false,
         };
         let display = err.to_string();
         assert!(display.contains("incomplete"));
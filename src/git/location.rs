@@ -0,0 +1,129 @@
+//! Explaining why git can't find a repository from a worktree.
+//!
+//! Git reports both a deleted worktree and one whose repository has moved as
+//! "not a git repository" (or, when the directory itself is gone, "Unable to
+//! read current working directory"). The filesystem usually says which it
+//! is: a linked worktree's `.git` file names its admin directory, and the
+//! default path template puts worktrees beside the main one.
+
+use std::path::{Path, PathBuf};
+
+use super::{GitError, path_from_git};
+
+/// Where a linked worktree's `.git` file points; `None` for a `.git`
+/// directory or no `.git` at all.
+pub(super) fn gitdir_link(worktree: &Path) -> Option<PathBuf> {
+    // A `.git` directory fails to read as a string
+    let contents = std::fs::read_to_string(worktree.join(".git")).ok()?;
+    let gitdir = contents.strip_prefix("gitdir:")?.trim();
+    Some(worktree.join(path_from_git(gitdir)))
+}
+
+/// The error explaining why git fails in `dir`, when it's (or was) a worktree
+/// that has been removed or whose repository has moved.
+///
+/// Returns `None` when `dir` is inside an intact worktree, or doesn't look
+/// like it was ever part of one.
+pub fn diagnose_worktree(dir: &Path) -> Option<GitError> {
+    if !dir.exists() {
+        return Some(GitError::WorktreeDeleted {
+            worktree: dir.to_path_buf(),
+            primary: primary_worktree_near(dir),
+            changed_directory: false,
+        });
+    }
+
+    let Some(worktree) = dir.ancestors().find(|path| path.join(".git").exists()) else {
+        // Nothing left of the worktree but files git didn't remove
+        return primary_worktree_near(dir).map(|primary| GitError::WorktreeDeleted {
+            worktree: dir.to_path_buf(),
+            primary: Some(primary),
+            changed_directory: false,
+        });
+    };
+    let gitdir = gitdir_link(worktree).filter(|gitdir| !gitdir.exists())?;
+
+    // `<repo>/.git/worktrees/<name>`, or `<repo>.git/worktrees/<name>` when bare
+    let common_dir = gitdir.parent().and_then(Path::parent).unwrap_or(&gitdir);
+    let main_worktree = (common_dir.file_name() == Some(".git".as_ref()))
+        .then(|| common_dir.parent())
+        .flatten();
+    if common_dir.exists() {
+        // The repository is where it was; only this worktree's entry is gone
+        return Some(GitError::WorktreeDeleted {
+            worktree: worktree.to_path_buf(),
+            primary: main_worktree.map(Path::to_path_buf),
+            changed_directory: false,
+        });
+    }
+    Some(GitError::RepositoryMoved {
+        worktree: worktree.to_path_buf(),
+        repository: main_worktree.unwrap_or(common_dir).to_path_buf(),
+    })
+}
+
+/// The main worktree a removed worktree at `path` most likely belonged to:
+/// a sibling whose name `path`'s starts with (`repo` for `repo.feature`), or
+/// else the closest enclosing repository (for worktrees kept inside it).
+fn primary_worktree_near(path: &Path) -> Option<PathBuf> {
+    let is_main_worktree = |candidate: &Path| candidate.join(".git").is_dir();
+    let name = path.file_name()?.to_str()?;
+    let siblings = path.parent().into_iter().flat_map(|parent| {
+        name.match_indices('.')
+            .rev()
+            .map(move |(end, _)| parent.join(&name[..end]))
+    });
+    siblings
+        .chain(path.ancestors().skip(1).map(Path::to_path_buf))
+        .find(|candidate| is_main_worktree(candidate))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnose_worktree() {
+        let temp = tempfile::tempdir().unwrap();
+        let repo = temp.path().join("repo");
+        std::fs::create_dir_all(repo.join(".git/worktrees/feature")).unwrap();
+        let worktree = temp.path().join("repo.feature");
+        std::fs::create_dir_all(worktree.join("src")).unwrap();
+        let link = |gitdir: &Path| {
+            std::fs::write(
+                worktree.join(".git"),
+                format!("gitdir: {}\n", gitdir.display()),
+            )
+            .unwrap()
+        };
+
+        link(&repo.join(".git/worktrees/feature"));
+        assert!(diagnose_worktree(&worktree.join("src")).is_none());
+
+        // Repository moved
+        link(&temp.path().join("old/.git/worktrees/feature"));
+        assert!(matches!(
+            diagnose_worktree(&worktree.join("src")),
+            Some(GitError::RepositoryMoved { repository, .. }) if repository == temp.path().join("old")
+        ));
+
+        // Worktree pruned from a repository that's still there
+        link(&repo.join(".git/worktrees/gone"));
+        assert!(matches!(
+            diagnose_worktree(&worktree),
+            Some(GitError::WorktreeDeleted { primary: Some(primary), .. }) if primary == repo
+        ));
+
+        // Directory deleted, or left with only untracked files
+        std::fs::remove_file(worktree.join(".git")).unwrap();
+        for dir in [worktree.clone(), temp.path().join("repo.other")] {
+            assert!(matches!(
+                diagnose_worktree(&dir),
+                Some(GitError::WorktreeDeleted { primary: Some(primary), .. }) if primary == repo
+            ));
+        }
+
+        // Unrelated directories aren't worktrees
+        assert!(diagnose_worktree(temp.path()).is_none());
+    }
+}
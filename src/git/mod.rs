@@ -3,8 +3,10 @@
 use std::path::PathBuf;
 
 // Submodules
+mod backend;
 mod diff;
 mod error;
+mod location;
 mod parse;
 mod repository;
 mod url;
@@ -29,8 +31,11 @@ use std::sync::LazyLock;
 static HEAVY_OPS_SEMAPHORE: LazyLock<Semaphore> = LazyLock::new(|| Semaphore::new(4));
 
 // Re-exports from submodules
+pub use backend::{GitBackend, ProcessBackend};
 pub use diff::{DiffStats, LineDiff};
 pub use error::{
+    // Failure classes and their exit codes
+    FailureKind,
     // Typed error enum (Display produces styled output)
     GitError,
     // Special-handling error enum (Display produces styled output)
@@ -40,7 +45,11 @@ pub use error::{
     add_hook_skip_hint,
     exit_code,
 };
-pub use repository::{Repository, ResolvedWorktree, set_base_path};
+pub use location::diagnose_worktree;
+pub use repository::{
+    Repository, ResolvedWorktree, set_base_path, set_git_dir_override, set_remote_override,
+    working_dir,
+};
 pub use url::GitRemoteUrl;
 /// Why branch content is considered integrated into the target branch.
 ///
@@ -276,7 +285,7 @@ pub struct CompletionBranch {
 }
 
 // Re-export parsing helpers for internal use
-pub(crate) use parse::DefaultBranchName;
+pub(crate) use parse::{DefaultBranchName, path_from_git};
 
 // Note: HookType and Worktree are defined in this module and are already public.
 // They're accessible as git::HookType and git::Worktree without needing re-export.
@@ -287,11 +296,14 @@ pub(crate) use parse::DefaultBranchName;
 pub enum HookType {
     PostCreate,
     PostStart,
+    PreSwitch,
     PostSwitch,
     PreCommit,
     PreMerge,
     PostMerge,
+    PrePush,
     PreRemove,
+    PostRemove,
 }
 
 /// Worktree information
@@ -325,6 +337,19 @@ impl Worktree {
     pub fn dir_name(&self) -> &str {
         path_dir_name(&self.path)
     }
+
+    /// The admin directory this worktree's `.git` file points to, when that
+    /// directory doesn't exist.
+    ///
+    /// Happens after a botched move or a restore from backup: git still lists
+    /// the worktree, but fails on any command run inside it until
+    /// `git worktree repair` rewrites the link.
+    pub fn broken_gitdir(&self) -> Option<PathBuf> {
+        if self.bare || self.prunable.is_some() {
+            return None;
+        }
+        location::gitdir_link(&self.path).filter(|gitdir| !gitdir.exists())
+    }
 }
 
 // Helper functions for worktree parsing
@@ -338,25 +363,7 @@ impl Worktree {
 
 /// Helper function to read rebase branch information
 fn read_rebase_branch(worktree_path: &PathBuf) -> Option<String> {
-    // Create a Repository instance to get the correct git directory
-    let repo = Repository::at(worktree_path);
-    let git_dir = repo.git_dir().ok()?;
-
-    // Check both rebase-merge and rebase-apply
-    for rebase_dir in ["rebase-merge", "rebase-apply"] {
-        let head_name_path = git_dir.join(rebase_dir).join("head-name");
-        if let Ok(content) = std::fs::read_to_string(head_name_path) {
-            let branch_ref = content.trim();
-            // Strip refs/heads/ prefix if present
-            let branch = branch_ref
-                .strip_prefix("refs/heads/")
-                .unwrap_or(branch_ref)
-                .to_string();
-            return Some(branch);
-        }
-    }
-
-    None
+    Repository::at(worktree_path).rebase_head_branch()
 }
 
 /// Finalize a worktree after parsing, filling in branch name from rebase state if needed.
@@ -461,6 +468,36 @@ mod tests {
         assert_eq!(wt.dir_name(), "myrepo.feature");
     }
 
+    #[test]
+    fn test_broken_gitdir() {
+        let temp = tempfile::tempdir().unwrap();
+        let admin = temp.path().join("repo/.git/worktrees/feature");
+        std::fs::create_dir_all(&admin).unwrap();
+        let path = temp.path().join("repo.feature");
+        std::fs::create_dir(&path).unwrap();
+        let wt = Worktree {
+            path: path.clone(),
+            head: "abc123".into(),
+            branch: Some("feature".into()),
+            bare: false,
+            detached: false,
+            locked: None,
+            prunable: None,
+        };
+
+        std::fs::write(path.join(".git"), format!("gitdir: {}\n", admin.display())).unwrap();
+        assert_eq!(wt.broken_gitdir(), None);
+
+        let moved = temp.path().join("old/.git/worktrees/feature");
+        std::fs::write(path.join(".git"), format!("gitdir: {}\n", moved.display())).unwrap();
+        assert_eq!(wt.broken_gitdir(), Some(moved));
+
+        // A `.git` directory is a main worktree, never a broken link
+        std::fs::remove_file(path.join(".git")).unwrap();
+        std::fs::create_dir(path.join(".git")).unwrap();
+        assert_eq!(wt.broken_gitdir(), None);
+    }
+
     #[test]
     fn test_hook_type_display() {
         let cases = [
@@ -470,6 +507,9 @@ mod tests {
             (HookType::PreMerge, "pre-merge"),
             (HookType::PostMerge, "post-merge"),
             (HookType::PreRemove, "pre-remove"),
+            (HookType::PreSwitch, "pre-switch"),
+            (HookType::PrePush, "pre-push"),
+            (HookType::PostRemove, "post-remove"),
         ];
         for (hook, expected) in cases {
             assert_eq!(format!("{hook}"), expected);
@@ -4,6 +4,19 @@ use std::path::PathBuf;
 
 use super::{GitError, Worktree, finalize_worktree};
 
+/// Convert a path printed by git to a native path.
+///
+/// Git for Windows prints forward slashes (`C:/src/repo`); switching to
+/// backslashes lets these compare equal to paths from the filesystem or
+/// `current_dir`. Elsewhere paths are used as-is.
+pub(crate) fn path_from_git(path: &str) -> PathBuf {
+    if cfg!(windows) {
+        PathBuf::from(path.replace('/', "\\"))
+    } else {
+        PathBuf::from(path)
+    }
+}
+
 impl Worktree {
     pub(crate) fn parse_porcelain_list(output: &str) -> anyhow::Result<Vec<Self>> {
         let mut worktrees = Vec::new();
@@ -31,7 +44,7 @@ impl Worktree {
                         .into());
                     };
                     current = Some(Worktree {
-                        path: PathBuf::from(path),
+                        path: path_from_git(path),
                         head: String::new(),
                         branch: None,
                         bare: false,
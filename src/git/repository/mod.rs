@@ -1,18 +1,17 @@
 use std::path::{Path, PathBuf};
-use std::process::Command;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
 use once_cell::sync::OnceCell;
 
-use anyhow::{Context, bail};
+use anyhow::Context;
 use normalize_path::NormalizePath;
 
 use dunce::canonicalize;
 
 // Import types and functions from parent module (mod.rs)
 use super::{
-    BranchCategory, CompletionBranch, DefaultBranchName, DiffStats, GitError, GitRemoteUrl,
-    LineDiff, Worktree,
+    BranchCategory, CompletionBranch, DefaultBranchName, DiffStats, GitBackend, GitError,
+    GitRemoteUrl, LineDiff, ProcessBackend, Worktree, path_from_git,
 };
 
 /// Result of resolving a worktree name.
@@ -47,6 +46,66 @@ pub fn set_base_path(path: PathBuf) {
     BASE_PATH.set(path).ok();
 }
 
+/// The directory commands operate in: the `-C` path if given, else the process cwd.
+///
+/// Use this instead of `std::env::current_dir()` wherever the working directory
+/// stands in for "the current worktree", so `wt -C <path>` behaves as if run there.
+pub fn working_dir() -> std::io::Result<PathBuf> {
+    let cwd = std::env::current_dir()?;
+    Ok(match BASE_PATH.get() {
+        Some(path) => cwd.join(path),
+        None => cwd,
+    })
+}
+
+/// Repository location given by `--git-dir`/`--work-tree` or `GIT_DIR`/`GIT_WORK_TREE`
+#[derive(Debug)]
+struct GitDirOverride {
+    git_dir: Option<PathBuf>,
+    work_tree: Option<PathBuf>,
+}
+
+impl GitDirOverride {
+    fn repository(&self, path: PathBuf) -> Repository {
+        let mut backend = ProcessBackend::default();
+        if let Some(git_dir) = &self.git_dir {
+            backend = backend.env("GIT_DIR", git_dir);
+        }
+        if let Some(work_tree) = &self.work_tree {
+            backend = backend.env("GIT_WORK_TREE", work_tree);
+        }
+        Repository {
+            work_tree: self.work_tree.clone(),
+            ..Repository::with_backend(path, Arc::new(backend))
+        }
+    }
+}
+
+static GIT_DIR_OVERRIDE: OnceLock<GitDirOverride> = OnceLock::new();
+
+/// Point [`Repository::current`] at an explicit git directory and/or work tree.
+///
+/// This should be called once at program startup from main(), with absolute
+/// paths. Git commands for other repositories and worktrees don't inherit
+/// these: [`ProcessBackend`] clears `GIT_DIR` and friends so they're found from
+/// their own paths.
+pub fn set_git_dir_override(git_dir: Option<PathBuf>, work_tree: Option<PathBuf>) {
+    GIT_DIR_OVERRIDE
+        .set(GitDirOverride { git_dir, work_tree })
+        .ok();
+}
+
+/// Remote chosen with `--remote` or the `remote` setting
+static REMOTE_OVERRIDE: OnceLock<String> = OnceLock::new();
+
+/// Compare against this remote instead of the one [`Repository::primary_remote`]
+/// would pick, e.g. `upstream` in a fork with `origin` and `upstream`.
+///
+/// This should be called once at program startup from main().
+pub fn set_remote_override(remote: String) {
+    REMOTE_OVERRIDE.set(remote).ok();
+}
+
 /// Get the base path for repository operations.
 fn base_path() -> &'static PathBuf {
     static DEFAULT: OnceLock<PathBuf> = OnceLock::new();
@@ -71,8 +130,16 @@ struct RepoCache {
     worktree_base: OnceCell<PathBuf>,
     /// Whether this is a bare repository
     is_bare: OnceCell<bool>,
+    /// Git `user.name`, if configured
+    user_name: OnceCell<Option<String>>,
+    /// Whether `extensions.worktreeConfig` is on
+    worktree_config: OnceCell<bool>,
 }
 
+/// `worktrunk.state.<branch>.<name>` values as `(branch, value)`, with the
+/// worktree whose own config holds them (`None` for shared config)
+type BranchStateSource = (Option<Repository>, Vec<(String, String)>);
+
 /// Repository context for git operations.
 ///
 /// Provides a more ergonomic API than the `*_in(path, ...)` functions by
@@ -92,23 +159,55 @@ struct RepoCache {
 pub struct Repository {
     path: PathBuf,
     cache: RepoCache,
+    backend: Arc<dyn GitBackend>,
+    /// Work tree from `--work-tree`/`GIT_WORK_TREE`, which git doesn't list
+    /// as a worktree
+    work_tree: Option<PathBuf>,
 }
 
 impl Repository {
     /// Create a repository context at the specified path.
+    ///
+    /// The `--work-tree` directory can't be found from its path, so it gets the
+    /// same overrides as [`Repository::current`].
     pub fn at(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        if let Some(git_override) = GIT_DIR_OVERRIDE.get()
+            && git_override.work_tree.as_ref() == Some(&path)
+        {
+            return git_override.repository(path);
+        }
+        Self::with_backend(path, Arc::new(ProcessBackend::default()))
+    }
+
+    /// Create a repository context whose git commands run through `backend`.
+    ///
+    /// Lets tests substitute scripted git output; see `worktrunk::testing`.
+    pub fn with_backend(path: impl Into<PathBuf>, backend: Arc<dyn GitBackend>) -> Self {
         Self {
             path: path.into(),
             cache: RepoCache::default(),
+            backend,
+            work_tree: None,
         }
     }
 
     /// Create a repository context for the current directory.
     ///
     /// This is the most common usage pattern. If the -C flag was used,
-    /// this uses that path instead of the actual current directory.
+    /// this uses that path instead of the actual current directory; with
+    /// `--git-dir`/`--work-tree` (or `GIT_DIR`/`GIT_WORK_TREE`), git is pointed
+    /// at those instead of discovering the repository.
     pub fn current() -> Self {
-        Self::at(base_path().clone())
+        match GIT_DIR_OVERRIDE.get() {
+            Some(git_override) => git_override.repository(
+                git_override
+                    .work_tree
+                    .clone()
+                    .unwrap_or_else(|| base_path().clone()),
+            ),
+            None => Self::at(base_path().clone()),
+        }
     }
 
     /// Get the base path this repository was created with.
@@ -121,9 +220,10 @@ impl Repository {
     /// Returns a consistent value across all worktrees (not branch-specific).
     ///
     /// Uses the following strategy:
-    /// 1. Use git's [`checkout.defaultRemote`][1] config if set and has a URL
-    /// 2. Otherwise, get the first remote with a configured URL
-    /// 3. Fall back to "origin" if no remotes exist
+    /// 1. Use the remote chosen with [`set_remote_override`], if any
+    /// 2. Use git's [`checkout.defaultRemote`][1] config if set and has a URL
+    /// 3. Otherwise, get the first remote with a configured URL
+    /// 4. Fall back to "origin" if no remotes exist
     ///
     /// Result is cached for the lifetime of this Repository instance.
     ///
@@ -132,6 +232,10 @@ impl Repository {
         self.cache
             .primary_remote
             .get_or_try_init(|| {
+                if let Some(remote) = REMOTE_OVERRIDE.get() {
+                    return Ok(remote.clone());
+                }
+
                 // Check git's checkout.defaultRemote config
                 if let Ok(default_remote) = self.run_command(&["config", "checkout.defaultRemote"])
                 {
@@ -160,6 +264,28 @@ impl Repository {
             .map(String::as_str)
     }
 
+    /// Git `user.name`, if configured.
+    ///
+    /// Result is cached for the lifetime of this Repository instance.
+    pub fn user_name(&self) -> Option<&str> {
+        self.cache
+            .user_name
+            .get_or_init(|| {
+                self.run_command(&["config", "user.name"])
+                    .ok()
+                    .map(|name| name.trim().to_string())
+                    .filter(|name| !name.is_empty())
+            })
+            .as_deref()
+    }
+
+    /// Parsed URL of the primary remote, if it has one in host/owner/repo form.
+    pub fn primary_remote_url(&self) -> Option<GitRemoteUrl> {
+        let remote = self.primary_remote().ok()?;
+        let url = self.run_command(&["remote", "get-url", remote]).ok()?;
+        GitRemoteUrl::parse(&url)
+    }
+
     /// Check if a remote has a URL configured.
     fn remote_has_url(&self, remote: &str) -> bool {
         self.run_command(&["config", &format!("remote.{}.url", remote)])
@@ -248,6 +374,173 @@ impl Repository {
         })
     }
 
+    /// Whether `extensions.worktreeConfig` is on, giving each worktree its own
+    /// `config.worktree` for `git config --worktree`.
+    pub fn has_worktree_config(&self) -> bool {
+        *self.cache.worktree_config.get_or_init(|| {
+            self.run_command(&["config", "--bool", "--get", "extensions.worktreeConfig"])
+                .is_ok_and(|output| output.trim() == "true")
+        })
+    }
+
+    /// The worktree whose own config holds `branch`'s marker and base.
+    ///
+    /// With `extensions.worktreeConfig`, state for a branch that's checked out
+    /// is written with `git config --worktree` in its worktree, so it's removed
+    /// along with the worktree. `None` means shared config: the extension is
+    /// off, or the branch has no worktree.
+    pub fn branch_state_worktree(&self, branch: &str) -> Option<Repository> {
+        if !self.has_worktree_config() {
+            return None;
+        }
+        let path = self.worktree_for_branch(branch).ok().flatten()?;
+        Some(Repository::at(path))
+    }
+
+    /// Write (or with `None`, clear) `worktrunk.state.<branch>.<name>`, in the
+    /// branch's worktree config when it has one (see [`Self::branch_state_worktree`]).
+    ///
+    /// Returns whether a value was cleared.
+    pub fn set_branch_state(
+        &self,
+        branch: &str,
+        name: &str,
+        value: Option<&str>,
+    ) -> anyhow::Result<bool> {
+        let config_key = format!("worktrunk.state.{branch}.{name}");
+        // Unset exits non-zero when the key isn't set, which is fine
+        let cleared_shared = self
+            .run_command(&["config", "--unset", &config_key])
+            .is_ok();
+        match self.branch_state_worktree(branch) {
+            Some(worktree) => {
+                let cleared = worktree
+                    .run_command(&["config", "--worktree", "--unset", &config_key])
+                    .is_ok();
+                if let Some(value) = value {
+                    worktree.run_command(&["config", "--worktree", &config_key, value])?;
+                }
+                Ok(cleared || cleared_shared)
+            }
+            None => {
+                if let Some(value) = value {
+                    self.run_command(&["config", &config_key, value])?;
+                }
+                Ok(cleared_shared)
+            }
+        }
+    }
+
+    /// Read `worktrunk.state.<branch>.<name>` from wherever
+    /// [`Self::set_branch_state`] writes it.
+    ///
+    /// A worktree's own config only speaks for the branch it has checked out:
+    /// entries left there after it switched branches are stale, and a plain
+    /// `git config --get` in that worktree would still return them.
+    pub fn branch_state(&self, branch: &str, name: &str) -> Option<String> {
+        let config_key = format!("worktrunk.state.{branch}.{name}");
+        let read = |repo: &Repository, args: &[&str]| {
+            repo.run_command(args)
+                .ok()
+                .map(|output| output.trim().to_string())
+                .filter(|s| !s.is_empty())
+        };
+
+        if !self.has_worktree_config() {
+            return read(self, &["config", "--get", &config_key]);
+        }
+        let own = if self.current_branch().ok().flatten() == Some(branch) {
+            read(self, &["config", "--worktree", "--get", &config_key])
+        } else {
+            self.branch_state_worktree(branch).and_then(|worktree| {
+                read(&worktree, &["config", "--worktree", "--get", &config_key])
+            })
+        };
+        own.or_else(|| read(self, &["config", "--local", "--get", &config_key]))
+    }
+
+    /// Every `worktrunk.state.<branch>.<name>` value, as `(branch, value)`:
+    /// from shared config and, with `extensions.worktreeConfig`, from each
+    /// worktree's own config.
+    ///
+    /// Stale entries in a worktree's config (for a branch it no longer has
+    /// checked out, see [`Self::branch_state`]) are left out.
+    pub fn all_branch_state(&self, name: &str) -> Vec<(String, String)> {
+        self.branch_state_sources(name, false)
+            .into_iter()
+            .flat_map(|(_, entries)| entries)
+            .collect()
+    }
+
+    /// Clear `worktrunk.state.<branch>.<name>` for every branch, wherever it's
+    /// stored, stale entries included. Returns how many values were cleared.
+    pub fn clear_all_branch_state(&self, name: &str) -> anyhow::Result<usize> {
+        let mut cleared = 0;
+        for (worktree, entries) in self.branch_state_sources(name, true) {
+            for (branch, _) in entries {
+                let config_key = format!("worktrunk.state.{branch}.{name}");
+                match &worktree {
+                    Some(worktree) => {
+                        worktree.run_command(&["config", "--worktree", "--unset", &config_key])?
+                    }
+                    None => self.run_command(&["config", "--unset", &config_key])?,
+                };
+                cleared += 1;
+            }
+        }
+        Ok(cleared)
+    }
+
+    /// `worktrunk.state.<branch>.<name>` entries grouped by where they're
+    /// stored: `None` for shared config, then each worktree with its own.
+    ///
+    /// A worktree's entries for branches other than the one it has checked out
+    /// are stale, and only included with `include_stale`.
+    fn branch_state_sources(&self, name: &str, include_stale: bool) -> Vec<BranchStateSource> {
+        let pattern = format!(r"^worktrunk\.state\..+\.{name}$");
+        let suffix = format!(".{name}");
+        let parse = |output: String| -> Vec<(String, String)> {
+            output
+                .lines()
+                .filter_map(|line| {
+                    // Format: "worktrunk.state.<branch>.<name> value"
+                    let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+                    let branch = key
+                        .strip_prefix("worktrunk.state.")?
+                        .strip_suffix(suffix.as_str())?;
+                    Some((branch.to_string(), value.to_string()))
+                })
+                .collect()
+        };
+
+        if !self.has_worktree_config() {
+            let output = self
+                .run_command(&["config", "--get-regexp", &pattern])
+                .unwrap_or_default();
+            return vec![(None, parse(output))];
+        }
+
+        // Without a scope, reads from a worktree include its `config.worktree`
+        let shared = self
+            .run_command(&["config", "--local", "--get-regexp", &pattern])
+            .unwrap_or_default();
+        let mut sources = vec![(None, parse(shared))];
+        for info in self.list_worktrees().unwrap_or_default() {
+            let worktree = Repository::at(info.path);
+            let output = worktree
+                .run_command(&["config", "--worktree", "--get-regexp", &pattern])
+                .unwrap_or_default();
+            let mut entries = parse(output);
+            if !include_stale {
+                entries.retain(|(branch, _)| info.branch.as_deref() == Some(branch.as_str()));
+            }
+            if !entries.is_empty() {
+                sources.push((Some(worktree), entries));
+            }
+        }
+        sources
+    }
+
     /// Read a user-defined marker from `worktrunk.state.<branch>.marker` in git config.
     ///
     /// Markers are stored as JSON: `{"marker": "text", "set_at": unix_timestamp}`.
@@ -257,15 +550,176 @@ impl Repository {
             marker: Option<String>,
         }
 
-        let config_key = format!("worktrunk.state.{branch}.marker");
-        let raw = self
-            .run_command(&["config", "--get", &config_key])
+        let raw = self.branch_state(branch, "marker")?;
+        let parsed: MarkerValue = serde_json::from_str(&raw).ok()?;
+        parsed.marker
+    }
+
+    /// Read a branch's description (`git branch --edit-description`), if set.
+    pub fn branch_description(&self, branch: &str) -> Option<String> {
+        self.run_command(&["config", "--get", &format!("branch.{branch}.description")])
             .ok()
             .map(|output| output.trim().to_string())
-            .filter(|s| !s.is_empty())?;
+            .filter(|s| !s.is_empty())
+    }
 
-        let parsed: MarkerValue = serde_json::from_str(&raw).ok()?;
-        parsed.marker
+    /// Nearest tag reachable from `commit`, as `v1.2` or `v1.2+3` (3 commits past the tag).
+    ///
+    /// Returns None when no tag is reachable.
+    pub fn describe(&self, commit: &str) -> Option<String> {
+        self.run_command(&["describe", "--tags", "--long", commit])
+            .ok()
+            .and_then(|output| format_describe(output.trim()))
+    }
+
+    /// Read the tag or commit a branch was created from with `wt switch --create --base`,
+    /// stored in `worktrunk.state.<branch>.base`. Branch bases aren't recorded.
+    pub fn branch_base(&self, branch: &str) -> Option<String> {
+        self.branch_state(branch, "base")
+    }
+
+    /// Record (or with `None`, clear) the tag or commit a branch was created from.
+    pub fn set_branch_base(&self, branch: &str, base: Option<&str>) -> anyhow::Result<()> {
+        self.set_branch_state(branch, "base", base)?;
+        Ok(())
+    }
+
+    /// URL of the issue a branch was created for with `wt switch --from-issue`,
+    /// stored in `worktrunk.state.<branch>.issue`.
+    pub fn branch_issue(&self, branch: &str) -> Option<String> {
+        self.run_command(&[
+            "config",
+            "--get",
+            &format!("worktrunk.state.{branch}.issue"),
+        ])
+        .ok()
+        .map(|output| output.trim().to_string())
+        .filter(|s| !s.is_empty())
+    }
+
+    /// Record the issue a branch was created for.
+    pub fn set_branch_issue(&self, branch: &str, url: &str) -> anyhow::Result<()> {
+        self.run_command(&["config", &format!("worktrunk.state.{branch}.issue"), url])?;
+        Ok(())
+    }
+
+    /// The `wt merge` stopped by rebase conflicts on this branch, as JSON
+    /// from `worktrunk.state.<branch>.merge`.
+    pub fn pending_merge(&self, branch: &str) -> Option<String> {
+        self.run_command(&[
+            "config",
+            "--get",
+            &format!("worktrunk.state.{branch}.merge"),
+        ])
+        .ok()
+        .map(|output| output.trim().to_string())
+        .filter(|s| !s.is_empty())
+    }
+
+    /// Record (or with `None`, clear) a `wt merge` stopped by rebase conflicts.
+    pub fn set_pending_merge(&self, branch: &str, state: Option<&str>) -> anyhow::Result<()> {
+        let config_key = format!("worktrunk.state.{branch}.merge");
+        match state {
+            Some(state) => {
+                self.run_command(&["config", &config_key, state])?;
+            }
+            None => {
+                // Exits non-zero when the key isn't set, which is fine
+                let _ = self.run_command(&["config", "--unset", &config_key]);
+            }
+        }
+        Ok(())
+    }
+
+    /// The branch being rebased in this worktree, while a rebase is stopped
+    /// (HEAD is detached until it finishes).
+    pub fn rebase_head_branch(&self) -> Option<String> {
+        let git_dir = self.git_dir().ok()?;
+        ["rebase-merge", "rebase-apply"]
+            .iter()
+            .find_map(|rebase_dir| {
+                let content =
+                    std::fs::read_to_string(git_dir.join(rebase_dir).join("head-name")).ok()?;
+                let branch_ref = content.trim();
+                // Strip refs/heads/ prefix if present
+                Some(
+                    branch_ref
+                        .strip_prefix("refs/heads/")
+                        .unwrap_or(branch_ref)
+                        .to_string(),
+                )
+            })
+    }
+
+    /// Record the rows of the last `wt list` table, in order, so `wt switch 3`
+    /// can refer to them. Each entry is a branch name, or a path for a
+    /// detached worktree. Stored as JSON in `.git/worktrunk/listing`, since it's
+    /// rewritten by every listing and doesn't belong in `.git/config`.
+    pub fn record_listing(&self, entries: &[String]) -> anyhow::Result<()> {
+        self.write_state_file("listing", &serde_json::to_string(entries)?)
+    }
+
+    /// Forget the last listing, after one that didn't number its rows.
+    pub fn clear_listing(&self) -> anyhow::Result<()> {
+        self.remove_state_file("listing")?;
+        Ok(())
+    }
+
+    /// `wt select`'s preview settings from when it last closed, as JSON stored
+    /// in `.git/worktrunk/select` (rewritten each time it closes, like the
+    /// listing).
+    pub fn select_preferences(&self) -> Option<String> {
+        let raw = std::fs::read_to_string(self.state_file("select").ok()?).ok()?;
+        Some(raw.trim().to_string())
+    }
+
+    /// Record `wt select`'s preview settings for the next time it opens.
+    pub fn set_select_preferences(&self, value: &str) -> anyhow::Result<()> {
+        self.write_state_file("select", value)
+    }
+
+    /// Forget `wt select`'s preview settings. Returns whether any were stored.
+    pub fn clear_select_preferences(&self) -> anyhow::Result<bool> {
+        self.remove_state_file("select")
+    }
+
+    /// A file in `.git/worktrunk/`, shared by every worktree
+    fn state_file(&self, name: &str) -> anyhow::Result<PathBuf> {
+        Ok(self.git_common_dir()?.join("worktrunk").join(name))
+    }
+
+    fn write_state_file(&self, name: &str, contents: &str) -> anyhow::Result<()> {
+        let path = self.state_file(name)?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Returns whether the file existed
+    fn remove_state_file(&self, name: &str) -> anyhow::Result<bool> {
+        match std::fs::remove_file(self.state_file(name)?) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Resolve a 1-based row number from the last `wt list` to its branch (or
+    /// detached worktree path).
+    ///
+    /// Returns None when `name` isn't a number, is out of range, or is itself
+    /// the name of a local branch (branches win), and when the last listing
+    /// didn't show the `#` column.
+    pub fn resolve_ordinal(&self, name: &str) -> Option<String> {
+        let ordinal: usize = name.parse().ok().filter(|&n| n > 0)?;
+        if self.local_branch_exists(name).unwrap_or(false) {
+            return None;
+        }
+        let raw = std::fs::read_to_string(self.state_file("listing").ok()?).ok()?;
+        let entries: Vec<String> = serde_json::from_str(&raw).ok()?;
+        entries.into_iter().nth(ordinal - 1)
     }
 
     /// Read user-defined branch-keyed marker.
@@ -301,10 +755,13 @@ impl Repository {
     ///   - "@" for current HEAD
     ///   - "-" for previous branch (via worktrunk.history)
     ///   - "^" for default branch
+    ///   - "refs/heads/NAME" or "heads/NAME" for local branch NAME
     ///   - any other string is returned as-is
     ///
     /// # Returns
     /// - `Ok(name)` if not a special symbol
+    /// - `Ok(branch)` if a qualified ref names a local branch, so it resolves
+    ///   to the same worktree as the short name
     /// - `Ok(current_branch)` if "@" and on a branch
     /// - `Ok(previous_branch)` if "-" and worktrunk.history has a previous branch
     /// - `Ok(default_branch)` if "^"
@@ -330,7 +787,18 @@ impl Repository {
                 })
             }
             "^" => self.default_branch(),
-            _ => Ok(name.to_string()),
+            _ => {
+                // A branch literally named `heads/...` takes precedence
+                if let Some(short) = name
+                    .strip_prefix("refs/heads/")
+                    .or_else(|| name.strip_prefix("heads/"))
+                    && !self.local_branch_exists(name)?
+                    && self.local_branch_exists(short)?
+                {
+                    return Ok(short.to_string());
+                }
+                Ok(name.to_string())
+            }
         }
     }
 
@@ -394,12 +862,15 @@ impl Repository {
     ///
     /// Detection results are cached to `worktrunk.default-branch` for future calls.
     pub fn default_branch(&self) -> anyhow::Result<String> {
+        // A chosen remote may name a different default branch than the cached
+        // one, and is read from its (local) remote HEAD each time
+        if REMOTE_OVERRIDE.get().is_some() {
+            return self.detect_default_branch();
+        }
+
         // Fast path: check worktrunk's own cache (single git config read)
-        if let Ok(branch) = self.run_command(&["config", "--get", "worktrunk.default-branch"]) {
-            let branch = branch.trim();
-            if !branch.is_empty() {
-                return Ok(branch.to_string());
-            }
+        if let Some(branch) = self.cached_default_branch() {
+            return Ok(branch);
         }
 
         // Detect and cache the default branch
@@ -408,6 +879,18 @@ impl Repository {
         Ok(branch)
     }
 
+    /// The default branch from worktrunk's cache, without detecting it.
+    ///
+    /// For callers that must stay fast (shell prompts) and would rather go
+    /// without than query the remote.
+    pub fn cached_default_branch(&self) -> Option<String> {
+        let branch = self
+            .run_command(&["config", "--get", "worktrunk.default-branch"])
+            .ok()?;
+        let branch = branch.trim();
+        (!branch.is_empty()).then(|| branch.to_string())
+    }
+
     /// Detect the default branch without using worktrunk's cache.
     ///
     /// Used by `default_branch()` to populate the cache, and by
@@ -507,7 +990,7 @@ impl Repository {
             .git_common_dir
             .get_or_try_init(|| {
                 let stdout = self.run_command(&["rev-parse", "--git-common-dir"])?;
-                let path = PathBuf::from(stdout.trim());
+                let path = path_from_git(stdout.trim());
                 if path.is_relative() {
                     canonicalize(self.path.join(&path))
                         .context("Failed to resolve git common directory")
@@ -523,7 +1006,7 @@ impl Repository {
     /// Always returns an absolute path, resolving any relative paths returned by git.
     pub fn git_dir(&self) -> anyhow::Result<PathBuf> {
         let stdout = self.run_command(&["rev-parse", "--git-dir"])?;
-        let path = PathBuf::from(stdout.trim());
+        let path = path_from_git(stdout.trim());
 
         // Resolve relative paths against the repo's directory
         if path.is_relative() {
@@ -537,6 +1020,7 @@ impl Repository {
     ///
     /// For normal repositories: the parent of .git (the repo root).
     /// For bare repositories: the bare repository directory itself.
+    /// With `--work-tree`: that directory, which acts as the main worktree.
     ///
     /// This is the path that should be used when constructing worktree paths.
     /// Result is cached for the lifetime of this Repository instance.
@@ -544,6 +1028,9 @@ impl Repository {
         self.cache
             .worktree_base
             .get_or_try_init(|| {
+                if let Some(work_tree) = &self.work_tree {
+                    return Ok(work_tree.clone());
+                }
                 let git_common_dir =
                     canonicalize(self.git_common_dir()?).context("Failed to canonicalize path")?;
 
@@ -569,12 +1056,17 @@ impl Repository {
     /// Check if this is a bare repository (no working tree).
     ///
     /// Bare repositories have no main worktree — all worktrees are linked
-    /// worktrees at templated paths, including the default branch.
+    /// worktrees at templated paths, including the default branch. A bare
+    /// repository used with `--work-tree` isn't treated as bare: like git, we
+    /// take that directory as its main worktree.
     /// Result is cached for the lifetime of this Repository instance.
     pub fn is_bare(&self) -> anyhow::Result<bool> {
         self.cache
             .is_bare
             .get_or_try_init(|| {
+                if self.work_tree.is_some() {
+                    return Ok(false);
+                }
                 let output = self.run_command(&["config", "--bool", "core.bare"])?;
                 Ok(output.trim() == "true")
             })
@@ -617,7 +1109,7 @@ impl Repository {
             .worktree_root
             .get_or_try_init(|| {
                 let stdout = self.run_command(&["rev-parse", "--show-toplevel"])?;
-                let path = PathBuf::from(stdout.trim());
+                let path = path_from_git(stdout.trim());
                 canonicalize(&path).context("Failed to canonicalize worktree root")
             })
             .map(PathBuf::as_path)
@@ -645,6 +1137,29 @@ impl Repository {
         Ok(git_dir.join("rebase-merge").exists() || git_dir.join("rebase-apply").exists())
     }
 
+    /// Step and total count of an in-progress rebase (`3/7` while applying
+    /// the third of seven commits), if one is running.
+    ///
+    /// Reads `msgnum`/`end` (written by the default merge backend) or
+    /// `next`/`last` (the apply backend) from `rebase-merge` or `rebase-apply`.
+    pub fn rebase_progress(&self) -> Option<(usize, usize)> {
+        let git_dir = self.git_dir().ok()?;
+        let read = |dir: &str, file: &str| -> Option<usize> {
+            std::fs::read_to_string(git_dir.join(dir).join(file))
+                .ok()?
+                .trim()
+                .parse()
+                .ok()
+        };
+        ["rebase-merge", "rebase-apply"]
+            .into_iter()
+            .find_map(|dir| {
+                read(dir, "msgnum")
+                    .zip(read(dir, "end"))
+                    .or_else(|| read(dir, "next").zip(read(dir, "last")))
+            })
+    }
+
     /// Check if a merge is in progress.
     pub fn is_merging(&self) -> anyhow::Result<bool> {
         let git_dir = self.git_dir()?;
@@ -702,16 +1217,9 @@ impl Repository {
     ///
     /// [1]: https://git-scm.com/docs/git-diff#Documentation/git-diff.txt-emgitdiffemltoptionsgtltcommitgtltcommitgt--telepathhellip
     pub fn has_added_changes(&self, branch: &str, target: &str) -> anyhow::Result<bool> {
-        use crate::shell_exec::run;
-
         // git diff --name-only target...branch shows files changed from merge-base to branch
         let range = format!("{target}...{branch}");
-        let mut cmd = std::process::Command::new("git");
-        cmd.args(["diff", "--name-only", &range]);
-        cmd.current_dir(&self.path);
-
-        let output = run(&mut cmd, Some(&self.logging_context()))
-            .with_context(|| format!("Failed to execute: git diff --name-only {range}"))?;
+        let output = self.run_git(&["diff", "--name-only", &range])?;
 
         if output.status.success() {
             let stdout = String::from_utf8_lossy(&output.stdout);
@@ -724,7 +1232,7 @@ impl Repository {
             if stderr.contains("no merge base") {
                 Ok(true)
             } else {
-                bail!("{}", stderr.trim())
+                Err(GitError::command_failed(&["diff", "--name-only", &range], &output).into())
             }
         }
     }
@@ -835,6 +1343,21 @@ impl Repository {
         }
     }
 
+    /// `branch` on the remote chosen with [`set_remote_override`] (e.g.
+    /// `upstream/main`), if a remote was chosen and has fetched that branch.
+    pub fn chosen_remote_branch(&self, branch: &str) -> Option<String> {
+        let remote = REMOTE_OVERRIDE.get()?;
+        let remote_branch = format!("{remote}/{branch}");
+        self.run_command(&[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/remotes/{remote_branch}"),
+        ])
+        .ok()
+        .map(|_| remote_branch)
+    }
+
     /// Determine the effective target for integration checks.
     ///
     /// If the upstream of the local target (e.g., `origin/main`) is strictly ahead of
@@ -852,10 +1375,14 @@ impl Repository {
     /// check integration against both and delete only if integrated into both.
     /// Current behavior: uses only local in diverged state, may miss remote-merged branches.
     pub fn effective_integration_target(&self, local_target: &str) -> String {
-        // Get the upstream ref for the local target (e.g., origin/main for main)
-        let upstream = match self.upstream_branch(local_target) {
-            Ok(Some(upstream)) => upstream,
-            _ => return local_target.to_string(),
+        // Get the upstream ref for the local target (e.g., origin/main for main),
+        // or its counterpart on the chosen remote
+        let upstream = match self.chosen_remote_branch(local_target) {
+            Some(remote_branch) => remote_branch,
+            None => match self.upstream_branch(local_target) {
+                Ok(Some(upstream)) => upstream,
+                _ => return local_target.to_string(),
+            },
         };
 
         // If local and upstream are the same commit, prefer local for clearer messaging
@@ -884,19 +1411,8 @@ impl Repository {
 
         // Check for rebase
         if git_dir.join("rebase-merge").exists() || git_dir.join("rebase-apply").exists() {
-            let rebase_dir = if git_dir.join("rebase-merge").exists() {
-                git_dir.join("rebase-merge")
-            } else {
-                git_dir.join("rebase-apply")
-            };
-
-            if let (Ok(msgnum), Ok(end)) = (
-                std::fs::read_to_string(rebase_dir.join("msgnum")),
-                std::fs::read_to_string(rebase_dir.join("end")),
-            ) {
-                let current = msgnum.trim();
-                let total = end.trim();
-                return Ok(Some(format!("REBASING {}/{}", current, total)));
+            if let Some((step, total)) = self.rebase_progress() {
+                return Ok(Some(format!("REBASING {step}/{total}")));
             }
 
             return Ok(Some("REBASING".to_string()));
@@ -1018,6 +1534,22 @@ impl Repository {
         Ok(upstreams)
     }
 
+    /// Local branches whose upstream is gone from the remote, usually deleted
+    /// after its pull request merged.
+    pub fn branches_with_gone_upstream(&self) -> anyhow::Result<std::collections::HashSet<String>> {
+        let output = self.run_command(&[
+            "for-each-ref",
+            "--format=%(upstream:track)%00%(refname:lstrip=2)",
+            "refs/heads/",
+        ])?;
+
+        Ok(output
+            .lines()
+            .filter_map(|line| line.strip_prefix("[gone]\0"))
+            .map(str::to_string)
+            .collect())
+    }
+
     /// List remote branches that aren't tracked by any local branch.
     ///
     /// Returns (branch_name, commit_sha) pairs for remote branches that have no
@@ -1108,13 +1640,20 @@ impl Repository {
 
     /// Get line diff statistics between two refs (using three-dot diff for merge base).
     ///
+    /// Refs with no merge base (an orphan branch) have nothing to compare, so
+    /// the diff is empty.
     pub fn branch_diff_stats(&self, base: &str, head: &str) -> anyhow::Result<LineDiff> {
         // Limit concurrent diff operations to reduce mmap thrash on pack files
         let _guard = super::HEAVY_OPS_SEMAPHORE.acquire();
 
         let range = format!("{}...{}", base, head);
-        let stdout = self.run_command(&["diff", "--numstat", &range])?;
-        LineDiff::from_numstat(&stdout)
+        match self.run_command(&["diff", "--numstat", &range]) {
+            Ok(stdout) => LineDiff::from_numstat(&stdout),
+            Err(e) if GitError::git_stderr(&e).is_some_and(|msg| msg.contains("no merge base")) => {
+                Ok(LineDiff::default())
+            }
+            Err(e) => Err(e),
+        }
     }
 
     /// Get formatted diff stats summary for display.
@@ -1328,9 +1867,19 @@ impl Repository {
     }
 
     /// Get the merge base between two commits.
-    pub fn merge_base(&self, commit1: &str, commit2: &str) -> anyhow::Result<String> {
-        let output = self.run_command(&["merge-base", commit1, commit2])?;
-        Ok(output.trim().to_owned())
+    ///
+    /// Returns None when they share no history, as with an orphan branch.
+    pub fn merge_base(&self, commit1: &str, commit2: &str) -> anyhow::Result<Option<String>> {
+        let args = ["merge-base", commit1, commit2];
+        let output = self.run_git(&args)?;
+        match output.status.code() {
+            Some(0) => Ok(Some(
+                String::from_utf8_lossy(&output.stdout).trim().to_owned(),
+            )),
+            // Exit 1 without output: no common ancestor
+            Some(1) if output.stdout.is_empty() && output.stderr.is_empty() => Ok(None),
+            _ => Err(GitError::command_failed(&args, &output).into()),
+        }
     }
 
     /// Check if merging head into base would result in conflicts.
@@ -1347,6 +1896,10 @@ impl Repository {
     /// # Ok::<(), anyhow::Error>(())
     /// ```
     pub fn has_merge_conflicts(&self, base: &str, head: &str) -> anyhow::Result<bool> {
+        // An orphan branch can't be merged normally, so it doesn't conflict either
+        if self.merge_base(base, head)?.is_none() {
+            return Ok(false);
+        }
         // Use modern merge-tree --write-tree mode which exits with 1 when conflicts exist
         // (the old 3-argument deprecated mode always exits with 0)
         // run_command_check returns true for exit 0, false otherwise
@@ -1354,6 +1907,46 @@ impl Repository {
         Ok(!clean_merge)
     }
 
+    /// Paths that would conflict when merging head into base.
+    ///
+    /// Runs the same `git merge-tree --write-tree` simulation as
+    /// [`has_merge_conflicts`](Self::has_merge_conflicts), listing the
+    /// conflicted paths (sorted, without duplicates) rather than only whether
+    /// there are any. Empty for a clean merge and for refs that share no
+    /// history (an orphan branch), where a conflict check doesn't apply; an
+    /// error when git refuses the merge for another reason (e.g. missing
+    /// objects), which `has_merge_conflicts` counts as conflicting.
+    pub fn merge_conflicts(&self, base: &str, head: &str) -> anyhow::Result<Vec<String>> {
+        // Exits 0 for a clean merge and 1 for conflicts; the old 3-argument
+        // mode always exits 0. The first line of output is the merged tree.
+        let args = [
+            "merge-tree",
+            "--write-tree",
+            "--name-only",
+            "--no-messages",
+            base,
+            head,
+        ];
+        let output = self.run_git(&args)?;
+        match output.status.code() {
+            Some(0) => Ok(Vec::new()),
+            // Refusals also exit 1, but without printing a tree
+            Some(1) if !output.stdout.is_empty() => {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let paths: std::collections::BTreeSet<&str> = stdout
+                    .lines()
+                    .skip(1)
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                Ok(paths.into_iter().map(str::to_string).collect())
+            }
+            _ if String::from_utf8_lossy(&output.stderr).contains("unrelated histories") => {
+                Ok(Vec::new())
+            }
+            _ => Err(GitError::command_failed(&args, &output).into()),
+        }
+    }
+
     /// Check if merging a branch into target would add anything (not already integrated).
     ///
     /// Uses `git merge-tree` to simulate merging the branch into the target. If the
@@ -1410,7 +2003,47 @@ impl Repository {
     pub fn list_worktrees(&self) -> anyhow::Result<Vec<Worktree>> {
         let stdout = self.run_command(&["worktree", "list", "--porcelain"])?;
         let raw_worktrees = Worktree::parse_porcelain_list(&stdout)?;
-        Ok(raw_worktrees.into_iter().filter(|wt| !wt.bare).collect())
+        let mut worktrees: Vec<_> = raw_worktrees.into_iter().filter(|wt| !wt.bare).collect();
+
+        // A `--work-tree` checkout (e.g. of a bare dotfiles repository) isn't
+        // registered with git, but it's where the user is working; treat it as
+        // the main worktree
+        if let Some(work_tree) = &self.work_tree
+            && !worktrees.iter().any(|wt| wt.path == *work_tree)
+        {
+            let branch = self.current_branch()?.map(str::to_string);
+            worktrees.insert(
+                0,
+                Worktree {
+                    path: work_tree.clone(),
+                    head: self.run_command(&["rev-parse", "HEAD"])?.trim().to_string(),
+                    detached: branch.is_none(),
+                    branch,
+                    bare: false,
+                    locked: None,
+                    prunable: None,
+                },
+            );
+        }
+        Ok(worktrees)
+    }
+
+    /// The worktree others are compared against and return to.
+    ///
+    /// That's the main worktree, except in a bare repository, which has none:
+    /// there it's the default branch's worktree, else the first one. `None`
+    /// only for a bare repository without worktrees.
+    pub fn primary_worktree(&self) -> anyhow::Result<Option<Worktree>> {
+        let worktrees = self.list_worktrees()?;
+        if !self.is_bare()? {
+            return Ok(worktrees.into_iter().next());
+        }
+        let default_branch = self.default_branch().ok();
+        let position = worktrees
+            .iter()
+            .position(|wt| wt.branch.is_some() && wt.branch == default_branch)
+            .unwrap_or(0);
+        Ok(worktrees.into_iter().nth(position))
     }
 
     /// Get the current worktree if we're inside one.
@@ -1604,17 +2237,8 @@ impl Repository {
                     if let Some(parsed) = GitRemoteUrl::parse(url.trim()) {
                         return Ok(parsed.project_identifier());
                     }
-                    // Fallback for URLs that don't fit host/owner/repo model (e.g., with ports)
+                    // Fallback for URLs that don't fit the host/owner/repo model
                     let url = url.trim().strip_suffix(".git").unwrap_or(url.trim());
-                    // Handle ssh:// format with port: ssh://git@host:port/path -> host/port/path
-                    if let Some(ssh_part) = url.strip_prefix("ssh://") {
-                        let ssh_part = ssh_part.strip_prefix("git@").unwrap_or(ssh_part);
-                        if let Some(colon_pos) = ssh_part.find(':') {
-                            let (host, rest) = ssh_part.split_at(colon_pos);
-                            return Ok(format!("{}{}", host, rest.replacen(':', "/", 1)));
-                        }
-                        return Ok(ssh_part.to_string());
-                    }
                     return Ok(url.to_string());
                 }
 
@@ -1666,32 +2290,15 @@ impl Repository {
     /// # Ok::<(), anyhow::Error>(())
     /// ```
     pub fn run_command(&self, args: &[&str]) -> anyhow::Result<String> {
-        use crate::shell_exec::run;
-
-        let mut cmd = Command::new("git");
-        cmd.args(args);
-        cmd.current_dir(&self.path);
-
-        let output = run(&mut cmd, Some(&self.logging_context()))
-            .with_context(|| format!("Failed to execute: git {}", args.join(" ")))?;
+        let output = self.run_git(args)?;
 
         if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            // Normalize carriage returns to newlines for consistent output
-            // Git uses \r for progress updates; in non-TTY contexts this causes snapshot instability
-            let stderr = stderr.replace('\r', "\n");
+            let error = GitError::command_failed(args, &output);
             // Log errors with ! prefix
-            for line in stderr.trim().lines() {
+            for line in error.to_string().lines() {
                 log::debug!("  ! {}", line);
             }
-            // Some git commands print errors to stdout (e.g., `commit` with nothing to commit)
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            let error_msg = [stderr.trim(), stdout.trim()]
-                .into_iter()
-                .filter(|s| !s.is_empty())
-                .collect::<Vec<_>>()
-                .join("\n");
-            bail!("{}", error_msg);
+            return Err(error.into());
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
@@ -1718,18 +2325,29 @@ impl Repository {
     /// # Ok::<(), anyhow::Error>(())
     /// ```
     pub fn run_command_check(&self, args: &[&str]) -> anyhow::Result<bool> {
-        use crate::shell_exec::run;
-
-        let mut cmd = Command::new("git");
-        cmd.args(args);
-        cmd.current_dir(&self.path);
-
-        let output = run(&mut cmd, Some(&self.logging_context()))
-            .with_context(|| format!("Failed to execute: git {}", args.join(" ")))?;
+        Ok(self.run_git(args)?.status.success())
+    }
 
-        Ok(output.status.success())
+    /// Run git through this repository's backend, whatever its exit status
+    fn run_git(&self, args: &[&str]) -> anyhow::Result<std::process::Output> {
+        self.backend
+            .run(&self.path, args, &self.logging_context())
+            .with_context(|| format!("Failed to execute: git {}", args.join(" ")))
     }
 }
 
+/// Condense `git describe --long` output (`v1.2-3-gabc1234`) to `v1.2+3`.
+fn format_describe(long: &str) -> Option<String> {
+    let mut parts = long.rsplitn(3, '-');
+    let _hash = parts.next()?;
+    let distance: usize = parts.next()?.parse().ok()?;
+    let tag = parts.next().filter(|t| !t.is_empty())?;
+    Some(if distance == 0 {
+        tag.to_string()
+    } else {
+        format!("{tag}+{distance}")
+    })
+}
+
 #[cfg(test)]
 mod tests;
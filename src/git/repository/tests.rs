@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use super::super::{DefaultBranchName, Worktree, finalize_worktree};
+use super::format_describe;
 
 #[test]
 fn test_parse_worktree_list() {
@@ -404,3 +405,50 @@ fn test_default_branch_name_display() {
         assert_eq!(branch, expected);
     }
 }
+
+#[test]
+fn test_primary_worktree_in_bare_repo() {
+    use std::sync::Arc;
+
+    use crate::git::Repository;
+    use crate::testing::MockGit;
+
+    // The default branch's worktree, even when it isn't listed first
+    let git = MockGit::default()
+        .on(
+            &["worktree", "list", "--porcelain"],
+            "worktree /project/.git
+bare
+
+worktree /project/feature
+HEAD efgh5678
+branch refs/heads/feature
+
+worktree /project/main
+HEAD abcd1234
+branch refs/heads/main
+
+",
+        )
+        .on(&["config", "--bool", "core.bare"], "true\n")
+        .on(&["config", "--get", "worktrunk.default-branch"], "main\n");
+    let repo = Repository::with_backend("/project/.git", Arc::new(git));
+    let primary = repo.primary_worktree().unwrap().unwrap();
+    assert_eq!(primary.path, PathBuf::from("/project/main"));
+}
+
+#[test]
+fn test_format_describe() {
+    assert_eq!(format_describe("v1.2-0-gabc1234").as_deref(), Some("v1.2"));
+    assert_eq!(
+        format_describe("v1.2-3-gabc1234").as_deref(),
+        Some("v1.2+3")
+    );
+    // Tags may themselves contain dashes
+    assert_eq!(
+        format_describe("release-2024-01-5-gabc1234").as_deref(),
+        Some("release-2024-01+5")
+    );
+    assert_eq!(format_describe("gabc1234"), None);
+    assert_eq!(format_describe(""), None);
+}
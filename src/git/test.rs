@@ -180,25 +180,28 @@ fn test_parse_local_default_branch_empty_remote() {
 // Tests for LineDiff::from_numstat
 
 #[rstest]
-#[case::normal("10\t5\tfile1.rs\n3\t2\tfile2.rs\n", 13, 7)]
-#[case::empty("", 0, 0)]
-#[case::binary_files("10\t5\tfile1.rs\n-\t-\timage.png\n3\t2\tfile2.rs\n", 13, 7)]
-#[case::mixed_binary("10\t-\tfile1.rs\n-\t5\tfile2.rs\n", 0, 0)]
-#[case::empty_lines("10\t5\tfile1.rs\n\n3\t2\tfile2.rs\n\n", 13, 7)]
-#[case::missing_deleted("10\tfile.rs\n", 0, 0)]
-#[case::no_tabs("file.rs\n", 0, 0)]
-#[case::non_numeric_added("abc\t5\tfile.rs\n", 0, 0)]
-#[case::non_numeric_deleted("5\txyz\tfile.rs\n", 0, 0)]
-#[case::zero_values("0\t0\tfile.rs\n", 0, 0)]
+#[case::normal("10\t5\tfile1.rs\n3\t2\tfile2.rs\n", 13, 7, 2)]
+#[case::empty("", 0, 0, 0)]
+#[case::binary_files("10\t5\tfile1.rs\n-\t-\timage.png\n3\t2\tfile2.rs\n", 13, 7, 3)]
+#[case::mixed_binary("10\t-\tfile1.rs\n-\t5\tfile2.rs\n", 0, 0, 0)]
+#[case::empty_lines("10\t5\tfile1.rs\n\n3\t2\tfile2.rs\n\n", 13, 7, 2)]
+#[case::missing_deleted("10\tfile.rs\n", 0, 0, 0)]
+#[case::no_tabs("file.rs\n", 0, 0, 0)]
+#[case::non_numeric_added("abc\t5\tfile.rs\n", 0, 0, 0)]
+#[case::non_numeric_deleted("5\txyz\tfile.rs\n", 0, 0, 0)]
+#[case::zero_values("0\t0\tfile.rs\n", 0, 0, 1)]
 fn test_line_diff_from_numstat(
     #[case] input: &str,
     #[case] expected_added: usize,
     #[case] expected_deleted: usize,
+    #[case] expected_files: usize,
 ) {
     let result = LineDiff::from_numstat(input);
 
     assert!(result.is_ok());
-    let (added, deleted): (usize, usize) = result.unwrap().into();
+    let diff = result.unwrap();
+    assert_eq!(diff.files, expected_files);
+    let (added, deleted): (usize, usize) = diff.into();
     assert_eq!(added, expected_added);
     assert_eq!(deleted, expected_deleted);
 }
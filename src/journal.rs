@@ -0,0 +1,310 @@
+//! Journal of the changes worktrunk makes to a repository (`wt history`,
+//! `wt undo`).
+//!
+//! Appends one JSON object per line to `.git/worktrunk/journal` in the
+//! repository's common directory, so every worktree shares it:
+//!
+//! ```text
+//! {"ts":1735776000,"action":"worktree_created","path":"/repo.feature","branch":"feature","created_branch":true}
+//! {"ts":1735779600,"action":"worktree_removed","path":"/repo.feature","branch":"feature","sha":"1a2b…","branch_deleted":true,"bundle":"/repo/.git/worktrunk/bundles/1735779600-feature.bundle"}
+//! {"ts":1735783200,"action":"undone","entry":2}
+//! ```
+//!
+//! Entries are numbered from 1 in the order they were written. Nothing is
+//! rewritten: undoing an entry appends an `undone` entry naming it.
+//!
+//! Removing a worktree first takes a [`WorktreeBackup`]: its `HEAD`, and a
+//! bundle of the commits the integration target (or the default branch)
+//! doesn't have, which outlives `git gc`. Only committed work is backed up:
+//! `wt remove --force` deletes untracked files with the worktree, and those
+//! are gone for good. Bundles are pruned after [`BUNDLE_RETENTION_SECS`].
+//!
+//! A removal that runs in the background is journaled by that process, with
+//! [`record_command`], once it has succeeded.
+//!
+//! Like the log file, the journal never fails a command: write errors are
+//! logged and dropped.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::git::Repository;
+
+/// One line of the journal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    /// Unix timestamp
+    pub ts: u64,
+    #[serde(flatten)]
+    pub action: Action,
+}
+
+/// A change to the repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum Action {
+    WorktreeCreated {
+        path: PathBuf,
+        branch: String,
+        /// The branch was created along with the worktree
+        created_branch: bool,
+    },
+    WorktreeRemoved {
+        path: PathBuf,
+        /// `None` for a detached worktree
+        branch: Option<String>,
+        /// `HEAD` at removal; `None` if the backup failed
+        sha: Option<String>,
+        branch_deleted: bool,
+        bundle: Option<PathBuf>,
+    },
+    BranchDeleted {
+        branch: String,
+        sha: String,
+    },
+    Merged {
+        branch: String,
+        target: String,
+        sha: String,
+    },
+    /// Entry number `entry` was undone
+    Undone {
+        entry: usize,
+    },
+}
+
+impl Action {
+    /// Whether `wt undo` can reverse this action
+    pub fn is_reversible(&self) -> bool {
+        match self {
+            Action::WorktreeRemoved { sha, .. } => sha.is_some(),
+            Action::BranchDeleted { .. } => true,
+            Action::WorktreeCreated { .. } | Action::Merged { .. } | Action::Undone { .. } => false,
+        }
+    }
+}
+
+/// How long bundles are kept: 90 days, like the reflog entries git keeps
+/// by default (`gc.reflogExpire`)
+pub const BUNDLE_RETENTION_SECS: u64 = 90 * 24 * 60 * 60;
+
+/// Where the journal and its bundles live
+fn journal_dir(repo: &Repository) -> anyhow::Result<PathBuf> {
+    Ok(repo.git_common_dir()?.join("worktrunk"))
+}
+
+/// Append `action` to the repository's journal
+pub fn record(repo: &Repository, action: Action) {
+    if let Err(e) = append(repo, action) {
+        log::debug!("Failed to write journal: {e:#}");
+    }
+}
+
+fn append(repo: &Repository, action: Action) -> anyhow::Result<()> {
+    let dir = journal_dir(repo)?;
+    std::fs::create_dir_all(&dir)?;
+    let mut line = entry_line(action)?;
+    line.push('\n');
+    // One write per entry, so concurrent processes don't interleave
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join("journal"))?;
+    file.write_all(line.as_bytes())?;
+    Ok(())
+}
+
+fn entry_line(action: Action) -> anyhow::Result<String> {
+    let entry = Entry {
+        ts: crate::utils::get_now(),
+        action,
+    };
+    Ok(serde_json::to_string(&entry)?)
+}
+
+/// A POSIX shell command appending `action` to the journal, for a background
+/// process to run once the change it describes has succeeded
+pub fn record_command(repo: &Repository, action: Action) -> anyhow::Result<String> {
+    use shell_escape::unix::escape;
+
+    let dir = journal_dir(repo)?;
+    let dir_str = dir.to_string_lossy();
+    let journal = dir.join("journal");
+    let journal_str = journal.to_string_lossy();
+    let line = entry_line(action)?;
+    Ok(format!(
+        "mkdir -p {} && printf '%s\\n' {} >> {}",
+        escape(dir_str),
+        escape(line.into()),
+        escape(journal_str)
+    ))
+}
+
+/// Every entry in the journal, oldest first. Lines that don't parse (from a
+/// newer worktrunk, or a torn write) are skipped but still numbered.
+pub fn read(repo: &Repository) -> anyhow::Result<Vec<(usize, Entry)>> {
+    let path = journal_dir(repo)?.join("journal");
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    };
+    Ok(parse(&contents))
+}
+
+fn parse(contents: &str) -> Vec<(usize, Entry)> {
+    contents
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| match serde_json::from_str(line) {
+            Ok(entry) => Some((index + 1, entry)),
+            Err(e) => {
+                log::debug!("Skipping journal entry {}: {e}", index + 1);
+                None
+            }
+        })
+        .collect()
+}
+
+/// What `wt undo` needs to bring back a removed worktree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeBackup {
+    pub sha: String,
+    pub bundle: Option<PathBuf>,
+}
+
+/// Back up the worktree at `path` before it's removed. A bundle is written
+/// when `HEAD` has commits that `target` (or without one, the default branch)
+/// doesn't; with neither, there's no base to bound it by, so there's none.
+pub fn backup_worktree(
+    repo: &Repository,
+    path: &Path,
+    branch: Option<&str>,
+    target: Option<&str>,
+) -> anyhow::Result<WorktreeBackup> {
+    let sha = Repository::at(path)
+        .run_command(&["rev-parse", "HEAD"])?
+        .trim()
+        .to_string();
+    let base = target
+        .map(String::from)
+        .or_else(|| repo.default_branch().ok());
+    let bundle = match base {
+        Some(base) if !repo.is_ancestor(&sha, &base).unwrap_or(false) => {
+            Some(write_bundle(repo, &sha, branch, &base)?)
+        }
+        _ => None,
+    };
+    Ok(WorktreeBackup { sha, bundle })
+}
+
+/// Bundle `tip`'s commits that `base` doesn't have, pruning expired bundles.
+/// A bundle only takes refs, so `tip` gets a temporary one.
+fn write_bundle(
+    repo: &Repository,
+    tip: &str,
+    branch: Option<&str>,
+    base: &str,
+) -> anyhow::Result<PathBuf> {
+    let dir = journal_dir(repo)?.join("bundles");
+    std::fs::create_dir_all(&dir)?;
+    let now = crate::utils::get_now();
+    prune_bundles(&dir, now);
+    let name = branch.unwrap_or("detached").replace('/', "-");
+    let bundle = dir.join(format!("{now}-{name}.bundle"));
+    let bundle_str = bundle.to_string_lossy();
+    let temp_ref = format!("refs/worktrunk/backup-{}", std::process::id());
+    repo.run_command(&["update-ref", &temp_ref, tip])?;
+    let exclude = format!("^{base}");
+    let created = repo.run_command(&[
+        "bundle",
+        "create",
+        "--quiet",
+        &bundle_str,
+        &temp_ref,
+        &exclude,
+    ]);
+    let _ = repo.run_command(&["update-ref", "-d", &temp_ref]);
+    created?;
+    Ok(bundle)
+}
+
+/// Delete bundles written more than [`BUNDLE_RETENTION_SECS`] before `now`,
+/// going by the timestamp that starts their names
+fn prune_bundles(dir: &Path, now: u64) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let written = name
+            .to_str()
+            .and_then(|name| name.split_once('-'))
+            .and_then(|(ts, _)| ts.parse::<u64>().ok());
+        if written.is_some_and(|ts| ts + BUNDLE_RETENTION_SECS < now)
+            && let Err(e) = std::fs::remove_file(entry.path())
+        {
+            log::debug!("Failed to prune {}: {e}", entry.path().display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entry_round_trip() {
+        let entry = Entry {
+            ts: 1735776000,
+            action: Action::BranchDeleted {
+                branch: "feature".into(),
+                sha: "abc123".into(),
+            },
+        };
+        let line = serde_json::to_string(&entry).unwrap();
+        assert_eq!(
+            line,
+            r#"{"ts":1735776000,"action":"branch_deleted","branch":"feature","sha":"abc123"}"#
+        );
+        assert_eq!(serde_json::from_str::<Entry>(&line).unwrap(), entry);
+    }
+
+    #[test]
+    fn test_prune_bundles() {
+        let dir = tempfile::tempdir().unwrap();
+        let now = 1_000 * BUNDLE_RETENTION_SECS;
+        let expired = format!("{}-old.bundle", now - BUNDLE_RETENTION_SECS - 1);
+        let kept = format!("{}-recent.bundle", now - BUNDLE_RETENTION_SECS);
+        for name in [expired.as_str(), kept.as_str(), "notes.txt"] {
+            std::fs::write(dir.path().join(name), "").unwrap();
+        }
+        prune_bundles(dir.path(), now);
+        let mut left: Vec<String> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        left.sort();
+        assert_eq!(left, [kept.as_str(), "notes.txt"]);
+    }
+
+    #[test]
+    fn test_parse_skips_unknown_lines() {
+        let contents = concat!(
+            r#"{"ts":1,"action":"undone","entry":3}"#,
+            "\n",
+            r#"{"ts":2,"action":"from_the_future"}"#,
+            "\n",
+            r#"{"ts":3,"action":"merged","branch":"feature","target":"main","sha":"abc"}"#,
+            "\n",
+        );
+        let entries = parse(contents);
+        let numbers: Vec<usize> = entries.iter().map(|(n, _)| *n).collect();
+        assert_eq!(numbers, [1, 3]);
+        assert_eq!(entries[0].1.action, Action::Undone { entry: 3 });
+    }
+}
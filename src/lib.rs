@@ -1,10 +1,15 @@
 pub mod config;
 pub mod git;
+pub mod journal;
+pub mod notify;
+pub mod op_log;
 pub mod path;
 pub mod shell;
 pub mod shell_exec;
 pub mod styling;
 pub mod sync;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod utils;
 
 // Re-export HookType for convenience
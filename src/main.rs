@@ -3,14 +3,15 @@ use clap::FromArgMatches;
 use color_print::cformat;
 use std::path::{Path, PathBuf};
 use std::process;
-use worktrunk::config::{WorktrunkConfig, set_config_path};
-use worktrunk::git::{Repository, exit_code, set_base_path};
+use worktrunk::config::{WorktrunkConfig, set_config_path, set_profile};
+use worktrunk::git::{Repository, exit_code, set_base_path, set_git_dir_override};
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{
-    error_message, format_with_gutter, hint_message, info_message, println, success_message,
-    warning_message,
+    ERROR_SYMBOL, error_message, format_with_gutter, hint_message, info_message, println,
+    set_emoji, success_message, warning_message,
 };
 
+mod alias;
 mod cli;
 mod commands;
 mod completion;
@@ -19,28 +20,36 @@ pub(crate) mod help_pager;
 mod llm;
 mod md_help;
 mod output;
+mod plugin;
 
 pub use crate::cli::OutputFormat;
 
 use commands::command_executor::CommandContext;
-#[cfg(unix)]
-use commands::handle_select;
-use commands::worktree::{SwitchResult, handle_push};
+use commands::repository_ext::RepositoryCliExt;
+use commands::worktree::SwitchResult;
 use commands::{
     ConfigAction, MergeOptions, RebaseResult, ResolutionContext, SquashResult, add_approvals,
-    approve_hooks, clear_approvals, compute_worktree_path, handle_config_create,
-    handle_config_show, handle_configure_shell, handle_hook_show, handle_init, handle_list,
-    handle_merge, handle_rebase, handle_remove, handle_remove_by_path, handle_remove_current,
-    handle_show_theme, handle_squash, handle_state_clear, handle_state_clear_all, handle_state_get,
-    handle_state_set, handle_state_show, handle_switch, handle_unconfigure_shell,
-    resolve_worktree_arg, run_hook, step_commit, step_for_each,
+    approve_hooks, clear_approvals, compute_worktree_path, confirm_bulk_removal, fetch_pr_branch,
+    handle_clone, handle_config_create, handle_config_migrate, handle_config_set,
+    handle_config_show, handle_config_validate, handle_configure_shell, handle_history,
+    handle_hook_show, handle_init, handle_list, handle_list_all_repos, handle_list_legend,
+    handle_log, handle_merge, handle_merge_abort, handle_merge_continue, handle_note, handle_pr,
+    handle_prompt, handle_rebase, handle_remove, handle_remove_by_path, handle_remove_current,
+    handle_repair, handle_repo_add, handle_repo_list, handle_repo_remove, handle_show_theme,
+    handle_squash, handle_state_clear, handle_state_clear_all, handle_state_get, handle_state_set,
+    handle_state_show, handle_switch, handle_sync, handle_sync_upstream, handle_tidy, handle_tmux,
+    handle_trust_list, handle_trust_revoke, handle_unconfigure_shell, handle_undo,
+    handle_workspace, issue_branch, merged_worktree_branches, pattern_worktree_branches,
+    print_bulk_removal, resolve_worktree_arg, run_hook, step_commit, step_for_each, step_push,
 };
+#[cfg(unix)]
+use commands::{handle_open_diff, handle_select};
 use output::{execute_user_command, handle_remove_output, handle_switch_output};
 
 use cli::{
     ApprovalsCommand, CiStatusAction, Cli, Commands, ConfigCommand, ConfigShellCommand,
     DefaultBranchAction, HookCommand, ListSubcommand, LogsAction, MarkerAction,
-    PreviousBranchAction, StateCommand, StepCommand,
+    PreviousBranchAction, RepoCommand, StateCommand, StepCommand, TrustCommand,
 };
 use worktrunk::HookType;
 
@@ -617,6 +626,213 @@ fn enhance_and_exit_error(err: clap::Error) -> ! {
     err.exit()
 }
 
+/// Switch to (or create) a worktree, running hooks and an optional command.
+///
+/// Shared by `wt switch` and `wt pr --checkout`.
+#[allow(clippy::too_many_arguments)]
+fn run_switch(
+    branch: &str,
+    create: bool,
+    base: Option<&str>,
+    orphan: bool,
+    cherry_pick: Option<&str>,
+    execute: Option<String>,
+    yes: bool,
+    clobber: bool,
+    strict: bool,
+    verify: bool,
+) -> anyhow::Result<()> {
+    let config = WorktrunkConfig::load().context("Failed to load config")?;
+
+    if create {
+        commands::limits::check_before_create(&Repository::current(), &config.limits(), strict)?;
+    }
+
+    // "Approve at the Gate": collect and approve hooks upfront
+    // This ensures approval happens once at the command entry point
+    // If user declines, skip hooks but continue with worktree operation
+    let approved = if verify {
+        let repo = Repository::current();
+        let repo_root = repo.worktree_base().context("Failed to switch worktree")?;
+        // Compute worktree path for template expansion in approval prompt
+        let worktree_path = compute_worktree_path(&repo, branch, &config)?;
+        let ctx = CommandContext::new(
+            &repo,
+            &config,
+            Some(branch),
+            &worktree_path,
+            &repo_root,
+            yes,
+        );
+        // Approve different hooks based on whether we're creating or switching
+        if create {
+            approve_hooks(
+                &ctx,
+                &[
+                    HookType::PreSwitch,
+                    HookType::PostCreate,
+                    HookType::PostStart,
+                    HookType::PostSwitch,
+                ],
+            )?
+        } else {
+            // When switching to existing, only pre-switch and post-switch need approval
+            approve_hooks(&ctx, &[HookType::PreSwitch, HookType::PostSwitch])?
+        }
+    } else {
+        true // --no-verify: skip all hooks
+    };
+
+    // Skip hooks if --no-verify or user declined approval
+    let skip_hooks = !verify || !approved;
+
+    // Show message if user declined approval
+    if !approved {
+        crate::output::print(info_message(if create {
+            "Commands declined, continuing worktree creation"
+        } else {
+            "Commands declined"
+        }))?;
+    }
+
+    // Pre-switch runs in the worktree being left, before anything changes
+    // (skipped outside a worktree, e.g. from a bare repository's directory)
+    let repo = Repository::current();
+    if !skip_hooks && let Ok(worktree_path) = repo.worktree_root() {
+        let worktree_path = worktree_path.to_path_buf();
+        let has_pre_switch = config.hooks.pre_switch.is_some()
+            || repo
+                .load_project_config()?
+                .is_some_and(|project| project.hooks.pre_switch.is_some());
+        if has_pre_switch {
+            let repo_root = repo.worktree_base().context("Failed to switch worktree")?;
+            let current_branch = repo.current_branch().context("Failed to switch worktree")?;
+            let target = repo.resolve_worktree_name(branch)?;
+            let ctx = CommandContext::new(
+                &repo,
+                &config,
+                current_branch,
+                &worktree_path,
+                &repo_root,
+                yes,
+            );
+            ctx.execute_pre_switch_commands(&target, None)?;
+        }
+    }
+
+    // Execute switch operation (creates worktree, runs post-create hooks if approved)
+    let (result, branch_info) = handle_switch(
+        branch,
+        create,
+        base,
+        orphan,
+        cherry_pick,
+        yes,
+        clobber,
+        skip_hooks,
+        &config,
+    )?;
+    output::report::record(|report| {
+        report.branch = Some(branch_info.branch().to_string());
+        if let SwitchResult::Created {
+            path,
+            created_branch,
+            from_remote,
+            ..
+        } = &result
+        {
+            report.created_worktrees.push(path.clone());
+            if *created_branch || from_remote.is_some() {
+                report
+                    .created_refs
+                    .push(format!("refs/heads/{}", branch_info.branch()));
+            }
+        }
+    });
+    if let SwitchResult::Created {
+        path,
+        created_branch,
+        base_branch,
+        from_remote,
+    } = &result
+    {
+        if *created_branch || from_remote.is_some() {
+            worktrunk::op_log::record(
+                "branch_created",
+                serde_json::json!({
+                    "branch": branch_info.branch(),
+                    "base": base_branch,
+                    "from_remote": from_remote,
+                }),
+            );
+        }
+        worktrunk::op_log::record(
+            "worktree_created",
+            serde_json::json!({ "path": path, "branch": branch_info.branch() }),
+        );
+        worktrunk::journal::record(
+            &Repository::current(),
+            worktrunk::journal::Action::WorktreeCreated {
+                path: path.clone(),
+                branch: branch_info.branch().to_string(),
+                created_branch: *created_branch || from_remote.is_some(),
+            },
+        );
+    }
+
+    // Show success message (temporal locality: immediately after worktree operation)
+    handle_switch_output(&result, &branch_info, execute.is_some())?;
+
+    // Spawn background hooks after success message
+    // - post-switch: runs on ALL switches (Created, Existing, AlreadyAt)
+    // - post-start: runs only when creating a NEW worktree
+    if !skip_hooks {
+        let repo = Repository::current();
+        let repo_root = repo.worktree_base().context("Failed to switch worktree")?;
+        let ctx = CommandContext::new(
+            &repo,
+            &config,
+            Some(branch_info.branch()),
+            result.path(),
+            &repo_root,
+            yes,
+        );
+
+        // Post-switch runs first (immediate "I'm here" signal)
+        ctx.spawn_post_switch_commands()?;
+
+        // Post-start runs only on creation (setup tasks)
+        if let SwitchResult::Created { base_branch, .. } = &result {
+            ctx.spawn_post_start_commands(base_branch.as_deref())?;
+        }
+    }
+
+    // Execute user command after post-start hooks have been spawned; it may
+    // replace this process, so the report goes out first
+    output::report::emit()?;
+    if let Some(cmd) = execute {
+        execute_user_command(&cmd)?;
+    }
+
+    Ok(())
+}
+
+/// Record a report of what `action` does, printed at the end, for `--format json`
+fn start_report(action: &'static str, format: OutputFormat) {
+    if matches!(format, OutputFormat::Json) {
+        output::report::start(action);
+    }
+}
+
+/// An error's message without styling or the leading error symbol
+fn plain_error_message(e: &anyhow::Error) -> String {
+    let message = ansi_str::AnsiStr::ansi_strip(&e.to_string()).into_owned();
+    message
+        .trim_start_matches(&*ERROR_SYMBOL.plain())
+        .trim()
+        .to_string()
+}
+
 fn main() {
     // Tell crossterm to always emit ANSI sequences
     crossterm::style::force_color_output(true);
@@ -635,8 +851,22 @@ fn main() {
     // Clap doesn't support this natively yet - see https://github.com/clap-rs/clap/issues/3320
     // When available, use built-in setting. Until then, could use try_parse() to intercept
     // MissingRequiredArgument errors and print custom messages with ValueEnum::value_variants().
+    let args = alias::expand_args(std::env::args_os().collect()).unwrap_or_else(|e| {
+        let _ = output::print(error_message(e.to_string()));
+        process::exit(1);
+    });
+
+    // Unknown subcommands dispatch to `wt-<name>` plugins on PATH
+    match plugin::maybe_run_plugin(&args) {
+        Some(Ok(code)) => process::exit(code),
+        Some(Err(e)) => {
+            let _ = output::print(error_message(e.to_string()));
+            process::exit(1);
+        }
+        None => {}
+    }
     let cmd = cli::build_command();
-    let matches = cmd.try_get_matches().unwrap_or_else(|e| {
+    let matches = cmd.try_get_matches_from(args).unwrap_or_else(|e| {
         enhance_and_exit_error(e);
     });
     let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
@@ -646,65 +876,122 @@ fn main() {
         set_base_path(path);
     }
 
+    // Like git, --git-dir/--work-tree take precedence over GIT_DIR/GIT_WORK_TREE,
+    // and relative paths are relative to the working directory
+    let git_dir = cli
+        .git_dir
+        .or_else(|| std::env::var_os("GIT_DIR").map(PathBuf::from));
+    let work_tree = cli
+        .work_tree
+        .or_else(|| std::env::var_os("GIT_WORK_TREE").map(PathBuf::from));
+    if git_dir.is_some() || work_tree.is_some() {
+        let cwd = worktrunk::git::working_dir().unwrap_or_default();
+        let resolve = |path: PathBuf| {
+            let path = cwd.join(path);
+            dunce::canonicalize(&path).unwrap_or(path)
+        };
+        set_git_dir_override(git_dir.map(resolve), work_tree.map(resolve));
+    }
+
     // Initialize config path from --config flag if provided
     if let Some(path) = cli.config {
         set_config_path(path);
     }
 
-    // Configure logging based on --verbose flag or RUST_LOG env var
-    env_logger::Builder::from_env(
-        env_logger::Env::default().default_filter_or(if cli.verbose { "debug" } else { "off" }),
-    )
-    .format(|buf, record| {
-        use std::io::Write;
-
-        let msg = record.args().to_string();
-
-        // Map thread ID to a single character (a-z, then A-Z)
-        let thread_id = format!("{:?}", std::thread::current().id());
-        let thread_num = thread_id
-            .strip_prefix("ThreadId(")
-            .and_then(|s| s.strip_suffix(")"))
-            .and_then(|s| s.parse::<usize>().ok())
-            .map(|n| {
-                if n <= 26 {
-                    char::from(b'a' + (n - 1) as u8)
-                } else if n <= 52 {
-                    char::from(b'A' + (n - 27) as u8)
+    // Select a config profile from --profile (WORKTRUNK_PROFILE is read at load)
+    if let Some(name) = cli.profile {
+        set_profile(name);
+    }
+
+    if cli.no_emoji {
+        set_emoji(false);
+    }
+
+    if cli.no_hooks {
+        commands::set_hooks_enabled(false);
+    }
+
+    let assume_yes_env = std::env::var("WORKTRUNK_ASSUME_YES")
+        .is_ok_and(|value| matches!(value.to_ascii_lowercase().as_str(), "1" | "true" | "yes"));
+    if cli.assume_yes || assume_yes_env {
+        commands::set_assume_yes(true);
+    }
+
+    // --repo targets a registered repository, like -C with its path
+    if let Some(name) = &cli.repo {
+        match commands::repo::resolve_repo(name) {
+            Ok(path) => set_base_path(path),
+            Err(e) => {
+                let _ = output::print(e.to_string());
+                process::exit(1);
+            }
+        }
+    }
+
+    if cli.quiet {
+        output::set_quiet(true);
+    }
+
+    // Configure logging based on -v/-vv or RUST_LOG env var: `-v` shows the
+    // commands worktrunk runs, `-vv` everything at debug level
+    let log_filter = match cli.verbose {
+        0 => "off",
+        1 => "worktrunk=info",
+        _ => "debug",
+    };
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(log_filter))
+        .format(|buf, record| {
+            use std::io::Write;
+
+            let msg = record.args().to_string();
+
+            // Map thread ID to a single character (a-z, then A-Z)
+            let thread_id = format!("{:?}", std::thread::current().id());
+            let thread_num = thread_id
+                .strip_prefix("ThreadId(")
+                .and_then(|s| s.strip_suffix(")"))
+                .and_then(|s| s.parse::<usize>().ok())
+                .map(|n| {
+                    if n <= 26 {
+                        char::from(b'a' + (n - 1) as u8)
+                    } else if n <= 52 {
+                        char::from(b'A' + (n - 27) as u8)
+                    } else {
+                        '?'
+                    }
+                })
+                .unwrap_or('?');
+
+            // Commands start with $, make only the command bold (not $ or [worktree])
+            if let Some(rest) = msg.strip_prefix("$ ") {
+                // Split: "git command [worktree]" -> ("git command", " [worktree]")
+                if let Some(bracket_pos) = rest.find(" [") {
+                    let command = &rest[..bracket_pos];
+                    let worktree = &rest[bracket_pos..];
+                    writeln!(
+                        buf,
+                        "{}",
+                        cformat!("<dim>[{thread_num}]</> $ <bold>{command}</>{worktree}")
+                    )
                 } else {
-                    '?'
+                    writeln!(
+                        buf,
+                        "{}",
+                        cformat!("<dim>[{thread_num}]</> $ <bold>{rest}</>")
+                    )
                 }
-            })
-            .unwrap_or('?');
-
-        // Commands start with $, make only the command bold (not $ or [worktree])
-        if let Some(rest) = msg.strip_prefix("$ ") {
-            // Split: "git command [worktree]" -> ("git command", " [worktree]")
-            if let Some(bracket_pos) = rest.find(" [") {
-                let command = &rest[..bracket_pos];
-                let worktree = &rest[bracket_pos..];
-                writeln!(
-                    buf,
-                    "{}",
-                    cformat!("<dim>[{thread_num}]</> $ <bold>{command}</>{worktree}")
-                )
+            } else if msg.starts_with("  ! ") {
+                // Error output - show in red
+                writeln!(buf, "{}", cformat!("<dim>[{thread_num}]</> <red>{msg}</>"))
             } else {
-                writeln!(
-                    buf,
-                    "{}",
-                    cformat!("<dim>[{thread_num}]</> $ <bold>{rest}</>")
-                )
+                // Regular output with thread ID
+                writeln!(buf, "{}", cformat!("<dim>[{thread_num}]</> {msg}"))
             }
-        } else if msg.starts_with("  ! ") {
-            // Error output - show in red
-            writeln!(buf, "{}", cformat!("<dim>[{thread_num}]</> <red>{msg}</>"))
-        } else {
-            // Regular output with thread ID
-            writeln!(buf, "{}", cformat!("<dim>[{thread_num}]</> {msg}"))
-        }
-    })
-    .init();
+        })
+        .init();
 
+    let log_file = cli.log_file;
+    let remote = cli.remote;
     let Some(command) = cli.command else {
         // No subcommand provided - print help to stderr (stdout is eval'd by shell wrapper)
         let mut cmd = cli::build_command();
@@ -713,14 +1000,84 @@ fn main() {
         return;
     };
 
+    // Rename keys from older releases before anything reads the config;
+    // `wt config migrate` reports (or previews) the migration itself
+    if !matches!(
+        command,
+        Commands::Config {
+            action: ConfigCommand::Migrate { .. }
+        }
+    ) {
+        commands::config::migrate_user_config_if_needed();
+    }
+
+    // `styling.emoji = false` is the config equivalent of --no-emoji, and
+    // `log-file` of --log-file; a config that fails to load is reported by the
+    // command itself
+    let config = WorktrunkConfig::load().ok();
+    if config.as_ref().is_some_and(|config| !config.emoji()) {
+        set_emoji(false);
+    }
+    // `remote` is the config equivalent of --remote
+    if let Some(remote) = remote
+        .or_else(|| config.as_ref().and_then(|config| config.remote.clone()))
+        .filter(|remote| !remote.is_empty())
+    {
+        worktrunk::git::set_remote_override(remote);
+    }
+    // Commands that can run long enough for users to switch away; interactive
+    // ones (`wt select`) end when the user does
+    let notify_label = match &command {
+        Commands::Switch { .. } => Some("switch"),
+        Commands::Remove { .. } => Some("remove"),
+        Commands::Merge { .. } => Some("merge"),
+        Commands::Sync { .. } => Some("sync"),
+        Commands::List { .. } => Some("list"),
+        Commands::Step { .. } => Some("step"),
+        Commands::Hook { .. } => Some("hook"),
+        Commands::Clone { .. } => Some("clone"),
+        _ => None,
+    }
+    .zip(config.as_ref().and_then(|config| config.notify_after()));
+    let started = std::time::Instant::now();
+    if let Some(path) = log_file.or_else(|| config.and_then(|config| config.log_file())) {
+        match worktrunk::op_log::open(&path) {
+            Ok(()) => worktrunk::op_log::record(
+                "start",
+                serde_json::json!({
+                    "args": std::env::args().collect::<Vec<_>>(),
+                    "cwd": std::env::current_dir().ok(),
+                    "version": env!("CARGO_PKG_VERSION"),
+                }),
+            ),
+            Err(e) => {
+                let _ = output::print(warning_message(cformat!(
+                    "Can't write log file <bold>{}</>: {e}",
+                    worktrunk::path::format_path_for_display(&path)
+                )));
+            }
+        }
+    }
+
     let result = match command {
         Commands::Config { action } => match action {
             ConfigCommand::Shell { action } => {
                 match action {
-                    ConfigShellCommand::Init { shell, cmd } => {
+                    ConfigShellCommand::Init {
+                        shell,
+                        cmd,
+                        on_switch,
+                        with_abbr,
+                    } => {
                         // Generate shell code to stdout
                         let cmd = cmd.unwrap_or_else(binary_name);
-                        handle_init(shell, cmd).map_err(|e| anyhow::anyhow!("{}", e))
+                        handle_init(shell, cmd, on_switch, with_abbr)
+                            .map_err(|e| anyhow::anyhow!("{}", e))
+                    }
+                    ConfigShellCommand::Completions { shell, cmd } => {
+                        let cmd = cmd.unwrap_or_else(binary_name);
+                        print!("{}", completion::completion_script(shell, &cmd));
+                        Ok(())
                     }
                     ConfigShellCommand::Install { shell, yes, cmd } => {
                         // Auto-write to shell config files and completions
@@ -1013,6 +1370,9 @@ fn main() {
             }
             ConfigCommand::Create { project } => handle_config_create(project),
             ConfigCommand::Show { full } => handle_config_show(full),
+            ConfigCommand::Validate => handle_config_validate(),
+            ConfigCommand::Migrate { dry_run } => handle_config_migrate(dry_run),
+            ConfigCommand::Set { key, value } => handle_config_set(&key, &value),
             ConfigCommand::State { action } => match action {
                 StateCommand::DefaultBranch { action } => match action {
                     Some(DefaultBranchAction::Get { refresh }) => {
@@ -1117,7 +1477,7 @@ fn main() {
                         false
                     };
 
-                    match handle_squash(target.as_deref(), yes, !verify, stage_final)? {
+                    match handle_squash(target.as_deref(), yes, !verify, stage_final, false)? {
                         SquashResult::Squashed | SquashResult::NoNetChanges => {}
                         SquashResult::NoCommitsAhead(branch) => {
                             crate::output::print(info_message(format!(
@@ -1132,9 +1492,17 @@ fn main() {
                     }
                     Ok(())
                 }),
-            StepCommand::Push { target } => handle_push(target.as_deref(), "Pushed to", None),
+            StepCommand::Push {
+                target,
+                yes,
+                verify,
+                format,
+            } => {
+                start_report("push", format);
+                step_push(target.as_deref(), yes, verify)
+            }
             StepCommand::Rebase { target } => {
-                handle_rebase(target.as_deref()).and_then(|result| match result {
+                handle_rebase(target.as_deref(), false).and_then(|result| match result {
                     RebaseResult::Rebased => Ok(()),
                     RebaseResult::UpToDate(branch) => {
                         crate::output::print(info_message(cformat!(
@@ -1151,12 +1519,16 @@ fn main() {
                 hook_type,
                 expanded,
             } => handle_hook_show(hook_type.as_deref(), expanded),
+            HookCommand::Status { format } => commands::hook_status::handle_hook_status(format),
             HookCommand::PostCreate { name, yes } => {
                 run_hook(HookType::PostCreate, yes, name.as_deref())
             }
             HookCommand::PostStart { name, yes } => {
                 run_hook(HookType::PostStart, yes, name.as_deref())
             }
+            HookCommand::PreSwitch { name, yes } => {
+                run_hook(HookType::PreSwitch, yes, name.as_deref())
+            }
             HookCommand::PostSwitch { name, yes } => {
                 run_hook(HookType::PostSwitch, yes, name.as_deref())
             }
@@ -1169,18 +1541,30 @@ fn main() {
             HookCommand::PostMerge { name, yes } => {
                 run_hook(HookType::PostMerge, yes, name.as_deref())
             }
+            HookCommand::PrePush { name, yes } => run_hook(HookType::PrePush, yes, name.as_deref()),
             HookCommand::PreRemove { name, yes } => {
                 run_hook(HookType::PreRemove, yes, name.as_deref())
             }
+            HookCommand::PostRemove { name, yes } => {
+                run_hook(HookType::PostRemove, yes, name.as_deref())
+            }
             HookCommand::Approvals { action } => match action {
                 ApprovalsCommand::Add { all } => add_approvals(all),
                 ApprovalsCommand::Clear { global } => clear_approvals(global),
             },
         },
         #[cfg(unix)]
-        Commands::Select => handle_select(),
+        Commands::Select {
+            branches,
+            open_diff,
+            preview_state,
+        } => match (open_diff, preview_state) {
+            (Some(target), Some(state)) => handle_open_diff(&target, &state),
+            _ => commands::list::collect::BranchFilter::new(&branches)
+                .and_then(|f| handle_select(&f)),
+        },
         #[cfg(not(unix))]
-        Commands::Select => {
+        Commands::Select { .. } => {
             let _ = output::print(error_message("wt select is not available on Windows"));
             let _ = output::print(hint_message(cformat!(
                 "To see all worktrees, run <bright-black>wt list</>; to switch directly, run <bright-black>wt switch BRANCH</>"
@@ -1190,22 +1574,47 @@ fn main() {
         Commands::List {
             subcommand,
             format,
+            json,
+            template,
             branches,
             remotes,
             full,
+            ci,
+            columns,
+            all_repos,
+            legend,
             progressive,
             no_progressive,
         } => match subcommand {
             Some(ListSubcommand::Statusline { claude_code }) => {
                 commands::statusline::run(claude_code)
             }
+            None if legend => handle_list_legend(),
             None => {
                 use commands::list::progressive::RenderMode;
 
+                // `--json <fields>` is JSON output cut down to those fields
+                let json_selection =
+                    json.map(|fields| commands::list::JsonSelection { fields, template });
+                let format = if json_selection.is_some() {
+                    OutputFormat::Json
+                } else {
+                    format
+                };
+
                 // Load config and merge with CLI flags (CLI flags take precedence)
                 WorktrunkConfig::load()
                     .context("Failed to load config")
-                    .and_then(|config| {
+                    .and_then(|mut config| {
+                        if let Some(selection) = &json_selection {
+                            selection.validate()?;
+                        }
+
+                        // --columns replaces the configured column list
+                        if !columns.is_empty() {
+                            config.list.get_or_insert_default().columns = Some(columns);
+                        }
+
                         // Get config values from global list config
                         let (show_branches_config, show_remotes_config, show_full_config) = config
                             .list
@@ -1220,9 +1629,16 @@ fn main() {
                             .unwrap_or((false, false, false));
 
                         // CLI flags override config
-                        let show_branches = branches || show_branches_config;
+                        let show_branches = branches.is_some() || show_branches_config;
+                        let branch_filter = commands::list::collect::BranchFilter::new(
+                            branches.as_deref().unwrap_or_default(),
+                        )?;
                         let show_remotes = remotes || show_remotes_config;
                         let show_full = full || show_full_config;
+                        // `--full` includes CI; `ci.provider = "none"` turns it off everywhere
+                        let ci_config = config.ci();
+                        let show_ci = (ci || show_full || ci_config.enabled.unwrap_or(false))
+                            && !ci_config.is_disabled();
 
                         // Convert two bools to Option<bool>: Some(true), Some(false), or None
                         let progressive_opt = match (progressive, no_progressive) {
@@ -1230,143 +1646,186 @@ fn main() {
                             (_, true) => Some(false),
                             _ => None,
                         };
+                        if all_repos {
+                            return handle_list_all_repos(
+                                format,
+                                show_branches,
+                                show_remotes,
+                                &branch_filter,
+                                show_full,
+                                show_ci,
+                                json_selection.as_ref(),
+                                &config,
+                            );
+                        }
                         let render_mode = RenderMode::detect(progressive_opt);
                         handle_list(
                             format,
                             show_branches,
                             show_remotes,
+                            &branch_filter,
                             show_full,
+                            show_ci,
                             render_mode,
+                            json_selection.as_ref(),
                             &config,
                         )
                     })
             }
         },
+        Commands::Log { branch, all } => handle_log(branch.as_deref(), all),
+        Commands::Note {
+            branch,
+            text,
+            clear,
+        } => Repository::current()
+            .resolve_worktree_name(&branch)
+            .and_then(|branch| handle_note(&branch, text, clear)),
+        Commands::Prompt { format } => handle_prompt(format),
+        Commands::Tmux { branch } => handle_tmux(branch.as_deref()),
+        Commands::Workspace { output } => handle_workspace(output.as_deref()),
+        Commands::Clone { url, dest, bare } => handle_clone(&url, dest.as_deref(), bare),
+        Commands::Repair { from } => handle_repair(from.as_deref()),
+        Commands::Tidy { stale_after } => handle_tidy(stale_after),
+        Commands::History => handle_history(),
+        Commands::Undo { entry } => handle_undo(entry),
+        Commands::Repo { action } => match action {
+            RepoCommand::Add { path } => handle_repo_add(path.as_deref()),
+            RepoCommand::List => handle_repo_list(),
+            RepoCommand::Remove { name } => handle_repo_remove(&name),
+        },
+        Commands::Trust { action } => match action {
+            TrustCommand::List { all } => handle_trust_list(all),
+            TrustCommand::Revoke { approval } => handle_trust_revoke(&approval),
+        },
+        Commands::Pr {
+            branch,
+            open,
+            checkout,
+            yes,
+            verify,
+        } => match checkout {
+            Some(number) => fetch_pr_branch(number).and_then(|branch| {
+                run_switch(
+                    &branch, false, None, false, None, None, yes, false, false, verify,
+                )
+            }),
+            None => handle_pr(branch.as_deref(), open),
+        },
         Commands::Switch {
             branch,
             create,
+            from_issue,
             base,
+            orphan,
+            cherry_pick,
             execute,
             execute_args,
             yes,
             clobber,
+            strict,
             verify,
-        } => WorktrunkConfig::load()
-            .context("Failed to load config")
-            .and_then(|config| {
-                // "Approve at the Gate": collect and approve hooks upfront
-                // This ensures approval happens once at the command entry point
-                // If user declines, skip hooks but continue with worktree operation
-                let approved = if verify {
-                    let repo = Repository::current();
-                    let repo_root = repo.worktree_base().context("Failed to switch worktree")?;
-                    // Compute worktree path for template expansion in approval prompt
-                    let worktree_path = compute_worktree_path(&repo, &branch, &config)?;
-                    let ctx = CommandContext::new(
-                        &repo,
-                        &config,
-                        Some(&branch),
-                        &worktree_path,
-                        &repo_root,
-                        yes,
-                    );
-                    // Approve different hooks based on whether we're creating or switching
-                    if create {
-                        approve_hooks(
-                            &ctx,
-                            &[
-                                HookType::PostCreate,
-                                HookType::PostStart,
-                                HookType::PostSwitch,
-                            ],
-                        )?
-                    } else {
-                        // When switching to existing, only post-switch needs approval
-                        approve_hooks(&ctx, &[HookType::PostSwitch])?
-                    }
+            format,
+        } => {
+            start_report("switch", format);
+            // Append any trailing args (after --) to the execute command
+            // Note: execute_args requires execute via clap's `requires` attribute
+            let execute = execute.map(|cmd| {
+                if execute_args.is_empty() {
+                    cmd
                 } else {
-                    true // --no-verify: skip all hooks
-                };
-
-                // Skip hooks if --no-verify or user declined approval
-                let skip_hooks = !verify || !approved;
-
-                // Show message if user declined approval
-                if !approved {
-                    crate::output::print(info_message(if create {
-                        "Commands declined, continuing worktree creation"
-                    } else {
-                        "Commands declined"
-                    }))?;
+                    let escaped_args: Vec<_> = execute_args
+                        .iter()
+                        .map(|arg| shlex::try_quote(arg).unwrap_or(arg.into()).into_owned())
+                        .collect();
+                    format!("{} {}", cmd, escaped_args.join(" "))
                 }
-
-                // Execute switch operation (creates worktree, runs post-create hooks if approved)
-                let (result, branch_info) = handle_switch(
+            });
+            // `wt switch --create --from-issue 1234`: the branch is named
+            // after the issue unless one was given
+            let branch = match from_issue {
+                Some(number) => issue_branch(number).and_then(|(name, url)| {
+                    let name = branch.unwrap_or(name);
+                    Repository::current().set_branch_issue(&name, &url)?;
+                    Ok(name)
+                }),
+                None => Ok(branch.expect("clap requires a branch without --from-issue")),
+            };
+            branch.and_then(|branch| {
+                // `wt switch 3`: the third row of the last `wt list`
+                let branch = if create {
+                    branch
+                } else {
+                    Repository::current()
+                        .resolve_ordinal(&branch)
+                        .unwrap_or(branch)
+                };
+                run_switch(
                     &branch,
                     create,
                     base.as_deref(),
+                    orphan,
+                    cherry_pick.as_deref(),
+                    execute,
                     yes,
                     clobber,
-                    skip_hooks,
-                    &config,
-                )?;
-
-                // Show success message (temporal locality: immediately after worktree operation)
-                handle_switch_output(&result, &branch_info, execute.is_some())?;
-
-                // Spawn background hooks after success message
-                // - post-switch: runs on ALL switches (Created, Existing, AlreadyAt)
-                // - post-start: runs only when creating a NEW worktree
-                if !skip_hooks {
-                    let repo = Repository::current();
-                    let repo_root = repo.worktree_base().context("Failed to switch worktree")?;
-                    let ctx = CommandContext::new(
-                        &repo,
-                        &config,
-                        Some(branch_info.branch()),
-                        result.path(),
-                        &repo_root,
-                        yes,
-                    );
-
-                    // Post-switch runs first (immediate "I'm here" signal)
-                    ctx.spawn_post_switch_commands()?;
-
-                    // Post-start runs only on creation (setup tasks)
-                    if matches!(&result, SwitchResult::Created { .. }) {
-                        ctx.spawn_post_start_commands()?;
-                    }
-                }
-
-                // Execute user command after post-start hooks have been spawned
-                // Note: execute_args requires execute via clap's `requires` attribute
-                if let Some(cmd) = execute {
-                    // Append any trailing args (after --) to the execute command
-                    let full_cmd = if execute_args.is_empty() {
-                        cmd
-                    } else {
-                        let escaped_args: Vec<_> = execute_args
-                            .iter()
-                            .map(|arg| shlex::try_quote(arg).unwrap_or(arg.into()).into_owned())
-                            .collect();
-                        format!("{} {}", cmd, escaped_args.join(" "))
-                    };
-                    execute_user_command(&full_cmd)?;
-                }
-
-                Ok(())
-            }),
+                    strict,
+                    verify,
+                )
+            })
+        }
         Commands::Remove {
             branches,
             delete_branch,
             force_delete,
+            merged,
+            pattern,
+            dry_run,
             background,
             verify,
             yes,
             force,
+            format,
         } => WorktrunkConfig::load()
             .context("Failed to load config")
             .and_then(|config| {
+                start_report("remove", format);
+                // `wt remove 2`: rows of the last `wt list`, resolved before anything moves
+                let repo = Repository::current();
+                let branches: Vec<String> = branches
+                    .into_iter()
+                    .map(|name| repo.resolve_ordinal(&name).unwrap_or(name))
+                    .collect();
+                if dry_run && !merged && pattern.is_none() {
+                    anyhow::bail!("--dry-run needs --merged or --pattern");
+                }
+                let branches = if merged {
+                    let branches = merged_worktree_branches(&repo)?;
+                    if branches.is_empty() {
+                        crate::output::print(info_message("No merged worktrees to remove"))?;
+                        return Ok(());
+                    }
+                    if dry_run {
+                        return print_bulk_removal(&repo, &branches, "merged");
+                    }
+                    branches
+                } else if let Some(pattern) = &pattern {
+                    let branches = pattern_worktree_branches(&repo, pattern)?;
+                    if branches.is_empty() {
+                        crate::output::print(info_message(cformat!(
+                            "No worktrees match <bold>{pattern}</>"
+                        )))?;
+                        return Ok(());
+                    }
+                    let description = cformat!("matching <bold>{pattern}</>");
+                    print_bulk_removal(&repo, &branches, &description)?;
+                    if dry_run || !(yes || confirm_bulk_removal()?) {
+                        return Ok(());
+                    }
+                    branches
+                } else {
+                    branches
+                };
                 // Validate conflicting flags
                 if !delete_branch && force_delete {
                     return Err(worktrunk::git::GitError::Other {
@@ -1385,7 +1844,7 @@ fn main() {
                 let verify = if verify {
                     // Create context for template expansion in approval prompt
                     let worktree_path =
-                        std::env::current_dir().context("Failed to get current directory")?;
+                        worktrunk::git::working_dir().context("Failed to get current directory")?;
                     let repo_root = repo.worktree_base().context("Failed to remove worktree")?;
                     // Keep as Option so detached HEAD maps to None -> "HEAD" via branch_or_head()
                     let current_branch =
@@ -1398,8 +1857,14 @@ fn main() {
                         &repo_root,
                         yes,
                     );
-                    let approved =
-                        approve_hooks(&ctx, &[HookType::PreRemove, HookType::PostSwitch])?;
+                    let approved = approve_hooks(
+                        &ctx,
+                        &[
+                            HookType::PreRemove,
+                            HookType::PostRemove,
+                            HookType::PostSwitch,
+                        ],
+                    )?;
                     // If declined, skip hooks but continue with removal
                     if !approved {
                         crate::output::print(info_message(
@@ -1527,6 +1992,20 @@ fn main() {
                     Ok(())
                 }
             }),
+        Commands::Merge {
+            yes,
+            continue_merge,
+            abort,
+            format,
+            ..
+        } if continue_merge || abort => {
+            start_report("merge", format);
+            if abort {
+                handle_merge_abort()
+            } else {
+                handle_merge_continue(yes)
+            }
+        }
         Commands::Merge {
             target,
             squash,
@@ -1541,6 +2020,15 @@ fn main() {
             no_verify,
             yes,
             stage,
+            dry_run,
+            require_ci,
+            override_ci,
+            gpg_sign,
+            no_gpg_sign,
+            autostash,
+            no_autostash,
+            format,
+            ..
         } => WorktrunkConfig::load()
             .context("Failed to load config")
             .and_then(|config| {
@@ -1560,6 +2048,8 @@ fn main() {
                 let rebase_default = merge_config.and_then(|m| m.rebase).unwrap_or(true);
                 let remove_default = merge_config.and_then(|m| m.remove).unwrap_or(true);
                 let verify_default = merge_config.and_then(|m| m.verify).unwrap_or(true);
+                let sign_default = merge_config.and_then(|m| m.sign).unwrap_or(false);
+                let autostash_default = merge_config.and_then(|m| m.autostash).unwrap_or(false);
 
                 // CLI flags override config, config overrides defaults
                 let squash_final = flag_pair(squash, no_squash).unwrap_or(squash_default);
@@ -1567,12 +2057,18 @@ fn main() {
                 let rebase_final = flag_pair(rebase, no_rebase).unwrap_or(rebase_default);
                 let remove_final = flag_pair(remove, no_remove).unwrap_or(remove_default);
                 let verify_final = flag_pair(verify, no_verify).unwrap_or(verify_default);
+                let sign_final = flag_pair(gpg_sign, no_gpg_sign).unwrap_or(sign_default);
+                let autostash_final =
+                    flag_pair(autostash, no_autostash).unwrap_or(autostash_default);
+                let require_ci_final = !override_ci
+                    && (require_ci || merge_config.and_then(|m| m.require_ci).unwrap_or(false));
 
                 // Stage defaults from [commit] config section
                 let stage_final = stage
                     .or_else(|| config.commit.and_then(|c| c.stage))
                     .unwrap_or_default();
 
+                start_report("merge", format);
                 handle_merge(MergeOptions {
                     target: target.as_deref(),
                     squash: squash_final,
@@ -1582,16 +2078,89 @@ fn main() {
                     verify: verify_final,
                     yes,
                     stage_mode: stage_final,
+                    dry_run,
+                    require_ci: require_ci_final,
+                    sign: sign_final,
+                    autostash: autostash_final,
                 })
             }),
+        Commands::Sync {
+            rebase_onto_upstream: true,
+            branch,
+            ..
+        } => handle_sync_upstream(branch.as_deref()),
+        Commands::Sync {
+            target, strategy, ..
+        } => handle_sync(target.as_deref(), strategy),
     };
 
+    // The `--format json` report (if requested) goes out after everything else
+    let result = result.and_then(|()| Ok(output::report::emit()?));
+
+    if let Some((label, after)) = notify_label
+        && started.elapsed() >= after
+    {
+        let body = match &result {
+            Ok(()) => format!("Finished after {}s", started.elapsed().as_secs()),
+            Err(e) => format!(
+                "Failed: {}",
+                plain_error_message(e).lines().next().unwrap_or_default()
+            ),
+        };
+        worktrunk::notify::send(&format!("wt {label}"), &body);
+    }
+
     if let Err(e) = result {
+        let message = plain_error_message(&e);
+        // Errors already shown along the way (e.g. removing several worktrees) are empty
+        let message = if message.is_empty() {
+            "Command failed"
+        } else {
+            &message
+        };
+        let _ = output::report::fail(message);
+
         // GitError, WorktrunkError, and HookErrorWithHint produce styled output via Display
-        if let Some(err) = e.downcast_ref::<worktrunk::git::GitError>() {
-            let _ = output::print(err.to_string());
+        // (a failed git command under added context falls through to show the context)
+        let git_error = e.downcast_ref::<worktrunk::git::GitError>().filter(|err| {
+            !matches!(err, worktrunk::git::GitError::CommandFailed { .. })
+                || e.chain().nth(1).is_none()
+        });
+        // Every git command fails the same way in a worktree that was removed or
+        // whose repository has moved; say which rather than showing git's
+        // "not a git repository"
+        let git_failed = e.chain().any(|err| {
+            matches!(
+                err.downcast_ref::<worktrunk::git::GitError>(),
+                Some(worktrunk::git::GitError::CommandFailed { .. })
+            )
+        });
+        let mut location_error = git_failed
+            .then(|| {
+                // A deleted working directory can't be read, but the shell knows it
+                worktrunk::git::working_dir()
+                    .ok()
+                    .or_else(|| std::env::var_os("PWD").map(PathBuf::from))
+            })
+            .flatten()
+            .and_then(|dir| worktrunk::git::diagnose_worktree(&dir));
+        if let Some(worktrunk::git::GitError::WorktreeDeleted {
+            primary: Some(primary),
+            changed_directory,
+            ..
+        }) = &mut location_error
+            && output::is_shell_integration_active()
+        {
+            *changed_directory = output::change_directory(&*primary).is_ok();
+        }
+        if let Some(err) = location_error.as_ref().or(git_error) {
+            let _ = output::print(err.styled());
         } else if let Some(err) = e.downcast_ref::<worktrunk::git::WorktrunkError>() {
-            let _ = output::print(err.to_string());
+            // AlreadyDisplayed renders as nothing; don't print a blank line for it
+            let msg = err.to_string();
+            if !msg.is_empty() {
+                let _ = output::print(msg);
+            }
         } else if let Some(err) = e.downcast_ref::<worktrunk::git::HookErrorWithHint>() {
             let _ = output::print(err.to_string());
         } else {
@@ -1626,12 +2195,18 @@ fn main() {
 
         // Preserve exit code from child processes (especially for signals like SIGINT)
         let code = exit_code(&e).unwrap_or(1);
+        worktrunk::op_log::record(
+            "exit",
+            serde_json::json!({ "ok": false, "exit_code": code, "error": message }),
+        );
 
         // Reset ANSI state before exiting
         let _ = output::terminate_output();
         process::exit(code);
     }
 
+    worktrunk::op_log::record("exit", serde_json::json!({ "ok": true, "exit_code": 0 }));
+
     // Reset ANSI state before returning to shell (success case)
     let _ = output::terminate_output();
 }
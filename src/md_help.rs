@@ -294,44 +294,28 @@ fn render_inline_formatting(line: &str) -> String {
 fn colorize_status_symbols(text: &str) -> String {
     use anstyle::{AnsiColor, Color as AnsiStyleColor, Style};
 
-    // Define semantic styles matching src/commands/list/model.rs StatusSymbols::styled_symbols
+    // CI indicator styles
     let error = Style::new().fg_color(Some(AnsiStyleColor::Ansi(AnsiColor::Red)));
     let warning = Style::new().fg_color(Some(AnsiStyleColor::Ansi(AnsiColor::Yellow)));
     let success = Style::new().fg_color(Some(AnsiStyleColor::Ansi(AnsiColor::Green)));
     let progress = Style::new().fg_color(Some(AnsiStyleColor::Ansi(AnsiColor::Blue)));
     let disabled = Style::new().fg_color(Some(AnsiStyleColor::Ansi(AnsiColor::BrightBlack)));
-    let working_tree = Style::new().fg_color(Some(AnsiStyleColor::Ansi(AnsiColor::Cyan)));
 
     // Pattern for dimmed text (from inline `code` rendering)
     // render_inline_formatting wraps backticked text in dimmed style
     let dim = Style::new().dimmed();
 
-    // Helper to create dimmed symbol pattern and its colored replacement
-    let replace_dim = |text: String, sym: &str, style: Style| -> String {
-        let dimmed = format!("{dim}{sym}{dim:#}");
-        let colored = format!("{style}{sym}{style:#}");
-        text.replace(&dimmed, &colored)
-    };
-
     let mut result = text.to_string();
 
-    // Working tree symbols: CYAN
-    result = replace_dim(result, "+", working_tree);
-    result = replace_dim(result, "!", working_tree);
-    result = replace_dim(result, "?", working_tree);
-
-    // Conflicts: ERROR (red)
-    result = replace_dim(result, "✘", error);
-
-    // Git operations, MergeTreeConflicts: WARNING (yellow)
-    result = replace_dim(result, "⤴", warning);
-    result = replace_dim(result, "⤵", warning);
-    result = replace_dim(result, "✗", warning);
-
-    // Worktree state: PathMismatch (red), Prunable/Locked (yellow)
-    result = replace_dim(result, "⚑", error);
-    result = replace_dim(result, "⊟", warning);
-    result = replace_dim(result, "⊞", warning);
+    // Status symbols: same colors as wt list, from the definitions it renders with.
+    // Symbols wt list dims or leaves plain stay as the backtick rendering left them.
+    for entry in crate::commands::list::model::status_legend() {
+        if entry.styled != entry.symbol
+            && entry.styled != color_print::cformat!("<dim>{}</>", entry.symbol)
+        {
+            result = result.replace(&format!("{dim}{}{dim:#}", entry.symbol), &entry.styled);
+        }
+    }
 
     // CI status circles: replace dimmed ● followed by color name
     let dimmed_bullet = format!("{dim}●{dim:#}");
@@ -0,0 +1,39 @@
+//! Desktop notifications when long operations finish (`[notify]` in user config).
+//!
+//! Uses `osascript` on macOS and `notify-send` elsewhere on Unix. With
+//! neither available (or on Windows), notifications are silently skipped:
+//! they're a convenience, so failing to send one never fails the command.
+
+use std::process::{Command, Stdio};
+
+use crate::shell_exec::run;
+
+/// A shell command that shows a notification, if this system can show one
+pub fn command(title: &str, body: &str) -> Option<String> {
+    let quote = |s: &str| shell_escape::escape(s.into()).into_owned();
+    if cfg!(target_os = "macos") {
+        // AppleScript string literals escape backslashes and double quotes
+        let literal = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+        let script = format!(
+            "display notification {} with title {}",
+            literal(body),
+            literal(title)
+        );
+        Some(format!("osascript -e {}", quote(&script)))
+    } else if cfg!(unix) && which::which("notify-send").is_ok() {
+        Some(format!("notify-send {} {}", quote(title), quote(body)))
+    } else {
+        None
+    }
+}
+
+/// Show a notification, waiting for the notifier to exit but ignoring failures
+pub fn send(title: &str, body: &str) {
+    let Some(command) = command(title, body) else {
+        return;
+    };
+    log::debug!("notify: {title}: {body}");
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", &command]).stdin(Stdio::null());
+    let _ = run(&mut cmd, None);
+}
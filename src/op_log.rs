@@ -0,0 +1,95 @@
+//! Machine-readable log of what worktrunk does (`--log-file`, `log-file`).
+//!
+//! Appends one JSON object per line: every external command run (with its
+//! duration and result), every hook command, and every change worktrunk makes
+//! — worktrees and branches created or removed, directory changes — bracketed
+//! by a `start` record with the arguments and an `exit` record. Each record has
+//! `ts` (RFC 3339, UTC), `pid`, and `event`; the rest depends on the event.
+//!
+//! ```text
+//! {"ts":"2025-01-02T10:00:00.000Z","pid":4242,"event":"start","args":["wt","remove"],"cwd":"/repo.feature"}
+//! {"ts":"2025-01-02T10:00:00.012Z","pid":4242,"event":"command","cmd":"git worktree remove /repo.feature","context":"repo","duration_ms":11.8,"ok":true}
+//! {"ts":"2025-01-02T10:00:00.020Z","pid":4242,"event":"worktree_removed","path":"/repo.feature"}
+//! ```
+//!
+//! The log is for auditing and bug reports, so failing to write it never fails
+//! the command.
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+use serde_json::Value;
+
+static LOG_FILE: OnceLock<Mutex<File>> = OnceLock::new();
+
+/// Start appending records to `path`, creating it (and its directory) if needed
+pub fn open(path: &Path) -> io::Result<()> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    // Opened once per process; a second call keeps the first file
+    let _ = LOG_FILE.set(Mutex::new(file));
+    Ok(())
+}
+
+/// Append an `event` record with `fields` (a JSON object) to the log, if one
+/// is open
+pub fn record(event: &str, fields: Value) {
+    let Some(file) = LOG_FILE.get() else {
+        return;
+    };
+    let line = format_record(
+        &chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        std::process::id(),
+        event,
+        fields,
+    );
+    // One write per record, so concurrent threads and processes don't interleave
+    let mut file = file.lock().expect("LOG_FILE lock poisoned");
+    if let Err(e) = file.write_all(line.as_bytes()) {
+        log::debug!("Failed to write log file: {e}");
+    }
+}
+
+/// One line of the log: `ts`, `pid`, and `event` first, then `fields`
+fn format_record(ts: &str, pid: u32, event: &str, fields: Value) -> String {
+    let mut line = format!(
+        "{{\"ts\":{},\"pid\":{pid},\"event\":{}",
+        Value::from(ts),
+        Value::from(event)
+    );
+    if let Value::Object(fields) = fields {
+        for (key, value) in fields {
+            line.push_str(&format!(",{}:{value}", Value::from(key)));
+        }
+    }
+    line.push_str("}\n");
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_record() {
+        let line = format_record(
+            "2025-01-02T10:00:00.000Z",
+            42,
+            "worktree_removed",
+            serde_json::json!({ "path": "/repo.feature" }),
+        );
+        assert_eq!(
+            line,
+            "{\"ts\":\"2025-01-02T10:00:00.000Z\",\"pid\":42,\"event\":\"worktree_removed\",\"path\":\"/repo.feature\"}\n"
+        );
+        let parsed: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed["event"], "worktree_removed");
+    }
+}
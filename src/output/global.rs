@@ -12,9 +12,15 @@
 //!
 //! # Shell Integration
 //!
-//! When `WORKTRUNK_DIRECTIVE_FILE` is set (by the shell wrapper), wt writes shell commands
-//! (like `cd '/path'`) to that file. The shell wrapper sources the file after wt exits.
-//! This allows the parent shell to change directory.
+//! When `WORKTRUNK_DIRECTIVE_FILE` is set (by the shell wrapper), wt writes directives
+//! (change directory, run a command) to that file, and the shell wrapper applies them
+//! after wt exits. This allows the parent shell to change directory.
+//!
+//! Wrappers that set `WORKTRUNK_DIRECTIVE_PROTOCOL=2` get structured records: a
+//! version header, then NUL-terminated `<action> <value>` records (`cd <path>`,
+//! `exec <command>`). Values are applied literally — a path is never parsed as
+//! shell code, so quotes, `$`, or newlines in it can't break out. Older wrappers
+//! don't set the variable and get protocol 1: shell code they source.
 //!
 //! # Trade-offs
 //!
@@ -32,11 +38,15 @@ use std::path::Path;
 use std::path::PathBuf;
 #[cfg(unix)]
 use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Mutex, OnceLock};
-use worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR;
 #[cfg(unix)]
 use worktrunk::shell_exec::ShellConfig;
-use worktrunk::styling::{eprintln, hint_message, stderr};
+use worktrunk::shell_exec::{DIRECTIVE_FILE_ENV_VAR, DIRECTIVE_HEADER, DIRECTIVE_PROTOCOL_ENV_VAR};
+use worktrunk::styling::{
+    ERROR_SYMBOL, HINT_SYMBOL, INFO_SYMBOL, PROGRESS_SYMBOL, SUCCESS_SYMBOL, WARNING_SYMBOL,
+    eprintln, hint_message, stderr,
+};
 
 /// Global output state, lazily initialized on first access.
 ///
@@ -54,10 +64,78 @@ struct OutputState {
     /// Path to the directive file (from WORKTRUNK_DIRECTIVE_FILE env var)
     /// If None, we're in interactive mode (no shell wrapper)
     directive_file: Option<PathBuf>,
+    /// How to write directives, from WORKTRUNK_DIRECTIVE_PROTOCOL
+    protocol: DirectiveProtocol,
     /// Buffered target directory for execute() in interactive mode
     target_dir: Option<PathBuf>,
 }
 
+/// Directive file format the shell wrapper understands
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum DirectiveProtocol {
+    /// Protocol 1: shell code the wrapper sources
+    #[default]
+    Shell,
+    /// Protocol 2: NUL-terminated records after [`DIRECTIVE_HEADER`]
+    Records,
+}
+
+impl DirectiveProtocol {
+    /// Protocol for the version a wrapper advertises; newer versions get the
+    /// newest this binary speaks, and the header tells the wrapper which that is
+    fn from_version(version: Option<&str>) -> Self {
+        match version.and_then(|version| version.trim().parse::<u32>().ok()) {
+            Some(version) if version >= 2 => Self::Records,
+            _ => Self::Shell,
+        }
+    }
+}
+
+/// Something for the shell wrapper to do after wt exits
+#[derive(Debug, Clone, Copy)]
+enum Directive<'a> {
+    Cd(&'a Path),
+    Exec(&'a str),
+}
+
+impl Directive<'_> {
+    /// Encode for the directive file. `first` adds the protocol 2 header.
+    fn encode(self, protocol: DirectiveProtocol, first: bool) -> String {
+        match protocol {
+            DirectiveProtocol::Shell => match self {
+                Self::Cd(path) => {
+                    let path_str = path.to_string_lossy();
+                    // Escape based on shell type. Both shell families use single-quoted strings
+                    // where contents are literal, but they escape embedded quotes differently:
+                    // - PowerShell: double the quote ('it''s')
+                    // - POSIX (bash/zsh/fish): end quote, escaped quote, start quote ('it'\''s')
+                    let is_powershell = std::env::var("WORKTRUNK_SHELL")
+                        .map(|v| v.eq_ignore_ascii_case("powershell"))
+                        .unwrap_or(false);
+                    let escaped = if is_powershell {
+                        path_str.replace('\'', "''")
+                    } else {
+                        path_str.replace('\'', "'\\''")
+                    };
+                    format!("cd '{escaped}'\n")
+                }
+                Self::Exec(command) => format!("{command}\n"),
+            },
+            DirectiveProtocol::Records => {
+                let record = match self {
+                    Self::Cd(path) => format!("cd {}\0", path.to_string_lossy()),
+                    Self::Exec(command) => format!("exec {command}\0"),
+                };
+                if first {
+                    format!("{DIRECTIVE_HEADER}\0{record}")
+                } else {
+                    record
+                }
+            }
+        }
+    }
+}
+
 /// Get or lazily initialize the global output state.
 ///
 /// Reads `WORKTRUNK_DIRECTIVE_FILE` from environment on first access.
@@ -69,13 +147,62 @@ fn get_state() -> &'static Mutex<OutputState> {
             .filter(|s| !s.trim().is_empty())
             .map(PathBuf::from);
 
+        let protocol = DirectiveProtocol::from_version(
+            std::env::var(DIRECTIVE_PROTOCOL_ENV_VAR).ok().as_deref(),
+        );
+
         Mutex::new(OutputState {
             directive_file,
+            protocol,
             target_dir: None,
         })
     })
 }
 
+/// Whether only warnings and errors are shown (`--quiet`)
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Whether the last message that started with a symbol was shown. Gutters,
+/// blank lines, and other unmarked output follow the message they belong to.
+static SHOWING: AtomicBool = AtomicBool::new(true);
+
+/// Show only warnings and errors (`--quiet`)
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether `--quiet` is set
+pub(super) fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Whether stderr is a terminal that understands escape sequences
+pub(super) fn stderr_is_terminal() -> bool {
+    use std::io::IsTerminal;
+
+    io::stderr().is_terminal() && std::env::var("TERM").map_or(true, |term| term != "dumb")
+}
+
+/// Whether a message should be shown, given `--quiet`
+fn visible(message: &str) -> bool {
+    if !QUIET.load(Ordering::Relaxed) {
+        return true;
+    }
+    // Some messages color the symbol along with the text, so compare unstyled
+    let message = ansi_str::AnsiStr::ansi_strip(message);
+    let starts_with = |symbols: &[worktrunk::styling::Symbol]| {
+        symbols
+            .iter()
+            .any(|symbol| message.starts_with(&*symbol.plain()))
+    };
+    if starts_with(&[ERROR_SYMBOL, WARNING_SYMBOL]) {
+        SHOWING.store(true, Ordering::Relaxed);
+    } else if starts_with(&[SUCCESS_SYMBOL, PROGRESS_SYMBOL, INFO_SYMBOL, HINT_SYMBOL]) {
+        SHOWING.store(false, Ordering::Relaxed);
+    }
+    SHOWING.load(Ordering::Relaxed)
+}
+
 /// Check if shell integration is active (directive file is set)
 fn has_directive_file() -> bool {
     get_state()
@@ -94,8 +221,7 @@ pub fn shell_integration_hint(message: impl Into<String>) -> io::Result<()> {
     if has_directive_file() {
         return Ok(());
     }
-    eprintln!("{}", hint_message(message.into()));
-    stderr().flush()
+    print(hint_message(message.into()))
 }
 
 /// Print a message to stderr (written as-is)
@@ -107,13 +233,23 @@ pub fn shell_integration_hint(message: impl Into<String>) -> io::Result<()> {
 /// output::print(success_message("Branch created"))?;
 /// output::print(hint_message("Use --force to override"))?;
 /// ```
+///
+/// With `--quiet`, only warnings and errors (and what follows them, like a
+/// gutter of details) are printed.
 pub fn print(message: impl Into<String>) -> io::Result<()> {
-    eprintln!("{}", message.into());
+    let message = message.into();
+    if !visible(&message) {
+        return Ok(());
+    }
+    eprintln!("{message}");
     stderr().flush()
 }
 
 /// Emit a blank line for visual separation
 pub fn blank() -> io::Result<()> {
+    if !visible("") {
+        return Ok(());
+    }
     eprintln!();
     stderr().flush()
 }
@@ -135,11 +271,11 @@ pub fn stdout(content: impl Into<String>) -> io::Result<()> {
 }
 
 /// Write a directive to the directive file (if set)
-fn write_directive(directive: &str) -> io::Result<()> {
+fn write_directive(directive: Directive<'_>) -> io::Result<()> {
     // Copy path out of lock to avoid holding mutex during I/O
-    let path = {
+    let (path, protocol) = {
         let guard = get_state().lock().expect("OUTPUT_STATE lock poisoned");
-        guard.directive_file.clone()
+        (guard.directive_file.clone(), guard.protocol)
     };
 
     let Some(path) = path else {
@@ -147,13 +283,14 @@ fn write_directive(directive: &str) -> io::Result<()> {
     };
 
     let mut file = OpenOptions::new().append(true).open(&path)?;
-    writeln!(file, "{}", directive)?;
+    let first = file.metadata()?.len() == 0;
+    file.write_all(directive.encode(protocol, first).as_bytes())?;
     file.flush()
 }
 
 /// Request directory change (for shell integration)
 ///
-/// If shell integration is active (WORKTRUNK_DIRECTIVE_FILE set), writes `cd` command to the file.
+/// If shell integration is active (WORKTRUNK_DIRECTIVE_FILE set), writes a `cd` directive to the file.
 /// Also stores path for execute() to use as working directory.
 pub fn change_directory(path: impl AsRef<Path>) -> io::Result<()> {
     let path = path.as_ref();
@@ -161,30 +298,62 @@ pub fn change_directory(path: impl AsRef<Path>) -> io::Result<()> {
 
     // Store for execute() to use
     guard.target_dir = Some(path.to_path_buf());
+    super::report::record(|report| report.path = Some(path.to_path_buf()));
+    worktrunk::op_log::record("cd", serde_json::json!({ "path": path }));
 
     // Write to directive file if set
     if guard.directive_file.is_some() {
         drop(guard); // Release lock before I/O
 
-        let path_str = path.to_string_lossy();
-        // Escape based on shell type. Both shell families use single-quoted strings
-        // where contents are literal, but they escape embedded quotes differently:
-        // - PowerShell: double the quote ('it''s')
-        // - POSIX (bash/zsh/fish): end quote, escaped quote, start quote ('it'\''s')
-        let is_powershell = std::env::var("WORKTRUNK_SHELL")
-            .map(|v| v.eq_ignore_ascii_case("powershell"))
-            .unwrap_or(false);
-        let escaped = if is_powershell {
-            path_str.replace('\'', "''")
-        } else {
-            path_str.replace('\'', "'\\''")
-        };
-        write_directive(&format!("cd '{}'", escaped))?;
+        write_directive(Directive::Cd(path))?;
+        report_directory(path)?;
     }
 
     Ok(())
 }
 
+/// Tell the terminal about the directory the shell is about to change to.
+///
+/// Terminals and multiplexers that track the working directory (to open new
+/// tabs and panes there) learn it from OSC 7. Many shells only send it from
+/// their prompt hook, if at all; sending it here keeps them in step with the
+/// wrapper's `cd`. Goes to stderr, which stays attached to the terminal while
+/// stdout may be piped.
+fn report_directory(path: &Path) -> io::Result<()> {
+    if !stderr_is_terminal() {
+        return Ok(());
+    }
+    #[cfg(unix)]
+    let host = nix::unistd::gethostname()
+        .map(|host| host.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    #[cfg(not(unix))]
+    let host = String::new();
+    let mut stderr = io::stderr();
+    write!(stderr, "{}", osc7_sequence(&host, path))?;
+    stderr.flush()
+}
+
+/// The OSC 7 sequence reporting `path` as the current directory on `host`
+fn osc7_sequence(host: &str, path: &Path) -> String {
+    let path = path.to_string_lossy();
+    #[cfg(windows)]
+    let path = path.replace('\\', "/");
+    let mut encoded = String::new();
+    if !path.starts_with('/') {
+        // Windows drive paths: file:///C:/...
+        encoded.push('/');
+    }
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~/:".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    format!("\x1b]7;file://{host}{encoded}\x1b\\")
+}
+
 /// Request command execution
 ///
 /// In interactive mode (no directive file), executes the command directly (replacing process on Unix).
@@ -199,7 +368,7 @@ pub fn execute(command: impl Into<String>) -> anyhow::Result<()> {
 
     if has_directive {
         // Write to directive file
-        write_directive(&command)?;
+        write_directive(Directive::Exec(&command))?;
         Ok(())
     } else {
         // Execute directly
@@ -243,7 +412,7 @@ fn execute_command(command: String, target_dir: Option<&Path>) -> anyhow::Result
     // On non-Unix platforms, fall back to spawn-and-wait.
     // This uses the shell abstraction (Git Bash if available).
     let exec_dir = target_dir.unwrap_or_else(|| Path::new("."));
-    if let Err(err) = execute_streaming(&command, exec_dir, false, None, true, false) {
+    if let Err(err) = execute_streaming(&command, exec_dir, false, None, true, false, &[], None) {
         // If the command failed with an exit code, just exit with that code.
         // This matches Unix behavior where exec() replaces the process and
         // the shell's exit code becomes the process exit code (no error message).
@@ -292,7 +461,19 @@ pub fn is_shell_integration_active() -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::path::PathBuf;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_osc7_sequence() {
+        assert_eq!(
+            osc7_sequence("host", Path::new("/home/user/my repo.feature")),
+            "\x1b]7;file://host/home/user/my%20repo.feature\x1b\\"
+        );
+        assert_eq!(
+            osc7_sequence("", Path::new("/tmp/caf\u{e9}")),
+            "\x1b]7;file:///tmp/caf%C3%A9\x1b\\"
+        );
+    }
 
     #[test]
     fn test_lazy_init_does_not_panic() {
@@ -324,31 +505,71 @@ mod tests {
     #[test]
     fn test_shell_script_format() {
         // Test that POSIX quoting produces correct output
-        let path = PathBuf::from("/test/path");
-        let path_str = path.to_string_lossy();
-        let escaped = path_str.replace('\'', "'\\''");
-        let cd_cmd = format!("cd '{}'", escaped);
-        assert_eq!(cd_cmd, "cd '/test/path'");
+        let cd = Directive::Cd(Path::new("/test/path"));
+        assert_eq!(
+            cd.encode(DirectiveProtocol::Shell, true),
+            "cd '/test/path'\n"
+        );
     }
 
     #[test]
     fn test_path_with_single_quotes() {
         // Paths with single quotes need escaping: ' -> '\''
-        let path = PathBuf::from("/test/it's/path");
-        let path_str = path.to_string_lossy();
-        let escaped = path_str.replace('\'', "'\\''");
-        let cd_cmd = format!("cd '{}'", escaped);
-        assert_eq!(cd_cmd, "cd '/test/it'\\''s/path'");
+        let cd = Directive::Cd(Path::new("/test/it's/path"));
+        assert_eq!(
+            cd.encode(DirectiveProtocol::Shell, true),
+            "cd '/test/it'\\''s/path'\n"
+        );
     }
 
     #[test]
     fn test_path_with_spaces() {
         // Paths with spaces are safely quoted
-        let path = PathBuf::from("/test/my path/here");
-        let path_str = path.to_string_lossy();
-        let escaped = path_str.replace('\'', "'\\''");
-        let cd_cmd = format!("cd '{}'", escaped);
-        assert_eq!(cd_cmd, "cd '/test/my path/here'");
+        let cd = Directive::Cd(Path::new("/test/my path/here"));
+        assert_eq!(
+            cd.encode(DirectiveProtocol::Shell, true),
+            "cd '/test/my path/here'\n"
+        );
+    }
+
+    #[test]
+    fn test_records_protocol() {
+        // Values go in literally; only the first record carries the header
+        let cd = Directive::Cd(Path::new("/test/it's $HOME/path"));
+        assert_eq!(
+            cd.encode(DirectiveProtocol::Records, true),
+            "worktrunk-directives 2\0cd /test/it's $HOME/path\0"
+        );
+        let exec = Directive::Exec("echo one\necho two");
+        assert_eq!(
+            exec.encode(DirectiveProtocol::Records, false),
+            "exec echo one\necho two\0"
+        );
+    }
+
+    #[test]
+    fn test_protocol_from_version() {
+        assert_eq!(
+            DirectiveProtocol::from_version(None),
+            DirectiveProtocol::Shell
+        );
+        assert_eq!(
+            DirectiveProtocol::from_version(Some("1")),
+            DirectiveProtocol::Shell
+        );
+        assert_eq!(
+            DirectiveProtocol::from_version(Some("2")),
+            DirectiveProtocol::Records
+        );
+        // A newer wrapper gets the newest protocol this binary speaks
+        assert_eq!(
+            DirectiveProtocol::from_version(Some("3")),
+            DirectiveProtocol::Records
+        );
+        assert_eq!(
+            DirectiveProtocol::from_version(Some("x")),
+            DirectiveProtocol::Shell
+        );
     }
 
     /// Test that anstyle formatting is preserved
@@ -5,6 +5,7 @@ use std::path::Path;
 
 use crate::commands::command_executor::CommandContext;
 use crate::commands::execute_pre_remove_commands;
+use crate::commands::list::model::DETACHED_SYMBOL;
 use crate::commands::process::spawn_detached;
 use crate::commands::worktree::{BranchDeletionMode, RemoveResult, SwitchBranchInfo, SwitchResult};
 use worktrunk::config::WorktrunkConfig;
@@ -15,8 +16,9 @@ use worktrunk::git::path_dir_name;
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell::Shell;
 use worktrunk::styling::{
-    FormattedMessage, error_message, format_with_gutter, hint_message, info_message,
-    progress_message, success_message, suggest_command, warning_message,
+    FormattedMessage, PROGRESS_SYMBOL, SUCCESS_SYMBOL, error_message, format_with_gutter,
+    hint_message, info_message, progress_message, success_message, suggest_command,
+    warning_message,
 };
 
 /// Format a switch message with a consistent location phrase
@@ -291,15 +293,38 @@ fn shell_integration_hint() -> String {
 }
 
 /// Show switch message when changing directory after worktree removal
+///
+/// Without shell integration the shell stays in the removed directory, so say
+/// where to go instead of claiming to have switched.
 fn print_switch_message_if_changed(
     changed_directory: bool,
     main_path: &Path,
 ) -> anyhow::Result<()> {
-    if changed_directory && let Ok(Some(dest_branch)) = Repository::at(main_path).current_branch() {
-        let path_display = format_path_for_display(main_path);
-        super::print(info_message(cformat!(
-            "Switched to worktree for <bold>{dest_branch}</> @ <bold>{path_display}</>"
+    if !changed_directory {
+        return Ok(());
+    }
+    let path_display = format_path_for_display(main_path);
+    let destination = match Repository::at(main_path).current_branch() {
+        Ok(Some(dest_branch)) => {
+            cformat!("worktree for <bold>{dest_branch}</> @ <bold>{path_display}</>")
+        }
+        _ => cformat!("<bold>{path_display}</>"),
+    };
+    if super::is_shell_integration_active() {
+        super::print(info_message(format!("Switched to {destination}")))?;
+    } else if Shell::is_integration_configured(&crate::binary_name())
+        .ok()
+        .flatten()
+        .is_some()
+    {
+        super::print(warning_message(format!(
+            "Current directory removed; cannot change to {destination} — restart the shell to activate"
+        )))?;
+    } else {
+        super::print(warning_message(format!(
+            "Current directory removed; cannot change to {destination} — shell integration not installed"
         )))?;
+        super::shell_integration_hint(shell_integration_hint())?;
     }
     Ok(())
 }
@@ -318,7 +343,12 @@ pub fn handle_switch_output(
 
     let path = result.path();
     let path_display = format_path_for_display(path);
-    let branch = branch_info.branch();
+    // Detached worktrees are named by their HEAD, as in `wt list`
+    let detached_label = branch_info
+        .detached_head
+        .as_ref()
+        .map(|sha| format!("{DETACHED_SYMBOL} {sha}"));
+    let branch = detached_label.as_deref().unwrap_or(branch_info.branch());
 
     // Check if shell integration is active (directive file set)
     let is_shell_integration_active = super::is_shell_integration_active();
@@ -390,6 +420,13 @@ pub fn handle_switch_output(
         }
     }
 
+    // Nothing to commit onto yet; point at how to start a branch from here
+    if let Some(sha) = &branch_info.detached_head {
+        super::print(hint_message(cformat!(
+            "HEAD is detached at <bold>{sha}</>; to create a branch here, run <bright-black>git switch -c NAME</>"
+        )))?;
+    }
+
     super::flush()?;
     Ok(())
 }
@@ -415,12 +452,17 @@ pub fn execute_user_command(command: &str) -> anyhow::Result<()> {
 ///
 /// `force_worktree` adds `--force` to `git worktree remove`, allowing removal
 /// even when the worktree contains untracked files (like build artifacts).
+///
+/// `then` runs only once the removal has succeeded (it journals the removal).
 fn build_remove_command(
     worktree_path: &std::path::Path,
     branch_to_delete: Option<&str>,
     force_worktree: bool,
+    then: Option<&str>,
 ) -> String {
-    use shell_escape::escape;
+    // Runs via `sh` (Git Bash on Windows), so quote for POSIX even where
+    // `shell_escape::escape` would pick cmd.exe rules
+    use shell_escape::unix::escape;
 
     let worktree_path_str = worktree_path.to_string_lossy();
     let worktree_escaped = escape(worktree_path_str.as_ref().into());
@@ -444,7 +486,7 @@ fn build_remove_command(
 
     let force_flag = if force_worktree { " --force" } else { "" };
 
-    match branch_to_delete {
+    let command = match branch_to_delete {
         Some(branch_name) => {
             let branch_escaped = escape(branch_name.into());
             format!(
@@ -458,6 +500,10 @@ fn build_remove_command(
                 delay, stop_fsmonitor, force_flag, worktree_escaped
             )
         }
+    };
+    match then {
+        Some(then) => format!("{command} && {then}"),
+        None => command,
     }
 }
 
@@ -499,6 +545,73 @@ pub fn handle_remove_output(
 }
 
 /// Handle output for BranchOnly removal (branch exists but no worktree)
+/// Add a removed worktree and deleted branch to the `--format json` report
+/// and the log file
+fn report_removal(worktree_path: Option<&Path>, deleted_branch: Option<&str>) {
+    if let Some(path) = worktree_path {
+        worktrunk::op_log::record("worktree_removed", serde_json::json!({ "path": path }));
+    }
+    if let Some(branch) = deleted_branch {
+        worktrunk::op_log::record("branch_deleted", serde_json::json!({ "branch": branch }));
+    }
+    super::report::record(|report| {
+        report
+            .removed_worktrees
+            .extend(worktree_path.map(Path::to_path_buf));
+        report
+            .deleted_refs
+            .extend(deleted_branch.map(|branch| format!("refs/heads/{branch}")));
+    });
+}
+
+/// Record a worktree removal in the journal, with the backup `wt undo`
+/// restores it from
+fn journal_removal(
+    repo: &Repository,
+    worktree_path: &Path,
+    branch: Option<&str>,
+    branch_deleted: bool,
+    backup: Option<worktrunk::journal::WorktreeBackup>,
+) {
+    worktrunk::journal::record(
+        repo,
+        removal_action(worktree_path, branch, branch_deleted, backup),
+    );
+}
+
+/// The command a background removal runs to journal itself once it's done
+fn journal_removal_command(
+    repo: &Repository,
+    worktree_path: &Path,
+    branch: Option<&str>,
+    branch_deleted: bool,
+    backup: Option<worktrunk::journal::WorktreeBackup>,
+) -> Option<String> {
+    let action = removal_action(worktree_path, branch, branch_deleted, backup);
+    worktrunk::journal::record_command(repo, action)
+        .map_err(|e| log::debug!("Failed to journal {}: {e:#}", worktree_path.display()))
+        .ok()
+}
+
+fn removal_action(
+    worktree_path: &Path,
+    branch: Option<&str>,
+    branch_deleted: bool,
+    backup: Option<worktrunk::journal::WorktreeBackup>,
+) -> worktrunk::journal::Action {
+    let (sha, bundle) = match backup {
+        Some(backup) => (Some(backup.sha), backup.bundle),
+        None => (None, None),
+    };
+    worktrunk::journal::Action::WorktreeRemoved {
+        path: worktree_path.to_path_buf(),
+        branch: branch.map(String::from),
+        sha,
+        branch_deleted,
+        bundle,
+    }
+}
+
 fn handle_branch_only_output(
     branch_name: &str,
     deletion_mode: BranchDeletionMode,
@@ -522,10 +635,24 @@ fn handle_branch_only_output(
     let default_branch = repo.default_branch().ok();
     let check_target = default_branch.as_deref().unwrap_or("HEAD");
 
+    // Recorded so `wt undo` can recreate the branch
+    let sha = repo
+        .run_command(&["rev-parse", &format!("refs/heads/{branch_name}")])
+        .map(|sha| sha.trim().to_string());
     let result = delete_branch_if_safe(&repo, branch_name, check_target, deletion_mode.is_force());
     let (deletion, _) = handle_branch_deletion_result(result, branch_name, false)?;
 
     if !matches!(deletion.outcome, BranchDeletionOutcome::NotDeleted) {
+        report_removal(None, Some(branch_name));
+        if let Ok(sha) = sha {
+            worktrunk::journal::record(
+                &repo,
+                worktrunk::journal::Action::BranchDeleted {
+                    branch: branch_name.to_string(),
+                    sha,
+                },
+            );
+        }
         let flag_note = get_flag_note(
             deletion_mode,
             &deletion.outcome,
@@ -534,7 +661,8 @@ fn handle_branch_only_output(
         let flag_text = &flag_note.text;
         let flag_after = flag_note.after_green();
         super::print(FormattedMessage::new(cformat!(
-            "<green>✓ Removed branch <bold>{branch_name}</>{flag_text}</>{flag_after}"
+            "<green>{} Removed branch <bold>{branch_name}</>{flag_text}</>{flag_after}",
+            SUCCESS_SYMBOL.plain()
         )))?;
     }
 
@@ -542,24 +670,40 @@ fn handle_branch_only_output(
     Ok(())
 }
 
-/// Spawn post-switch hooks in the destination worktree after a directory change.
+/// Spawn post-remove hooks, then post-switch hooks after a directory change.
 ///
-/// Called when removing a worktree causes a cd to the main worktree.
-/// Only runs if `verify` is true (hooks approved) and `changed_directory` is true.
-fn spawn_post_switch_after_remove(
+/// Both run in the destination worktree. Post-remove runs for every removal,
+/// with `{{ branch }}` naming the removed branch; post-switch runs only when
+/// removing a worktree causes a cd to the main worktree.
+/// Only runs if `verify` is true (hooks approved).
+fn spawn_hooks_after_remove(
     main_path: &std::path::Path,
+    removed_branch: Option<&str>,
     verify: bool,
     changed_directory: bool,
 ) -> anyhow::Result<()> {
-    if !verify || !changed_directory {
+    if !verify {
         return Ok(());
     }
     let Ok(config) = WorktrunkConfig::load() else {
         return Ok(());
     };
     let dest_repo = Repository::at(main_path);
-    let dest_branch = dest_repo.current_branch()?;
     let repo_root = dest_repo.worktree_base()?;
+    CommandContext::new(
+        &dest_repo,
+        &config,
+        removed_branch,
+        main_path,
+        &repo_root,
+        false, // force=false for CommandContext
+    )
+    .spawn_post_remove_commands()?;
+
+    if !changed_directory {
+        return Ok(());
+    }
+    let dest_branch = dest_repo.current_branch()?;
     let ctx = CommandContext::new(
         &dest_repo,
         &config,
@@ -609,6 +753,12 @@ fn handle_removed_worktree_output(
         execute_pre_remove_commands(&ctx, None)?;
     }
 
+    // Taken before anything is removed, for `wt undo`
+    let backup =
+        worktrunk::journal::backup_worktree(&repo, worktree_path, branch_name, target_branch)
+            .map_err(|e| log::debug!("Failed to back up {}: {e:#}", worktree_path.display()))
+            .ok();
+
     // Handle detached HEAD case (no branch known)
     let Some(branch_name) = branch_name else {
         // No branch associated - just remove the worktree
@@ -616,7 +766,9 @@ fn handle_removed_worktree_output(
             super::print(progress_message(
                 "Removing worktree in background (detached HEAD, no branch to delete)",
             ))?;
-            let remove_command = build_remove_command(worktree_path, None, force_worktree);
+            let journal = journal_removal_command(&repo, worktree_path, None, false, backup);
+            let remove_command =
+                build_remove_command(worktree_path, None, force_worktree, journal.as_deref());
             spawn_detached(
                 &repo,
                 main_path,
@@ -624,6 +776,7 @@ fn handle_removed_worktree_output(
                 "detached",
                 "remove",
                 None,
+                &[],
             )?;
         } else {
             let target_repo = worktrunk::git::Repository::at(worktree_path);
@@ -639,8 +792,10 @@ fn handle_removed_worktree_output(
             super::print(success_message(
                 "Removed worktree (detached HEAD, no branch to delete)",
             ))?;
+            journal_removal(&repo, worktree_path, None, false, backup);
         }
-        spawn_post_switch_after_remove(main_path, verify, changed_directory)?;
+        report_removal(Some(worktree_path), None);
+        spawn_hooks_after_remove(main_path, None, verify, changed_directory)?;
         super::flush()?;
         return Ok(());
     };
@@ -690,11 +845,15 @@ fn handle_removed_worktree_output(
         let action = if should_delete_branch {
             // Branch will be deleted (integrated or force-deleted)
             cformat!(
-                "<cyan>◎ Removing <bold>{branch_name}</> worktree & branch in background{flag_text}</>{flag_after}"
+                "<cyan>{} Removing <bold>{branch_name}</> worktree & branch in background{flag_text}</>{flag_after}",
+                PROGRESS_SYMBOL.plain()
             )
         } else {
             // Branch kept: hint will explain why (integrated+flag, unmerged, or unmerged+flag)
-            cformat!("<cyan>◎ Removing <bold>{branch_name}</> worktree in background</>")
+            cformat!(
+                "<cyan>{} Removing <bold>{branch_name}</> worktree in background</>",
+                PROGRESS_SYMBOL.plain()
+            )
         };
         super::print(FormattedMessage::new(action))?;
 
@@ -722,10 +881,18 @@ fn handle_removed_worktree_output(
         print_switch_message_if_changed(changed_directory, main_path)?;
 
         // Build command with the decision we already made
+        let journal = journal_removal_command(
+            &repo,
+            worktree_path,
+            Some(branch_name),
+            should_delete_branch,
+            backup,
+        );
         let remove_command = build_remove_command(
             worktree_path,
             should_delete_branch.then_some(branch_name),
             force_worktree,
+            journal.as_deref(),
         );
 
         // Spawn the removal in background - runs from main_path (where we cd'd to)
@@ -736,9 +903,14 @@ fn handle_removed_worktree_output(
             branch_name,
             "remove",
             None,
+            &[],
         )?;
+        report_removal(
+            Some(worktree_path),
+            should_delete_branch.then_some(branch_name),
+        );
 
-        spawn_post_switch_after_remove(main_path, verify, changed_directory)?;
+        spawn_hooks_after_remove(main_path, Some(branch_name), verify, changed_directory)?;
         super::flush()?;
         Ok(())
     } else {
@@ -788,6 +960,14 @@ fn handle_removed_worktree_output(
             outcome,
             BranchDeletionOutcome::ForceDeleted | BranchDeletionOutcome::Integrated(_)
         );
+        report_removal(Some(worktree_path), branch_deleted.then_some(branch_name));
+        journal_removal(
+            &repo,
+            worktree_path,
+            Some(branch_name),
+            branch_deleted,
+            backup,
+        );
         // Message structure parallel to background mode:
         // - Branch deleted (integrated/force): "worktree & branch (reason)"
         // - Branch kept (any reason): "worktree" + hint (if relevant)
@@ -796,11 +976,15 @@ fn handle_removed_worktree_output(
             let flag_text = &flag_note.text;
             let flag_after = flag_note.after_green();
             cformat!(
-                "<green>✓ Removed <bold>{branch_name}</> worktree & branch{flag_text}</>{flag_after}"
+                "<green>{} Removed <bold>{branch_name}</> worktree & branch{flag_text}</>{flag_after}",
+                SUCCESS_SYMBOL.plain()
             )
         } else {
             // Branch kept: hint will explain why (integrated+flag, unmerged, or unmerged+flag)
-            cformat!("<green>✓ Removed <bold>{branch_name}</> worktree</>")
+            cformat!(
+                "<green>{} Removed <bold>{branch_name}</> worktree</>",
+                SUCCESS_SYMBOL.plain()
+            )
         };
         super::print(FormattedMessage::new(msg))?;
 
@@ -827,7 +1011,7 @@ fn handle_removed_worktree_output(
 
         print_switch_message_if_changed(changed_directory, main_path)?;
 
-        spawn_post_switch_after_remove(main_path, verify, changed_directory)?;
+        spawn_hooks_after_remove(main_path, Some(branch_name), verify, changed_directory)?;
         super::flush()?;
         Ok(())
     }
@@ -909,6 +1093,13 @@ fn forward_signal_with_escalation(pgid: i32, sig: i32) {
 /// the entire command tree without shell-wrapping. If the process group does not exit
 /// promptly, we escalate to SIGTERM/SIGKILL (SIGINT path) or SIGKILL (SIGTERM path).
 /// We still return exit code 128 + signal number (e.g., 130 for SIGINT) to match Unix conventions.
+///
+/// ## Timeouts
+///
+/// With a `timeout`, a command still running when it expires is stopped (on Unix, its whole
+/// process group with SIGTERM, escalating to SIGKILL) and exit code 124 is returned, as
+/// `timeout(1)` does.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn execute_streaming(
     command: &str,
     working_dir: &std::path::Path,
@@ -916,6 +1107,8 @@ pub(crate) fn execute_streaming(
     stdin_content: Option<&str>,
     inherit_stdin: bool,
     forward_signals: bool,
+    env: &[(&str, String)],
+    timeout: Option<std::time::Duration>,
 ) -> anyhow::Result<()> {
     use std::io::Write;
     use worktrunk::git::WorktrunkError;
@@ -950,6 +1143,11 @@ pub(crate) fn execute_streaming(
         std::process::Stdio::null()
     };
 
+    // The process group is what a timeout stops, so isolate the child when there is one
+    #[cfg(unix)]
+    let forward_signals = forward_signals || timeout.is_some();
+    let deadline = timeout.map(|timeout| std::time::Instant::now() + timeout);
+
     #[cfg(unix)]
     let mut signals = if forward_signals {
         Some(Signals::new([SIGINT, SIGTERM])?)
@@ -973,6 +1171,7 @@ pub(crate) fn execute_streaming(
         .env_remove("VERGEN_GIT_DESCRIBE")
         // Prevent hooks from writing to the directive file
         .env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR)
+        .envs(env.iter().map(|(name, value)| (name, value)))
         .spawn()
         .map_err(|e| {
             anyhow::Error::from(worktrunk::git::GitError::Other {
@@ -1013,6 +1212,13 @@ pub(crate) fn execute_streaming(
                     }
                 }
             }
+            if let (Some(deadline), Some(timeout)) = (deadline, timeout)
+                && std::time::Instant::now() >= deadline
+            {
+                forward_signal_with_escalation(child_pgid, SIGTERM);
+                let _ = child.wait();
+                return Err(timed_out(timeout));
+            }
             std::thread::sleep(std::time::Duration::from_millis(10));
         }
     } else {
@@ -1025,11 +1231,24 @@ pub(crate) fn execute_streaming(
     };
 
     #[cfg(not(unix))]
-    let status = child.wait().map_err(|e| {
-        anyhow::Error::from(worktrunk::git::GitError::Other {
-            message: format!("Failed to wait for command: {}", e),
-        })
-    })?;
+    let status = loop {
+        let status = child.try_wait().map_err(|e| {
+            anyhow::Error::from(worktrunk::git::GitError::Other {
+                message: format!("Failed to wait for command: {}", e),
+            })
+        })?;
+        if let Some(status) = status {
+            break status;
+        }
+        if let (Some(deadline), Some(timeout)) = (deadline, timeout)
+            && std::time::Instant::now() >= deadline
+        {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(timed_out(timeout));
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    };
 
     #[cfg(unix)]
     if let Some(sig) = seen_signal {
@@ -1040,6 +1259,25 @@ pub(crate) fn execute_streaming(
         .into());
     }
 
+    exit_status_result(status)
+}
+
+/// The error for a command stopped by its timeout, with `timeout(1)`'s exit code
+fn timed_out(timeout: std::time::Duration) -> anyhow::Error {
+    worktrunk::git::WorktrunkError::ChildProcessExited {
+        code: 124,
+        message: format!(
+            "timed out after {}",
+            worktrunk::config::format_duration(timeout)
+        ),
+    }
+    .into()
+}
+
+/// Turn a child's exit status into `ChildProcessExited` unless it succeeded
+fn exit_status_result(status: std::process::ExitStatus) -> anyhow::Result<()> {
+    use worktrunk::git::WorktrunkError;
+
     // Check if child was killed by a signal (Unix only)
     // This handles Ctrl-C: when SIGINT is sent, the child receives it and terminates,
     // and we propagate the signal exit code (128 + signal number, e.g., 130 for SIGINT)
@@ -1092,6 +1330,8 @@ pub fn execute_command_in_worktree(
     worktree_path: &std::path::Path,
     command: &str,
     stdin_content: Option<&str>,
+    env: &[(&str, String)],
+    timeout: Option<std::time::Duration>,
 ) -> anyhow::Result<()> {
     use std::io::Write;
     use worktrunk::styling::{eprint, stderr};
@@ -1108,7 +1348,16 @@ pub fn execute_command_in_worktree(
 
     // Execute with stdout→stderr redirect for deterministic ordering
     // Hooks don't need stdin inheritance (inherit_stdin=false)
-    execute_streaming(command, worktree_path, true, stdin_content, false, true)?;
+    execute_streaming(
+        command,
+        worktree_path,
+        true,
+        stdin_content,
+        false,
+        true,
+        env,
+        timeout,
+    )?;
 
     // Flush to ensure all output appears before we continue
     super::flush()?;
@@ -1116,6 +1365,205 @@ pub fn execute_command_in_worktree(
     Ok(())
 }
 
+/// A command for [`execute_commands_in_worktree_parallel`]
+pub struct LabeledCommand<'a> {
+    /// Shown before each line of the command's output, e.g. `user:lint`
+    pub label: String,
+    pub command: &'a str,
+    pub stdin_content: Option<&'a str>,
+    pub env: &'a [(&'static str, String)],
+    pub timeout: Option<std::time::Duration>,
+}
+
+/// A spawned command of [`execute_commands_in_worktree_parallel`]
+enum ParallelChild {
+    Running {
+        child: std::process::Child,
+        deadline: Option<std::time::Instant>,
+    },
+    Done(anyhow::Result<()>),
+}
+
+/// Execute several commands in a worktree directory at once
+///
+/// Each command's stdout and stderr are read line by line and printed to
+/// stderr as they arrive, prefixed with the command's label (see
+/// [`format_with_label`](worktrunk::styling::format_with_label)). Every
+/// command runs to completion or until its timeout; the results are returned
+/// in the order the commands were given.
+///
+/// Children stay in our process group so Ctrl-C reaches all of them, except
+/// those with a timeout, which get their own group so it can be stopped whole.
+pub fn execute_commands_in_worktree_parallel(
+    worktree_path: &std::path::Path,
+    commands: &[LabeledCommand<'_>],
+) -> anyhow::Result<Vec<anyhow::Result<()>>> {
+    use std::io::{BufRead, Write};
+    use std::process::Stdio;
+    use std::sync::mpsc;
+    use std::time::{Duration, Instant};
+    use worktrunk::shell_exec::ShellConfig;
+    use worktrunk::styling::{eprint, format_with_label, stderr};
+
+    super::flush()?;
+    eprint!("{}", anstyle::Reset);
+    stderr().flush().ok();
+
+    let shell = ShellConfig::get();
+    let label_width = commands
+        .iter()
+        .map(|cmd| cmd.label.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let (sender, receiver) = mpsc::channel::<(usize, String)>();
+    let mut children = Vec::with_capacity(commands.len());
+
+    for (index, cmd) in commands.iter().enumerate() {
+        let mut process = shell.command(cmd.command);
+        #[cfg(unix)]
+        if cmd.timeout.is_some() {
+            use std::os::unix::process::CommandExt;
+            process.process_group(0);
+        }
+        let spawned = process
+            .current_dir(worktree_path)
+            .stdin(if cmd.stdin_content.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .env_remove("VERGEN_GIT_DESCRIBE")
+            .env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR)
+            .envs(cmd.env.iter().map(|(name, value)| (name, value)))
+            .spawn();
+        let mut child = match spawned {
+            Ok(child) => child,
+            Err(e) => {
+                children.push(ParallelChild::Done(Err(anyhow::Error::from(
+                    GitError::Other {
+                        message: format!("Failed to execute command with {}: {}", shell.name, e),
+                    },
+                ))));
+                continue;
+            }
+        };
+
+        // Hooks that don't read stdin still work, so write errors are ignored
+        if let Some(content) = cmd.stdin_content
+            && let Some(mut stdin) = child.stdin.take()
+        {
+            let _ = stdin.write_all(content.as_bytes());
+        }
+
+        let stdout = child
+            .stdout
+            .take()
+            .map(|s| Box::new(s) as Box<dyn std::io::Read + Send>);
+        let stderr = child
+            .stderr
+            .take()
+            .map(|s| Box::new(s) as Box<dyn std::io::Read + Send>);
+        for stream in [stdout, stderr].into_iter().flatten() {
+            let sender = sender.clone();
+            // Not joined: a process the command left running may hold the stream open
+            std::thread::spawn(move || {
+                let mut reader = std::io::BufReader::new(stream);
+                let mut buf = Vec::new();
+                while matches!(reader.read_until(b'\n', &mut buf), Ok(n) if n > 0) {
+                    let line = String::from_utf8_lossy(&buf);
+                    let line = line.trim_end_matches(['\n', '\r']).to_string();
+                    if sender.send((index, line)).is_err() {
+                        break;
+                    }
+                    buf.clear();
+                }
+            });
+        }
+        children.push(ParallelChild::Running {
+            deadline: cmd.timeout.map(|timeout| Instant::now() + timeout),
+            child,
+        });
+    }
+    drop(sender);
+
+    // Print lines as they arrive while any command runs, then whatever is left
+    // once they've all exited (briefly, in case a leftover process holds a stream)
+    let mut draining = false;
+    loop {
+        let wait = if draining {
+            Duration::from_millis(100)
+        } else {
+            Duration::from_millis(20)
+        };
+        match receiver.recv_timeout(wait) {
+            Ok((index, line)) => {
+                super::print(format_with_label(
+                    &commands[index].label,
+                    label_width,
+                    &line,
+                ))?;
+                continue;
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            Err(mpsc::RecvTimeoutError::Timeout) if draining => break,
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+        }
+
+        for (slot, cmd) in children.iter_mut().zip(commands) {
+            let ParallelChild::Running { child, deadline } = slot else {
+                continue;
+            };
+            match child.try_wait() {
+                Ok(Some(status)) => *slot = ParallelChild::Done(exit_status_result(status)),
+                Ok(None) => {
+                    if let (Some(deadline), Some(timeout)) = (*deadline, cmd.timeout)
+                        && Instant::now() >= deadline
+                    {
+                        #[cfg(unix)]
+                        forward_signal_with_escalation(
+                            child.id() as i32,
+                            signal_hook::consts::SIGTERM,
+                        );
+                        #[cfg(not(unix))]
+                        let _ = child.kill();
+                        let _ = child.wait();
+                        *slot = ParallelChild::Done(Err(timed_out(timeout)));
+                    }
+                }
+                Err(e) => {
+                    *slot = ParallelChild::Done(Err(anyhow::Error::from(GitError::Other {
+                        message: format!("Failed to wait for command: {}", e),
+                    })));
+                }
+            }
+        }
+        draining = children
+            .iter()
+            .all(|slot| matches!(slot, ParallelChild::Done(_)));
+    }
+
+    let results = children
+        .into_iter()
+        .map(|slot| match slot {
+            ParallelChild::Done(result) => result,
+            ParallelChild::Running { mut child, .. } => {
+                let status = child.wait().map_err(|e| {
+                    anyhow::Error::from(GitError::Other {
+                        message: format!("Failed to wait for command: {}", e),
+                    })
+                })?;
+                exit_status_result(status)
+            }
+        })
+        .collect();
+
+    super::flush()?;
+    Ok(results)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1230,32 +1678,36 @@ mod tests {
         let path = PathBuf::from("/tmp/test-worktree");
 
         // Without branch deletion, without force
-        let cmd = build_remove_command(&path, None, false);
+        let cmd = build_remove_command(&path, None, false, None);
         assert!(cmd.contains("git worktree remove"));
         assert!(cmd.contains("/tmp/test-worktree"));
         assert!(!cmd.contains("branch -D"));
         assert!(!cmd.contains("--force"));
 
         // With branch deletion, without force
-        let cmd = build_remove_command(&path, Some("feature-branch"), false);
+        let cmd = build_remove_command(&path, Some("feature-branch"), false, None);
         assert!(cmd.contains("git worktree remove"));
         assert!(cmd.contains("git branch -D"));
         assert!(cmd.contains("feature-branch"));
         assert!(!cmd.contains("--force"));
 
         // With force flag
-        let cmd = build_remove_command(&path, None, true);
+        let cmd = build_remove_command(&path, None, true, None);
         assert!(cmd.contains("git worktree remove --force"));
 
         // With branch deletion and force
-        let cmd = build_remove_command(&path, Some("feature-branch"), true);
+        let cmd = build_remove_command(&path, Some("feature-branch"), true, None);
         assert!(cmd.contains("git worktree remove --force"));
         assert!(cmd.contains("git branch -D"));
 
         // Shell escaping for special characters
         let special_path = PathBuf::from("/tmp/test worktree");
-        let cmd = build_remove_command(&special_path, Some("feature/branch"), false);
+        let cmd = build_remove_command(&special_path, Some("feature/branch"), false, None);
         assert!(cmd.contains("worktree remove"));
+
+        // Follow-up runs only after the branch is deleted
+        let cmd = build_remove_command(&path, Some("feature-branch"), false, Some("echo done"));
+        assert!(cmd.ends_with("git branch -D feature-branch && echo done"));
     }
 
     #[test]
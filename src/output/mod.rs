@@ -28,13 +28,17 @@
 
 mod global;
 pub mod handlers;
+mod progress;
+pub mod report;
 
 // Re-export the public API
 pub use global::{
-    blank, change_directory, execute, flush, is_shell_integration_active, print,
+    blank, change_directory, execute, flush, is_shell_integration_active, print, set_quiet,
     shell_integration_hint, stdout, terminate_output,
 };
+pub use progress::with_progress;
 // Re-export output handlers
 pub use handlers::{
-    execute_command_in_worktree, execute_user_command, handle_remove_output, handle_switch_output,
+    LabeledCommand, execute_command_in_worktree, execute_commands_in_worktree_parallel,
+    execute_user_command, handle_remove_output, handle_switch_output,
 };
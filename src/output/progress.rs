@@ -0,0 +1,62 @@
+//! Progress for operations that would otherwise pause silently
+//!
+//! [`with_progress`] prints a progress message, then — on a terminal, without
+//! `--quiet` — animates a spinner with the elapsed time beneath it until the
+//! work finishes. Elsewhere (pipes, CI, tests) only the message is printed, so
+//! output stays the same whether or not anyone is watching.
+
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, mpsc};
+use std::time::{Duration, Instant};
+
+use color_print::cformat;
+
+use super::global::{is_quiet, stderr_is_terminal};
+
+const FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+const FRAME_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Print `message`, then run `work` with a spinner beneath the message.
+///
+/// `work` shouldn't print: its output would land on the spinner's line.
+pub fn with_progress<T>(message: impl Into<String>, work: impl FnOnce() -> T) -> io::Result<T> {
+    super::print(message)?;
+    if is_quiet() || !stderr_is_terminal() {
+        return Ok(work());
+    }
+
+    let (done, finished) = mpsc::channel::<()>();
+    let drawn = Arc::new(AtomicBool::new(false));
+    let spinner = {
+        let drawn = Arc::clone(&drawn);
+        std::thread::spawn(move || {
+            let started = Instant::now();
+            for frame in FRAMES.iter().cycle() {
+                // Quick work finishes before anything is drawn
+                if finished.recv_timeout(FRAME_INTERVAL).is_ok() {
+                    break;
+                }
+                let elapsed = started.elapsed().as_secs();
+                let mut stderr = io::stderr();
+                let _ = write!(
+                    stderr,
+                    "\r\x1b[2K{}",
+                    cformat!("<dim>{frame} {elapsed}s</>")
+                );
+                let _ = stderr.flush();
+                drawn.store(true, Ordering::Relaxed);
+            }
+        })
+    };
+
+    let result = work();
+    let _ = done.send(());
+    let _ = spinner.join();
+    if drawn.load(Ordering::Relaxed) {
+        let mut stderr = io::stderr();
+        write!(stderr, "\r\x1b[2K")?;
+        stderr.flush()?;
+    }
+    Ok(result)
+}
@@ -0,0 +1,146 @@
+//! Structured results for `--format json` on commands that change things
+//!
+//! `wt switch`, `wt merge`, `wt remove`, and `wt step push` record what they
+//! did as they go — from wherever the work happens, like the rest of the output
+//! layer — and [`emit`] prints it as a single JSON object on stdout when the
+//! command finishes. Messages still go to stderr, so for wrappers and agents
+//! the object is the whole of stdout.
+//!
+//! Recording is a no-op unless [`start`] was called, so call sites don't need
+//! to check the output format.
+
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::Serialize;
+
+/// The report being built, when `--format json` was requested
+static REPORT: Mutex<Option<Report>> = Mutex::new(None);
+
+/// What a command did
+#[derive(Debug, Default, Serialize)]
+pub struct Report {
+    /// The command: `switch`, `merge`, `remove`, or `push`
+    pub action: &'static str,
+    /// Branch the command acted on
+    pub branch: Option<String>,
+    /// Branch merged or pushed onto
+    pub target: Option<String>,
+    /// Directory wt changed to (null when it stays put)
+    pub path: Option<PathBuf>,
+    /// Commits merged or pushed onto the target
+    pub commits: Option<usize>,
+    pub created_worktrees: Vec<PathBuf>,
+    /// Worktrees removed, including removals handed to a background process
+    pub removed_worktrees: Vec<PathBuf>,
+    /// Refs created, such as `refs/heads/feature`
+    pub created_refs: Vec<String>,
+    /// Refs deleted, including deletions handed to a background process
+    pub deleted_refs: Vec<String>,
+    pub hooks: Vec<HookReport>,
+    /// Why the command failed, without styling
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// A hook command that ran (or was started in the background)
+#[derive(Debug, Serialize)]
+pub struct HookReport {
+    /// Hook type, such as `post-create`
+    pub hook: String,
+    /// Source-qualified command name, such as `user:lint`
+    pub name: String,
+    pub status: HookStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookStatus {
+    Succeeded,
+    Failed,
+    /// Spawned in the background; its outcome is in the hook's log file
+    Background,
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Option<Report>> {
+    REPORT.lock().expect("REPORT lock poisoned")
+}
+
+/// Start recording a report for `action`, to be printed by [`emit`]
+pub fn start(action: &'static str) {
+    *lock() = Some(Report {
+        action,
+        ..Report::default()
+    });
+}
+
+/// Update the report, if one is being recorded
+pub fn record(update: impl FnOnce(&mut Report)) {
+    if let Some(report) = lock().as_mut() {
+        update(report);
+    }
+}
+
+/// Record a failure, then print the report (if one is being recorded)
+pub fn fail(error: impl Into<String>) -> io::Result<()> {
+    record(|report| report.error = Some(error.into()));
+    emit()
+}
+
+/// Print the report to stdout and stop recording. Does nothing when no report
+/// is being recorded, so it's safe to call more than once.
+pub fn emit() -> io::Result<()> {
+    let Some(report) = lock().take() else {
+        return Ok(());
+    };
+    let json = serde_json::to_string_pretty(&report).map_err(io::Error::other)?;
+    super::stdout(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_json() {
+        let report = Report {
+            action: "remove",
+            removed_worktrees: vec![PathBuf::from("/repo.feature")],
+            deleted_refs: vec!["refs/heads/feature".into()],
+            hooks: vec![HookReport {
+                hook: "post-remove".into(),
+                name: "user:cleanup".into(),
+                status: HookStatus::Background,
+                exit_code: None,
+            }],
+            ..Report::default()
+        };
+        insta::assert_snapshot!(serde_json::to_string_pretty(&report).unwrap(), @r#"
+        {
+          "action": "remove",
+          "branch": null,
+          "target": null,
+          "path": null,
+          "commits": null,
+          "created_worktrees": [],
+          "removed_worktrees": [
+            "/repo.feature"
+          ],
+          "created_refs": [],
+          "deleted_refs": [
+            "refs/heads/feature"
+          ],
+          "hooks": [
+            {
+              "hook": "post-remove",
+              "name": "user:cleanup",
+              "status": "background"
+            }
+          ]
+        }
+        "#);
+    }
+}
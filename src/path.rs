@@ -91,6 +91,8 @@ pub use home::home_dir;
 /// Replaces home directory prefix with `~` (e.g., `/Users/alex/projects/wt` -> `~/projects/wt`).
 /// Paths outside home are returned unchanged.
 pub fn format_path_for_display(path: &Path) -> String {
+    // Show `C:\...` rather than Windows' verbatim `\\?\C:\...` form
+    let path = dunce::simplified(path);
     if let Some(home) = home_dir()
         && let Ok(stripped) = path.strip_prefix(&home)
     {
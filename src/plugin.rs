@@ -0,0 +1,91 @@
+//! External plugin subcommands.
+//!
+//! Like git, an unknown subcommand `wt foo` runs a `wt-foo` executable from
+//! `PATH`, passing the remaining arguments through. Plugins get repository
+//! context in the environment so they don't need to rediscover it:
+//!
+//! - `WT_REPO_ROOT` — the main worktree (or bare repository directory)
+//! - `WT_WORKTREE` — the root of the current worktree
+//! - `WT_BRANCH` — the current branch, empty when HEAD is detached
+//!
+//! Outside a repository the plugin still runs, without these variables.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use worktrunk::git::Repository;
+
+use crate::alias;
+
+/// Find the `wt-<name>` executable on `PATH`.
+fn find_plugin(name: &str) -> Option<PathBuf> {
+    // Names with path separators would escape PATH lookup
+    if name.is_empty() || name.contains(['/', '\\']) {
+        return None;
+    }
+    which::which(format!("wt-{name}")).ok()
+}
+
+/// Set `WT_*` context variables for the repository at `dir`, if any.
+fn add_repo_context(cmd: &mut Command, dir: &Path) {
+    let repo = Repository::at(dir);
+    let Ok(worktree) = repo.worktree_root() else {
+        return;
+    };
+    cmd.env("WT_WORKTREE", worktree);
+    if let Ok(root) = repo.worktree_base() {
+        cmd.env("WT_REPO_ROOT", root);
+    }
+    let branch = repo.current_branch().ok().flatten().unwrap_or_default();
+    cmd.env("WT_BRANCH", branch);
+}
+
+/// Run a plugin if `args` invokes an unknown subcommand with a matching `wt-<name>`.
+///
+/// Returns `None` when there's no plugin to run, leaving clap to report the
+/// unknown subcommand. Otherwise the plugin replaces this process on Unix;
+/// elsewhere its exit code is returned.
+pub fn maybe_run_plugin(args: &[OsString]) -> Option<anyhow::Result<i32>> {
+    let scan = alias::scan(args);
+    let position = scan.position?;
+    let name = args[position].to_str()?;
+    if alias::is_builtin(name) {
+        return None;
+    }
+    let program = find_plugin(name)?;
+
+    let dir = match (scan.directory, scan.repo) {
+        (Some(dir), _) => dir,
+        (None, Some(name)) => match crate::commands::repo::resolve_repo(&name) {
+            Ok(dir) => dir,
+            Err(e) => return Some(Err(e)),
+        },
+        (None, None) => PathBuf::from("."),
+    };
+    let mut cmd = Command::new(&program);
+    cmd.args(&args[position + 1..]).current_dir(&dir);
+    add_repo_context(&mut cmd, &dir);
+
+    Some(run_plugin(cmd, &program))
+}
+
+#[cfg(unix)]
+fn run_plugin(mut cmd: Command, program: &Path) -> anyhow::Result<i32> {
+    use std::os::unix::process::CommandExt;
+
+    // exec() only returns on error
+    let err = cmd.exec();
+    Err(anyhow::anyhow!(
+        "Failed to run {}: {err}",
+        program.display()
+    ))
+}
+
+#[cfg(not(unix))]
+fn run_plugin(mut cmd: Command, program: &Path) -> anyhow::Result<i32> {
+    let status = cmd
+        .status()
+        .map_err(|e| anyhow::anyhow!("Failed to run {}: {e}", program.display()))?;
+    Ok(status.code().unwrap_or(1))
+}
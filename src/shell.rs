@@ -350,7 +350,7 @@ pub fn scan_for_detection_details(cmd: &str) -> Result<Vec<FileDetectionResult>,
 
 /// Supported shells
 ///
-/// Currently supported: bash, fish, zsh, powershell
+/// Currently supported: bash, fish, zsh, powershell, nushell
 ///
 /// On Windows, Git Bash users should use `bash` for shell integration.
 /// PowerShell integration is available for native Windows users without Git Bash.
@@ -363,6 +363,7 @@ pub enum Shell {
     #[strum(serialize = "powershell")]
     #[clap(name = "powershell")]
     PowerShell,
+    Nushell,
 }
 
 impl Shell {
@@ -395,6 +396,19 @@ impl Shell {
                 ]
             }
             Self::PowerShell => powershell_profile_paths(&home),
+            Self::Nushell => {
+                // Nushell can't eval generated code at startup, so the wrapper
+                // itself is saved to the autoload directory (see `config_line`)
+                let config_home = choose_base_strategy()
+                    .map(|s| s.config_dir())
+                    .unwrap_or_else(|_| home.join(".config"));
+                vec![
+                    config_home
+                        .join("nushell")
+                        .join("autoload")
+                        .join(format!("{}.nu", cmd)),
+                ]
+            }
         })
     }
 
@@ -442,6 +456,10 @@ impl Shell {
                 // Return a dummy path that won't be used
                 home.join(format!(".{}-powershell-completions", cmd))
             }
+            Self::Nushell => {
+                // No nushell completions yet; return a dummy path that won't be used
+                home.join(format!(".{}-nushell-completions", cmd))
+            }
         })
     }
 
@@ -471,6 +489,13 @@ impl Shell {
                     "if (Get-Command {cmd} -ErrorAction SilentlyContinue) {{ Invoke-Expression (& {cmd} config shell init powershell) }}",
                 )
             }
+            Self::Nushell => {
+                // Not a config line: nushell parses sourced files before running
+                // anything, so this is the command that saves the wrapper to autoload
+                format!(
+                    "{cmd} config shell init nushell | save -f ($nu.default-config-dir | path join autoload {cmd}.nu)"
+                )
+            }
         }
     }
 
@@ -495,11 +520,33 @@ impl Shell {
 pub struct ShellInit {
     pub shell: Shell,
     pub cmd: String,
+    /// Shell code the wrapper runs in the current shell after a command
+    /// changes directory (e.g. `source .venv/bin/activate`)
+    pub on_switch: Option<String>,
+    /// Also define abbreviations and helper functions (fish only)
+    pub with_abbr: bool,
 }
 
 impl ShellInit {
     pub fn with_prefix(shell: Shell, cmd: String) -> Self {
-        Self { shell, cmd }
+        Self {
+            shell,
+            cmd,
+            on_switch: None,
+            with_abbr: false,
+        }
+    }
+
+    /// Run `snippet` in the current shell whenever the wrapper changes directory
+    pub fn on_switch(mut self, snippet: Option<String>) -> Self {
+        self.on_switch = snippet;
+        self
+    }
+
+    /// Define `{cmd}s`/`{cmd}l` abbreviations and a `{cmd}cd` function (fish only)
+    pub fn with_abbr(mut self, with_abbr: bool) -> Self {
+        self.with_abbr = with_abbr;
+        self
     }
 
     /// Generate shell integration code
@@ -509,19 +556,37 @@ impl ShellInit {
                 let template = BashTemplate {
                     shell_name: self.shell.to_string(),
                     cmd: &self.cmd,
+                    on_switch: self.on_switch.as_deref(),
                 };
                 template.render()
             }
             Shell::Zsh => {
-                let template = ZshTemplate { cmd: &self.cmd };
+                let template = ZshTemplate {
+                    cmd: &self.cmd,
+                    on_switch: self.on_switch.as_deref(),
+                };
                 template.render()
             }
             Shell::Fish => {
-                let template = FishTemplate { cmd: &self.cmd };
+                let template = FishTemplate {
+                    cmd: &self.cmd,
+                    on_switch: self.on_switch.as_deref(),
+                    with_abbr: self.with_abbr,
+                };
                 template.render()
             }
             Shell::PowerShell => {
-                let template = PowerShellTemplate { cmd: &self.cmd };
+                let template = PowerShellTemplate {
+                    cmd: &self.cmd,
+                    on_switch: self.on_switch.as_deref(),
+                };
+                template.render()
+            }
+            Shell::Nushell => {
+                let template = NushellTemplate {
+                    cmd: &self.cmd,
+                    on_switch: self.on_switch.as_deref(),
+                };
                 template.render()
             }
         }
@@ -534,6 +599,7 @@ impl ShellInit {
 struct BashTemplate<'a> {
     shell_name: String,
     cmd: &'a str,
+    on_switch: Option<&'a str>,
 }
 
 /// Zsh shell template
@@ -541,6 +607,7 @@ struct BashTemplate<'a> {
 #[template(path = "zsh.zsh", escape = "none")]
 struct ZshTemplate<'a> {
     cmd: &'a str,
+    on_switch: Option<&'a str>,
 }
 
 /// Fish shell template
@@ -548,6 +615,8 @@ struct ZshTemplate<'a> {
 #[template(path = "fish.fish", escape = "none")]
 struct FishTemplate<'a> {
     cmd: &'a str,
+    on_switch: Option<&'a str>,
+    with_abbr: bool,
 }
 
 /// PowerShell template
@@ -555,6 +624,15 @@ struct FishTemplate<'a> {
 #[template(path = "powershell.ps1", escape = "none")]
 struct PowerShellTemplate<'a> {
     cmd: &'a str,
+    on_switch: Option<&'a str>,
+}
+
+/// Nushell template
+#[derive(Template)]
+#[template(path = "nushell.nu", escape = "none")]
+struct NushellTemplate<'a> {
+    cmd: &'a str,
+    on_switch: Option<&'a str>,
 }
 
 /// Detect if user's zsh has compinit enabled by probing for the compdef function.
@@ -677,6 +755,7 @@ mod tests {
             "POWERSHELL".parse::<Shell>(),
             Ok(Shell::PowerShell)
         ));
+        assert!(matches!("nushell".parse::<Shell>(), Ok(Shell::Nushell)));
         assert!("invalid".parse::<Shell>().is_err());
     }
 
@@ -686,6 +765,7 @@ mod tests {
         assert_eq!(Shell::Fish.to_string(), "fish");
         assert_eq!(Shell::Zsh.to_string(), "zsh");
         assert_eq!(Shell::PowerShell.to_string(), "powershell");
+        assert_eq!(Shell::Nushell.to_string(), "nushell");
     }
 
     #[test]
@@ -715,7 +795,13 @@ mod tests {
 
     #[test]
     fn test_shell_init_generate() {
-        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+        for shell in [
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::PowerShell,
+            Shell::Nushell,
+        ] {
             let init = ShellInit::with_prefix(shell, "wt".to_string());
             let output = init.generate().expect("Failed to generate");
             insta::assert_snapshot!(format!("init_{shell}"), output);
@@ -725,7 +811,13 @@ mod tests {
     #[test]
     fn test_shell_config_paths_returns_paths() {
         // All shells should return at least one config path
-        let shells = [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell];
+        let shells = [
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::PowerShell,
+            Shell::Nushell,
+        ];
         for shell in shells {
             let result = shell.config_paths("wt");
             assert!(result.is_ok(), "Failed to get config paths for {:?}", shell);
@@ -741,7 +833,13 @@ mod tests {
     #[test]
     fn test_shell_completion_path_returns_path() {
         // All shells should return a completion path
-        let shells = [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell];
+        let shells = [
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::PowerShell,
+            Shell::Nushell,
+        ];
         for shell in shells {
             let result = shell.completion_path("wt");
             assert!(
@@ -770,6 +868,13 @@ mod tests {
             "Fish config should include prefix in filename"
         );
 
+        // Nushell autoload file should include prefix in filename
+        let nushell_paths = Shell::Nushell.config_paths(prefix).unwrap();
+        assert!(
+            nushell_paths[0].ends_with("nushell/autoload/custom-wt.nu"),
+            "Nushell autoload file should include prefix in filename"
+        );
+
         // Bash and Zsh config paths are fixed (not affected by prefix)
         let bash_paths = Shell::Bash.config_paths(prefix).unwrap();
         assert!(
@@ -816,6 +921,28 @@ mod tests {
         insta::assert_snapshot!(init.generate().expect("Should generate with custom prefix"));
     }
 
+    #[test]
+    fn test_shell_init_on_switch() {
+        for shell in [
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::PowerShell,
+            Shell::Nushell,
+        ] {
+            let init = ShellInit::with_prefix(shell, "wt".to_string())
+                .on_switch(Some("source .venv/bin/activate".to_string()));
+            let output = init.generate().expect("Failed to generate");
+            insta::assert_snapshot!(format!("init_on_switch_{shell}"), output);
+        }
+    }
+
+    #[test]
+    fn test_shell_init_fish_with_abbr() {
+        let init = ShellInit::with_prefix(Shell::Fish, "wt".to_string()).with_abbr(true);
+        insta::assert_snapshot!(init.generate().expect("Failed to generate"));
+    }
+
     /// Verify that `config_line()` generates lines that
     /// `is_shell_integration_line()` can detect.
     ///
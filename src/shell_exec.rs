@@ -15,6 +15,7 @@
 //! - No support for POSIX redirections like `{ cmd; } 1>&2`
 //! - Different string escaping rules for JSON piping
 
+use std::borrow::Cow;
 use std::path::PathBuf;
 use std::process::Command;
 use std::sync::OnceLock;
@@ -100,6 +101,46 @@ impl ShellConfig {
     pub fn is_windows_without_git_bash(&self) -> bool {
         false
     }
+
+    /// Quote `value` as a single literal word for this shell
+    ///
+    /// `shell_escape::escape` picks cmd.exe rules on Windows (outside MSYS),
+    /// which neither Git Bash nor PowerShell understands, so anything passed to
+    /// [`command`](Self::command) should be quoted here instead.
+    pub fn quote<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        if self.is_posix {
+            shell_escape::unix::escape(Cow::Borrowed(value))
+        } else {
+            powershell_quote(value)
+        }
+    }
+}
+
+/// Quote `value` for PowerShell, leaving plain words (including Windows
+/// paths like `C:\src\repo`) unquoted
+///
+/// Single-quoted strings are fully literal except for the quote itself, which
+/// is doubled. PowerShell also treats typographic single quotes as quotes.
+/// See: https://learn.microsoft.com/en-us/powershell/module/microsoft.powershell.core/about/about_quoting_rules
+fn powershell_quote(value: &str) -> Cow<'_, str> {
+    let plain = !value.is_empty()
+        && value.chars().all(|c| {
+            c.is_ascii_alphanumeric()
+                || matches!(c, '-' | '_' | '.' | '/' | '\\' | ':' | '=' | '+' | ',')
+        });
+    if plain && !value.starts_with('-') {
+        return Cow::Borrowed(value);
+    }
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('\'');
+    for c in value.chars() {
+        if matches!(c, '\'' | '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}') {
+            quoted.push(c);
+        }
+        quoted.push(c);
+    }
+    quoted.push('\'');
+    Cow::Owned(quoted)
 }
 
 /// Detect the best available shell for the current platform
@@ -203,6 +244,14 @@ fn find_git_bash() -> Option<PathBuf> {
 /// Hooks and other child processes should not be able to write to the directive file.
 pub const DIRECTIVE_FILE_ENV_VAR: &str = "WORKTRUNK_DIRECTIVE_FILE";
 
+/// Set by shell wrappers to the newest directive protocol they understand.
+/// Without it, directives are written as shell code for the wrapper to source.
+pub const DIRECTIVE_PROTOCOL_ENV_VAR: &str = "WORKTRUNK_DIRECTIVE_PROTOCOL";
+
+/// First record of a protocol 2 directive file, so wrappers can tell it from
+/// the shell script an older binary writes
+pub const DIRECTIVE_HEADER: &str = "worktrunk-directives 2";
+
 /// Execute a command with timing and debug logging.
 ///
 /// This is the **only** way to run external commands in worktrunk. All command execution
@@ -234,10 +283,10 @@ pub fn run(cmd: &mut Command, context: Option<&str>) -> std::io::Result<std::pro
         format!("{} {}", program, args.join(" "))
     };
 
-    // Log command with optional context
+    // Log command with optional context (shown from `-v`; timing below from `-vv`)
     match context {
-        Some(ctx) => log::debug!("$ {} [{}]", cmd_str, ctx),
-        None => log::debug!("$ {}", cmd_str),
+        Some(ctx) => log::info!("$ {} [{}]", cmd_str, ctx),
+        None => log::info!("$ {}", cmd_str),
     }
 
     // Acquire semaphore to limit concurrent commands (prevents resource exhaustion)
@@ -248,6 +297,25 @@ pub fn run(cmd: &mut Command, context: Option<&str>) -> std::io::Result<std::pro
     let result = cmd.output();
     let duration_ms = t0.elapsed().as_secs_f64() * 1000.0;
 
+    crate::op_log::record(
+        "command",
+        match &result {
+            Ok(output) => serde_json::json!({
+                "cmd": cmd_str,
+                "context": context,
+                "duration_ms": (duration_ms * 10.0).round() / 10.0,
+                "ok": output.status.success(),
+                "exit_code": output.status.code(),
+            }),
+            Err(e) => serde_json::json!({
+                "cmd": cmd_str,
+                "context": context,
+                "duration_ms": (duration_ms * 10.0).round() / 10.0,
+                "error": e.to_string(),
+            }),
+        },
+    );
+
     // Log trace with timing
     match (&result, context) {
         (Ok(output), Some(ctx)) => {
@@ -468,4 +536,21 @@ mod tests {
         let config = ShellConfig::get();
         assert!(!config.is_windows_without_git_bash());
     }
+
+    #[test]
+    fn test_powershell_quote() {
+        for (value, expected) in [
+            ("feature", "feature"),
+            (r"C:\src\repo.feature", r"C:\src\repo.feature"),
+            ("my branch", "'my branch'"),
+            ("it's", "'it''s'"),
+            ("$HOME", "'$HOME'"),
+            ("`cmd`", "'`cmd`'"),
+            ("-flag", "'-flag'"),
+            ("", "''"),
+            ("a\u{2019}b", "'a\u{2019}\u{2019}b'"),
+        ] {
+            assert_eq!(powershell_quote(value), expected, "{value}");
+        }
+    }
 }
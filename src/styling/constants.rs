@@ -62,27 +62,100 @@ pub const DEFAULT_HELP_WIDTH: usize = 98;
 //
 // Single-width Unicode symbols for message prefixes with embedded colors.
 // Using `cstr!` to create colored `&'static str` constants that work everywhere.
+//
+// Each symbol has a plain-text tag shown instead when emoji are turned off
+// (`--no-emoji` or `styling.emoji = false`), for terminals and logs where the
+// symbols render poorly.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether message symbols render as emoji (the default) or plain-text tags
+static EMOJI: AtomicBool = AtomicBool::new(true);
+
+/// Render message symbols as emoji (`true`) or plain-text tags (`false`)
+pub fn set_emoji(enabled: bool) {
+    EMOJI.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether message symbols render as emoji
+pub fn emoji_enabled() -> bool {
+    EMOJI.load(Ordering::Relaxed)
+}
+
+/// A message prefix symbol, with its plain-text fallback
+///
+/// Displays as whichever form [`emoji_enabled`] selects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    emoji: &'static str,
+    tag: &'static str,
+}
+
+impl Symbol {
+    /// The symbol as currently rendered
+    pub fn as_str(self) -> &'static str {
+        if emoji_enabled() {
+            self.emoji
+        } else {
+            self.tag
+        }
+    }
+}
+
+impl Symbol {
+    /// The symbol without its color, for messages that color it with the text
+    pub fn plain(self) -> std::borrow::Cow<'static, str> {
+        ansi_str::AnsiStr::ansi_strip(self.as_str())
+    }
+}
+
+impl std::fmt::Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
 /// Progress symbol (cyan ◎)
-pub const PROGRESS_SYMBOL: &str = cstr!("<cyan>◎</>");
+pub const PROGRESS_SYMBOL: Symbol = Symbol {
+    emoji: cstr!("<cyan>◎</>"),
+    tag: cstr!("<cyan>[progress]</>"),
+};
 
 /// Success symbol (green ✓)
-pub const SUCCESS_SYMBOL: &str = cstr!("<green>✓</>");
+pub const SUCCESS_SYMBOL: Symbol = Symbol {
+    emoji: cstr!("<green>✓</>"),
+    tag: cstr!("<green>[ok]</>"),
+};
 
 /// Error symbol (red ✗)
-pub const ERROR_SYMBOL: &str = cstr!("<red>✗</>");
+pub const ERROR_SYMBOL: Symbol = Symbol {
+    emoji: cstr!("<red>✗</>"),
+    tag: cstr!("<red>[error]</>"),
+};
 
 /// Warning symbol (yellow ▲)
-pub const WARNING_SYMBOL: &str = cstr!("<yellow>▲</>");
+pub const WARNING_SYMBOL: Symbol = Symbol {
+    emoji: cstr!("<yellow>▲</>"),
+    tag: cstr!("<yellow>[warning]</>"),
+};
 
 /// Hint symbol (dim ↳)
-pub const HINT_SYMBOL: &str = cstr!("<dim>↳</>");
+pub const HINT_SYMBOL: Symbol = Symbol {
+    emoji: cstr!("<dim>↳</>"),
+    tag: cstr!("<dim>[hint]</>"),
+};
 
 /// Info symbol (dim ○) - for neutral status
-pub const INFO_SYMBOL: &str = cstr!("<dim>○</>");
+pub const INFO_SYMBOL: Symbol = Symbol {
+    emoji: cstr!("<dim>○</>"),
+    tag: cstr!("<dim>[info]</>"),
+};
 
 /// Prompt symbol (cyan ❯) - for questions requiring user input
-pub const PROMPT_SYMBOL: &str = cstr!("<cyan>❯</>");
+pub const PROMPT_SYMBOL: Symbol = Symbol {
+    emoji: cstr!("<cyan>❯</>"),
+    tag: cstr!("<cyan>[input]</>"),
+};
 
 // ============================================================================
 // Formatted Message Type
@@ -267,13 +340,30 @@ mod tests {
     #[test]
     fn test_symbol_constants() {
         // Symbols are pre-colored with ANSI codes, but contain the Unicode character
-        assert!(PROGRESS_SYMBOL.contains("◎"));
-        assert!(SUCCESS_SYMBOL.contains("✓"));
-        assert!(ERROR_SYMBOL.contains("✗"));
-        assert!(WARNING_SYMBOL.contains("▲"));
-        assert!(HINT_SYMBOL.contains("↳"));
-        assert!(INFO_SYMBOL.contains("○"));
-        assert!(PROMPT_SYMBOL.contains("❯"));
+        assert!(PROGRESS_SYMBOL.emoji.contains("◎"));
+        assert!(SUCCESS_SYMBOL.emoji.contains("✓"));
+        assert!(ERROR_SYMBOL.emoji.contains("✗"));
+        assert!(WARNING_SYMBOL.emoji.contains("▲"));
+        assert!(HINT_SYMBOL.emoji.contains("↳"));
+        assert!(INFO_SYMBOL.emoji.contains("○"));
+        assert!(PROMPT_SYMBOL.emoji.contains("❯"));
+    }
+
+    #[test]
+    fn test_symbol_tags() {
+        // Tags are plain ASCII once colors are stripped
+        for symbol in [
+            PROGRESS_SYMBOL,
+            SUCCESS_SYMBOL,
+            ERROR_SYMBOL,
+            WARNING_SYMBOL,
+            HINT_SYMBOL,
+            INFO_SYMBOL,
+            PROMPT_SYMBOL,
+        ] {
+            let tag = ansi_str::AnsiStr::ansi_strip(symbol.tag);
+            assert!(tag.starts_with('[') && tag.ends_with(']') && tag.is_ascii());
+        }
     }
 
     // ============================================================================
@@ -283,7 +373,7 @@ mod tests {
     #[test]
     fn test_error_message() {
         let msg = error_message("Something went wrong");
-        assert!(msg.as_str().contains(ERROR_SYMBOL));
+        assert!(msg.as_str().contains(ERROR_SYMBOL.as_str()));
         assert!(msg.as_str().contains("Something went wrong"));
     }
 
@@ -291,7 +381,7 @@ mod tests {
     fn test_error_message_with_inner_styling() {
         let name = "feature";
         let msg = error_message(cformat!("Branch <bold>{name}</> not found"));
-        assert!(msg.as_str().contains(ERROR_SYMBOL));
+        assert!(msg.as_str().contains(ERROR_SYMBOL.as_str()));
         assert!(msg.as_str().contains("Branch"));
         assert!(msg.as_str().contains("feature"));
     }
@@ -299,35 +389,35 @@ mod tests {
     #[test]
     fn test_hint_message() {
         let msg = hint_message("Try running --help");
-        assert!(msg.as_str().contains(HINT_SYMBOL));
+        assert!(msg.as_str().contains(HINT_SYMBOL.as_str()));
         assert!(msg.as_str().contains("Try running --help"));
     }
 
     #[test]
     fn test_warning_message() {
         let msg = warning_message("Deprecated option");
-        assert!(msg.as_str().contains(WARNING_SYMBOL));
+        assert!(msg.as_str().contains(WARNING_SYMBOL.as_str()));
         assert!(msg.as_str().contains("Deprecated option"));
     }
 
     #[test]
     fn test_success_message() {
         let msg = success_message("Operation completed");
-        assert!(msg.as_str().contains(SUCCESS_SYMBOL));
+        assert!(msg.as_str().contains(SUCCESS_SYMBOL.as_str()));
         assert!(msg.as_str().contains("Operation completed"));
     }
 
     #[test]
     fn test_progress_message() {
         let msg = progress_message("Loading data...");
-        assert!(msg.as_str().contains(PROGRESS_SYMBOL));
+        assert!(msg.as_str().contains(PROGRESS_SYMBOL.as_str()));
         assert!(msg.as_str().contains("Loading data..."));
     }
 
     #[test]
     fn test_info_message() {
         let msg = info_message("5 items found");
-        assert!(msg.as_str().contains(INFO_SYMBOL));
+        assert!(msg.as_str().contains(INFO_SYMBOL.as_str()));
         assert!(msg.as_str().contains("5 items found"));
     }
 
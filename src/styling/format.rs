@@ -359,6 +359,21 @@ pub fn format_bash_with_gutter(content: &str) -> String {
     format_with_gutter(content, None)
 }
 
+/// Prefix one line of a command's output with a dimmed label
+///
+/// Used when several commands run at once, so each line shows which command
+/// wrote it. Labels are padded to `label_width` to keep the output aligned.
+///
+/// ```
+/// use worktrunk::styling::format_with_label;
+///
+/// eprintln!("{}", format_with_label("user:lint", 12, "All checks passed"));
+/// ```
+pub fn format_with_label(label: &str, label_width: usize, line: &str) -> String {
+    let dim = anstyle::Style::new().dimmed();
+    format!("{dim}{label:<label_width$} │{dim:#} {line}")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -555,4 +570,11 @@ mod tests {
         assert!(result.contains("cargo"));
         assert!(result.contains("--release"));
     }
+
+    #[test]
+    fn test_format_with_label_pads_label() {
+        let result = format_with_label("user:lint", 12, "ok");
+        let plain = anstream::adapter::strip_str(&result).to_string();
+        assert_eq!(plain, "user:lint    │ ok");
+    }
 }
@@ -29,7 +29,10 @@ pub use anstyle::Style as AnstyleStyle;
 pub use constants::*;
 #[cfg(all(test, feature = "syntax-highlighting"))]
 pub(crate) use format::format_bash_with_gutter_at_width;
-pub use format::{GUTTER_OVERHEAD, format_bash_with_gutter, format_with_gutter, wrap_styled_text};
+pub use format::{
+    GUTTER_OVERHEAD, format_bash_with_gutter, format_with_gutter, format_with_label,
+    wrap_styled_text,
+};
 pub use highlighting::format_toml;
 pub use line::{StyledLine, StyledString, truncate_visible};
 pub use suggest::suggest_command;
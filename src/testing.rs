@@ -0,0 +1,263 @@
+//! Test utilities for code that consumes worktrunk types.
+//!
+//! Enabled by the `testing` feature:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! worktrunk = { version = "0.8", features = ["testing"] }
+//! ```
+//!
+//! - [`MockGit`] scripts git's responses, so a [`Repository`] can be exercised
+//!   without spawning git or having a repository on disk.
+//! - [`TestRepo`] creates a real repository in a temporary directory, isolated
+//!   from the user's git config, for tests that need git itself.
+
+use std::path::{Path, PathBuf};
+use std::process::Output;
+use std::sync::{Arc, Mutex};
+
+use tempfile::TempDir;
+
+use crate::git::{GitBackend, ProcessBackend, Repository};
+
+/// One scripted git response
+#[derive(Debug, Clone)]
+struct Response {
+    args: Vec<String>,
+    exit_code: i32,
+    stdout: String,
+    stderr: String,
+}
+
+/// A [`GitBackend`] answering from scripted responses.
+///
+/// Commands match on their exact arguments; a command with no scripted
+/// response fails with an error naming it.
+///
+/// ```
+/// use std::sync::Arc;
+/// use worktrunk::git::Repository;
+/// use worktrunk::testing::MockGit;
+///
+/// let git = Arc::new(MockGit::default().on(&["branch", "--show-current"], "feature\n"));
+/// let repo = Repository::with_backend("/repo", git.clone());
+/// assert_eq!(repo.current_branch().unwrap(), Some("feature"));
+/// assert_eq!(git.calls(), [vec!["branch", "--show-current"]]);
+/// ```
+#[derive(Debug, Default)]
+pub struct MockGit {
+    responses: Vec<Response>,
+    calls: Mutex<Vec<Vec<String>>>,
+}
+
+impl MockGit {
+    /// Answer `git <args>` successfully with `stdout`
+    pub fn on(self, args: &[&str], stdout: impl Into<String>) -> Self {
+        self.respond(args, 0, stdout.into(), String::new())
+    }
+
+    /// Answer `git <args>` with a failure
+    pub fn fail(self, args: &[&str], exit_code: i32, stderr: impl Into<String>) -> Self {
+        self.respond(args, exit_code, String::new(), stderr.into())
+    }
+
+    fn respond(mut self, args: &[&str], exit_code: i32, stdout: String, stderr: String) -> Self {
+        self.responses.push(Response {
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            exit_code,
+            stdout,
+            stderr,
+        });
+        self
+    }
+
+    /// Arguments of every command run so far, in order
+    pub fn calls(&self) -> Vec<Vec<String>> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+impl GitBackend for MockGit {
+    fn run(&self, _dir: &Path, args: &[&str], _context: &str) -> std::io::Result<Output> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(args.iter().map(|arg| arg.to_string()).collect());
+        let response = self
+            .responses
+            .iter()
+            .find(|response| response.args == args)
+            .ok_or_else(|| {
+                std::io::Error::other(format!(
+                    "MockGit has no response for `git {}`",
+                    args.join(" ")
+                ))
+            })?;
+        Ok(Output {
+            status: exit_status(response.exit_code),
+            stdout: response.stdout.clone().into_bytes(),
+            stderr: response.stderr.clone().into_bytes(),
+        })
+    }
+}
+
+#[cfg(unix)]
+fn exit_status(code: i32) -> std::process::ExitStatus {
+    use std::os::unix::process::ExitStatusExt;
+    std::process::ExitStatus::from_raw(code << 8)
+}
+
+#[cfg(windows)]
+fn exit_status(code: i32) -> std::process::ExitStatus {
+    use std::os::windows::process::ExitStatusExt;
+    std::process::ExitStatus::from_raw(code as u32)
+}
+
+/// A real repository in a temporary directory, removed on drop.
+///
+/// Starts on `main` with one commit. Git runs with its own global config
+/// (identity set, system config ignored) and fixed commit dates, so results
+/// don't depend on the machine running the tests.
+#[derive(Debug)]
+pub struct TestRepo {
+    _temp_dir: TempDir,
+    root: PathBuf,
+    backend: ProcessBackend,
+}
+
+impl TestRepo {
+    /// Create the repository with an initial commit on `main`
+    pub fn new() -> Self {
+        let temp_dir = TempDir::new().expect("failed to create temp dir");
+        let base = dunce::canonicalize(temp_dir.path()).expect("failed to resolve temp dir");
+        let git_config = base.join("gitconfig");
+        std::fs::write(
+            &git_config,
+            "[user]\n\tname = Test User\n\temail = test@example.com\n\
+             [init]\n\tdefaultBranch = main\n[advice]\n\tdetachedHead = false\n",
+        )
+        .expect("failed to write git config");
+
+        let root = base.join("repo");
+        std::fs::create_dir(&root).expect("failed to create repo dir");
+        let backend = ProcessBackend::default()
+            .env("GIT_CONFIG_GLOBAL", &git_config)
+            .env("GIT_CONFIG_NOSYSTEM", "1")
+            .env("GIT_AUTHOR_DATE", "2025-01-01T00:00:00Z")
+            .env("GIT_COMMITTER_DATE", "2025-01-01T00:00:00Z");
+        let repo = Self {
+            _temp_dir: temp_dir,
+            root,
+            backend,
+        };
+        repo.git(&["init", "--quiet"]);
+        repo.commit("README.md", "# Test\n", "Initial commit");
+        repo
+    }
+
+    /// Path of the main worktree
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// A [`Repository`] for the main worktree, using this repo's isolated git
+    pub fn repository(&self) -> Repository {
+        self.repository_at(&self.root)
+    }
+
+    /// A [`Repository`] for another worktree of this repo
+    pub fn repository_at(&self, path: impl Into<PathBuf>) -> Repository {
+        Repository::with_backend(path, Arc::new(self.backend.clone()))
+    }
+
+    /// Run git in the main worktree, panicking if it fails; returns stdout
+    pub fn git(&self, args: &[&str]) -> String {
+        self.repository()
+            .run_command(args)
+            .unwrap_or_else(|e| panic!("git {} failed: {e:#}", args.join(" ")))
+    }
+
+    /// Write `path` in the main worktree and commit it
+    pub fn commit(&self, path: &str, contents: &str, message: &str) {
+        let file = self.root.join(path);
+        if let Some(parent) = file.parent() {
+            std::fs::create_dir_all(parent).expect("failed to create parent dir");
+        }
+        std::fs::write(file, contents).expect("failed to write file");
+        self.git(&["add", path]);
+        self.git(&["commit", "--quiet", "-m", message]);
+    }
+
+    /// Create a worktree for a new `branch` next to the main worktree,
+    /// returning its path
+    pub fn add_worktree(&self, branch: &str) -> PathBuf {
+        let path = self
+            .root
+            .with_file_name(format!("repo.{}", branch.replace('/', "-")));
+        self.git(&[
+            "worktree",
+            "add",
+            "--quiet",
+            "-b",
+            branch,
+            path.to_str().expect("temp paths are UTF-8"),
+        ]);
+        path
+    }
+}
+
+impl Default for TestRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::GitError;
+
+    #[test]
+    fn test_mock_git_scripted_failure() {
+        let git = Arc::new(MockGit::default().fail(
+            &["status", "--porcelain"],
+            128,
+            "fatal: not a git repository",
+        ));
+        let repo = Repository::with_backend("/nowhere", git);
+        let err = repo.is_dirty().unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<GitError>(),
+            Some(GitError::CommandFailed {
+                exit_code: Some(128),
+                ..
+            })
+        ));
+
+        // Unscripted commands fail rather than reaching real git
+        let repo = Repository::with_backend("/nowhere", Arc::new(MockGit::default()));
+        let err = format!("{:#}", repo.current_branch().unwrap_err());
+        assert!(
+            err.contains("no response for `git branch --show-current`"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_test_repo_worktrees() {
+        let test = TestRepo::new();
+        let repo = test.repository();
+        assert_eq!(repo.current_branch().unwrap(), Some("main"));
+        assert!(!repo.is_dirty().unwrap());
+
+        let path = test.add_worktree("feature");
+        assert_eq!(
+            test.repository_at(&path).current_branch().unwrap(),
+            Some("feature")
+        );
+        assert_eq!(
+            repo.worktree_for_branch("feature").unwrap().as_deref(),
+            Some(path.as_path())
+        );
+    }
+}
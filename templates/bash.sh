@@ -3,8 +3,29 @@
 # Only initialize if {{ cmd }} is available (in PATH or via WORKTRUNK_BIN)
 if command -v {{ cmd }} >/dev/null 2>&1 || [[ -n "${WORKTRUNK_BIN:-}" ]]; then
 
+    # Apply the directives {{ cmd }} wrote to the file at $1 (protocol 2): a version
+    # header, then NUL-terminated `<action> <value>` records. Values are used
+    # literally, so paths with quotes or `$` can't be misparsed. A file without
+    # the header is protocol 1 shell code from an older binary, so source it.
+    _{{ cmd }}_apply_directives() {
+        local record result=0
+        local -a records=()
+        while IFS= read -r -d '' record; do records+=("$record"); done < "$1"
+        if [[ "${records[@]:0:1}" != "worktrunk-directives 2" ]]; then
+            source "$1"
+            return
+        fi
+        for record in "${records[@]:1}"; do
+            case "$record" in
+                "cd "*) builtin cd -- "${record#cd }" || result=$? ;;
+                "exec "*) eval "${record#exec }"; result=$? ;;
+            esac
+        done
+        return "$result"
+    }
+
     # Override {{ cmd }} command with file-based directive passing.
-    # Creates a temp file, passes path via WORKTRUNK_DIRECTIVE_FILE, sources it after.
+    # Creates a temp file, passes path via WORKTRUNK_DIRECTIVE_FILE, applies it after.
     # WORKTRUNK_BIN can override the binary path (for testing dev builds).
     {{ cmd }}() {
         local use_source=false
@@ -24,21 +45,33 @@ if command -v {{ cmd }} >/dev/null 2>&1 || [[ -n "${WORKTRUNK_BIN:-}" ]]; then
 
         local directive_file exit_code=0
         directive_file="$(mktemp)"
+{%- if on_switch.is_some() %}
+        local prev_dir="$PWD"
+{%- endif %}
 
         # --source: use cargo run (builds from source)
         if [[ "$use_source" == true ]]; then
-            WORKTRUNK_DIRECTIVE_FILE="$directive_file" cargo run --bin {{ cmd }} --quiet -- "${args[@]}" || exit_code=$?
+            WORKTRUNK_DIRECTIVE_FILE="$directive_file" WORKTRUNK_DIRECTIVE_PROTOCOL=2 cargo run --bin {{ cmd }} --quiet -- "${args[@]}" || exit_code=$?
         else
-            WORKTRUNK_DIRECTIVE_FILE="$directive_file" command "${WORKTRUNK_BIN:-{{ cmd }}}" "${args[@]}" || exit_code=$?
+            WORKTRUNK_DIRECTIVE_FILE="$directive_file" WORKTRUNK_DIRECTIVE_PROTOCOL=2 command "${WORKTRUNK_BIN:-{{ cmd }}}" "${args[@]}" || exit_code=$?
         fi
 
         if [[ -s "$directive_file" ]]; then
-            source "$directive_file"
+            _{{ cmd }}_apply_directives "$directive_file"
+            local directive_status=$?
             if [[ $exit_code -eq 0 ]]; then
-                exit_code=$?
+                exit_code=$directive_status
             fi
         fi
 
+{%- if let Some(on_switch) = on_switch %}
+
+        # Snippet from --on-switch: runs in this shell after {{ cmd }} changes directory
+        if [[ $exit_code -eq 0 && "$PWD" != "$prev_dir" ]]; then
+            {{ on_switch }}
+        fi
+{%- endif %}
+
         rm -f "$directive_file"
         return "$exit_code"
     }
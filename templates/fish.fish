@@ -3,14 +3,38 @@
 # Only initialize if {{ cmd }} is available (in PATH or via WORKTRUNK_BIN)
 if type -q {{ cmd }}; or test -n "$WORKTRUNK_BIN"
 
-    # Override {{ cmd }} command with file-based directive passing.
-    # Creates a temp file, passes path via WORKTRUNK_DIRECTIVE_FILE, evals it after.
-    # WORKTRUNK_BIN can override the binary path (for testing dev builds).
+    # Apply the directives {{ cmd }} wrote to a file (protocol 2): a version header,
+    # then NUL-terminated `<action> <value>` records. Values are used literally,
+    # so paths with quotes or `$` can't be misparsed. A file without the header is
+    # protocol 1 shell code from an older binary, so eval it.
     #
-    # Note: We use `eval (cat ... | string collect)` instead of `source` because:
+    # Note: We use `eval (... | string collect)` instead of `source` because:
     # 1. fish's `source` doesn't propagate `exit` to the parent function
-    # 2. `eval (cat ...)` without `string collect` splits on newlines, breaking multiline directives
+    # 2. `eval (...)` without `string collect` splits on newlines, breaking multiline commands
     # With `string collect`, `exit 42` properly exits the function with code 42.
+    function __{{ cmd }}_apply_directives --argument-names file
+        # split0 output is split on NUL, not newlines, by command substitution
+        set -l records (string split0 < $file)
+        if test "$records[1]" != "worktrunk-directives 2"
+            eval (cat $file | string collect)
+            return
+        end
+        set -l result 0
+        for record in $records[2..-1]
+            switch $record
+                case 'cd *'
+                    builtin cd -- (string sub -s 4 -- $record | string collect); or set result $status
+                case 'exec *'
+                    eval (string sub -s 6 -- $record | string collect)
+                    set result $status
+            end
+        end
+        return $result
+    end
+
+    # Override {{ cmd }} command with file-based directive passing.
+    # Creates a temp file, passes path via WORKTRUNK_DIRECTIVE_FILE, applies it after.
+    # WORKTRUNK_BIN can override the binary path (for testing dev builds).
     function {{ cmd }}
         set -l use_source false
         set -l args
@@ -21,25 +45,50 @@ if type -q {{ cmd }}; or test -n "$WORKTRUNK_BIN"
 
         test -n "$WORKTRUNK_BIN"; or set -l WORKTRUNK_BIN (type -P {{ cmd }})
         set -l directive_file (mktemp)
+{%- if on_switch.is_some() %}
+        set -l prev_dir $PWD
+{%- endif %}
 
         # --source: use cargo run (builds from source)
         if test $use_source = true
-            WORKTRUNK_DIRECTIVE_FILE=$directive_file cargo run --bin {{ cmd }} --quiet -- $args
+            WORKTRUNK_DIRECTIVE_FILE=$directive_file WORKTRUNK_DIRECTIVE_PROTOCOL=2 cargo run --bin {{ cmd }} --quiet -- $args
         else
-            WORKTRUNK_DIRECTIVE_FILE=$directive_file command $WORKTRUNK_BIN $args
+            WORKTRUNK_DIRECTIVE_FILE=$directive_file WORKTRUNK_DIRECTIVE_PROTOCOL=2 command $WORKTRUNK_BIN $args
         end
         set -l exit_code $status
 
         if test -s "$directive_file"
-            eval (cat "$directive_file" | string collect)
+            __{{ cmd }}_apply_directives $directive_file
+            set -l directive_status $status
             if test $exit_code -eq 0
-                set exit_code $status
+                set exit_code $directive_status
             end
         end
 
+{%- if let Some(on_switch) = on_switch %}
+
+        # Snippet from --on-switch: runs in this shell after {{ cmd }} changes directory
+        if test $exit_code -eq 0; and test "$PWD" != "$prev_dir"
+            {{ on_switch }}
+        end
+{%- endif %}
+
         rm -f "$directive_file"
         return $exit_code
     end
 
+{%- if with_abbr %}
+
+    # Abbreviations (from --with-abbr); they expand as typed, so history shows the full command
+    abbr --add {{ cmd }}s '{{ cmd }} switch'
+    abbr --add {{ cmd }}l '{{ cmd }} list'
+
+    # cd to an existing worktree without running switch hooks
+    function {{ cmd }}cd --description 'cd to a worktree'
+        {{ cmd }} switch --no-verify $argv
+    end
+    complete -c {{ cmd }}cd -w '{{ cmd }} switch'
+{%- endif %}
+
     # Completions are in ~/.config/fish/completions/wt.fish (installed by `wt config shell install`)
 end
@@ -17,26 +17,50 @@ if (Get-Command {{ cmd }} -ErrorAction SilentlyContinue) {
 
         $wtBin = (Get-Command {{ cmd }} -CommandType Application).Source
         $directiveFile = [System.IO.Path]::GetTempFileName()
+{%- if on_switch.is_some() %}
+        $prevDir = $PWD.Path
+{%- endif %}
 
         try {
             # Run wt with WORKTRUNK_DIRECTIVE_FILE env var
-            # WORKTRUNK_SHELL tells the binary to use PowerShell-compatible escaping
+            # WORKTRUNK_DIRECTIVE_PROTOCOL=2 asks for structured records (see below)
+            # WORKTRUNK_SHELL tells an older binary to use PowerShell-compatible escaping
             # stdout and stderr both go to console normally
             $env:WORKTRUNK_DIRECTIVE_FILE = $directiveFile
+            $env:WORKTRUNK_DIRECTIVE_PROTOCOL = "2"
             $env:WORKTRUNK_SHELL = "powershell"
             & $wtBin @Arguments
             $exitCode = $LASTEXITCODE
         }
         finally {
             Remove-Item Env:\WORKTRUNK_DIRECTIVE_FILE -ErrorAction SilentlyContinue
+            Remove-Item Env:\WORKTRUNK_DIRECTIVE_PROTOCOL -ErrorAction SilentlyContinue
             Remove-Item Env:\WORKTRUNK_SHELL -ErrorAction SilentlyContinue
         }
 
-        # Execute the directive script if it has content
+        # Apply the directives if there are any. Protocol 2 is a version header,
+        # then NUL-terminated `<action> <value>` records whose values are used
+        # literally; a file without the header is a script from an older binary.
         try {
             if ((Test-Path $directiveFile) -and (Get-Item $directiveFile).Length -gt 0) {
-                $script = Get-Content -Path $directiveFile -Raw
-                if ($script.Trim()) {
+                $script = [System.IO.File]::ReadAllText($directiveFile)
+                $records = $script.Split([char]0)
+                if ($records[0] -eq "worktrunk-directives 2") {
+                    foreach ($record in ($records | Select-Object -Skip 1)) {
+                        $action, $value = $record.Split([char[]]" ", 2)
+                        switch ($action) {
+                            "cd" { Set-Location -LiteralPath $value }
+                            "exec" {
+                                Invoke-Expression $value
+                                # If wt succeeded, use the command's exit code
+                                if ($exitCode -eq 0) {
+                                    $exitCode = $LASTEXITCODE
+                                }
+                            }
+                        }
+                    }
+                }
+                elseif ($script.Trim()) {
                     Invoke-Expression $script
                     # If wt succeeded, use the directive script's exit code
                     if ($exitCode -eq 0) {
@@ -49,6 +73,13 @@ if (Get-Command {{ cmd }} -ErrorAction SilentlyContinue) {
             # Cleanup even if Invoke-Expression throws
             Remove-Item $directiveFile -ErrorAction SilentlyContinue
         }
+{%- if let Some(on_switch) = on_switch %}
+
+        # Snippet from --on-switch: runs in this shell after {{ cmd }} changes directory
+        if ($exitCode -eq 0 -and $PWD.Path -ne $prevDir) {
+            {{ on_switch }}
+        }
+{%- endif %}
 
         # Propagate exit code so $? and $LASTEXITCODE are consistent for scripts/CI
         $global:LASTEXITCODE = $exitCode
@@ -1701,6 +1701,36 @@ exit 1
         self.mock_bin_path = Some(mock_bin);
     }
 
+    /// Setup a mock `curl` that returns `response` (for Gerrit CI queries)
+    ///
+    /// The curl config worktrunk sends on stdin is saved to `curl_request` in
+    /// the mock bin directory, returned here, so tests can check the request.
+    #[cfg(unix)]
+    pub fn setup_mock_curl(&mut self, response: &str) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mock_bin = self.temp_dir.path().join("mock-bin");
+        std::fs::create_dir_all(&mock_bin).unwrap();
+        let response_file = mock_bin.join("curl_response");
+        let request_file = mock_bin.join("curl_request");
+        std::fs::write(&response_file, response).unwrap();
+
+        let curl_script = mock_bin.join("curl");
+        std::fs::write(
+            &curl_script,
+            format!(
+                "#!/bin/sh\n# Mock curl: save the request config, print the response\ncat > \"{}\"\ncat \"{}\"\n",
+                request_file.display(),
+                response_file.display(),
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&curl_script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        self.mock_bin_path = Some(mock_bin);
+        request_file
+    }
+
     /// Configure a command to use mock gh/glab commands
     ///
     /// Must call `setup_mock_gh()` first. Prepends the mock bin directory to PATH
@@ -1740,6 +1770,45 @@ exit 1
         }
     }
 
+    /// Configure SSH commit signing with a fresh key, trusted via an allowed signers file,
+    /// and sign every commit (`commit.gpgsign`). Returns the key's fingerprint.
+    pub fn setup_ssh_signing(&self) -> String {
+        let dir = self.root_path().parent().unwrap();
+        let key = dir.join("signing-key");
+        let status = std::process::Command::new("ssh-keygen")
+            .args(["-q", "-t", "ed25519", "-N", "", "-f"])
+            .arg(&key)
+            .status()
+            .unwrap();
+        assert!(status.success());
+        let public_key = std::fs::read_to_string(key.with_extension("pub")).unwrap();
+        let allowed_signers = dir.join("allowed-signers");
+        std::fs::write(&allowed_signers, format!("test@example.com {public_key}")).unwrap();
+        self.run_git(&["config", "gpg.format", "ssh"]);
+        self.run_git(&[
+            "config",
+            "user.signingkey",
+            key.with_extension("pub").to_str().unwrap(),
+        ]);
+        self.run_git(&[
+            "config",
+            "gpg.ssh.allowedSignersFile",
+            allowed_signers.to_str().unwrap(),
+        ]);
+        self.run_git(&["config", "commit.gpgsign", "true"]);
+
+        let output = std::process::Command::new("ssh-keygen")
+            .args(["-l", "-f"])
+            .arg(key.with_extension("pub"))
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout)
+            .split_whitespace()
+            .nth(1)
+            .unwrap()
+            .to_string()
+    }
+
     /// Set a marker for a branch.
     ///
     /// Markers are stored as JSON with a timestamp in `worktrunk.state.<branch>.marker`.
@@ -2207,6 +2276,23 @@ pub fn make_snapshot_cmd(
     make_snapshot_cmd_with_global_flags(repo, subcommand, args, cwd, &[])
 }
 
+/// Branch names listed by `wt <args>` (which must ask for JSON) with `env` set
+pub fn listed_branches(repo: &TestRepo, args: &[&str], env: &[(&str, &str)]) -> Vec<String> {
+    let mut cmd = make_snapshot_cmd(repo, args[0], &args[1..], None);
+    cmd.envs(env.iter().copied());
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    items
+        .iter()
+        .filter_map(|item| item["branch"].as_str().map(String::from))
+        .collect()
+}
+
 /// Resolve the git common directory (shared across all worktrees)
 ///
 /// This is where centralized logs and other shared data are stored.
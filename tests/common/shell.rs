@@ -39,6 +39,11 @@ static AVAILABLE_SHELLS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
         shells.insert("powershell");
     }
 
+    if check_shell_available("nu", "--version") {
+        shells.insert("nu");
+        shells.insert("nushell");
+    }
+
     shells
 });
 
@@ -197,6 +202,16 @@ pub fn path_export_syntax(shell: &str, bin_path: &str) -> String {
     }
 }
 
+/// Print `marker` followed by the shell's current directory.
+///
+/// Nushell has no `$PWD` variable, so it needs its own form.
+pub fn print_pwd_syntax(shell: &str, marker: &str) -> String {
+    match shell {
+        "nushell" => format!(r#"print $"{marker} ($env.PWD)""#),
+        _ => format!(r#"echo "{marker} $PWD""#),
+    }
+}
+
 /// Helper that returns the `wt` binary directory for PATH injection.
 pub fn wt_bin_dir() -> String {
     get_cargo_bin("wt")
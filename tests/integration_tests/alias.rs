@@ -0,0 +1,190 @@
+//! Integration tests for `[alias]` command aliases
+
+use crate::common::{
+    TestRepo, listed_branches, make_snapshot_cmd, repo, setup_snapshot_settings, wt_command,
+};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+#[rstest]
+fn test_alias_expands_with_extra_args(mut repo: TestRepo) {
+    repo.write_test_config(
+        r#"
+[alias]
+lg = "log"
+"#,
+    );
+    repo.add_feature();
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        // Arguments after the alias are appended to its expansion
+        let mut cmd = make_snapshot_cmd(&repo, "lg", &["--all"], None);
+        assert_cmd_snapshot!("alias_expands_with_extra_args", cmd);
+    });
+}
+
+#[rstest]
+fn test_alias_after_global_flags(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+    repo.write_test_config(
+        r#"
+[alias]
+ls = "list --branches"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["--no-hooks", "-v", "ls", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(items.len(), 2);
+}
+
+#[rstest]
+fn test_alias_builtin_takes_precedence(repo: TestRepo) {
+    repo.write_test_config(
+        r#"
+[alias]
+log = "list --branches"
+"#,
+    );
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    let output = cmd
+        .args(["log"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("has no commits ahead"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_alias_invalid_expansion(repo: TestRepo) {
+    repo.write_test_config(
+        r#"
+[alias]
+broken = "list 'unterminated"
+"#,
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "broken", &[], None);
+        assert_cmd_snapshot!("alias_invalid_expansion", cmd);
+    });
+}
+
+#[rstest]
+fn test_alias_completes_as_subcommand(repo: TestRepo) {
+    repo.write_test_config(
+        r#"
+[alias]
+lg = "log --all"
+"#,
+    );
+
+    let output = repo.completion_cmd(&["wt", "l"]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let suggestions: Vec<&str> = stdout.lines().map(str::trim).collect();
+    assert!(suggestions.contains(&"lg"), "suggestions: {suggestions:?}");
+    assert!(
+        suggestions.contains(&"list"),
+        "suggestions: {suggestions:?}"
+    );
+}
+
+#[rstest]
+fn test_alias_completes_expanded_command(repo: TestRepo) {
+    repo.write_test_config(
+        r#"
+[alias]
+ls = "list --branches"
+"#,
+    );
+
+    let output = repo.completion_cmd(&["wt", "ls", "--"]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("--remotes"), "stdout: {stdout}");
+}
+
+#[rstest]
+fn test_defaults_inserted_after_subcommand(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+    repo.write_test_config(
+        r#"
+[alias]
+ls = "list"
+
+[defaults]
+list = ["--branches", "--format", "table"]
+"#,
+    );
+
+    // Command-line arguments come after the defaults and win
+    assert_eq!(
+        listed_branches(&repo, &["list", "--format", "json"], &[]),
+        ["main", "feature"]
+    );
+    // Aliases pick up the defaults of the command they expand to
+    assert_eq!(
+        listed_branches(&repo, &["ls", "--format=json"], &[]),
+        ["main", "feature"]
+    );
+}
+
+#[rstest]
+fn test_defaults_only_from_untracked_repo_config(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+    let defaults = "[defaults]\nlist = [\"--branches\"]\n";
+
+    // A tracked file could slip flags into everyone's commands
+    std::fs::write(repo.root_path().join(".worktrunk.toml"), defaults).unwrap();
+    let output = make_snapshot_cmd(&repo, "list", &[], None)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("defaults can't be set here"), "{stderr}");
+
+    std::fs::remove_file(repo.root_path().join(".worktrunk.toml")).unwrap();
+    std::fs::write(repo.root_path().join(".git/worktrunk.toml"), defaults).unwrap();
+    assert_eq!(
+        listed_branches(&repo, &["list", "--format", "json"], &[]),
+        ["main", "feature"]
+    );
+}
+
+#[rstest]
+fn test_defaults_for_nested_subcommand(repo: TestRepo) {
+    repo.write_test_config(
+        r#"
+[defaults]
+config = "--this-flag-does-not-exist"
+"config state get" = "--format json"
+"#,
+    );
+
+    // Only the full command path gets its defaults
+    let output = make_snapshot_cmd(&repo, "config", &["state", "get"], None)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap();
+}
@@ -620,3 +620,51 @@ fn test_select_fails_in_non_tty(repo: TestRepo) {
         assert_cmd_snapshot!("select_fails_in_non_tty", cmd);
     });
 }
+
+/// `wt --yes` (and `WORKTRUNK_ASSUME_YES`) approve for every command without
+/// remembering the approval
+#[rstest]
+fn test_global_yes_approves_without_saving(repo: TestRepo) {
+    repo.write_project_config(r#"post-create = "echo 'test command' > output.txt""#);
+    repo.commit("Add config");
+
+    let output = repo
+        .wt_command()
+        .args(["--yes", "switch", "--create", "global-yes"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "env-yes"])
+        .env("WORKTRUNK_ASSUME_YES", "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert!(
+        repo.root_path()
+            .with_file_name("repo.env-yes")
+            .join("output.txt")
+            .exists()
+    );
+
+    // Nothing was saved, so without it the approval is needed again
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "no-yes"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+#[rstest]
+fn test_global_yes_fails_instead_of_asking(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["--yes", "select"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--yes"));
+}
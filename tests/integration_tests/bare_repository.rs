@@ -771,3 +771,52 @@ fn test_bare_repo_bootstrap_first_worktree() {
     );
     assert!(stdout.contains("main"), "Should list main worktree");
 }
+
+/// `wt clone --bare` sets up the bare layout: repo in `.git`, default branch
+/// checked out beside it, remote branches tracked, new worktrees beside `.git`
+#[rstest]
+fn test_clone_bare_layout(repo: TestRepo) {
+    let dest = tempfile::tempdir().unwrap();
+    let dest_root = canonicalize(dest.path()).unwrap();
+    let source = repo.root_path().to_str().unwrap();
+
+    let (directive_path, _guard) = directive_file();
+    let mut cmd = repo.wt_command();
+    configure_directive_file(&mut cmd, &directive_path);
+    cmd.args(["clone", "--bare", source, "project"])
+        .current_dir(&dest_root);
+    let output = cmd.output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "wt clone failed: {stderr}");
+
+    let project = dest_root.join("project");
+    let main_worktree = project.join("main");
+    assert!(main_worktree.join(".git").is_file(), "stderr: {stderr}");
+    assert!(project.join(".git/worktrunk.toml").exists());
+    let directive = fs::read_to_string(&directive_path).unwrap();
+    assert!(
+        directive.contains(main_worktree.to_str().unwrap()),
+        "{directive}"
+    );
+
+    // Remote branches are tracked, unlike a plain bare clone
+    let remote_branches = repo
+        .git_command()
+        .args(["branch", "-r"])
+        .current_dir(&main_worktree)
+        .output()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&remote_branches.stdout).contains("origin/main"));
+
+    // New worktrees land beside .git
+    let mut cmd = repo.wt_command();
+    cmd.args(["switch", "--create", "feature"])
+        .current_dir(&main_worktree);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(project.join("feature").exists());
+}
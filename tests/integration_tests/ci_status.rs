@@ -513,3 +513,110 @@ fn test_list_full_filters_by_repo_owner(mut repo: TestRepo) {
         assert_cmd_snapshot!(cmd);
     });
 }
+
+/// Add a GitHub remote and a `feature` worktree with a passing PR
+fn setup_github_pr_passed(repo: &mut TestRepo) {
+    repo.run_git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://github.com/test-owner/test-repo.git",
+    ]);
+    repo.add_worktree("feature");
+    let head_sha = get_branch_sha(repo, "feature");
+    let pr_json = format!(
+        r#"[{{
+        "headRefOid": "{head_sha}",
+        "mergeStateStatus": "CLEAN",
+        "statusCheckRollup": [
+            {{"status": "COMPLETED", "conclusion": "SUCCESS"}}
+        ],
+        "url": "https://github.com/test-owner/test-repo/pull/1",
+        "headRepositoryOwner": {{"login": "test-owner"}}
+    }}]"#
+    );
+    repo.setup_mock_gh_with_ci_data(&pr_json, "[]");
+}
+
+/// `--ci` fetches CI status without the rest of `--full`
+#[rstest]
+fn test_list_ci_flag(mut repo: TestRepo) {
+    setup_github_pr_passed(&mut repo);
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", &["--ci"], None);
+        repo.configure_mock_commands(&mut cmd);
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
+/// `ci.enabled` turns on `--ci` by default
+#[rstest]
+fn test_list_ci_enabled_in_config(mut repo: TestRepo) {
+    setup_github_pr_passed(&mut repo);
+    repo.write_test_config("[ci]\nenabled = true\n");
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", &[], None);
+        repo.configure_mock_commands(&mut cmd);
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
+/// `ci.provider = "none"` skips CI lookups, even with `--full`
+#[rstest]
+fn test_list_full_ci_provider_none(mut repo: TestRepo) {
+    setup_github_pr_passed(&mut repo);
+    repo.write_test_config("[ci]\nprovider = \"none\"\n");
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", &["--full"], None);
+        repo.configure_mock_commands(&mut cmd);
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
+/// Gerrit status comes from the change's `Verified` label, queried with curl
+#[rstest]
+fn test_list_ci_gerrit(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    let response = r#")]}'
+[{"project": "tools/app", "_number": 4242, "labels": {"Verified": {"rejected": {"_account_id": 7}}}}]"#;
+    let request_file = repo.setup_mock_curl(response);
+    repo.write_test_config(
+        "[ci]\nprovider = \"gerrit\"\napi-url = \"https://review.example.com/\"\ntoken-env = \"TEST_GERRIT_TOKEN\"\n",
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", &["--ci", "--format=json"], None);
+        repo.configure_mock_commands(&mut cmd);
+        cmd.env("TEST_GERRIT_TOKEN", "bot:s3cret");
+        let output = cmd.output().unwrap();
+        assert!(output.status.success(), "{output:?}");
+
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let feature = json
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|item| item["branch"] == "feature")
+            .unwrap();
+        assert_eq!(feature["ci"]["status"], "failed");
+        assert_eq!(
+            feature["ci"]["url"],
+            "https://review.example.com/c/tools/app/+/4242"
+        );
+
+        // The token goes to curl on stdin, using the authenticated endpoint
+        let request = std::fs::read_to_string(&request_file).unwrap();
+        assert!(
+            request.contains("url = \"https://review.example.com/a/changes/?q=commit:"),
+            "{request}"
+        );
+        assert!(request.contains("user = \"bot:s3cret\""), "{request}");
+    });
+}
@@ -393,16 +393,16 @@ fn test_complete_merge_shows_branches(mut repo: TestRepo) {
     // Create another branch without worktree
     repo.run_git(&["branch", "hotfix/bug"]);
 
-    // Test completion for merge (should show ALL branches, including those with worktrees)
+    // Test completion for merge (only branches checked out in a worktree)
     let output = repo.completion_cmd(&["wt", "merge", ""]).output().unwrap();
 
     assert!(output.status.success());
     let stdout = String::from_utf8_lossy(&output.stdout);
     let branches: Vec<&str> = stdout.lines().collect();
 
-    // Should include both branches (merge shows all)
+    assert!(branches.iter().any(|b| b.contains("main")));
     assert!(branches.iter().any(|b| b.contains("feature/new")));
-    assert!(branches.iter().any(|b| b.contains("hotfix/bug")));
+    assert!(!branches.iter().any(|b| b.contains("hotfix/bug")));
 }
 
 #[rstest]
@@ -555,7 +555,7 @@ fn test_complete_switch_base_flag_after_branch(repo: TestRepo) {
 }
 
 #[rstest]
-fn test_complete_remove_excludes_remote_only_branches(mut repo: TestRepo) {
+fn test_complete_remove_shows_worktrees_only(mut repo: TestRepo) {
     repo.commit("initial");
 
     // Create worktree (creates "feature/new" branch)
@@ -564,7 +564,6 @@ fn test_complete_remove_excludes_remote_only_branches(mut repo: TestRepo) {
     // Create another local branch without worktree
     repo.run_git(&["branch", "hotfix/bug"]);
 
-    // Test completion for remove (should show local branches, exclude remote-only)
     let output = repo.completion_cmd(&["wt", "remove", ""]).output().unwrap();
 
     assert!(output.status.success());
@@ -573,8 +572,42 @@ fn test_complete_remove_excludes_remote_only_branches(mut repo: TestRepo) {
 
     // Should include branches with worktrees
     assert!(branches.iter().any(|b| b.contains("feature/new")));
-    // Should include local branches without worktrees (can still delete the branch)
-    assert!(branches.iter().any(|b| b.contains("hotfix/bug")));
+    // Branches without worktrees have nothing to remove
+    assert!(!branches.iter().any(|b| b.contains("hotfix/bug")));
+}
+
+#[rstest]
+fn test_complete_remove_worktree_paths(mut repo: TestRepo) {
+    repo.commit("initial");
+    let feature = repo.add_worktree("feature");
+
+    // A word starting with `.` completes paths relative to the current directory
+    let output = repo
+        .completion_cmd_for_shell(&["wt", "remove", "../"], "fish")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let expected = format!(
+        "../{}\tfeature",
+        feature.file_name().unwrap().to_string_lossy()
+    );
+    assert!(
+        stdout.lines().any(|line| line == expected),
+        "expected {expected:?} in:\n{stdout}"
+    );
+
+    // ...and `/` absolute paths
+    let prefix = feature.parent().unwrap().to_string_lossy().to_string() + "/";
+    let output = repo
+        .completion_cmd(&["wt", "remove", &prefix])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.lines().any(|line| line == feature.to_string_lossy()),
+        "{stdout}"
+    );
 }
 
 #[rstest]
@@ -610,18 +643,22 @@ fn test_complete_hook_subcommands(repo: TestRepo) {
     let subcommands = value_suggestions(&stdout);
     // Hook types and commands
     assert!(subcommands.contains(&"show"), "Missing show");
+    assert!(subcommands.contains(&"status"), "Missing status");
     assert!(subcommands.contains(&"post-create"), "Missing post-create");
     assert!(subcommands.contains(&"post-start"), "Missing post-start");
+    assert!(subcommands.contains(&"pre-switch"), "Missing pre-switch");
     assert!(subcommands.contains(&"post-switch"), "Missing post-switch");
     assert!(subcommands.contains(&"pre-commit"), "Missing pre-commit");
     assert!(subcommands.contains(&"pre-merge"), "Missing pre-merge");
     assert!(subcommands.contains(&"post-merge"), "Missing post-merge");
+    assert!(subcommands.contains(&"pre-push"), "Missing pre-push");
     assert!(subcommands.contains(&"pre-remove"), "Missing pre-remove");
+    assert!(subcommands.contains(&"post-remove"), "Missing post-remove");
     assert!(subcommands.contains(&"approvals"), "Missing approvals");
     assert_eq!(
         subcommands.len(),
-        9,
-        "Should have exactly 9 hook subcommands"
+        13,
+        "Should have exactly 13 hook subcommands"
     );
 
     // Test 2: Partial input "po" - filters to post-* subcommands
@@ -633,6 +670,7 @@ fn test_complete_hook_subcommands(repo: TestRepo) {
     assert!(subcommands.contains(&"post-start"));
     assert!(subcommands.contains(&"post-switch"));
     assert!(subcommands.contains(&"post-merge"));
+    assert!(subcommands.contains(&"post-remove"));
     assert!(!subcommands.contains(&"pre-commit"));
     assert!(!subcommands.contains(&"pre-merge"));
 }
@@ -652,8 +690,8 @@ fn test_complete_init_shell_all_variations(repo: TestRepo) {
     assert!(shells.contains(&"bash"));
     assert!(shells.contains(&"fish"));
     assert!(shells.contains(&"zsh"));
+    assert!(shells.contains(&"nushell"));
     assert!(!shells.contains(&"elvish"));
-    assert!(!shells.contains(&"nushell"));
 
     // Test 2: Partial input "fi" - filters to fish
     let output = repo
@@ -808,10 +846,10 @@ fn test_complete_switch_completing_execute_value(repo: TestRepo) {
 }
 
 #[rstest]
-fn test_complete_merge_with_flags(repo: TestRepo) {
+fn test_complete_merge_with_flags(mut repo: TestRepo) {
     repo.commit("initial");
 
-    repo.run_git(&["branch", "hotfix"]);
+    repo.add_worktree("hotfix");
 
     // Test: wt merge --no-remove --yes <cursor>
     // Should complete branches for positional (boolean flags don't consume arguments)
@@ -1143,3 +1181,94 @@ fn test_complete_single_dash_shows_both_short_and_long_flags(repo: TestRepo) {
         );
     }
 }
+
+/// zsh's `_describe` treats an unescaped colon as the value/description separator
+#[rstest]
+fn test_complete_zsh_escapes_colons(repo: TestRepo) {
+    repo.write_project_config(
+        r#"[post-create]
+"db:migrate" = "echo migrate"
+"#,
+    );
+    repo.commit("Add config");
+
+    let output = repo
+        .completion_cmd_for_shell(&["wt", "hook", "post-create", ""], "zsh")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.lines().any(|line| line == r"db\:migrate"),
+        "colon should be escaped for zsh, got:\n{stdout}"
+    );
+
+    let output = repo
+        .completion_cmd_for_shell(&["wt", "hook", "post-create", ""], "fish")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.lines().any(|line| line == "db:migrate"), "{stdout}");
+}
+
+/// Elvish and xonsh completions call back with `COMPLETE=<shell>` and get plain candidates
+#[rstest]
+fn test_complete_elvish_and_xonsh(repo: TestRepo) {
+    repo.commit("initial");
+    repo.run_git(&["branch", "feature"]);
+
+    for shell in ["elvish", "xonsh"] {
+        let output = wt_command()
+            .args(["config", "shell", "completions", shell])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{shell}: script failed");
+        let script = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            script.contains(&format!("COMPLETE={shell}"))
+                || script.contains(&format!("COMPLETE=\"{shell}\"")),
+            "{script}"
+        );
+
+        let output = repo
+            .completion_cmd_for_shell(&["wt", "switch", "fe"], shell)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{shell}: completion failed");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(value_suggestions(&stdout), ["feature"], "{shell}");
+    }
+}
+
+#[rstest]
+fn test_complete_config_set(repo: TestRepo) {
+    let output = repo
+        .completion_cmd(&["wt", "config", "set", "sync."])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(value_suggestions(&stdout), ["sync.strategy"]);
+
+    let output = repo
+        .completion_cmd(&["wt", "config", "set", "sync.strategy", ""])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(value_suggestions(&stdout), ["rebase", "merge"]);
+
+    let output = repo
+        .completion_cmd(&["wt", "config", "set", "list.full", "t"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(value_suggestions(&stdout), ["true"]);
+
+    // Free-form values have nothing to offer
+    let output = repo
+        .completion_cmd(&["wt", "config", "set", "worktree-path", ""])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(value_suggestions(&stdout).is_empty());
+}
@@ -0,0 +1,113 @@
+//! Integration tests for `WORKTRUNK_*` config overrides and explicit config files
+
+use crate::common::{TestRepo, listed_branches, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+const LIST_JSON: &[&str] = &["list", "--format", "json"];
+
+#[rstest]
+fn test_env_overrides_config_files(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+    repo.write_test_config("[list]\nbranches = false\n");
+    std::fs::write(
+        repo.root_path().join(".git/worktrunk.toml"),
+        "[list]\nbranches = false\n",
+    )
+    .unwrap();
+    assert_eq!(listed_branches(&repo, LIST_JSON, &[]), ["main"]);
+
+    // Both spellings override every file
+    assert_eq!(
+        listed_branches(&repo, LIST_JSON, &[("WORKTRUNK_LIST_BRANCHES", "true")]),
+        ["main", "feature"]
+    );
+    assert_eq!(
+        listed_branches(&repo, LIST_JSON, &[("WORKTRUNK_LIST__BRANCHES", "1")]),
+        ["main", "feature"]
+    );
+}
+
+#[rstest]
+fn test_env_override_invalid_bool(repo: TestRepo) {
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", &[], None);
+        cmd.env("WORKTRUNK_LIST_BRANCHES", "sometimes");
+        assert_cmd_snapshot!("env_override_invalid_bool", cmd);
+    });
+}
+
+#[rstest]
+fn test_env_override_list_columns(repo: TestRepo) {
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", &[], None);
+        cmd.env("WORKTRUNK_LIST_COLUMNS", "branch,age");
+        assert_cmd_snapshot!("env_override_list_columns", cmd);
+    });
+}
+
+#[rstest]
+fn test_config_show_lists_env_overrides(repo: TestRepo) {
+    let mut cmd = make_snapshot_cmd(&repo, "config", &["show"], None);
+    cmd.env("WORKTRUNK_MERGE_SQUASH", "false")
+        .env("WORKTRUNK_MERGE__SQAUSH", "false");
+    let output = cmd.output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let shown = format!("{stdout}{stderr}");
+    assert!(shown.contains("ENVIRONMENT"), "{shown}");
+    assert!(shown.contains("WORKTRUNK_MERGE_SQUASH=false"), "{shown}");
+    assert!(
+        shown.contains("WORKTRUNK_MERGE__SQAUSH") && shown.contains("doesn't match a config key"),
+        "{shown}"
+    );
+}
+
+#[rstest]
+fn test_explicit_config_file(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+    let explicit = repo.root_path().join("ci-config.toml");
+    std::fs::write(&explicit, "[list]\nbranches = true\n").unwrap();
+    let explicit = explicit.to_str().unwrap();
+
+    // The flag takes precedence over WORKTRUNK_CONFIG_PATH
+    assert_eq!(listed_branches(&repo, LIST_JSON, &[]), ["main"]);
+    let output = make_snapshot_cmd(
+        &repo,
+        "list",
+        &["--format", "json", "--config", explicit],
+        None,
+    )
+    .output()
+    .unwrap();
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(items.len(), 2);
+}
+
+#[rstest]
+fn test_explicit_config_file_missing(repo: TestRepo) {
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", &["--config", "missing.toml"], None);
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
+#[rstest]
+fn test_config_show_explicit_config_file(repo: TestRepo) {
+    let explicit = repo.root_path().join("ci-config.toml");
+    std::fs::write(&explicit, "[list]\nbranches = true\n").unwrap();
+
+    let mut cmd = make_snapshot_cmd(
+        &repo,
+        "config",
+        &["show", "--config", explicit.to_str().unwrap()],
+        None,
+    );
+    let output = cmd.output().unwrap();
+    let shown = String::from_utf8_lossy(&output.stderr);
+    assert!(shown.contains("ci-config.toml"), "stderr: {shown}");
+    assert!(shown.contains("branches = "), "stderr: {shown}");
+}
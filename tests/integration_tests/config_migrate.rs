@@ -0,0 +1,93 @@
+//! Integration tests for user config versioning (`wt config migrate`)
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+const OLD_CONFIG: &str = r#"# Written for an older release
+[post-create-command]
+marker = "echo created > created.txt"
+
+[profiles.ci]
+pre-merge-command = "cargo test"
+"#;
+
+fn backup(repo: &TestRepo) -> std::path::PathBuf {
+    let mut name = repo.test_config_path().as_os_str().to_owned();
+    name.push(".v0.bak");
+    name.into()
+}
+
+#[rstest]
+fn test_config_migrate_dry_run(repo: TestRepo) {
+    repo.write_test_config(OLD_CONFIG);
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(
+            &repo,
+            "config",
+            &["migrate", "--dry-run"],
+            None
+        ));
+    });
+    // Nothing written
+    let contents = std::fs::read_to_string(repo.test_config_path()).unwrap();
+    assert_eq!(contents, OLD_CONFIG);
+    assert!(!backup(&repo).exists());
+}
+
+#[rstest]
+fn test_config_migrate(repo: TestRepo) {
+    repo.write_test_config(OLD_CONFIG);
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(&repo, "config", &["migrate"], None));
+    });
+
+    let contents = std::fs::read_to_string(repo.test_config_path()).unwrap();
+    insta::assert_snapshot!(contents, @r#"
+    version = 1
+    # Written for an older release
+    [post-create]
+    marker = "echo created > created.txt"
+
+    [profiles.ci]
+    pre-merge = "cargo test"
+    "#);
+    assert_eq!(std::fs::read_to_string(backup(&repo)).unwrap(), OLD_CONFIG);
+
+    // A second run has nothing to do
+    let output = make_snapshot_cmd(&repo, "config", &["migrate"], None)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("is up to date (version 1)"), "{stderr}");
+}
+
+#[rstest]
+fn test_config_migrated_before_command_runs(repo: TestRepo) {
+    repo.write_test_config(OLD_CONFIG);
+    let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature"], None)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Migrated"), "{stderr}");
+
+    // The renamed hook ran in the new worktree
+    let worktree = repo.root_path().parent().unwrap().join("repo.feature");
+    assert!(worktree.join("created.txt").exists(), "{stderr}");
+    assert!(backup(&repo).exists());
+}
+
+#[rstest]
+fn test_unversioned_config_left_alone(repo: TestRepo) {
+    repo.write_test_config("[list]\nbranches = true\n");
+    let output = make_snapshot_cmd(&repo, "list", &[], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let contents = std::fs::read_to_string(repo.test_config_path()).unwrap();
+    assert_eq!(contents, "[list]\nbranches = true\n");
+    assert!(!backup(&repo).exists());
+}
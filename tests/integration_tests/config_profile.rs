@@ -0,0 +1,127 @@
+//! Integration tests for config overlays: profiles (`[profiles.<name>]`) and
+//! conditional sections (`[if."<remote pattern>"]`)
+
+use crate::common::{TestRepo, listed_branches, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+const CONFIG: &str = r#"
+[list]
+branches = false
+
+[profiles.full.list]
+branches = true
+
+[profiles.full.alias]
+everything = "list --remotes"
+"#;
+
+#[rstest]
+fn test_profile_selected_by_flag_or_env(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+    repo.write_test_config(CONFIG);
+    assert_eq!(
+        listed_branches(&repo, &["list", "--format", "json"], &[]),
+        ["main"]
+    );
+    assert_eq!(
+        listed_branches(
+            &repo,
+            &["list", "--profile", "full", "--format", "json"],
+            &[]
+        ),
+        ["main", "feature"]
+    );
+    assert_eq!(
+        listed_branches(
+            &repo,
+            &["list", "--format", "json"],
+            &[("WORKTRUNK_PROFILE", "full")]
+        ),
+        ["main", "feature"]
+    );
+}
+
+#[rstest]
+fn test_profile_overridden_by_env(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+    repo.write_test_config(CONFIG);
+    assert_eq!(
+        listed_branches(
+            &repo,
+            &["list", "--profile", "full", "--format", "json"],
+            &[("WORKTRUNK_LIST_BRANCHES", "false")]
+        ),
+        ["main"]
+    );
+}
+
+#[rstest]
+fn test_profile_aliases(repo: TestRepo) {
+    repo.write_test_config(CONFIG);
+    let mut cmd = make_snapshot_cmd(&repo, "--profile", &["full", "everything"], None);
+    cmd.args(["--format", "json"]);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[rstest]
+fn test_profile_unknown(repo: TestRepo) {
+    repo.write_test_config(CONFIG);
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", &["--profile", "home"], None);
+        assert_cmd_snapshot!("profile_unknown", cmd);
+    });
+}
+
+const CONDITIONAL_CONFIG: &str = r#"
+[list]
+branches = false
+
+[if."github.com/acme/*".list]
+branches = true
+"#;
+
+#[rstest]
+fn test_conditional_section_matches_remote(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+    repo.write_test_config(CONDITIONAL_CONFIG);
+    repo.run_git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://github.com/other/api.git",
+    ]);
+    assert_eq!(
+        listed_branches(&repo, &["list", "--format", "json"], &[]),
+        ["main"]
+    );
+
+    repo.run_git(&["remote", "set-url", "origin", "git@github.com:acme/api.git"]);
+    assert_eq!(
+        listed_branches(&repo, &["list", "--format", "json"], &[]),
+        ["main", "feature"]
+    );
+}
+
+#[rstest]
+fn test_profile_overrides_conditional_section(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+    repo.write_test_config(&format!(
+        "{CONDITIONAL_CONFIG}\n[profiles.quiet.list]\nbranches = false\n"
+    ));
+    repo.run_git(&["remote", "add", "origin", "https://github.com/acme/api.git"]);
+    assert_eq!(
+        listed_branches(
+            &repo,
+            &["list", "--profile", "quiet", "--format", "json"],
+            &[]
+        ),
+        ["main"]
+    );
+}
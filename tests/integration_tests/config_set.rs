@@ -0,0 +1,48 @@
+//! Integration tests for `wt config set`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+#[rstest]
+fn test_config_set(repo: TestRepo) {
+    repo.write_test_config("# Personal settings\n[list]\nfull = true  # always\n");
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(
+            &repo,
+            "config",
+            &["set", "sync.strategy", "merge"],
+            None
+        ));
+    });
+    make_snapshot_cmd(&repo, "config", &["set", "list.branches", "true"], None)
+        .output()
+        .unwrap();
+
+    let contents = std::fs::read_to_string(repo.test_config_path()).unwrap();
+    insta::assert_snapshot!(contents, @r#"
+    # Personal settings
+    [list]
+    full = true  # always
+    branches = true
+
+    [sync]
+    strategy = "merge"
+    "#);
+}
+
+#[rstest]
+#[case::unknown_key(&["set", "list.fulll", "true"])]
+#[case::wrong_type(&["set", "list.full", "yes"])]
+#[case::invalid_value(&["set", "select.height", "500%"])]
+fn test_config_set_rejects(repo: TestRepo, #[case] args: &[&str]) {
+    repo.write_test_config("[list]\nfull = true\n");
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(&repo, "config", args, None));
+    });
+    // Nothing written
+    let contents = std::fs::read_to_string(repo.test_config_path()).unwrap();
+    assert_eq!(contents, "[list]\nfull = true\n");
+}
@@ -414,6 +414,120 @@ fn test_state_clear_marker_all_empty(repo: TestRepo) {
     assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[2m○[22m No markers to clear");
 }
 
+#[rstest]
+fn test_state_marker_worktree_config(mut repo: TestRepo) {
+    repo.run_git(&["config", "extensions.worktreeConfig", "true"]);
+    let feature_path = repo.add_worktree("feature");
+
+    let output = wt_state_cmd(&repo, "marker", "set", &["🔧", "--branch", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Stored in the feature worktree's own config, not the shared one
+    let output = repo
+        .git_command()
+        .current_dir(&feature_path)
+        .args([
+            "config",
+            "--worktree",
+            "--get",
+            "worktrunk.state.feature.marker",
+        ])
+        .output()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&output.stdout).contains("🔧"));
+    let output = repo
+        .git_command()
+        .args([
+            "config",
+            "--local",
+            "--get",
+            "worktrunk.state.feature.marker",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    // Read back from another worktree
+    let output = wt_state_cmd(&repo, "marker", "get", &["--branch", "feature"])
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "🔧");
+
+    // A branch without a worktree keeps shared config
+    repo.create_branch("bugfix");
+    let output = wt_state_cmd(&repo, "marker", "set", &["🐛", "--branch", "bugfix"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let output = wt_state_cmd(&repo, "marker", "clear", &["--all"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[32m✓[39m [32mCleared [1m2[22m markers[39m");
+    let output = wt_state_cmd(&repo, "marker", "get", &["--branch", "feature"])
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "");
+}
+
+#[rstest]
+fn test_state_marker_worktree_config_branch_switched(mut repo: TestRepo) {
+    repo.run_git(&["config", "extensions.worktreeConfig", "true"]);
+    let feature_path = repo.add_worktree("feature");
+
+    let output = wt_state_cmd(&repo, "marker", "set", &["🔧", "--branch", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // The worktree moves to another branch outside wt
+    repo.run_git_in(&feature_path, &["switch", "--quiet", "-c", "other"]);
+
+    // Its config no longer speaks for `feature`, from here or from inside it
+    let output = wt_state_cmd(&repo, "marker", "get", &["--branch", "feature"])
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "");
+    let output = wt_state_cmd(&repo, "marker", "get", &["--branch", "feature"])
+        .current_dir(&feature_path)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "");
+
+    // Listing leaves the stale entry out without touching it
+    let output = wt_state_get_cmd(&repo)
+        .args(["--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("🔧"));
+    let stale_marker = || {
+        repo.git_command()
+            .current_dir(&feature_path)
+            .args([
+                "config",
+                "--worktree",
+                "--get",
+                "worktrunk.state.feature.marker",
+            ])
+            .output()
+            .unwrap()
+            .status
+            .success()
+    };
+    assert!(stale_marker());
+
+    // Clearing every marker removes it
+    let output = wt_state_cmd(&repo, "marker", "clear", &["--all"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(!stale_marker());
+}
+
 // ============================================================================
 // logs
 // ============================================================================
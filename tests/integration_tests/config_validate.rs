@@ -0,0 +1,76 @@
+//! Integration tests for `wt config validate` and config load diagnostics
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+#[rstest]
+fn test_config_validate_valid(repo: TestRepo) {
+    repo.write_test_config("worktree-path = \".worktrees/{{ branch | sanitize }}\"\n");
+    repo.write_project_config("[post-create]\ninstall = \"npm ci\"\n");
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "config", &["validate"], None);
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
+#[rstest]
+fn test_config_validate_user_config_problems(repo: TestRepo) {
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch | sanitise }}"
+
+[merge]
+sqaush = false
+verify = "no"
+"#,
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "config", &["validate"], None);
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
+#[rstest]
+fn test_config_validate_project_config_problems(repo: TestRepo) {
+    repo.write_project_config(
+        r#"[post-craete]
+install = "npm ci"
+
+[pre-merge]
+test = "cargo test {% if %}"
+"#,
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "config", &["validate"], None);
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
+#[rstest]
+fn test_config_validate_syntax_error(repo: TestRepo) {
+    repo.write_project_config("[pre-merge\ntest = \"cargo test\"\n");
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "config", &["validate"], None);
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
+/// Loading a config with a wrong type points at the key instead of a generic error
+#[rstest]
+fn test_config_load_error_points_at_key(repo: TestRepo) {
+    repo.write_test_config("[list]\nbranches = [true]\n");
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", &[], None);
+        assert_cmd_snapshot!(cmd);
+    });
+}
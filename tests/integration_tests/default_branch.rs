@@ -186,3 +186,60 @@ fn test_get_default_branch_no_remote_fails_when_no_match(repo: TestRepo) {
         err
     );
 }
+
+/// `--remote` (or `remote` in the config) picks which remote names the default branch
+#[rstest]
+fn test_default_branch_from_chosen_remote(mut repo: TestRepo) {
+    repo.setup_remote("main");
+    repo.run_git(&["branch", "develop"]);
+    repo.setup_custom_remote("upstream", "develop");
+
+    let default_branch = |args: &[&str]| {
+        let output = repo
+            .wt_command()
+            .args(args)
+            .args(["config", "state", "default-branch", "get"])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    };
+    assert_eq!(default_branch(&[]), "main");
+    assert_eq!(default_branch(&["--remote", "upstream"]), "develop");
+
+    repo.write_test_config("remote = \"upstream\"\n");
+    assert_eq!(default_branch(&[]), "develop");
+    assert_eq!(default_branch(&["--remote", "origin"]), "main");
+}
+
+/// Ahead/behind counts against the chosen remote's copy of the default branch
+#[rstest]
+fn test_ahead_behind_against_chosen_remote(mut repo: TestRepo) {
+    repo.setup_remote("main");
+    repo.setup_custom_remote("upstream", "main");
+    repo.add_worktree("feature");
+    // upstream/main moves on without local main
+    repo.commit("Upstream work");
+    repo.run_git(&["push", "upstream", "main"]);
+    repo.run_git(&["reset", "--hard", "HEAD~1"]);
+
+    let behind = |args: &[&str]| {
+        let output = repo
+            .wt_command()
+            .args(args)
+            .args(["list", "--format=json"])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let feature = items
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|item| item["branch"] == "feature")
+            .unwrap();
+        feature["main"]["behind"].as_u64().unwrap()
+    };
+    assert_eq!(behind(&[]), 0);
+    assert_eq!(behind(&["--remote", "upstream"]), 1);
+}
@@ -41,6 +41,33 @@ fn test_switch_directive_file(#[from(repo_with_remote)] mut repo: TestRepo) {
     });
 }
 
+/// Test that wrappers asking for protocol 2 get literal records, not shell code
+#[rstest]
+fn test_switch_directive_protocol_2(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature_wt = repo.add_worktree("feature");
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    cmd.env("WORKTRUNK_DIRECTIVE_PROTOCOL", "2")
+        .args(["switch", "feature", "--execute", "echo 'one'\necho two"])
+        .current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    let records: Vec<&str> = directives.split_terminator('\0').collect();
+    assert_eq!(
+        records,
+        [
+            "worktrunk-directives 2",
+            &format!("cd {}", feature_wt.display()),
+            "exec echo 'one'\necho two",
+        ]
+    );
+}
+
 /// Test merge with directive file (switch back to main after merge)
 #[rstest]
 fn test_merge_directive_file(mut repo_with_remote_and_feature: TestRepo) {
@@ -4,7 +4,8 @@
 use crate::common::{
     TestRepo, repo,
     shell::{
-        execute_shell_script, generate_init_code, path_export_syntax, shell_available, wt_bin_dir,
+        execute_shell_script, generate_init_code, path_export_syntax, print_pwd_syntax,
+        shell_available, wt_bin_dir,
     },
 };
 use rstest::rstest;
@@ -24,6 +25,10 @@ macro_rules! skip_if_shell_unavailable {
 #[case("bash")]
 #[case("fish")]
 #[case("zsh")]
+// Nushell keeps a custom command's `cd` only when it's declared `--env`, and
+// PowerShell applies directives with `Set-Location` instead of sourcing them
+#[case("nushell")]
+#[case("powershell")]
 fn test_shell_integration_switch_and_remove(#[case] shell: &str, repo: TestRepo) {
     skip_if_shell_unavailable!(shell);
 
@@ -35,12 +40,14 @@ fn test_shell_integration_switch_and_remove(#[case] shell: &str, repo: TestRepo)
         {}
         {}
         wt switch --create combo-branch
-        echo "__PWD_AFTER_SWITCH__ $PWD"
+        {}
         wt remove
-        echo "__PWD_AFTER_REMOVE__ $PWD"
+        {}
         "#,
         path_export_syntax(shell, &bin_path),
-        init_code
+        init_code,
+        print_pwd_syntax(shell, "__PWD_AFTER_SWITCH__"),
+        print_pwd_syntax(shell, "__PWD_AFTER_REMOVE__"),
     );
 
     let output = execute_shell_script(&repo, shell, &script);
@@ -157,6 +164,50 @@ fn test_bash_shell_integration_switch_existing_worktree(repo: TestRepo) {
     );
 }
 
+#[rstest]
+#[case("bash")]
+#[case("fish")]
+#[case("zsh")]
+fn test_shell_integration_on_switch(#[case] shell: &str, repo: TestRepo) {
+    skip_if_shell_unavailable!(shell);
+
+    let mut cmd = crate::common::wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    let output = cmd
+        .args(["config", "shell", "init", shell, "--on-switch"])
+        .arg("echo __ON_SWITCH__ $PWD")
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let init_code = String::from_utf8(output.stdout).unwrap();
+    let bin_path = wt_bin_dir();
+
+    let script = format!(
+        r#"
+        {}
+        {}
+        wt switch --create snippet-branch
+        wt list >/dev/null
+        echo "__DONE__"
+        "#,
+        path_export_syntax(shell, &bin_path),
+        init_code
+    );
+
+    let output = execute_shell_script(&repo, shell, &script);
+
+    // Runs once, from the new directory, and not for commands that stay put
+    let ran: Vec<_> = output
+        .lines()
+        .filter(|line| line.contains("__ON_SWITCH__"))
+        .collect();
+    assert_eq!(ran.len(), 1, "snippet should run once, got:\n{output}");
+    assert!(
+        ran[0].contains("snippet-branch"),
+        "snippet should run in the new worktree, got:\n{output}"
+    );
+}
+
 fn extract_pwd_marker(output: &str, marker: &str) -> Option<String> {
     output
         .lines()
@@ -37,6 +37,33 @@ fn display_worktree_missing() {
     assert_snapshot!("worktree_missing", err.to_string());
 }
 
+#[test]
+fn display_worktree_deleted() {
+    let err = GitError::WorktreeDeleted {
+        worktree: PathBuf::from("/tmp/repo.feature"),
+        primary: Some(PathBuf::from("/tmp/repo")),
+        changed_directory: false,
+    };
+    assert_snapshot!("worktree_deleted", err.to_string());
+
+    let err = GitError::WorktreeDeleted {
+        worktree: PathBuf::from("/tmp/repo.feature"),
+        primary: Some(PathBuf::from("/tmp/repo")),
+        changed_directory: true,
+    };
+    assert_snapshot!("worktree_deleted_changed_directory", err.to_string());
+}
+
+#[test]
+fn display_repository_moved() {
+    let err = GitError::RepositoryMoved {
+        worktree: PathBuf::from("/tmp/repo.feature"),
+        repository: PathBuf::from("/tmp/repo"),
+    };
+
+    assert_snapshot!("repository_moved", err.to_string());
+}
+
 #[test]
 fn display_no_worktree_found() {
     let err = GitError::NoWorktreeFound {
@@ -57,6 +84,19 @@ fn display_worktree_path_occupied() {
     assert_snapshot!("worktree_path_occupied", err.to_string());
 }
 
+#[test]
+fn display_worktree_path_collision() {
+    let err = GitError::WorktreePathCollision {
+        branch: "feat/x".into(),
+        path: PathBuf::from("/tmp/repo.feat-x"),
+        occupant: "feat-x".into(),
+        create: true,
+        alternative: Some("feat/x-2".into()),
+    };
+
+    assert_snapshot!("worktree_path_collision", err.to_string());
+}
+
 #[test]
 fn display_worktree_path_exists() {
     let err = GitError::WorktreePathExists {
@@ -128,11 +168,50 @@ fn display_branch_already_exists() {
 fn display_invalid_reference() {
     let err = GitError::InvalidReference {
         reference: "nonexistent-branch".into(),
+        similar: vec![],
     };
 
     assert_snapshot!("invalid_reference", err.to_string());
 }
 
+#[test]
+fn display_invalid_reference_with_similar() {
+    let err = GitError::InvalidReference {
+        reference: "featur".into(),
+        similar: vec!["feature".into(), "feature-2".into()],
+    };
+
+    assert_snapshot!("invalid_reference_with_similar", err.to_string());
+}
+
+// ============================================================================
+// Git command errors
+// ============================================================================
+
+#[test]
+fn display_command_failed() {
+    let err = GitError::CommandFailed {
+        args: vec!["rev-parse".into(), "--verify".into(), "no such ref".into()],
+        exit_code: Some(128),
+        stderr: "fatal: Needed a single revision".into(),
+    };
+
+    // Display stays verbatim so callers can embed git's output
+    assert_eq!(err.to_string(), "fatal: Needed a single revision");
+    assert_snapshot!("command_failed", err.styled());
+}
+
+#[test]
+fn display_command_failed_with_hint() {
+    let err = GitError::CommandFailed {
+        args: vec!["commit".into()],
+        exit_code: Some(128),
+        stderr: "fatal: Unable to create '/repo/.git/index.lock': File exists.".into(),
+    };
+
+    assert_snapshot!("command_failed_with_hint", err.styled());
+}
+
 // ============================================================================
 // Merge/push errors
 // ============================================================================
@@ -185,11 +264,23 @@ fn display_rebase_conflict() {
     let err = GitError::RebaseConflict {
         target_branch: "main".into(),
         git_output: "CONFLICT (content): Merge conflict in src/main.rs".into(),
+        in_merge_context: false,
     };
 
     assert_snapshot!("rebase_conflict", err.to_string());
 }
 
+#[test]
+fn display_rebase_conflict_merge_context() {
+    let err = GitError::RebaseConflict {
+        target_branch: "main".into(),
+        git_output: "CONFLICT (content): Merge conflict in src/main.rs".into(),
+        in_merge_context: true,
+    };
+
+    assert_snapshot!("rebase_conflict_merge_context", err.to_string());
+}
+
 // ============================================================================
 // Validation/other errors
 // ============================================================================
@@ -0,0 +1,90 @@
+//! Integration tests for `wt history` and `wt undo`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+fn snapshot(test_name: &str, repo: &TestRepo, subcommand: &str, args: &[&str]) {
+    let settings = setup_snapshot_settings(repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(repo, subcommand, args, None);
+        assert_cmd_snapshot!(test_name, cmd);
+    });
+}
+
+fn run_wt(repo: &TestRepo, subcommand: &str, args: &[&str]) {
+    let output = make_snapshot_cmd(repo, subcommand, args, None)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "wt {subcommand} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[rstest]
+fn test_undo_worktree_removal(mut repo: TestRepo) {
+    let path = repo.add_worktree_with_commit("feature", "feature.txt", "feature", "Add feature");
+    let sha = repo.head_sha_in(&path);
+    run_wt(&repo, "remove", &["feature", "-D", "--no-background"]);
+    assert!(!path.exists());
+
+    // The unmerged commit survives garbage collection in the bundle
+    repo.run_git(&["reflog", "expire", "--expire-unreachable=now", "--all"]);
+    repo.run_git(&["gc", "--quiet", "--prune=now"]);
+
+    snapshot("history_after_remove", &repo, "history", &[]);
+    snapshot("undo_worktree_removal", &repo, "undo", &[]);
+    assert_eq!(repo.head_sha_in(&path), sha);
+    assert_eq!(repo.git_output(&["rev-parse", "feature"]), sha);
+
+    snapshot("history_after_undo", &repo, "history", &[]);
+    snapshot("undo_nothing_left", &repo, "undo", &[]);
+}
+
+/// A branch that moved off the removed commit isn't checked out in its place
+#[rstest]
+fn test_undo_worktree_removal_branch_moved(mut repo: TestRepo) {
+    let path = repo.add_worktree_with_commit("feature", "feature.txt", "feature", "Add feature");
+    let sha = repo.head_sha_in(&path);
+    run_wt(&repo, "remove", &["feature", "--no-background"]);
+    repo.run_git(&["branch", "--force", "feature", "main"]);
+    let moved = repo.git_output(&["rev-parse", "feature"]);
+
+    snapshot("undo_worktree_removal_branch_moved", &repo, "undo", &[]);
+    assert_eq!(repo.head_sha_in(&path), sha);
+    assert_eq!(repo.git_output(&["rev-parse", "feature"]), moved);
+}
+
+#[rstest]
+fn test_undo_branch_deletion(repo: TestRepo) {
+    repo.create_branch("old");
+    let sha = repo.git_output(&["rev-parse", "old"]);
+    run_wt(&repo, "remove", &["old"]);
+
+    snapshot("undo_branch_deletion", &repo, "undo", &[]);
+    assert_eq!(repo.git_output(&["rev-parse", "old"]), sha);
+}
+
+#[rstest]
+fn test_undo_restored_path_exists(mut repo: TestRepo) {
+    let path = repo.add_worktree("feature");
+    run_wt(&repo, "remove", &["feature", "--no-background"]);
+    std::fs::create_dir(&path).unwrap();
+
+    snapshot("undo_path_exists", &repo, "undo", &[]);
+}
+
+#[rstest]
+fn test_undo_irreversible_entry(repo: TestRepo) {
+    run_wt(&repo, "switch", &["--create", "feature"]);
+
+    snapshot("undo_irreversible", &repo, "undo", &["1"]);
+    snapshot("undo_missing_entry", &repo, "undo", &["7"]);
+}
+
+#[rstest]
+fn test_history_empty(repo: TestRepo) {
+    snapshot("history_empty", &repo, "history", &[]);
+}
@@ -65,8 +65,8 @@ fn test_init_invalid_shell(repo: TestRepo) {
         ----- stdout -----
 
         ----- stderr -----
-        [1m[31merror:[0m invalid value '[1m[33minvalid-shell[0m' for '[1m[36m<bash|fish|zsh|powershell>[0m'
-          [possible values: [1m[32mbash[0m, [1m[32mfish[0m, [1m[32mzsh[0m, [1m[32mpowershell[0m]
+        [1m[31merror:[0m invalid value '[1m[33minvalid-shell[0m' for '[1m[36m<bash|fish|zsh|powershell|nushell>[0m'
+          [possible values: [1m[32mbash[0m, [1m[32mfish[0m, [1m[32mzsh[0m, [1m[32mpowershell[0m, [1m[32mnushell[0m]
 
         For more information, try '[1m[36m--help[0m'.
         ");
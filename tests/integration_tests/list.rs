@@ -249,6 +249,55 @@ fn test_list_detached_head_in_worktree(mut repo: TestRepo) {
     snapshot_list("detached_head_in_worktree", &repo);
 }
 
+#[rstest]
+fn test_list_detached_head_describe(mut repo: TestRepo) {
+    // Detached worktrees show the nearest tag and how far past it HEAD is
+    repo.run_git(&["tag", "v1.0"]);
+    repo.add_worktree_with_commit("feature", "file.txt", "content", "Add file");
+    repo.detach_head_in_worktree("feature");
+
+    snapshot_list("detached_head_describe", &repo);
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let detached = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"].is_null())
+        .unwrap();
+    assert_eq!(detached["worktree"]["describe"], "v1.0+1");
+}
+
+#[rstest]
+fn test_list_json_is_merged(mut repo: TestRepo) {
+    repo.add_worktree("fresh");
+    repo.add_worktree_with_commit("feature", "file.txt", "content", "Add file");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let is_merged = |branch: &str| {
+        items
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|item| item["branch"] == branch)
+            .unwrap()["is_merged"]
+            .clone()
+    };
+    assert_eq!(is_merged("main"), serde_json::Value::Null);
+    assert_eq!(is_merged("fresh"), true);
+    assert_eq!(is_merged("feature"), false);
+}
+
 #[rstest]
 fn test_list_locked_worktree(mut repo: TestRepo) {
     repo.add_worktree("locked-feature");
@@ -265,6 +314,19 @@ fn test_list_locked_no_reason(mut repo: TestRepo) {
     snapshot_list("locked_no_reason", &repo);
 }
 
+#[rstest]
+fn test_list_broken_gitdir(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    // As if the worktree had been restored from a copy of another repository
+    std::fs::write(
+        feature.join(".git"),
+        "gitdir: /nonexistent/.git/worktrees/feature\n",
+    )
+    .unwrap();
+
+    snapshot_list("broken_gitdir", &repo);
+}
+
 // Removed: test_list_long_branch_name - covered by spacing_edge_cases.rs
 
 #[rstest]
@@ -306,6 +368,59 @@ fn test_list_many_worktrees_with_varied_stats(mut repo: TestRepo) {
     snapshot_list("many_worktrees_varied", &repo);
 }
 
+#[rstest]
+fn test_list_json_fields(mut repo: TestRepo) {
+    repo.add_worktree_with_commit("feature", "file.txt", "content", "Add file");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--json", "branch,ahead,behind,kind"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    // Exactly the fields asked for, with `null` where there's no value
+    assert_eq!(
+        items,
+        serde_json::json!([
+            {"branch": "main", "ahead": null, "behind": null, "kind": "worktree"},
+            {"branch": "feature", "ahead": 1, "behind": 0, "kind": "worktree"},
+        ])
+    );
+}
+
+#[rstest]
+fn test_list_json_template(mut repo: TestRepo) {
+    repo.add_worktree_with_commit("feature", "file.txt", "content", "Add file");
+
+    let output = repo
+        .wt_command()
+        .args([
+            "list",
+            "--json",
+            "branch,ahead",
+            "--template",
+            "{{ branch }}: {{ ahead }}",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "main: \nfeature: 1\n"
+    );
+}
+
+#[rstest]
+fn test_list_json_unknown_field(repo: TestRepo) {
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = list_snapshots::command(&repo, repo.root_path());
+        cmd.args(["--json", "branch,ahaed"]);
+        assert_cmd_snapshot!("list_json_unknown_field", cmd);
+    });
+}
+
 // Removed: test_list_json_single_worktree and test_list_json_multiple_worktrees
 // Basic JSON serialization is covered by test_list_json_with_metadata
 
@@ -381,6 +496,59 @@ fn test_list_with_branches_flag_only_branches(repo: TestRepo) {
     snapshot_list_with_branches("with_branches_flag_only_branches", &repo);
 }
 
+#[rstest]
+fn test_list_with_branches_pattern(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.create_branch("feature/login");
+    repo.create_branch("feature/search");
+    repo.create_branch("fix/typo");
+    repo.create_branch("stale-experiment");
+    // Worktrees are listed whether or not they match
+    repo.add_worktree("docs");
+
+    // Remote-only branch, matched without its remote name
+    repo.create_branch("feature/remote");
+    repo.push_branch("feature/remote");
+    repo.run_git(&["branch", "-D", "feature/remote"]);
+
+    let mut cmd = list_snapshots::command(&repo, repo.root_path());
+    cmd.args(["--branches", "feature/*", "--remotes"]);
+    run_snapshot(setup_snapshot_settings(&repo), "with_branches_pattern", cmd);
+
+    // Repeated patterns combine
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .args(["--branches", "fix/*", "--branches", "stale-*"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let mut branches: Vec<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|item| item["kind"] == "branch")
+        .map(|item| item["branch"].as_str().unwrap())
+        .collect();
+    branches.sort();
+    assert_eq!(branches, ["fix/typo", "stale-experiment"]);
+}
+
+#[rstest]
+fn test_list_with_invalid_branches_pattern(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["list", "--branches", "feature/[a"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid branch pattern 'feature/[a'"),
+        "{stderr}"
+    );
+}
+
 #[rstest]
 fn test_list_with_remotes_flag(#[from(repo_with_remote)] repo: TestRepo) {
     // Create feature branches in the main repo and push them
@@ -676,6 +844,76 @@ fn test_list_json_with_git_operation(mut repo: TestRepo) {
 
     // JSON output should show git_operation: "rebase" for the feature worktree
     snapshot_list_json("json_with_git_operation", &repo);
+    // The table leads the message with the rebase step: `rebase 1/1 · …`
+    snapshot_list("with_git_operation", &repo);
+}
+
+#[rstest]
+fn test_list_json_rerere_resolution(mut repo: TestRepo) {
+    repo.run_git(&["config", "rerere.enabled", "true"]);
+    for (file, contents) in [("a.txt", "base a\n"), ("b.txt", "base b\n")] {
+        std::fs::write(repo.root_path().join(file), contents).unwrap();
+    }
+    repo.commit("Add files");
+    let feature = repo.add_worktree("feature");
+    for (file, contents) in [("a.txt", "feature a\n"), ("b.txt", "feature b\n")] {
+        std::fs::write(feature.join(file), contents).unwrap();
+    }
+    repo.run_git_in(&feature, &["commit", "--quiet", "-am", "Feature changes"]);
+    let merge_main = || {
+        repo.git_command()
+            .current_dir(&feature)
+            .args(["merge", "main"])
+            .output()
+            .unwrap()
+    };
+
+    // Resolve an a.txt conflict once so rerere records it
+    std::fs::write(repo.root_path().join("a.txt"), "main a\n").unwrap();
+    repo.run_git(&["commit", "--quiet", "-am", "Change a"]);
+    assert!(!merge_main().status.success());
+    std::fs::write(feature.join("a.txt"), "resolved a\n").unwrap();
+    repo.run_git_in(&feature, &["rerere"]);
+    repo.run_git_in(&feature, &["merge", "--abort"]);
+
+    // Merging again replays a.txt; b.txt now conflicts too
+    std::fs::write(repo.root_path().join("b.txt"), "main b\n").unwrap();
+    repo.run_git(&["commit", "--quiet", "-am", "Change b"]);
+    assert!(!merge_main().status.success());
+
+    let resolution = |repo: &TestRepo| {
+        let output = repo
+            .wt_command()
+            .args(["list", "--format=json"])
+            .output()
+            .unwrap();
+        let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let item = items
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|item| item["branch"] == "feature")
+            .unwrap()
+            .clone();
+        (item["operation_state"].clone(), item["resolution"].clone())
+    };
+    assert_eq!(
+        resolution(&repo),
+        (
+            serde_json::json!("conflicts"),
+            serde_json::json!({"remaining": 1, "rerere_resolved": 1})
+        )
+    );
+
+    std::fs::write(feature.join("b.txt"), "resolved b\n").unwrap();
+    repo.run_git_in(&feature, &["add", "b.txt"]);
+    assert_eq!(
+        resolution(&repo),
+        (
+            serde_json::json!("resolved"),
+            serde_json::json!({"remaining": 0, "rerere_resolved": 1})
+        )
+    );
 }
 
 #[rstest]
@@ -2247,6 +2485,69 @@ fn test_list_full_clean_working_tree_uses_commit_conflicts(mut repo: TestRepo) {
     snapshot_list_full("commit_conflicts_with_full", &repo);
 }
 
+/// Test that JSON output lists the conflicting paths, not just the symbol:
+/// committed conflicts always, uncommitted ones with `--full`.
+#[rstest]
+fn test_list_json_conflict_paths(mut repo: TestRepo) {
+    std::fs::write(repo.root_path().join("shared.txt"), "original content").unwrap();
+    std::fs::write(repo.root_path().join("notes.txt"), "original notes").unwrap();
+    repo.commit("Initial commit");
+
+    let feature = repo.add_worktree("feature");
+    std::fs::write(feature.join("shared.txt"), "feature's committed version").unwrap();
+    repo.run_git_in(&feature, &["add", "."]);
+    repo.run_git_in(&feature, &["commit", "-m", "Feature changes shared.txt"]);
+
+    std::fs::write(repo.root_path().join("shared.txt"), "main's version").unwrap();
+    std::fs::write(repo.root_path().join("notes.txt"), "main's notes").unwrap();
+    repo.commit("Main changes shared.txt and notes.txt");
+
+    // Uncommitted change that only conflicts once the working tree is checked
+    std::fs::write(feature.join("notes.txt"), "feature's notes").unwrap();
+
+    let conflicts = |args: &[&str]| {
+        let output = repo.wt_command().args(args).output().unwrap();
+        assert!(output.status.success(), "{output:?}");
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        json.as_array()
+            .unwrap()
+            .iter()
+            .find(|item| item["branch"] == "feature")
+            .unwrap()["main"]["conflicts"]
+            .clone()
+    };
+
+    assert_eq!(
+        conflicts(&["list", "--format=json"]),
+        serde_json::json!(["shared.txt"])
+    );
+    assert_eq!(
+        conflicts(&["list", "--format=json", "--full"]),
+        serde_json::json!(["notes.txt", "shared.txt"])
+    );
+}
+
+/// An orphan branch shares no history with main, so there's no merge to
+/// conflict or integrate: it shows as diverged, not ✗
+#[rstest]
+fn test_list_orphan_branch(repo: TestRepo) {
+    std::fs::write(repo.root_path().join("README.md"), "main's readme\n").unwrap();
+    repo.commit("Add readme");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "site", "--orphan"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let worktree = repo.root_path().parent().unwrap().join("repo.site");
+    std::fs::write(worktree.join("README.md"), "the site's readme\n").unwrap();
+    repo.run_git_in(&worktree, &["add", "README.md"]);
+    repo.run_git_in(&worktree, &["commit", "-m", "Add site readme"]);
+
+    snapshot_list_full("orphan_branch", &repo);
+}
+
 #[rstest]
 fn test_list_warns_when_default_branch_missing_worktree(repo: TestRepo) {
     // Move primary worktree off the default branch so no worktree holds it
@@ -2318,3 +2619,76 @@ fn test_list_handles_orphan_branch(repo: TestRepo) {
 
     snapshot_list_with_branches("orphan_branch_no_error", &repo);
 }
+
+/// A bare repository checked out with `GIT_DIR`/`GIT_WORK_TREE` (the usual
+/// dotfiles setup) lists its work tree as the main worktree, and `--git-dir`/
+/// `--work-tree` do the same
+#[rstest]
+fn test_list_with_git_dir_override(repo: TestRepo) {
+    let temp = tempfile::tempdir().unwrap();
+    let temp_path = dunce::canonicalize(temp.path()).unwrap();
+    let git_dir = temp_path.join("dotfiles.git");
+    let work_tree = temp_path.join("home");
+    std::fs::create_dir(&work_tree).unwrap();
+    std::fs::write(work_tree.join(".bashrc"), "export A=1\n").unwrap();
+    repo.run_git(&["init", "--quiet", "--bare", git_dir.to_str().unwrap()]);
+    for args in [
+        &["add", ".bashrc"][..],
+        &["commit", "--quiet", "-m", "Add bashrc"],
+    ] {
+        let output = repo
+            .git_command()
+            .args(args)
+            .env("GIT_DIR", &git_dir)
+            .env("GIT_WORK_TREE", &work_tree)
+            .current_dir(&work_tree)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {args:?} failed");
+    }
+
+    let git_dir_str = git_dir.to_str().unwrap();
+    let work_tree_str = work_tree.to_str().unwrap();
+    let mut from_env = repo.wt_command();
+    from_env
+        .args(["list", "--format=json"])
+        .env("GIT_DIR", &git_dir)
+        .env("GIT_WORK_TREE", &work_tree)
+        .current_dir(&temp_path);
+    let mut from_flags = repo.wt_command();
+    from_flags
+        .args([
+            "--git-dir",
+            git_dir_str,
+            "--work-tree",
+            work_tree_str,
+            "list",
+            "--format=json",
+        ])
+        .current_dir(&temp_path);
+
+    for mut cmd in [from_env, from_flags] {
+        let output = cmd.output().unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(output.status.success(), "{stderr}");
+        assert!(!stderr.contains("failed"), "{stderr}");
+        let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let items = items.as_array().unwrap();
+        assert_eq!(items.len(), 1, "{items:?}");
+        assert_eq!(items[0]["branch"], "main");
+        assert_eq!(items[0]["path"], work_tree_str);
+        assert_eq!(items[0]["is_main"], true);
+        assert_eq!(items[0]["commit"]["message"], "Add bashrc");
+    }
+}
+
+#[rstest]
+fn test_list_over_worktree_budget(mut repo: TestRepo) {
+    repo.add_worktree("feature-a");
+    repo.add_worktree_with_commit("feature-b", "b.txt", "b", "Add b");
+    repo.add_worktree_with_commit("feature-c", "c.txt", "c", "Add c");
+    repo.write_test_config("[limits]\nmax-worktrees = 2\n");
+
+    // Merged feature-a is suggested first, then the others by commit age
+    snapshot_list("over_worktree_budget", &repo);
+}
@@ -1,7 +1,8 @@
 //! Tests for `wt list` command with user config
 
 use crate::common::{
-    TestRepo, repo, set_temp_home_env, setup_snapshot_settings_with_home, temp_home, wt_command,
+    DAY, HOUR, TestRepo, make_snapshot_cmd, repo, set_temp_home_env, setup_snapshot_settings,
+    setup_snapshot_settings_with_home, temp_home, wt_command,
 };
 use insta_cmd::assert_cmd_snapshot;
 use rstest::rstest;
@@ -347,3 +348,184 @@ url = "http://localhost:8080/{{ branch }}"
     let url = first["url"].as_str().unwrap();
     assert_eq!(url, "http://localhost:8080/main");
 }
+
+/// Test `wt list` with `list.columns` choosing and ordering columns
+#[rstest]
+fn test_list_config_columns(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.write_test_config(
+        r#"[list]
+columns = ["message", "branch", "head", "age"]
+"#,
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(&repo, "list", &[], None));
+    });
+}
+
+/// Test `--columns` overriding `list.columns`
+#[rstest]
+fn test_list_columns_flag_overrides_config(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[list]
+columns = ["message", "branch"]
+"#,
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(
+            &repo,
+            "list",
+            &["--columns", "commit,branch,status"],
+            None
+        ));
+    });
+}
+
+/// Test that selecting the `signature` column checks signatures without `--full`
+#[rstest]
+fn test_list_columns_signature(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(
+            &repo,
+            "list",
+            &["--columns", "branch,signature,commit"],
+            None
+        ));
+    });
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--full"])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        json[0]["signature"],
+        serde_json::json!({"status": "unsigned"})
+    );
+
+    // Without --full, signatures aren't checked
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(json[0].get("signature").is_none());
+}
+
+/// Test the opt-in `description` column and JSON field
+#[rstest]
+fn test_list_columns_description(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.run_git(&[
+        "config",
+        "branch.feature.description",
+        "Rework the login flow\n\nLonger notes that only JSON shows",
+    ]);
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(
+            &repo,
+            "list",
+            &["--columns", "branch,description,age"],
+            None
+        ));
+    });
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(json[0].get("description").is_none());
+    assert_eq!(
+        json[1]["description"],
+        "Rework the login flow\n\nLonger notes that only JSON shows"
+    );
+}
+
+/// Test the opt-in `files` column, which computes the branch diff without `--full`
+#[rstest]
+fn test_list_columns_files(mut repo: TestRepo) {
+    let feature = repo.add_worktree_with_commit("feature", "a.txt", "a\n", "Add a");
+    repo.commit_in_worktree(&feature, "b.txt", "b\nb\n", "Add b");
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(
+            &repo,
+            "list",
+            &["--columns", "branch,main-diff,files,message"],
+            None
+        ));
+    });
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--full"])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "feature")
+        .unwrap();
+    assert_eq!(
+        feature["main"]["diff"],
+        serde_json::json!({"added": 3, "deleted": 0, "files": 2})
+    );
+}
+
+#[rstest]
+fn test_list_columns_index(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(
+            &repo,
+            "list",
+            &["--columns", "index,branch,message"],
+            None
+        ));
+    });
+}
+
+/// Test `list.dim-after` and `list.highlight-within` styling rows by commit age
+#[rstest]
+fn test_list_config_age_styling(mut repo: TestRepo) {
+    repo.commit_with_age("Old main commit", 60 * DAY);
+    let recent = repo.add_worktree("recent");
+    std::fs::write(recent.join("recent.txt"), "recent").unwrap();
+    repo.run_git_in(&recent, &["add", "recent.txt"]);
+    repo.commit_staged_with_age("Recent work", HOUR, &recent);
+    let middle = repo.add_worktree("middle");
+    std::fs::write(middle.join("middle.txt"), "middle").unwrap();
+    repo.run_git_in(&middle, &["add", "middle.txt"]);
+    repo.commit_staged_with_age("Last week's work", 5 * DAY, &middle);
+    repo.write_test_config(
+        r#"[list]
+columns = ["branch", "age", "message"]
+dim-after = "30d"
+highlight-within = "1d"
+"#,
+    );
+
+    // main is dimmed, recent is bold, middle is unstyled
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(&repo, "list", &[], None));
+    });
+}
@@ -0,0 +1,48 @@
+//! Integration tests for `wt log`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+/// Helper to create snapshot with normalized paths
+fn snapshot_log(test_name: &str, repo: &TestRepo, args: &[&str], cwd: Option<&std::path::Path>) {
+    let settings = setup_snapshot_settings(repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(repo, "log", args, cwd);
+        assert_cmd_snapshot!(test_name, cmd);
+    });
+}
+
+#[rstest]
+fn test_log_current_branch(mut repo: TestRepo) {
+    let feature_wt = repo.add_feature();
+    repo.commit_in_worktree(&feature_wt, "second.txt", "more", "Add second file");
+
+    snapshot_log("log_current_branch", &repo, &[], Some(&feature_wt));
+}
+
+#[rstest]
+fn test_log_named_branch(mut repo: TestRepo) {
+    repo.add_feature();
+
+    snapshot_log("log_named_branch", &repo, &["feature"], None);
+}
+
+#[rstest]
+fn test_log_no_unique_commits(repo: TestRepo) {
+    snapshot_log("log_no_unique_commits", &repo, &[], None);
+}
+
+#[rstest]
+fn test_log_all(mut repo: TestRepo) {
+    repo.add_feature();
+    let other_wt = repo.add_worktree_with_commit("other", "other.txt", "other", "Add other file");
+    repo.commit_in_worktree(&other_wt, "other.txt", "changed", "Update other file");
+
+    snapshot_log("log_all", &repo, &["--all"], None);
+}
+
+#[rstest]
+fn test_log_all_skips_default_branch_only(repo: TestRepo) {
+    snapshot_log("log_all_empty", &repo, &["--all"], None);
+}
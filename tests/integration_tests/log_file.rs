@@ -0,0 +1,78 @@
+//! Integration tests for `--log-file` and the `log-file` config key
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+use serde_json::Value;
+use std::path::Path;
+
+fn read_log(path: &Path) -> Vec<Value> {
+    std::fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+fn events(records: &[Value]) -> Vec<&str> {
+    records
+        .iter()
+        .map(|record| record["event"].as_str().unwrap())
+        .collect()
+}
+
+#[rstest]
+fn test_log_file_records_remove(mut repo: TestRepo) {
+    let worktree = repo.add_worktree("feature");
+    let log = repo.home_path().join("logs/wt.jsonl");
+    let output = repo
+        .wt_command()
+        .arg("--log-file")
+        .arg(&log)
+        .args(["remove", "feature", "--no-background"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let records = read_log(&log);
+    let events = events(&records);
+    assert_eq!(events.first(), Some(&"start"));
+    assert_eq!(events.last(), Some(&"exit"));
+    assert!(events.contains(&"command"), "{events:?}");
+
+    let removed = records
+        .iter()
+        .find(|record| record["event"] == "worktree_removed")
+        .unwrap();
+    assert_eq!(
+        Path::new(removed["path"].as_str().unwrap()).file_name(),
+        worktree.file_name()
+    );
+    assert!(
+        records
+            .iter()
+            .any(|record| record["event"] == "branch_deleted" && record["branch"] == "feature")
+    );
+    assert_eq!(records.last().unwrap()["ok"], true);
+
+    // Every record leads with the same keys
+    let first_line = std::fs::read_to_string(&log).unwrap();
+    assert!(first_line.starts_with("{\"ts\":"), "{first_line}");
+}
+
+#[rstest]
+fn test_log_file_from_config_records_failure(repo: TestRepo) {
+    let log = repo.home_path().join("config.jsonl");
+    repo.write_test_config(&format!("log-file = {:?}\n", log.display().to_string()));
+    let output = repo
+        .wt_command()
+        .args(["remove", "missing"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success(), "{output:?}");
+
+    let records = read_log(&log);
+    let exit = records.last().unwrap();
+    assert_eq!(exit["event"], "exit");
+    assert_eq!(exit["ok"], false);
+    assert!(exit["error"].as_str().is_some(), "{exit}");
+}
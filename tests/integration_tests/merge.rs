@@ -1,5 +1,5 @@
 use crate::common::{
-    TestRepo, make_snapshot_cmd, merge_scenario, repo, repo_with_alternate_primary,
+    TEST_EPOCH, TestRepo, make_snapshot_cmd, merge_scenario, repo, repo_with_alternate_primary,
     repo_with_feature_worktree, repo_with_main_worktree, repo_with_multi_commit_feature,
     setup_snapshot_settings,
 };
@@ -217,6 +217,128 @@ fn test_merge_rebase_conflict(repo: TestRepo) {
     snapshot_merge("merge_rebase_conflict", &repo, &["main"], Some(&feature_wt));
 }
 
+/// Feature worktree whose commit conflicts with main, plus an uncommitted
+/// file for `wt merge` to squash in before rebasing
+fn setup_stopped_merge(repo: &mut TestRepo) -> std::path::PathBuf {
+    std::fs::write(repo.root_path().join("shared.txt"), "initial content\n").unwrap();
+    repo.commit("Add shared file");
+    let feature_wt = repo.add_worktree_with_commit(
+        "feature",
+        "shared.txt",
+        "feature version\n",
+        "Update shared.txt in feature",
+    );
+    std::fs::write(repo.root_path().join("shared.txt"), "main version\n").unwrap();
+    repo.run_git(&["commit", "-am", "Update shared.txt in main"]);
+    std::fs::write(feature_wt.join("notes.txt"), "notes\n").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--yes"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("wt merge --continue"));
+    feature_wt
+}
+
+#[rstest]
+fn test_merge_continue_after_conflict(mut repo: TestRepo) {
+    let feature_wt = setup_stopped_merge(&mut repo);
+
+    std::fs::write(feature_wt.join("shared.txt"), "resolved\n").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "shared.txt"]);
+
+    snapshot_merge(
+        "merge_continue_after_conflict",
+        &repo,
+        &["--continue", "--yes"],
+        Some(&feature_wt),
+    );
+    assert_eq!(
+        std::fs::read_to_string(repo.root_path().join("shared.txt")).unwrap(),
+        "resolved\n"
+    );
+    assert!(
+        !repo
+            .git_command()
+            .args(["config", "--get", "worktrunk.state.feature.merge"])
+            .output()
+            .unwrap()
+            .status
+            .success()
+    );
+}
+
+#[rstest]
+fn test_merge_abort_after_conflict(mut repo: TestRepo) {
+    let feature_wt = setup_stopped_merge(&mut repo);
+
+    snapshot_merge(
+        "merge_abort_after_conflict",
+        &repo,
+        &["--abort"],
+        Some(&feature_wt),
+    );
+    // Back on the branch, at its original commit, with the squashed-in file staged
+    let status = repo.git_output(&["-C", feature_wt.to_str().unwrap(), "status", "--porcelain"]);
+    assert_eq!(status.trim(), "A  notes.txt");
+    let subject = repo.git_output(&[
+        "-C",
+        feature_wt.to_str().unwrap(),
+        "log",
+        "-1",
+        "--format=%s",
+        "feature",
+    ]);
+    assert_eq!(subject.trim(), "Update shared.txt in feature");
+}
+
+#[rstest]
+fn test_merge_continue_without_stopped_merge(mut repo: TestRepo) {
+    let feature_wt = repo.add_worktree("feature");
+    snapshot_merge(
+        "merge_continue_without_stopped_merge",
+        &repo,
+        &["--continue"],
+        Some(&feature_wt),
+    );
+}
+
+#[rstest]
+fn test_merge_dry_run_lists_conflicts(mut repo: TestRepo) {
+    std::fs::write(repo.root_path().join("shared.txt"), "initial content\n").unwrap();
+    repo.commit("Add shared file");
+    let feature_wt = repo.add_worktree_with_commit(
+        "feature",
+        "shared.txt",
+        "feature version\n",
+        "Update shared.txt in feature",
+    );
+    std::fs::write(repo.root_path().join("shared.txt"), "main version\n").unwrap();
+    repo.run_git(&["commit", "-am", "Update shared.txt in main"]);
+    // Uncommitted changes count too, as `wt merge` would commit them
+    std::fs::write(feature_wt.join("shared.txt"), "feature version\nmore\n").unwrap();
+
+    snapshot_merge(
+        "merge_dry_run_conflicts",
+        &repo,
+        &["main", "--dry-run"],
+        Some(&feature_wt),
+    );
+
+    // Nothing changed: the feature commit is still unmerged and unrebased
+    assert_eq!(
+        repo.git_output(&["rev-list", "--count", "main..feature"]),
+        "1"
+    );
+    assert_eq!(
+        std::fs::read_to_string(feature_wt.join("shared.txt")).unwrap(),
+        "feature version\nmore\n"
+    );
+}
+
 #[rstest]
 fn test_merge_to_default_branch(merge_scenario: (TestRepo, PathBuf)) {
     let (repo, feature_wt) = merge_scenario;
@@ -1464,7 +1586,7 @@ fn test_merge_no_commit_with_dirty_tree(mut repo: TestRepo) {
             make_snapshot_cmd(&repo, "merge", &["main", "--no-commit"], Some(&feature_wt));
         assert_cmd_snapshot!(cmd, @"
         success: false
-        exit_code: 1
+        exit_code: 3
         ----- stdout -----
 
         ----- stderr -----
@@ -1474,6 +1596,71 @@ fn test_merge_no_commit_with_dirty_tree(mut repo: TestRepo) {
     });
 }
 
+#[rstest]
+fn test_merge_autostash_no_commit(mut repo: TestRepo) {
+    let feature_wt = repo.add_worktree_with_commit(
+        "feature",
+        "committed.txt",
+        "committed content",
+        "Add committed file",
+    );
+    // Main moves on, so the merge has to rebase
+    std::fs::write(repo.root_path().join("main.txt"), "main\n").unwrap();
+    repo.commit("Add main file");
+    fs::write(feature_wt.join("uncommitted.txt"), "uncommitted content").unwrap();
+
+    snapshot_merge(
+        "merge_autostash_no_commit",
+        &repo,
+        &["main", "--no-commit", "--autostash", "--yes"],
+        Some(&feature_wt),
+    );
+    // The changes are back, in the worktree that was kept to hold them
+    assert_eq!(
+        fs::read_to_string(feature_wt.join("uncommitted.txt")).unwrap(),
+        "uncommitted content"
+    );
+    assert!(repo.git_output(&["stash", "list"]).trim().is_empty());
+    let subject = repo.git_output(&["log", "-1", "--format=%s", "main"]);
+    assert_eq!(subject.trim(), "Add committed file");
+}
+
+#[rstest]
+fn test_merge_autostash_abort_restores_changes(mut repo: TestRepo) {
+    std::fs::write(repo.root_path().join("shared.txt"), "initial content\n").unwrap();
+    repo.commit("Add shared file");
+    let feature_wt = repo.add_worktree_with_commit(
+        "feature",
+        "shared.txt",
+        "feature version\n",
+        "Update shared.txt in feature",
+    );
+    std::fs::write(repo.root_path().join("shared.txt"), "main version\n").unwrap();
+    repo.run_git(&["commit", "-am", "Update shared.txt in main"]);
+    std::fs::write(feature_wt.join("notes.txt"), "notes\n").unwrap();
+
+    // The rebase stops on conflicts with the notes still stashed
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--no-commit", "--autostash", "--yes"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(!feature_wt.join("notes.txt").exists());
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "--abort"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let status = repo.git_output(&["-C", feature_wt.to_str().unwrap(), "status", "--porcelain"]);
+    assert_eq!(status.trim(), "?? notes.txt");
+    assert!(repo.git_output(&["stash", "list"]).trim().is_empty());
+}
+
 #[rstest]
 fn test_merge_no_commit_no_squash_no_remove_redundant(mut repo_with_feature_worktree: TestRepo) {
     let repo = &mut repo_with_feature_worktree;
@@ -2338,3 +2525,166 @@ fn test_step_rebase_with_merge_commit(mut repo: TestRepo) {
         Some(&feature_wt),
     );
 }
+
+#[rstest]
+fn test_merge_format_json(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--format=json"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["action"], "merge");
+    assert_eq!(json["branch"], "feature");
+    assert_eq!(json["target"], "main");
+    assert_eq!(json["commits"], 1);
+    assert_eq!(json["removed_worktrees"][0], feature_wt.to_str().unwrap());
+    assert_eq!(
+        json["deleted_refs"],
+        serde_json::json!(["refs/heads/feature"])
+    );
+}
+
+/// Write a cached CI status for `branch` at its current HEAD, so no provider is queried
+fn write_ci_status(repo: &TestRepo, branch: &str, ci_status: &str) {
+    let head = repo.git_output(&["rev-parse", branch]);
+    let cache_dir = repo.root_path().join(".git/wt-cache/ci-status");
+    std::fs::create_dir_all(&cache_dir).unwrap();
+    std::fs::write(
+        cache_dir.join(format!("{branch}.json")),
+        format!(
+            r#"{{"status":{{"ci_status":"{ci_status}","source":"pr","is_stale":false}},"checked_at":{TEST_EPOCH},"head":"{}"}}"#,
+            head.trim()
+        ),
+    )
+    .unwrap();
+}
+
+#[rstest]
+fn test_merge_require_ci_failed(mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "feature.txt", "content", "Add feature");
+    write_ci_status(&repo, "feature", "failed");
+
+    snapshot_merge(
+        "merge_require_ci_failed",
+        &repo,
+        &["main", "--require-ci"],
+        Some(&feature_wt),
+    );
+}
+
+#[rstest]
+fn test_merge_require_ci_passed(mut repo: TestRepo) {
+    repo.write_test_config("[merge]\nrequire-ci = true\n");
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "feature.txt", "content", "Add feature");
+    write_ci_status(&repo, "feature", "passed");
+
+    snapshot_merge(
+        "merge_require_ci_passed",
+        &repo,
+        &["main", "--no-remove"],
+        Some(&feature_wt),
+    );
+}
+
+/// `--override-ci` merges even when config requires CI and there's no status at all
+#[rstest]
+fn test_merge_override_ci(mut repo: TestRepo) {
+    repo.write_test_config("[merge]\nrequire-ci = true\n");
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "feature.txt", "content", "Add feature");
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--no-remove", "--override-ci"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Signature status (`%G?`) of each commit `main` gained from `feature`
+fn merged_signatures(repo: &TestRepo, base: &str) -> Vec<String> {
+    repo.git_output(&["log", "--format=%G?", &format!("{base}..main")])
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+/// With `--gpg-sign`, the squash commit, the commit of uncommitted changes,
+/// and rebased commits are all signed
+#[rstest]
+fn test_merge_gpg_sign(mut repo: TestRepo) {
+    repo.setup_ssh_signing();
+    repo.run_git(&["config", "--unset", "commit.gpgsign"]);
+    let base = repo.git_output(&["rev-parse", "main"]);
+    let feature_wt = repo.add_worktree_with_commit("feature", "a.txt", "a", "Add a");
+    repo.commit_in_worktree(&feature_wt, "b.txt", "b", "Add b");
+    std::fs::write(feature_wt.join("c.txt"), "c").unwrap();
+    repo.commit_in_worktree(repo.root_path(), "main.txt", "main", "Advance main");
+    let base_after = repo.git_output(&["rev-parse", "main"]);
+
+    // Squashed: one signed commit
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--no-remove", "--gpg-sign"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(merged_signatures(&repo, &base_after), ["G"]);
+    assert_eq!(merged_signatures(&repo, &base).last().unwrap(), "N");
+}
+
+/// `merge.sign` signs commits kept by `--no-squash` and rebased onto the target
+#[rstest]
+fn test_merge_sign_config_no_squash(mut repo: TestRepo) {
+    repo.setup_ssh_signing();
+    repo.run_git(&["config", "--unset", "commit.gpgsign"]);
+    repo.write_test_config("[merge]\nsign = true\n");
+    let feature_wt = repo.add_worktree_with_commit("feature", "a.txt", "a", "Add a");
+    repo.commit_in_worktree(&feature_wt, "b.txt", "b", "Add b");
+    std::fs::write(feature_wt.join("c.txt"), "c").unwrap();
+    repo.commit_in_worktree(repo.root_path(), "main.txt", "main", "Advance main");
+    let base = repo.git_output(&["rev-parse", "main"]);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--no-remove", "--no-squash"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(merged_signatures(&repo, &base), ["G", "G", "G"]);
+
+    // --no-gpg-sign overrides the config
+    let feature_wt = repo.add_worktree_with_commit("other", "d.txt", "d", "Add d");
+    let base = repo.git_output(&["rev-parse", "main"]);
+    std::fs::write(feature_wt.join("e.txt"), "e").unwrap();
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--no-remove", "--no-gpg-sign"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(merged_signatures(&repo, &base), ["N"]);
+}
@@ -5,6 +5,7 @@
 // Windows path differences are handled by snapshot filters in setup_snapshot_settings().
 
 // column_alignment merged into spacing_edge_cases
+pub mod alias;
 pub mod approval_pty;
 
 pub mod approval_save;
@@ -15,10 +16,15 @@ pub mod ci_status;
 pub mod column_alignment_verification;
 pub mod completion;
 pub mod completion_validation;
+pub mod config_env;
 pub mod config_init;
+pub mod config_migrate;
+pub mod config_profile;
+pub mod config_set;
 pub mod config_show;
 pub mod config_show_theme;
 pub mod config_state;
+pub mod config_validate;
 pub mod configure_shell;
 pub mod default_branch;
 pub mod directives;
@@ -27,23 +33,41 @@ pub mod e2e_shell_post_start;
 pub mod for_each;
 pub mod git_error_display;
 pub mod help;
+pub mod history;
 pub mod hook_show;
 pub mod init;
 pub mod list;
 pub mod list_column_alignment;
 pub mod list_config;
 pub mod list_progressive;
+pub mod log;
+pub mod log_file;
 pub mod merge;
+pub mod note;
+pub mod notify;
 pub mod output_system_guard;
+pub mod plugin;
 pub mod post_start_commands;
+pub mod pr;
+pub mod prompt;
 pub mod push;
 pub mod readme_sync;
 pub mod remove;
+pub mod repair;
+pub mod repo;
+pub mod repo_config;
 pub mod repository;
 pub mod security;
 pub mod select;
 pub mod shell_wrapper;
 pub mod spacing_edge_cases;
 pub mod statusline;
+pub mod styling;
 pub mod switch;
+pub mod sync;
+pub mod tidy;
+pub mod tmux;
+pub mod trust;
 pub mod user_hooks;
+pub mod verbosity;
+pub mod workspace;
@@ -0,0 +1,65 @@
+//! Integration tests for `wt note`
+
+use crate::common::{TestRepo, repo, wt_command};
+use insta::assert_snapshot;
+use rstest::rstest;
+use std::process::Command;
+
+fn wt_note_cmd(repo: &TestRepo, args: &[&str]) -> Command {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.arg("note").args(args).current_dir(repo.root_path());
+    cmd
+}
+
+#[rstest]
+fn test_note_set_and_show(repo: TestRepo) {
+    repo.create_branch("feature");
+
+    let output = wt_note_cmd(&repo, &["feature", "waiting on review"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[32m✓[39m [32mSet note for [1mfeature[22m to [1mwaiting on review[22m[39m");
+
+    let output = wt_note_cmd(&repo, &["feature"]).output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "waiting on review"
+    );
+}
+
+#[rstest]
+fn test_note_current_branch_shortcut(repo: TestRepo) {
+    let output = wt_note_cmd(&repo, &["@", "🚧"]).output().unwrap();
+    assert!(output.status.success());
+
+    let marker = repo.git_output(&["config", "--get", "worktrunk.state.main.marker"]);
+    assert!(marker.contains(r#""marker":"🚧""#), "marker: {marker}");
+}
+
+#[rstest]
+fn test_note_clear(repo: TestRepo) {
+    repo.set_marker("main", "blocked");
+
+    let output = wt_note_cmd(&repo, &["main", "--clear"]).output().unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[32m✓[39m [32mCleared note for [1mmain[22m[39m");
+
+    let output = wt_note_cmd(&repo, &["main"]).output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "");
+
+    let output = wt_note_cmd(&repo, &["main", "--clear"]).output().unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[2m○[22m No note set for [1mmain[22m");
+}
+
+#[rstest]
+fn test_note_clear_conflicts_with_text(repo: TestRepo) {
+    let output = wt_note_cmd(&repo, &["main", "blocked", "--clear"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
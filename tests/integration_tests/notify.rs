@@ -0,0 +1,95 @@
+//! Integration tests for desktop notifications (`[notify]` in user config)
+//!
+//! A mock `notify-send` records each notification as a line in a file.
+#![cfg(all(unix, not(target_os = "macos")))]
+
+use crate::common::{TestRepo, repo, wait_for_file_lines};
+use rstest::rstest;
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+
+/// Install a mock `notify-send` and return the file it writes notifications to
+fn mock_notify_send(repo: &TestRepo) -> PathBuf {
+    let temp = repo.root_path().parent().unwrap();
+    let mock_bin = temp.join("mock-bin");
+    let notifications = temp.join("notifications.txt");
+    let script = mock_bin.join("notify-send");
+    std::fs::write(
+        &script,
+        format!(
+            "#!/bin/sh\necho \"$1: $2\" >> \"{}\"\n",
+            notifications.display()
+        ),
+    )
+    .unwrap();
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+    notifications
+}
+
+#[rstest]
+fn test_notify_after_command(repo: TestRepo) {
+    let notifications = mock_notify_send(&repo);
+    repo.write_test_config("[notify]\nenabled = true\nmin-duration = \"0s\"\n");
+
+    let output = repo.wt_command().arg("list").output().unwrap();
+    assert!(output.status.success());
+
+    let sent = std::fs::read_to_string(&notifications).unwrap();
+    assert_eq!(sent, "wt list: Finished after 0s\n");
+}
+
+#[rstest]
+fn test_notify_failed_command(repo: TestRepo) {
+    let notifications = mock_notify_send(&repo);
+    repo.write_test_config("[notify]\nenabled = true\nmin-duration = \"0s\"\n");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "no-such-branch"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let sent = std::fs::read_to_string(&notifications).unwrap();
+    assert!(sent.starts_with("wt switch: Failed: "), "{sent}");
+}
+
+/// Nothing is sent without `enabled = true`, or for operations quicker than `min-duration`
+#[rstest]
+fn test_notify_disabled_or_quick(repo: TestRepo) {
+    let notifications = mock_notify_send(&repo);
+
+    repo.wt_command().arg("list").output().unwrap();
+    repo.write_test_config("[notify]\nenabled = true\nmin-duration = \"1h\"\n");
+    repo.wt_command().arg("list").output().unwrap();
+
+    assert!(!notifications.exists());
+}
+
+#[rstest]
+fn test_notify_background_hook(repo: TestRepo) {
+    let notifications = mock_notify_send(&repo);
+    repo.write_test_config(
+        "[notify]\nenabled = true\nmin-duration = \"0s\"\n\n[post-start]\nwarm = \"true\"\n",
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // The command's own notification and the hook's arrive in either order
+    wait_for_file_lines(&notifications, 2, std::time::Duration::from_secs(10));
+    let sent = std::fs::read_to_string(&notifications).unwrap();
+    assert!(
+        sent.lines()
+            .any(|line| line == "wt post-start hook: warm on feature finished"),
+        "{sent}"
+    );
+}
@@ -0,0 +1,112 @@
+//! Integration tests for `wt-<name>` plugin subcommands
+#![cfg(unix)]
+
+use crate::common::{TestRepo, repo, wt_command};
+use rstest::rstest;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::process::Command;
+
+/// Install an executable `wt-<name>` script into a fresh bin directory.
+fn install_plugin(repo: &TestRepo, name: &str, script: &str) -> std::path::PathBuf {
+    let bin = repo.home_path().join("plugin-bin");
+    std::fs::create_dir_all(&bin).unwrap();
+    let path = bin.join(format!("wt-{name}"));
+    std::fs::write(&path, format!("#!/bin/sh\n{script}\n")).unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    bin
+}
+
+fn wt_with_plugins(repo: &TestRepo, bin: &Path, args: &[&str], cwd: &Path) -> Command {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    let path = std::env::var_os("PATH").unwrap_or_default();
+    let mut paths = vec![bin.to_path_buf()];
+    paths.extend(std::env::split_paths(&path));
+    cmd.env("PATH", std::env::join_paths(paths).unwrap())
+        .args(args)
+        .current_dir(cwd);
+    cmd
+}
+
+#[rstest]
+fn test_plugin_receives_args_and_context(mut repo: TestRepo) {
+    let feature_wt = repo.add_feature();
+    let bin = install_plugin(
+        &repo,
+        "hello",
+        r#"echo "args=$*"
+echo "branch=$WT_BRANCH"
+echo "worktree=$WT_WORKTREE"
+echo "root=$WT_REPO_ROOT""#,
+    );
+
+    let output = wt_with_plugins(&repo, &bin, &["hello", "one", "--two"], &feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let worktree = dunce::canonicalize(&feature_wt).unwrap();
+    let root = dunce::canonicalize(repo.root_path()).unwrap();
+    assert_eq!(
+        stdout.lines().collect::<Vec<_>>(),
+        [
+            "args=one --two".to_string(),
+            "branch=feature".to_string(),
+            format!("worktree={}", worktree.display()),
+            format!("root={}", root.display()),
+        ]
+    );
+}
+
+#[rstest]
+fn test_plugin_respects_directory_flag(repo: TestRepo) {
+    let bin = install_plugin(&repo, "where", r#"echo "$WT_BRANCH $(pwd -P)""#);
+
+    let root = repo.root_path().to_str().unwrap();
+    let output = wt_with_plugins(&repo, &bin, &["-C", root, "where"], repo.home_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let expected = dunce::canonicalize(repo.root_path()).unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        format!("main {}", expected.display())
+    );
+}
+
+#[rstest]
+fn test_plugin_exit_code_propagates(repo: TestRepo) {
+    let bin = install_plugin(&repo, "fail", "exit 3");
+
+    let output = wt_with_plugins(&repo, &bin, &["fail"], repo.root_path())
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(3));
+}
+
+#[rstest]
+fn test_builtin_command_shadows_plugin(repo: TestRepo) {
+    let bin = install_plugin(&repo, "list", "echo plugin");
+
+    let output = wt_with_plugins(&repo, &bin, &["list"], repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("plugin"));
+}
+
+#[rstest]
+fn test_unknown_command_without_plugin(repo: TestRepo) {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    let output = cmd
+        .arg("no-such-plugin")
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unrecognized subcommand"));
+}
@@ -626,6 +626,59 @@ approved-commands = ["sleep 0.1 && echo 'Background task done' > background.txt"
     wait_for_file(output_file.as_path(), Duration::from_secs(5));
 }
 
+#[rstest]
+fn test_post_create_background_entry(repo: TestRepo) {
+    // A background entry in a blocking hook runs detached after the others
+    repo.write_project_config(
+        r#"[post-create]
+setup = "echo setup > setup.txt"
+
+[post-create.slow]
+command = "sleep 0.1 && echo done > slow.txt"
+background = true
+"#,
+    );
+    repo.commit("Add background post-create entry");
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[projects."repo"]
+approved-commands = ["echo setup > setup.txt", "sleep 0.1 && echo done > slow.txt"]
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    assert!(worktree_path.join("setup.txt").exists());
+    wait_for_file(&worktree_path.join("slow.txt"), Duration::from_secs(5));
+
+    // The status file records the exit code once the command finishes
+    let log_dir = resolve_git_common_dir(&worktree_path).join("wt-logs");
+    let status_file = log_dir.join("feature-project-post-create-slow.status");
+    wait_for_file_lines(&status_file, 7, Duration::from_secs(5));
+
+    let output = repo
+        .wt_command()
+        .args(["hook", "status", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let hooks = json.as_array().unwrap();
+    assert_eq!(hooks.len(), 1, "{json}");
+    assert_eq!(hooks[0]["hook"], "post-create");
+    assert_eq!(hooks[0]["name"], "project:slow");
+    assert_eq!(hooks[0]["branch"], "feature");
+    assert_eq!(hooks[0]["state"], "succeeded");
+    assert_eq!(hooks[0]["exit_code"], 0);
+}
+
 #[rstest]
 fn test_post_start_multiple_background_commands(repo: TestRepo) {
     // Create project config with multiple background commands (table format)
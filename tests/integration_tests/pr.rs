@@ -0,0 +1,104 @@
+//! Integration tests for `wt pr`
+//!
+//! Skipped on Windows for the same reason as `ci_status.rs`: the mock `gh.bat`
+//! isn't found by `Command::new("gh")`.
+
+#![cfg(not(windows))]
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+/// Add a GitHub origin and a feature worktree, with mock `gh` returning `pr_json`
+fn setup_github_pr(repo: &mut TestRepo, pr_json: impl FnOnce(&str) -> String) {
+    repo.run_git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://github.com/test-owner/test-repo.git",
+    ]);
+    repo.add_worktree("feature");
+    let head_sha = repo.git_output(&["rev-parse", "feature"]);
+    repo.setup_mock_gh_with_ci_data(&pr_json(&head_sha), "[]");
+}
+
+fn snapshot_pr(test_name: &str, repo: &TestRepo, args: &[&str]) {
+    let settings = setup_snapshot_settings(repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(repo, "pr", args, None);
+        repo.configure_mock_commands(&mut cmd);
+        assert_cmd_snapshot!(test_name, cmd);
+    });
+}
+
+#[rstest]
+fn test_pr_shows_details(mut repo: TestRepo) {
+    setup_github_pr(&mut repo, |head_sha| {
+        format!(
+            r#"[{{
+            "number": 42,
+            "title": "Add login page",
+            "state": "OPEN",
+            "isDraft": false,
+            "reviewDecision": "CHANGES_REQUESTED",
+            "latestReviews": [
+                {{"author": {{"login": "alice"}}, "state": "APPROVED"}},
+                {{"author": {{"login": "bob"}}, "state": "CHANGES_REQUESTED"}}
+            ],
+            "headRefOid": "{head_sha}",
+            "mergeStateStatus": "CLEAN",
+            "statusCheckRollup": [{{"status": "COMPLETED", "conclusion": "SUCCESS"}}],
+            "url": "https://github.com/test-owner/test-repo/pull/42",
+            "headRepositoryOwner": {{"login": "test-owner"}}
+        }}]"#
+        )
+    });
+
+    snapshot_pr("pr_shows_details", &repo, &["feature"]);
+}
+
+#[rstest]
+fn test_pr_draft_without_reviews(mut repo: TestRepo) {
+    setup_github_pr(&mut repo, |head_sha| {
+        format!(
+            r#"[{{
+            "number": 7,
+            "title": "WIP: refactor",
+            "state": "OPEN",
+            "isDraft": true,
+            "reviewDecision": "",
+            "latestReviews": [],
+            "headRefOid": "{head_sha}",
+            "mergeStateStatus": "CLEAN",
+            "statusCheckRollup": [{{"status": "IN_PROGRESS", "conclusion": null}}],
+            "url": "https://github.com/test-owner/test-repo/pull/7",
+            "headRepositoryOwner": {{"login": "test-owner"}}
+        }}]"#
+        )
+    });
+
+    snapshot_pr("pr_draft_without_reviews", &repo, &["feature"]);
+}
+
+#[rstest]
+fn test_pr_not_found(mut repo: TestRepo) {
+    setup_github_pr(&mut repo, |_| "[]".to_string());
+
+    snapshot_pr("pr_not_found", &repo, &["feature"]);
+}
+
+#[rstest]
+fn test_pr_open(mut repo: TestRepo) {
+    setup_github_pr(&mut repo, |_| {
+        r#"[{
+            "number": 42,
+            "title": "Add login page",
+            "state": "OPEN",
+            "url": "https://github.com/test-owner/test-repo/pull/42",
+            "headRepositoryOwner": {"login": "test-owner"}
+        }]"#
+        .to_string()
+    });
+
+    snapshot_pr("pr_open", &repo, &["feature", "--open"]);
+}
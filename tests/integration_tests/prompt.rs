@@ -0,0 +1,65 @@
+//! Integration tests for `wt prompt`
+
+use crate::common::{TestRepo, repo, wt_command};
+use rstest::rstest;
+
+fn prompt(repo: &TestRepo, dir: &std::path::Path, args: &[&str]) -> String {
+    let output = repo
+        .wt_command()
+        .arg("prompt")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[rstest]
+fn test_prompt_worktree(mut repo: TestRepo) {
+    repo.run_git(&["config", "worktrunk.default-branch", "main"]);
+    let feature = repo.add_feature();
+
+    assert_eq!(prompt(&repo, &feature, &[]), "feature ↑1\n");
+
+    std::fs::write(feature.join("scratch.txt"), "wip").unwrap();
+    assert_eq!(prompt(&repo, &feature, &[]), "feature ↑1 *\n");
+    assert_eq!(prompt(&repo, repo.root_path(), &[]), "main\n");
+}
+
+#[rstest]
+fn test_prompt_json(mut repo: TestRepo) {
+    repo.run_git(&["config", "worktrunk.default-branch", "main"]);
+    let feature = repo.add_feature();
+
+    let json: serde_json::Value =
+        serde_json::from_str(&prompt(&repo, &feature, &["--format=json"])).unwrap();
+    assert_eq!(json["name"], "feature");
+    assert_eq!(json["branch"], "feature");
+    assert_eq!(json["ahead"], 1);
+    assert_eq!(json["behind"], 0);
+    assert_eq!(json["dirty"], false);
+}
+
+#[rstest]
+fn test_prompt_without_cached_default_branch(mut repo: TestRepo) {
+    // Counts are left out rather than detecting the default branch
+    let feature = repo.add_feature();
+
+    let json: serde_json::Value =
+        serde_json::from_str(&prompt(&repo, &feature, &["--format=json"])).unwrap();
+    assert!(json.get("ahead").is_none());
+    assert_eq!(prompt(&repo, &feature, &[]), "feature\n");
+}
+
+#[test]
+fn test_prompt_outside_repository() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = wt_command()
+        .arg("prompt")
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+}
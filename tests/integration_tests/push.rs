@@ -236,3 +236,102 @@ fn test_push_no_remote(#[from(repo_with_feature_worktree)] repo: TestRepo) {
     // Try to push without specifying target (should fail - no remote to get default branch)
     snapshot_push("push_no_remote", &repo, &[], Some(feature_wt));
 }
+
+#[rstest]
+fn test_push_format_json(mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "push", "main", "--format=json"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["action"], "push");
+    assert_eq!(json["branch"], "feature");
+    assert_eq!(json["target"], "main");
+    assert_eq!(json["commits"], 1);
+}
+
+/// With `[push] require-signed`, unsigned outgoing commits block the push and are listed
+#[rstest]
+fn test_push_require_signed_rejects_unsigned(mut repo: TestRepo) {
+    repo.write_test_config("[push]\nrequire-signed = true\n");
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+
+    snapshot_push(
+        "push_require_signed_rejects_unsigned",
+        &repo,
+        &["main"],
+        Some(&feature_wt),
+    );
+}
+
+#[rstest]
+fn test_push_require_signed_accepts_allowed_key(mut repo: TestRepo) {
+    let fingerprint = repo.setup_ssh_signing();
+    repo.write_test_config(&format!(
+        "[push]\nrequire-signed = true\nallowed-keys = [\"{fingerprint}\"]\n"
+    ));
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "push", "main"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Part of a fingerprint doesn't identify a key; only the whole one is accepted
+#[rstest]
+fn test_push_require_signed_rejects_partial_fingerprint(mut repo: TestRepo) {
+    let fingerprint = repo.setup_ssh_signing();
+    let suffix = &fingerprint[fingerprint.len() - 8..];
+    repo.write_test_config(&format!(
+        "[push]\nrequire-signed = true\nallowed-keys = [\"{suffix}\"]\n"
+    ));
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "push", "main"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("not an allowed key"), "{stderr}");
+}
+
+#[rstest]
+fn test_push_require_signed_rejects_other_key(mut repo: TestRepo) {
+    repo.setup_ssh_signing();
+    repo.write_test_config(
+        "[push]\nrequire-signed = true\nallowed-keys = [\"SHA256:someoneelse\"]\n",
+    );
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "push", "main"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("not an allowed key"), "{stderr}");
+    assert!(stderr.contains("Add test file"), "{stderr}");
+}
@@ -198,11 +198,13 @@ fn parse_snapshot_raw(content: &str) -> String {
     // Handle insta_cmd format with stdout/stderr sections
     if content.contains("----- stdout -----") {
         let stderr = extract_section(&content, "----- stderr -----\n", "----- ");
-        if !stderr.is_empty() {
-            return stderr;
-        }
         let stdout = extract_section(&content, "----- stdout -----\n", "----- stderr -----");
-        return stdout; // May be empty if both sections are empty
+        return match (stdout.is_empty(), stderr.is_empty()) {
+            // A table on stdout followed by hints on stderr, as the terminal shows them
+            (false, false) => format!("{stdout}\n{stderr}"),
+            (true, _) => stderr,
+            (false, true) => stdout, // May be empty if both sections are empty
+        };
     }
 
     // Plain content (PTY-based tests without section markers)
@@ -68,6 +68,28 @@ fn test_remove_from_worktree(mut repo: TestRepo) {
     snapshot_remove("remove_from_worktree", &repo, &[], Some(&worktree_path));
 }
 
+#[rstest]
+fn test_remove_by_path(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature-path");
+
+    // A worktree path works in place of its branch name
+    let path = worktree_path.to_string_lossy();
+    snapshot_remove("remove_by_path", &repo, &[&path], None);
+}
+
+#[rstest]
+fn test_remove_by_ordinal(mut repo: TestRepo) {
+    repo.add_worktree("feature-ordinal");
+    repo.wt_command()
+        .arg("list")
+        .env("WORKTRUNK_LIST_COLUMNS", "index,branch")
+        .output()
+        .unwrap();
+
+    // Row 2 of the last `wt list` (row 1 is main)
+    snapshot_remove("remove_by_ordinal", &repo, &["2"], None);
+}
+
 #[rstest]
 fn test_remove_internal_mode(mut repo: TestRepo) {
     let worktree_path = repo.add_worktree("feature-internal");
@@ -295,6 +317,88 @@ fn test_remove_multiple_including_current(mut repo: TestRepo) {
     );
 }
 
+#[rstest]
+fn test_remove_merged(mut repo: TestRepo) {
+    // Merged by fast-forward, then main moved on
+    repo.add_worktree_with_commit("landed", "landed.txt", "landed", "Land feature");
+    repo.run_git(&["merge", "--ff-only", "landed"]);
+    repo.commit("Advance main");
+    repo.add_worktree_with_commit("unmerged", "wip.txt", "wip", "Work in progress");
+    repo.add_worktree("locked");
+    repo.lock_worktree("locked", None);
+
+    snapshot_remove(
+        "remove_merged",
+        &repo,
+        &["--merged", "--no-background"],
+        None,
+    );
+
+    let branches = repo.git_output(&["branch", "--format=%(refname:short)"]);
+    assert!(!branches.lines().any(|b| b == "landed"), "{branches}");
+    assert!(branches.lines().any(|b| b == "unmerged"), "{branches}");
+    assert!(branches.lines().any(|b| b == "locked"), "{branches}");
+}
+
+#[rstest]
+fn test_remove_merged_none(mut repo: TestRepo) {
+    repo.add_worktree_with_commit("unmerged", "wip.txt", "wip", "Work in progress");
+
+    snapshot_remove("remove_merged_none", &repo, &["--merged"], None);
+}
+
+#[rstest]
+fn test_remove_pattern(mut repo: TestRepo) {
+    repo.add_worktree("experiment/a");
+    repo.add_worktree("experiment/b");
+    repo.add_worktree("experiment/locked");
+    repo.lock_worktree("experiment/locked", None);
+    repo.add_worktree("feature");
+
+    snapshot_remove(
+        "remove_pattern_dry_run",
+        &repo,
+        &["--pattern", "experiment/*", "--dry-run"],
+        None,
+    );
+    // Nothing to confirm with outside a terminal
+    snapshot_remove(
+        "remove_pattern_not_interactive",
+        &repo,
+        &["--pattern", "experiment/*"],
+        None,
+    );
+    let branches = repo.git_output(&["branch", "--format=%(refname:short)"]);
+    assert!(branches.lines().any(|b| b == "experiment/a"), "{branches}");
+
+    snapshot_remove(
+        "remove_pattern",
+        &repo,
+        &["--pattern", "experiment/*", "--yes", "--no-background"],
+        None,
+    );
+    let branches = repo.git_output(&["branch", "--format=%(refname:short)"]);
+    assert!(!branches.lines().any(|b| b == "experiment/a"), "{branches}");
+    assert!(!branches.lines().any(|b| b == "experiment/b"), "{branches}");
+    assert!(
+        branches.lines().any(|b| b == "experiment/locked"),
+        "{branches}"
+    );
+    assert!(branches.lines().any(|b| b == "feature"), "{branches}");
+}
+
+#[rstest]
+fn test_remove_pattern_none(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+
+    snapshot_remove(
+        "remove_pattern_none",
+        &repo,
+        &["--pattern", "experiment/*"],
+        None,
+    );
+}
+
 #[rstest]
 fn test_remove_branch_not_fully_merged(mut repo: TestRepo) {
     // Create a worktree with an unmerged commit
@@ -1308,3 +1412,64 @@ approved-commands = ["echo 'branch={{{{ branch }}}}' > {branch_path}"]
         "{{ branch }} should expand to 'HEAD' for detached HEAD worktrees"
     );
 }
+
+#[rstest]
+fn test_command_in_removed_worktree(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature");
+    repo.run_git(&["worktree", "remove", worktree_path.to_str().unwrap()]);
+    // A shell still in the worktree, with a build tool recreating the directory
+    std::fs::create_dir(&worktree_path).unwrap();
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let (directive_path, _guard) = directive_file();
+        let mut cmd = make_snapshot_cmd(&repo, "list", &[], Some(&worktree_path));
+        configure_directive_file(&mut cmd, &directive_path);
+        assert_cmd_snapshot!("command_in_removed_worktree", cmd);
+
+        let directive = std::fs::read_to_string(&directive_path).unwrap();
+        assert!(directive.starts_with("cd "), "{directive}");
+    });
+}
+
+#[rstest]
+fn test_remove_format_json(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature");
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "feature", "--no-background", "--format=json"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["action"], "remove");
+    assert_eq!(
+        json["removed_worktrees"][0],
+        worktree_path.to_str().unwrap()
+    );
+    assert_eq!(
+        json["deleted_refs"],
+        serde_json::json!(["refs/heads/feature"])
+    );
+    assert!(json.get("error").is_none());
+}
+
+#[rstest]
+fn test_remove_format_json_failure(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["remove", "--format=json"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["action"], "remove");
+    assert!(
+        json["error"]
+            .as_str()
+            .is_some_and(|error| !error.is_empty())
+    );
+}
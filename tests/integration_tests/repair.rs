@@ -0,0 +1,67 @@
+//! Integration tests for `wt repair`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+#[rstest]
+fn test_repair_relinks_broken_worktree(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    std::fs::write(
+        feature.join(".git"),
+        "gitdir: /nonexistent/.git/worktrees/feature\n",
+    )
+    .unwrap();
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "repair", &[], None);
+        assert_cmd_snapshot!("repair_broken_worktree", cmd);
+    });
+
+    // git works inside the worktree again
+    repo.run_git_in(&feature, &["status", "--short"]);
+}
+
+#[rstest]
+fn test_repair_nothing_broken(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "repair", &[], None);
+        assert_cmd_snapshot!("repair_nothing_broken", cmd);
+    });
+}
+
+#[rstest]
+fn test_repair_from_moved_repository(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    let moved = repo.root_path().with_file_name("moved");
+    std::fs::rename(repo.root_path(), &moved).unwrap();
+
+    // Inside the worktree, git can't find the repository; explain why
+    let output = repo
+        .wt_command()
+        .current_dir(&feature)
+        .arg("list")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("can't find its repository"), "{stderr}");
+    assert!(stderr.contains("wt repair --from"), "{stderr}");
+
+    let output = repo
+        .wt_command()
+        .current_dir(&feature)
+        .args(["repair", "--from"])
+        .arg(&moved)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Repaired worktree link for"), "{stderr}");
+
+    repo.run_git_in(&feature, &["status", "--short"]);
+}
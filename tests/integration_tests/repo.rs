@@ -0,0 +1,158 @@
+//! Integration tests for `wt repo`, the global `--repo` flag, and `wt list --all-repos`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, setup_snapshot_settings, wt_command};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::process::Command;
+
+/// `wt` run from outside any repository, with the test config
+fn wt_outside(repo: &TestRepo, args: &[&str]) -> Command {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(args).current_dir(repo.home_path());
+    cmd
+}
+
+fn register(repo: &TestRepo) {
+    let output = make_snapshot_cmd(repo, "repo", &["add"], None)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[rstest]
+fn test_repo_add_and_list(repo: TestRepo) {
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "repo", &["add"], None);
+        assert_cmd_snapshot!("repo_add", cmd);
+
+        let mut cmd = make_snapshot_cmd(&repo, "repo", &["add"], None);
+        assert_cmd_snapshot!("repo_add_again", cmd);
+
+        let mut cmd = make_snapshot_cmd(&repo, "repo", &["list"], None);
+        assert_cmd_snapshot!("repo_list", cmd);
+    });
+}
+
+#[rstest]
+fn test_repo_list_empty(repo: TestRepo) {
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "repo", &["list"], None);
+        assert_cmd_snapshot!("repo_list_empty", cmd);
+    });
+}
+
+#[rstest]
+fn test_repo_flag_targets_registered_repo(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    register(&repo);
+
+    let output = wt_outside(&repo, &["--repo", "repo", "list", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains(r#""branch": "feature""#),
+        "stdout: {stdout}"
+    );
+}
+
+#[rstest]
+fn test_repo_flag_unknown_name(repo: TestRepo) {
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = wt_outside(&repo, &["--repo", "nope", "list"]);
+        assert_cmd_snapshot!("repo_flag_unknown_name", cmd);
+    });
+}
+
+#[rstest]
+fn test_repo_remove(repo: TestRepo) {
+    register(&repo);
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = wt_outside(&repo, &["repo", "remove", "repo"]);
+        assert_cmd_snapshot!("repo_remove", cmd);
+    });
+
+    let output = wt_outside(&repo, &["repo", "list"]).output().unwrap();
+    assert!(String::from_utf8_lossy(&output.stdout).trim().is_empty());
+}
+
+/// A second repository under the test home, registered alongside `repo`
+fn add_other_repo(repo: &TestRepo) {
+    repo.run_git_in(repo.home_path(), &["init", "-q", "-b", "main", "other"]);
+    let other = repo.home_path().join("other");
+    repo.run_git_in(&other, &["commit", "--allow-empty", "-m", "Initial commit"]);
+    let output = wt_outside(repo, &["-C", "other", "repo", "add"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[rstest]
+fn test_list_all_repos(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    register(&repo);
+    add_other_repo(&repo);
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", &["--all-repos"], None);
+        assert_cmd_snapshot!("list_all_repos", cmd);
+    });
+}
+
+#[rstest]
+fn test_list_all_repos_json(repo: TestRepo) {
+    register(&repo);
+    add_other_repo(&repo);
+
+    let output = wt_outside(&repo, &["list", "--all-repos", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let repos: Vec<_> = items.iter().map(|item| item["repo"].as_str()).collect();
+    assert_eq!(repos, [Some("other"), Some("repo")]);
+    // Run from outside both repositories, so neither has a current worktree
+    assert!(items.iter().all(|item| item["is_current"] == false));
+}
+
+#[rstest]
+fn test_list_all_repos_missing_path(repo: TestRepo) {
+    register(&repo);
+    add_other_repo(&repo);
+    std::fs::remove_dir_all(repo.home_path().join("other")).unwrap();
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", &["--all-repos"], None);
+        assert_cmd_snapshot!("list_all_repos_missing_path", cmd);
+    });
+}
+
+#[rstest]
+fn test_list_all_repos_none_registered(repo: TestRepo) {
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", &["--all-repos"], None);
+        assert_cmd_snapshot!("list_all_repos_none_registered", cmd);
+    });
+}
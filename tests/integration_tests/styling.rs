@@ -0,0 +1,25 @@
+//! Integration tests for plain-text message tags (`--no-emoji`, `styling.emoji`)
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+#[rstest]
+fn test_no_emoji_flag(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "remove", &["--no-emoji", "feature"], None);
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
+#[rstest]
+fn test_emoji_disabled_in_config(repo: TestRepo) {
+    repo.write_test_config("[styling]\nemoji = false\n");
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "switch", &["missing-branch"], None);
+        assert_cmd_snapshot!(cmd);
+    });
+}
@@ -1,7 +1,7 @@
 use crate::common::{
-    TestRepo, configure_directive_file, directive_file, make_snapshot_cmd, repo, repo_with_remote,
-    set_temp_home_env, setup_home_snapshot_settings, setup_snapshot_settings, temp_home,
-    wt_command,
+    TestRepo, TestRepoBase, configure_directive_file, directive_file, make_snapshot_cmd, repo,
+    repo_with_remote, set_temp_home_env, setup_home_snapshot_settings, setup_snapshot_settings,
+    temp_home, wt_command,
 };
 use insta_cmd::assert_cmd_snapshot;
 use rstest::rstest;
@@ -90,6 +90,78 @@ fn test_switch_existing_branch(mut repo: TestRepo) {
     snapshot_switch("switch_existing_branch", &repo, &["feature-z"]);
 }
 
+/// A fully qualified ref for a branch that has a worktree goes to that worktree,
+/// rather than checking the ref out detached in a new one.
+#[rstest]
+fn test_switch_qualified_ref_to_existing_worktree(mut repo: TestRepo) {
+    repo.add_worktree("feature-z");
+
+    snapshot_switch(
+        "switch_qualified_ref_to_existing_worktree",
+        &repo,
+        &["refs/heads/feature-z"],
+    );
+    snapshot_switch(
+        "switch_heads_ref_to_existing_worktree",
+        &repo,
+        &["heads/feature-z"],
+    );
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert!(!worktrees.contains("detached"), "{worktrees}");
+}
+
+/// A branch checked out in a worktree wt didn't create (here, at a path
+/// outside the template) is found there, not checked out a second time.
+#[rstest]
+fn test_switch_branch_checked_out_elsewhere(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+    let elsewhere = repo.root_path().parent().unwrap().join("repo.elsewhere");
+    repo.run_git(&["worktree", "add", elsewhere.to_str().unwrap(), "feature"]);
+
+    snapshot_switch("switch_branch_checked_out_elsewhere", &repo, &["feature"]);
+}
+
+/// A number refers to that row of the last `wt list`.
+#[rstest]
+fn test_switch_by_ordinal(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.wt_command()
+        .arg("list")
+        .env("WORKTRUNK_LIST_COLUMNS", "index,branch")
+        .output()
+        .unwrap();
+    // Kept out of .git/config, which every listing would otherwise rewrite
+    let config = std::fs::read_to_string(repo.root_path().join(".git/config")).unwrap();
+    assert!(!config.contains("listing"), "{config}");
+
+    snapshot_switch("switch_by_ordinal", &repo, &["2"]);
+}
+
+/// A listing without the `#` column forgets the numbers of an earlier one.
+#[rstest]
+fn test_switch_by_ordinal_needs_shown_index(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.wt_command()
+        .arg("list")
+        .env("WORKTRUNK_LIST_COLUMNS", "index,branch")
+        .output()
+        .unwrap();
+    repo.wt_command().arg("list").output().unwrap();
+
+    let output = repo.wt_command().args(["switch", "2"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(!repo.root_path().join(".git/worktrunk/listing").exists());
+}
+
+/// Switching to a detached-HEAD worktree by path names it by its SHA and suggests creating a branch.
+#[rstest]
+fn test_switch_detached_worktree(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.detach_head_in_worktree("feature");
+
+    snapshot_switch("switch_detached_worktree", &repo, &["../repo.feature"]);
+}
+
 /// Test switching to existing worktree when shell integration is configured but not active.
 ///
 /// When shell integration is configured in user's rc files (e.g., .zshrc) but the user
@@ -132,6 +204,209 @@ fn test_switch_with_base_branch(repo: TestRepo) {
     );
 }
 
+#[rstest]
+fn test_switch_with_base_tag(repo: TestRepo) {
+    repo.run_git(&["tag", "v1.0"]);
+    repo.commit("After the release");
+
+    snapshot_switch(
+        "switch_with_base_tag",
+        &repo,
+        &["--create", "--base", "v1.0", "fix-1.0"],
+    );
+
+    // The tag is remembered, and ahead/behind counts against it rather than main
+    assert_eq!(
+        repo.git_output(&["config", "worktrunk.state.fix-1.0.base"]),
+        "v1.0"
+    );
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let fix = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "fix-1.0")
+        .unwrap();
+    assert_eq!(fix["main"]["behind"], 0);
+
+    // Branch bases aren't recorded
+    repo.wt_command()
+        .args(["switch", "--create", "--base", "main", "feature"])
+        .output()
+        .unwrap();
+    let output = repo
+        .git_command()
+        .args(["config", "worktrunk.state.feature.base"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+#[rstest]
+fn test_switch_with_base_tag_worktree_config(repo: TestRepo) {
+    repo.run_git(&["config", "extensions.worktreeConfig", "true"]);
+    repo.run_git(&["tag", "v1.0"]);
+    repo.commit("After the release");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "--base", "v1.0", "fix-1.0"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // The base lives in the new worktree's own config, where `wt list` reads it
+    let output = repo
+        .git_command()
+        .args(["config", "--local", "--get", "worktrunk.state.fix-1.0.base"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let fix = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "fix-1.0")
+        .unwrap();
+    assert_eq!(fix["main"]["behind"], 0);
+    let worktree = fix["path"].as_str().unwrap();
+    let output = repo
+        .git_command()
+        .current_dir(worktree)
+        .args([
+            "config",
+            "--worktree",
+            "--get",
+            "worktrunk.state.fix-1.0.base",
+        ])
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "v1.0");
+}
+
+#[rstest]
+fn test_switch_create_cherry_pick_range(mut repo: TestRepo) {
+    let tangled = repo.add_worktree("tangled");
+    repo.commit_in_worktree(&tangled, "api.txt", "api", "Add API");
+    repo.commit_in_worktree(&tangled, "docs.txt", "docs", "Document API");
+    repo.commit_in_worktree(&tangled, "wip.txt", "wip", "WIP");
+
+    snapshot_switch(
+        "switch_create_cherry_pick_range",
+        &repo,
+        &[
+            "--create",
+            "extract",
+            "--cherry-pick",
+            "tangled~3..tangled~1",
+        ],
+    );
+
+    let log = repo.git_output(&["log", "--format=%s", "main..extract"]);
+    assert_eq!(log, "Document API\nAdd API");
+}
+
+#[rstest]
+fn test_switch_create_cherry_pick_conflict(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature, "file.txt", "feature", "Change file");
+    repo.commit("Change file on main");
+
+    snapshot_switch(
+        "switch_create_cherry_pick_conflict",
+        &repo,
+        &["--create", "extract", "--cherry-pick", "feature"],
+    );
+
+    // The worktree is created with the cherry-pick left in progress
+    let output = repo
+        .git_command()
+        .args(["rev-parse", "--verify", "--quiet", "extract^{commit}"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+}
+
+#[rstest]
+fn test_switch_create_cherry_pick_invalid(repo: TestRepo) {
+    snapshot_switch(
+        "switch_create_cherry_pick_invalid",
+        &repo,
+        &["--create", "extract", "--cherry-pick", "nope..main"],
+    );
+
+    // Nothing is created for a revision that doesn't exist
+    let output = repo
+        .git_command()
+        .args(["rev-parse", "--verify", "--quiet", "extract"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+#[rstest]
+fn test_switch_create_orphan(repo: TestRepo) {
+    repo.commit("Initial commit on main");
+
+    snapshot_switch(
+        "switch_create_orphan",
+        &repo,
+        &["--create", "gh-pages", "--orphan"],
+    );
+
+    // One parentless commit with an empty tree, and nothing checked out
+    let worktree = repo.root_path().parent().unwrap().join("repo.gh-pages");
+    assert_eq!(repo.git_output(&["rev-list", "--count", "gh-pages"]), "1");
+    assert_eq!(
+        repo.git_output(&["ls-tree", "-r", "--name-only", "gh-pages"]),
+        ""
+    );
+    let entries: Vec<_> = std::fs::read_dir(&worktree)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(entries, [".git"]);
+
+    // With no merge base, every commit the branch has counts as ahead
+    std::fs::write(worktree.join("index.html"), "<h1>Docs</h1>\n").unwrap();
+    repo.run_git_in(&worktree, &["add", "index.html"]);
+    repo.run_git_in(&worktree, &["commit", "-m", "Add docs page"]);
+    let output = repo
+        .wt_command()
+        .args(["list", "--full", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let pages = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "gh-pages")
+        .unwrap();
+    assert_eq!(pages["main"]["ahead"], 2);
+}
+
+#[rstest]
+fn test_switch_orphan_with_base(repo: TestRepo) {
+    snapshot_switch(
+        "switch_orphan_with_base",
+        &repo,
+        &["--create", "gh-pages", "--orphan", "--base", "main"],
+    );
+}
+
 #[rstest]
 fn test_switch_base_without_create_warning(repo: TestRepo) {
     snapshot_switch(
@@ -503,6 +778,22 @@ fn test_switch_error_path_occupied_detached(repo: TestRepo) {
     snapshot_switch_with_directive_file("switch_error_path_occupied_detached", &repo, &["feature"]);
 }
 
+/// Test that `feat/x` fails early when `feat-x`'s worktree is at the same path
+#[rstest]
+fn test_switch_error_path_collision(mut repo: TestRepo) {
+    repo.add_worktree("feat-x");
+
+    snapshot_switch(
+        "switch_error_path_collision_create",
+        &repo,
+        &["--create", "feat/x"],
+    );
+
+    // An existing branch gets a rename suggested instead
+    repo.run_git(&["branch", "feat/x"]);
+    snapshot_switch("switch_error_path_collision", &repo, &["feat/x"]);
+}
+
 /// Test switching to default branch when main worktree is on a different branch
 ///
 /// When the main worktree (repo root) has been switched to a feature branch via
@@ -895,3 +1186,219 @@ fn test_switch_clobber_path_with_extension(repo: TestRepo) {
         "file with extension"
     );
 }
+
+/// With `[switch] init-submodules`, new worktrees get their submodules checked
+/// out, and `wt list --format=json` stops reporting them as uninitialized
+#[rstest]
+fn test_switch_create_init_submodules(repo: TestRepo) {
+    use std::io::Write;
+
+    // Local-path submodules are blocked by default since git 2.38
+    std::fs::OpenOptions::new()
+        .append(true)
+        .open(repo.git_config_path())
+        .unwrap()
+        .write_all(b"[protocol \"file\"]\n\tallow = always\n")
+        .unwrap();
+    let library = TempDir::new().unwrap();
+    repo.run_git_in(library.path(), &["init", "--quiet", "-b", "main"]);
+    std::fs::write(library.path().join("lib.txt"), "library\n").unwrap();
+    repo.run_git_in(library.path(), &["add", "."]);
+    repo.run_git_in(library.path(), &["commit", "--quiet", "-m", "Library"]);
+    repo.run_git(&[
+        "submodule",
+        "add",
+        "--quiet",
+        library.path().to_str().unwrap(),
+        "vendor/lib",
+    ]);
+    repo.run_git(&["commit", "--quiet", "-m", "Add submodule"]);
+
+    let submodules = |repo: &TestRepo, branch: &str| {
+        let output = repo
+            .wt_command()
+            .args(["list", "--format=json"])
+            .output()
+            .unwrap();
+        let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        items
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|item| item["branch"] == branch)
+            .map(|item| item["worktree"]["submodules"].clone())
+    };
+
+    // Without the option, the submodule directory is left empty
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "sub-off"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        submodules(&repo, "sub-off").unwrap(),
+        serde_json::json!({"uninitialized": 1, "out_of_sync": 0, "dirty": 0})
+    );
+    repo.write_test_config("[switch]\ninit-submodules = true\n");
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "sub-on"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let worktree = repo.root_path().parent().unwrap().join("repo.sub-on");
+    assert!(worktree.join("vendor/lib/lib.txt").exists());
+    assert_eq!(
+        submodules(&repo, "sub-on").unwrap(),
+        serde_json::json!({"uninitialized": 0, "out_of_sync": 0, "dirty": 0})
+    );
+}
+
+#[rstest]
+fn test_switch_format_json(repo: TestRepo) {
+    repo.write_project_config(r#"post-create = "true""#);
+    repo.run_git(&["add", ".config"]);
+    repo.run_git(&["commit", "-m", "Add project config"]);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature", "--yes", "--format=json"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["action"], "switch");
+    assert_eq!(json["branch"], "feature");
+    assert_eq!(
+        json["created_refs"],
+        serde_json::json!(["refs/heads/feature"])
+    );
+    assert_eq!(json["created_worktrees"][0], json["path"]);
+    assert_eq!(json["hooks"][0]["hook"], "post-create");
+    assert_eq!(json["hooks"][0]["name"], "project");
+    assert_eq!(json["hooks"][0]["status"], "succeeded");
+}
+
+/// Mock `gh` answering `gh issue view 1234` with an issue on test-owner/test-repo
+#[cfg(not(windows))]
+fn setup_mock_issue(repo: &mut TestRepo) {
+    use std::os::unix::fs::PermissionsExt;
+
+    repo.run_git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://github.com/test-owner/test-repo.git",
+    ]);
+    repo.setup_mock_gh();
+    let gh = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join("mock-bin")
+        .join("gh");
+    std::fs::write(
+        &gh,
+        r#"#!/bin/sh
+if [ "$1 $2 $3" = "issue view 1234" ]; then
+    echo '{"title": "Fix login redirect: keep the return URL", "url": "https://github.com/test-owner/test-repo/issues/1234"}'
+    exit 0
+fi
+echo "issue not found" >&2
+exit 1
+"#,
+    )
+    .unwrap();
+    std::fs::set_permissions(&gh, std::fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+#[cfg(not(windows))]
+#[rstest]
+fn test_switch_create_from_issue(mut repo: TestRepo) {
+    setup_mock_issue(&mut repo);
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd =
+            make_snapshot_cmd(&repo, "switch", &["--create", "--from-issue", "1234"], None);
+        repo.configure_mock_commands(&mut cmd);
+        assert_cmd_snapshot!("switch_create_from_issue", cmd);
+    });
+
+    let branch = "1234-fix-login-redirect-keep-the-return-url";
+    repo.run_git(&["rev-parse", "--verify", branch]);
+    assert_eq!(
+        repo.git_output(&["config", &format!("worktrunk.state.{branch}.issue")]),
+        "https://github.com/test-owner/test-repo/issues/1234"
+    );
+}
+
+#[cfg(not(windows))]
+#[rstest]
+fn test_switch_create_from_issue_template(mut repo: TestRepo) {
+    setup_mock_issue(&mut repo);
+    repo.write_test_config("[switch]\nissue-branch = \"issue/{{ ticket }}\"\n");
+
+    let mut cmd = repo.wt_command();
+    cmd.args(["switch", "--create", "--from-issue", "1234"]);
+    repo.configure_mock_commands(&mut cmd);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    repo.run_git(&["rev-parse", "--verify", "issue/1234"]);
+}
+
+#[cfg(not(windows))]
+#[rstest]
+fn test_switch_create_from_missing_issue(mut repo: TestRepo) {
+    setup_mock_issue(&mut repo);
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "switch", &["--create", "--from-issue", "99"], None);
+        repo.configure_mock_commands(&mut cmd);
+        assert_cmd_snapshot!("switch_create_from_missing_issue", cmd);
+    });
+}
+
+#[rstest]
+fn test_switch_create_over_worktree_budget(mut repo: TestRepo) {
+    repo.add_worktree("feature-a");
+    repo.write_test_config("[limits]\nmax-worktrees = 2\n");
+
+    snapshot_switch(
+        "switch_create_over_worktree_budget",
+        &repo,
+        &["--create", "feature-b"],
+    );
+}
+
+#[rstest]
+fn test_switch_create_strict_over_worktree_budget(mut repo: TestRepo) {
+    repo.add_worktree("feature-a");
+    repo.write_test_config("[limits]\nmax-worktrees = 2\n");
+
+    snapshot_switch(
+        "switch_create_strict_over_worktree_budget",
+        &repo,
+        &["--create", "feature-b", "--strict"],
+    );
+    assert!(
+        !repo
+            .root_path()
+            .parent()
+            .unwrap()
+            .join("repo.feature-b")
+            .exists(),
+        "--strict should refuse to create the worktree"
+    );
+}
@@ -0,0 +1,193 @@
+//! Integration tests for `wt sync`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, repo_with_remote, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+/// A `feature` worktree and a `release/1.0` worktree, each a commit ahead of
+/// `main`, which has moved on since they branched
+fn setup_behind_main(repo: &mut TestRepo) -> (std::path::PathBuf, std::path::PathBuf) {
+    let feature = repo.add_feature();
+    let release =
+        repo.add_worktree_with_commit("release/1.0", "release.txt", "release", "Prepare release");
+    repo.commit_in_worktree(repo.root_path(), "main.txt", "main", "Advance main");
+    (feature, release)
+}
+
+/// Whether HEAD in `dir` is a merge commit
+fn head_is_merge(repo: &TestRepo, dir: &std::path::Path) -> bool {
+    let output = repo
+        .git_command()
+        .current_dir(dir)
+        .args(["rev-parse", "--verify", "--quiet", "HEAD^2"])
+        .output()
+        .unwrap();
+    output.status.success()
+}
+
+#[rstest]
+fn test_sync_rebases_by_default(mut repo: TestRepo) {
+    let (feature, _) = setup_behind_main(&mut repo);
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "sync", &[], Some(&feature));
+        assert_cmd_snapshot!("sync_rebase", cmd);
+    });
+
+    assert!(!head_is_merge(&repo, &feature));
+    repo.run_git_in(&feature, &["merge-base", "--is-ancestor", "main", "HEAD"]);
+}
+
+#[rstest]
+fn test_sync_branch_pattern_merges(mut repo: TestRepo) {
+    let (feature, release) = setup_behind_main(&mut repo);
+    repo.write_test_config(
+        r#"
+[sync]
+strategy = "rebase"
+
+[sync.branches]
+"release/*" = "merge"
+"#,
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "sync", &[], Some(&release));
+        assert_cmd_snapshot!("sync_merge_by_pattern", cmd);
+    });
+    assert!(head_is_merge(&repo, &release));
+
+    // Branches no pattern matches use the default strategy
+    let output = repo
+        .wt_command()
+        .current_dir(&feature)
+        .arg("sync")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(!head_is_merge(&repo, &feature));
+}
+
+#[rstest]
+fn test_sync_strategy_flag_overrides_config(mut repo: TestRepo) {
+    let (feature, _) = setup_behind_main(&mut repo);
+    repo.write_test_config("[sync]\nstrategy = \"rebase\"\n");
+
+    let output = repo
+        .wt_command()
+        .current_dir(&feature)
+        .args(["sync", "--strategy", "merge"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Merged"), "{stderr}");
+    assert!(head_is_merge(&repo, &feature));
+}
+
+#[rstest]
+fn test_sync_already_up_to_date(mut repo: TestRepo) {
+    let feature = repo.add_feature();
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "sync", &["--strategy", "merge"], Some(&feature));
+        assert_cmd_snapshot!("sync_up_to_date", cmd);
+    });
+}
+
+#[rstest]
+fn test_sync_merge_conflict(mut repo: TestRepo) {
+    let feature =
+        repo.add_worktree_with_commit("feature", "shared.txt", "feature side", "Feature edit");
+    repo.commit_in_worktree(repo.root_path(), "shared.txt", "main side", "Main edit");
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "sync", &["--strategy", "merge"], Some(&feature));
+        assert_cmd_snapshot!("sync_merge_conflict", cmd);
+    });
+
+    // The merge is left in progress for the user to resolve
+    repo.run_git_in(&feature, &["rev-parse", "--verify", "MERGE_HEAD"]);
+}
+
+/// A `feature` worktree whose upstream got a commit the local branch doesn't
+/// have, while the local branch got one of its own
+fn setup_diverged_upstream(repo: &mut TestRepo) -> std::path::PathBuf {
+    let feature = repo.add_worktree_with_commit("feature", "remote.txt", "remote", "Remote edit");
+    repo.run_git_in(&feature, &["push", "--quiet", "-u", "origin", "feature"]);
+    repo.run_git_in(&feature, &["reset", "--hard", "HEAD~1"]);
+    std::fs::write(feature.join("local.txt"), "local").unwrap();
+    repo.run_git_in(&feature, &["add", "local.txt"]);
+    repo.run_git_in(&feature, &["commit", "-m", "Local edit"]);
+    feature
+}
+
+#[rstest]
+fn test_sync_rebase_onto_upstream(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature = setup_diverged_upstream(&mut repo);
+
+    // Run from the main worktree: the rebase happens in feature's
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(
+            &repo,
+            "sync",
+            &["--branch", "feature", "--rebase-onto-upstream"],
+            None,
+        );
+        assert_cmd_snapshot!("sync_rebase_onto_upstream", cmd);
+    });
+
+    repo.run_git_in(
+        &feature,
+        &["merge-base", "--is-ancestor", "origin/feature", "HEAD"],
+    );
+    assert!(feature.join("local.txt").exists());
+    assert!(feature.join("remote.txt").exists());
+}
+
+#[rstest]
+fn test_sync_rebase_onto_upstream_up_to_date(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature = repo.add_worktree_with_commit("feature", "f.txt", "f", "Add f");
+    repo.run_git_in(&feature, &["push", "--quiet", "-u", "origin", "feature"]);
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "sync", &["--rebase-onto-upstream"], Some(&feature));
+        assert_cmd_snapshot!("sync_rebase_onto_upstream_up_to_date", cmd);
+    });
+}
+
+#[rstest]
+fn test_sync_rebase_onto_upstream_without_upstream(mut repo: TestRepo) {
+    repo.add_feature();
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(
+            &repo,
+            "sync",
+            &["--branch", "feature", "--rebase-onto-upstream"],
+            None,
+        );
+        assert_cmd_snapshot!("sync_rebase_onto_upstream_no_upstream", cmd);
+    });
+}
+
+#[rstest]
+fn test_list_hints_diverged_upstream(#[from(repo_with_remote)] mut repo: TestRepo) {
+    setup_diverged_upstream(&mut repo);
+
+    let output = make_snapshot_cmd(&repo, "list", &[], None)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("wt sync --branch feature --rebase-onto-upstream"),
+        "{stderr}"
+    );
+}
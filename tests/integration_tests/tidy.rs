@@ -0,0 +1,77 @@
+//! Integration tests for `wt tidy`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, repo_with_remote, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+const DAY: i64 = 24 * 60 * 60;
+
+fn snapshot_tidy(test_name: &str, repo: &TestRepo, args: &[&str]) {
+    let settings = setup_snapshot_settings(repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(repo, "tidy", args, None);
+        assert_cmd_snapshot!(test_name, cmd);
+    });
+}
+
+#[rstest]
+fn test_tidy_report(#[from(repo_with_remote)] mut repo: TestRepo) {
+    // No commits beyond main
+    repo.add_worktree("merged");
+    repo.create_branch("merged-branch");
+
+    // Pushed, then deleted from the remote
+    let gone = repo.add_worktree_with_commit("gone", "gone.txt", "gone", "Add gone");
+    repo.run_git_in(&gone, &["push", "--quiet", "-u", "origin", "gone"]);
+    repo.run_git(&["push", "--quiet", "origin", "--delete", "gone"]);
+
+    // Last commit two months ago, with and without uncommitted changes
+    let stale = repo.add_worktree("stale");
+    std::fs::write(stale.join("stale.txt"), "stale").unwrap();
+    repo.run_git_in(&stale, &["add", "stale.txt"]);
+    repo.commit_staged_with_age("Old work", 60 * DAY, &stale);
+    let abandoned = repo.add_worktree("abandoned");
+    std::fs::write(abandoned.join("abandoned.txt"), "abandoned").unwrap();
+    repo.run_git_in(&abandoned, &["add", "abandoned.txt"]);
+    repo.commit_staged_with_age("Old work", 60 * DAY, &abandoned);
+    std::fs::write(abandoned.join("notes.txt"), "unfinished").unwrap();
+
+    // Changes the same file as main
+    repo.add_worktree_with_commit("conflict", "shared.txt", "branch", "Change shared");
+    repo.commit_in_worktree(
+        repo.root_path(),
+        "shared.txt",
+        "main",
+        "Change shared on main",
+    );
+
+    // Recent, unmerged, and merges cleanly: not reported
+    repo.add_worktree_with_commit("active", "active.txt", "active", "Add active");
+
+    // No shared history, so nothing to conflict with: not reported
+    repo.wt_command()
+        .args(["switch", "--create", "site", "--orphan"])
+        .output()
+        .unwrap();
+    let site = repo.root_path().parent().unwrap().join("repo.site");
+    std::fs::write(site.join("shared.txt"), "site").unwrap();
+    repo.run_git_in(&site, &["add", "shared.txt"]);
+    repo.run_git_in(&site, &["commit", "-m", "Add site"]);
+
+    snapshot_tidy("tidy_report", &repo, &[]);
+}
+
+#[rstest]
+fn test_tidy_nothing_to_tidy(mut repo: TestRepo) {
+    repo.add_worktree_with_commit("feature", "feature.txt", "feature", "Add feature");
+
+    snapshot_tidy("tidy_nothing", &repo, &[]);
+}
+
+#[rstest]
+fn test_tidy_stale_after(mut repo: TestRepo) {
+    repo.add_worktree_with_commit("feature", "feature.txt", "feature", "Add feature");
+
+    // Test commits are a day old
+    snapshot_tidy("tidy_stale_after", &repo, &["--stale-after", "12h"]);
+}
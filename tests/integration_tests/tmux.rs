@@ -0,0 +1,123 @@
+//! Integration tests for `wt tmux`, against a stand-in `tmux` that logs its
+//! arguments
+#![cfg(unix)]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+/// Put a fake `tmux` first on PATH. It logs each invocation and reports
+/// sessions and windows listed in `existing` as present.
+fn fake_tmux(repo: &TestRepo, cmd: &mut Command, existing: &[&str]) -> PathBuf {
+    use std::os::unix::fs::PermissionsExt;
+
+    let bin = repo.home_path().join("fake-bin");
+    std::fs::create_dir_all(&bin).unwrap();
+    let log = repo.home_path().join("tmux.log");
+    let script = format!(
+        r#"#!/bin/sh
+echo "$*" >> '{log}'
+case "$1" in
+  has-session) case " {existing} " in *" ${{3#=}} "*) exit 0;; esac; exit 1;;
+  list-windows) for w in {existing}; do echo "$w"; done;;
+esac
+"#,
+        log = log.display(),
+        existing = existing.join(" "),
+    );
+    let tmux = bin.join("tmux");
+    std::fs::write(&tmux, script).unwrap();
+    std::fs::set_permissions(&tmux, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let path = std::env::var("PATH").unwrap_or_default();
+    cmd.env("PATH", format!("{}:{path}", bin.display()));
+    cmd.env_remove("TMUX");
+    log
+}
+
+fn run(mut cmd: Command, dir: &Path) -> String {
+    let output = cmd.current_dir(dir).output().unwrap();
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stderr).unwrap()
+}
+
+fn read_log(log: &Path) -> Vec<String> {
+    std::fs::read_to_string(log)
+        .unwrap_or_default()
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+#[rstest]
+fn test_tmux_creates_and_attaches_session(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature/auth");
+    let mut cmd = repo.wt_command();
+    cmd.args(["tmux", "feature/auth"]);
+    let log = fake_tmux(&repo, &mut cmd, &[]);
+
+    let stderr = run(cmd, repo.root_path());
+    assert!(stderr.contains("Created tmux session"), "{stderr}");
+    assert_eq!(
+        read_log(&log),
+        [
+            "has-session -t =feature-auth".to_string(),
+            format!("new-session -d -s feature-auth -c {}", feature.display()),
+            "attach-session -t =feature-auth".to_string(),
+        ]
+    );
+}
+
+#[rstest]
+fn test_tmux_attaches_existing_session(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    let mut cmd = repo.wt_command();
+    cmd.arg("tmux");
+    let log = fake_tmux(&repo, &mut cmd, &["feature"]);
+
+    let stderr = run(cmd, &feature);
+    assert!(stderr.contains("Switching to tmux session"), "{stderr}");
+    assert_eq!(
+        read_log(&log),
+        ["has-session -t =feature", "attach-session -t =feature"]
+    );
+}
+
+#[rstest]
+fn test_tmux_window_inside_tmux(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    let mut cmd = repo.wt_command();
+    cmd.args(["tmux", "feature"]);
+    let log = fake_tmux(&repo, &mut cmd, &["main"]);
+    cmd.env("TMUX", "/tmp/tmux-1000/default,1,0");
+
+    let stderr = run(cmd, repo.root_path());
+    assert!(stderr.contains("Created tmux window"), "{stderr}");
+    assert_eq!(
+        read_log(&log),
+        [
+            "list-windows -F #{window_name}".to_string(),
+            format!("new-window -d -n feature -c {}", feature.display()),
+            "select-window -t :=feature".to_string(),
+        ]
+    );
+}
+
+#[rstest]
+fn test_switch_create_with_tmux_config(repo: TestRepo) {
+    repo.write_test_config("[switch]\ntmux = true\n");
+    let mut cmd = repo.wt_command();
+    cmd.args(["switch", "--create", "feature"]);
+    let log = fake_tmux(&repo, &mut cmd, &[]);
+
+    let stderr = run(cmd, repo.root_path());
+    assert!(stderr.contains("Created tmux session"), "{stderr}");
+    let log = read_log(&log);
+    assert_eq!(log.len(), 2, "{log:?}");
+    assert!(
+        log[1].starts_with("new-session -d -s feature -c "),
+        "{log:?}"
+    );
+}
@@ -0,0 +1,93 @@
+//! Integration tests for `wt trust`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use worktrunk::config::approval_id;
+
+/// Approve `commands` for this repo and one for another project
+fn write_approvals(repo: &TestRepo, commands: &[&str]) {
+    let project_id = repo.root_path().file_name().unwrap().to_str().unwrap();
+    let commands: Vec<String> = commands.iter().map(|c| format!("{c:?}")).collect();
+    repo.write_test_config(&format!(
+        r#"[projects."{project_id}"]
+approved-commands = [{}]
+
+[projects."github.com/other/app"]
+approved-commands = ["make install"]
+"#,
+        commands.join(", ")
+    ));
+}
+
+#[rstest]
+fn test_trust_list(repo: TestRepo) {
+    write_approvals(&repo, &["npm ci", "npm test"]);
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(&repo, "trust", &["list"], None));
+    });
+}
+
+#[rstest]
+fn test_trust_list_all(repo: TestRepo) {
+    write_approvals(&repo, &["npm ci"]);
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(&repo, "trust", &["list", "--all"], None));
+    });
+}
+
+#[rstest]
+fn test_trust_list_empty(repo: TestRepo) {
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(&repo, "trust", &["list"], None));
+    });
+}
+
+#[rstest]
+fn test_trust_revoke_by_id_prefix(repo: TestRepo) {
+    write_approvals(&repo, &["npm ci", "npm test"]);
+    let id = approval_id("npm test");
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(
+            &repo,
+            "trust",
+            &["revoke", &id[..5]],
+            None
+        ));
+    });
+
+    let config = std::fs::read_to_string(repo.test_config_path()).unwrap();
+    assert!(config.contains("npm ci"), "{config}");
+    assert!(!config.contains("npm test"), "{config}");
+    assert!(config.contains("make install"), "{config}");
+}
+
+#[rstest]
+fn test_trust_revoke_other_project(repo: TestRepo) {
+    write_approvals(&repo, &["npm ci"]);
+    let output = make_snapshot_cmd(&repo, "trust", &["revoke", "make install"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let config = std::fs::read_to_string(repo.test_config_path()).unwrap();
+    assert!(!config.contains("github.com/other/app"), "{config}");
+}
+
+#[rstest]
+fn test_trust_revoke_no_match(repo: TestRepo) {
+    write_approvals(&repo, &["npm ci"]);
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(make_snapshot_cmd(
+            &repo,
+            "trust",
+            &["revoke", "cargo test"],
+            None
+        ));
+    });
+}
@@ -7,8 +7,8 @@
 //! - Skipped together with project hooks via --no-verify
 
 use crate::common::{
-    TestRepo, make_snapshot_cmd, repo, resolve_git_common_dir, setup_snapshot_settings,
-    wait_for_file, wait_for_file_content, wait_for_file_count,
+    TestRepo, make_snapshot_cmd, make_snapshot_cmd_with_global_flags, repo, resolve_git_common_dir,
+    setup_snapshot_settings, wait_for_file, wait_for_file_content, wait_for_file_count,
 };
 use insta_cmd::assert_cmd_snapshot;
 use rstest::rstest;
@@ -60,6 +60,38 @@ log = "echo 'USER_POST_CREATE_RAN' > user_hook_marker.txt"
     );
 }
 
+#[rstest]
+fn test_branch_and_repo_template_variables(repo: TestRepo) {
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ ticket }}.{{ branch_slug }}"
+
+[post-create]
+vars = "echo '{{ ticket }}|{{ branch_slug }}|{{ user }}|{{ default_branch }}|{{ date }}' > vars.txt"
+"#,
+    );
+
+    let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature/PROJ-7_Login"], None)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let worktree_path = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join("repo.PROJ-7.feature-proj-7-login");
+    let contents = fs::read_to_string(worktree_path.join("vars.txt")).unwrap();
+    // SOURCE_DATE_EPOCH is 2025-01-02T00:00Z; the date is local
+    assert!(
+        contents.starts_with("PROJ-7|feature-proj-7-login|Test User|main|2025-01-0"),
+        "{contents}"
+    );
+}
+
 #[rstest]
 fn test_user_hooks_run_before_project_hooks(repo: TestRepo) {
     // Create project config with post-create hook
@@ -694,6 +726,335 @@ vars = "echo 'repo={{ repo }} branch={{ branch }}' > template_vars.txt"
     );
 }
 
+/// Run `wt` with `args` in `cwd`, asserting success
+fn run_wt(repo: &TestRepo, subcommand: &str, args: &[&str], cwd: Option<&std::path::Path>) {
+    let output = make_snapshot_cmd(repo, subcommand, args, cwd)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[rstest]
+fn test_user_hook_environment_variables(repo: TestRepo) {
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+post-create = "echo \"$WT_HOOK_TYPE $WT_BRANCH $WT_BASE_BRANCH\" > env.txt; echo \"$WT_WORKTREE\" >> env.txt; echo \"$WT_REPO_ROOT\" >> env.txt"
+post-start = "echo \"$WT_HOOK_TYPE $WT_BRANCH $WT_BASE_BRANCH\" > bg_env.txt"
+"#,
+    );
+
+    run_wt(&repo, "switch", &["--create", "feature"], None);
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    let contents = fs::read_to_string(worktree_path.join("env.txt")).unwrap();
+    let lines: Vec<_> = contents.lines().collect();
+    assert_eq!(lines[0], "post-create feature main");
+    assert!(
+        lines[1].ends_with("repo.feature"),
+        "WT_WORKTREE: {}",
+        lines[1]
+    );
+    assert!(lines[2].ends_with("repo"), "WT_REPO_ROOT: {}", lines[2]);
+
+    // Background hooks get the same variables
+    let bg_file = worktree_path.join("bg_env.txt");
+    wait_for_file_content(&bg_file, Duration::from_secs(5));
+    let contents = fs::read_to_string(&bg_file).unwrap();
+    assert_eq!(contents.trim(), "post-start feature main");
+}
+
+#[rstest]
+fn test_user_hook_environment_base_branch_is_merge_target(mut repo: TestRepo) {
+    repo.run_git(&["branch", "develop"]);
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "feature.txt", "feature content", "Add feature");
+    repo.write_test_config(
+        r#"pre-merge = "echo \"$WT_HOOK_TYPE $WT_BASE_BRANCH\" > env.txt"
+"#,
+    );
+
+    run_wt(
+        &repo,
+        "merge",
+        &["develop", "--yes", "--no-remove"],
+        Some(&feature_wt),
+    );
+
+    let contents = fs::read_to_string(feature_wt.join("env.txt")).unwrap();
+    assert_eq!(contents.trim(), "pre-merge develop");
+}
+
+#[rstest]
+fn test_user_hooks_parallel(mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "feature.txt", "feature content", "Add feature");
+    // `wait` only succeeds if `signal` runs while it's still polling
+    repo.write_test_config(
+        r#"[hooks]
+parallel = true
+
+[pre-merge]
+wait = "for i in $(seq 100); do [ -f ready ] && break; sleep 0.1; done; cat ready"
+signal = "echo signalled > ready"
+"#,
+    );
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--yes", "--no-remove"],
+        Some(&feature_wt),
+    )
+    .output()
+    .unwrap();
+    let stderr = anstream::adapter::strip_str(&String::from_utf8_lossy(&output.stderr)).to_string();
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("user:wait   │ signalled"),
+        "stderr: {stderr}"
+    );
+}
+
+#[rstest]
+fn test_user_hooks_parallel_failure_summary(mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "feature.txt", "feature content", "Add feature");
+    repo.write_test_config(
+        r#"[hooks]
+parallel = true
+
+[pre-merge]
+lint = "exit 2"
+build = "echo built"
+test = "exit 3"
+"#,
+    );
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--yes", "--no-remove"],
+        Some(&feature_wt),
+    )
+    .output()
+    .unwrap();
+    let stderr = anstream::adapter::strip_str(&String::from_utf8_lossy(&output.stderr)).to_string();
+    assert!(!output.status.success(), "stderr: {stderr}");
+    let summary = stderr
+        .lines()
+        .find(|line| line.contains("2 of 3 pre-merge commands failed: "))
+        .unwrap_or_else(|| panic!("no failure summary in stderr: {stderr}"));
+    assert!(
+        summary.contains("user:lint (exit 2)") && summary.contains("user:test (exit 3)"),
+        "summary: {summary}"
+    );
+    assert!(stderr.contains("user:build │ built"), "stderr: {stderr}");
+    // Nothing was merged
+    assert!(!repo.root_path().join("feature.txt").exists());
+}
+
+#[rstest]
+fn test_user_hook_timeout(repo: TestRepo) {
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[post-create.slow]
+command = "sleep 30"
+timeout = "1s"
+on-failure = "abort"
+"#,
+    );
+
+    let started = std::time::Instant::now();
+    let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature"], None)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        started.elapsed() < Duration::from_secs(20),
+        "hook wasn't stopped"
+    );
+    assert_eq!(output.status.code(), Some(124), "stderr: {stderr}");
+    assert!(stderr.contains("timed out after 1s"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_user_hook_branch_scope(repo: TestRepo) {
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[post-create.frontend]
+command = "echo frontend > frontend.txt"
+branches = ["ui/*"]
+exclude = ["ui/legacy-*"]
+
+[post-create.always]
+command = "echo always > always.txt"
+"#,
+    );
+
+    for branch in ["ui/button", "ui/legacy-form", "api"] {
+        let output = make_snapshot_cmd(&repo, "switch", &["--create", branch], None)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+    }
+
+    let worktree = |branch: &str| {
+        repo.root_path()
+            .parent()
+            .unwrap()
+            .join(format!("repo.{branch}"))
+    };
+    assert!(worktree("ui/button").join("frontend.txt").exists());
+    assert!(!worktree("ui/legacy-form").join("frontend.txt").exists());
+    assert!(!worktree("api").join("frontend.txt").exists());
+    for branch in ["ui/button", "ui/legacy-form", "api"] {
+        assert!(worktree(branch).join("always.txt").exists(), "{branch}");
+    }
+
+    // Naming the command runs it regardless of scope
+    let output = repo
+        .wt_command()
+        .args(["hook", "post-create", "frontend"])
+        .current_dir(worktree("api"))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert!(worktree("api").join("frontend.txt").exists());
+}
+
+#[rstest]
+fn test_user_hook_on_failure_policies(mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "feature.txt", "feature content", "Add feature");
+    repo.write_test_config(
+        r#"[pre-merge.slow]
+command = "sleep 30"
+timeout = "1s"
+on-failure = "warn"
+
+[pre-merge.flaky]
+command = "exit 1"
+on-failure = "ignore"
+"#,
+    );
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--yes", "--no-remove"],
+        Some(&feature_wt),
+    )
+    .output()
+    .unwrap();
+    let stderr = anstream::adapter::strip_str(&String::from_utf8_lossy(&output.stderr)).to_string();
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("Command slow failed: timed out after 1s"),
+        "stderr: {stderr}"
+    );
+    assert!(!stderr.contains("Command flaky failed"), "stderr: {stderr}");
+    assert!(repo.root_path().join("feature.txt").exists());
+}
+
+#[rstest]
+fn test_user_hooks_parallel_timeout(mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "feature.txt", "feature content", "Add feature");
+    // `slow` leaves a process holding its output open, which mustn't block either
+    repo.write_test_config(
+        r#"[hooks]
+parallel = true
+
+[pre-merge]
+fast = "echo done; sleep 30 &"
+
+[pre-merge.slow]
+command = "sleep 30"
+timeout = "1s"
+"#,
+    );
+
+    let started = std::time::Instant::now();
+    let output = make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--yes", "--no-remove"],
+        Some(&feature_wt),
+    )
+    .output()
+    .unwrap();
+    let stderr = anstream::adapter::strip_str(&String::from_utf8_lossy(&output.stderr)).to_string();
+    assert!(
+        started.elapsed() < Duration::from_secs(20),
+        "stderr: {stderr}"
+    );
+    assert_eq!(output.status.code(), Some(124), "stderr: {stderr}");
+    assert!(
+        stderr.contains("1 of 2 pre-merge commands failed: user:slow (exit 124)"),
+        "stderr: {stderr}"
+    );
+    assert!(stderr.contains("user:fast │ done"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_no_hooks_skips_switch_hooks(repo: TestRepo) {
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+post-create = "echo created > created.txt"
+post-start = "echo started > started.txt"
+"#,
+    );
+    repo.write_project_config(r#"post-create = "echo project > project.txt""#);
+
+    // No approval prompt either: nothing will run
+    run_wt(
+        &repo,
+        "switch",
+        &["--create", "feature", "--no-hooks"],
+        None,
+    );
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    assert!(worktree_path.exists());
+    thread::sleep(SLEEP_FOR_ABSENCE_CHECK);
+    for file in ["created.txt", "started.txt", "project.txt"] {
+        assert!(!worktree_path.join(file).exists(), "{file} was written");
+    }
+}
+
+#[rstest]
+fn test_no_hooks_skips_failing_merge_hook(mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "feature.txt", "feature content", "Add feature");
+    repo.write_test_config(
+        r#"pre-merge = "exit 1"
+"#,
+    );
+
+    // Global flags are accepted before the subcommand too
+    let output = make_snapshot_cmd_with_global_flags(
+        &repo,
+        "merge",
+        &["main", "--yes", "--no-remove"],
+        Some(&feature_wt),
+        &["--no-hooks"],
+    )
+    .output()
+    .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(repo.root_path().join("feature.txt").exists());
+}
+
 // ============================================================================
 // Combined User and Project Hooks Tests
 // ============================================================================
@@ -812,6 +1173,29 @@ fn test_standalone_hook_pre_commit(repo: TestRepo) {
     assert!(content.contains("STANDALONE_PRE_COMMIT"));
 }
 
+/// Test `wt -C <worktree> hook` runs in that worktree, not the process cwd
+#[rstest]
+fn test_standalone_hook_with_directory_flag(mut repo: TestRepo) {
+    let feature_wt = repo.add_feature();
+    repo.write_test_config(r#"pre-commit = "echo '{{ branch }}' > hook_ran.txt""#);
+
+    let mut cmd = crate::common::wt_command();
+    cmd.current_dir(repo.home_path());
+    cmd.env("WORKTRUNK_CONFIG_PATH", repo.test_config_path());
+    cmd.args(["-C", feature_wt.to_str().unwrap(), "hook", "pre-commit"]);
+
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let content = fs::read_to_string(feature_wt.join("hook_ran.txt")).unwrap();
+    assert_eq!(content.trim(), "feature");
+    assert!(!repo.home_path().join("hook_ran.txt").exists());
+}
+
 /// Test `wt hook post-create` fails when no hooks configured
 #[rstest]
 fn test_standalone_hook_no_hooks_configured(repo: TestRepo) {
@@ -1073,3 +1457,166 @@ first = "echo 'FIRST'"
         "Error should list available commands, got: {stderr}"
     );
 }
+
+// ============================================================================
+// Pre-Switch, Pre-Push, and Post-Remove Hook Tests
+// ============================================================================
+
+#[rstest]
+fn test_user_pre_switch_hook_executes(repo: TestRepo) {
+    repo.write_test_config(
+        r#"pre-switch = "echo '{{ branch }} -> {{ target }}' > pre_switch.txt"
+"#,
+    );
+
+    snapshot_switch("user_pre_switch_executes", &repo, &["--create", "feature"]);
+
+    // Runs in the worktree being left, naming the destination as target
+    let marker_file = repo.root_path().join("pre_switch.txt");
+    let contents = fs::read_to_string(&marker_file).unwrap();
+    assert_eq!(contents.trim(), "main -> feature");
+}
+
+#[rstest]
+fn test_user_pre_switch_failure_blocks_switch(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[pre-switch]
+block = "exit 1"
+"#,
+    );
+
+    snapshot_switch("user_pre_switch_failure", &repo, &["--create", "feature"]);
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    assert!(
+        !worktree_path.exists(),
+        "Worktree should not be created when pre-switch hook fails"
+    );
+}
+
+/// Helper for `wt step push` snapshots
+fn snapshot_step_push(test_name: &str, repo: &TestRepo, cwd: &std::path::Path) {
+    let settings = setup_snapshot_settings(repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(repo, "step", &["push"], Some(cwd));
+        assert_cmd_snapshot!(test_name, cmd);
+    });
+}
+
+#[rstest]
+fn test_user_pre_push_hook_executes(mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "feature.txt", "feature content", "Add feature");
+
+    repo.write_test_config(
+        r#"[pre-push]
+check = "echo 'pushing to {{ target }}' > pre_push.txt"
+"#,
+    );
+
+    snapshot_step_push("user_pre_push_executes", &repo, &feature_wt);
+
+    let contents = fs::read_to_string(feature_wt.join("pre_push.txt")).unwrap();
+    assert_eq!(contents.trim(), "pushing to main");
+}
+
+#[rstest]
+fn test_user_pre_push_failure_blocks_push(mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "feature.txt", "feature content", "Add feature");
+    let main_before = repo.git_output(&["rev-parse", "main"]);
+
+    repo.write_test_config(
+        r#"[pre-push]
+check = "exit 1"
+"#,
+    );
+
+    snapshot_step_push("user_pre_push_failure", &repo, &feature_wt);
+
+    assert_eq!(
+        repo.git_output(&["rev-parse", "main"]),
+        main_before,
+        "main should not move when pre-push hook fails"
+    );
+}
+
+#[rstest]
+fn test_user_post_remove_hook_executes(mut repo: TestRepo) {
+    let _feature_wt = repo.add_worktree("feature");
+
+    repo.write_test_config(
+        r#"[post-remove]
+teardown = "echo 'removed {{ branch }}' > post_remove.txt"
+"#,
+    );
+
+    snapshot_remove(
+        "user_post_remove_executes",
+        &repo,
+        &["feature", "--force-delete"],
+        Some(repo.root_path()),
+    );
+
+    // Runs in the worktree the user lands in, naming the removed branch
+    let marker_file = repo.root_path().join("post_remove.txt");
+    wait_for_file_content(&marker_file, Duration::from_secs(5));
+    let contents = fs::read_to_string(&marker_file).unwrap();
+    assert_eq!(contents.trim(), "removed feature");
+}
+
+#[rstest]
+fn test_user_post_create_links(repo: TestRepo) {
+    fs::create_dir_all(repo.root_path().join("node_modules/pkg")).unwrap();
+    fs::write(repo.root_path().join("node_modules/pkg/index.js"), "shared").unwrap();
+    fs::create_dir_all(repo.root_path().join("target/debug")).unwrap();
+    fs::write(repo.root_path().join("target/debug/app"), "binary").unwrap();
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[post-create]
+symlink = ["node_modules", "dist"]
+hardlink = ["target"]
+check = "cat node_modules/pkg/index.js > linked_marker.txt"
+"#,
+    );
+
+    let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature"], None)
+        .output()
+        .unwrap();
+    let stderr = anstream::adapter::strip_str(&String::from_utf8_lossy(&output.stderr)).to_string();
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("Symlinked node_modules from"),
+        "stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains("Hardlinked target from"),
+        "stderr: {stderr}"
+    );
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    // Missing sources (`dist`) are skipped
+    assert!(!worktree_path.join("dist").exists());
+    assert!(
+        fs::symlink_metadata(worktree_path.join("node_modules"))
+            .unwrap()
+            .file_type()
+            .is_symlink()
+    );
+    assert!(
+        !fs::symlink_metadata(worktree_path.join("target"))
+            .unwrap()
+            .file_type()
+            .is_symlink()
+    );
+    assert_eq!(
+        fs::read_to_string(worktree_path.join("target/debug/app")).unwrap(),
+        "binary"
+    );
+    // Links exist before hook commands run
+    assert_eq!(
+        fs::read_to_string(worktree_path.join("linked_marker.txt")).unwrap(),
+        "shared"
+    );
+}
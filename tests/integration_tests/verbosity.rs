@@ -0,0 +1,47 @@
+//! Integration tests for `-v`/`-vv` logging and `-q`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+fn stderr(repo: &TestRepo, args: &[&str]) -> String {
+    let output = repo
+        .wt_command()
+        .args(args)
+        .env_remove("RUST_LOG")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stderr).unwrap()
+}
+
+#[rstest]
+fn test_verbose_shows_commands(repo: TestRepo) {
+    let verbose = stderr(&repo, &["-v", "list"]);
+    assert!(verbose.contains("git rev-parse"), "{verbose}");
+    assert!(!verbose.contains("[wt-trace]"), "{verbose}");
+
+    let very_verbose = stderr(&repo, &["-vv", "list"]);
+    assert!(very_verbose.contains("[wt-trace]"), "{very_verbose}");
+    assert!(very_verbose.contains("dur="), "{very_verbose}");
+}
+
+#[rstest]
+fn test_quiet_success(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "remove", &["-q", "feature"], None);
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
+#[rstest]
+fn test_quiet_keeps_warnings(repo: TestRepo) {
+    repo.run_git(&["branch", "unmerged"]);
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "remove", &["-q", "unmerged", "missing"], None);
+        assert_cmd_snapshot!(cmd);
+    });
+}
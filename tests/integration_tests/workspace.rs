@@ -0,0 +1,67 @@
+//! Integration tests for `wt workspace`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+fn workspace(repo: &TestRepo, args: &[&str]) {
+    let output = repo
+        .wt_command()
+        .arg("workspace")
+        .args(args)
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+}
+
+fn read(path: &std::path::Path) -> serde_json::Value {
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[rstest]
+fn test_workspace_writes_worktrees(mut repo: TestRepo) {
+    repo.add_worktree("feature/auth");
+    workspace(&repo, &[]);
+
+    let root = repo.root_path();
+    let stem = root.file_name().unwrap().to_string_lossy();
+    let file = root.with_file_name(format!("{stem}.code-workspace"));
+    let folders = &read(&file)["folders"];
+    assert_eq!(folders[0]["name"], "main");
+    assert_eq!(folders[0]["path"], stem.as_ref());
+    assert_eq!(folders[1]["name"], "feature/auth");
+    assert_eq!(folders.as_array().unwrap().len(), 2);
+}
+
+#[rstest]
+fn test_workspace_update_keeps_settings(mut repo: TestRepo) {
+    let file = repo.root_path().join("project.code-workspace");
+    std::fs::write(
+        &file,
+        r#"{"folders": [{"path": "gone"}], "settings": {"editor.tabSize": 2}}"#,
+    )
+    .unwrap();
+    repo.add_worktree("feature");
+    workspace(&repo, &["--output", "project.code-workspace"]);
+
+    let json = read(&file);
+    assert_eq!(json["settings"]["editor.tabSize"], 2);
+    let folders = json["folders"].as_array().unwrap();
+    assert_eq!(folders.len(), 2);
+    assert_eq!(folders[0]["path"], ".");
+    assert_eq!(folders[1]["name"], "feature");
+}
+
+#[rstest]
+fn test_workspace_rejects_comments(repo: TestRepo) {
+    let file = repo.root_path().join("project.code-workspace");
+    std::fs::write(&file, "// settings\n{}").unwrap();
+    let output = repo
+        .wt_command()
+        .args(["workspace", "--output", "project.code-workspace"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), "// settings\n{}");
+}